@@ -1,5 +1,5 @@
 use sc_cli::RunCmd;
-use structopt::StructOpt;
+use structopt::{clap::arg_enum, StructOpt};
 
 #[derive(Debug, StructOpt)]
 pub struct Cli {
@@ -8,6 +8,28 @@ pub struct Cli {
 
 	#[structopt(flatten)]
 	pub run: RunCmd,
+
+	/// Author blocks with manual seal instead of Aura and GRANDPA.
+	///
+	/// With `manual`, blocks are only authored when requested through the `engine_createBlock`
+	/// RPC. With `instant`, a block is authored as soon as a transaction enters the pool.
+	#[structopt(
+		long,
+		value_name = "METHOD",
+		possible_values = &Sealing::variants(),
+		case_insensitive = true,
+		requires = "dev"
+	)]
+	pub sealing: Option<Sealing>,
+}
+
+arg_enum! {
+	/// The method used to seal blocks of a development chain.
+	#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+	pub enum Sealing {
+		Manual,
+		Instant,
+	}
 }
 
 #[derive(Debug, StructOpt)]
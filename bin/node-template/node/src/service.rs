@@ -1,13 +1,20 @@
 //! Service and ServiceFactory implementation. Specialized wrapper over substrate service.
 
+use crate::cli::Sealing;
+use futures::{channel::mpsc, StreamExt};
 use node_template_runtime::{self, opaque::Block, RuntimeApi};
 use sc_client_api::{ExecutorProvider, RemoteBackend};
 use sc_consensus_aura::{ImportQueueParams, SlotProportion, StartAuraParams};
+use sc_consensus_manual_seal::{
+	consensus::aura::{AuraConsensusDataProvider, SlotTimestampProvider},
+	EngineCommand, ManualSealParams,
+};
 pub use sc_executor::NativeElseWasmExecutor;
 use sc_finality_grandpa::SharedVoterState;
 use sc_keystore::LocalKeystore;
 use sc_service::{error::Error as ServiceError, Configuration, TaskManager};
 use sc_telemetry::{Telemetry, TelemetryWorker};
+use sc_transaction_pool_api::TransactionPool;
 use sp_consensus::SlotData;
 use sp_consensus_aura::sr25519::AuthorityPair as AuraPair;
 use std::{sync::Arc, time::Duration};
@@ -32,25 +39,17 @@ type FullClient =
 type FullBackend = sc_service::TFullBackend<Block>;
 type FullSelectChain = sc_consensus::LongestChain<FullBackend, Block>;
 
-pub fn new_partial(
+/// Builds the components of a full client that don't depend on the consensus engine.
+fn new_chain_components(
 	config: &Configuration,
 ) -> Result<
 	sc_service::PartialComponents<
 		FullClient,
 		FullBackend,
 		FullSelectChain,
-		sc_consensus::DefaultImportQueue<Block, FullClient>,
+		(),
 		sc_transaction_pool::FullPool<Block, FullClient>,
-		(
-			sc_finality_grandpa::GrandpaBlockImport<
-				FullBackend,
-				Block,
-				FullClient,
-				FullSelectChain,
-			>,
-			sc_finality_grandpa::LinkHalf<Block, FullClient, FullSelectChain>,
-			Option<Telemetry>,
-		),
+		Option<Telemetry>,
 	>,
 	ServiceError,
 > {
@@ -73,6 +72,7 @@ pub fn new_partial(
 		config.wasm_method,
 		config.default_heap_pages,
 		config.max_runtime_instances,
+		config.runtime_cache_size,
 	);
 
 	let (client, backend, keystore_container, task_manager) =
@@ -98,6 +98,51 @@ pub fn new_partial(
 		client.clone(),
 	);
 
+	Ok(sc_service::PartialComponents {
+		client,
+		backend,
+		task_manager,
+		import_queue: (),
+		keystore_container,
+		select_chain,
+		transaction_pool,
+		other: telemetry,
+	})
+}
+
+pub fn new_partial(
+	config: &Configuration,
+) -> Result<
+	sc_service::PartialComponents<
+		FullClient,
+		FullBackend,
+		FullSelectChain,
+		sc_consensus::DefaultImportQueue<Block, FullClient>,
+		sc_transaction_pool::FullPool<Block, FullClient>,
+		(
+			sc_finality_grandpa::GrandpaBlockImport<
+				FullBackend,
+				Block,
+				FullClient,
+				FullSelectChain,
+			>,
+			sc_finality_grandpa::LinkHalf<Block, FullClient, FullSelectChain>,
+			Option<Telemetry>,
+		),
+	>,
+	ServiceError,
+> {
+	let sc_service::PartialComponents {
+		client,
+		backend,
+		task_manager,
+		keystore_container,
+		select_chain,
+		transaction_pool,
+		other: telemetry,
+		..
+	} = new_chain_components(config)?;
+
 	let (grandpa_block_import, grandpa_link) = sc_finality_grandpa::block_import(
 		client.clone(),
 		&(client.clone() as Arc<_>),
@@ -129,6 +174,7 @@ pub fn new_partial(
 			),
 			registry: config.prometheus_registry(),
 			check_for_equivocation: Default::default(),
+			allowed_slot_drift: Default::default(),
 			telemetry: telemetry.as_ref().map(|x| x.handle()),
 		})?;
 
@@ -152,7 +198,21 @@ fn remote_keystore(_url: &String) -> Result<Arc<LocalKeystore>, &'static str> {
 }
 
 /// Builds a new service for a full client.
-pub fn new_full(mut config: Configuration) -> Result<TaskManager, ServiceError> {
+///
+/// Blocks are authored with Aura and finalized with GRANDPA, unless a `sealing` method is given,
+/// in which case they are sealed with manual seal instead.
+pub fn new_full(
+	config: Configuration,
+	sealing: Option<Sealing>,
+) -> Result<TaskManager, ServiceError> {
+	match sealing {
+		Some(sealing) => new_full_manual_seal(config, sealing),
+		None => new_full_aura_grandpa(config),
+	}
+}
+
+/// Builds a new service for a full client running Aura and GRANDPA.
+fn new_full_aura_grandpa(mut config: Configuration) -> Result<TaskManager, ServiceError> {
 	let sc_service::PartialComponents {
 		client,
 		backend,
@@ -191,6 +251,7 @@ pub fn new_full(mut config: Configuration) -> Result<TaskManager, ServiceError>
 			on_demand: None,
 			block_announce_validator_builder: None,
 			warp_sync: Some(warp_sync),
+			block_relay: None,
 		})?;
 
 	if config.offchain_worker.enabled {
@@ -214,8 +275,12 @@ pub fn new_full(mut config: Configuration) -> Result<TaskManager, ServiceError>
 		let pool = transaction_pool.clone();
 
 		Box::new(move |deny_unsafe, _| {
-			let deps =
-				crate::rpc::FullDeps { client: client.clone(), pool: pool.clone(), deny_unsafe };
+			let deps = crate::rpc::FullDeps {
+				client: client.clone(),
+				pool: pool.clone(),
+				deny_unsafe,
+				command_sink: None,
+			};
 
 			Ok(crate::rpc::create_full(deps))
 		})
@@ -331,6 +396,155 @@ pub fn new_full(mut config: Configuration) -> Result<TaskManager, ServiceError>
 	Ok(task_manager)
 }
 
+/// Builds a new service for a full client sealing blocks with manual seal.
+///
+/// There is no finality gadget, blocks are only finalized on request through the
+/// `engine_finalizeBlock` RPC.
+fn new_full_manual_seal(
+	config: Configuration,
+	sealing: Sealing,
+) -> Result<TaskManager, ServiceError> {
+	let sc_service::PartialComponents {
+		client,
+		backend,
+		mut task_manager,
+		keystore_container,
+		select_chain,
+		transaction_pool,
+		other: mut telemetry,
+		..
+	} = new_chain_components(&config)?;
+
+	let import_queue = sc_consensus_manual_seal::import_queue(
+		Box::new(client.clone()),
+		&task_manager.spawn_essential_handle(),
+		config.prometheus_registry(),
+	);
+
+	let (network, system_rpc_tx, network_starter) =
+		sc_service::build_network(sc_service::BuildNetworkParams {
+			config: &config,
+			client: client.clone(),
+			transaction_pool: transaction_pool.clone(),
+			spawn_handle: task_manager.spawn_handle(),
+			import_queue,
+			on_demand: None,
+			block_announce_validator_builder: None,
+			warp_sync: None,
+			block_relay: None,
+		})?;
+
+	if config.offchain_worker.enabled {
+		sc_service::build_offchain_workers(
+			&config,
+			task_manager.spawn_handle(),
+			client.clone(),
+			network.clone(),
+		);
+	}
+
+	let prometheus_registry = config.prometheus_registry().cloned();
+
+	// Channel for the rpc handler to communicate with the authorship task.
+	let (command_sink, commands_stream) = mpsc::channel(1024);
+
+	let rpc_extensions_builder = {
+		let client = client.clone();
+		let pool = transaction_pool.clone();
+
+		Box::new(move |deny_unsafe, _| {
+			let deps = crate::rpc::FullDeps {
+				client: client.clone(),
+				pool: pool.clone(),
+				deny_unsafe,
+				command_sink: Some(command_sink.clone()),
+			};
+
+			Ok(crate::rpc::create_full(deps))
+		})
+	};
+
+	let _rpc_handlers = sc_service::spawn_tasks(sc_service::SpawnTasksParams {
+		network,
+		client: client.clone(),
+		keystore: keystore_container.sync_keystore(),
+		task_manager: &mut task_manager,
+		transaction_pool: transaction_pool.clone(),
+		rpc_extensions_builder,
+		on_demand: None,
+		remote_blockchain: None,
+		backend,
+		system_rpc_tx,
+		config,
+		telemetry: telemetry.as_mut(),
+	})?;
+
+	let commands_stream = match sealing {
+		Sealing::Manual => commands_stream.boxed(),
+		// seal a block whenever a transaction is imported into the pool, while still accepting
+		// commands through the rpc.
+		Sealing::Instant => futures::stream::select(
+			commands_stream,
+			transaction_pool
+				.import_notification_stream()
+				.map(|_| EngineCommand::SealNewBlock {
+					create_empty: false,
+					finalize: false,
+					parent_hash: None,
+					sender: None,
+				}),
+		)
+		.boxed(),
+	};
+
+	let proposer_factory = sc_basic_authorship::ProposerFactory::new(
+		task_manager.spawn_handle(),
+		client.clone(),
+		transaction_pool.clone(),
+		prometheus_registry.as_ref(),
+		telemetry.as_ref().map(|x| x.handle()),
+	);
+
+	let consensus_data_provider = AuraConsensusDataProvider::<Block, _>::new(client.clone())
+		.map_err(|e| ServiceError::Other(e.to_string()))?;
+
+	let create_inherent_data_providers = {
+		let client = client.clone();
+
+		move |parent, ()| {
+			let client = client.clone();
+
+			async move {
+				let timestamp = SlotTimestampProvider::new::<Block, _>(client, parent)
+					.map_err(|err| format!("{:?}", err))?;
+
+				let slot =
+					sp_consensus_aura::inherents::InherentDataProvider::new(timestamp.slot());
+
+				Ok((timestamp, slot))
+			}
+		}
+	};
+
+	let authorship_future = sc_consensus_manual_seal::run_manual_seal(ManualSealParams {
+		block_import: client.clone(),
+		env: proposer_factory,
+		client,
+		pool: transaction_pool,
+		commands_stream,
+		select_chain,
+		consensus_data_provider: Some(Box::new(consensus_data_provider)),
+		create_inherent_data_providers,
+	});
+
+	// the manual seal authoring task is considered essential, i.e. if it
+	// fails we take down the service with it.
+	task_manager.spawn_essential_handle().spawn("manual-seal", authorship_future);
+
+	network_starter.start_network();
+	Ok(task_manager)
+}
+
 /// Builds a new service for a light client.
 pub fn new_light(mut config: Configuration) -> Result<TaskManager, ServiceError> {
 	let telemetry = config
@@ -348,6 +562,7 @@ pub fn new_light(mut config: Configuration) -> Result<TaskManager, ServiceError>
 		config.wasm_method,
 		config.default_heap_pages,
 		config.max_runtime_instances,
+		config.runtime_cache_size,
 	);
 
 	let (client, backend, keystore_container, mut task_manager, on_demand) =
@@ -403,6 +618,7 @@ pub fn new_light(mut config: Configuration) -> Result<TaskManager, ServiceError>
 			can_author_with: sp_consensus::NeverCanAuthor,
 			registry: config.prometheus_registry(),
 			check_for_equivocation: Default::default(),
+			allowed_slot_drift: Default::default(),
 			telemetry: telemetry.as_ref().map(|x| x.handle()),
 		})?;
 
@@ -421,6 +637,7 @@ pub fn new_light(mut config: Configuration) -> Result<TaskManager, ServiceError>
 			on_demand: Some(on_demand.clone()),
 			block_announce_validator_builder: None,
 			warp_sync: Some(warp_sync),
+			block_relay: None,
 		})?;
 
 	if config.offchain_worker.enabled {
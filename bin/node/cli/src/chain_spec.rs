@@ -363,6 +363,7 @@ pub fn testnet_genesis(
 		vesting: Default::default(),
 		gilt: Default::default(),
 		transaction_storage: Default::default(),
+		alliance: Default::default(),
 	}
 }
 
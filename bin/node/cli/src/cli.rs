@@ -47,6 +47,13 @@ pub enum Subcommand {
 	#[structopt(name = "benchmark", about = "Benchmark runtime pallets.")]
 	Benchmark(frame_benchmarking_cli::BenchmarkCmd),
 
+	/// Re-execute historic blocks and compare their execution time to their consumed weight.
+	#[structopt(
+		name = "benchmark-block",
+		about = "Benchmark the execution time of historic blocks against their weight."
+	)]
+	BenchmarkBlock(frame_benchmarking_cli::BlockCmd),
+
 	/// Try some command against runtime state.
 	#[cfg(feature = "try-runtime")]
 	TryRuntime(try_runtime_cli::TryRuntimeCmd),
@@ -84,4 +91,7 @@ pub enum Subcommand {
 
 	/// Revert the chain to a previous state.
 	Revert(sc_cli::RevertCmd),
+
+	/// Print information about the local chain database.
+	ChainInfo(sc_cli::ChainInfoCmd),
 }
@@ -42,6 +42,23 @@ type FullGrandpaBlockImport =
 type LightClient =
 	sc_service::TLightClient<Block, RuntimeApi, NativeElseWasmExecutor<ExecutorDispatch>>;
 
+/// Transaction pool options from the configuration, with senders identified by the address
+/// signing the extrinsic.
+///
+/// The pool only knows about opaque extrinsics, so if a per-sender limit is set, every submitted
+/// extrinsic gets encoded and decoded a second time, on top of the decoding done by its
+/// validation, to find out its sender.
+fn transaction_pool_options(config: &Configuration) -> sc_transaction_pool::Options {
+	use codec::{Decode, Encode};
+
+	let mut options = config.transaction_pool.clone();
+	options.sender_of = Some(sc_transaction_pool::SenderOf::new(|mut encoded| {
+		let xt = node_runtime::UncheckedExtrinsic::decode(&mut encoded).ok()?;
+		xt.signature.map(|(address, _, _)| address.encode())
+	}));
+	options
+}
+
 pub fn new_partial(
 	config: &Configuration,
 ) -> Result<
@@ -82,6 +99,7 @@ pub fn new_partial(
 		config.wasm_method,
 		config.default_heap_pages,
 		config.max_runtime_instances,
+		config.runtime_cache_size,
 	);
 
 	let (client, backend, keystore_container, task_manager) =
@@ -100,7 +118,7 @@ pub fn new_partial(
 	let select_chain = sc_consensus::LongestChain::new(backend.clone());
 
 	let transaction_pool = sc_transaction_pool::BasicPool::new_full(
-		config.transaction_pool.clone(),
+		transaction_pool_options(config),
 		config.role.is_authority().into(),
 		config.prometheus_registry(),
 		task_manager.spawn_essential_handle(),
@@ -256,6 +274,7 @@ pub fn new_full_base(
 			on_demand: None,
 			block_announce_validator_builder: None,
 			warp_sync: Some(warp_sync),
+			block_relay: None,
 		})?;
 
 	if config.offchain_worker.enabled {
@@ -461,6 +480,7 @@ pub fn new_light_base(
 		config.wasm_method,
 		config.default_heap_pages,
 		config.max_runtime_instances,
+		config.runtime_cache_size,
 	);
 
 	let (client, backend, keystore_container, mut task_manager, on_demand) =
@@ -480,7 +500,7 @@ pub fn new_light_base(
 	let select_chain = sc_consensus::LongestChain::new(backend.clone());
 
 	let transaction_pool = Arc::new(sc_transaction_pool::BasicPool::new_light(
-		config.transaction_pool.clone(),
+		transaction_pool_options(&config),
 		config.prometheus_registry(),
 		task_manager.spawn_essential_handle(),
 		client.clone(),
@@ -543,6 +563,7 @@ pub fn new_light_base(
 			on_demand: Some(on_demand.clone()),
 			block_announce_validator_builder: None,
 			warp_sync: Some(warp_sync),
+			block_relay: None,
 		})?;
 
 	let enable_grandpa = !config.disable_grandpa;
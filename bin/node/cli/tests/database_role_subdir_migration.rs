@@ -38,6 +38,7 @@ fn database_role_subdir_migration() {
 			state_cache_size: 0,
 			state_cache_child_ratio: None,
 			state_pruning: PruningMode::ArchiveAll,
+			override_state_pruning: false,
 			source: DatabaseSource::RocksDb { path: path.to_path_buf(), cache_size: 128 },
 			keep_blocks: KeepBlocks::All,
 			transaction_storage: TransactionStorageMode::BlockBody,
@@ -83,6 +84,7 @@ fn database_role_subdir_migration_fail_on_different_role() {
 			state_cache_size: 0,
 			state_cache_child_ratio: None,
 			state_pruning: PruningMode::ArchiveAll,
+			override_state_pruning: false,
 			source: DatabaseSource::RocksDb { path: path.to_path_buf(), cache_size: 128 },
 			keep_blocks: KeepBlocks::All,
 			transaction_storage: TransactionStorageMode::BlockBody,
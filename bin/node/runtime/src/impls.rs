@@ -17,8 +17,18 @@
 
 //! Some configurable implementations as associated type for the substrate runtime.
 
-use crate::{Authorship, Balances, NegativeImbalance};
-use frame_support::traits::{Currency, OnUnbalanced};
+use crate::{
+	AccountId, AllianceMotion, Authorship, Balances, Call, Hash, Identity, NegativeImbalance,
+	Origin,
+};
+use frame_support::{
+	dispatch::DispatchResultWithPostInfo,
+	traits::{Currency, OnUnbalanced},
+	weights::Weight,
+};
+use pallet_alliance::{IdentityVerifier, ProposalIndex, ProposalProvider};
+use pallet_identity::{Data, Judgement};
+use sp_std::prelude::*;
 
 pub struct Author;
 impl OnUnbalanced<NegativeImbalance> for Author {
@@ -27,6 +37,67 @@ impl OnUnbalanced<NegativeImbalance> for Author {
 	}
 }
 
+pub struct AllianceIdentityVerifier;
+impl IdentityVerifier<AccountId> for AllianceIdentityVerifier {
+	fn has_required_identities(who: &AccountId) -> bool {
+		Identity::identity(who)
+			.map_or(false, |reg| reg.info.display != Data::None && reg.info.web != Data::None)
+	}
+
+	fn has_good_judgement(who: &AccountId) -> bool {
+		Identity::identity(who).map_or(false, |reg| {
+			reg.judgements
+				.iter()
+				.any(|(_, j)| matches!(j, Judgement::KnownGood | Judgement::Reasonable))
+		})
+	}
+
+	fn super_account_id(who: &AccountId) -> Option<AccountId> {
+		Identity::super_of(who).map(|parent| parent.0)
+	}
+}
+
+pub struct AllianceProposalProvider;
+impl ProposalProvider<AccountId, Hash, Call> for AllianceProposalProvider {
+	fn propose_proposal(
+		who: AccountId,
+		threshold: u32,
+		proposal: Box<Call>,
+		length_bound: u32,
+	) -> DispatchResultWithPostInfo {
+		AllianceMotion::propose(Origin::signed(who), threshold, proposal, length_bound)
+	}
+
+	fn vote_proposal(
+		who: AccountId,
+		proposal: Hash,
+		index: ProposalIndex,
+		approve: bool,
+	) -> DispatchResultWithPostInfo {
+		AllianceMotion::vote(Origin::signed(who), proposal, index, approve)
+	}
+
+	fn close_proposal(
+		who: AccountId,
+		proposal_hash: Hash,
+		proposal_index: ProposalIndex,
+		proposal_weight_bound: Weight,
+		length_bound: u32,
+	) -> DispatchResultWithPostInfo {
+		AllianceMotion::close(
+			Origin::signed(who),
+			proposal_hash,
+			proposal_index,
+			proposal_weight_bound,
+			length_bound,
+		)
+	}
+
+	fn proposal_of(proposal_hash: Hash) -> Option<Call> {
+		AllianceMotion::proposal_of(proposal_hash)
+	}
+}
+
 #[cfg(test)]
 mod multiplier_tests {
 	use pallet_transaction_payment::{Multiplier, TargetedFeeAdjustment};
@@ -41,7 +112,7 @@ mod multiplier_tests {
 		AdjustmentVariable, MinimumMultiplier, Runtime, RuntimeBlockWeights as BlockWeights,
 		System, TargetBlockFullness, TransactionPayment,
 	};
-	use frame_support::weights::{DispatchClass, Weight, WeightToFeePolynomial};
+	use frame_support::weights::{DispatchClass, Weight, WeightToFee};
 
 	fn max_normal() -> Weight {
 		BlockWeights::get()
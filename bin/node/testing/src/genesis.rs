@@ -100,5 +100,6 @@ pub fn config_endowed(
 		vesting: Default::default(),
 		gilt: Default::default(),
 		transaction_storage: Default::default(),
+		alliance: Default::default(),
 	}
 }
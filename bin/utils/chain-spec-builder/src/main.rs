@@ -27,10 +27,12 @@ use rand::{distributions::Alphanumeric, rngs::OsRng, Rng};
 use structopt::StructOpt;
 
 use node_cli::chain_spec::{self, AccountId};
+use sc_chain_spec::{ChainSpec, GenesisConfigBuilderRuntimeCaller, NoExtension};
 use sc_keystore::LocalKeystore;
 use sp_core::{
 	crypto::{Public, Ss58Codec},
 	sr25519,
+	storage::{well_known_keys, Storage},
 };
 use sp_keystore::{SyncCryptoStore, SyncCryptoStorePtr};
 
@@ -84,6 +86,28 @@ enum ChainSpecBuilder {
 		#[structopt(long, short)]
 		keystore_path: Option<PathBuf>,
 	},
+	/// Create a new raw chain spec from a runtime Wasm blob, using the `GenesisBuilder` API of
+	/// the runtime. No native runtime is needed.
+	///
+	/// Without `--preset` or `--patch`, the default genesis config of the runtime is used.
+	Runtime {
+		/// The path to the runtime Wasm blob.
+		#[structopt(long, short)]
+		runtime: PathBuf,
+		/// List the names of the presets provided by the runtime instead of creating a chain
+		/// spec.
+		#[structopt(long)]
+		list_presets: bool,
+		/// The name of the preset to build the genesis config from.
+		#[structopt(long, conflicts_with = "patch")]
+		preset: Option<String>,
+		/// The path to a JSON patch that is merged over the default genesis config.
+		#[structopt(long)]
+		patch: Option<PathBuf>,
+		/// The path where the chain spec should be saved.
+		#[structopt(long, short, default_value = "./chain_spec.json")]
+		chain_spec_path: PathBuf,
+	},
 }
 
 impl ChainSpecBuilder {
@@ -92,6 +116,7 @@ impl ChainSpecBuilder {
 		match self {
 			ChainSpecBuilder::New { chain_spec_path, .. } => chain_spec_path.as_path(),
 			ChainSpecBuilder::Generate { chain_spec_path, .. } => chain_spec_path.as_path(),
+			ChainSpecBuilder::Runtime { chain_spec_path, .. } => chain_spec_path.as_path(),
 		}
 	}
 }
@@ -161,6 +186,55 @@ fn generate_chain_spec(
 	chain_spec.as_json(false).map_err(|err| err)
 }
 
+fn generate_chain_spec_from_runtime(
+	code: &[u8],
+	preset: Option<String>,
+	patch: Option<PathBuf>,
+) -> Result<String, String> {
+	let caller = GenesisConfigBuilderRuntimeCaller::new(code);
+	let mut storage = match (preset, patch) {
+		(Some(preset), _) => caller.get_storage_for_named_preset(&preset)?,
+		(None, Some(patch)) => {
+			let patch = fs::read(patch).map_err(|err| err.to_string())?;
+			let patch = serde_json::from_slice(&patch)
+				.map_err(|err| format!("Failed to parse the JSON patch: {}", err))?;
+			caller.get_storage_for_patch(patch)?
+		},
+		(None, None) => caller.get_storage_for_config(caller.get_default_config()?)?,
+	};
+	storage.top.insert(well_known_keys::CODE.to_vec(), code.to_vec());
+
+	let mut chain_spec = sc_chain_spec::GenericChainSpec::<(), NoExtension>::from_genesis(
+		"Custom",
+		"custom",
+		sc_chain_spec::ChainType::Live,
+		|| (),
+		vec![],
+		None,
+		None,
+		None,
+		Default::default(),
+	);
+	chain_spec.set_storage(storage.clone());
+	let json = chain_spec.as_json(true)?;
+
+	verify_round_trip(&json, &storage)?;
+	Ok(json)
+}
+
+/// Check that the raw chain spec `json` builds exactly the genesis `storage` it was created from.
+fn verify_round_trip(json: &str, storage: &Storage) -> Result<(), String> {
+	let chain_spec = sc_chain_spec::GenericChainSpec::<(), NoExtension>::from_json_bytes(
+		json.as_bytes().to_vec(),
+	)?;
+	let built = chain_spec.as_storage_builder().build_storage()?;
+
+	if built.top != storage.top || built.children_default != storage.children_default {
+		return Err("The generated chain spec does not round-trip to the same genesis storage".into())
+	}
+	Ok(())
+}
+
 fn generate_authority_keys_and_store(seeds: &[String], keystore_path: &Path) -> Result<(), String> {
 	for (n, seed) in seeds.into_iter().enumerate() {
 		let keystore: SyncCryptoStorePtr = Arc::new(
@@ -280,6 +354,18 @@ fn main() -> Result<(), String> {
 			sudo_account,
 			..
 		} => (authority_seeds, nominator_accounts, endowed_accounts, sudo_account),
+		ChainSpecBuilder::Runtime { runtime, list_presets, preset, patch, .. } => {
+			let code = fs::read(runtime).map_err(|err| err.to_string())?;
+			if list_presets {
+				for name in GenesisConfigBuilderRuntimeCaller::new(&code).preset_names()? {
+					println!("{}", name);
+				}
+				return Ok(())
+			}
+
+			let json = generate_chain_spec_from_runtime(&code, preset, patch)?;
+			return fs::write(chain_spec_path, json).map_err(|err| err.to_string())
+		},
 	};
 
 	let json =
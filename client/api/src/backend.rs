@@ -559,6 +559,13 @@ pub trait Backend<Block: BlockT>: AuxStore + Send + Sync {
 	/// something that the import of a block would interfere with, e.g. importing
 	/// a new block or calculating the best head.
 	fn get_import_lock(&self) -> &RwLock<()>;
+
+	/// Pin the state of the given block, preventing it from being pruned until a matching
+	/// call to `unpin_block`. Pins are reference counted.
+	fn pin_block(&self, hash: &Block::Hash) -> sp_blockchain::Result<()>;
+
+	/// Release a pin previously taken with `pin_block`.
+	fn unpin_block(&self, hash: &Block::Hash);
 }
 
 /// Changes trie storage that supports pruning.
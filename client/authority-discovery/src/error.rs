@@ -32,6 +32,14 @@ pub enum Error {
 	ReceivingDhtValueFoundEventWithNoRecords,
 	/// Failed to verify a dht payload with the given signature.
 	VerifyingDhtPayload,
+	/// Received a dht record without a signature with the network identity of its publisher.
+	MissingPeerIdSignature,
+	/// Received a dht record whose addresses don't all share the same peer id.
+	ReceivingDhtValueWithDifferentPeerIds,
+	/// Failed to decode the network identity of the publisher of a dht record.
+	ParsingLibp2pIdentity(libp2p::identity::error::DecodingError),
+	/// Failed to sign with the network identity of the local node.
+	NetworkSigning(libp2p::identity::error::SigningError),
 	/// Failed to hash the authority id to be used as a dht key.
 	HashingAuthorityId(libp2p::core::multiaddr::multihash::Error),
 	/// Failed calling into the Substrate runtime.
@@ -75,6 +75,13 @@ pub struct WorkerConfig {
 	///
 	/// Defaults to `true` to avoid the surprise factor.
 	pub publish_non_global_ips: bool,
+
+	/// If `true`, records which aren't signed with the network identity of the node that
+	/// published them are discarded. Otherwise they are accepted, as nodes which don't sign them
+	/// yet may still be part of the network.
+	///
+	/// Defaults to `false`.
+	pub strict_record_validation: bool,
 }
 
 impl Default for WorkerConfig {
@@ -95,6 +102,7 @@ impl Default for WorkerConfig {
 			// `authority_discovery_dht_event_received`.
 			max_query_interval: Duration::from_secs(10 * 60),
 			publish_non_global_ips: true,
+			strict_record_validation: false,
 		}
 	}
 }
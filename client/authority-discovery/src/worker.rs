@@ -27,14 +27,14 @@ use std::{
 	convert::TryInto,
 	marker::PhantomData,
 	sync::Arc,
-	time::Duration,
+	time::{Duration, SystemTime, UNIX_EPOCH},
 };
 
 use futures::{channel::mpsc, future, stream::Fuse, FutureExt, Stream, StreamExt};
 
 use addr_cache::AddrCache;
 use async_trait::async_trait;
-use codec::Decode;
+use codec::{Decode, Encode};
 use ip_network::IpNetwork;
 use libp2p::{
 	core::multiaddr,
@@ -45,7 +45,7 @@ use prometheus_endpoint::{register, Counter, CounterVec, Gauge, Opts, U64};
 use prost::Message;
 use rand::{seq::SliceRandom, thread_rng};
 use sc_client_api::blockchain::HeaderBackend;
-use sc_network::{DhtEvent, ExHashT, Multiaddr, NetworkStateInfo, PeerId};
+use sc_network::{DhtEvent, ExHashT, Multiaddr, NetworkStateInfo, PeerId, Signature};
 use sp_api::ProvideRuntimeApi;
 use sp_authority_discovery::{
 	AuthorityDiscoveryApi, AuthorityId, AuthorityPair, AuthoritySignature,
@@ -87,9 +87,9 @@ pub enum Role {
 ///
 ///    2. Get the list of keys owned by the local node participating in the current authority set.
 ///
-///    3. Sign the addresses with the keys.
+///    3. Sign the addresses with the keys and with the network identity of the node.
 ///
-///    4. Put addresses and signature as a record with the authority id as a key on a Kademlia DHT.
+///    4. Put addresses and signatures as a record with the authority id as a key on a Kademlia DHT.
 ///
 /// When constructed with either [`Role::PublishAndDiscover`] or [`Role::Discover`] a [`Worker`]
 /// will
@@ -98,7 +98,8 @@ pub enum Role {
 ///
 ///    2. Start DHT queries for the ids of the authorities.
 ///
-///    3. Validate the signatures of the retrieved key value pairs.
+///    3. Validate the signatures of the retrieved key value pairs, keeping only the most recently
+///    created records.
 ///
 ///    4. Add the retrieved external addresses as priority nodes to the
 ///    network peerset.
@@ -124,6 +125,8 @@ pub struct Worker<Client, Network, Block, DhtEventStream> {
 	latest_published_keys: HashSet<CryptoTypePublicPair>,
 	/// Same value as in the configuration.
 	publish_non_global_ips: bool,
+	/// Same value as in the configuration.
+	strict_record_validation: bool,
 
 	/// Interval at which to request addresses of authorities, refilling the pending lookups queue.
 	query_interval: ExpIncInterval,
@@ -134,6 +137,9 @@ pub struct Worker<Client, Network, Block, DhtEventStream> {
 	in_flight_lookups: HashMap<libp2p::kad::record::Key, AuthorityId>,
 
 	addr_cache: addr_cache::AddrCache,
+	/// Creation time of the latest record cached for each authority, used to ignore outdated
+	/// records.
+	latest_record_creation_times: HashMap<AuthorityId, u128>,
 
 	metrics: Option<Metrics>,
 
@@ -197,10 +203,12 @@ where
 			publish_if_changed_interval,
 			latest_published_keys: HashSet::new(),
 			publish_non_global_ips: config.publish_non_global_ips,
+			strict_record_validation: config.strict_record_validation,
 			query_interval,
 			pending_lookups: Vec::new(),
 			in_flight_lookups: HashMap::new(),
 			addr_cache,
+			latest_record_creation_times: HashMap::new(),
 			role,
 			metrics,
 			phantom: PhantomData,
@@ -322,10 +330,26 @@ where
 				.set(addresses.len().try_into().unwrap_or(std::u64::MAX));
 		}
 
+		let creation_time = SystemTime::now()
+			.duration_since(UNIX_EPOCH)
+			.map(|time| time.as_nanos())
+			.unwrap_or_default();
 		let mut serialized_addresses = vec![];
-		schema::AuthorityAddresses { addresses }
-			.encode(&mut serialized_addresses)
-			.map_err(Error::EncodingProto)?;
+		schema::AuthorityAddresses {
+			addresses,
+			creation_time: Some(schema::TimestampInfo { timestamp: creation_time.encode() }),
+		}
+		.encode(&mut serialized_addresses)
+		.map_err(Error::EncodingProto)?;
+
+		let peer_signature = self
+			.network
+			.sign_with_local_identity(&serialized_addresses)
+			.map_err(Error::NetworkSigning)?;
+		let peer_signature = schema::PeerSignature {
+			signature: peer_signature.bytes,
+			public_key: peer_signature.public_key.into_protobuf_encoding(),
+		};
 
 		let keys_vec = keys.iter().cloned().collect::<Vec<_>>();
 		let signatures = key_store
@@ -343,9 +367,13 @@ where
 			// Verify that all signatures exist for all provided keys.
 			let signature =
 				sign_result.ok().flatten().ok_or_else(|| Error::MissingSignature(key.clone()))?;
-			schema::SignedAuthorityAddresses { addresses: serialized_addresses.clone(), signature }
-				.encode(&mut signed_addresses)
-				.map_err(Error::EncodingProto)?;
+			schema::SignedAuthorityAddresses {
+				addresses: serialized_addresses.clone(),
+				signature,
+				peer_signature: Some(peer_signature.clone()),
+			}
+			.encode(&mut signed_addresses)
+			.map_err(Error::EncodingProto)?;
 
 			self.network.put_value(hash_authority_id(key.1.as_ref()), signed_addresses);
 		}
@@ -377,6 +405,7 @@ where
 			.collect();
 
 		self.addr_cache.retain_ids(&authorities);
+		self.latest_record_creation_times.retain(|id, _| authorities.contains(id));
 
 		authorities.shuffle(&mut thread_rng());
 		self.pending_lookups = authorities;
@@ -492,33 +521,33 @@ where
 
 		let local_peer_id = self.network.local_peer_id();
 
-		let remote_addresses: Vec<Multiaddr> = values
+		let records = values
 			.into_iter()
-			.map(|(_k, v)| {
-				let schema::SignedAuthorityAddresses { signature, addresses } =
-					schema::SignedAuthorityAddresses::decode(v.as_slice())
-						.map_err(Error::DecodingProto)?;
+			.map(|(_k, v)| self.verify_record(&authority_id, &v))
+			.collect::<Result<Vec<(u128, Vec<Multiaddr>)>>>()?;
 
-				let signature = AuthoritySignature::decode(&mut &signature[..])
-					.map_err(Error::EncodingDecodingScale)?;
-
-				if !AuthorityPair::verify(&signature, &addresses, &authority_id) {
-					return Err(Error::VerifyingDhtPayload)
-				}
-
-				let addresses = schema::AuthorityAddresses::decode(addresses.as_slice())
-					.map(|a| a.addresses)
-					.map_err(Error::DecodingProto)?
-					.into_iter()
-					.map(|a| a.try_into())
-					.collect::<std::result::Result<_, _>>()
-					.map_err(Error::ParsingMultiaddress)?;
+		// Only keep the most recent records, ignoring them if an even more recent one is cached.
+		let latest_creation_time = records
+			.iter()
+			.map(|(creation_time, _)| *creation_time)
+			.max()
+			.unwrap_or_default();
+		if self
+			.latest_record_creation_times
+			.get(&authority_id)
+			.map_or(false, |cached| *cached > latest_creation_time)
+		{
+			debug!(
+				target: LOG_TARGET,
+				"Ignoring outdated records of authority {:?}.", authority_id,
+			);
+			return Ok(())
+		}
 
-				Ok(addresses)
-			})
-			.collect::<Result<Vec<Vec<Multiaddr>>>>()?
+		let remote_addresses: Vec<Multiaddr> = records
 			.into_iter()
-			.flatten()
+			.filter(|(creation_time, _)| *creation_time == latest_creation_time)
+			.flat_map(|(_, addresses)| addresses)
 			// Ignore [`Multiaddr`]s without [`PeerId`] and own addresses.
 			.filter(|addr| {
 				addr.iter().any(|protocol| {
@@ -544,6 +573,8 @@ where
 			.collect();
 
 		if !remote_addresses.is_empty() {
+			self.latest_record_creation_times
+				.insert(authority_id.clone(), latest_creation_time);
 			self.addr_cache.insert(authority_id, remote_addresses);
 			if let Some(metrics) = &self.metrics {
 				metrics
@@ -554,6 +585,70 @@ where
 		Ok(())
 	}
 
+	/// Verify the signatures of a record of `authority_id`, returning its creation time and its
+	/// addresses.
+	///
+	/// Records published before creation times were introduced have a creation time of `0`.
+	fn verify_record(
+		&self,
+		authority_id: &AuthorityId,
+		record: &[u8],
+	) -> Result<(u128, Vec<Multiaddr>)> {
+		let schema::SignedAuthorityAddresses { signature, addresses, peer_signature } =
+			schema::SignedAuthorityAddresses::decode(record).map_err(Error::DecodingProto)?;
+
+		let auth_signature = AuthoritySignature::decode(&mut &signature[..])
+			.map_err(Error::EncodingDecodingScale)?;
+
+		if !AuthorityPair::verify(&auth_signature, &addresses, authority_id) {
+			return Err(Error::VerifyingDhtPayload)
+		}
+
+		let schema::AuthorityAddresses { addresses: raw_addresses, creation_time } =
+			schema::AuthorityAddresses::decode(addresses.as_slice())
+				.map_err(Error::DecodingProto)?;
+		let remote_addresses = raw_addresses
+			.into_iter()
+			.map(|a| a.try_into())
+			.collect::<std::result::Result<Vec<Multiaddr>, _>>()
+			.map_err(Error::ParsingMultiaddress)?;
+
+		match peer_signature {
+			Some(peer_signature) => {
+				let public_key =
+					libp2p::identity::PublicKey::from_protobuf_encoding(&peer_signature.public_key)
+						.map_err(Error::ParsingLibp2pIdentity)?;
+				let signature = Signature { public_key, bytes: peer_signature.signature };
+				let signer = signature.public_key.clone().into_peer_id();
+
+				// All the addresses must belong to the node which signed them.
+				if remote_addresses
+					.iter()
+					.filter_map(addr_cache::peer_id_from_multiaddr)
+					.any(|peer_id| peer_id != signer)
+				{
+					return Err(Error::ReceivingDhtValueWithDifferentPeerIds)
+				}
+				if !signature.verify(&addresses, &signer) {
+					return Err(Error::VerifyingDhtPayload)
+				}
+			},
+			None if self.strict_record_validation => return Err(Error::MissingPeerIdSignature),
+			None => debug!(
+				target: LOG_TARGET,
+				"Received a record of authority {:?} without a peer id signature.", authority_id,
+			),
+		}
+
+		let creation_time = creation_time
+			.map(|time| u128::decode(&mut &time.timestamp[..]))
+			.transpose()
+			.map_err(Error::EncodingDecodingScale)?
+			.unwrap_or_default();
+
+		Ok((creation_time, remote_addresses))
+	}
+
 	/// Retrieve our public keys within the current and next authority set.
 	// A node might have multiple authority discovery keys within its keystore, e.g. an old one and
 	// one for the upcoming session. In addition it could be participating in the current and (/ or)
@@ -598,6 +693,12 @@ pub trait NetworkProvider: NetworkStateInfo {
 
 	/// Start getting a value from the Dht.
 	fn get_value(&self, key: &libp2p::kad::record::Key);
+
+	/// Sign a message with the network identity of the local node.
+	fn sign_with_local_identity(
+		&self,
+		msg: &[u8],
+	) -> std::result::Result<Signature, libp2p::identity::error::SigningError>;
 }
 
 #[async_trait::async_trait]
@@ -612,6 +713,12 @@ where
 	fn get_value(&self, key: &libp2p::kad::record::Key) {
 		self.get_value(key)
 	}
+	fn sign_with_local_identity(
+		&self,
+		msg: &[u8],
+	) -> std::result::Result<Signature, libp2p::identity::error::SigningError> {
+		self.sign_with_local_identity(msg)
+	}
 }
 
 fn hash_authority_id(id: &[u8]) -> libp2p::kad::record::Key {
@@ -137,7 +137,7 @@ impl AddrCache {
 	}
 }
 
-fn peer_id_from_multiaddr(addr: &Multiaddr) -> Option<PeerId> {
+pub(super) fn peer_id_from_multiaddr(addr: &Multiaddr) -> Option<PeerId> {
 	addr.iter().last().and_then(|protocol| {
 		if let Protocol::P2p(multihash) = protocol {
 			PeerId::from_multihash(multihash).ok()
@@ -31,7 +31,7 @@ use futures::{
 	sink::SinkExt,
 	task::LocalSpawn,
 };
-use libp2p::{core::multiaddr, kad, PeerId};
+use libp2p::{core::multiaddr, identity::Keypair, kad, PeerId};
 use prometheus_endpoint::prometheus::default_registry;
 
 use sp_api::{ApiRef, ProvideRuntimeApi};
@@ -118,6 +118,7 @@ pub enum TestNetworkEvent {
 
 pub struct TestNetwork {
 	peer_id: PeerId,
+	identity: Keypair,
 	external_addresses: Vec<Multiaddr>,
 	// Whenever functions on `TestNetwork` are called, the function arguments are added to the
 	// vectors below.
@@ -136,8 +137,10 @@ impl TestNetwork {
 impl Default for TestNetwork {
 	fn default() -> Self {
 		let (tx, rx) = mpsc::unbounded();
+		let identity = Keypair::generate_ed25519();
 		TestNetwork {
-			peer_id: PeerId::random(),
+			peer_id: identity.public().into_peer_id(),
+			identity,
 			external_addresses: vec!["/ip6/2001:db8::/tcp/30333".parse().unwrap()],
 			put_value_call: Default::default(),
 			get_value_call: Default::default(),
@@ -163,6 +166,12 @@ impl NetworkProvider for TestNetwork {
 			.unbounded_send(TestNetworkEvent::GetCalled(key.clone()))
 			.unwrap();
 	}
+	fn sign_with_local_identity(
+		&self,
+		msg: &[u8],
+	) -> std::result::Result<Signature, libp2p::identity::error::SigningError> {
+		Signature::sign_message(msg, &self.identity)
+	}
 }
 
 impl NetworkStateInfo for TestNetwork {
@@ -179,12 +188,35 @@ async fn build_dht_event(
 	addresses: Vec<Multiaddr>,
 	public_key: AuthorityId,
 	key_store: &KeyStore,
+) -> (libp2p::kad::record::Key, Vec<u8>) {
+	build_signed_dht_event(addresses, public_key, key_store, None, None).await
+}
+
+/// Same as [`build_dht_event`], optionally signing the record with the network identity
+/// `peer_identity` and setting its `creation_time`.
+async fn build_signed_dht_event(
+	addresses: Vec<Multiaddr>,
+	public_key: AuthorityId,
+	key_store: &KeyStore,
+	peer_identity: Option<&Keypair>,
+	creation_time: Option<u128>,
 ) -> (libp2p::kad::record::Key, Vec<u8>) {
 	let mut serialized_addresses = vec![];
-	schema::AuthorityAddresses { addresses: addresses.into_iter().map(|a| a.to_vec()).collect() }
-		.encode(&mut serialized_addresses)
-		.map_err(Error::EncodingProto)
-		.unwrap();
+	schema::AuthorityAddresses {
+		addresses: addresses.into_iter().map(|a| a.to_vec()).collect(),
+		creation_time: creation_time.map(|time| schema::TimestampInfo { timestamp: time.encode() }),
+	}
+	.encode(&mut serialized_addresses)
+	.map_err(Error::EncodingProto)
+	.unwrap();
+
+	let peer_signature = peer_identity.map(|identity| {
+		let signature = Signature::sign_message(&serialized_addresses, identity).unwrap();
+		schema::PeerSignature {
+			signature: signature.bytes,
+			public_key: signature.public_key.into_protobuf_encoding(),
+		}
+	});
 
 	let signature = key_store
 		.sign_with(
@@ -197,9 +229,13 @@ async fn build_dht_event(
 		.unwrap();
 
 	let mut signed_addresses = vec![];
-	schema::SignedAuthorityAddresses { addresses: serialized_addresses.clone(), signature }
-		.encode(&mut signed_addresses)
-		.unwrap();
+	schema::SignedAuthorityAddresses {
+		addresses: serialized_addresses.clone(),
+		signature,
+		peer_signature,
+	}
+	.encode(&mut signed_addresses)
+	.unwrap();
 
 	let key = hash_authority_id(&public_key.to_raw_vec());
 	let value = signed_addresses;
@@ -567,6 +603,143 @@ fn do_not_cache_addresses_without_peer_id() {
 	);
 }
 
+/// Build a [`Worker`] looking up the addresses of `authority`, with the given configuration.
+fn discovering_worker(
+	authority: AuthorityId,
+	config: crate::WorkerConfig,
+) -> Worker<TestApi, TestNetwork, Block, std::pin::Pin<Box<mpsc::Receiver<DhtEvent>>>> {
+	let (_dht_event_tx, dht_event_rx) = channel(1);
+	let (_to_worker, from_service) = mpsc::channel(0);
+	let mut worker = Worker::new(
+		from_service,
+		Arc::new(TestApi { authorities: vec![authority] }),
+		Arc::new(TestNetwork::default()),
+		Box::pin(dht_event_rx),
+		Role::Discover,
+		None,
+		config,
+	);
+
+	block_on(worker.refill_pending_lookups_queue()).unwrap();
+	worker.start_new_lookups();
+	worker
+}
+
+fn address_of(peer_id: PeerId) -> Multiaddr {
+	let address: Multiaddr = "/ip6/2001:db8:0:0:0:0:0:1/tcp/30333".parse().unwrap();
+	address.with(multiaddr::Protocol::P2p(peer_id.into()))
+}
+
+#[test]
+fn strict_record_validation_requires_peer_signature() {
+	let remote_key_store = KeyStore::new();
+	let remote_public: AuthorityId =
+		block_on(remote_key_store.sr25519_generate_new(key_types::AUTHORITY_DISCOVERY, None))
+			.unwrap()
+			.into();
+	let remote_identity = Keypair::generate_ed25519();
+	let remote_address = address_of(remote_identity.public().into_peer_id());
+
+	let mut worker = discovering_worker(
+		remote_public.clone(),
+		crate::WorkerConfig { strict_record_validation: true, ..Default::default() },
+	);
+
+	let unsigned = block_on(build_dht_event(
+		vec![remote_address.clone()],
+		remote_public.clone(),
+		&remote_key_store,
+	));
+	assert!(matches!(
+		worker.handle_dht_value_found_event(vec![unsigned]),
+		Err(Error::MissingPeerIdSignature)
+	));
+
+	block_on(worker.refill_pending_lookups_queue()).unwrap();
+	worker.start_new_lookups();
+	let signed = block_on(build_signed_dht_event(
+		vec![remote_address.clone()],
+		remote_public.clone(),
+		&remote_key_store,
+		Some(&remote_identity),
+		None,
+	));
+	worker.handle_dht_value_found_event(vec![signed]).unwrap();
+	assert_eq!(
+		Some(&vec![remote_address]),
+		worker.addr_cache.get_addresses_by_authority_id(&remote_public),
+	);
+}
+
+#[test]
+fn reject_records_with_addresses_of_other_peers() {
+	let remote_key_store = KeyStore::new();
+	let remote_public: AuthorityId =
+		block_on(remote_key_store.sr25519_generate_new(key_types::AUTHORITY_DISCOVERY, None))
+			.unwrap()
+			.into();
+	let remote_identity = Keypair::generate_ed25519();
+
+	let mut worker = discovering_worker(remote_public.clone(), Default::default());
+
+	let dht_event = block_on(build_signed_dht_event(
+		vec![address_of(PeerId::random())],
+		remote_public.clone(),
+		&remote_key_store,
+		Some(&remote_identity),
+		None,
+	));
+	assert!(matches!(
+		worker.handle_dht_value_found_event(vec![dht_event]),
+		Err(Error::ReceivingDhtValueWithDifferentPeerIds)
+	));
+	assert!(worker.addr_cache.get_addresses_by_authority_id(&remote_public).is_none());
+}
+
+#[test]
+fn newer_records_replace_older_ones() {
+	let remote_key_store = KeyStore::new();
+	let remote_public: AuthorityId =
+		block_on(remote_key_store.sr25519_generate_new(key_types::AUTHORITY_DISCOVERY, None))
+			.unwrap()
+			.into();
+	let remote_identity = Keypair::generate_ed25519();
+	let old_address = address_of(remote_identity.public().into_peer_id());
+	let new_address = {
+		let address: Multiaddr = "/ip6/2001:db8:0:0:0:0:0:2/tcp/30333".parse().unwrap();
+		address.with(multiaddr::Protocol::P2p(remote_identity.public().into_peer_id().into()))
+	};
+	let record = |address: &Multiaddr, creation_time| {
+		block_on(build_signed_dht_event(
+			vec![address.clone()],
+			remote_public.clone(),
+			&remote_key_store,
+			Some(&remote_identity),
+			Some(creation_time),
+		))
+	};
+
+	let mut worker = discovering_worker(remote_public.clone(), Default::default());
+
+	// Among the records of a single lookup, only the newest one is kept.
+	worker
+		.handle_dht_value_found_event(vec![record(&old_address, 1), record(&new_address, 2)])
+		.unwrap();
+	assert_eq!(
+		Some(&vec![new_address.clone()]),
+		worker.addr_cache.get_addresses_by_authority_id(&remote_public),
+	);
+
+	// An older record found by a later lookup doesn't replace the cached one.
+	block_on(worker.refill_pending_lookups_queue()).unwrap();
+	worker.start_new_lookups();
+	worker.handle_dht_value_found_event(vec![record(&old_address, 1)]).unwrap();
+	assert_eq!(
+		Some(&vec![new_address]),
+		worker.addr_cache.get_addresses_by_authority_id(&remote_public),
+	);
+}
+
 #[test]
 fn addresses_to_publish_adds_p2p() {
 	let (_dht_event_tx, dht_event_rx) = channel(1000);
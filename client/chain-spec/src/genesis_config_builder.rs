@@ -0,0 +1,140 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! A helper module for calling the [`GenesisBuilder`] runtime API of a Wasm blob.
+//!
+//! [`GenesisBuilder`]: sp_genesis_builder::GenesisBuilder
+
+use crate::json_patch;
+use codec::{Decode, Encode};
+use sc_executor::{sp_wasm_interface::HostFunctions, WasmExecutionMethod, WasmExecutor};
+use serde_json::Value;
+use sp_core::{
+	storage::Storage,
+	traits::{CodeExecutor, Externalities, RuntimeCode, WrappedRuntimeCode},
+	NeverNativeValue,
+};
+use sp_genesis_builder::{PresetId, Result as BuildResult};
+use sp_state_machine::BasicExternalities;
+use std::borrow::Cow;
+
+/// A utility that facilitates calling the [`GenesisBuilder`] API of the runtime Wasm blob,
+/// without any native runtime linked in.
+///
+/// [`GenesisBuilder`]: sp_genesis_builder::GenesisBuilder
+pub struct GenesisConfigBuilderRuntimeCaller<'a> {
+	code: Cow<'a, [u8]>,
+	code_hash: Vec<u8>,
+	executor: WasmExecutor,
+}
+
+impl<'a> GenesisConfigBuilderRuntimeCaller<'a> {
+	/// Creates new instance using the provided Wasm blob.
+	pub fn new(code: &'a [u8]) -> Self {
+		GenesisConfigBuilderRuntimeCaller {
+			code: code.into(),
+			code_hash: sp_core::blake2_256(code).to_vec(),
+			executor: WasmExecutor::new(
+				WasmExecutionMethod::Interpreted,
+				None,
+				sp_io::SubstrateHostFunctions::host_functions(),
+				1,
+				None,
+				1,
+			),
+		}
+	}
+
+	fn call(
+		&self,
+		ext: &mut dyn Externalities,
+		method: &str,
+		data: &[u8],
+	) -> Result<Vec<u8>, String> {
+		let code_fetcher = WrappedRuntimeCode(self.code.as_ref().into());
+		let runtime_code = RuntimeCode {
+			code_fetcher: &code_fetcher,
+			heap_pages: None,
+			hash: self.code_hash.clone(),
+		};
+		self.executor
+			.call::<NeverNativeValue, fn() -> _>(ext, &runtime_code, method, data, false, None)
+			.0
+			.map(|r| r.into_encoded())
+			.map_err(|e| format!("Failed to call `{}`: {}", method, e))
+	}
+
+	/// Returns the identifiers of the presets provided by the runtime.
+	pub fn preset_names(&self) -> Result<Vec<String>, String> {
+		let mut ext = BasicExternalities::new_empty();
+		let call_result = self.call(&mut ext, "GenesisBuilder_preset_names", &[])?;
+		let names = Vec::<PresetId>::decode(&mut &call_result[..]).map_err(|e| e.to_string())?;
+		Ok(names.into_iter().map(String::from).collect())
+	}
+
+	/// Returns the JSON of the preset named `id`, or of the default genesis config if `id` is
+	/// `None`.
+	///
+	/// Returns `None` if the runtime does not know the preset.
+	pub fn get_preset(&self, id: Option<&str>) -> Result<Option<Value>, String> {
+		let mut ext = BasicExternalities::new_empty();
+		// `&str` has the same encoding as `PresetId`.
+		let call_result = self.call(&mut ext, "GenesisBuilder_get_preset", &id.encode())?;
+		Option::<Vec<u8>>::decode(&mut &call_result[..])
+			.map_err(|e| e.to_string())?
+			.map(|json| serde_json::from_slice(&json).map_err(|e| e.to_string()))
+			.transpose()
+	}
+
+	/// Returns the JSON of the default genesis config.
+	pub fn get_default_config(&self) -> Result<Value, String> {
+		self.get_preset(None)?
+			.ok_or_else(|| "The runtime did not return the default genesis config".into())
+	}
+
+	/// Builds the genesis storage for the given complete genesis config.
+	pub fn get_storage_for_config(&self, config: Value) -> Result<Storage, String> {
+		let mut ext = BasicExternalities::new_empty();
+		let call_result = self.call(
+			&mut ext,
+			"GenesisBuilder_build_state",
+			&config.to_string().into_bytes().encode(),
+		)?;
+		BuildResult::decode(&mut &call_result[..])
+			.map_err(|e| e.to_string())?
+			.map_err(|e| format!("Failed to build the genesis state: {}", e))?;
+		Ok(ext.into_storages())
+	}
+
+	/// Builds the genesis storage for the default genesis config, patched with `patch`.
+	///
+	/// `patch` is merged over the default config using [`json_patch::merge`].
+	pub fn get_storage_for_patch(&self, patch: Value) -> Result<Storage, String> {
+		let mut config = self.get_default_config()?;
+		json_patch::merge(&mut config, patch);
+		self.get_storage_for_config(config)
+	}
+
+	/// Builds the genesis storage for the preset named `id`.
+	pub fn get_storage_for_named_preset(&self, id: &str) -> Result<Storage, String> {
+		let patch = self
+			.get_preset(Some(id))?
+			.ok_or_else(|| format!("The preset `{}` is not known by the runtime", id))?;
+		self.get_storage_for_patch(patch)
+	}
+}
@@ -0,0 +1,65 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! A helper module providing the merge of JSON patches into genesis configs.
+
+use serde_json::Value;
+
+/// Recursively merges the `b` JSON patch into `a`.
+///
+/// Objects are merged key by key. Any other value in `b`, including arrays, replaces the value
+/// in `a`. This is used to apply a genesis config preset, returned by the runtime as a patch,
+/// over the runtime's default genesis config.
+pub fn merge(a: &mut Value, b: Value) {
+	match (a, b) {
+		(Value::Object(a), Value::Object(b)) =>
+			for (k, v) in b {
+				merge(a.entry(k).or_insert(Value::Null), v);
+			},
+		(a, b) => *a = b,
+	};
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn merge_objects_works() {
+		let mut j1 = json!({ "a": 123, "b": { "c": 1, "d": [1, 2] } });
+		let j2 = json!({ "b": { "c": 2, "e": true }, "f": "g" });
+		merge(&mut j1, j2);
+		assert_eq!(j1, json!({ "a": 123, "b": { "c": 2, "d": [1, 2], "e": true }, "f": "g" }));
+	}
+
+	#[test]
+	fn merge_replaces_arrays_and_scalars() {
+		let mut j1 = json!({ "a": [1, 2, 3], "b": 1 });
+		let j2 = json!({ "a": [4], "b": { "c": 1 } });
+		merge(&mut j1, j2);
+		assert_eq!(j1, json!({ "a": [4], "b": { "c": 1 } }));
+	}
+
+	#[test]
+	fn merge_into_non_object_replaces_it() {
+		let mut j1 = json!(1);
+		merge(&mut j1, json!({ "a": 1 }));
+		assert_eq!(j1, json!({ "a": 1 }));
+	}
+}
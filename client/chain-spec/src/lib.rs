@@ -109,11 +109,14 @@
 
 mod chain_spec;
 mod extension;
+mod genesis_config_builder;
+pub mod json_patch;
 
 pub use chain_spec::{ChainSpec as GenericChainSpec, NoExtension};
 pub use extension::{
 	get_extension, get_extension_mut, Extension, Fork, Forks, GetExtension, Group,
 };
+pub use genesis_config_builder::GenesisConfigBuilderRuntimeCaller;
 pub use sc_chain_spec_derive::{ChainSpecExtension, ChainSpecGroup};
 
 use sc_network::config::MultiaddrWithPeerId;
@@ -102,6 +102,24 @@ impl Into<sc_tracing::TracingReceiver> for TracingReceiver {
 	}
 }
 
+arg_enum! {
+	#[allow(missing_docs)]
+	#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+	pub enum LogFormat {
+		Text,
+		Json,
+	}
+}
+
+impl Into<sc_tracing::logging::LogFormat> for LogFormat {
+	fn into(self) -> sc_tracing::logging::LogFormat {
+		match self {
+			LogFormat::Text => sc_tracing::logging::LogFormat::Text,
+			LogFormat::Json => sc_tracing::logging::LogFormat::Json,
+		}
+	}
+}
+
 arg_enum! {
 	#[allow(missing_docs)]
 	#[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -208,7 +226,9 @@ impl std::str::FromStr for Database {
 	fn from_str(s: &str) -> Result<Self, String> {
 		if s.eq_ignore_ascii_case("rocksdb") {
 			Ok(Self::RocksDb)
-		} else if s.eq_ignore_ascii_case("paritydb-experimental") {
+		} else if s.eq_ignore_ascii_case("paritydb") ||
+			s.eq_ignore_ascii_case("paritydb-experimental")
+		{
 			Ok(Self::ParityDb)
 		} else if s.eq_ignore_ascii_case("auto") {
 			Ok(Self::Auto)
@@ -221,7 +241,7 @@ impl std::str::FromStr for Database {
 impl Database {
 	/// Returns all the variants of this enum to be shown in the cli.
 	pub fn variants() -> &'static [&'static str] {
-		&["rocksdb", "paritydb-experimental", "auto"]
+		&["rocksdb", "paritydb", "paritydb-experimental", "auto"]
 	}
 }
 
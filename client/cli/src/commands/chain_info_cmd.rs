@@ -0,0 +1,124 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+
+use crate::{
+	error,
+	params::{DatabaseParams, PruningParams, SharedParams},
+	CliConfiguration,
+};
+use sc_client_api::Backend as _;
+use sc_service::{Configuration, DatabaseSettings, DatabaseSource};
+use serde_json::json;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+use std::{fs, io, path::Path};
+use structopt::StructOpt;
+
+/// The `chain-info` subcommand used to print information about the local database.
+///
+/// The database is opened read-only, without starting any of the node services.
+#[derive(Debug, StructOpt, Clone)]
+pub struct ChainInfoCmd {
+	#[allow(missing_docs)]
+	#[structopt(flatten)]
+	pub pruning_params: PruningParams,
+
+	#[allow(missing_docs)]
+	#[structopt(flatten)]
+	pub shared_params: SharedParams,
+
+	#[allow(missing_docs)]
+	#[structopt(flatten)]
+	pub database_params: DatabaseParams,
+}
+
+impl ChainInfoCmd {
+	/// Run the `chain-info` command.
+	pub fn run<B>(&self, config: &Configuration) -> error::Result<()>
+	where
+		B: BlockT,
+	{
+		let db_config = DatabaseSettings {
+			state_cache_size: config.state_cache_size,
+			state_cache_child_ratio: config.state_cache_child_ratio.map(|v| (v, 100)),
+			state_pruning: config.state_pruning.clone(),
+			override_state_pruning: config.override_state_pruning,
+			source: config.database.clone(),
+			keep_blocks: config.keep_blocks.clone(),
+			transaction_storage: config.transaction_storage.clone(),
+		};
+		let (backend, column_stats) = sc_service::new_read_only_db_backend::<B>(db_config)?;
+		let info = backend.blockchain().info();
+
+		let (db_backend, db_path) = match &config.database {
+			DatabaseSource::Auto { .. } => ("auto", config.database.path()),
+			DatabaseSource::RocksDb { path, .. } => ("rocksdb", Some(path.as_path())),
+			DatabaseSource::ParityDb { path } => ("paritydb", Some(path.as_path())),
+			DatabaseSource::Custom(_) => ("custom", None),
+		};
+		let db_size = db_path.map(dir_size).transpose()?;
+
+		let json = json!({
+			"best_hash": info.best_hash,
+			"best_number": info.best_number,
+			"finalized_hash": info.finalized_hash,
+			"finalized_number": info.finalized_number,
+			"genesis_hash": info.genesis_hash,
+			"number_leaves": info.number_leaves,
+			"database": {
+				"backend": db_backend,
+				"path": db_path,
+				"size": db_size,
+				"columns": column_stats.iter().map(|stats| json!({
+					"column": stats.column,
+					"keys": stats.keys,
+					"size": stats.size,
+				})).collect::<Vec<_>>(),
+			},
+			"state_pruning": format!("{:?}", config.state_pruning),
+			"keep_blocks": format!("{:?}", config.keep_blocks),
+		});
+		println!("{}", serde_json::to_string_pretty(&json).expect("Json pretty print failed"));
+
+		Ok(())
+	}
+}
+
+/// Total size in bytes of all the files under `path`.
+fn dir_size(path: &Path) -> io::Result<u64> {
+	let mut size = 0;
+	for entry in fs::read_dir(path)? {
+		let entry = entry?;
+		let metadata = entry.metadata()?;
+		size += if metadata.is_dir() { dir_size(&entry.path())? } else { metadata.len() };
+	}
+	Ok(size)
+}
+
+impl CliConfiguration for ChainInfoCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+
+	fn pruning_params(&self) -> Option<&PruningParams> {
+		Some(&self.pruning_params)
+	}
+
+	fn database_params(&self) -> Option<&DatabaseParams> {
+		Some(&self.database_params)
+	}
+}
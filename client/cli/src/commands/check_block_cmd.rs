@@ -75,4 +75,8 @@ impl CliConfiguration for CheckBlockCmd {
 	fn import_params(&self) -> Option<&ImportParams> {
 		Some(&self.import_params)
 	}
+
+	fn default_heap_pages(&self) -> error::Result<Option<u64>> {
+		Ok(self.default_heap_pages.map(Into::into))
+	}
 }
@@ -95,4 +95,8 @@ impl CliConfiguration for ImportBlocksCmd {
 	fn import_params(&self) -> Option<&ImportParams> {
 		Some(&self.import_params)
 	}
+
+	fn default_heap_pages(&self) -> error::Result<Option<u64>> {
+		Ok(self.default_heap_pages.map(Into::into))
+	}
 }
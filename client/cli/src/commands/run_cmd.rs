@@ -27,11 +27,14 @@ use crate::{
 };
 use regex::Regex;
 use sc_service::{
-	config::{BasePath, PrometheusConfig, TransactionPoolOptions},
+	config::{BasePath, PrometheusConfig, RpcLimits, TransactionPoolOptions},
 	ChainSpec, Role,
 };
 use sc_telemetry::TelemetryEndpoints;
-use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::{
+	net::{IpAddr, Ipv4Addr, SocketAddr},
+	num::NonZeroU32,
+};
 use structopt::StructOpt;
 
 /// The `run` command used to run a node.
@@ -105,6 +108,46 @@ pub struct RunCmd {
 	#[structopt(long = "rpc-max-payload")]
 	pub rpc_max_payload: Option<usize>,
 
+	/// Set the maximum size of an RPC request (both http and ws), in megabytes.
+	///
+	/// Default is the maximum RPC payload size.
+	#[structopt(long = "rpc-max-request-size", value_name = "MB")]
+	pub rpc_max_request_size: Option<usize>,
+
+	/// Set the maximum number of RPC calls per second of a single connection (both http and ws).
+	///
+	/// Calls over HTTP are limited per client if `--rpc-trust-proxy-headers` is passed, and share
+	/// a single limit otherwise. Default is unlimited.
+	#[structopt(long = "rpc-rate-limit", value_name = "CALLS")]
+	pub rpc_rate_limit: Option<NonZeroU32>,
+
+	/// Identify the clients of the HTTP RPC server by the address reported by a reverse proxy.
+	///
+	/// The address is read from the `X-Real-IP` or `X-Forwarded-For` headers, which clients can
+	/// set themselves: only pass this if the server is reachable through the proxy alone.
+	#[structopt(long = "rpc-trust-proxy-headers")]
+	pub rpc_trust_proxy_headers: bool,
+
+	/// Set the maximum number of calls in an RPC batch request. Default is unlimited.
+	#[structopt(long = "rpc-max-batch-request-len", value_name = "LEN")]
+	pub rpc_max_batch_request_len: Option<usize>,
+
+	/// Set the maximum size of the response to a single RPC call, in megabytes.
+	///
+	/// Larger responses are replaced by an error. Default is unlimited.
+	#[structopt(long = "rpc-max-response-size", value_name = "MB")]
+	pub rpc_max_response_size: Option<usize>,
+
+	/// Deny calls to the given RPC method. Can be passed multiple times.
+	#[structopt(long = "rpc-deny-method", value_name = "METHOD")]
+	pub rpc_deny_methods: Vec<String>,
+
+	/// Set the maximum number of subscriptions a single WS connection may have open.
+	///
+	/// Default is unlimited.
+	#[structopt(long = "rpc-max-subscriptions-per-connection", value_name = "COUNT")]
+	pub rpc_max_subscriptions_per_connection: Option<usize>,
+
 	/// Expose Prometheus exporter on all interfaces.
 	///
 	/// Default is local.
@@ -124,7 +167,13 @@ pub struct RunCmd {
 	pub ws_port: Option<u16>,
 
 	/// Maximum number of WS RPC server connections.
-	#[structopt(long = "ws-max-connections", value_name = "COUNT")]
+	///
+	/// HTTP calls don't keep a connection open, so they are not counted.
+	#[structopt(
+		long = "ws-max-connections",
+		visible_alias = "rpc-max-connections",
+		value_name = "COUNT"
+	)]
 	pub ws_max_connections: Option<usize>,
 
 	/// Specify browser Origins allowed to access the HTTP & WS RPC servers.
@@ -234,6 +283,16 @@ pub struct RunCmd {
 	#[structopt(long)]
 	pub max_runtime_instances: Option<usize>,
 
+	/// Maximum number of different runtimes that can be cached.
+	#[structopt(long, default_value = "2")]
+	pub runtime_cache_size: u8,
+
+	/// The default number of 64KB pages to ever allocate for Wasm execution.
+	///
+	/// Don't alter this unless you know what you're doing.
+	#[structopt(long = "default-heap-pages", value_name = "COUNT")]
+	pub default_heap_pages: Option<u64>,
+
 	/// Run a temporary node.
 	///
 	/// A temporary directory will be created to store the configuration and will be deleted
@@ -432,6 +491,21 @@ impl CliConfiguration for RunCmd {
 		Ok(self.rpc_max_payload)
 	}
 
+	fn rpc_max_request_size(&self) -> Result<Option<usize>> {
+		Ok(self.rpc_max_request_size)
+	}
+
+	fn rpc_limits(&self) -> Result<RpcLimits> {
+		Ok(RpcLimits {
+			max_calls_per_second: self.rpc_rate_limit,
+			max_batch_len: self.rpc_max_batch_request_len,
+			max_response_size: self.rpc_max_response_size.map(|mb| mb.saturating_mul(1024 * 1024)),
+			max_subscriptions_per_connection: self.rpc_max_subscriptions_per_connection,
+			denied_methods: self.rpc_deny_methods.iter().cloned().collect(),
+			trust_proxy_headers: self.rpc_trust_proxy_headers,
+		})
+	}
+
 	fn transaction_pool(&self) -> Result<TransactionPoolOptions> {
 		Ok(self.pool_config.transaction_pool())
 	}
@@ -440,6 +514,14 @@ impl CliConfiguration for RunCmd {
 		Ok(self.max_runtime_instances.map(|x| x.min(256)))
 	}
 
+	fn runtime_cache_size(&self) -> Result<u8> {
+		Ok(self.runtime_cache_size)
+	}
+
+	fn default_heap_pages(&self) -> Result<Option<u64>> {
+		Ok(self.default_heap_pages)
+	}
+
 	fn base_path(&self) -> Result<Option<BasePath>> {
 		Ok(if self.tmp {
 			Some(BasePath::new_temp_dir()?)
@@ -24,6 +24,13 @@ use crate::{
 use rand::{rngs::OsRng, RngCore};
 use sp_core::crypto::{Ss58AddressFormat, Ss58Codec};
 use sp_runtime::traits::IdentifyAccount;
+use std::{
+	sync::{
+		atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering},
+		mpsc, Arc,
+	},
+	thread,
+};
 use structopt::StructOpt;
 use utils::print_from_uri;
 
@@ -35,6 +42,10 @@ pub struct VanityCmd {
 	#[structopt(long, parse(try_from_str = assert_non_empty_string))]
 	pattern: String,
 
+	/// Number of threads searching in parallel. Defaults to the number of CPUs.
+	#[structopt(long)]
+	threads: Option<usize>,
+
 	#[allow(missing_docs)]
 	#[structopt(flatten)]
 	network_scheme: NetworkSchemeFlag,
@@ -53,7 +64,11 @@ impl VanityCmd {
 	pub fn run(&self) -> error::Result<()> {
 		let formated_seed = with_crypto_scheme!(
 			self.crypto_scheme.scheme,
-			generate_key(&self.pattern, self.network_scheme.network.clone().unwrap_or_default()),
+			generate_key(
+				&self.pattern,
+				self.network_scheme.network.clone().unwrap_or_default(),
+				self.threads.unwrap_or_else(num_cpus::get),
+			),
 		)?;
 
 		with_crypto_scheme!(
@@ -69,25 +84,66 @@ impl VanityCmd {
 	}
 }
 
-/// genertae a key based on given pattern
+/// Progress of a key search, shared between the searching threads.
+#[derive(Default)]
+struct Progress {
+	/// Number of keys searched so far.
+	done: AtomicU64,
+	/// Best score reached so far.
+	best: AtomicUsize,
+	/// Whether a matching key was found.
+	found: AtomicBool,
+}
+
+/// Generate a key based on the given pattern, searching on `threads` threads in parallel.
 fn generate_key<Pair>(
 	desired: &str,
 	network_override: Ss58AddressFormat,
+	threads: usize,
 ) -> Result<String, &'static str>
 where
-	Pair: sp_core::Pair,
+	Pair: sp_core::Pair + 'static,
 	Pair::Public: IdentifyAccount,
 	<Pair::Public as IdentifyAccount>::AccountId: Ss58Codec,
 {
 	println!("Generating key containing pattern '{}'", desired);
 
 	let top = 45 + (desired.len() * 48);
-	let mut best = 0;
+	let progress = Arc::new(Progress::default());
+	let (tx, rx) = mpsc::channel();
+
+	for _ in 0..threads.max(1) {
+		let desired = desired.to_string();
+		let progress = progress.clone();
+		let tx = tx.clone();
+		thread::spawn(move || {
+			if let Some(seed) = search_key::<Pair>(&desired, network_override, top, &progress) {
+				let _ = tx.send(utils::format_seed::<Pair>(seed));
+			}
+		});
+	}
+	drop(tx);
+
+	rx.recv().map_err(|_| "Key search stopped without result")
+}
+
+/// Search for a key scoring `top` on the current thread, until it or another thread found one.
+fn search_key<Pair>(
+	desired: &str,
+	network_override: Ss58AddressFormat,
+	top: usize,
+	progress: &Progress,
+) -> Option<Pair::Seed>
+where
+	Pair: sp_core::Pair,
+	Pair::Public: IdentifyAccount,
+	<Pair::Public as IdentifyAccount>::AccountId: Ss58Codec,
+{
 	let mut seed = Pair::Seed::default();
-	let mut done = 0;
+	let mut searched: u64 = 0;
 
-	loop {
-		if done % 100000 == 0 {
+	while !progress.found.load(Ordering::Relaxed) {
+		if searched % 100000 == 0 {
 			OsRng.fill_bytes(seed.as_mut());
 		} else {
 			next_seed(seed.as_mut());
@@ -95,20 +151,21 @@ where
 
 		let p = Pair::from_seed(&seed);
 		let ss58 = p.public().into_account().to_ss58check_with_version(network_override);
-		let score = calculate_score(&desired, &ss58);
-		if score > best || desired.len() < 2 {
-			best = score;
-			if best >= top {
-				println!("best: {} == top: {}", best, top);
-				return Ok(utils::format_seed::<Pair>(seed.clone()))
-			}
+		let score = calculate_score(desired, &ss58);
+		let best = progress.best.fetch_max(score, Ordering::Relaxed).max(score);
+		if score >= top && !progress.found.swap(true, Ordering::Relaxed) {
+			println!("best: {} == top: {}", best, top);
+			return Some(seed)
 		}
-		done += 1;
+		searched += 1;
 
+		let done = progress.done.fetch_add(1, Ordering::Relaxed) + 1;
 		if done % good_waypoint(done) == 0 {
 			println!("{} keys searched; best is {}/{} complete", done, best, top);
 		}
 	}
+
+	None
 }
 
 fn good_waypoint(done: u64) -> u64 {
@@ -172,7 +229,7 @@ mod tests {
 
 	#[test]
 	fn test_generation_with_single_char() {
-		let seed = generate_key::<sr25519::Pair>("ab", Default::default()).unwrap();
+		let seed = generate_key::<sr25519::Pair>("ab", Default::default(), 1).unwrap();
 		assert!(sr25519::Pair::from_seed_slice(&hex::decode(&seed[2..]).unwrap())
 			.unwrap()
 			.public()
@@ -182,7 +239,8 @@ mod tests {
 
 	#[test]
 	fn generate_key_respects_network_override() {
-		let seed = generate_key::<sr25519::Pair>("ab", Ss58AddressFormat::PolkadotAccount).unwrap();
+		let seed =
+			generate_key::<sr25519::Pair>("ab", Ss58AddressFormat::PolkadotAccount, 1).unwrap();
 		assert!(sr25519::Pair::from_seed_slice(&hex::decode(&seed[2..]).unwrap())
 			.unwrap()
 			.public()
@@ -190,6 +248,22 @@ mod tests {
 			.contains("ab"));
 	}
 
+	#[test]
+	fn test_generation_with_multiple_threads() {
+		let seed = generate_key::<sr25519::Pair>("ab", Default::default(), 4).unwrap();
+		assert!(sr25519::Pair::from_seed_slice(&hex::decode(&seed[2..]).unwrap())
+			.unwrap()
+			.public()
+			.to_ss58check()
+			.contains("ab"));
+	}
+
+	#[test]
+	fn vanity_with_threads() {
+		let vanity = VanityCmd::from_iter(&["vanity", "--pattern", "j", "--threads", "2"]);
+		assert!(vanity.run().is_ok());
+	}
+
 	#[test]
 	fn test_score_1_char_100() {
 		let score = calculate_score("j", "5jolkadotwHY5k9GpdTgpqs9xjuNvtv8EcwCFpEeyEf3KHim");
@@ -19,8 +19,10 @@
 //! Configuration trait for a CLI based on substrate
 
 use crate::{
-	arg_enums::Database, error::Result, DatabaseParams, ImportParams, KeystoreParams,
-	NetworkParams, NodeKeyParams, OffchainWorkerParams, PruningParams, SharedParams, SubstrateCli,
+	arg_enums::Database,
+	error::{Error, Result},
+	DatabaseParams, ImportParams, KeystoreParams, NetworkParams, NodeKeyParams,
+	OffchainWorkerParams, PruningParams, SharedParams, SubstrateCli,
 };
 use log::warn;
 use names::{Generator, Name};
@@ -28,13 +30,14 @@ use sc_client_api::execution_extensions::ExecutionStrategies;
 use sc_service::{
 	config::{
 		BasePath, Configuration, DatabaseSource, KeystoreConfig, NetworkConfiguration,
-		NodeKeyConfig, OffchainWorkerConfig, PrometheusConfig, PruningMode, Role, RpcMethods,
-		TelemetryEndpoints, TransactionPoolOptions, WasmExecutionMethod,
+		NodeKeyConfig, OffchainWorkerConfig, PrometheusConfig, PruningMode, Role, RpcLimits,
+		RpcMethods, TelemetryEndpoints, TransactionPoolOptions, WasmExecutionMethod,
 	},
 	ChainSpec, KeepBlocks, TracingReceiver, TransactionStorageMode,
 };
-use sc_tracing::logging::LoggerBuilder;
-use std::{net::SocketAddr, path::PathBuf};
+use sc_tracing::logging::{LogFormat, LoggerBuilder};
+use sp_core::crypto::Ss58AddressFormat;
+use std::{convert::TryFrom, net::SocketAddr, path::PathBuf};
 
 /// The maximum number of characters for a node name.
 pub(crate) const NODE_NAME_MAX_LENGTH: usize = 64;
@@ -227,9 +230,35 @@ pub trait CliConfiguration<DCV: DefaultConfigurationValues = ()>: Sized {
 		};
 		let rocksdb_path = base_path.join("db").join(role_dir);
 		let paritydb_path = base_path.join("paritydb").join(role_dir);
+		// Files that identify an existing database of the respective backend.
+		let rocksdb_exists = rocksdb_path.join("db_version").exists();
+		let paritydb_exists = paritydb_path.join("metadata").exists();
 		Ok(match database {
-			Database::RocksDb => DatabaseSource::RocksDb { path: rocksdb_path, cache_size },
-			Database::ParityDb => DatabaseSource::ParityDb { path: rocksdb_path },
+			Database::RocksDb => {
+				if paritydb_exists && !rocksdb_exists {
+					return Err(Error::Input(format!(
+						"Found an existing ParityDb database at {:?}. Use `--database paritydb` (or \
+						 `auto`) to keep using it, or remove it to switch to RocksDb.",
+						paritydb_path,
+					)))
+				}
+				DatabaseSource::RocksDb { path: rocksdb_path, cache_size }
+			},
+			Database::ParityDb => {
+				// ParityDb used to be stored at the RocksDb location, keep using it if it is there.
+				if rocksdb_path.join("metadata").exists() && !paritydb_exists {
+					warn!("Using the ParityDb database at the legacy location {:?}.", rocksdb_path);
+					DatabaseSource::ParityDb { path: rocksdb_path }
+				} else if rocksdb_exists && !paritydb_exists {
+					return Err(Error::Input(format!(
+						"Found an existing RocksDb database at {:?}. Use `--database rocksdb` (or \
+						 `auto`) to keep using it, or remove it to switch to ParityDb.",
+						rocksdb_path,
+					)))
+				} else {
+					DatabaseSource::ParityDb { path: paritydb_path }
+				}
+			},
 			Database::Auto => DatabaseSource::Auto { paritydb_path, rocksdb_path, cache_size },
 		})
 	}
@@ -258,6 +287,14 @@ pub trait CliConfiguration<DCV: DefaultConfigurationValues = ()>: Sized {
 			.unwrap_or_else(|| Ok(Default::default()))
 	}
 
+	/// Whether the state pruning mode overrides the one the database was created with.
+	///
+	/// By default this is retrieved from `PruningParams` if it is available. Otherwise its
+	/// `false`.
+	fn override_state_pruning(&self) -> Result<bool> {
+		Ok(self.pruning_params().map(|x| x.override_state_pruning).unwrap_or_default())
+	}
+
 	/// Get the block pruning mode.
 	///
 	/// By default this is retrieved from `block_pruning` if it is available. Otherwise its
@@ -360,6 +397,20 @@ pub trait CliConfiguration<DCV: DefaultConfigurationValues = ()>: Sized {
 		Ok(None)
 	}
 
+	/// Get maximum RPC request size, in megabytes.
+	///
+	/// By default this is `None`, so the maximum RPC payload applies.
+	fn rpc_max_request_size(&self) -> Result<Option<usize>> {
+		Ok(None)
+	}
+
+	/// Get the limits enforced on RPC calls.
+	///
+	/// By default no limit is enforced.
+	fn rpc_limits(&self) -> Result<RpcLimits> {
+		Ok(Default::default())
+	}
+
 	/// Get the prometheus configuration (`None` if disabled)
 	///
 	/// By default this is `None`.
@@ -447,6 +498,13 @@ pub trait CliConfiguration<DCV: DefaultConfigurationValues = ()>: Sized {
 		Ok(Default::default())
 	}
 
+	/// Get maximum different runtimes in cache
+	///
+	/// By default this is `2`.
+	fn runtime_cache_size(&self) -> Result<u8> {
+		Ok(2)
+	}
+
 	/// Activate or not the automatic announcing of blocks after import
 	///
 	/// By default this is `false`.
@@ -463,6 +521,7 @@ pub trait CliConfiguration<DCV: DefaultConfigurationValues = ()>: Sized {
 		let is_dev = self.is_dev()?;
 		let chain_id = self.chain_id(is_dev)?;
 		let chain_spec = cli.load_spec(&chain_id)?;
+		set_default_ss58_version(&chain_spec);
 		let base_path = self
 			.base_path()?
 			.unwrap_or_else(|| BasePath::from_project("", "", &C::executable_name()));
@@ -474,6 +533,7 @@ pub trait CliConfiguration<DCV: DefaultConfigurationValues = ()>: Sized {
 		let node_key = self.node_key(&net_config_dir)?;
 		let role = self.role(is_dev)?;
 		let max_runtime_instances = self.max_runtime_instances()?.unwrap_or(8);
+		let runtime_cache_size = self.runtime_cache_size()?;
 		let is_validator = role.is_authority();
 		let (keystore_remote, keystore) = self.keystore_config(&config_dir)?;
 		let telemetry_endpoints = self.telemetry_endpoints(&chain_spec)?;
@@ -501,6 +561,7 @@ pub trait CliConfiguration<DCV: DefaultConfigurationValues = ()>: Sized {
 			state_cache_size: self.state_cache_size()?,
 			state_cache_child_ratio: self.state_cache_child_ratio()?,
 			state_pruning: self.state_pruning(unsafe_pruning, &role)?,
+			override_state_pruning: self.override_state_pruning()?,
 			keep_blocks: self.keep_blocks()?,
 			transaction_storage: self.database_transaction_storage()?,
 			wasm_method: self.wasm_method()?,
@@ -513,6 +574,8 @@ pub trait CliConfiguration<DCV: DefaultConfigurationValues = ()>: Sized {
 			rpc_ws_max_connections: self.rpc_ws_max_connections()?,
 			rpc_cors: self.rpc_cors(is_dev)?,
 			rpc_max_payload: self.rpc_max_payload()?,
+			rpc_max_request_size: self.rpc_max_request_size()?,
+			rpc_limits: self.rpc_limits()?,
 			prometheus_config: self.prometheus_config(DCV::prometheus_listen_port())?,
 			telemetry_endpoints,
 			default_heap_pages: self.default_heap_pages()?,
@@ -525,6 +588,7 @@ pub trait CliConfiguration<DCV: DefaultConfigurationValues = ()>: Sized {
 			disable_log_reloading: self.is_log_filter_reloading_disabled()?,
 			chain_spec,
 			max_runtime_instances,
+			runtime_cache_size,
 			announce_block: self.announce_block()?,
 			role,
 			base_path: Some(base_path),
@@ -552,6 +616,13 @@ pub trait CliConfiguration<DCV: DefaultConfigurationValues = ()>: Sized {
 		Ok(self.shared_params().disable_log_color())
 	}
 
+	/// Get the format in which log records are written.
+	///
+	/// By default this is retrieved from `SharedParams`.
+	fn log_format(&self) -> Result<LogFormat> {
+		Ok(self.shared_params().log_format())
+	}
+
 	/// Initialize substrate. This must be done only once per process.
 	///
 	/// This method:
@@ -574,6 +645,8 @@ pub trait CliConfiguration<DCV: DefaultConfigurationValues = ()>: Sized {
 			logger.with_colors(false);
 		}
 
+		logger.with_log_format(self.log_format()?);
+
 		logger.init()?;
 
 		if let Some(new_limit) = fdlimit::raise_fd_limit() {
@@ -603,3 +676,19 @@ pub fn generate_node_name() -> String {
 		}
 	}
 }
+
+/// Set the default SS58 address format from the `ss58Format` property of the chain spec, if any.
+///
+/// Addresses printed while running the node then use the chain's own prefix.
+fn set_default_ss58_version(chain_spec: &Box<dyn ChainSpec>) {
+	let ss58_version = chain_spec
+		.properties()
+		.get("ss58Format")
+		.and_then(|v| v.as_u64())
+		.and_then(|v| u16::try_from(v).ok())
+		.and_then(|v| Ss58AddressFormat::try_from(v).ok());
+
+	if let Some(ss58_version) = ss58_version {
+		sp_core::crypto::set_default_ss58_version(ss58_version);
+	}
+}
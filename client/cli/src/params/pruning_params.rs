@@ -23,18 +23,37 @@ use structopt::StructOpt;
 /// Parameters to define the pruning mode
 #[derive(Debug, StructOpt, Clone)]
 pub struct PruningParams {
-	/// Specify the state pruning mode, a number of blocks to keep or 'archive'.
+	/// Specify the state pruning mode, a number of blocks to keep, 'archive' or
+	/// 'archive-canonical'.
+	///
+	/// 'archive' keeps the state of all blocks, 'archive-canonical' only keeps the
+	/// state of finalized blocks.
 	///
 	/// Default is to keep all block states if the node is running as a
 	/// validator (i.e. 'archive'), otherwise state is only kept for the last
 	/// 256 blocks.
-	#[structopt(long = "pruning", value_name = "PRUNING_MODE")]
+	///
+	/// The pruning mode can not be changed once the database is created, unless
+	/// `--override-state-pruning` is given.
+	#[structopt(long = "state-pruning", alias = "pruning", value_name = "PRUNING_MODE")]
 	pub pruning: Option<String>,
-	/// Specify the number of finalized blocks to keep in the database.
+	/// Use the state pruning mode even if the database was created with another one.
+	///
+	/// The new mode only applies to the blocks imported from now on: the state kept under
+	/// the previous mode is not pruned, and switching to 'archive' does not bring back the
+	/// state which was already pruned.
+	#[structopt(long)]
+	pub override_state_pruning: bool,
+	/// Specify the blocks pruning mode, a number of finalized blocks to keep, 'archive' or
+	/// 'archive-canonical'.
+	///
+	/// 'archive' keeps all blocks, 'archive-canonical' keeps all finalized blocks and discards
+	/// the blocks of branches displaced by finality. Pruning a block removes its body and
+	/// justifications, its header is always kept.
 	///
 	/// Default is to keep all blocks.
-	#[structopt(long, value_name = "COUNT")]
-	pub keep_blocks: Option<u32>,
+	#[structopt(long = "blocks-pruning", alias = "keep-blocks", value_name = "PRUNING_MODE")]
+	pub keep_blocks: Option<String>,
 }
 
 impl PruningParams {
@@ -46,6 +65,7 @@ impl PruningParams {
 		// unless `unsafe_pruning` is set.
 		Ok(match &self.pruning {
 			Some(ref s) if s == "archive" => PruningMode::ArchiveAll,
+			Some(ref s) if s == "archive-canonical" => PruningMode::ArchiveCanonical,
 			None if role.is_authority() => PruningMode::ArchiveAll,
 			None => PruningMode::default(),
 			Some(s) => {
@@ -66,9 +86,13 @@ impl PruningParams {
 
 	/// Get the block pruning value from the parameters
 	pub fn keep_blocks(&self) -> error::Result<KeepBlocks> {
-		Ok(match self.keep_blocks {
-			Some(n) => KeepBlocks::Some(n),
+		Ok(match &self.keep_blocks {
+			Some(ref s) if s == "archive" => KeepBlocks::All,
+			Some(ref s) if s == "archive-canonical" => KeepBlocks::Finalized,
 			None => KeepBlocks::All,
+			Some(s) => KeepBlocks::Some(s.parse().map_err(|_| {
+				error::Error::Input("Invalid blocks pruning mode specified".to_string())
+			})?),
 		})
 	}
 }
@@ -16,7 +16,7 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::arg_enums::TracingReceiver;
+use crate::arg_enums::{LogFormat, TracingReceiver};
 use sc_service::config::BasePath;
 use std::path::PathBuf;
 use structopt::StructOpt;
@@ -50,6 +50,19 @@ pub struct SharedParams {
 	#[structopt(long)]
 	pub disable_log_color: bool,
 
+	/// Format of the log output.
+	///
+	/// `json` writes one JSON object per log record, containing its target, level, fields and
+	/// the fields of the spans it was emitted in. Colors are never used in this format.
+	#[structopt(
+		long = "log-format",
+		value_name = "FORMAT",
+		possible_values = &LogFormat::variants(),
+		case_insensitive = true,
+		default_value = "Text"
+	)]
+	pub log_format: LogFormat,
+
 	/// Disable feature to dynamically update and reload the log filter.
 	///
 	/// By default this feature is enabled, however it leads to a small performance decrease.
@@ -107,6 +120,11 @@ impl SharedParams {
 		self.disable_log_color
 	}
 
+	/// Format of the log output.
+	pub fn log_format(&self) -> sc_tracing::logging::LogFormat {
+		self.log_format.into()
+	}
+
 	/// Is log reloading disabled
 	pub fn is_log_filter_reloading_disabled(&self) -> bool {
 		self.disable_log_reloading
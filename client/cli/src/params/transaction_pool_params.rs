@@ -17,6 +17,7 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use sc_service::config::TransactionPoolOptions;
+use std::time::Duration;
 use structopt::StructOpt;
 
 /// Parameters used to create the pool configuration.
@@ -29,6 +30,22 @@ pub struct TransactionPoolParams {
 	/// Maximum number of kilobytes of all transactions stored in the pool.
 	#[structopt(long = "pool-kbytes", value_name = "COUNT", default_value = "20480")]
 	pub pool_kbytes: usize,
+
+	/// Maximum number of transactions from a single sender in the transaction pool.
+	///
+	/// Unlimited by default.
+	#[structopt(long = "pool-limit-per-sender", value_name = "COUNT")]
+	pub pool_limit_per_sender: Option<usize>,
+
+	/// Maximum number of future transactions from a single sender in the transaction pool.
+	///
+	/// Unlimited by default.
+	#[structopt(long = "pool-future-limit-per-sender", value_name = "COUNT")]
+	pub pool_future_limit_per_sender: Option<usize>,
+
+	/// How long, in seconds, invalid and stale transactions are banned from the pool.
+	#[structopt(long = "tx-ban-seconds", value_name = "SECONDS")]
+	pub tx_ban_seconds: Option<u64>,
 }
 
 impl TransactionPoolParams {
@@ -45,6 +62,14 @@ impl TransactionPoolParams {
 		opts.future.count = self.pool_limit / factor;
 		opts.future.total_bytes = self.pool_kbytes * 1024 / factor;
 
+		// per sender
+		opts.per_sender.total = self.pool_limit_per_sender;
+		opts.per_sender.future = self.pool_future_limit_per_sender;
+
+		if let Some(ban_seconds) = self.tx_ban_seconds {
+			opts.ban_time = Duration::from_secs(ban_seconds);
+		}
+
 		opts
 	}
 }
@@ -60,7 +60,7 @@ fn check_header<C, B: BlockT, P: Pair>(
 	mut header: B::Header,
 	hash: B::Hash,
 	authorities: &[AuthorityId<P>],
-	check_for_equivocation: CheckForEquivocation,
+	check_for_equivocation: bool,
 ) -> Result<CheckedHeader<B::Header, (Slot, DigestItemFor<B>)>, Error<B>>
 where
 	DigestItemFor<B>: CompatibleDigestItem<P::Signature>,
@@ -86,7 +86,7 @@ where
 		let pre_hash = header.hash();
 
 		if P::verify(&sig, pre_hash.as_ref(), expected_author) {
-			if check_for_equivocation.check_for_equivocation() {
+			if check_for_equivocation {
 				if let Some(equivocation_proof) =
 					check_equivocation(client, slot_now, slot, &header, expected_author)
 						.map_err(Error::Client)?
@@ -115,6 +115,7 @@ pub struct AuraVerifier<C, P, CAW, CIDP> {
 	create_inherent_data_providers: CIDP,
 	can_author_with: CAW,
 	check_for_equivocation: CheckForEquivocation,
+	allowed_slot_drift: AllowedSlotDrift,
 	telemetry: Option<TelemetryHandle>,
 }
 
@@ -124,6 +125,7 @@ impl<C, P, CAW, CIDP> AuraVerifier<C, P, CAW, CIDP> {
 		create_inherent_data_providers: CIDP,
 		can_author_with: CAW,
 		check_for_equivocation: CheckForEquivocation,
+		allowed_slot_drift: AllowedSlotDrift,
 		telemetry: Option<TelemetryHandle>,
 	) -> Self {
 		Self {
@@ -131,6 +133,7 @@ impl<C, P, CAW, CIDP> AuraVerifier<C, P, CAW, CIDP> {
 			create_inherent_data_providers,
 			can_author_with,
 			check_for_equivocation,
+			allowed_slot_drift,
 			telemetry,
 			phantom: PhantomData,
 		}
@@ -194,6 +197,7 @@ where
 		+ Sync
 		+ sc_client_api::backend::AuxStore
 		+ ProvideCache<B>
+		+ HeaderBackend<B>
 		+ BlockOf,
 	C::Api: BlockBuilderApi<B> + AuraApi<B, AuthorityId<P>> + ApiExt<B>,
 	DigestItemFor<B>: CompatibleDigestItem<P::Signature>,
@@ -225,16 +229,23 @@ where
 
 		let slot_now = create_inherent_data_providers.slot();
 
-		// we add one to allow for some small drift.
+		let check_for_equivocation = match self.check_for_equivocation {
+			CheckForEquivocation::Yes => true,
+			CheckForEquivocation::No => false,
+			CheckForEquivocation::NonFinalized =>
+				*block.header.number() > self.client.info().finalized_number,
+		};
+
+		// we allow for some small drift.
 		// FIXME #1019 in the future, alter this queue to allow deferring of
 		// headers
 		let checked_header = check_header::<C, B, P>(
 			&self.client,
-			slot_now + 1,
+			slot_now + self.allowed_slot_drift.0,
 			block.header,
 			hash,
 			&authorities[..],
-			self.check_for_equivocation,
+			check_for_equivocation,
 		)
 		.map_err(|e| e.to_string())?;
 		match checked_header {
@@ -328,18 +339,33 @@ pub enum CheckForEquivocation {
 	Yes,
 	/// No, don't check for equivocation.
 	No,
+	/// Only check for equivocation of blocks above the last finalized block.
+	///
+	/// This allows a node to sync a chain that contains historical equivocations
+	/// without re-checking (and recording) headers that are already final.
+	NonFinalized,
 }
 
-impl CheckForEquivocation {
-	/// Should we check for equivocation?
-	fn check_for_equivocation(self) -> bool {
-		matches!(self, Self::Yes)
+impl Default for CheckForEquivocation {
+	fn default() -> Self {
+		Self::Yes
 	}
 }
 
-impl Default for CheckForEquivocation {
+/// The number of slots a block's slot may be ahead of our current slot before
+/// it is rejected as being too far in the future.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AllowedSlotDrift(pub u64);
+
+impl Default for AllowedSlotDrift {
 	fn default() -> Self {
-		Self::Yes
+		Self(1)
+	}
+}
+
+impl From<u64> for AllowedSlotDrift {
+	fn from(drift: u64) -> Self {
+		Self(drift)
 	}
 }
 
@@ -361,6 +387,8 @@ pub struct ImportQueueParams<'a, Block, I, C, S, CAW, CIDP> {
 	pub can_author_with: CAW,
 	/// Should we check for equivocation?
 	pub check_for_equivocation: CheckForEquivocation,
+	/// How far into the future a block's slot may be.
+	pub allowed_slot_drift: AllowedSlotDrift,
 	/// Telemetry instance used to report telemetry metrics.
 	pub telemetry: Option<TelemetryHandle>,
 }
@@ -376,6 +404,7 @@ pub fn import_queue<'a, P, Block, I, C, S, CAW, CIDP>(
 		registry,
 		can_author_with,
 		check_for_equivocation,
+		allowed_slot_drift,
 		telemetry,
 	}: ImportQueueParams<'a, Block, I, C, S, CAW, CIDP>,
 ) -> Result<DefaultImportQueue<Block, C>, sp_consensus::Error>
@@ -409,6 +438,7 @@ where
 		create_inherent_data_providers,
 		can_author_with,
 		check_for_equivocation,
+		allowed_slot_drift,
 		telemetry,
 	});
 
@@ -425,6 +455,8 @@ pub struct BuildVerifierParams<C, CIDP, CAW> {
 	pub can_author_with: CAW,
 	/// Should we check for equivocation?
 	pub check_for_equivocation: CheckForEquivocation,
+	/// How far into the future a block's slot may be.
+	pub allowed_slot_drift: AllowedSlotDrift,
 	/// Telemetry instance used to report telemetry metrics.
 	pub telemetry: Option<TelemetryHandle>,
 }
@@ -436,6 +468,7 @@ pub fn build_verifier<P, C, CIDP, CAW>(
 		create_inherent_data_providers,
 		can_author_with,
 		check_for_equivocation,
+		allowed_slot_drift,
 		telemetry,
 	}: BuildVerifierParams<C, CIDP, CAW>,
 ) -> AuraVerifier<C, P, CAW, CIDP> {
@@ -444,6 +477,7 @@ pub fn build_verifier<P, C, CIDP, CAW>(
 		create_inherent_data_providers,
 		can_author_with,
 		check_for_equivocation,
+		allowed_slot_drift,
 		telemetry,
 	)
 }
@@ -68,8 +68,8 @@ use sp_runtime::{
 mod import_queue;
 
 pub use import_queue::{
-	build_verifier, import_queue, AuraVerifier, BuildVerifierParams, CheckForEquivocation,
-	ImportQueueParams,
+	build_verifier, import_queue, AllowedSlotDrift, AuraVerifier, BuildVerifierParams,
+	CheckForEquivocation, ImportQueueParams,
 };
 pub use sc_consensus_slots::SlotProportion;
 pub use sp_consensus::SyncOracle;
@@ -679,6 +679,7 @@ mod tests {
 						}),
 						AlwaysCanAuthor,
 						CheckForEquivocation::Yes,
+						Default::default(),
 						None,
 					)
 				},
@@ -302,8 +302,10 @@ pub(crate) async fn import_single_block_metered<
 
 	let cache = HashMap::from_iter(maybe_keys.unwrap_or_default());
 	let import_block = import_block.clear_storage_changes_and_mutate();
+	let import_started = std::time::Instant::now();
 	let imported = import_handle.import_block(import_block, cache).await;
 	if let Some(metrics) = metrics.as_ref() {
+		metrics.report_block_import(import_started.elapsed());
 		metrics.report_verification_and_import(started.elapsed());
 	}
 	import_handler(imported)
@@ -48,6 +48,8 @@ pub struct BasicQueue<B: BlockT, Transaction> {
 	block_import_sender: TracingUnboundedSender<worker_messages::ImportBlocks<B>>,
 	/// Results coming from the worker task.
 	result_port: BufferedLinkReceiver<B>,
+	/// Metrics shared with the background task, used to track the number of pending blocks.
+	metrics: Option<Metrics>,
 	_phantom: PhantomData<Transaction>,
 }
 
@@ -86,12 +88,18 @@ impl<B: BlockT, Transaction: Send + 'static> BasicQueue<B, Transaction> {
 			verifier,
 			block_import,
 			justification_import,
-			metrics,
+			metrics.clone(),
 		);
 
 		spawner.spawn_essential_blocking("basic-block-import-worker", future.boxed());
 
-		Self { justification_sender, block_import_sender, result_port, _phantom: PhantomData }
+		Self {
+			justification_sender,
+			block_import_sender,
+			result_port,
+			metrics,
+			_phantom: PhantomData,
+		}
 	}
 }
 
@@ -102,6 +110,9 @@ impl<B: BlockT, Transaction: Send> ImportQueue<B> for BasicQueue<B, Transaction>
 		}
 
 		trace!(target: "sync", "Scheduling {} blocks for import", blocks.len());
+		if let Some(metrics) = self.metrics.as_ref() {
+			metrics.report_blocks_scheduled(&origin, blocks.len());
+		}
 		let res = self
 			.block_import_sender
 			.unbounded_send(worker_messages::ImportBlocks(origin, blocks));
@@ -312,7 +323,7 @@ impl<B: BlockT> BlockImportWorker<B> {
 		};
 
 		if let Some(metrics) = self.metrics.as_ref() {
-			metrics.justification_import_time.observe(started.elapsed().as_secs_f64());
+			metrics.report_justification_import(success, started.elapsed());
 		}
 
 		self.result_sender.justification_imported(who, &hash, number, success);
@@ -388,6 +399,7 @@ async fn import_many_blocks<B: BlockT, V: Verifier<B>, Transaction: Send + 'stat
 
 		if let Some(metrics) = metrics.as_ref() {
 			metrics.report_import::<B>(&import_result);
+			metrics.report_block_processed(&blocks_origin);
 		}
 
 		if import_result.is_ok() {
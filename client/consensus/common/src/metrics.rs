@@ -19,10 +19,11 @@
 //! Metering tools for consensus
 
 use prometheus_endpoint::{
-	register, CounterVec, Histogram, HistogramOpts, HistogramVec, Opts, PrometheusError, Registry,
-	U64,
+	register, CounterVec, GaugeVec, Histogram, HistogramOpts, HistogramVec, Opts, PrometheusError,
+	Registry, U64,
 };
 
+use sp_consensus::BlockOrigin;
 use sp_runtime::traits::{Block as BlockT, NumberFor};
 
 use crate::import_queue::{BlockImportError, BlockImportStatus};
@@ -33,7 +34,10 @@ pub(crate) struct Metrics {
 	pub import_queue_processed: CounterVec<U64>,
 	pub block_verification_time: HistogramVec,
 	pub block_verification_and_import_time: Histogram,
+	pub block_import_time: Histogram,
 	pub justification_import_time: Histogram,
+	pub justification_import_processed: CounterVec<U64>,
+	pub import_queue_pending_blocks: GaugeVec<U64>,
 }
 
 impl Metrics {
@@ -60,6 +64,13 @@ impl Metrics {
 				))?,
 				registry,
 			)?,
+			block_import_time: register(
+				Histogram::with_opts(HistogramOpts::new(
+					"block_import_time",
+					"Time taken to import verified blocks",
+				))?,
+				registry,
+			)?,
 			justification_import_time: register(
 				Histogram::with_opts(HistogramOpts::new(
 					"justification_import_time",
@@ -67,6 +78,26 @@ impl Metrics {
 				))?,
 				registry,
 			)?,
+			justification_import_processed: register(
+				CounterVec::new(
+					Opts::new(
+						"import_queue_justifications_processed_total",
+						"Justifications processed by import queue",
+					),
+					&["result"], // 'success or failure
+				)?,
+				registry,
+			)?,
+			import_queue_pending_blocks: register(
+				GaugeVec::new(
+					Opts::new(
+						"import_queue_pending_blocks",
+						"Blocks scheduled for import that have not been processed yet",
+					),
+					&["origin"],
+				)?,
+				registry,
+			)?,
 		})
 	}
 
@@ -97,4 +128,38 @@ impl Metrics {
 	pub fn report_verification_and_import(&self, time: std::time::Duration) {
 		self.block_verification_and_import_time.observe(time.as_secs_f64());
 	}
+
+	pub fn report_block_import(&self, time: std::time::Duration) {
+		self.block_import_time.observe(time.as_secs_f64());
+	}
+
+	pub fn report_justification_import(&self, success: bool, time: std::time::Duration) {
+		self.justification_import_time.observe(time.as_secs_f64());
+		self.justification_import_processed
+			.with_label_values(&[if success { "success" } else { "failure" }])
+			.inc();
+	}
+
+	pub fn report_blocks_scheduled(&self, origin: &BlockOrigin, count: usize) {
+		self.import_queue_pending_blocks
+			.with_label_values(&[origin_label(origin)])
+			.add(count as u64);
+	}
+
+	pub fn report_block_processed(&self, origin: &BlockOrigin) {
+		self.import_queue_pending_blocks
+			.with_label_values(&[origin_label(origin)])
+			.dec();
+	}
+}
+
+fn origin_label(origin: &BlockOrigin) -> &'static str {
+	match origin {
+		BlockOrigin::Genesis => "genesis",
+		BlockOrigin::NetworkInitialSync => "network_initial_sync",
+		BlockOrigin::NetworkBroadcast => "network_broadcast",
+		BlockOrigin::ConsensusBroadcast => "consensus_broadcast",
+		BlockOrigin::Own => "own",
+		BlockOrigin::File => "file",
+	}
 }
@@ -23,6 +23,7 @@ use sc_consensus::BlockImportParams;
 use sp_inherents::InherentData;
 use sp_runtime::traits::{Block as BlockT, DigestFor};
 
+pub mod aura;
 pub mod babe;
 
 /// Consensus data provider, manual seal uses this trait object for authoring blocks valid
@@ -0,0 +1,164 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2020-2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Aura consensus data provider
+
+use super::ConsensusDataProvider;
+use crate::Error;
+use sc_client_api::{AuxStore, UsageProvider};
+use sc_consensus::BlockImportParams;
+use sp_api::{ProvideRuntimeApi, TransactionFor};
+use sp_blockchain::HeaderBackend;
+use sp_consensus_aura::{
+	digests::CompatibleDigestItem,
+	sr25519::{AuthorityId, AuthoritySignature},
+	AuraApi,
+};
+use sp_consensus_slots::Slot;
+use sp_inherents::{InherentData, InherentDataProvider, InherentIdentifier};
+use sp_runtime::{
+	generic::{BlockId, Digest},
+	traits::{Block as BlockT, DigestFor, DigestItemFor, Header},
+};
+use sp_timestamp::{InherentType, TimestampInherentData, INHERENT_IDENTIFIER};
+use std::{marker::PhantomData, sync::Arc, time::SystemTime};
+
+/// Provides Aura-compatible predigests.
+/// Intended for use with Aura runtimes.
+///
+/// The slot of each block is derived from its timestamp inherent, see [`SlotTimestampProvider`]
+/// for a timestamp that moves to a new slot on every block.
+pub struct AuraConsensusDataProvider<B, C> {
+	/// slot duration in milliseconds
+	slot_duration: u64,
+
+	_phantom: PhantomData<(B, C)>,
+}
+
+impl<B, C> AuraConsensusDataProvider<B, C>
+where
+	B: BlockT,
+	C: AuxStore + ProvideRuntimeApi<B> + UsageProvider<B>,
+	C::Api: AuraApi<B, AuthorityId>,
+{
+	/// Creates a new instance of the [`AuraConsensusDataProvider`].
+	pub fn new(client: Arc<C>) -> Result<Self, Error> {
+		let slot_duration = sc_consensus_aura::slot_duration(&*client)?.get().get();
+
+		Ok(Self { slot_duration, _phantom: PhantomData })
+	}
+}
+
+impl<B, C> ConsensusDataProvider<B> for AuraConsensusDataProvider<B, C>
+where
+	B: BlockT,
+	C: ProvideRuntimeApi<B> + Send + Sync,
+{
+	type Transaction = TransactionFor<C, B>;
+
+	fn create_digest(
+		&self,
+		_parent: &B::Header,
+		inherents: &InherentData,
+	) -> Result<DigestFor<B>, Error> {
+		let timestamp = inherents
+			.timestamp_inherent_data()?
+			.ok_or_else(|| Error::StringError("No timestamp inherent data".into()))?;
+		let slot = Slot::from(*timestamp / self.slot_duration);
+
+		let digest_item =
+			<DigestItemFor<B> as CompatibleDigestItem<AuthoritySignature>>::aura_pre_digest(slot);
+
+		Ok(Digest { logs: vec![digest_item] })
+	}
+
+	fn append_block_import(
+		&self,
+		_parent: &B::Header,
+		_params: &mut BlockImportParams<B, Self::Transaction>,
+		_inherents: &InherentData,
+	) -> Result<(), Error> {
+		Ok(())
+	}
+}
+
+/// Provide a mock duration starting at the slot after the parent's slot, so that blocks can be
+/// sealed faster than the slot duration without two of them ending up in the same slot.
+pub struct SlotTimestampProvider {
+	time: u64,
+	slot_duration: u64,
+}
+
+impl SlotTimestampProvider {
+	/// Create a new mocked time stamp provider for a block built on top of `parent`.
+	pub fn new<B, C>(client: Arc<C>, parent: B::Hash) -> Result<Self, Error>
+	where
+		B: BlockT,
+		C: AuxStore + HeaderBackend<B> + ProvideRuntimeApi<B> + UsageProvider<B>,
+		C::Api: AuraApi<B, AuthorityId>,
+	{
+		let slot_duration = sc_consensus_aura::slot_duration(&*client)?.get().get();
+		let header = client
+			.header(BlockId::Hash(parent))?
+			.ok_or_else(|| Error::BlockNotFound(format!("{}", parent)))?;
+
+		// the genesis block has no pre-digest and any slot is fine for the first block.
+		let next_slot_time = header
+			.digest()
+			.logs()
+			.iter()
+			.find_map(|log| {
+				<DigestItemFor<B> as CompatibleDigestItem<AuthoritySignature>>::as_aura_pre_digest(
+					log,
+				)
+			})
+			.map(|slot| (*slot + 1) * slot_duration)
+			.unwrap_or_default();
+
+		let now = SystemTime::now()
+			.duration_since(SystemTime::UNIX_EPOCH)
+			.map_err(|err| Error::StringError(format!("{}", err)))?
+			.as_millis() as u64;
+
+		Ok(Self { time: now.max(next_slot_time), slot_duration })
+	}
+
+	/// Get the current slot number
+	pub fn slot(&self) -> Slot {
+		Slot::from(self.time / self.slot_duration)
+	}
+}
+
+#[async_trait::async_trait]
+impl InherentDataProvider for SlotTimestampProvider {
+	fn provide_inherent_data(
+		&self,
+		inherent_data: &mut InherentData,
+	) -> Result<(), sp_inherents::Error> {
+		let time: InherentType = self.time.into();
+		inherent_data.put_data(INHERENT_IDENTIFIER, &time)
+	}
+
+	async fn try_handle_error(
+		&self,
+		_: &InherentIdentifier,
+		_: &[u8],
+	) -> Option<Result<(), sp_inherents::Error>> {
+		None
+	}
+}
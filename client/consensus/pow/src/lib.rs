@@ -29,7 +29,12 @@
 //! the worker handle, you can pull the metadata needed to start the
 //! mining process via [`MiningWorker::metadata`], and then do the actual
 //! mining on a standalone thread. Finally, when a seal is found, call
-//! [`MiningWorker::submit`] to build the block.
+//! [`MiningWorker::submit`] to build the block. External miners can do the
+//! same over RPC, through the [`rpc::PowApi`] served on top of the worker.
+//!
+//! Difficulty adjustment is left to the [`PowAlgorithm`]. Chains keeping
+//! the adjustment in the runtime can use [`runtime_difficulty`] to query it
+//! through the `DifficultyApi`.
 //!
 //! The auxiliary storage for PoW engine only stores the total difficulty.
 //! For other storage requirements for particular PoW algorithm (such as
@@ -39,6 +44,7 @@
 //! as the storage, but it is not recommended as it won't work well with light
 //! clients.
 
+pub mod rpc;
 mod worker;
 
 pub use crate::worker::{MiningBuild, MiningMetadata, MiningWorker};
@@ -60,7 +66,7 @@ use sp_blockchain::{well_known_cache_keys::Id as CacheKeyId, HeaderBackend, Prov
 use sp_consensus::{
 	CanAuthorWith, Environment, Error as ConsensusError, Proposer, SelectChain, SyncOracle,
 };
-use sp_consensus_pow::{Seal, TotalDifficulty, POW_ENGINE_ID};
+use sp_consensus_pow::{DifficultyApi, Seal, TotalDifficulty, POW_ENGINE_ID};
 use sp_core::ExecutionContext;
 use sp_inherents::{CreateInherentDataProviders, InherentDataProvider};
 use sp_runtime::{
@@ -209,6 +215,23 @@ pub trait PowAlgorithm<B: BlockT> {
 	) -> Result<bool, Error<B>>;
 }
 
+/// Get the difficulty of the block built on `parent` from the runtime's `DifficultyApi`.
+///
+/// Meant to be called by [`PowAlgorithm::difficulty`] implementations of chains that keep their
+/// difficulty adjustment algorithm in the runtime.
+pub fn runtime_difficulty<B, C, D>(client: &C, parent: B::Hash) -> Result<D, Error<B>>
+where
+	B: BlockT,
+	C: ProvideRuntimeApi<B>,
+	C::Api: DifficultyApi<B, D>,
+	D: Decode,
+{
+	client
+		.runtime_api()
+		.difficulty(&BlockId::Hash(parent))
+		.map_err(|e| Error::Client(e.into()))
+}
+
 /// A block importer for PoW.
 pub struct PowBlockImport<B: BlockT, I, C, S, Algorithm, CAW, CIDP> {
 	algorithm: Algorithm,
@@ -546,8 +569,8 @@ where
 	let worker = Arc::new(Mutex::new(MiningWorker {
 		build: None,
 		algorithm: algorithm.clone(),
-		block_import,
-		justification_sync_link,
+		block_import: Arc::new(futures::lock::Mutex::new(block_import)),
+		justification_sync_link: Arc::new(justification_sync_link),
 	}));
 	let worker_ret = worker.clone();
 
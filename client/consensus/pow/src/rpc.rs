@@ -0,0 +1,124 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! RPC interface for external PoW miners.
+//!
+//! Miners poll `pow_miningMetadata` for the block currently being mined and submit the seal they
+//! found through `pow_submitSeal`.
+
+pub use self::gen_client::Client as PowClient;
+use futures::FutureExt;
+use jsonrpc_core::{BoxFuture, Result};
+use jsonrpc_derive::rpc;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use sp_core::Bytes;
+use sp_runtime::traits::Block as BlockT;
+use std::sync::Arc;
+
+use crate::{MiningMetadata, MiningWorker, PowAlgorithm};
+
+/// RPC trait that allows external miners to mine with the PoW mining worker.
+#[rpc]
+pub trait PowApi<Hash, Difficulty> {
+	/// Returns the metadata of the block currently being mined, if there is one.
+	#[rpc(name = "pow_miningMetadata")]
+	fn mining_metadata(&self) -> Result<Option<RpcMiningMetadata<Hash, Difficulty>>>;
+
+	/// Submits a seal for the block currently being mined.
+	///
+	/// Returns `true` if the seal is valid and the mined block got imported.
+	#[rpc(name = "pow_submitSeal")]
+	fn submit_seal(&self, seal: Bytes) -> BoxFuture<Result<bool>>;
+}
+
+/// Return type of `pow_miningMetadata`.
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcMiningMetadata<Hash, Difficulty> {
+	/// Best hash the block being mined is built on.
+	pub best_hash: Hash,
+	/// Pre-hash to mine a seal for.
+	pub pre_hash: Hash,
+	/// Pre-runtime digest of the block being mined.
+	pub pre_runtime: Option<Bytes>,
+	/// Target difficulty of the seal.
+	pub difficulty: Difficulty,
+}
+
+impl<Hash, Difficulty> From<MiningMetadata<Hash, Difficulty>>
+	for RpcMiningMetadata<Hash, Difficulty>
+{
+	fn from(metadata: MiningMetadata<Hash, Difficulty>) -> Self {
+		Self {
+			best_hash: metadata.best_hash,
+			pre_hash: metadata.pre_hash,
+			pre_runtime: metadata.pre_runtime.map(Into::into),
+			difficulty: metadata.difficulty,
+		}
+	}
+}
+
+/// A struct that implements the [`PowApi`] on top of a mining worker.
+pub struct Pow<Block, Algorithm, C, L, Proof>
+where
+	Block: BlockT,
+	Algorithm: PowAlgorithm<Block>,
+	C: sp_api::ProvideRuntimeApi<Block>,
+	L: sc_consensus::JustificationSyncLink<Block>,
+{
+	worker: Arc<Mutex<MiningWorker<Block, Algorithm, C, L, Proof>>>,
+}
+
+impl<Block, Algorithm, C, L, Proof> Pow<Block, Algorithm, C, L, Proof>
+where
+	Block: BlockT,
+	Algorithm: PowAlgorithm<Block>,
+	C: sp_api::ProvideRuntimeApi<Block>,
+	L: sc_consensus::JustificationSyncLink<Block>,
+{
+	/// Create new `Pow` with the worker returned by
+	/// [`start_mining_worker`](crate::start_mining_worker).
+	pub fn new(worker: Arc<Mutex<MiningWorker<Block, Algorithm, C, L, Proof>>>) -> Self {
+		Self { worker }
+	}
+}
+
+impl<Block, Algorithm, C, L, Proof> PowApi<Block::Hash, Algorithm::Difficulty>
+	for Pow<Block, Algorithm, C, L, Proof>
+where
+	Block: BlockT,
+	Algorithm: PowAlgorithm<Block> + Send + 'static,
+	Algorithm::Difficulty: Serialize + Send + 'static,
+	C: sp_api::ProvideRuntimeApi<Block> + Send + Sync + 'static,
+	L: sc_consensus::JustificationSyncLink<Block> + 'static,
+	Proof: Send + 'static,
+	sp_api::TransactionFor<C, Block>: Send + 'static,
+{
+	fn mining_metadata(
+		&self,
+	) -> Result<Option<RpcMiningMetadata<Block::Hash, Algorithm::Difficulty>>> {
+		Ok(self.worker.lock().metadata().map(Into::into))
+	}
+
+	fn submit_seal(&self, seal: Bytes) -> BoxFuture<Result<bool>> {
+		// The worker is only locked while the seal is validated, not during the import.
+		let submit = self.worker.lock().submit(seal.to_vec());
+		submit.map(Ok).boxed()
+	}
+}
@@ -30,7 +30,7 @@ use sp_runtime::{
 	traits::{Block as BlockT, Header as HeaderT},
 	DigestItem,
 };
-use std::{borrow::Cow, collections::HashMap, pin::Pin, time::Duration};
+use std::{borrow::Cow, collections::HashMap, pin::Pin, sync::Arc, time::Duration};
 
 use crate::{PowAlgorithm, PowIntermediate, Seal, INTERMEDIATE_KEY, POW_ENGINE_ID};
 
@@ -70,8 +70,9 @@ pub struct MiningWorker<
 > {
 	pub(crate) build: Option<MiningBuild<Block, Algorithm, C, Proof>>,
 	pub(crate) algorithm: Algorithm,
-	pub(crate) block_import: BoxBlockImport<Block, sp_api::TransactionFor<C, Block>>,
-	pub(crate) justification_sync_link: L,
+	pub(crate) block_import:
+		Arc<futures::lock::Mutex<BoxBlockImport<Block, sp_api::TransactionFor<C, Block>>>>,
+	pub(crate) justification_sync_link: Arc<L>,
 }
 
 impl<Block, Algorithm, C, L, Proof> MiningWorker<Block, Algorithm, C, L, Proof>
@@ -102,65 +103,44 @@ where
 		self.build.as_ref().map(|b| b.metadata.clone())
 	}
 
-	/// Submit a mined seal. The seal will be validated again. Returns true if the submission is
-	/// successful.
-	pub async fn submit(&mut self, seal: Seal) -> bool {
-		if let Some(build) = self.build.take() {
-			match self.algorithm.verify(
-				&BlockId::Hash(build.metadata.best_hash),
-				&build.metadata.pre_hash,
-				build.metadata.pre_runtime.as_ref().map(|v| &v[..]),
-				&seal,
-				build.metadata.difficulty,
-			) {
-				Ok(true) => (),
-				Ok(false) => {
-					warn!(
-						target: "pow",
-						"Unable to import mined block: seal is invalid",
-					);
-					return false
-				},
-				Err(err) => {
-					warn!(
-						target: "pow",
-						"Unable to import mined block: {:?}",
-						err,
-					);
-					return false
-				},
-			}
-
-			let seal = DigestItem::Seal(POW_ENGINE_ID, seal);
-			let (header, body) = build.proposal.block.deconstruct();
-
-			let mut import_block = BlockImportParams::new(BlockOrigin::Own, header);
-			import_block.post_digests.push(seal);
-			import_block.body = Some(body);
-			import_block.state_action =
-				StateAction::ApplyChanges(StorageChanges::Changes(build.proposal.storage_changes));
+	/// Submit a mined seal. The seal will be validated again. The returned future resolves to
+	/// true if the submission is successful.
+	///
+	/// The seal is validated right away, but the returned future doesn't borrow the worker, so
+	/// the worker doesn't need to stay locked while the mined block is imported.
+	pub fn submit(&mut self, seal: Seal) -> impl Future<Output = bool> + Send {
+		let import = match self.build.take() {
+			Some(build) => self.import_params(build, seal),
+			None => {
+				warn!(
+					target: "pow",
+					"Unable to import mined block: build does not exist",
+				);
+				None
+			},
+		};
+		let block_import = self.block_import.clone();
+		let mut justification_sync_link = self.justification_sync_link.clone();
 
-			let intermediate = PowIntermediate::<Algorithm::Difficulty> {
-				difficulty: Some(build.metadata.difficulty),
+		async move {
+			let (import_block, best_hash) = match import {
+				Some(import) => import,
+				None => return false,
 			};
 
-			import_block
-				.intermediates
-				.insert(Cow::from(INTERMEDIATE_KEY), Box::new(intermediate) as Box<_>);
-
 			let header = import_block.post_header();
-			match self.block_import.import_block(import_block, HashMap::default()).await {
+			match block_import.lock().await.import_block(import_block, HashMap::default()).await {
 				Ok(res) => {
 					res.handle_justification(
 						&header.hash(),
 						*header.number(),
-						&mut self.justification_sync_link,
+						&mut justification_sync_link,
 					);
 
 					info!(
 						target: "pow",
 						"✅ Successfully mined block on top of: {}",
-						build.metadata.best_hash
+						best_hash
 					);
 					true
 				},
@@ -173,14 +153,60 @@ where
 					false
 				},
 			}
-		} else {
-			warn!(
-				target: "pow",
-				"Unable to import mined block: build does not exist",
-			);
-			false
 		}
 	}
+
+	/// Validate `seal` for `build`, returning the parameters to import the mined block with and
+	/// the hash it was built on.
+	fn import_params(
+		&self,
+		build: MiningBuild<Block, Algorithm, C, Proof>,
+		seal: Seal,
+	) -> Option<(BlockImportParams<Block, sp_api::TransactionFor<C, Block>>, Block::Hash)> {
+		match self.algorithm.verify(
+			&BlockId::Hash(build.metadata.best_hash),
+			&build.metadata.pre_hash,
+			build.metadata.pre_runtime.as_ref().map(|v| &v[..]),
+			&seal,
+			build.metadata.difficulty,
+		) {
+			Ok(true) => (),
+			Ok(false) => {
+				warn!(
+					target: "pow",
+					"Unable to import mined block: seal is invalid",
+				);
+				return None
+			},
+			Err(err) => {
+				warn!(
+					target: "pow",
+					"Unable to import mined block: {:?}",
+					err,
+				);
+				return None
+			},
+		}
+
+		let seal = DigestItem::Seal(POW_ENGINE_ID, seal);
+		let (header, body) = build.proposal.block.deconstruct();
+
+		let mut import_block = BlockImportParams::new(BlockOrigin::Own, header);
+		import_block.post_digests.push(seal);
+		import_block.body = Some(body);
+		import_block.state_action =
+			StateAction::ApplyChanges(StorageChanges::Changes(build.proposal.storage_changes));
+
+		let intermediate = PowIntermediate::<Algorithm::Difficulty> {
+			difficulty: Some(build.metadata.difficulty),
+		};
+
+		import_block
+			.intermediates
+			.insert(Cow::from(INTERMEDIATE_KEY), Box::new(intermediate) as Box<_>);
+
+		Some((import_block, build.metadata.best_hash))
+	}
 }
 
 /// A stream that waits for a block import or timeout.
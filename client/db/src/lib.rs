@@ -100,6 +100,7 @@ use sp_trie::{prefixed_key, MemoryDB, PrefixedMemoryDB};
 // Re-export the Database trait so that one can pass an implementation of it.
 pub use sc_state_db::PruningMode;
 pub use sp_database::Database;
+pub use utils::ColumnStats;
 
 #[cfg(any(feature = "with-kvdb-rocksdb", test))]
 pub use bench::BenchmarkingState;
@@ -296,6 +297,8 @@ pub struct DatabaseSettings {
 	pub state_cache_child_ratio: Option<(usize, usize)>,
 	/// State pruning mode.
 	pub state_pruning: PruningMode,
+	/// Use `state_pruning` even if the database was created with another state pruning mode.
+	pub override_state_pruning: bool,
 	/// Where to find the database.
 	pub source: DatabaseSource,
 	/// Block pruning mode.
@@ -309,6 +312,8 @@ pub struct DatabaseSettings {
 pub enum KeepBlocks {
 	/// Keep full block history.
 	All,
+	/// Keep all finalized blocks, but discard the blocks of branches displaced by finality.
+	Finalized,
 	/// Keep N recent finalized blocks.
 	Some(u32),
 }
@@ -1110,6 +1115,19 @@ impl<Block: BlockT> Backend<Block> {
 		Self::from_database(db as Arc<_>, canonicalization_delay, &config)
 	}
 
+	/// Create a new instance of database backend opened read-only, along with the statistics of
+	/// the database columns.
+	///
+	/// The database must exist and is not upgraded. Importing or finalizing blocks fails.
+	pub fn new_read_only(
+		config: DatabaseSettings,
+		canonicalization_delay: u64,
+	) -> ClientResult<(Self, Vec<ColumnStats>)> {
+		let (db, stats) =
+			crate::utils::open_database_read_only::<Block>(&config, DatabaseType::Full)?;
+		Ok((Self::from_database(db, canonicalization_delay, &config)?, stats))
+	}
+
 	/// Create new memory-backed client backend for tests.
 	#[cfg(any(test, feature = "test-helpers"))]
 	pub fn new_test(keep_blocks: u32, canonicalization_delay: u64) -> Self {
@@ -1133,6 +1151,7 @@ impl<Block: BlockT> Backend<Block> {
 			state_cache_size: 16777216,
 			state_cache_child_ratio: Some((50, 100)),
 			state_pruning: PruningMode::keep_blocks(keep_blocks),
+			override_state_pruning: false,
 			source: DatabaseSource::Custom(db),
 			keep_blocks: KeepBlocks::Some(keep_blocks),
 			transaction_storage,
@@ -1153,6 +1172,7 @@ impl<Block: BlockT> Backend<Block> {
 		let state_db: StateDb<_, _> = StateDb::new(
 			config.state_pruning.clone(),
 			!db.supports_ref_counting(),
+			config.override_state_pruning,
 			&StateMetaDb(&*db),
 		)
 		.map_err(map_e)?;
@@ -1789,7 +1809,9 @@ impl<Block: BlockT> Backend<Block> {
 				let number = finalized.saturating_sub(keep.into());
 				self.prune_block(transaction, BlockId::<Block>::number(number))?;
 			}
+		}
 
+		if let KeepBlocks::Finalized | KeepBlocks::Some(_) = self.keep_blocks {
 			// Also discard all blocks from displaced branches
 			for h in displaced.leaves() {
 				let mut number = finalized;
@@ -1819,37 +1841,42 @@ impl<Block: BlockT> Backend<Block> {
 		transaction: &mut Transaction<DbHash>,
 		id: BlockId<Block>,
 	) -> ClientResult<()> {
-		match read_db(&*self.storage.db, columns::KEY_LOOKUP, columns::BODY, id)? {
-			Some(body) => {
-				debug!(target: "db", "Removing block #{}", id);
-				utils::remove_from_db(
-					transaction,
-					&*self.storage.db,
-					columns::KEY_LOOKUP,
-					columns::BODY,
-					id,
-				)?;
-				match self.transaction_storage {
-					TransactionStorageMode::BlockBody => {},
-					TransactionStorageMode::StorageChain => {
-						match Vec::<ExtrinsicHeader>::decode(&mut &body[..]) {
-							Ok(body) =>
-								for ExtrinsicHeader { indexed_hash, .. } in body {
-									if indexed_hash != Default::default() {
-										transaction.release(columns::TRANSACTION, indexed_hash);
-									}
-								},
-							Err(err) =>
-								return Err(sp_blockchain::Error::Backend(format!(
-									"Error decoding body list: {}",
-									err
-								))),
-						}
-					},
-				}
-			},
-			None => return Ok(()),
+		if let Some(body) = read_db(&*self.storage.db, columns::KEY_LOOKUP, columns::BODY, id)? {
+			debug!(target: "db", "Removing block #{}", id);
+			utils::remove_from_db(
+				transaction,
+				&*self.storage.db,
+				columns::KEY_LOOKUP,
+				columns::BODY,
+				id,
+			)?;
+			match self.transaction_storage {
+				TransactionStorageMode::BlockBody => {},
+				TransactionStorageMode::StorageChain => {
+					match Vec::<ExtrinsicHeader>::decode(&mut &body[..]) {
+						Ok(body) =>
+							for ExtrinsicHeader { indexed_hash, .. } in body {
+								if indexed_hash != Default::default() {
+									transaction.release(columns::TRANSACTION, indexed_hash);
+								}
+							},
+						Err(err) =>
+							return Err(sp_blockchain::Error::Backend(format!(
+								"Error decoding body list: {}",
+								err
+							))),
+					}
+				},
+			}
 		}
+		// The justifications go together with the body, the header is always kept.
+		utils::remove_from_db(
+			transaction,
+			&*self.storage.db,
+			columns::KEY_LOOKUP,
+			columns::JUSTIFICATIONS,
+			id,
+		)?;
 		Ok(())
 	}
 
@@ -2401,6 +2428,16 @@ impl<Block: BlockT> sc_client_api::backend::Backend<Block> for Backend<Block> {
 	fn get_import_lock(&self) -> &RwLock<()> {
 		&*self.import_lock
 	}
+
+	fn pin_block(&self, hash: &Block::Hash) -> ClientResult<()> {
+		self.storage.state_db.pin(hash).map_err(|_| {
+			sp_blockchain::Error::UnknownBlock(format!("State already discarded for {:?}", hash))
+		})
+	}
+
+	fn unpin_block(&self, hash: &Block::Hash) {
+		self.storage.state_db.unpin(hash);
+	}
 }
 
 impl<Block: BlockT> sc_client_api::backend::LocalBackend<Block> for Backend<Block> {}
@@ -2541,6 +2578,7 @@ pub(crate) mod tests {
 				state_cache_size: 16777216,
 				state_cache_child_ratio: Some((50, 100)),
 				state_pruning: PruningMode::keep_blocks(1),
+				override_state_pruning: false,
 				source: DatabaseSource::Custom(backing),
 				keep_blocks: KeepBlocks::All,
 				transaction_storage: TransactionStorageMode::BlockBody,
@@ -3253,7 +3291,8 @@ pub(crate) mod tests {
 				let mut op = backend.begin_operation().unwrap();
 				backend.begin_state_operation(&mut op, BlockId::Hash(blocks[4])).unwrap();
 				for i in 1..5 {
-					op.mark_finalized(BlockId::Hash(blocks[i]), None).unwrap();
+					let justification = Some((CONS0_ENGINE_ID, vec![i as u8]));
+					op.mark_finalized(BlockId::Hash(blocks[i]), justification).unwrap();
 				}
 				backend.commit_operation(op).unwrap();
 			}
@@ -3263,7 +3302,69 @@ pub(crate) mod tests {
 			assert_eq!(None, bc.body(BlockId::hash(blocks[2])).unwrap());
 			assert_eq!(Some(vec![3.into()]), bc.body(BlockId::hash(blocks[3])).unwrap());
 			assert_eq!(Some(vec![4.into()]), bc.body(BlockId::hash(blocks[4])).unwrap());
+			assert_eq!(None, bc.justifications(BlockId::hash(blocks[2])).unwrap());
+			assert_eq!(
+				Some(Justifications::from((CONS0_ENGINE_ID, vec![3]))),
+				bc.justifications(BlockId::hash(blocks[3])).unwrap()
+			);
+			// Headers are never pruned.
+			assert!(bc.header(BlockId::hash(blocks[0])).unwrap().is_some());
+		}
+	}
+
+	#[test]
+	fn prune_displaced_blocks_when_keeping_finalized() {
+		let backend = Backend::<Block>::new(
+			DatabaseSettings {
+				state_cache_size: 16777216,
+				state_cache_child_ratio: Some((50, 100)),
+				state_pruning: PruningMode::ArchiveAll,
+				override_state_pruning: false,
+				source: DatabaseSource::Custom(sp_database::as_database(kvdb_memorydb::create(
+					crate::utils::NUM_COLUMNS,
+				))),
+				keep_blocks: KeepBlocks::Finalized,
+				transaction_storage: TransactionStorageMode::BlockBody,
+			},
+			0,
+		)
+		.unwrap();
+		let mut blocks = Vec::new();
+		let mut prev_hash = Default::default();
+		for i in 0..5 {
+			let hash = insert_block(
+				&backend,
+				i,
+				prev_hash,
+				None,
+				Default::default(),
+				vec![i.into()],
+				None,
+			);
+			blocks.push(hash);
+			prev_hash = hash;
+		}
+
+		// insert a fork at block 2
+		let fork_hash_root =
+			insert_block(&backend, 2, blocks[1], None, H256::random(), vec![2.into()], None);
+		let mut op = backend.begin_operation().unwrap();
+		backend.begin_state_operation(&mut op, BlockId::Hash(blocks[4])).unwrap();
+		op.mark_head(BlockId::Hash(blocks[4])).unwrap();
+		backend.commit_operation(op).unwrap();
+
+		for i in 1..5 {
+			let mut op = backend.begin_operation().unwrap();
+			backend.begin_state_operation(&mut op, BlockId::Hash(blocks[4])).unwrap();
+			op.mark_finalized(BlockId::Hash(blocks[i]), None).unwrap();
+			backend.commit_operation(op).unwrap();
+		}
+
+		let bc = backend.blockchain();
+		for i in 0..5 {
+			assert_eq!(Some(vec![(i as u64).into()]), bc.body(BlockId::hash(blocks[i])).unwrap());
 		}
+		assert_eq!(None, bc.body(BlockId::hash(fork_hash_root)).unwrap());
 	}
 
 	#[test]
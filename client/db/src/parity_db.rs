@@ -17,7 +17,7 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 use crate::{
 	columns, light,
-	utils::{DatabaseType, NUM_COLUMNS},
+	utils::{ColumnStats, DatabaseType, NUM_COLUMNS},
 };
 /// A `Database` adapter for parity-db.
 use sp_database::{error::DatabaseError, Change, ColumnId, Database, Transaction};
@@ -39,6 +39,43 @@ pub fn open<H: Clone + AsRef<[u8]>>(
 	db_type: DatabaseType,
 	create: bool,
 ) -> parity_db::Result<std::sync::Arc<dyn Database<H>>> {
+	let config = options(path, db_type);
+	let db = if create {
+		parity_db::Db::open_or_create(&config)?
+	} else {
+		parity_db::Db::open(&config)?
+	};
+
+	Ok(std::sync::Arc::new(DbAdapter(db)))
+}
+
+/// Wrap parity-db database opened read-only into a trait object that implements
+/// `sp_database::Database`, along with the statistics of its columns.
+pub fn open_read_only<H: Clone + AsRef<[u8]>>(
+	path: &std::path::Path,
+	db_type: DatabaseType,
+) -> parity_db::Result<(std::sync::Arc<dyn Database<H>>, Vec<ColumnStats>)> {
+	let db = parity_db::Db::open_read_only(&options(path, db_type))?;
+
+	let stats = (0..NUM_COLUMNS)
+		.map(|column| {
+			let mut summary = Vec::new();
+			db.collect_stats(&mut summary, Some(column as u8));
+			let summary = String::from_utf8_lossy(&summary);
+			let total = |name: &str| {
+				summary
+					.lines()
+					.find_map(|line| line.strip_prefix(name)?.trim().parse().ok())
+					.unwrap_or_default()
+			};
+			ColumnStats { column, keys: total("Total values:"), size: Some(total("Total bytes:")) }
+		})
+		.collect();
+
+	Ok((std::sync::Arc::new(DbAdapter(db)), stats))
+}
+
+fn options(path: &std::path::Path, db_type: DatabaseType) -> parity_db::Options {
 	let mut config = parity_db::Options::with_columns(path, NUM_COLUMNS as u8);
 
 	match db_type {
@@ -67,13 +104,7 @@ pub fn open<H: Clone + AsRef<[u8]>>(
 		},
 	}
 
-	let db = if create {
-		parity_db::Db::open_or_create(&config)?
-	} else {
-		parity_db::Db::open(&config)?
-	};
-
-	Ok(std::sync::Arc::new(DbAdapter(db)))
+	config
 }
 
 impl<H: Clone + AsRef<[u8]>> Database<H> for DbAdapter {
@@ -50,6 +50,8 @@ pub enum UpgradeError {
 	UnsupportedVersion(u32),
 	/// Database version comes from future version of the client.
 	FutureDatabaseVersion(u32),
+	/// Database version is not upgraded yet.
+	OutdatedDatabaseVersion(u32),
 	/// Invalid justification block.
 	DecodingJustificationBlock,
 	/// Common io error.
@@ -77,6 +79,9 @@ impl fmt::Display for UpgradeError {
 			UpgradeError::FutureDatabaseVersion(version) => {
 				write!(f, "Database version comes from future version of the client: {}", version)
 			},
+			UpgradeError::OutdatedDatabaseVersion(version) => {
+				write!(f, "Database version must be upgraded by the client first: {}", version)
+			},
 			UpgradeError::DecodingJustificationBlock => {
 				write!(f, "Decodoning justification block failed")
 			},
@@ -102,6 +107,17 @@ pub fn upgrade_db<Block: BlockT>(db_path: &Path, db_type: DatabaseType) -> Upgra
 	Ok(())
 }
 
+/// Check that the database is of the current version, without upgrading it.
+pub fn check_version(db_path: &Path) -> UpgradeResult<()> {
+	let db_version = current_version(db_path)?;
+	match db_version {
+		0 => Err(UpgradeError::UnsupportedVersion(db_version)),
+		CURRENT_VERSION => Ok(()),
+		_ if db_version < CURRENT_VERSION => Err(UpgradeError::OutdatedDatabaseVersion(db_version)),
+		_ => Err(UpgradeError::FutureDatabaseVersion(db_version)),
+	}
+}
+
 /// Migration from version1 to version2:
 /// 1) the number of columns has changed from 11 to 12;
 /// 2) transactions column is added;
@@ -192,6 +208,7 @@ mod tests {
 				state_cache_size: 0,
 				state_cache_child_ratio: None,
 				state_pruning: PruningMode::ArchiveAll,
+				override_state_pruning: false,
 				source: DatabaseSource::RocksDb { path: db_path.to_owned(), cache_size: 128 },
 				keep_blocks: KeepBlocks::All,
 				transaction_storage: TransactionStorageMode::BlockBody,
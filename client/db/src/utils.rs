@@ -66,6 +66,17 @@ pub mod meta_keys {
 	pub const CHILDREN_PREFIX: &[u8; 8] = b"children";
 }
 
+/// Statistics of a database column.
+#[derive(Debug, Clone)]
+pub struct ColumnStats {
+	/// Index of the column.
+	pub column: u32,
+	/// Number of keys in the column, estimated by RocksDb.
+	pub keys: u64,
+	/// Size of the values in the column, in bytes. Not reported by RocksDb.
+	pub size: Option<u64>,
+}
+
 /// Database metadata.
 #[derive(Debug)]
 pub struct Meta<N, H> {
@@ -223,6 +234,37 @@ pub fn open_database<Block: BlockT>(
 	open_database_at::<Block>(&config.source, db_type)
 }
 
+/// Opens the configured database read-only, along with the statistics of its columns.
+///
+/// Unlike [`open_database`], the database is neither migrated nor upgraded, and it must exist.
+pub fn open_database_read_only<Block: BlockT>(
+	config: &DatabaseSettings,
+	db_type: DatabaseType,
+) -> sp_blockchain::Result<(Arc<dyn Database<DbHash>>, Vec<ColumnStats>)> {
+	let (db, stats) = match &config.source {
+		DatabaseSource::ParityDb { path } => open_parity_db_read_only::<Block>(&path, db_type)?,
+		DatabaseSource::RocksDb { path, cache_size } =>
+			open_kvdb_rocksdb_read_only::<Block>(&path, *cache_size)?,
+		DatabaseSource::Custom(db) => (db.clone(), Vec::new()),
+		DatabaseSource::Auto { paritydb_path, rocksdb_path, cache_size } => {
+			// check if rocksdb exists first, if not, open paritydb
+			match open_kvdb_rocksdb_read_only::<Block>(&rocksdb_path, *cache_size) {
+				Ok(db) => db,
+				Err(OpenDbError::NotEnabled(_)) | Err(OpenDbError::DoesNotExist) =>
+					open_parity_db_read_only::<Block>(&paritydb_path, db_type)?,
+				Err(_) => return Err(backend_err("cannot open rocksdb. corrupted database")),
+			}
+		},
+	};
+
+	// `check_database_type` would write the type of a new database.
+	if db.get(COLUMN_META, meta_keys::TYPE).is_none() {
+		return Err(OpenDbError::DoesNotExist.into())
+	}
+	check_database_type(&*db, db_type)?;
+	Ok((db, stats))
+}
+
 fn open_database_at<Block: BlockT>(
 	source: &DatabaseSource,
 	db_type: DatabaseType,
@@ -258,6 +300,8 @@ enum OpenDbError {
 
 type OpenDbResult = Result<Arc<dyn Database<DbHash>>, OpenDbError>;
 
+type OpenDbReadOnlyResult = Result<(Arc<dyn Database<DbHash>>, Vec<ColumnStats>), OpenDbError>;
+
 impl fmt::Display for OpenDbError {
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
 		match self {
@@ -312,6 +356,23 @@ fn open_parity_db<Block: BlockT>(
 	Err(OpenDbError::NotEnabled("with-parity-db"))
 }
 
+#[cfg(feature = "with-parity-db")]
+fn open_parity_db_read_only<Block: BlockT>(
+	path: &Path,
+	db_type: DatabaseType,
+) -> OpenDbReadOnlyResult {
+	let db = crate::parity_db::open_read_only(path, db_type)?;
+	Ok(db)
+}
+
+#[cfg(not(feature = "with-parity-db"))]
+fn open_parity_db_read_only<Block: BlockT>(
+	_path: &Path,
+	_db_type: DatabaseType,
+) -> OpenDbReadOnlyResult {
+	Err(OpenDbError::NotEnabled("with-parity-db"))
+}
+
 #[cfg(any(feature = "with-kvdb-rocksdb", test))]
 fn open_kvdb_rocksdb<Block: BlockT>(
 	path: &Path,
@@ -384,6 +445,45 @@ fn open_kvdb_rocksdb<Block: BlockT>(
 	Err(OpenDbError::NotEnabled("with-kvdb-rocksdb"))
 }
 
+#[cfg(any(feature = "with-kvdb-rocksdb", test))]
+fn open_kvdb_rocksdb_read_only<Block: BlockT>(
+	path: &Path,
+	cache_size: usize,
+) -> OpenDbReadOnlyResult {
+	match crate::upgrade::check_version(&path) {
+		Ok(()) => (),
+		Err(crate::upgrade::UpgradeError::MissingDatabaseVersionFile) =>
+			return Err(OpenDbError::DoesNotExist),
+		Err(err) => return Err(io::Error::new(io::ErrorKind::Other, err.to_string()).into()),
+	}
+
+	// A secondary instance can be opened while a node uses the database, and can not write to
+	// it. It only needs a directory of its own for its logs.
+	let secondary = std::env::temp_dir().join(format!("substrate-db-{}", std::process::id()));
+	let mut db_config = kvdb_rocksdb::DatabaseConfig::with_columns(NUM_COLUMNS);
+	db_config.create_if_missing = false;
+	db_config.secondary = Some(secondary.clone());
+	let budget = cache_size / (NUM_COLUMNS as usize);
+	db_config.memory_budget = (0..NUM_COLUMNS).map(|i| (i, budget)).collect();
+
+	let db = kvdb_rocksdb::Database::open(&db_config, path);
+	// The logs of the secondary instance are not kept.
+	let _ = fs::remove_dir_all(&secondary);
+	let db = db?;
+	let stats = (0..NUM_COLUMNS)
+		.map(|column| Ok(ColumnStats { column, keys: db.num_keys(column)?, size: None }))
+		.collect::<io::Result<_>>()?;
+	Ok((sp_database::as_database(db), stats))
+}
+
+#[cfg(not(any(feature = "with-kvdb-rocksdb", test)))]
+fn open_kvdb_rocksdb_read_only<Block: BlockT>(
+	_path: &Path,
+	_cache_size: usize,
+) -> OpenDbReadOnlyResult {
+	Err(OpenDbError::NotEnabled("with-kvdb-rocksdb"))
+}
+
 /// Check database type.
 pub fn check_database_type(
 	db: &dyn Database<DbHash>,
@@ -713,6 +813,35 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn database_opens_read_only() {
+		let base_path = tempfile::TempDir::new().unwrap();
+		let source = DatabaseSource::RocksDb { path: base_path.path().join("db"), cache_size: 128 };
+		let settings = db_settings(source);
+
+		assert!(
+			open_database_read_only::<Block>(&settings, DatabaseType::Full).is_err(),
+			"A missing database should not be created.",
+		);
+		assert!(!base_path.path().join("db").exists());
+
+		{
+			let db = open_database::<Block>(&settings, DatabaseType::Full).unwrap();
+			let mut transaction = Transaction::new();
+			transaction.set(crate::columns::AUX, b"key", b"value");
+			db.commit(transaction).unwrap();
+		}
+
+		let (db, stats) = open_database_read_only::<Block>(&settings, DatabaseType::Full).unwrap();
+		assert_eq!(db.get(crate::columns::AUX, b"key"), Some(b"value".to_vec()));
+		assert_eq!(stats.len(), NUM_COLUMNS as usize);
+		assert_eq!(stats[crate::columns::AUX as usize].column, crate::columns::AUX);
+		assert!(
+			open_database_read_only::<Block>(&settings, DatabaseType::Light).is_err(),
+			"Opening a full database in light role should fail",
+		);
+	}
+
 	#[test]
 	fn number_index_key_doesnt_panic() {
 		let id = BlockId::<Block>::Number(72340207214430721);
@@ -754,6 +883,7 @@ mod tests {
 			state_cache_size: 0,
 			state_cache_child_ratio: None,
 			state_pruning: PruningMode::ArchiveAll,
+			override_state_pruning: false,
 			source,
 			keep_blocks: KeepBlocks::All,
 			transaction_storage: TransactionStorageMode::BlockBody,
@@ -46,7 +46,7 @@ pub use sp_core::traits::Externalities;
 pub use sp_version::{NativeVersion, RuntimeVersion};
 #[doc(hidden)]
 pub use sp_wasm_interface;
-pub use wasm_runtime::{read_embedded_version, WasmExecutionMethod};
+pub use wasm_runtime::{read_embedded_version, WasmExecutionMethod, DEFAULT_RUNTIME_CACHE_SIZE};
 pub use wasmi;
 
 pub use sc_executor_common::{error, sandbox};
@@ -80,6 +80,7 @@ mod tests {
 			sp_io::SubstrateHostFunctions::host_functions(),
 			8,
 			None,
+			2,
 		);
 		let res = executor
 			.uncached_call(
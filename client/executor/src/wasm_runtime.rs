@@ -137,7 +137,8 @@ impl VersionedRuntime {
 	}
 }
 
-const MAX_RUNTIMES: usize = 2;
+/// The default number of runtimes kept in the [`RuntimeCache`].
+pub const DEFAULT_RUNTIME_CACHE_SIZE: u8 = 2;
 
 /// Cache for the runtimes.
 ///
@@ -149,12 +150,14 @@ const MAX_RUNTIMES: usize = 2;
 /// the memory reset to the initial memory. So, one runtime instance is reused for every fetch
 /// request.
 ///
-/// The size of cache is equal to `MAX_RUNTIMES`.
+/// The size of cache is given by the `runtime_cache_size` passed to [`RuntimeCache::new`].
 pub struct RuntimeCache {
 	/// A cache of runtimes along with metadata.
 	///
 	/// Runtimes sorted by recent usage. The most recently used is at the front.
-	runtimes: Mutex<[Option<Arc<VersionedRuntime>>; MAX_RUNTIMES]>,
+	runtimes: Mutex<Vec<Arc<VersionedRuntime>>>,
+	/// The maximum number of runtimes kept in `runtimes`.
+	runtime_cache_size: usize,
 	/// The size of the instances cache for each runtime.
 	max_runtime_instances: usize,
 	cache_path: Option<PathBuf>,
@@ -168,8 +171,21 @@ impl RuntimeCache {
 	///
 	/// `cache_path` allows to specify an optional directory where the executor can store files
 	/// for caching.
-	pub fn new(max_runtime_instances: usize, cache_path: Option<PathBuf>) -> RuntimeCache {
-		RuntimeCache { runtimes: Default::default(), max_runtime_instances, cache_path }
+	///
+	/// `runtime_cache_size` specifies the number of different runtimes kept in the cache. It is
+	/// at least one.
+	pub fn new(
+		max_runtime_instances: usize,
+		cache_path: Option<PathBuf>,
+		runtime_cache_size: u8,
+	) -> RuntimeCache {
+		let runtime_cache_size = runtime_cache_size.max(1) as usize;
+		RuntimeCache {
+			runtimes: Mutex::new(Vec::with_capacity(runtime_cache_size)),
+			runtime_cache_size,
+			max_runtime_instances,
+			cache_path,
+		}
 	}
 
 	/// Prepares a WASM module instance and executes given function for it.
@@ -223,17 +239,11 @@ impl RuntimeCache {
 
 		let mut runtimes = self.runtimes.lock(); // this must be released prior to calling f
 		let pos = runtimes.iter().position(|r| {
-			r.as_ref().map_or(false, |r| {
-				r.wasm_method == wasm_method &&
-					r.code_hash == *code_hash &&
-					r.heap_pages == heap_pages
-			})
+			r.wasm_method == wasm_method && r.code_hash == *code_hash && r.heap_pages == heap_pages
 		});
 
 		let runtime = match pos {
-			Some(n) => runtimes[n]
-				.clone()
-				.expect("`position` only returns `Some` for entries that are `Some`"),
+			Some(n) => runtimes.remove(n),
 			None => {
 				let code = runtime_code.fetch_runtime_code().ok_or(WasmError::CodeNotFound)?;
 
@@ -270,19 +280,8 @@ impl RuntimeCache {
 		};
 
 		// Rearrange runtimes by last recently used.
-		match pos {
-			Some(0) => {},
-			Some(n) =>
-				for i in (1..n + 1).rev() {
-					runtimes.swap(i, i - 1);
-				},
-			None => {
-				runtimes[MAX_RUNTIMES - 1] = Some(runtime.clone());
-				for i in (1..MAX_RUNTIMES).rev() {
-					runtimes.swap(i, i - 1);
-				}
-			},
-		}
+		runtimes.insert(0, runtime.clone());
+		runtimes.truncate(self.runtime_cache_size);
 		drop(runtimes);
 
 		Ok(runtime.with_instance(ext, f))
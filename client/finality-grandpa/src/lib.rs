@@ -703,6 +703,7 @@ pub fn grandpa_peers_set_config() -> sc_network::config::NonDefaultSetConfig {
 			reserved_nodes: Vec::new(),
 			non_reserved_mode: sc_network::config::NonReservedPeerMode::Deny,
 		},
+		handshake_validator: None,
 	}
 }
 
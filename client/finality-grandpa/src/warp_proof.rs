@@ -31,6 +31,7 @@ use sp_runtime::{
 	traits::{Block as BlockT, Header as HeaderT, NumberFor, One},
 };
 
+use parking_lot::Mutex;
 use std::sync::Arc;
 
 /// Warp proof processing error.
@@ -87,7 +88,6 @@ impl<Block: BlockT> WarpSyncProof<Block> {
 	where
 		Backend: ClientBackend<Block>,
 	{
-		// TODO: cache best response (i.e. the one with lowest begin_number)
 		let blockchain = backend.blockchain();
 
 		let begin_number = blockchain
@@ -235,6 +235,13 @@ where
 {
 	backend: Arc<Backend>,
 	authority_set: SharedAuthoritySet<Block::Hash, NumberFor<Block>>,
+	/// The encoded partial proof with the lowest starting block generated so far, along with the
+	/// hash and number of that starting block.
+	///
+	/// A proof that is not finished only covers past authority set changes and therefore never
+	/// changes. Most warp syncing nodes start from genesis, so caching the lowest one avoids
+	/// regenerating the largest proof on every request.
+	cached_proof: Mutex<Option<(Block::Hash, NumberFor<Block>, Vec<u8>)>>,
 }
 
 impl<Block: BlockT, Backend: ClientBackend<Block>> NetworkProvider<Block, Backend>
@@ -246,7 +253,7 @@ where
 		backend: Arc<Backend>,
 		authority_set: SharedAuthoritySet<Block::Hash, NumberFor<Block>>,
 	) -> Self {
-		NetworkProvider { backend, authority_set }
+		NetworkProvider { backend, authority_set, cached_proof: Mutex::new(None) }
 	}
 }
 
@@ -259,13 +266,36 @@ where
 		&self,
 		start: Block::Hash,
 	) -> Result<EncodedProof, Box<dyn std::error::Error + Send + Sync>> {
+		if let Some((hash, _, encoded)) = &*self.cached_proof.lock() {
+			if *hash == start {
+				return Ok(EncodedProof(encoded.clone()))
+			}
+		}
+
 		let proof = WarpSyncProof::<Block>::generate(
 			&*self.backend,
 			start,
 			&self.authority_set.authority_set_changes(),
 		)
 		.map_err(Box::new)?;
-		Ok(EncodedProof(proof.encode()))
+		let encoded = proof.encode();
+
+		if !proof.is_finished {
+			let start_number = self
+				.backend
+				.blockchain()
+				.block_number_from_id(&BlockId::Hash(start))
+				.map_err(|e| Box::new(Error::Client(e)))?;
+
+			if let Some(start_number) = start_number {
+				let mut cached_proof = self.cached_proof.lock();
+				if cached_proof.as_ref().map_or(true, |(_, number, _)| start_number < *number) {
+					*cached_proof = Some((start, start_number, encoded.clone()));
+				}
+			}
+		}
+
+		Ok(EncodedProof(encoded))
 	}
 
 	fn verify(
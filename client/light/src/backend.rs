@@ -259,6 +259,12 @@ where
 	fn get_import_lock(&self) -> &RwLock<()> {
 		&self.import_lock
 	}
+
+	fn pin_block(&self, _hash: &Block::Hash) -> ClientResult<()> {
+		Ok(())
+	}
+
+	fn unpin_block(&self, _hash: &Block::Hash) {}
 }
 
 impl<S, Block> RemoteBackend<Block> for Backend<S, HashFor<Block>>
@@ -0,0 +1,215 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Encoding of the requests and responses of the block request protocol.
+//!
+//! By default, blocks are requested using the protobuf messages answered by
+//! [`crate::block_request_handler::BlockRequestHandler`]. Networks wanting to distribute blocks
+//! differently can provide their own [`BlockRelay`] in [`crate::config::Params::block_relay`],
+//! together with a matching server answering on
+//! [`crate::config::Params::block_request_protocol_config`].
+
+use crate::{
+	config::RequestResponseConfig, schema::v1::block_request::FromBlock as ProtoFromBlock,
+};
+
+use codec::{Decode, DecodeAll, Encode};
+use futures::future::BoxFuture;
+use prost::Message as _;
+use sp_runtime::traits::Block as BlockT;
+
+pub use crate::protocol::message::{
+	BlockAttributes, BlockData, BlockRequest, Direction, FromBlock,
+};
+
+/// Error returned by a [`BlockRelay`].
+pub type BlockRelayError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Client side of the block request protocol.
+pub trait BlockRelay<B: BlockT>: Send + Sync {
+	/// Encodes `request` into the payload sent to the remote.
+	fn encode_request(&self, request: &BlockRequest<B>) -> Result<Vec<u8>, BlockRelayError>;
+
+	/// Decodes the `response` sent by the remote to `request`.
+	fn decode_response(
+		&self,
+		request: &BlockRequest<B>,
+		response: &[u8],
+	) -> Result<Vec<BlockData<B>>, BlockRelayError>;
+}
+
+/// Custom block relay to use instead of the default one.
+pub struct BlockRelayParams<B: BlockT> {
+	/// Client side of the protocol.
+	pub relay: Box<dyn BlockRelay<B>>,
+	/// Request-response configuration of the protocol.
+	pub request_response_config: RequestResponseConfig,
+	/// Task answering the requests received on `request_response_config`, if any.
+	pub server: Option<BoxFuture<'static, ()>>,
+}
+
+/// [`BlockRelay`] using the protobuf messages of
+/// [`crate::block_request_handler::BlockRequestHandler`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ProtobufBlockRelay;
+
+impl<B: BlockT> BlockRelay<B> for ProtobufBlockRelay {
+	fn encode_request(&self, request: &BlockRequest<B>) -> Result<Vec<u8>, BlockRelayError> {
+		let request = crate::schema::v1::BlockRequest {
+			fields: request.fields.to_be_u32(),
+			from_block: match &request.from {
+				FromBlock::Hash(h) => Some(ProtoFromBlock::Hash(h.encode())),
+				FromBlock::Number(n) => Some(ProtoFromBlock::Number(n.encode())),
+			},
+			to_block: request.to.as_ref().map(|h| h.encode()).unwrap_or_default(),
+			direction: request.direction as i32,
+			max_blocks: request.max.unwrap_or(0),
+			support_multiple_justifications: true,
+		};
+
+		let mut buf = Vec::with_capacity(request.encoded_len());
+		request.encode(&mut buf)?;
+		Ok(buf)
+	}
+
+	fn decode_response(
+		&self,
+		request: &BlockRequest<B>,
+		response: &[u8],
+	) -> Result<Vec<BlockData<B>>, BlockRelayError> {
+		let response = crate::schema::v1::BlockResponse::decode(response)?;
+
+		let blocks = response
+			.blocks
+			.into_iter()
+			.map(|block_data| {
+				Ok(BlockData::<B> {
+					hash: Decode::decode(&mut block_data.hash.as_ref())?,
+					header: if !block_data.header.is_empty() {
+						Some(Decode::decode(&mut block_data.header.as_ref())?)
+					} else {
+						None
+					},
+					body: if request.fields.contains(BlockAttributes::BODY) {
+						Some(
+							block_data
+								.body
+								.iter()
+								.map(|body| Decode::decode(&mut body.as_ref()))
+								.collect::<Result<Vec<_>, _>>()?,
+						)
+					} else {
+						None
+					},
+					indexed_body: if request.fields.contains(BlockAttributes::INDEXED_BODY) {
+						Some(block_data.indexed_body)
+					} else {
+						None
+					},
+					receipt: if !block_data.receipt.is_empty() {
+						Some(block_data.receipt)
+					} else {
+						None
+					},
+					message_queue: if !block_data.message_queue.is_empty() {
+						Some(block_data.message_queue)
+					} else {
+						None
+					},
+					justification: if !block_data.justification.is_empty() {
+						Some(block_data.justification)
+					} else if block_data.is_empty_justification {
+						Some(Vec::new())
+					} else {
+						None
+					},
+					justifications: if !block_data.justifications.is_empty() {
+						Some(DecodeAll::decode_all(&mut block_data.justifications.as_ref())?)
+					} else {
+						None
+					},
+				})
+			})
+			.collect::<Result<Vec<_>, codec::Error>>()?;
+
+		Ok(blocks)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_core::H256;
+	use substrate_test_runtime_client::runtime::Block;
+
+	fn request() -> BlockRequest<Block> {
+		BlockRequest::<Block> {
+			id: 0,
+			fields: BlockAttributes::HEADER | BlockAttributes::BODY,
+			from: FromBlock::Number(10),
+			to: None,
+			direction: Direction::Descending,
+			max: Some(8),
+		}
+	}
+
+	#[test]
+	fn protobuf_request_is_encoded() {
+		let encoded = BlockRelay::<Block>::encode_request(&ProtobufBlockRelay, &request()).unwrap();
+		let decoded = crate::schema::v1::BlockRequest::decode(&encoded[..]).unwrap();
+
+		assert_eq!(decoded.fields, request().fields.to_be_u32());
+		assert_eq!(decoded.from_block, Some(ProtoFromBlock::Number(10u64.encode())));
+		assert!(decoded.to_block.is_empty());
+		assert_eq!(decoded.direction, Direction::Descending as i32);
+		assert_eq!(decoded.max_blocks, 8);
+	}
+
+	#[test]
+	fn protobuf_response_is_decoded() {
+		let hash = H256::repeat_byte(1);
+		let response = crate::schema::v1::BlockResponse {
+			blocks: vec![crate::schema::v1::BlockData {
+				hash: hash.encode(),
+				body: Vec::new(),
+				is_empty_justification: true,
+				..Default::default()
+			}],
+		};
+		let mut encoded = Vec::new();
+		response.encode(&mut encoded).unwrap();
+
+		let blocks =
+			BlockRelay::<Block>::decode_response(&ProtobufBlockRelay, &request(), &encoded)
+				.unwrap();
+
+		assert_eq!(blocks.len(), 1);
+		assert_eq!(blocks[0].hash, hash);
+		assert_eq!(blocks[0].header, None);
+		assert_eq!(blocks[0].body, Some(Vec::new()));
+		assert_eq!(blocks[0].justification, Some(Vec::new()));
+		assert_eq!(blocks[0].justifications, None);
+	}
+
+	#[test]
+	fn malformed_response_is_rejected() {
+		assert!(
+			BlockRelay::<Block>::decode_response(&ProtobufBlockRelay, &request(), &[0xff]).is_err()
+		);
+	}
+}
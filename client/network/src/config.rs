@@ -22,6 +22,7 @@
 //! See the documentation of [`Params`].
 
 pub use crate::{
+	block_relay::BlockRelay,
 	chain::Client,
 	on_demand_layer::{AlwaysBadChecker, OnDemand},
 	request_responses::{
@@ -121,6 +122,12 @@ pub struct Params<B: BlockT, H: ExHashT> {
 	/// incoming requests.
 	pub block_request_protocol_config: RequestResponseConfig,
 
+	/// Encoding of the block requests sent using `block_request_protocol_config`.
+	///
+	/// If `None`, [`crate::block_relay::ProtobufBlockRelay`] is used, which is the encoding
+	/// answered by [`crate::block_request_handler::BlockRequestHandler`].
+	pub block_relay: Option<Box<dyn BlockRelay<B>>>,
+
 	/// Request response configuration for the light client request protocol.
 	///
 	/// Can be constructed either via
@@ -580,6 +587,9 @@ pub struct NonDefaultSetConfig {
 	pub max_notification_size: u64,
 	/// Base configuration.
 	pub set_config: SetConfig,
+	/// If set, called with the handshake of every substream opened on this protocol. Substreams
+	/// whose handshake is rejected are closed and the remote gets a reputation penalty.
+	pub handshake_validator: Option<HandshakeValidator>,
 }
 
 impl NonDefaultSetConfig {
@@ -595,6 +605,7 @@ impl NonDefaultSetConfig {
 				reserved_nodes: Vec::new(),
 				non_reserved_mode: NonReservedPeerMode::Deny,
 			},
+			handshake_validator: None,
 		}
 	}
 
@@ -609,6 +620,38 @@ impl NonDefaultSetConfig {
 	pub fn add_reserved(&mut self, peer: MultiaddrWithPeerId) {
 		self.set_config.reserved_nodes.push(peer);
 	}
+
+	/// Sets the callback used to validate the handshakes received on this protocol.
+	pub fn set_handshake_validator(
+		&mut self,
+		validator: impl Fn(&PeerId, &[u8]) -> bool + Send + Sync + 'static,
+	) {
+		self.handshake_validator = Some(HandshakeValidator::new(validator));
+	}
+}
+
+/// Callback deciding whether to accept a substream based on the handshake sent by the remote.
+///
+/// Returns `true` if the handshake is acceptable.
+#[derive(Clone)]
+pub struct HandshakeValidator(Arc<dyn Fn(&PeerId, &[u8]) -> bool + Send + Sync>);
+
+impl HandshakeValidator {
+	/// Wraps the given callback.
+	pub fn new(validator: impl Fn(&PeerId, &[u8]) -> bool + Send + Sync + 'static) -> Self {
+		Self(Arc::new(validator))
+	}
+
+	/// Returns `true` if `handshake`, received from `peer_id`, is acceptable.
+	pub fn validate(&self, peer_id: &PeerId, handshake: &[u8]) -> bool {
+		(self.0)(peer_id, handshake)
+	}
+}
+
+impl fmt::Debug for HandshakeValidator {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_tuple("HandshakeValidator").finish()
+	}
 }
 
 /// Configuration for the transport layer.
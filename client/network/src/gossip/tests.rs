@@ -136,6 +136,7 @@ fn build_test_full_node(network_config: config::NetworkConfiguration)
 		),
 		metrics_registry: None,
 		block_request_protocol_config,
+		block_relay: None,
 		state_request_protocol_config,
 		light_client_request_protocol_config,
 		warp_sync: None,
@@ -168,7 +169,8 @@ fn build_nodes_one_proto()
 				notifications_protocol: PROTOCOL_NAME,
 				fallback_names: Vec::new(),
 				max_notification_size: 1024 * 1024,
-				set_config: Default::default()
+				set_config: Default::default(),
+				handshake_validator: None,
 			}
 		],
 		listen_addresses: vec![listen_addr.clone()],
@@ -190,6 +192,7 @@ fn build_nodes_one_proto()
 					}],
 					.. Default::default()
 				},
+				handshake_validator: None,
 			}
 		],
 		transport: config::TransportConfig::MemoryOnly,
@@ -257,6 +257,7 @@ mod transport;
 mod utils;
 
 pub mod bitswap;
+pub mod block_relay;
 pub mod block_request_handler;
 pub mod config;
 pub mod error;
@@ -274,8 +275,8 @@ pub use protocol::{
 	PeerInfo,
 };
 pub use service::{
-	IfDisconnected, NetworkService, NetworkWorker, NotificationSender, NotificationSenderReady,
-	OutboundFailure, RequestFailure,
+	IfDisconnected, NetworkService, NetworkWorker, NotificationEvent, NotificationSender,
+	NotificationSenderReady, NotificationService, OutboundFailure, RequestFailure, Signature,
 };
 
 pub use sc_peerset::ReputationChange;
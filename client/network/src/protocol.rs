@@ -17,6 +17,7 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
+	block_relay::BlockRelay,
 	chain::Client,
 	config::{self, ProtocolId, WarpSyncProvider},
 	error,
@@ -178,6 +179,11 @@ pub struct Protocol<B: BlockT> {
 	behaviour: Notifications,
 	/// List of notifications protocols that have been registered.
 	notification_protocols: Vec<Cow<'static, str>>,
+	/// Encodes the block requests and decodes their responses.
+	block_relay: Box<dyn BlockRelay<B>>,
+	/// Handshake validators of the notifications protocols, in the same order as
+	/// `notification_protocols`.
+	handshake_validators: Vec<Option<config::HandshakeValidator>>,
 	/// If we receive a new "substream open" event that contains an invalid handshake, we ask the
 	/// inner layer to force-close the substream. Force-closing the substream will generate a
 	/// "substream closed" event. This is a problem: since we can't propagate the "substream open"
@@ -288,6 +294,7 @@ impl<B: BlockT> Protocol<B> {
 		block_announce_validator: Box<dyn BlockAnnounceValidator<B> + Send>,
 		metrics_registry: Option<&Registry>,
 		warp_sync_provider: Option<Arc<dyn WarpSyncProvider<B>>>,
+		block_relay: Box<dyn BlockRelay<B>>,
 	) -> error::Result<(Protocol<B>, sc_peerset::PeersetHandle, Vec<(PeerId, Multiaddr)>)> {
 		let info = chain.info();
 		let sync = ChainSync::new(
@@ -434,6 +441,12 @@ impl<B: BlockT> Protocol<B> {
 				.iter()
 				.map(|s| s.notifications_protocol.clone())
 				.collect(),
+			block_relay,
+			handshake_validators: network_config
+				.extra_sets
+				.iter()
+				.map(|s| s.handshake_validator.clone())
+				.collect(),
 			bad_handshake_substreams: Default::default(),
 			metrics: if let Some(r) = metrics_registry {
 				Some(Metrics::register(r)?)
@@ -481,6 +494,11 @@ impl<B: BlockT> Protocol<B> {
 		self.behaviour.peerset_debug_info()
 	}
 
+	/// Returns the reputation of the given peer.
+	pub fn peer_reputation(&mut self, peer_id: &PeerId) -> i32 {
+		self.behaviour.peer_reputation(peer_id)
+	}
+
 	/// Returns the number of peers we're connected to.
 	pub fn num_connected_peers(&self) -> usize {
 		self.peers.values().count()
@@ -553,7 +571,7 @@ impl<B: BlockT> Protocol<B> {
 		who: PeerId,
 		request: message::BlockRequest<B>,
 	) -> CustomMessageOutcome<B> {
-		prepare_block_request::<B>(&mut self.peers, who, request)
+		prepare_block_request::<B>(&mut self.peers, &*self.block_relay, who, request)
 	}
 
 	/// Called by peer when it is disconnecting.
@@ -590,71 +608,8 @@ impl<B: BlockT> Protocol<B> {
 		&mut self,
 		peer_id: PeerId,
 		request: message::BlockRequest<B>,
-		response: crate::schema::v1::BlockResponse,
+		blocks: Vec<message::BlockData<B>>,
 	) -> CustomMessageOutcome<B> {
-		let blocks = response
-			.blocks
-			.into_iter()
-			.map(|block_data| {
-				Ok(message::BlockData::<B> {
-					hash: Decode::decode(&mut block_data.hash.as_ref())?,
-					header: if !block_data.header.is_empty() {
-						Some(Decode::decode(&mut block_data.header.as_ref())?)
-					} else {
-						None
-					},
-					body: if request.fields.contains(message::BlockAttributes::BODY) {
-						Some(
-							block_data
-								.body
-								.iter()
-								.map(|body| Decode::decode(&mut body.as_ref()))
-								.collect::<Result<Vec<_>, _>>()?,
-						)
-					} else {
-						None
-					},
-					indexed_body: if request.fields.contains(message::BlockAttributes::INDEXED_BODY)
-					{
-						Some(block_data.indexed_body)
-					} else {
-						None
-					},
-					receipt: if !block_data.receipt.is_empty() {
-						Some(block_data.receipt)
-					} else {
-						None
-					},
-					message_queue: if !block_data.message_queue.is_empty() {
-						Some(block_data.message_queue)
-					} else {
-						None
-					},
-					justification: if !block_data.justification.is_empty() {
-						Some(block_data.justification)
-					} else if block_data.is_empty_justification {
-						Some(Vec::new())
-					} else {
-						None
-					},
-					justifications: if !block_data.justifications.is_empty() {
-						Some(DecodeAll::decode_all(&mut block_data.justifications.as_ref())?)
-					} else {
-						None
-					},
-				})
-			})
-			.collect::<Result<Vec<_>, codec::Error>>();
-
-		let blocks = match blocks {
-			Ok(blocks) => blocks,
-			Err(err) => {
-				debug!(target: "sync", "Failed to decode block response from {}: {}", peer_id, err);
-				self.peerset_handle.report_peer(peer_id, rep::BAD_MESSAGE);
-				return CustomMessageOutcome::None
-			},
-		};
-
 		let block_response = message::BlockResponse::<B> { id: request.id, blocks };
 
 		let blocks_range = || match (
@@ -1069,6 +1024,7 @@ impl<B: BlockT> Protocol<B> {
 				Ok((id, req)) => {
 					self.pending_messages.push_back(prepare_block_request(
 						&mut self.peers,
+						&*self.block_relay,
 						id,
 						req,
 					));
@@ -1225,30 +1181,29 @@ impl<B: BlockT> Protocol<B> {
 
 fn prepare_block_request<B: BlockT>(
 	peers: &mut HashMap<PeerId, Peer<B>>,
+	block_relay: &dyn BlockRelay<B>,
 	who: PeerId,
 	request: message::BlockRequest<B>,
 ) -> CustomMessageOutcome<B> {
+	let encoded_request = match block_relay.encode_request(&request) {
+		Ok(encoded_request) => encoded_request,
+		Err(err) => {
+			log::warn!(target: "sync", "Failed to encode block request {:?}: {}", request, err);
+			return CustomMessageOutcome::None
+		},
+	};
+
 	let (tx, rx) = oneshot::channel();
 
 	if let Some(ref mut peer) = peers.get_mut(&who) {
-		peer.request = Some((PeerRequest::Block(request.clone()), rx));
+		peer.request = Some((PeerRequest::Block(request), rx));
 	}
 
-	let request = crate::schema::v1::BlockRequest {
-		fields: request.fields.to_be_u32(),
-		from_block: match request.from {
-			message::FromBlock::Hash(h) =>
-				Some(crate::schema::v1::block_request::FromBlock::Hash(h.encode())),
-			message::FromBlock::Number(n) =>
-				Some(crate::schema::v1::block_request::FromBlock::Number(n.encode())),
-		},
-		to_block: request.to.map(|h| h.encode()).unwrap_or_default(),
-		direction: request.direction as i32,
-		max_blocks: request.max.unwrap_or(0),
-		support_multiple_justifications: true,
-	};
-
-	CustomMessageOutcome::BlockRequest { target: who, request, pending_response: tx }
+	CustomMessageOutcome::BlockRequest {
+		target: who,
+		request: encoded_request,
+		pending_response: tx,
+	}
 }
 
 fn prepare_state_request<B: BlockT>(
@@ -1311,7 +1266,7 @@ pub enum CustomMessageOutcome<B: BlockT> {
 	/// A new block request must be emitted.
 	BlockRequest {
 		target: PeerId,
-		request: crate::schema::v1::BlockRequest,
+		request: Vec<u8>,
 		pending_response: oneshot::Sender<Result<Vec<u8>, RequestFailure>>,
 	},
 	/// A new storage request must be emitted.
@@ -1407,24 +1362,22 @@ impl<B: BlockT> NetworkBehaviour for Protocol<B> {
 						let (req, _) = peer.request.take().unwrap();
 						match req {
 							PeerRequest::Block(req) => {
-								let protobuf_response =
-									match crate::schema::v1::BlockResponse::decode(&resp[..]) {
-										Ok(proto) => proto,
-										Err(e) => {
-											debug!(
-												target: "sync",
-												"Failed to decode block response from peer {:?}: {:?}.",
-												id,
-												e
-											);
-											self.peerset_handle.report_peer(*id, rep::BAD_MESSAGE);
-											self.behaviour
-												.disconnect_peer(id, HARDCODED_PEERSETS_SYNC);
-											continue
-										},
-									};
-
-								finished_block_requests.push((id.clone(), req, protobuf_response));
+								let blocks = match self.block_relay.decode_response(&req, &resp) {
+									Ok(blocks) => blocks,
+									Err(e) => {
+										debug!(
+											target: "sync",
+											"Failed to decode block response from peer {:?}: {}.",
+											id,
+											e
+										);
+										self.peerset_handle.report_peer(*id, rep::BAD_MESSAGE);
+										self.behaviour.disconnect_peer(id, HARDCODED_PEERSETS_SYNC);
+										continue
+									},
+								};
+
+								finished_block_requests.push((*id, req, blocks));
 							},
 							PeerRequest::State => {
 								let protobuf_response =
@@ -1503,8 +1456,8 @@ impl<B: BlockT> NetworkBehaviour for Protocol<B> {
 				}
 			}
 		}
-		for (id, req, protobuf_response) in finished_block_requests {
-			let ev = self.on_block_response(id, req, protobuf_response);
+		for (id, req, blocks) in finished_block_requests {
+			let ev = self.on_block_response(id, req, blocks);
 			self.pending_messages.push_back(ev);
 		}
 		for (id, protobuf_response) in finished_state_requests {
@@ -1521,7 +1474,8 @@ impl<B: BlockT> NetworkBehaviour for Protocol<B> {
 		}
 
 		for (id, request) in self.sync.block_requests() {
-			let event = prepare_block_request(&mut self.peers, id.clone(), request);
+			let event =
+				prepare_block_request(&mut self.peers, &*self.block_relay, id.clone(), request);
 			self.pending_messages.push_back(event);
 		}
 		if let Some((id, request)) = self.sync.state_request() {
@@ -1529,7 +1483,7 @@ impl<B: BlockT> NetworkBehaviour for Protocol<B> {
 			self.pending_messages.push_back(event);
 		}
 		for (id, request) in self.sync.justification_requests() {
-			let event = prepare_block_request(&mut self.peers, id, request);
+			let event = prepare_block_request(&mut self.peers, &*self.block_relay, id, request);
 			self.pending_messages.push_back(event);
 		}
 		if let Some((id, request)) = self.sync.warp_sync_request() {
@@ -1635,10 +1589,23 @@ impl<B: BlockT> NetworkBehaviour for Protocol<B> {
 						},
 					}
 				} else {
+					let handshake_rejected = self.handshake_validators
+						[usize::from(set_id) - NUM_HARDCODED_PEERSETS]
+						.as_ref()
+						.map_or(false, |validator| {
+							!validator.validate(&peer_id, &received_handshake)
+						});
 					match (
 						message::Roles::decode_all(&received_handshake[..]),
 						self.peers.get(&peer_id),
 					) {
+						_ if handshake_rejected => {
+							debug!(target: "sync", "Handshake of {} rejected by validator", peer_id);
+							self.bad_handshake_substreams.insert((peer_id, set_id));
+							self.behaviour.disconnect_peer(&peer_id, set_id);
+							self.peerset_handle.report_peer(peer_id, rep::BAD_MESSAGE);
+							CustomMessageOutcome::None
+						},
 						(Ok(roles), _) => CustomMessageOutcome::NotificationStreamOpened {
 							remote: peer_id,
 							protocol: self.notification_protocols
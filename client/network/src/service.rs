@@ -30,6 +30,7 @@
 use crate::{
 	behaviour::{self, Behaviour, BehaviourOut},
 	bitswap::Bitswap,
+	block_relay::ProtobufBlockRelay,
 	config::{parse_str_addr, Params, TransportConfig},
 	discovery::DiscoveryConfig,
 	error::Error,
@@ -56,6 +57,7 @@ use libp2p::{
 		either::EitherError,
 		upgrade, ConnectedPoint, Executor,
 	},
+	identity::{error::SigningError, Keypair},
 	kad::record,
 	multiaddr,
 	ping::handler::PingFailure,
@@ -87,14 +89,19 @@ use std::{
 		Arc,
 	},
 	task::Poll,
+	time::Duration,
 };
 
 pub use behaviour::{
 	IfDisconnected, InboundFailure, OutboundFailure, RequestFailure, ResponseFailure,
 };
+pub use notification_service::{NotificationEvent, NotificationService};
+pub use signature::Signature;
 
 mod metrics;
+mod notification_service;
 mod out_events;
+mod signature;
 #[cfg(test)]
 mod tests;
 
@@ -108,6 +115,8 @@ pub struct NetworkService<B: BlockT + 'static, H: ExHashT> {
 	is_major_syncing: Arc<AtomicBool>,
 	/// Local copy of the `PeerId` of the local node.
 	local_peer_id: PeerId,
+	/// The `KeyPair` that defines the `PeerId` of the local node.
+	local_identity: Keypair,
 	/// Bandwidth logging system. Can be queried to know the average bandwidth consumed.
 	bandwidth: Arc<transport::BandwidthSinks>,
 	/// Peerset manager (PSM); manages the reputation of nodes and indicates the network which
@@ -210,6 +219,7 @@ impl<B: BlockT + 'static, H: ExHashT> NetworkWorker<B, H> {
 			params.block_announce_validator,
 			params.metrics_registry.as_ref(),
 			warp_sync_provider,
+			params.block_relay.unwrap_or_else(|| Box::new(ProtobufBlockRelay)),
 		)?;
 
 		// List of multiaddresses that we know in the network.
@@ -334,7 +344,7 @@ impl<B: BlockT + 'static, H: ExHashT> NetworkWorker<B, H> {
 				};
 
 				transport::build_transport(
-					local_identity,
+					local_identity.clone(),
 					config_mem,
 					params.network_config.yamux_window_size,
 					yamux_maximum_buffer_size,
@@ -423,6 +433,7 @@ impl<B: BlockT + 'static, H: ExHashT> NetworkWorker<B, H> {
 			is_major_syncing: is_major_syncing.clone(),
 			peerset: peerset_handle,
 			local_peer_id,
+			local_identity,
 			to_worker,
 			peers_notifications_sinks: peers_notifications_sinks.clone(),
 			notifications_sizes_metric: metrics
@@ -449,6 +460,7 @@ impl<B: BlockT + 'static, H: ExHashT> NetworkWorker<B, H> {
 			from_service,
 			light_client_rqs: params.on_demand.and_then(|od| od.extract_receiver()),
 			event_streams: out_events::OutChannels::new(params.metrics_registry.as_ref())?,
+			notification_services: HashMap::new(),
 			peers_notifications_sinks,
 			tx_handler_controller,
 			metrics,
@@ -677,6 +689,14 @@ impl<B: BlockT + 'static, H: ExHashT> NetworkWorker<B, H> {
 	pub fn reserved_peers(&self) -> impl Iterator<Item = &PeerId> {
 		self.network_service.behaviour().user_protocol().reserved_peers()
 	}
+
+	/// Returns the reputation of the given peer.
+	pub fn peer_reputation(&mut self, peer_id: &PeerId) -> i32 {
+		self.network_service
+			.behaviour_mut()
+			.user_protocol_mut()
+			.peer_reputation(peer_id)
+	}
 }
 
 impl<B: BlockT + 'static, H: ExHashT> NetworkService<B, H> {
@@ -685,6 +705,14 @@ impl<B: BlockT + 'static, H: ExHashT> NetworkService<B, H> {
 		&self.local_peer_id
 	}
 
+	/// Signs the message with the `KeyPair` that defines the local `PeerId`.
+	pub fn sign_with_local_identity(
+		&self,
+		msg: impl AsRef<[u8]>,
+	) -> Result<Signature, SigningError> {
+		Signature::sign_message(msg, &self.local_identity)
+	}
+
 	/// Set authorized peers.
 	///
 	/// Need a better solution to manage authorized peers, but now just use reserved peers for
@@ -775,9 +803,9 @@ impl<B: BlockT + 'static, H: ExHashT> NetworkService<B, H> {
 	/// a receiver. With a `NotificationSender` at hand, sending a notification is done in two
 	/// steps:
 	///
-	/// 1.  [`NotificationSender::ready`] is used to wait for the sender to become ready
+	/// 1. [`NotificationSender::ready`] is used to wait for the sender to become ready
 	/// for another notification, yielding a [`NotificationSenderReady`] token.
-	/// 2.  [`NotificationSenderReady::send`] enqueues the notification for sending. This operation
+	/// 2. [`NotificationSenderReady::send`] enqueues the notification for sending. This operation
 	/// can only fail if the underlying notification substream or connection has suddenly closed.
 	///
 	/// An error is returned by [`NotificationSenderReady::send`] if there exists no open
@@ -876,6 +904,22 @@ impl<B: BlockT + 'static, H: ExHashT> NetworkService<B, H> {
 		rx
 	}
 
+	/// Returns a handle dedicated to the given notifications protocol.
+	///
+	/// The handle only reports the events of this protocol and sends notifications with
+	/// backpressure. The protocol must have been registered in
+	/// [`crate::config::NetworkConfiguration::extra_sets`].
+	pub fn notification_service(
+		self: &Arc<Self>,
+		protocol: Cow<'static, str>,
+	) -> NotificationService<B, H> {
+		let (tx, rx) = tracing_unbounded("mpsc_notification_service");
+		let _ = self
+			.to_worker
+			.unbounded_send(ServiceToWorkerMsg::NotificationService(protocol.clone(), tx));
+		NotificationService::new(self.clone(), protocol, rx)
+	}
+
 	/// Sends a single targeted request to a specific peer. On success, returns the response of
 	/// the peer.
 	///
@@ -1008,6 +1052,25 @@ impl<B: BlockT + 'static, H: ExHashT> NetworkService<B, H> {
 		self.peerset.report_peer(who, cost_benefit);
 	}
 
+	/// Returns the reputation of the given peer.
+	///
+	/// Returns an error if the `NetworkWorker` is no longer running.
+	pub async fn peer_reputation(&self, who: PeerId) -> Result<i32, ()> {
+		self.peerset.clone().peer_reputation(who).await
+	}
+
+	/// Ban the given peer for the given duration, disconnecting from it.
+	///
+	/// While banned, connections to and from the peer are refused.
+	pub fn ban_peer(&self, who: PeerId, duration: Duration) {
+		self.peerset.ban_peer(who, duration);
+	}
+
+	/// Lift the ban of the given peer.
+	pub fn unban_peer(&self, who: PeerId) {
+		self.peerset.unban_peer(who);
+	}
+
 	/// Disconnect from a node as soon as possible.
 	///
 	/// This triggers the same effects as if the connection had closed itself spontaneously.
@@ -1406,6 +1469,7 @@ enum ServiceToWorkerMsg<B: BlockT, H: ExHashT> {
 	RemoveFromPeersSet(Cow<'static, str>, PeerId),
 	SyncFork(Vec<PeerId>, B::Hash, NumberFor<B>),
 	EventStream(out_events::Sender),
+	NotificationService(Cow<'static, str>, TracingUnboundedSender<NotificationEvent>),
 	Request {
 		target: PeerId,
 		protocol: Cow<'static, str>,
@@ -1446,6 +1510,9 @@ pub struct NetworkWorker<B: BlockT + 'static, H: ExHashT> {
 	light_client_rqs: Option<TracingUnboundedReceiver<light_client_requests::sender::Request<B>>>,
 	/// Senders for events that happen on the network.
 	event_streams: out_events::OutChannels,
+	/// Senders for the events of each notifications protocol, one per [`NotificationService`].
+	notification_services:
+		HashMap<Cow<'static, str>, Vec<TracingUnboundedSender<NotificationEvent>>>,
 	/// Prometheus network metrics.
 	metrics: Option<Metrics>,
 	/// The `PeerId`'s of all boot nodes.
@@ -1579,6 +1646,8 @@ impl<B: BlockT + 'static, H: ExHashT> Future for NetworkWorker<B, H> {
 					.user_protocol_mut()
 					.set_sync_fork_request(peer_ids, &hash, number),
 				ServiceToWorkerMsg::EventStream(sender) => this.event_streams.push(sender),
+				ServiceToWorkerMsg::NotificationService(protocol, sender) =>
+					this.notification_services.entry(protocol).or_default().push(sender),
 				ServiceToWorkerMsg::Request {
 					target,
 					protocol,
@@ -1750,6 +1819,15 @@ impl<B: BlockT + 'static, H: ExHashT> Future for NetworkWorker<B, H> {
 							.insert((remote.clone(), protocol.clone()), notifications_sink);
 						debug_assert!(_previous_value.is_none());
 					}
+					send_notification_event(
+						&mut this.notification_services,
+						&protocol,
+						NotificationEvent::StreamOpened {
+							peer: remote.clone(),
+							role: role.clone(),
+							negotiated_fallback: negotiated_fallback.clone(),
+						},
+					);
 					this.event_streams.send(Event::NotificationStreamOpened {
 						remote,
 						protocol,
@@ -1804,6 +1882,11 @@ impl<B: BlockT + 'static, H: ExHashT> Future for NetworkWorker<B, H> {
 							.with_label_values(&[&protocol[..]])
 							.inc();
 					}
+					send_notification_event(
+						&mut this.notification_services,
+						&protocol,
+						NotificationEvent::StreamClosed { peer: remote.clone() },
+					);
 					this.event_streams.send(Event::NotificationStreamClosed {
 						remote: remote.clone(),
 						protocol: protocol.clone(),
@@ -1827,6 +1910,16 @@ impl<B: BlockT + 'static, H: ExHashT> Future for NetworkWorker<B, H> {
 								.observe(message.len() as f64);
 						}
 					}
+					for (protocol, message) in &messages {
+						send_notification_event(
+							&mut this.notification_services,
+							protocol,
+							NotificationEvent::NotificationReceived {
+								peer: remote.clone(),
+								notification: message.clone(),
+							},
+						);
+					}
 					this.event_streams.send(Event::NotificationsReceived { remote, messages });
 				},
 				Poll::Ready(SwarmEvent::Behaviour(BehaviourOut::SyncConnected(remote))) => {
@@ -2105,6 +2198,18 @@ impl<B: BlockT + 'static, H: ExHashT> Future for NetworkWorker<B, H> {
 
 impl<B: BlockT + 'static, H: ExHashT> Unpin for NetworkWorker<B, H> {}
 
+/// Sends `event` to the [`NotificationService`]s of `protocol`, forgetting the ones that have been
+/// dropped.
+fn send_notification_event(
+	services: &mut HashMap<Cow<'static, str>, Vec<TracingUnboundedSender<NotificationEvent>>>,
+	protocol: &Cow<'static, str>,
+	event: NotificationEvent,
+) {
+	if let Some(senders) = services.get_mut(protocol) {
+		senders.retain(|sender| sender.unbounded_send(event.clone()).is_ok());
+	}
+}
+
 /// The libp2p swarm, customized for our needs.
 type Swarm<B> = libp2p::swarm::Swarm<Behaviour<B>>;
 
@@ -0,0 +1,114 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Per-protocol handle for sending and receiving notifications.
+
+use crate::{
+	protocol::event::ObservedRole,
+	service::{NetworkService, NotificationSenderError},
+	ExHashT,
+};
+
+use bytes::Bytes;
+use futures::prelude::*;
+use libp2p::PeerId;
+use sc_utils::mpsc::TracingUnboundedReceiver;
+use sp_runtime::traits::Block as BlockT;
+use std::{borrow::Cow, sync::Arc};
+
+/// Event emitted by a [`NotificationService`].
+#[derive(Debug, Clone)]
+pub enum NotificationEvent {
+	/// A substream has been opened with the given peer.
+	StreamOpened {
+		/// Peer we opened the substream with.
+		peer: PeerId,
+		/// Role of the remote.
+		role: ObservedRole,
+		/// Name of the fallback protocol, if it was used instead of the main one.
+		negotiated_fallback: Option<Cow<'static, str>>,
+	},
+	/// The substream with the given peer has been closed. Always matches a previous
+	/// `StreamOpened` event.
+	StreamClosed {
+		/// Peer we closed the substream with.
+		peer: PeerId,
+	},
+	/// Received a notification from the given peer.
+	NotificationReceived {
+		/// Peer that sent the notification.
+		peer: PeerId,
+		/// The notification.
+		notification: Bytes,
+	},
+}
+
+/// Handle to a single notifications protocol.
+///
+/// The `NetworkWorker` delivers the events of its protocol on a channel dedicated to this handle,
+/// and notifications are sent with backpressure through [`NetworkService::notification_sender`].
+pub struct NotificationService<B: BlockT + 'static, H: ExHashT> {
+	service: Arc<NetworkService<B, H>>,
+	protocol: Cow<'static, str>,
+	events: TracingUnboundedReceiver<NotificationEvent>,
+}
+
+impl<B: BlockT + 'static, H: ExHashT> NotificationService<B, H> {
+	pub(super) fn new(
+		service: Arc<NetworkService<B, H>>,
+		protocol: Cow<'static, str>,
+		events: TracingUnboundedReceiver<NotificationEvent>,
+	) -> Self {
+		Self { service, protocol, events }
+	}
+
+	/// Returns the name of the protocol this handle is bound to.
+	pub fn protocol(&self) -> &Cow<'static, str> {
+		&self.protocol
+	}
+
+	/// Queues a notification for the given peer, dropping it if the peer is too slow.
+	///
+	/// See [`NetworkService::write_notification`].
+	pub fn send_sync_notification(&self, peer: PeerId, notification: impl Into<Vec<u8>>) {
+		self.service
+			.write_notification(peer, self.protocol.clone(), notification.into());
+	}
+
+	/// Sends a notification to the given peer, waiting until there is room for it in the
+	/// buffer of the substream.
+	///
+	/// Returns an error if no substream is open with the peer for this protocol, or if it closes
+	/// while waiting.
+	pub async fn send_async_notification(
+		&self,
+		peer: PeerId,
+		notification: impl Into<Vec<u8>>,
+	) -> Result<(), NotificationSenderError> {
+		let sender = self.service.notification_sender(peer, self.protocol.clone())?;
+		let result = sender.ready().await?.send(notification);
+		result
+	}
+
+	/// Returns the next event related to this protocol.
+	///
+	/// Returns `None` once the `NetworkWorker` has been shut down.
+	pub async fn next_event(&mut self) -> Option<NotificationEvent> {
+		self.events.next().await
+	}
+}
@@ -0,0 +1,67 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Signing with the network identity of the local node.
+
+use libp2p::{
+	identity::{error::SigningError, Keypair, PublicKey},
+	PeerId,
+};
+
+/// A message signed with the network identity of a node.
+#[derive(Debug, Clone)]
+pub struct Signature {
+	/// The public key of the network identity that signed the message.
+	pub public_key: PublicKey,
+	/// The signature of the message.
+	pub bytes: Vec<u8>,
+}
+
+impl Signature {
+	/// Sign `message` with `keypair`.
+	pub fn sign_message(
+		message: impl AsRef<[u8]>,
+		keypair: &Keypair,
+	) -> Result<Self, SigningError> {
+		let public_key = keypair.public();
+		let bytes = keypair.sign(message.as_ref())?;
+		Ok(Signature { public_key, bytes })
+	}
+
+	/// Verify that the signature is valid for `message` and was made by `peer_id`.
+	pub fn verify(&self, message: impl AsRef<[u8]>, peer_id: &PeerId) -> bool {
+		let signer = self.public_key.clone().into_peer_id();
+		&signer == peer_id && self.public_key.verify(message.as_ref(), &self.bytes)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn signature_is_verified_against_signer() {
+		let keypair = Keypair::generate_ed25519();
+		let peer_id = keypair.public().into_peer_id();
+		let signature = Signature::sign_message(b"message", &keypair).unwrap();
+
+		assert!(signature.verify(b"message", &peer_id));
+		assert!(!signature.verify(b"other message", &peer_id));
+		assert!(!signature.verify(b"message", &PeerId::random()));
+	}
+}
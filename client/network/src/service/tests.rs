@@ -20,10 +20,12 @@ use crate::{
 	block_request_handler::BlockRequestHandler, config,
 	light_client_requests::handler::LightClientRequestHandler,
 	state_request_handler::StateRequestHandler, Event, NetworkService, NetworkWorker,
+	NotificationEvent, NotificationService,
 };
 
 use futures::prelude::*;
 use libp2p::PeerId;
+use parking_lot::Mutex;
 use sp_runtime::traits::{Block as BlockT, Header as _};
 use std::{borrow::Cow, sync::Arc, time::Duration};
 use substrate_test_runtime_client::{TestClientBuilder, TestClientBuilderExt as _};
@@ -125,6 +127,7 @@ fn build_test_full_node(
 		),
 		metrics_registry: None,
 		block_request_protocol_config,
+		block_relay: None,
 		state_request_protocol_config,
 		light_client_request_protocol_config,
 		warp_sync: None,
@@ -160,6 +163,7 @@ fn build_nodes_one_proto() -> (
 			fallback_names: Vec::new(),
 			max_notification_size: 1024 * 1024,
 			set_config: Default::default(),
+			handshake_validator: None,
 		}],
 		listen_addresses: vec![listen_addr.clone()],
 		transport: config::TransportConfig::MemoryOnly,
@@ -178,6 +182,7 @@ fn build_nodes_one_proto() -> (
 				}],
 				..Default::default()
 			},
+			handshake_validator: None,
 		}],
 		listen_addresses: vec![],
 		transport: config::TransportConfig::MemoryOnly,
@@ -351,6 +356,7 @@ fn lots_of_incoming_peers_works() {
 			fallback_names: Vec::new(),
 			max_notification_size: 1024 * 1024,
 			set_config: config::SetConfig { in_peers: u32::MAX, ..Default::default() },
+			handshake_validator: None,
 		}],
 		transport: config::TransportConfig::MemoryOnly,
 		..config::NetworkConfiguration::new_local()
@@ -376,6 +382,7 @@ fn lots_of_incoming_peers_works() {
 					}],
 					..Default::default()
 				},
+				handshake_validator: None,
 			}],
 			transport: config::TransportConfig::MemoryOnly,
 			..config::NetworkConfiguration::new_local()
@@ -483,6 +490,7 @@ fn fallback_name_working() {
 			fallback_names: vec![PROTOCOL_NAME],
 			max_notification_size: 1024 * 1024,
 			set_config: Default::default(),
+			handshake_validator: None,
 		}],
 		listen_addresses: vec![listen_addr.clone()],
 		transport: config::TransportConfig::MemoryOnly,
@@ -501,6 +509,7 @@ fn fallback_name_working() {
 				}],
 				..Default::default()
 			},
+			handshake_validator: None,
 		}],
 		listen_addresses: vec![],
 		transport: config::TransportConfig::MemoryOnly,
@@ -539,6 +548,113 @@ fn fallback_name_working() {
 	});
 }
 
+#[test]
+fn notification_service_works() {
+	// Node 1 sends notifications to node 2 through their `NotificationService`s.
+
+	const TOTAL_NOTIFS: usize = 100;
+
+	let (node1, events_stream1, node2, events_stream2) = build_nodes_one_proto();
+	let node1_id = *node1.local_peer_id();
+	let node2_id = *node2.local_peer_id();
+	let mut service1 = NotificationService::new(node1, PROTOCOL_NAME, events_stream1.boxed());
+	let mut service2 = NotificationService::new(node2, PROTOCOL_NAME, events_stream2.boxed());
+
+	let receiver = async_std::task::spawn(async move {
+		let mut received_notifications = 0;
+
+		while received_notifications < TOTAL_NOTIFS {
+			match service2.next_event().await.unwrap() {
+				NotificationEvent::StreamOpened { peer, .. } => assert_eq!(peer, node1_id),
+				NotificationEvent::StreamClosed { .. } => panic!(),
+				NotificationEvent::NotificationReceived { peer, notification } => {
+					assert_eq!(peer, node1_id);
+					assert_eq!(notification, format!("hello #{}", received_notifications));
+					received_notifications += 1;
+				},
+			}
+		}
+	});
+
+	async_std::task::block_on(async move {
+		// Wait for the `StreamOpened`.
+		loop {
+			if let NotificationEvent::StreamOpened { peer, .. } =
+				service1.next_event().await.unwrap()
+			{
+				assert_eq!(peer, node2_id);
+				break
+			}
+		}
+
+		for num in 0..TOTAL_NOTIFS {
+			service1
+				.send_async_notification(node2_id, format!("hello #{}", num))
+				.await
+				.unwrap();
+		}
+
+		receiver.await;
+	});
+}
+
+#[test]
+fn handshake_validator_is_called() {
+	// Node 1 validates the handshakes it receives. Checks that the handshake of node 2 went
+	// through the validator before the substream has been reported as open.
+
+	let listen_addr = config::build_multiaddr![Memory(rand::random::<u64>())];
+	let validated = Arc::new(Mutex::new(Vec::new()));
+
+	let mut set_config = config::NonDefaultSetConfig::new(PROTOCOL_NAME, 1024 * 1024);
+	set_config.allow_non_reserved(1, 1);
+	set_config.set_handshake_validator({
+		let validated = validated.clone();
+		move |peer_id, _| {
+			validated.lock().push(*peer_id);
+			true
+		}
+	});
+
+	let (node1, mut events_stream1) = build_test_full_node(config::NetworkConfiguration {
+		extra_sets: vec![set_config],
+		listen_addresses: vec![listen_addr.clone()],
+		transport: config::TransportConfig::MemoryOnly,
+		..config::NetworkConfiguration::new_local()
+	});
+
+	let (node2, _) = build_test_full_node(config::NetworkConfiguration {
+		extra_sets: vec![config::NonDefaultSetConfig {
+			set_config: config::SetConfig {
+				reserved_nodes: vec![config::MultiaddrWithPeerId {
+					multiaddr: listen_addr,
+					peer_id: *node1.local_peer_id(),
+				}],
+				..Default::default()
+			},
+			..config::NonDefaultSetConfig::new(PROTOCOL_NAME, 1024 * 1024)
+		}],
+		listen_addresses: vec![],
+		transport: config::TransportConfig::MemoryOnly,
+		..config::NetworkConfiguration::new_local()
+	});
+
+	async_std::task::block_on(async move {
+		// Wait for the `NotificationStreamOpened`.
+		loop {
+			match events_stream1.next().await.unwrap() {
+				Event::NotificationStreamOpened { remote, .. } => {
+					assert_eq!(remote, *node2.local_peer_id());
+					break
+				},
+				_ => {},
+			};
+		}
+	});
+
+	assert!(validated.lock().contains(node2.local_peer_id()));
+}
+
 #[test]
 #[should_panic(expected = "don't match the transport")]
 fn ensure_listen_addresses_consistent_with_transport_memory() {
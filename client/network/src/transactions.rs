@@ -156,6 +156,7 @@ impl TransactionsHandlerPrototype {
 				reserved_nodes: Vec::new(),
 				non_reserved_mode: config::NonReservedPeerMode::Deny,
 			},
+			handshake_validator: None,
 		}
 	}
 
@@ -776,6 +776,7 @@ where
 				fallback_names: Vec::new(),
 				max_notification_size: 1024 * 1024,
 				set_config: Default::default(),
+				handshake_validator: None,
 			})
 			.collect();
 		if let Some(connect_to) = config.connect_to_peers {
@@ -831,6 +832,7 @@ where
 				.unwrap_or_else(|| Box::new(DefaultBlockAnnounceValidator)),
 			metrics_registry: None,
 			block_request_protocol_config,
+			block_relay: None,
 			state_request_protocol_config,
 			light_client_request_protocol_config,
 			warp_sync: None,
@@ -921,6 +923,7 @@ where
 			block_announce_validator: Box::new(DefaultBlockAnnounceValidator),
 			metrics_registry: None,
 			block_request_protocol_config,
+			block_relay: None,
 			state_request_protocol_config,
 			light_client_request_protocol_config,
 			warp_sync: None,
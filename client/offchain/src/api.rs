@@ -21,7 +21,9 @@ use std::{collections::HashSet, convert::TryFrom, str::FromStr, sync::Arc, threa
 use crate::NetworkProvider;
 use codec::{Decode, Encode};
 use futures::Future;
-pub use http::SharedClient;
+pub use http::{
+	SharedClient, DEFAULT_MAX_CONCURRENT_REQUESTS as DEFAULT_MAX_CONCURRENT_HTTP_REQUESTS,
+};
 use sc_network::{Multiaddr, PeerId};
 use sp_core::{
 	offchain::{
@@ -45,13 +45,29 @@ use std::{
 	task::{Context, Poll},
 };
 
+/// The default maximum number of HTTP requests a single offchain worker may have in flight.
+pub const DEFAULT_MAX_CONCURRENT_REQUESTS: usize = 64;
+
 /// Wrapper struct used for keeping the hyper_rustls client running.
 #[derive(Clone)]
-pub struct SharedClient(Arc<HyperClient<HttpsConnector<client::HttpConnector>, Body>>);
+pub struct SharedClient {
+	client: Arc<HyperClient<HttpsConnector<client::HttpConnector>, Body>>,
+	max_concurrent_requests: usize,
+}
 
 impl SharedClient {
 	pub fn new() -> Self {
-		Self(Arc::new(HyperClient::builder().build(HttpsConnector::with_native_roots())))
+		Self {
+			client: Arc::new(HyperClient::builder().build(HttpsConnector::with_native_roots())),
+			max_concurrent_requests: DEFAULT_MAX_CONCURRENT_REQUESTS,
+		}
+	}
+
+	/// Set the maximum number of requests a single offchain worker may have in flight.
+	///
+	/// Starting more requests than this fails until earlier requests complete.
+	pub fn set_max_concurrent_requests(&mut self, max: usize) {
+		self.max_concurrent_requests = max;
 	}
 }
 
@@ -67,10 +83,11 @@ pub fn http(shared_client: SharedClient) -> (HttpApi, HttpWorker) {
 		// writing runtime code with hardcoded IDs.
 		next_id: HttpRequestId(rand::random::<u16>() % 2000),
 		requests: FnvHashMap::default(),
+		max_requests: shared_client.max_concurrent_requests,
 	};
 
 	let engine =
-		HttpWorker { to_api, from_api, http_client: shared_client.0, requests: Vec::new() };
+		HttpWorker { to_api, from_api, http_client: shared_client.client, requests: Vec::new() };
 
 	(api, engine)
 }
@@ -89,6 +106,8 @@ pub struct HttpApi {
 	next_id: HttpRequestId,
 	/// List of HTTP requests in preparation or in progress.
 	requests: FnvHashMap<HttpRequestId, HttpApiRequest>,
+	/// Maximum number of entries in `requests`.
+	max_requests: usize,
 }
 
 /// One active request within `HttpApi`.
@@ -130,6 +149,11 @@ struct HttpApiRequestRp {
 impl HttpApi {
 	/// Mimics the corresponding method in the offchain API.
 	pub fn request_start(&mut self, method: &str, uri: &str) -> Result<HttpRequestId, ()> {
+		if self.requests.len() >= self.max_requests {
+			error!("Too many concurrent offchain worker HTTP requests");
+			return Err(())
+		}
+
 		// Start by building the prototype of the request.
 		// We do this first so that we don't touch anything in `self` if building the prototype
 		// fails.
@@ -781,6 +805,21 @@ mod tests {
 		};
 	}
 
+	#[test]
+	fn request_start_too_many_requests() {
+		let mut hyper_client = SHARED_CLIENT.clone();
+		hyper_client.set_max_concurrent_requests(2);
+		let (mut api, _worker) = http(hyper_client);
+
+		let first = api.request_start("GET", "http://localhost").unwrap();
+		api.request_start("GET", "http://localhost").unwrap();
+		assert!(api.request_start("GET", "http://localhost").is_err());
+
+		// Dropping a request frees up a slot.
+		api.requests.remove(&first);
+		api.request_start("GET", "http://localhost").unwrap();
+	}
+
 	#[test]
 	fn request_add_header_invalid_call() {
 		let (mut api, addr) = build_api_server!();
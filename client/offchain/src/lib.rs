@@ -54,7 +54,7 @@ use threadpool::ThreadPool;
 
 mod api;
 
-pub use api::Db as OffchainDb;
+pub use api::{Db as OffchainDb, DEFAULT_MAX_CONCURRENT_HTTP_REQUESTS};
 pub use sp_offchain::{OffchainWorkerApi, STORAGE_PREFIX};
 
 /// NetworkProvider provides [`OffchainWorkers`] with all necessary hooks into the
@@ -103,6 +103,13 @@ impl<Client, Block: traits::Block> OffchainWorkers<Client, Block> {
 			shared_client,
 		}
 	}
+
+	/// Set the maximum number of HTTP requests a single offchain worker may have in flight.
+	///
+	/// Defaults to [`DEFAULT_MAX_CONCURRENT_HTTP_REQUESTS`].
+	pub fn set_max_concurrent_http_requests(&mut self, max: usize) {
+		self.shared_client.set_max_concurrent_requests(max);
+	}
 }
 
 impl<Client, Block: traits::Block> fmt::Debug for OffchainWorkers<Client, Block> {
@@ -66,6 +66,8 @@ enum Action {
 	AddToPeersSet(SetId, PeerId),
 	RemoveFromPeersSet(SetId, PeerId),
 	PeerReputation(PeerId, oneshot::Sender<i32>),
+	BanPeer(PeerId, Duration),
+	UnbanPeer(PeerId),
 }
 
 /// Identifier of a set in the peerset.
@@ -176,6 +178,21 @@ impl PeersetHandle {
 		// The channel can only be closed if the peerset no longer exists.
 		rx.await.map_err(|_| ())
 	}
+
+	/// Bans the given peer for the given duration, disconnecting it from all the sets.
+	///
+	/// The reputation of the peer stays at its minimum while it is banned, and is reset once
+	/// the ban is over.
+	pub fn ban_peer(&self, peer_id: PeerId, duration: Duration) {
+		let _ = self.tx.unbounded_send(Action::BanPeer(peer_id, duration));
+	}
+
+	/// Lifts the ban of the given peer, resetting its reputation.
+	///
+	/// Has no effect if the peer is not banned.
+	pub fn unban_peer(&self, peer_id: PeerId) {
+		let _ = self.tx.unbounded_send(Action::UnbanPeer(peer_id));
+	}
 }
 
 /// Message that can be sent by the peer set manager (PSM).
@@ -270,6 +287,8 @@ pub struct Peerset {
 	/// Next time to do a periodic call to `alloc_slots` with all sets. This is done once per
 	/// second, to match the period of the reputation updates.
 	next_periodic_alloc_slots: Delay,
+	/// Peers explicitly banned, and when their ban is over.
+	banned: HashMap<PeerId, Instant>,
 }
 
 impl Peerset {
@@ -297,6 +316,7 @@ impl Peerset {
 				created: now,
 				latest_time_update: now,
 				next_periodic_alloc_slots: Delay::new(Duration::new(0, 0)),
+				banned: HashMap::new(),
 			}
 		};
 
@@ -470,11 +490,41 @@ impl Peerset {
 		let _ = pending_response.send(reputation.reputation());
 	}
 
+	fn on_ban_peer(&mut self, peer_id: PeerId, duration: Duration) {
+		debug!(target: "peerset", "Banning {} for {:?}", peer_id, duration);
+		self.banned.insert(peer_id, Instant::now() + duration);
+		// Reporting a fatal change disconnects the peer from all the sets.
+		self.on_report_peer(peer_id, ReputationChange::new_fatal("Banned"));
+	}
+
+	fn on_unban_peer(&mut self, peer_id: PeerId) {
+		if self.banned.remove(&peer_id).is_none() {
+			return
+		}
+
+		debug!(target: "peerset", "Unbanning {}", peer_id);
+		self.data.peer_reputation(peer_id).set_reputation(0);
+		for set_index in 0..self.data.num_sets() {
+			self.alloc_slots(SetId(set_index));
+		}
+	}
+
 	/// Updates the value of `self.latest_time_update` and performs all the updates that happen
 	/// over time, such as reputation increases for staying connected.
 	fn update_time(&mut self) {
 		let now = Instant::now();
 
+		// Lift the bans that are over.
+		let data = &mut self.data;
+		self.banned.retain(|peer_id, until| {
+			if *until > now {
+				return true
+			}
+			debug!(target: "peerset", "Ban of {} is over", peer_id);
+			data.peer_reputation(*peer_id).set_reputation(0);
+			false
+		});
+
 		// We basically do `(now - self.latest_update).as_secs()`, except that by the way we do it
 		// we know that we're not going to miss seconds because of rounding to integers.
 		let secs_diff = {
@@ -490,6 +540,11 @@ impl Peerset {
 		// empirically determine a value of `k` that looks correct.
 		for _ in 0..secs_diff {
 			for peer_id in self.data.peers().cloned().collect::<Vec<_>>() {
+				// The reputation of banned peers stays at its minimum.
+				if self.banned.contains_key(&peer_id) {
+					continue
+				}
+
 				// We use `k = 0.98`, so we divide by `50`. With that value, it takes 34.3 seconds
 				// to reduce the reputation by half.
 				fn reput_tick(reput: i32) -> i32 {
@@ -716,6 +771,12 @@ impl Peerset {
 		})
 	}
 
+	/// Returns the reputation of the given peer.
+	pub fn peer_reputation(&mut self, peer_id: &PeerId) -> i32 {
+		self.update_time();
+		self.data.peer_reputation(*peer_id).reputation()
+	}
+
 	/// Returns the number of peers that we have discovered.
 	pub fn num_discovered_peers(&self) -> usize {
 		self.data.peers().len()
@@ -762,6 +823,8 @@ impl Stream for Peerset {
 					self.on_remove_from_peers_set(sets_name, peer_id),
 				Action::PeerReputation(peer_id, pending_response) =>
 					self.on_peer_reputation(peer_id, pending_response),
+				Action::BanPeer(peer_id, duration) => self.on_ban_peer(peer_id, duration),
+				Action::UnbanPeer(peer_id) => self.on_unban_peer(peer_id),
 			}
 		}
 	}
@@ -1000,4 +1063,47 @@ mod tests {
 
 		futures::executor::block_on(fut);
 	}
+
+	#[test]
+	fn test_peerset_ban_and_unban() {
+		let (mut peerset, handle) = Peerset::from_config(PeersetConfig {
+			sets: vec![SetConfig {
+				in_peers: 25,
+				out_peers: 25,
+				bootnodes: vec![],
+				reserved_nodes: Default::default(),
+				reserved_only: false,
+			}],
+		});
+
+		let peer_id = PeerId::random();
+		handle.ban_peer(peer_id, Duration::from_secs(3600));
+
+		let fut = futures::future::poll_fn(move |cx| {
+			// We need one polling for the message to be processed.
+			assert_eq!(Stream::poll_next(Pin::new(&mut peerset), cx), Poll::Pending);
+
+			// Check that an incoming connection from that node gets refused, even after its
+			// reputation would have recovered.
+			thread::sleep(Duration::from_millis(1500));
+			peerset.incoming(SetId::from(0), peer_id, IncomingIndex(1));
+			if let Poll::Ready(msg) = Stream::poll_next(Pin::new(&mut peerset), cx) {
+				assert_eq!(msg.unwrap(), Message::Reject(IncomingIndex(1)));
+			} else {
+				panic!()
+			}
+
+			// Once unbanned, the peerset connects to the node again.
+			handle.unban_peer(peer_id);
+			if let Poll::Ready(msg) = Stream::poll_next(Pin::new(&mut peerset), cx) {
+				assert_eq!(msg.unwrap(), Message::Connect { set_id: SetId::from(0), peer_id });
+			} else {
+				panic!()
+			}
+
+			Poll::Ready(())
+		});
+
+		futures::executor::block_on(fut);
+	}
 }
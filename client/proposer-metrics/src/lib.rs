@@ -19,7 +19,7 @@
 //! Prometheus basic proposer metrics.
 
 use prometheus_endpoint::{
-	register, Gauge, Histogram, HistogramOpts, PrometheusError, Registry, U64,
+	register, CounterVec, Gauge, Histogram, HistogramOpts, Opts, PrometheusError, Registry, U64,
 };
 
 /// Optional shareable link to basic authorship metrics.
@@ -42,11 +42,20 @@ impl MetricsLink {
 	}
 }
 
+/// The reason why proposing a block ended.
+pub enum EndProposingReason {
+	NoMoreTransactions,
+	HitDeadline,
+	HitBlockSizeLimit,
+	HitBlockWeightLimit,
+}
+
 /// Authorship metrics.
 #[derive(Clone)]
 pub struct Metrics {
 	pub block_constructed: Histogram,
 	pub number_of_transactions: Gauge<U64>,
+	pub end_proposing_reason: CounterVec<U64>,
 }
 
 impl Metrics {
@@ -66,6 +75,28 @@ impl Metrics {
 				)?,
 				registry,
 			)?,
+			end_proposing_reason: register(
+				CounterVec::new(
+					Opts::new(
+						"proposer_end_proposal_reason",
+						"The reason why the block proposing was ended. This doesn't include errors.",
+					),
+					&["reason"],
+				)?,
+				registry,
+			)?,
 		})
 	}
+
+	/// Report the reason why the proposing ended.
+	pub fn report_end_proposing_reason(&self, reason: EndProposingReason) {
+		let reason = match reason {
+			EndProposingReason::HitDeadline => "hit_deadline",
+			EndProposingReason::NoMoreTransactions => "no_more_transactions",
+			EndProposingReason::HitBlockSizeLimit => "hit_block_size_limit",
+			EndProposingReason::HitBlockWeightLimit => "hit_block_weight_limit",
+		};
+
+		self.end_proposing_reason.with_label_values(&[reason]).inc();
+	}
 }
@@ -0,0 +1,180 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Substrate archive API helpers.
+
+use serde::{Deserialize, Serialize};
+use sp_core::{
+	storage::{StorageData, StorageKey},
+	Bytes,
+};
+
+/// The result of a runtime call.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MethodResult {
+	/// Whether the call succeeded.
+	pub success: bool,
+	/// The SCALE-encoded output of the call, if it succeeded.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub value: Option<Bytes>,
+	/// The reason of the failure, if the call failed.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub error: Option<String>,
+}
+
+impl MethodResult {
+	/// A successful call returning `value`.
+	pub fn ok(value: impl Into<Bytes>) -> Self {
+		Self { success: true, value: Some(value.into()), error: None }
+	}
+
+	/// A failed call.
+	pub fn err(error: impl Into<String>) -> Self {
+		Self { success: false, value: None, error: Some(error.into()) }
+	}
+}
+
+/// What to query about a storage key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum StorageQueryType {
+	/// The value of the key.
+	Value,
+	/// The hash of the value of the key.
+	Hash,
+	/// The Merkle value of the closest descendant of the key in the trie.
+	ClosestDescendantMerkleValue,
+	/// The values of the keys starting with the key.
+	DescendantsValues,
+	/// The hashes of the values of the keys starting with the key.
+	DescendantsHashes,
+}
+
+impl StorageQueryType {
+	/// Whether the query is about the descendants of the key.
+	pub fn is_descendants_query(&self) -> bool {
+		matches!(self, Self::DescendantsValues | Self::DescendantsHashes)
+	}
+}
+
+/// A storage query.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageQuery {
+	/// The queried key.
+	pub key: StorageKey,
+	/// What to query about the key.
+	#[serde(rename = "type")]
+	pub query_type: StorageQueryType,
+	/// For descendants queries, only keys after this one are returned.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub pagination_start_key: Option<StorageKey>,
+}
+
+/// The result of a storage query for a single key.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum StorageResultType<Hash> {
+	/// The value of the key.
+	Value(StorageData),
+	/// The hash of the value of the key.
+	Hash(Hash),
+	/// The Merkle value of the closest descendant of the key.
+	ClosestDescendantMerkleValue(Bytes),
+}
+
+/// The result of a storage query.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageResult<Hash> {
+	/// The key the result is about.
+	pub key: StorageKey,
+	/// The result.
+	#[serde(flatten)]
+	pub result: StorageResultType<Hash>,
+}
+
+/// The results of the storage queries of an `archive_unstable_storage` call.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchiveStorageResult<Hash> {
+	/// The results, in the order of the queries.
+	pub result: Vec<StorageResult<Hash>>,
+	/// The number of queries at the end of the list which were not processed.
+	pub discarded_items: usize,
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn should_serialize_method_result() {
+		assert_eq!(
+			::serde_json::to_string(&MethodResult::ok(vec![0u8])).unwrap(),
+			r#"{"success":true,"value":"0x00"}"#,
+		);
+		assert_eq!(
+			::serde_json::to_string(&MethodResult::err("failed")).unwrap(),
+			r#"{"success":false,"error":"failed"}"#,
+		);
+	}
+
+	#[test]
+	fn should_deserialize_storage_query() {
+		let query: StorageQuery =
+			::serde_json::from_str(r#"{"key":"0x01","type":"descendantsValues"}"#).unwrap();
+		assert_eq!(
+			query,
+			StorageQuery {
+				key: StorageKey(vec![1]),
+				query_type: StorageQueryType::DescendantsValues,
+				pagination_start_key: None,
+			}
+		);
+
+		let query: StorageQuery = ::serde_json::from_str(
+			r#"{"key":"0x01","type":"closestDescendantMerkleValue","paginationStartKey":"0x0102"}"#,
+		)
+		.unwrap();
+		assert_eq!(query.query_type, StorageQueryType::ClosestDescendantMerkleValue);
+		assert_eq!(query.pagination_start_key, Some(StorageKey(vec![1, 2])));
+	}
+
+	#[test]
+	fn should_serialize_storage_result() {
+		let result = ArchiveStorageResult {
+			result: vec![
+				StorageResult {
+					key: StorageKey(vec![1]),
+					result: StorageResultType::Value(StorageData(vec![2])),
+				},
+				StorageResult {
+					key: StorageKey(vec![1]),
+					result: StorageResultType::Hash("0x03".to_string()),
+				},
+			],
+			discarded_items: 1,
+		};
+		assert_eq!(
+			::serde_json::to_string(&result).unwrap(),
+			r#"{"result":[{"key":"0x01","value":"0x02"},{"key":"0x01","hash":"0x03"}],"discardedItems":1}"#,
+		);
+	}
+}
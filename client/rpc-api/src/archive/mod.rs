@@ -0,0 +1,66 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Substrate archive API, following the `archive` functions of the JSON-RPC interface
+//! specification.
+//!
+//! The archive API gives access to the blocks and state of the past, as far as the node keeps
+//! them. Every query is bounded: storage queries only process a limited number of items, and
+//! descendants queries are paginated with `paginationStartKey`.
+
+pub mod error;
+pub mod helpers;
+
+use self::{
+	error::Result,
+	helpers::{ArchiveStorageResult, MethodResult, StorageQuery},
+};
+use jsonrpc_derive::rpc;
+use sp_core::{storage::PrefixedStorageKey, Bytes};
+
+pub use self::gen_client::Client as ArchiveClient;
+
+/// Substrate archive API
+#[rpc]
+pub trait ArchiveApi<Hash> {
+	/// Get the hashes of the blocks at the given height.
+	///
+	/// There is at most one such block up to the finalized height, and possibly several forks
+	/// above it.
+	#[rpc(name = "archive_unstable_hashByHeight")]
+	fn hash_by_height(&self, height: u64) -> Result<Vec<Hash>>;
+
+	/// Get the SCALE-encoded extrinsics of a block, or `None` if the body is not available.
+	#[rpc(name = "archive_unstable_body")]
+	fn body(&self, hash: Hash) -> Result<Option<Vec<Bytes>>>;
+
+	/// Call the runtime `function` with `call_parameters` at the state of a block.
+	#[rpc(name = "archive_unstable_call")]
+	fn call(&self, hash: Hash, function: String, call_parameters: Bytes) -> Result<MethodResult>;
+
+	/// Query the storage of a block, or of one of its child tries.
+	///
+	/// Items beyond the processing limit are not processed, and are reported as discarded.
+	#[rpc(name = "archive_unstable_storage")]
+	fn storage(
+		&self,
+		hash: Hash,
+		items: Vec<StorageQuery>,
+		child_trie: Option<PrefixedStorageKey>,
+	) -> Result<ArchiveStorageResult<Hash>>;
+}
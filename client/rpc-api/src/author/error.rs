@@ -89,6 +89,8 @@ const UNSUPPORTED_KEY_TYPE: i64 = POOL_INVALID_TX + 7;
 /// The transaction was not included to the pool since it is unactionable,
 /// it is not propagable and the local node does not author blocks.
 const POOL_UNACTIONABLE: i64 = POOL_INVALID_TX + 8;
+/// The sender of the transaction has reached its limit of transactions in the pool.
+const POOL_SENDER_LIMIT_REACHED: i64 = POOL_INVALID_TX + 9;
 
 impl From<Error> for rpc::Error {
 	fn from(e: Error) -> Self {
@@ -156,6 +158,13 @@ impl From<Error> for rpc::Error {
 					 the local node does not author blocks".into(),
 				),
 			},
+			Error::Pool(PoolError::SenderLimitReached) => rpc::Error {
+				code: rpc::ErrorCode::ServerError(POOL_SENDER_LIMIT_REACHED),
+				message: "Sender Limit Reached".into(),
+				data: Some(
+					"The sender of the transaction has too many transactions in the pool".into(),
+				),
+			},
 			Error::UnsupportedKeyType => rpc::Error {
 				code: rpc::ErrorCode::ServerError(UNSUPPORTED_KEY_TYPE),
 				message: "Unknown key type crypto" .into(),
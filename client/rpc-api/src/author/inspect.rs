@@ -0,0 +1,46 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2019-2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Transaction pool inspection helpers for author RPC module.
+
+use serde::{Deserialize, Serialize};
+use sp_core::Bytes;
+
+/// A transaction in the pool, as reported by the inspection RPCs.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PoolTransaction<Hash> {
+	/// The hash of the extrinsic.
+	pub hash: Hash,
+	/// Raw extrinsic bytes.
+	pub extrinsic: Bytes,
+	/// Priority of the transaction, determining its position in the ready queue.
+	pub priority: u64,
+	/// Block number until which the transaction stays valid.
+	pub valid_till: u64,
+	/// Tags required by the transaction.
+	pub requires: Vec<Bytes>,
+	/// Tags provided by the transaction.
+	pub provides: Vec<Bytes>,
+	/// Whether the transaction is propagated to other peers.
+	pub propagate: bool,
+	/// Required tags that no transaction in the pool provides yet.
+	///
+	/// Always empty for ready transactions.
+	pub missing_tags: Vec<Bytes>,
+}
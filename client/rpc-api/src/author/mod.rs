@@ -20,6 +20,7 @@
 
 pub mod error;
 pub mod hash;
+pub mod inspect;
 
 use self::error::{FutureResult, Result};
 use jsonrpc_derive::rpc;
@@ -72,6 +73,15 @@ pub trait AuthorApi<Hash, BlockHash> {
 		bytes_or_hash: Vec<hash::ExtrinsicOrHash<Hash>>,
 	) -> Result<Vec<Hash>>;
 
+	/// Returns the ready transactions in the order they would be included in a block,
+	/// highest priority first.
+	#[rpc(name = "author_inspectReady")]
+	fn inspect_ready(&self) -> Result<Vec<inspect::PoolTransaction<Hash>>>;
+
+	/// Returns the future transactions, along with the tags each of them is waiting for.
+	#[rpc(name = "author_inspectFuture")]
+	fn inspect_future(&self) -> Result<Vec<inspect::PoolTransaction<Hash>>>;
+
 	/// Submit an extrinsic to watch.
 	///
 	/// See [`TransactionStatus`](sc_transaction_pool_api::TransactionStatus) for details on
@@ -0,0 +1,59 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Chain head RPC module errors.
+
+use jsonrpc_core as rpc;
+
+/// Chain head RPC Result type.
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Chain head RPC errors.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+	/// The provided block hash is not pinned by the follow subscription.
+	#[error("Invalid block hash")]
+	InvalidBlock,
+	/// The follow subscription does not exist, or has been stopped.
+	#[error("Invalid follow subscription")]
+	InvalidSubscription,
+	/// Client error.
+	#[error("Client error: {}", .0)]
+	Client(#[from] Box<dyn std::error::Error + Send>),
+}
+
+/// Base code for all chain head errors.
+const BASE_ERROR: i64 = 6000;
+
+impl From<Error> for rpc::Error {
+	fn from(e: Error) -> Self {
+		match e {
+			Error::InvalidBlock => rpc::Error {
+				code: rpc::ErrorCode::ServerError(BASE_ERROR + 1),
+				message: format!("{}", e),
+				data: None,
+			},
+			Error::InvalidSubscription => rpc::Error {
+				code: rpc::ErrorCode::ServerError(BASE_ERROR + 2),
+				message: format!("{}", e),
+				data: None,
+			},
+			e => crate::errors::internal(e),
+		}
+	}
+}
@@ -0,0 +1,196 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Events emitted by the chain head RPC subscriptions.
+
+use serde::{Deserialize, Serialize};
+use sp_version::RuntimeVersion;
+
+/// The runtime specification of a block.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuntimeVersionEvent {
+	/// The runtime version.
+	pub spec: RuntimeVersion,
+}
+
+/// The runtime of a block, or the reason it could not be obtained.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", tag = "type")]
+pub enum RuntimeEvent {
+	/// The runtime is valid.
+	Valid(RuntimeVersionEvent),
+	/// The runtime could not be obtained.
+	Invalid(ErrorEvent),
+}
+
+/// An error, described by a human-readable message.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ErrorEvent {
+	/// Reason of the error.
+	pub error: String,
+}
+
+/// The first event of a follow subscription, reporting the current finalized block.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Initialized<Hash> {
+	/// The hash of the latest finalized block.
+	pub finalized_block_hash: Hash,
+	/// The runtime of the finalized block.
+	///
+	/// Only present if the subscription was started with `runtime_updates`.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub finalized_block_runtime: Option<RuntimeEvent>,
+}
+
+/// A block has been added to the tree of non-finalized blocks.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewBlock<Hash> {
+	/// The hash of the new block.
+	pub block_hash: Hash,
+	/// The hash of its parent.
+	pub parent_block_hash: Hash,
+	/// The runtime of the new block, if it differs from the runtime of its parent.
+	///
+	/// Always `None` if the subscription was started without `runtime_updates`.
+	pub new_runtime: Option<RuntimeEvent>,
+}
+
+/// The best block of the chain has changed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BestBlockChanged<Hash> {
+	/// The hash of the new best block.
+	pub best_block_hash: Hash,
+}
+
+/// Some blocks have been finalized, and others pruned as a consequence.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Finalized<Hash> {
+	/// The hashes of the newly finalized blocks, in ascending order.
+	pub finalized_block_hashes: Vec<Hash>,
+	/// The hashes of the reported blocks which can no longer become finalized.
+	pub pruned_block_hashes: Vec<Hash>,
+}
+
+/// An event of the `chainHead_unstable_follow` subscription.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", tag = "event")]
+pub enum FollowEvent<Hash> {
+	/// The subscription has started. Always the first event.
+	Initialized(Initialized<Hash>),
+	/// A new non-finalized block has been added.
+	NewBlock(NewBlock<Hash>),
+	/// The best block has changed.
+	BestBlockChanged(BestBlockChanged<Hash>),
+	/// Some blocks have been finalized or pruned.
+	Finalized(Finalized<Hash>),
+	/// The subscription has been stopped by the node. No further events are generated, and
+	/// the pinned blocks are released.
+	Stop,
+}
+
+/// The result of a chain head query.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChainHeadResult<T> {
+	/// The result of the query.
+	pub result: T,
+}
+
+/// An event of the `chainHead_unstable_body`, `chainHead_unstable_call` and
+/// `chainHead_unstable_storage` subscriptions.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", tag = "event")]
+pub enum ChainHeadEvent<T> {
+	/// The query has been completed.
+	Done(ChainHeadResult<T>),
+	/// The node does not have the data to answer the query.
+	Inaccessible(ErrorEvent),
+	/// The query failed.
+	Error(ErrorEvent),
+	/// The follow subscription the query refers to does not exist, or has been stopped.
+	Disjoint,
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn should_serialize_follow_events() {
+		assert_eq!(
+			::serde_json::to_string(&FollowEvent::Initialized(Initialized {
+				finalized_block_hash: "0x1".to_string(),
+				finalized_block_runtime: None,
+			}))
+			.unwrap(),
+			r#"{"event":"initialized","finalizedBlockHash":"0x1"}"#,
+		);
+
+		assert_eq!(
+			::serde_json::to_string(&FollowEvent::NewBlock(NewBlock {
+				block_hash: "0x2".to_string(),
+				parent_block_hash: "0x1".to_string(),
+				new_runtime: Some(RuntimeEvent::Invalid(ErrorEvent { error: "err".into() })),
+			}))
+			.unwrap(),
+			r#"{"event":"newBlock","blockHash":"0x2","parentBlockHash":"0x1","newRuntime":{"type":"invalid","error":"err"}}"#,
+		);
+
+		assert_eq!(
+			::serde_json::to_string(&FollowEvent::Finalized(Finalized {
+				finalized_block_hashes: vec!["0x2".to_string()],
+				pruned_block_hashes: vec!["0x3".to_string()],
+			}))
+			.unwrap(),
+			r#"{"event":"finalized","finalizedBlockHashes":["0x2"],"prunedBlockHashes":["0x3"]}"#,
+		);
+
+		assert_eq!(
+			::serde_json::to_string(&FollowEvent::<String>::Stop).unwrap(),
+			r#"{"event":"stop"}"#,
+		);
+	}
+
+	#[test]
+	fn should_serialize_chain_head_events() {
+		assert_eq!(
+			::serde_json::to_string(&ChainHeadEvent::Done(ChainHeadResult { result: "0x00" }))
+				.unwrap(),
+			r#"{"event":"done","result":"0x00"}"#,
+		);
+
+		assert_eq!(
+			::serde_json::to_string(&ChainHeadEvent::<()>::Inaccessible(ErrorEvent {
+				error: "gone".into()
+			}))
+			.unwrap(),
+			r#"{"event":"inaccessible","error":"gone"}"#,
+		);
+
+		assert_eq!(
+			::serde_json::to_string(&ChainHeadEvent::<()>::Disjoint).unwrap(),
+			r#"{"event":"disjoint"}"#,
+		);
+	}
+}
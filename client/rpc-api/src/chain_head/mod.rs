@@ -0,0 +1,156 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Substrate chain head API, following the `chainHead` functions of the JSON-RPC interface
+//! specification.
+//!
+//! Unlike the `chain` and `state` APIs, all queries are made relative to a `follow`
+//! subscription. The blocks reported by such a subscription are pinned, and can be queried
+//! until they are unpinned with `chainHead_unstable_unpin`.
+
+pub mod error;
+pub mod event;
+
+use self::{
+	error::Result,
+	event::{ChainHeadEvent, FollowEvent},
+};
+use jsonrpc_core::Result as RpcResult;
+use jsonrpc_derive::rpc;
+use jsonrpc_pubsub::{typed::Subscriber, SubscriptionId};
+use sp_core::{
+	storage::{PrefixedStorageKey, StorageData, StorageKey},
+	Bytes,
+};
+
+pub use self::gen_client::Client as ChainHeadClient;
+
+/// Substrate chain head API
+#[rpc]
+pub trait ChainHeadApi<Hash> {
+	/// RPC metadata
+	type Metadata;
+
+	/// Follow the tree of non-finalized blocks, pinning every reported block.
+	///
+	/// If `runtime_updates` is `true`, the runtime of the finalized block and any change of
+	/// runtime are reported as well.
+	#[pubsub(
+		subscription = "chainHead_unstable_followEvent",
+		subscribe,
+		name = "chainHead_unstable_follow"
+	)]
+	fn follow(
+		&self,
+		metadata: Self::Metadata,
+		subscriber: Subscriber<FollowEvent<Hash>>,
+		runtime_updates: bool,
+	);
+
+	/// Stop a follow subscription, unpinning all of its blocks.
+	#[pubsub(
+		subscription = "chainHead_unstable_followEvent",
+		unsubscribe,
+		name = "chainHead_unstable_unfollow"
+	)]
+	fn unfollow(&self, metadata: Option<Self::Metadata>, id: SubscriptionId) -> RpcResult<bool>;
+
+	/// Retrieve the SCALE-encoded extrinsics of a block pinned by `follow_subscription`.
+	#[pubsub(
+		subscription = "chainHead_unstable_bodyEvent",
+		subscribe,
+		name = "chainHead_unstable_body"
+	)]
+	fn body(
+		&self,
+		metadata: Self::Metadata,
+		subscriber: Subscriber<ChainHeadEvent<Vec<Bytes>>>,
+		follow_subscription: String,
+		hash: Hash,
+	);
+
+	/// Stop a body query.
+	#[pubsub(
+		subscription = "chainHead_unstable_bodyEvent",
+		unsubscribe,
+		name = "chainHead_unstable_stopBody"
+	)]
+	fn stop_body(&self, metadata: Option<Self::Metadata>, id: SubscriptionId) -> RpcResult<bool>;
+
+	/// Call a runtime function at a block pinned by `follow_subscription`.
+	#[pubsub(
+		subscription = "chainHead_unstable_callEvent",
+		subscribe,
+		name = "chainHead_unstable_call"
+	)]
+	fn call(
+		&self,
+		metadata: Self::Metadata,
+		subscriber: Subscriber<ChainHeadEvent<Bytes>>,
+		follow_subscription: String,
+		hash: Hash,
+		function: String,
+		call_parameters: Bytes,
+	);
+
+	/// Stop a runtime call.
+	#[pubsub(
+		subscription = "chainHead_unstable_callEvent",
+		unsubscribe,
+		name = "chainHead_unstable_stopCall"
+	)]
+	fn stop_call(&self, metadata: Option<Self::Metadata>, id: SubscriptionId) -> RpcResult<bool>;
+
+	/// Retrieve a storage value at a block pinned by `follow_subscription`, optionally from the
+	/// child trie of `child_key`.
+	#[pubsub(
+		subscription = "chainHead_unstable_storageEvent",
+		subscribe,
+		name = "chainHead_unstable_storage"
+	)]
+	fn storage(
+		&self,
+		metadata: Self::Metadata,
+		subscriber: Subscriber<ChainHeadEvent<Option<StorageData>>>,
+		follow_subscription: String,
+		hash: Hash,
+		key: StorageKey,
+		child_key: Option<PrefixedStorageKey>,
+	);
+
+	/// Stop a storage query.
+	#[pubsub(
+		subscription = "chainHead_unstable_storageEvent",
+		unsubscribe,
+		name = "chainHead_unstable_stopStorage"
+	)]
+	fn stop_storage(&self, metadata: Option<Self::Metadata>, id: SubscriptionId)
+		-> RpcResult<bool>;
+
+	/// Retrieve the SCALE-encoded header of a block pinned by `follow_subscription`.
+	#[rpc(name = "chainHead_unstable_header")]
+	fn header(&self, follow_subscription: String, hash: Hash) -> Result<Option<Bytes>>;
+
+	/// Get the hash of the genesis block.
+	#[rpc(name = "chainHead_unstable_genesisHash")]
+	fn genesis_hash(&self) -> Result<Hash>;
+
+	/// Unpin a block reported by `follow_subscription`, allowing the node to release it.
+	#[rpc(name = "chainHead_unstable_unpin")]
+	fn unpin(&self, follow_subscription: String, hash: Hash) -> Result<()>;
+}
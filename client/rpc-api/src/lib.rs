@@ -32,9 +32,12 @@ pub use jsonrpc_core::IoHandlerExtension as RpcExtension;
 pub use metadata::Metadata;
 pub use policy::{DenyUnsafe, UnsafeRpcError};
 
+pub mod archive;
 pub mod author;
 pub mod chain;
+pub mod chain_head;
 pub mod child_state;
 pub mod offchain;
 pub mod state;
 pub mod system;
+pub mod transaction;
@@ -17,10 +17,11 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 //! RPC Metadata
-use std::sync::Arc;
+use std::{net::IpAddr, sync::Arc};
 
 use futures::channel::mpsc;
 use jsonrpc_pubsub::{PubSubMetadata, Session};
+use sc_rpc_server::PeerMetadata;
 
 /// RPC Metadata.
 ///
@@ -30,6 +31,7 @@ use jsonrpc_pubsub::{PubSubMetadata, Session};
 #[derive(Default, Clone)]
 pub struct Metadata {
 	session: Option<Arc<Session>>,
+	peer: Option<IpAddr>,
 }
 
 impl jsonrpc_core::Metadata for Metadata {}
@@ -38,11 +40,16 @@ impl PubSubMetadata for Metadata {
 		self.session.clone()
 	}
 }
+impl PeerMetadata for Metadata {
+	fn peer(&self) -> Option<IpAddr> {
+		self.peer
+	}
+}
 
 impl Metadata {
 	/// Create new `Metadata` with session (Pub/Sub) support.
 	pub fn new(transport: mpsc::UnboundedSender<String>) -> Self {
-		Metadata { session: Some(Arc::new(Session::new(transport))) }
+		Metadata { session: Some(Arc::new(Session::new(transport))), peer: None }
 	}
 
 	/// Create new `Metadata` for tests.
@@ -58,3 +65,9 @@ impl From<mpsc::UnboundedSender<String>> for Metadata {
 		Self::new(sender)
 	}
 }
+
+impl From<IpAddr> for Metadata {
+	fn from(peer: IpAddr) -> Self {
+		Metadata { session: None, peer: Some(peer) }
+	}
+}
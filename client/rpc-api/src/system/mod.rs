@@ -107,6 +107,34 @@ pub trait SystemApi<Hash, Number> {
 	#[rpc(name = "system_reservedPeers", returns = "Vec<String>")]
 	fn system_reserved_peers(&self) -> Receiver<Vec<String>>;
 
+	/// Sets whether only reserved peers are accepted for syncing. Switching to reserved-only
+	/// disconnects all the peers that aren't reserved.
+	#[rpc(name = "system_setReservedOnly", returns = "()")]
+	fn system_set_reserved_only(
+		&self,
+		reserved_only: bool,
+	) -> BoxFuture<Result<(), jsonrpc_core::Error>>;
+
+	/// Returns the reputation of a peer. The string should encode only the PeerId.
+	#[rpc(name = "system_peerReputation", returns = "i32")]
+	fn system_peer_reputation(
+		&self,
+		peer_id: String,
+	) -> BoxFuture<Result<i32, jsonrpc_core::Error>>;
+
+	/// Bans a peer for the given number of seconds, disconnecting from it. The string should
+	/// encode only the PeerId.
+	#[rpc(name = "system_banPeer", returns = "()")]
+	fn system_ban_peer(
+		&self,
+		peer_id: String,
+		duration_secs: u64,
+	) -> BoxFuture<Result<(), jsonrpc_core::Error>>;
+
+	/// Lifts the ban of a peer. The string should encode only the PeerId.
+	#[rpc(name = "system_unbanPeer", returns = "()")]
+	fn system_unban_peer(&self, peer_id: String) -> BoxFuture<Result<(), jsonrpc_core::Error>>;
+
 	/// Returns the roles the node is running as.
 	#[rpc(name = "system_nodeRoles", returns = "Vec<NodeRole>")]
 	fn system_node_roles(&self) -> Receiver<Vec<NodeRole>>;
@@ -0,0 +1,117 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Events emitted by the transaction RPC subscriptions.
+
+use serde::{Deserialize, Serialize};
+
+/// The block including a transaction.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionBlock<Hash> {
+	/// The hash of the block.
+	pub hash: Hash,
+	/// The index of the transaction in the body of the block.
+	pub index: usize,
+}
+
+/// An event of the life cycle of a watched transaction.
+///
+/// The `finalized`, `error`, `invalid` and `dropped` events are final: no further event is
+/// generated after them.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", tag = "event")]
+pub enum TransactionEvent<Hash> {
+	/// The transaction is valid, and has been added to the pool.
+	Validated,
+	/// The transaction is included in a block of the best chain, or no longer is if `block` is
+	/// `None`.
+	#[serde(rename_all = "camelCase")]
+	BestChainBlockIncluded {
+		/// The block including the transaction.
+		block: Option<TransactionBlock<Hash>>,
+	},
+	/// The transaction is included in a finalized block.
+	Finalized {
+		/// The block including the transaction.
+		block: TransactionBlock<Hash>,
+	},
+	/// The transaction could not be processed because of an error of the node.
+	Error {
+		/// The reason of the error.
+		error: String,
+	},
+	/// The transaction is invalid.
+	Invalid {
+		/// The reason the transaction is invalid.
+		error: String,
+	},
+	/// The node stopped watching the transaction.
+	Dropped {
+		/// Whether the transaction has been broadcasted to other nodes.
+		broadcasted: bool,
+		/// The reason the transaction has been dropped.
+		error: String,
+	},
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn should_serialize_transaction_events() {
+		assert_eq!(
+			::serde_json::to_string(&TransactionEvent::<String>::Validated).unwrap(),
+			r#"{"event":"validated"}"#,
+		);
+
+		assert_eq!(
+			::serde_json::to_string(&TransactionEvent::BestChainBlockIncluded {
+				block: Some(TransactionBlock { hash: "0x1".to_string(), index: 2 }),
+			})
+			.unwrap(),
+			r#"{"event":"bestChainBlockIncluded","block":{"hash":"0x1","index":2}}"#,
+		);
+
+		assert_eq!(
+			::serde_json::to_string(&TransactionEvent::<String>::BestChainBlockIncluded {
+				block: None
+			})
+			.unwrap(),
+			r#"{"event":"bestChainBlockIncluded","block":null}"#,
+		);
+
+		assert_eq!(
+			::serde_json::to_string(&TransactionEvent::Finalized {
+				block: TransactionBlock { hash: "0x1".to_string(), index: 2 },
+			})
+			.unwrap(),
+			r#"{"event":"finalized","block":{"hash":"0x1","index":2}}"#,
+		);
+
+		assert_eq!(
+			::serde_json::to_string(&TransactionEvent::<String>::Dropped {
+				broadcasted: true,
+				error: "limits".into(),
+			})
+			.unwrap(),
+			r#"{"event":"dropped","broadcasted":true,"error":"limits"}"#,
+		);
+	}
+}
@@ -0,0 +1,62 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Substrate transaction API, following the `transactionWatch` functions of the JSON-RPC
+//! interface specification.
+//!
+//! Unlike `author_submitAndWatchExtrinsic`, every failure is reported as an event of the
+//! subscription, and the blocks including the transaction are reported along with the index of
+//! the transaction in them.
+
+pub mod event;
+
+use self::event::TransactionEvent;
+use jsonrpc_core::Result as RpcResult;
+use jsonrpc_derive::rpc;
+use jsonrpc_pubsub::{typed::Subscriber, SubscriptionId};
+use sp_core::Bytes;
+
+pub use self::gen_client::Client as TransactionClient;
+
+/// Substrate transaction API
+#[rpc]
+pub trait TransactionApi<Hash> {
+	/// RPC metadata
+	type Metadata;
+
+	/// Submit a SCALE-encoded transaction, and watch its life cycle.
+	#[pubsub(
+		subscription = "transactionWatch_unstable_watchEvent",
+		subscribe,
+		name = "transactionWatch_unstable_submitAndWatch"
+	)]
+	fn submit_and_watch(
+		&self,
+		metadata: Self::Metadata,
+		subscriber: Subscriber<TransactionEvent<Hash>>,
+		transaction: Bytes,
+	);
+
+	/// Stop watching a transaction.
+	#[pubsub(
+		subscription = "transactionWatch_unstable_watchEvent",
+		unsubscribe,
+		name = "transactionWatch_unstable_unwatch"
+	)]
+	fn unwatch(&self, metadata: Option<Self::Metadata>, id: SubscriptionId) -> RpcResult<bool>;
+}
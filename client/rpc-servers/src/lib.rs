@@ -26,7 +26,7 @@ use jsonrpc_core::{IoHandlerExtension, MetaIoHandler};
 use log::error;
 use prometheus_endpoint::{register, Counter, PrometheusError, Registry, U64};
 use pubsub::PubSubMetadata;
-use std::io;
+use std::{io, net::IpAddr};
 
 const MEGABYTE: usize = 1024 * 1024;
 
@@ -39,10 +39,10 @@ const WS_MAX_CONNECTIONS: usize = 100;
 /// The RPC IoHandler containing all requested APIs.
 pub type RpcHandler<T> = pubsub::PubSubHandler<T, RpcMiddleware>;
 
-pub use middleware::{method_names, RpcMetrics, RpcMiddleware};
+pub use middleware::{method_names, PeerMetadata, RpcLimits, RpcMetrics, RpcMiddleware};
 
 /// Construct rpc `IoHandler`
-pub fn rpc_handler<M: PubSubMetadata>(
+pub fn rpc_handler<M: PubSubMetadata + PeerMetadata>(
 	extension: impl IoHandlerExtension<M>,
 	rpc_middleware: RpcMiddleware,
 ) -> RpcHandler<M> {
@@ -125,30 +125,41 @@ impl ws::SessionStats for ServerMetrics {
 }
 
 /// Start HTTP server listening on given address.
-pub fn start_http<M: pubsub::PubSubMetadata + Default + Unpin>(
+///
+/// If `trust_proxy_headers` is set, the peer of each call is read from the headers set by a
+/// reverse proxy.
+pub fn start_http<M>(
 	addr: &std::net::SocketAddr,
 	cors: Option<&Vec<String>>,
 	io: RpcHandler<M>,
 	maybe_max_payload_mb: Option<usize>,
+	trust_proxy_headers: bool,
 	tokio_handle: tokio::runtime::Handle,
-) -> io::Result<http::Server> {
+) -> io::Result<http::Server>
+where
+	M: pubsub::PubSubMetadata + PeerMetadata + Default + From<IpAddr> + Unpin,
+{
 	let max_request_body_size = maybe_max_payload_mb
 		.map(|mb| mb.saturating_mul(MEGABYTE))
 		.unwrap_or(RPC_MAX_PAYLOAD_DEFAULT);
 
-	http::ServerBuilder::new(io)
-		.threads(1)
-		.event_loop_executor(tokio_handle)
-		.health_api(("/health", "system_health"))
-		.allowed_hosts(hosts_filtering(cors.is_some()))
-		.rest_api(if cors.is_some() { http::RestApi::Secure } else { http::RestApi::Unsecure })
-		.cors(map_cors::<http::AccessControlAllowOrigin>(cors))
-		.max_request_body_size(max_request_body_size)
-		.start_http(addr)
+	http::ServerBuilder::with_meta_extractor(io, move |request: &http::hyper::Request<_>| {
+		if trust_proxy_headers { proxied_peer(request.headers()) } else { None }
+			.map(M::from)
+			.unwrap_or_default()
+	})
+	.threads(1)
+	.event_loop_executor(tokio_handle)
+	.health_api(("/health", "system_health"))
+	.allowed_hosts(hosts_filtering(cors.is_some()))
+	.rest_api(if cors.is_some() { http::RestApi::Secure } else { http::RestApi::Unsecure })
+	.cors(map_cors::<http::AccessControlAllowOrigin>(cors))
+	.max_request_body_size(max_request_body_size)
+	.start_http(addr)
 }
 
 /// Start IPC server listening on given path.
-pub fn start_ipc<M: pubsub::PubSubMetadata + Default>(
+pub fn start_ipc<M: pubsub::PubSubMetadata + PeerMetadata + Default>(
 	addr: &str,
 	io: RpcHandler<M>,
 	server_metrics: ServerMetrics,
@@ -165,7 +176,7 @@ pub fn start_ipc<M: pubsub::PubSubMetadata + Default>(
 
 /// Start WS server listening on given address.
 pub fn start_ws<
-	M: pubsub::PubSubMetadata + From<futures::channel::mpsc::UnboundedSender<String>>,
+	M: pubsub::PubSubMetadata + PeerMetadata + From<futures::channel::mpsc::UnboundedSender<String>>,
 >(
 	addr: &std::net::SocketAddr,
 	max_connections: Option<usize>,
@@ -198,6 +209,15 @@ pub fn start_ws<
 	})
 }
 
+/// The address of the client reported by a reverse proxy, from the `X-Real-IP` header or else
+/// the last entry of the `X-Forwarded-For` one, which is the one added by the proxy itself.
+fn proxied_peer(headers: &http::hyper::HeaderMap) -> Option<IpAddr> {
+	let header = |name| headers.get(name).and_then(|value| value.to_str().ok());
+	header("x-real-ip")
+		.or_else(|| header("x-forwarded-for").and_then(|value| value.rsplit(',').next()))
+		.and_then(|value| value.trim().parse().ok())
+}
+
 fn map_cors<T: for<'a> From<&'a str>>(cors: Option<&Vec<String>>) -> http::DomainsValidation<T> {
 	cors.map(|x| x.iter().map(AsRef::as_ref).map(Into::into).collect::<Vec<_>>())
 		.into()
@@ -213,3 +233,34 @@ fn hosts_filtering(enable: bool) -> http::DomainsValidation<http::Host> {
 		http::DomainsValidation::Disabled
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use http::hyper::{header::HeaderValue, HeaderMap};
+
+	fn headers(entries: &[(&'static str, &'static str)]) -> HeaderMap {
+		entries
+			.iter()
+			.map(|(name, value)| (*name, HeaderValue::from_static(value)))
+			.fold(HeaderMap::new(), |mut headers, (name, value)| {
+				headers.append(name, value);
+				headers
+			})
+	}
+
+	#[test]
+	fn proxied_peer_is_read_from_the_proxy_headers() {
+		let peer = |entries| proxied_peer(&headers(entries));
+
+		assert_eq!(peer(&[]), None);
+		assert_eq!(peer(&[("x-real-ip", "10.0.0.1")]), Some([10, 0, 0, 1].into()));
+		assert_eq!(
+			peer(&[("x-forwarded-for", "10.0.0.1, 10.0.0.2"), ("x-real-ip", "::1")]),
+			Some("::1".parse().unwrap()),
+		);
+		// The last entry is the one added by the trusted proxy.
+		assert_eq!(peer(&[("x-forwarded-for", "10.0.0.1, 10.0.0.2")]), Some([10, 0, 0, 2].into()),);
+		assert_eq!(peer(&[("x-forwarded-for", "unknown")]), None);
+	}
+}
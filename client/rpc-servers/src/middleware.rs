@@ -18,15 +18,28 @@
 
 //! Middleware for RPC requests.
 
-use std::collections::HashSet;
+use std::{
+	collections::{HashMap, HashSet},
+	net::IpAddr,
+	num::NonZeroU32,
+	sync::{Arc, Mutex},
+	time::{Duration, Instant},
+};
 
-use jsonrpc_core::{FutureOutput, FutureResponse, Metadata, Middleware as RequestMiddleware};
+use jsonrpc_core::{
+	Call, Error, ErrorCode, FutureOutput, FutureResponse, Middleware as RequestMiddleware, Output,
+	Request, Response, Version,
+};
 use prometheus_endpoint::{
 	register, CounterVec, HistogramOpts, HistogramVec, Opts, PrometheusError, Registry, U64,
 };
 
-use futures::{future::Either, Future, FutureExt};
-use pubsub::PubSubMetadata;
+use futures::{
+	future::{self, Either},
+	Future, FutureExt,
+};
+use pubsub::{PubSubMetadata, Session};
+use sc_utils::task_guard::{self, TaskGuard};
 
 use crate::RpcHandler;
 
@@ -106,17 +119,114 @@ impl RpcMetrics {
 pub fn method_names<F, M, E>(gen_handler: F) -> Result<HashSet<String>, E>
 where
 	F: FnOnce(RpcMiddleware) -> Result<RpcHandler<M>, E>,
-	M: PubSubMetadata,
+	M: PubSubMetadata + PeerMetadata,
 {
 	let io = gen_handler(RpcMiddleware::new(None, HashSet::new(), "dummy"))?;
 	Ok(io.iter().map(|x| x.0.clone()).collect())
 }
 
+/// Error code of the calls rejected because of the rate limit.
+const RATE_LIMIT_ERROR_CODE: i64 = -32029;
+
+/// Error code of the calls whose response exceeds the size limit.
+const RESPONSE_TOO_LARGE_ERROR_CODE: i64 = -32008;
+
+/// Error code of the subscriptions rejected because of the subscription limit.
+const TOO_MANY_SUBSCRIPTIONS_ERROR_CODE: i64 = -32030;
+
+/// Limits enforced on RPC calls by the middleware.
+#[derive(Debug, Clone, Default)]
+pub struct RpcLimits {
+	/// Maximum number of calls per second of a single connection. Calls received without a
+	/// persistent connection, such as HTTP ones, are limited per peer if it is known, and share a
+	/// single limit otherwise. `None` if unlimited.
+	pub max_calls_per_second: Option<NonZeroU32>,
+	/// Maximum number of calls in a batch request. `None` if unlimited.
+	pub max_batch_len: Option<usize>,
+	/// Maximum size of the response to a single call, in bytes. `None` if unlimited.
+	pub max_response_size: Option<usize>,
+	/// Maximum number of active subscriptions of a single connection. `None` if unlimited.
+	pub max_subscriptions_per_connection: Option<usize>,
+	/// Methods which may not be called.
+	pub denied_methods: HashSet<String>,
+	/// Whether to identify the peers of HTTP calls by the client address reported by a reverse
+	/// proxy in the `X-Real-IP` or `X-Forwarded-For` headers. The HTTP server doesn't expose the
+	/// address of its peers, so HTTP calls share a single rate limit otherwise.
+	pub trust_proxy_headers: bool,
+}
+
+/// Metadata of RPC calls, identifying their peer when they are received without a persistent
+/// connection.
+pub trait PeerMetadata {
+	/// The address of the peer which made the call, if known.
+	fn peer(&self) -> Option<IpAddr>;
+}
+
+/// The key of a tracked connection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum ConnectionKey {
+	/// A persistent connection, by the address of its session.
+	Session(usize),
+	/// The calls of a peer without a persistent connection.
+	Peer(IpAddr),
+	/// The calls of unknown peers without a persistent connection.
+	Unknown,
+}
+
+impl ConnectionKey {
+	fn new(session: &Option<Arc<Session>>, peer: Option<IpAddr>) -> Self {
+		match (session, peer) {
+			(Some(session), _) => ConnectionKey::Session(Arc::as_ptr(session) as usize),
+			(None, Some(peer)) => ConnectionKey::Peer(peer),
+			(None, None) => ConnectionKey::Unknown,
+		}
+	}
+}
+
+/// What is being tracked about a connection.
+struct Connection {
+	/// Start of the current one second window.
+	window_start: Instant,
+	/// Calls made during the current window.
+	calls: u32,
+	/// Subscriptions whose tasks are not over yet.
+	subscriptions: usize,
+}
+
+/// The tracked connections.
+type Connections = Arc<Mutex<HashMap<ConnectionKey, Connection>>>;
+
+/// Whether a method opens or closes a subscription.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SubscriptionCall {
+	Subscribe,
+	Unsubscribe,
+}
+
+/// Classify `method` using the naming of the subscription methods, e.g.
+/// `chain_subscribeNewHeads`, `author_unwatchExtrinsic` or `chainHead_unstable_follow`.
+fn subscription_call(method: &str) -> Option<SubscriptionCall> {
+	let name = method.rsplit('_').next().unwrap_or(method);
+	let opens = |name: &str| {
+		name.starts_with("subscribe") ||
+			name.starts_with("watch") ||
+			name.starts_with("submitAndWatch") ||
+			name == "follow"
+	};
+	match name.strip_prefix("un") {
+		Some(rest) if opens(rest) => Some(SubscriptionCall::Unsubscribe),
+		_ if opens(name) => Some(SubscriptionCall::Subscribe),
+		_ => None,
+	}
+}
+
 /// Middleware for RPC calls
 pub struct RpcMiddleware {
 	metrics: Option<RpcMetrics>,
 	known_rpc_method_names: HashSet<String>,
 	transport_label: String,
+	limits: RpcLimits,
+	connections: Connections,
 }
 
 impl RpcMiddleware {
@@ -129,11 +239,104 @@ impl RpcMiddleware {
 		known_rpc_method_names: HashSet<String>,
 		transport_label: &str,
 	) -> Self {
-		RpcMiddleware { metrics, known_rpc_method_names, transport_label: transport_label.into() }
+		RpcMiddleware {
+			metrics,
+			known_rpc_method_names,
+			transport_label: transport_label.into(),
+			limits: Default::default(),
+			connections: Default::default(),
+		}
+	}
+
+	/// Enforce the given limits on the calls.
+	pub fn with_limits(mut self, limits: RpcLimits) -> Self {
+		self.limits = limits;
+		self
+	}
+
+	/// Run `f` on the tracked state of the connection with the given key, whose session is
+	/// `session`.
+	fn with_connection<R>(
+		&self,
+		key: ConnectionKey,
+		session: &Option<Arc<Session>>,
+		f: impl FnOnce(&mut Connection) -> R,
+	) -> R {
+		let mut connections = self.connections.lock().unwrap_or_else(|e| e.into_inner());
+
+		if !connections.contains_key(&key) {
+			if let Some(session) = session {
+				// Forget about the connection once it is closed.
+				let connections = self.connections.clone();
+				session.on_drop(move || {
+					connections.lock().unwrap_or_else(|e| e.into_inner()).remove(&key);
+				});
+			} else if let ConnectionKey::Peer(_) = key {
+				// Peers don't close anything, so forget about the ones without calls in the
+				// current window.
+				let now = Instant::now();
+				connections.retain(|key, connection| match key {
+					ConnectionKey::Peer(_) =>
+						now.duration_since(connection.window_start) < Duration::from_secs(1),
+					_ => true,
+				});
+			}
+		}
+		let connection = connections.entry(key).or_insert_with(|| Connection {
+			window_start: Instant::now(),
+			calls: 0,
+			subscriptions: 0,
+		});
+		f(connection)
+	}
+
+	/// Count a call of the connection with the given key, returning `false` if it exceeds the
+	/// rate limit.
+	fn record_call(&self, key: ConnectionKey, session: &Option<Arc<Session>>) -> bool {
+		let max_calls = match self.limits.max_calls_per_second {
+			Some(max_calls) => max_calls.get(),
+			None => return true,
+		};
+		self.with_connection(key, session, |connection| {
+			let now = Instant::now();
+			if now.duration_since(connection.window_start) >= Duration::from_secs(1) {
+				connection.window_start = now;
+				connection.calls = 0;
+			}
+			if connection.calls >= max_calls {
+				return false
+			}
+			connection.calls += 1;
+			true
+		})
+	}
+
+	/// Reserve a subscription of the connection of `session`, returning `None` if it exceeds
+	/// the subscription limit, or no slot if subscriptions are not counted.
+	///
+	/// Calls without a persistent connection can't subscribe, so they are not counted.
+	fn reserve_subscription(&self, session: &Option<Arc<Session>>) -> Option<Option<TaskGuard>> {
+		let max_subscriptions = match (self.limits.max_subscriptions_per_connection, session) {
+			(Some(max_subscriptions), Some(_)) => max_subscriptions,
+			_ => return Some(None),
+		};
+		let key = ConnectionKey::new(session, None);
+		let reserved = self.with_connection(key, session, |connection| {
+			if connection.subscriptions >= max_subscriptions {
+				return false
+			}
+			connection.subscriptions += 1;
+			true
+		});
+		if !reserved {
+			return None
+		}
+		let slot = SubscriptionSlot { connections: self.connections.clone(), key };
+		Some(Some(Arc::new(slot)))
 	}
 }
 
-impl<M: Metadata> RequestMiddleware<M> for RpcMiddleware {
+impl<M: PubSubMetadata + PeerMetadata> RequestMiddleware<M> for RpcMiddleware {
 	type Future = FutureResponse;
 	type CallFuture = FutureOutput;
 
@@ -147,6 +350,20 @@ impl<M: Metadata> RequestMiddleware<M> for RpcMiddleware {
 		F: Fn(jsonrpc_core::Request, M) -> X + Send + Sync,
 		X: Future<Output = Option<jsonrpc_core::Response>> + Send + 'static,
 	{
+		if let (Request::Batch(calls), Some(max_batch_len)) = (&request, self.limits.max_batch_len)
+		{
+			if calls.len() > max_batch_len {
+				let error = Error {
+					code: ErrorCode::InvalidRequest,
+					message: format!("Batch request exceeds the limit of {} calls", max_batch_len),
+					data: None,
+				};
+				return Either::Left(
+					future::ready(Some(Response::from(error, Some(Version::V2)))).boxed(),
+				)
+			}
+		}
+
 		let metrics = self.metrics.clone();
 		let transport_label = self.transport_label.clone();
 		if let Some(ref metrics) = metrics {
@@ -175,8 +392,46 @@ impl<M: Metadata> RequestMiddleware<M> for RpcMiddleware {
 		F: Fn(jsonrpc_core::Call, M) -> X + Send + Sync,
 		X: Future<Output = Option<jsonrpc_core::Output>> + Send + 'static,
 	{
+		let method = match &call {
+			Call::MethodCall(call) => Some(&call.method),
+			Call::Notification(notification) => Some(&notification.method),
+			Call::Invalid { .. } => None,
+		};
+		if method.map_or(false, |method| self.limits.denied_methods.contains(method)) {
+			return Either::Left(
+				future::ready(error_output(&call, Error::method_not_found())).boxed(),
+			)
+		}
+		let session = meta.session();
+		if !self.record_call(ConnectionKey::new(&session, meta.peer()), &session) {
+			let error = Error {
+				code: ErrorCode::ServerError(RATE_LIMIT_ERROR_CODE),
+				message: "Too many requests".into(),
+				data: None,
+			};
+			return Either::Left(future::ready(error_output(&call, error)).boxed())
+		}
+		let subscribe = method.and_then(|method| subscription_call(method)) ==
+			Some(SubscriptionCall::Subscribe);
+		let slot = if subscribe {
+			match self.reserve_subscription(&session) {
+				Some(slot) => slot,
+				None => {
+					let error = Error {
+						code: ErrorCode::ServerError(TOO_MANY_SUBSCRIPTIONS_ERROR_CODE),
+						message: "Too many subscriptions on the connection".into(),
+						data: None,
+					};
+					return Either::Left(future::ready(error_output(&call, error)).boxed())
+				},
+			}
+		} else {
+			None
+		};
+
 		let start = std::time::Instant::now();
 		let name = call_name(&call, &self.known_rpc_method_names).to_owned();
+		let max_response_size = self.limits.max_response_size;
 		let metrics = self.metrics.clone();
 		let transport_label = self.transport_label.clone();
 		log::trace!(target: "rpc_metrics", "[{}] {} call: {:?}", transport_label, name, &call);
@@ -186,10 +441,28 @@ impl<M: Metadata> RequestMiddleware<M> for RpcMiddleware {
 				.with_label_values(&[transport_label.as_str(), name.as_str()])
 				.inc();
 		}
-		let r = next(call, meta);
+		// The tasks spawned by the call keep the slot, so it is released once they are all over,
+		// whether the subscription is closed by the client or ends on its own.
+		let r = task_guard::with_guard(slot, || next(call, meta));
 		Either::Left(
 			async move {
-				let r = r.await;
+				let r = match (r.await, max_response_size) {
+					(Some(output), Some(max_response_size))
+						if serde_json::to_vec(&output)
+							.map_or(false, |encoded| encoded.len() > max_response_size) =>
+					{
+						let error = Error {
+							code: ErrorCode::ServerError(RESPONSE_TOO_LARGE_ERROR_CODE),
+							message: format!(
+								"Response exceeds the limit of {} bytes",
+								max_response_size
+							),
+							data: None,
+						};
+						Some(Output::from(Err(error), output.id().clone(), output.version()))
+					},
+					(r, _) => r,
+				};
 				let micros = start.elapsed().as_micros();
 				if let Some(ref metrics) = metrics {
 					metrics
@@ -236,9 +509,198 @@ fn call_name<'a>(call: &'a jsonrpc_core::Call, known_methods: &HashSet<String>)
 	}
 }
 
+/// A subscription reserved by a connection, released when dropped.
+struct SubscriptionSlot {
+	connections: Connections,
+	key: ConnectionKey,
+}
+
+impl Drop for SubscriptionSlot {
+	fn drop(&mut self) {
+		let mut connections = self.connections.lock().unwrap_or_else(|e| e.into_inner());
+		if let Some(connection) = connections.get_mut(&self.key) {
+			connection.subscriptions = connection.subscriptions.saturating_sub(1);
+		}
+	}
+}
+
+/// The output answering `call` with `error`, if it expects one.
+fn error_output(call: &Call, error: Error) -> Option<Output> {
+	match call {
+		Call::MethodCall(call) => Some(Output::from(Err(error), call.id.clone(), call.jsonrpc)),
+		Call::Notification(_) => None,
+		Call::Invalid { id } => Some(Output::from(Err(error), id.clone(), Some(Version::V2))),
+	}
+}
+
 fn is_success(output: &Option<jsonrpc_core::Output>) -> bool {
 	match output {
 		Some(jsonrpc_core::Output::Success(..)) => true,
 		_ => false,
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use futures::{executor::block_on, future::BoxFuture};
+	use jsonrpc_core::{MetaIoHandler, Value};
+
+	#[derive(Clone, Default)]
+	struct Meta {
+		session: Option<Arc<Session>>,
+		peer: Option<IpAddr>,
+	}
+
+	impl Meta {
+		fn session(session: &Arc<Session>) -> Self {
+			Meta { session: Some(session.clone()), peer: None }
+		}
+
+		fn peer(peer: [u8; 4]) -> Self {
+			Meta { session: None, peer: Some(peer.into()) }
+		}
+	}
+
+	impl jsonrpc_core::Metadata for Meta {}
+
+	impl PubSubMetadata for Meta {
+		fn session(&self) -> Option<Arc<Session>> {
+			self.session.clone()
+		}
+	}
+
+	impl PeerMetadata for Meta {
+		fn peer(&self) -> Option<IpAddr> {
+			self.peer
+		}
+	}
+
+	/// The tasks of the test subscriptions.
+	type Tasks = Arc<Mutex<Vec<BoxFuture<'static, ()>>>>;
+
+	fn handler(limits: RpcLimits) -> (MetaIoHandler<Meta, RpcMiddleware>, Connections, Tasks) {
+		let middleware = RpcMiddleware::new(None, HashSet::new(), "test").with_limits(limits);
+		let connections = middleware.connections.clone();
+		let tasks = Tasks::default();
+		let mut io = MetaIoHandler::with_middleware(middleware);
+		io.add_method("hello", |_| async { Ok(Value::String("hello".into())) });
+		io.add_method("big", |_| async { Ok(Value::String("x".repeat(100))) });
+		let subscription_tasks = tasks.clone();
+		io.add_method("test_subscribe", move |_| {
+			// Stands for the task driving the subscription sink.
+			let task = task_guard::bind(future::pending()).boxed();
+			subscription_tasks.lock().unwrap().push(task);
+			async { Ok(Value::String("id".into())) }
+		});
+		(io, connections, tasks)
+	}
+
+	fn call(io: &MetaIoHandler<Meta, RpcMiddleware>, request: &str, meta: Meta) -> String {
+		block_on(io.handle_request(request, meta)).unwrap()
+	}
+
+	fn session() -> Arc<Session> {
+		Arc::new(Session::new(futures::channel::mpsc::unbounded().0))
+	}
+
+	const HELLO: &str = r#"{"jsonrpc":"2.0","method":"hello","id":1}"#;
+
+	#[test]
+	fn denied_methods_are_not_found() {
+		let (io, _, _) = handler(RpcLimits {
+			denied_methods: vec!["hello".to_string()].into_iter().collect(),
+			..Default::default()
+		});
+
+		assert!(call(&io, HELLO, Meta::default()).contains(r#""code":-32601"#));
+		assert!(call(&io, r#"{"jsonrpc":"2.0","method":"big","id":1}"#, Meta::default())
+			.contains("result"));
+	}
+
+	#[test]
+	fn calls_are_rate_limited_per_connection() {
+		let (io, connections, _) =
+			handler(RpcLimits { max_calls_per_second: NonZeroU32::new(2), ..Default::default() });
+		let first = session();
+		let second = session();
+
+		assert!(call(&io, HELLO, Meta::session(&first)).contains("result"));
+		assert!(call(&io, HELLO, Meta::session(&first)).contains("result"));
+		assert!(call(&io, HELLO, Meta::session(&first)).contains(r#""code":-32029"#));
+		// Another connection has its own limit.
+		assert!(call(&io, HELLO, Meta::session(&second)).contains("result"));
+
+		// A closed connection is forgotten.
+		drop(first);
+		assert_eq!(connections.lock().unwrap().len(), 1);
+	}
+
+	#[test]
+	fn calls_without_connection_are_rate_limited_per_peer() {
+		let (io, connections, _) =
+			handler(RpcLimits { max_calls_per_second: NonZeroU32::new(1), ..Default::default() });
+
+		assert!(call(&io, HELLO, Meta::peer([10, 0, 0, 1])).contains("result"));
+		assert!(call(&io, HELLO, Meta::peer([10, 0, 0, 1])).contains(r#""code":-32029"#));
+		// Another peer has its own limit.
+		assert!(call(&io, HELLO, Meta::peer([10, 0, 0, 2])).contains("result"));
+		// Unknown peers share a single limit.
+		assert!(call(&io, HELLO, Meta::default()).contains("result"));
+		assert!(call(&io, HELLO, Meta::default()).contains(r#""code":-32029"#));
+		assert_eq!(connections.lock().unwrap().len(), 3);
+	}
+
+	#[test]
+	fn batch_requests_are_limited() {
+		let (io, _, _) = handler(RpcLimits { max_batch_len: Some(2), ..Default::default() });
+
+		let batch = |len| format!("[{}]", vec![HELLO; len].join(","));
+		assert!(call(&io, &batch(2), Meta::default()).contains("result"));
+		assert!(call(&io, &batch(3), Meta::default()).contains(r#""code":-32600"#));
+	}
+
+	#[test]
+	fn large_responses_are_replaced_by_an_error() {
+		let (io, _, _) = handler(RpcLimits { max_response_size: Some(64), ..Default::default() });
+
+		assert!(call(&io, HELLO, Meta::default()).contains("result"));
+		assert!(call(&io, r#"{"jsonrpc":"2.0","method":"big","id":1}"#, Meta::default())
+			.contains(r#""code":-32008"#));
+	}
+
+	#[test]
+	fn subscriptions_are_limited_per_connection() {
+		let (io, _, tasks) =
+			handler(RpcLimits { max_subscriptions_per_connection: Some(1), ..Default::default() });
+		let first = session();
+		let second = session();
+		let subscribe = r#"{"jsonrpc":"2.0","method":"test_subscribe","id":1}"#;
+
+		assert!(call(&io, subscribe, Meta::session(&first)).contains("result"));
+		assert!(call(&io, subscribe, Meta::session(&first)).contains(r#""code":-32030"#));
+		// Another connection has its own limit.
+		assert!(call(&io, subscribe, Meta::session(&second)).contains("result"));
+
+		// The end of a subscription task makes room for another one, whatever the reason.
+		drop(tasks.lock().unwrap().remove(0));
+		assert!(call(&io, subscribe, Meta::session(&first)).contains("result"));
+	}
+
+	#[test]
+	fn subscription_methods_are_recognized() {
+		let subscribe = Some(SubscriptionCall::Subscribe);
+		let unsubscribe = Some(SubscriptionCall::Unsubscribe);
+
+		assert_eq!(subscription_call("chain_subscribeNewHeads"), subscribe);
+		assert_eq!(subscription_call("chain_unsubscribeNewHeads"), unsubscribe);
+		assert_eq!(subscription_call("author_submitAndWatchExtrinsic"), subscribe);
+		assert_eq!(subscription_call("author_unwatchExtrinsic"), unsubscribe);
+		assert_eq!(subscription_call("chainHead_unstable_follow"), subscribe);
+		assert_eq!(subscription_call("chainHead_unstable_unfollow"), unsubscribe);
+		assert_eq!(subscription_call("transactionWatch_unstable_submitAndWatch"), subscribe);
+		assert_eq!(subscription_call("transactionWatch_unstable_unwatch"), unsubscribe);
+		assert_eq!(subscription_call("chain_getHeader"), None);
+		assert_eq!(subscription_call("chainHead_unstable_body"), None);
+	}
+}
@@ -0,0 +1,303 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Substrate archive API, following the `archive` functions of the JSON-RPC interface
+//! specification.
+
+#[cfg(test)]
+mod tests;
+
+use std::{convert::TryFrom, iter, marker::PhantomData, sync::Arc};
+
+use codec::{Decode, Encode};
+use sc_client_api::{
+	Backend, BlockBackend, CallExecutor, ExecutorProvider, ProofProvider, StorageProvider,
+};
+use sp_blockchain::{Backend as _, HeaderBackend, HeaderMetadata};
+use sp_core::{
+	storage::{ChildInfo, ChildType, PrefixedStorageKey, StorageKey},
+	Bytes,
+};
+use sp_runtime::{
+	generic::BlockId,
+	traits::{Block as BlockT, HashFor, Header, NumberFor},
+};
+
+use self::{
+	error::{Error, Result},
+	helpers::{
+		ArchiveStorageResult, MethodResult, StorageQuery, StorageQueryType, StorageResult,
+		StorageResultType,
+	},
+};
+
+pub use sc_rpc_api::archive::*;
+
+/// The maximum number of items processed by a single `archive_unstable_storage` call. Any
+/// further item is discarded.
+const MAX_QUERIED_ITEMS: usize = 8;
+
+/// The maximum number of results of a single descendants query. Further results are obtained
+/// by querying again with the last returned key as `paginationStartKey`.
+const MAX_DESCENDANT_RESPONSES: usize = 5;
+
+/// Archive API for full nodes.
+pub struct Archive<BE, Block: BlockT, Client> {
+	/// Substrate client.
+	client: Arc<Client>,
+	/// Backend of the chain.
+	backend: Arc<BE>,
+	_phantom: PhantomData<Block>,
+}
+
+impl<BE, Block: BlockT, Client> Archive<BE, Block, Client> {
+	/// Create a new archive API.
+	pub fn new(client: Arc<Client>, backend: Arc<BE>) -> Self {
+		Self { client, backend, _phantom: PhantomData }
+	}
+}
+
+impl<BE, Block, Client> Archive<BE, Block, Client>
+where
+	Block: BlockT + 'static,
+	BE: Backend<Block> + 'static,
+	Client: StorageProvider<Block, BE>
+		+ ProofProvider<Block>
+		+ HeaderBackend<Block>
+		+ Send
+		+ Sync
+		+ 'static,
+{
+	/// Check that the block `hash` is known, returning its header.
+	fn ensure_block(&self, hash: Block::Hash) -> Result<Block::Header> {
+		self.client
+			.header(BlockId::Hash(hash))
+			.map_err(client_err)?
+			.ok_or(Error::InvalidBlock)
+	}
+
+	/// Answer a single storage query, appending the results to `results`.
+	fn query(
+		&self,
+		header: &Block::Header,
+		child_info: Option<&ChildInfo>,
+		query: StorageQuery,
+		results: &mut Vec<StorageResult<Block::Hash>>,
+	) -> sp_blockchain::Result<()> {
+		let block = BlockId::Hash(header.hash());
+		let StorageQuery { key, query_type, pagination_start_key } = query;
+
+		if query_type == StorageQueryType::ClosestDescendantMerkleValue {
+			if let Some(merkle_value) =
+				self.closest_descendant_merkle_value(header, child_info, &key)?
+			{
+				results.push(StorageResult {
+					key,
+					result: StorageResultType::ClosestDescendantMerkleValue(merkle_value.into()),
+				});
+			}
+			return Ok(())
+		}
+
+		let keys: Vec<StorageKey> = if query_type.is_descendants_query() {
+			// The key itself is not returned by the key iterator, which starts after its
+			// start key.
+			let first = match pagination_start_key {
+				None => Some(key.clone()),
+				Some(_) => None,
+			};
+			let start_key = pagination_start_key.as_ref().unwrap_or(&key);
+			let descendants = match child_info {
+				Some(child_info) => self.client.child_storage_keys_iter(
+					&block,
+					child_info.clone(),
+					Some(&key),
+					Some(start_key),
+				)?,
+				None => self.client.storage_keys_iter(&block, Some(&key), Some(start_key))?,
+			};
+			first.into_iter().chain(descendants).collect()
+		} else {
+			vec![key]
+		};
+
+		let mut responses = 0;
+		for key in keys {
+			if responses == MAX_DESCENDANT_RESPONSES {
+				break
+			}
+			let result = match query_type {
+				StorageQueryType::Value | StorageQueryType::DescendantsValues => match child_info {
+					Some(child_info) => self.client.child_storage(&block, child_info, &key)?,
+					None => self.client.storage(&block, &key)?,
+				}
+				.map(StorageResultType::Value),
+				_ => match child_info {
+					Some(child_info) => self.client.child_storage_hash(&block, child_info, &key)?,
+					None => self.client.storage_hash(&block, &key)?,
+				}
+				.map(StorageResultType::Hash),
+			};
+			if let Some(result) = result {
+				results.push(StorageResult { key, result });
+				responses += 1;
+			}
+		}
+		Ok(())
+	}
+
+	/// The Merkle value of the closest descendant of `key`, read from a proof of its lookup.
+	fn closest_descendant_merkle_value(
+		&self,
+		header: &Block::Header,
+		child_info: Option<&ChildInfo>,
+		key: &StorageKey,
+	) -> sp_blockchain::Result<Option<Vec<u8>>> {
+		let block = BlockId::Hash(header.hash());
+		let (root, proof) = match child_info {
+			Some(child_info) => {
+				let prefixed_key = StorageKey(child_info.prefixed_storage_key().into_inner());
+				let root = match self.client.storage(&block, &prefixed_key)? {
+					Some(root) => Block::Hash::decode(&mut &root.0[..])
+						.map_err(|_| sp_blockchain::Error::InvalidChildStorageKey)?,
+					None => return Ok(None),
+				};
+				let proof = self.client.read_child_proof(
+					&block,
+					child_info,
+					&mut iter::once(&key.0[..]),
+				)?;
+				(root, proof)
+			},
+			None =>
+				(*header.state_root(), self.client.read_proof(&block, &mut iter::once(&key.0[..]))?),
+		};
+
+		let db = proof.into_memory_db::<HashFor<Block>>();
+		sp_trie::read_closest_descendant_merkle_value::<sp_trie::Layout<HashFor<Block>>, _>(
+			&db, &root, &key.0,
+		)
+		.map_err(|e| sp_blockchain::Error::Storage(e.to_string()))
+	}
+}
+
+impl<BE, Block, Client> ArchiveApi<Block::Hash> for Archive<BE, Block, Client>
+where
+	Block: BlockT + 'static,
+	BE: Backend<Block> + 'static,
+	Client: BlockBackend<Block>
+		+ ExecutorProvider<Block>
+		+ StorageProvider<Block, BE>
+		+ ProofProvider<Block>
+		+ HeaderBackend<Block>
+		+ HeaderMetadata<Block, Error = sp_blockchain::Error>
+		+ Send
+		+ Sync
+		+ 'static,
+{
+	fn hash_by_height(&self, height: u64) -> Result<Vec<Block::Hash>> {
+		let height = match NumberFor::<Block>::try_from(height) {
+			Ok(height) => height,
+			Err(_) => return Ok(Vec::new()),
+		};
+
+		if height <= self.client.info().finalized_number {
+			return self
+				.client
+				.hash(height)
+				.map(|hash| hash.into_iter().collect())
+				.map_err(client_err)
+		}
+
+		// Above the finalized height, each fork may have a block at that height.
+		let mut hashes = Vec::new();
+		for leaf in self.backend.blockchain().leaves().map_err(client_err)? {
+			let mut header = self.client.header_metadata(leaf).map_err(client_err)?;
+			if header.number < height {
+				continue
+			}
+			while header.number > height {
+				header = self.client.header_metadata(header.parent).map_err(client_err)?;
+			}
+			if !hashes.contains(&header.hash) {
+				hashes.push(header.hash);
+			}
+		}
+		Ok(hashes)
+	}
+
+	fn body(&self, hash: Block::Hash) -> Result<Option<Vec<Bytes>>> {
+		self.client
+			.block_body(&BlockId::Hash(hash))
+			.map(|body| {
+				body.map(|body| {
+					body.into_iter().map(|extrinsic| extrinsic.encode().into()).collect()
+				})
+			})
+			.map_err(client_err)
+	}
+
+	fn call(
+		&self,
+		hash: Block::Hash,
+		function: String,
+		call_parameters: Bytes,
+	) -> Result<MethodResult> {
+		self.ensure_block(hash)?;
+		let result = self.client.executor().call(
+			&BlockId::Hash(hash),
+			&function,
+			&*call_parameters,
+			self.client.execution_extensions().strategies().other,
+			None,
+		);
+		Ok(match result {
+			Ok(result) => MethodResult::ok(result),
+			Err(e) => MethodResult::err(e.to_string()),
+		})
+	}
+
+	fn storage(
+		&self,
+		hash: Block::Hash,
+		items: Vec<StorageQuery>,
+		child_trie: Option<PrefixedStorageKey>,
+	) -> Result<ArchiveStorageResult<Block::Hash>> {
+		let header = self.ensure_block(hash)?;
+		let child_info = match child_trie {
+			Some(child_trie) => match ChildType::from_prefixed_key(&child_trie) {
+				Some((ChildType::ParentKeyId, storage_key)) =>
+					Some(ChildInfo::new_default(storage_key)),
+				None => return Err(Error::InvalidChildTrie),
+			},
+			None => None,
+		};
+
+		let discarded_items = items.len().saturating_sub(MAX_QUERIED_ITEMS);
+		let mut result = Vec::new();
+		for query in items.into_iter().take(MAX_QUERIED_ITEMS) {
+			self.query(&header, child_info.as_ref(), query, &mut result)
+				.map_err(client_err)?;
+		}
+		Ok(ArchiveStorageResult { result, discarded_items })
+	}
+}
+
+fn client_err(err: sp_blockchain::Error) -> Error {
+	Error::Client(Box::new(err))
+}
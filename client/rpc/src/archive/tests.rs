@@ -0,0 +1,210 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use assert_matches::assert_matches;
+use futures::executor;
+use sc_block_builder::BlockBuilderProvider;
+use sp_consensus::BlockOrigin;
+use sp_core::{hash::H256, storage::StorageData};
+use substrate_test_runtime_client::{prelude::*, runtime::Block, Backend};
+
+const CHILD_STORAGE_KEY: &[u8] = b"child";
+
+type Api = Archive<Backend, Block, Client<Backend>>;
+
+fn new_api() -> (Arc<Client<Backend>>, Api) {
+	let child_info = ChildInfo::new_default(CHILD_STORAGE_KEY);
+	let (client, backend) = TestClientBuilder::new()
+		.add_extra_storage(b":map:acc1".to_vec(), vec![1])
+		.add_extra_storage(b":map:acc2".to_vec(), vec![2])
+		.add_extra_storage(b":map:acc3".to_vec(), vec![3])
+		.add_extra_child_storage(&child_info, b":child".to_vec(), vec![4])
+		.build_with_backend();
+	let client = Arc::new(client);
+	(client.clone(), Archive::new(client, backend))
+}
+
+fn query(key: &[u8], query_type: StorageQueryType) -> StorageQuery {
+	StorageQuery { key: StorageKey(key.to_vec()), query_type, pagination_start_key: None }
+}
+
+#[test]
+fn should_return_hashes_by_height() {
+	let (mut client, api) = new_api();
+	let genesis_hash = client.genesis_hash();
+
+	let a1 = client.new_block(Default::default()).unwrap().build().unwrap().block;
+	executor::block_on(client.import(BlockOrigin::Own, a1.clone())).unwrap();
+	let mut builder = client
+		.new_block_at(&BlockId::Hash(genesis_hash), Default::default(), false)
+		.unwrap();
+	builder.push_storage_change(vec![1], None).unwrap();
+	let b1 = builder.build().unwrap().block;
+	executor::block_on(client.import(BlockOrigin::Own, b1.clone())).unwrap();
+
+	assert_matches!(api.hash_by_height(0), Ok(hashes) if hashes == vec![genesis_hash]);
+	let hashes = api.hash_by_height(1).unwrap();
+	assert_eq!(hashes.len(), 2);
+	assert!(hashes.contains(&a1.hash()) && hashes.contains(&b1.hash()));
+	assert_matches!(api.hash_by_height(2), Ok(hashes) if hashes.is_empty());
+
+	// Only the finalized block is returned once its height is finalized.
+	client.finalize_block(BlockId::Hash(a1.hash()), None).unwrap();
+	assert_matches!(api.hash_by_height(1), Ok(hashes) if hashes == vec![a1.hash()]);
+}
+
+#[test]
+fn should_return_body_and_call_result() {
+	let (client, api) = new_api();
+	let genesis_hash = client.genesis_hash();
+
+	assert_matches!(api.body(genesis_hash), Ok(Some(body)) if body.is_empty());
+	assert_matches!(api.body(H256::from_low_u64_be(5)), Ok(None));
+
+	assert_matches!(
+		api.call(genesis_hash, "Core_version".into(), Bytes(vec![])),
+		Ok(MethodResult { success: true, value: Some(_), .. })
+	);
+	assert_matches!(
+		api.call(genesis_hash, "Core_unknown".into(), Bytes(vec![])),
+		Ok(MethodResult { success: false, error: Some(_), .. })
+	);
+	assert_matches!(
+		api.call(H256::from_low_u64_be(5), "Core_version".into(), Bytes(vec![])),
+		Err(Error::InvalidBlock)
+	);
+}
+
+#[test]
+fn should_return_storage_values_and_hashes() {
+	let (client, api) = new_api();
+	let genesis_hash = client.genesis_hash();
+
+	let result = api
+		.storage(
+			genesis_hash,
+			vec![
+				query(b":map:acc1", StorageQueryType::Value),
+				query(b":map:acc2", StorageQueryType::Hash),
+				query(b":map:unknown", StorageQueryType::Value),
+			],
+			None,
+		)
+		.unwrap();
+	assert_eq!(result.discarded_items, 0);
+	assert_eq!(
+		result.result,
+		vec![
+			StorageResult {
+				key: StorageKey(b":map:acc1".to_vec()),
+				result: StorageResultType::Value(StorageData(vec![1])),
+			},
+			StorageResult {
+				key: StorageKey(b":map:acc2".to_vec()),
+				result: StorageResultType::Hash(
+					client
+						.storage_hash(
+							&BlockId::Hash(genesis_hash),
+							&StorageKey(b":map:acc2".to_vec())
+						)
+						.unwrap()
+						.unwrap()
+				),
+			},
+		]
+	);
+
+	let child_trie = ChildInfo::new_default(CHILD_STORAGE_KEY).prefixed_storage_key();
+	let result = api
+		.storage(genesis_hash, vec![query(b":child", StorageQueryType::Value)], Some(child_trie))
+		.unwrap();
+	assert_eq!(result.result[0].result, StorageResultType::Value(StorageData(vec![4])));
+
+	assert_matches!(api.storage(H256::from_low_u64_be(5), vec![], None), Err(Error::InvalidBlock));
+	assert_matches!(
+		api.storage(genesis_hash, vec![], Some(PrefixedStorageKey::new(b"invalid".to_vec()))),
+		Err(Error::InvalidChildTrie)
+	);
+}
+
+#[test]
+fn should_paginate_descendants() {
+	let (client, api) = new_api();
+	let genesis_hash = client.genesis_hash();
+	let keys = |result: ArchiveStorageResult<H256>| {
+		result.result.into_iter().map(|r| r.key.0).collect::<Vec<_>>()
+	};
+
+	let result = api
+		.storage(genesis_hash, vec![query(b":map", StorageQueryType::DescendantsValues)], None)
+		.unwrap();
+	assert_eq!(
+		keys(result),
+		vec![b":map:acc1".to_vec(), b":map:acc2".to_vec(), b":map:acc3".to_vec()]
+	);
+
+	let mut paginated = query(b":map", StorageQueryType::DescendantsHashes);
+	paginated.pagination_start_key = Some(StorageKey(b":map:acc1".to_vec()));
+	let result = api.storage(genesis_hash, vec![paginated], None).unwrap();
+	assert_eq!(keys(result), vec![b":map:acc2".to_vec(), b":map:acc3".to_vec()]);
+
+	// Descendants queries return a bounded number of results.
+	let result = api
+		.storage(genesis_hash, vec![query(b"", StorageQueryType::DescendantsValues)], None)
+		.unwrap();
+	assert_eq!(result.result.len(), MAX_DESCENDANT_RESPONSES);
+}
+
+#[test]
+fn should_discard_items_beyond_limit() {
+	let (client, api) = new_api();
+	let genesis_hash = client.genesis_hash();
+
+	let items = vec![query(b":map:acc1", StorageQueryType::Value); MAX_QUERIED_ITEMS + 2];
+	let result = api.storage(genesis_hash, items, None).unwrap();
+	assert_eq!(result.result.len(), MAX_QUERIED_ITEMS);
+	assert_eq!(result.discarded_items, 2);
+}
+
+#[test]
+fn should_return_closest_descendant_merkle_value() {
+	let (client, api) = new_api();
+	let genesis_hash = client.genesis_hash();
+	let header = client.header(BlockId::Hash(genesis_hash)).unwrap().unwrap();
+
+	let result = api
+		.storage(
+			genesis_hash,
+			vec![
+				query(b"", StorageQueryType::ClosestDescendantMerkleValue),
+				query(b":map", StorageQueryType::ClosestDescendantMerkleValue),
+			],
+			None,
+		)
+		.unwrap();
+	// The closest descendant of the empty key is the root of the trie.
+	assert_eq!(
+		result.result[0].result,
+		StorageResultType::ClosestDescendantMerkleValue(
+			header.state_root().as_ref().to_vec().into()
+		)
+	);
+	assert_matches!(result.result[1].result, StorageResultType::ClosestDescendantMerkleValue(_));
+	assert_ne!(result.result[0].result, result.result[1].result);
+}
@@ -35,7 +35,7 @@ use jsonrpc_pubsub::{manager::SubscriptionManager, typed::Subscriber, Subscripti
 use sc_rpc_api::DenyUnsafe;
 use sc_transaction_pool_api::{
 	error::IntoPoolError, BlockHash, InPoolTransaction, TransactionFor, TransactionPool,
-	TransactionSource, TransactionStatus, TxHash,
+	TransactionSource, TransactionStatus, TransactionTag, TxHash,
 };
 use sp_api::ProvideRuntimeApi;
 use sp_core::Bytes;
@@ -178,6 +178,23 @@ where
 			.collect())
 	}
 
+	fn inspect_ready(&self) -> Result<Vec<inspect::PoolTransaction<TxHash<P>>>> {
+		self.deny_unsafe.check_if_safe()?;
+
+		Ok(self.pool.ready().map(|tx| pool_transaction(&*tx, Vec::new())).collect())
+	}
+
+	fn inspect_future(&self) -> Result<Vec<inspect::PoolTransaction<TxHash<P>>>> {
+		self.deny_unsafe.check_if_safe()?;
+
+		Ok(self
+			.pool
+			.futures()
+			.into_iter()
+			.map(|(tx, missing_tags)| pool_transaction(&*tx, missing_tags))
+			.collect())
+	}
+
 	fn watch_extrinsic(
 		&self,
 		_metadata: Self::Metadata,
@@ -241,3 +258,26 @@ where
 		Ok(self.subscriptions.cancel(id))
 	}
 }
+
+/// Describe a transaction in the pool for the inspection RPCs.
+fn pool_transaction<T>(
+	tx: &T,
+	missing_tags: Vec<TransactionTag>,
+) -> inspect::PoolTransaction<T::Hash>
+where
+	T: InPoolTransaction,
+	T::Transaction: Encode,
+	T::Hash: Clone,
+{
+	let tags = |tags: &[TransactionTag]| tags.iter().cloned().map(Bytes).collect();
+	inspect::PoolTransaction {
+		hash: tx.hash().clone(),
+		extrinsic: tx.data().encode().into(),
+		priority: *tx.priority(),
+		valid_till: *tx.longevity(),
+		requires: tags(tx.requires()),
+		provides: tags(tx.provides()),
+		propagate: tx.is_propagable(),
+		missing_tags: missing_tags.into_iter().map(Bytes).collect(),
+	}
+}
@@ -35,7 +35,7 @@ use sp_keystore::testing::KeyStore;
 use std::{mem, sync::Arc};
 use substrate_test_runtime_client::{
 	self,
-	runtime::{Block, Extrinsic, SessionKeys, Transfer},
+	runtime::{AccountId, Block, Extrinsic, SessionKeys, Transfer},
 	AccountKeyring, Backend, Client, DefaultTestClientBuilderExt, TestClientBuilderExt,
 };
 
@@ -189,6 +189,45 @@ fn should_return_pending_extrinsics() {
 	);
 }
 
+#[test]
+fn should_inspect_ready_and_future_extrinsics() {
+	let p = TestSetup::default().author();
+
+	let ready = uxt(AccountKeyring::Alice, 0);
+	let future = uxt(AccountKeyring::Alice, 2);
+	let ready_hash = executor::block_on(p.submit_extrinsic(ready.encode().into())).unwrap();
+	let future_hash = executor::block_on(p.submit_extrinsic(future.encode().into())).unwrap();
+
+	let alice: AccountId = AccountKeyring::Alice.into();
+	let tag = |nonce: u64| Bytes((&alice, nonce).encode());
+	assert_eq!(
+		p.inspect_ready().unwrap(),
+		vec![inspect::PoolTransaction {
+			hash: ready_hash,
+			extrinsic: ready.encode().into(),
+			priority: 0,
+			valid_till: 64,
+			requires: vec![],
+			provides: vec![tag(0)],
+			propagate: true,
+			missing_tags: vec![],
+		}],
+	);
+	let futures = p.inspect_future().unwrap();
+	assert_eq!(futures.len(), 1);
+	assert_eq!(futures[0].hash, future_hash);
+	assert_eq!(futures[0].missing_tags, vec![tag(1)]);
+}
+
+#[test]
+fn should_deny_unsafe_pool_inspection() {
+	let mut p = TestSetup::default().author();
+	p.deny_unsafe = DenyUnsafe::Yes;
+
+	assert_matches!(p.inspect_ready(), Err(Error::UnsafeRpcCalled(_)));
+	assert_matches!(p.inspect_future(), Err(Error::UnsafeRpcCalled(_)));
+}
+
 #[test]
 fn should_remove_extrinsics() {
 	let setup = TestSetup::default();
@@ -0,0 +1,640 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Substrate chain head API, following the `chainHead` functions of the JSON-RPC interface
+//! specification.
+
+#[cfg(test)]
+mod tests;
+
+use futures::{stream, FutureExt, SinkExt, StreamExt};
+use jsonrpc_pubsub::{manager::SubscriptionManager, typed::Subscriber, SubscriptionId};
+use log::warn;
+use parking_lot::{Mutex, RwLock};
+use rpc::{serde::Serialize, Result as RpcResult};
+use std::{
+	collections::{HashMap, HashSet},
+	sync::Arc,
+};
+
+use codec::Encode;
+use sc_client_api::{
+	Backend, BlockBackend, BlockImportNotification, BlockchainEvents, CallExecutor,
+	ExecutorProvider, FinalityNotification, StorageProvider,
+};
+use sp_api::CallApiAt;
+use sp_blockchain::{HeaderBackend, HeaderMetadata};
+use sp_core::{
+	storage::{ChildInfo, ChildType, PrefixedStorageKey, StorageData, StorageKey},
+	Bytes,
+};
+use sp_runtime::{
+	generic::BlockId,
+	traits::{Block as BlockT, NumberFor},
+};
+
+use self::{
+	error::{Error, Result},
+	event::{
+		BestBlockChanged, ChainHeadEvent, ChainHeadResult, ErrorEvent, Finalized, FollowEvent,
+		Initialized, NewBlock, RuntimeEvent, RuntimeVersionEvent,
+	},
+};
+
+pub use sc_rpc_api::chain_head::*;
+
+/// The maximum number of blocks a single follow subscription may keep pinned. A subscription
+/// which would exceed it is stopped.
+const MAX_PINNED_BLOCKS: usize = 512;
+
+/// The state of a follow subscription, shared between the task generating its events and the
+/// methods querying its blocks.
+struct FollowSubscription<Block: BlockT, BE: Backend<Block>> {
+	/// Backend keeping the pinned blocks from being pruned.
+	backend: Arc<BE>,
+	/// The blocks reported and not unpinned yet.
+	pinned: HashSet<Block::Hash>,
+	/// Whether the subscription has been stopped.
+	stopped: bool,
+	/// The key of the subscription in `ChainHead::follow_subscriptions`, once registered.
+	key: Option<String>,
+}
+
+impl<Block: BlockT, BE: Backend<Block>> FollowSubscription<Block, BE> {
+	fn new(backend: Arc<BE>) -> Self {
+		Self { backend, pinned: HashSet::new(), stopped: false, key: None }
+	}
+
+	/// Pin `hash` in the backend, returning `false` if that fails or would exceed
+	/// `MAX_PINNED_BLOCKS`.
+	fn pin(&mut self, hash: Block::Hash) -> bool {
+		if self.pinned.contains(&hash) {
+			return true
+		}
+		if self.pinned.len() >= MAX_PINNED_BLOCKS {
+			return false
+		}
+		if let Err(e) = self.backend.pin_block(&hash) {
+			warn!("Failed to pin block {:?}: {:?}", hash, e);
+			return false
+		}
+		self.pinned.insert(hash);
+		true
+	}
+
+	/// Release the pin of `hash`, if any.
+	fn unpin(&mut self, hash: &Block::Hash) {
+		if self.pinned.remove(hash) {
+			self.backend.unpin_block(hash);
+		}
+	}
+
+	/// Stop the subscription, releasing all of its blocks.
+	fn stop(&mut self) {
+		self.stopped = true;
+		for hash in self.pinned.drain() {
+			self.backend.unpin_block(&hash);
+		}
+	}
+}
+
+type SharedFollowSubscription<Block, BE> = Arc<Mutex<FollowSubscription<Block, BE>>>;
+
+type FollowSubscriptions<Block, BE> =
+	Arc<RwLock<HashMap<String, SharedFollowSubscription<Block, BE>>>>;
+
+/// Stops a follow subscription and unregisters it when dropped, which happens once its events
+/// task finishes for any reason, including the subscriber going away.
+struct FollowGuard<Block: BlockT, BE: Backend<Block>> {
+	subscription: SharedFollowSubscription<Block, BE>,
+	follow_subscriptions: FollowSubscriptions<Block, BE>,
+}
+
+impl<Block: BlockT, BE: Backend<Block>> Drop for FollowGuard<Block, BE> {
+	fn drop(&mut self) {
+		let mut follow_subscriptions = self.follow_subscriptions.write();
+		let mut subscription = self.subscription.lock();
+		subscription.stop();
+		if let Some(key) = subscription.key.take() {
+			follow_subscriptions.remove(&key);
+		}
+	}
+}
+
+/// A notification of the client, to be turned into follow events.
+enum Notification<Block: BlockT> {
+	Import(BlockImportNotification<Block>),
+	Finality(FinalityNotification<Block>),
+}
+
+/// Generates the events of a single follow subscription.
+struct Follower<Block: BlockT, BE: Backend<Block>, Client> {
+	client: Arc<Client>,
+	subscription: SharedFollowSubscription<Block, BE>,
+	/// Whether to report the runtime of blocks.
+	runtime_updates: bool,
+	/// The latest finalized block reported.
+	finalized: (Block::Hash, NumberFor<Block>),
+	/// The reported blocks which are neither finalized nor pruned yet.
+	unfinalized: HashSet<Block::Hash>,
+	/// The latest best block reported.
+	best: Option<Block::Hash>,
+}
+
+impl<Block, BE, Client> Follower<Block, BE, Client>
+where
+	Block: BlockT + 'static,
+	BE: Backend<Block>,
+	Client: HeaderBackend<Block>
+		+ HeaderMetadata<Block, Error = sp_blockchain::Error>
+		+ CallApiAt<Block>
+		+ 'static,
+{
+	fn new(
+		client: Arc<Client>,
+		subscription: SharedFollowSubscription<Block, BE>,
+		runtime_updates: bool,
+	) -> Self {
+		let info = client.info();
+		Self {
+			client,
+			subscription,
+			runtime_updates,
+			finalized: (info.finalized_hash, info.finalized_number),
+			unfinalized: HashSet::new(),
+			best: None,
+		}
+	}
+
+	/// The events reporting the current state of the chain.
+	fn initial_events(&mut self) -> Vec<FollowEvent<Block::Hash>> {
+		let finalized_hash = self.finalized.0;
+		let finalized_block_runtime =
+			if self.runtime_updates { Some(self.runtime_event(finalized_hash)) } else { None };
+		let mut events = vec![FollowEvent::Initialized(Initialized {
+			finalized_block_hash: finalized_hash,
+			finalized_block_runtime,
+		})];
+
+		if !self.subscription.lock().pin(finalized_hash) {
+			self.stop(&mut events);
+			return events
+		}
+		let best_hash = self.client.info().best_hash;
+		if self.report_block(best_hash, &mut events) {
+			self.report_best(best_hash, &mut events);
+		}
+		events
+	}
+
+	/// The events following the given client notification.
+	fn handle(&mut self, notification: Notification<Block>) -> Vec<FollowEvent<Block::Hash>> {
+		let mut events = Vec::new();
+		match notification {
+			Notification::Import(notification) => {
+				if self.report_block(notification.hash, &mut events) && notification.is_new_best {
+					self.report_best(notification.hash, &mut events);
+				}
+			},
+			Notification::Finality(notification) => {
+				// Make sure the finalized block has been reported before.
+				if self.report_block(notification.hash, &mut events) {
+					self.report_finalized(notification.hash, &mut events);
+				}
+			},
+		}
+		events
+	}
+
+	/// Report `hash` along with any of its ancestors which have not been reported yet.
+	///
+	/// Returns `false` if the block could not be reported, in which case it must not be
+	/// referenced by later events.
+	fn report_block(
+		&mut self,
+		hash: Block::Hash,
+		events: &mut Vec<FollowEvent<Block::Hash>>,
+	) -> bool {
+		let mut new_blocks = Vec::new();
+		let mut current = hash;
+		while current != self.finalized.0 && !self.unfinalized.contains(&current) {
+			let header = match self.client.header_metadata(current) {
+				Ok(header) => header,
+				Err(e) => {
+					warn!("Failed to retrieve header of block {:?}: {:?}", current, e);
+					return false
+				},
+			};
+			// The block is not a descendant of the finalized block, and won't be reported.
+			if header.number <= self.finalized.1 {
+				return false
+			}
+			new_blocks.push((current, header.parent));
+			current = header.parent;
+		}
+
+		for (hash, parent) in new_blocks.into_iter().rev() {
+			if !self.subscription.lock().pin(hash) {
+				self.stop(events);
+				return false
+			}
+			self.unfinalized.insert(hash);
+			let new_runtime = self.new_runtime(hash, parent);
+			events.push(FollowEvent::NewBlock(NewBlock {
+				block_hash: hash,
+				parent_block_hash: parent,
+				new_runtime,
+			}));
+		}
+		true
+	}
+
+	/// Report `hash` as the best block, unless it already is.
+	fn report_best(&mut self, hash: Block::Hash, events: &mut Vec<FollowEvent<Block::Hash>>) {
+		if self.best != Some(hash) {
+			self.best = Some(hash);
+			events.push(FollowEvent::BestBlockChanged(BestBlockChanged { best_block_hash: hash }));
+		}
+	}
+
+	/// Report the finalization of the reported block `hash` and its ancestors, pruning any
+	/// reported block which does not descend from it.
+	fn report_finalized(&mut self, hash: Block::Hash, events: &mut Vec<FollowEvent<Block::Hash>>) {
+		let route = match sp_blockchain::tree_route(&*self.client, self.finalized.0, hash) {
+			Ok(route) => route,
+			Err(e) => {
+				warn!("Failed to compute the route to finalized block {:?}: {:?}", hash, e);
+				return
+			},
+		};
+		let finalized_block_hashes: Vec<_> = route.enacted().iter().map(|b| b.hash).collect();
+		// Nothing new has been finalized.
+		let last = match route.enacted().last() {
+			Some(last) => (last.hash, last.number),
+			None => return,
+		};
+		for hash in &finalized_block_hashes {
+			self.unfinalized.remove(hash);
+		}
+		self.finalized = last;
+
+		let pruned_block_hashes: Vec<_> = self
+			.unfinalized
+			.iter()
+			.filter(|hash| !self.descends_from_finalized(**hash))
+			.cloned()
+			.collect();
+		for hash in &pruned_block_hashes {
+			self.unfinalized.remove(hash);
+		}
+
+		// A pruned best block is replaced before the pruning is reported.
+		if self.best.map_or(false, |best| pruned_block_hashes.contains(&best)) {
+			let best_hash = self.client.info().best_hash;
+			if self.report_block(best_hash, events) {
+				self.report_best(best_hash, events);
+			} else if self.subscription.lock().stopped {
+				return
+			}
+		}
+
+		events.push(FollowEvent::Finalized(Finalized {
+			finalized_block_hashes,
+			pruned_block_hashes,
+		}));
+	}
+
+	/// Whether `hash` is a descendant of the latest finalized block.
+	fn descends_from_finalized(&self, hash: Block::Hash) -> bool {
+		sp_blockchain::tree_route(&*self.client, self.finalized.0, hash)
+			.map(|route| route.retracted().is_empty())
+			.unwrap_or(false)
+	}
+
+	/// The runtime of `hash`, if it differs from the runtime of `parent` and runtime updates
+	/// were requested.
+	fn new_runtime(&self, hash: Block::Hash, parent: Block::Hash) -> Option<RuntimeEvent> {
+		if !self.runtime_updates {
+			return None
+		}
+		let runtime = self.runtime_event(hash);
+		if runtime != self.runtime_event(parent) {
+			Some(runtime)
+		} else {
+			None
+		}
+	}
+
+	/// The runtime of `hash`.
+	fn runtime_event(&self, hash: Block::Hash) -> RuntimeEvent {
+		match self.client.runtime_version_at(&BlockId::Hash(hash)) {
+			Ok(spec) => RuntimeEvent::Valid(RuntimeVersionEvent { spec }),
+			Err(e) => RuntimeEvent::Invalid(ErrorEvent { error: e.to_string() }),
+		}
+	}
+
+	/// Stop the subscription.
+	fn stop(&self, events: &mut Vec<FollowEvent<Block::Hash>>) {
+		self.subscription.lock().stop();
+		events.push(FollowEvent::Stop);
+	}
+}
+
+/// Chain head API for full nodes.
+pub struct ChainHead<BE: Backend<Block>, Block: BlockT, Client> {
+	/// Substrate client.
+	client: Arc<Client>,
+	/// Backend of the chain.
+	backend: Arc<BE>,
+	/// Current subscriptions.
+	subscriptions: SubscriptionManager,
+	/// The state of the follow subscriptions, by subscription ID.
+	follow_subscriptions: FollowSubscriptions<Block, BE>,
+	/// The hash of the genesis block.
+	genesis_hash: Block::Hash,
+}
+
+impl<BE, Block: BlockT, Client> ChainHead<BE, Block, Client>
+where
+	BE: Backend<Block>,
+	Client: HeaderBackend<Block>,
+{
+	/// Create a new chain head API.
+	pub fn new(client: Arc<Client>, backend: Arc<BE>, subscriptions: SubscriptionManager) -> Self {
+		let genesis_hash = client.info().genesis_hash;
+		Self {
+			client,
+			backend,
+			subscriptions,
+			follow_subscriptions: Default::default(),
+			genesis_hash,
+		}
+	}
+
+	/// Check that `hash` is pinned by `follow_subscription`.
+	fn ensure_pinned(&self, follow_subscription: &str, hash: &Block::Hash) -> Result<()> {
+		let subscription = self
+			.follow_subscriptions
+			.read()
+			.get(follow_subscription)
+			.cloned()
+			.ok_or(Error::InvalidSubscription)?;
+		let subscription = subscription.lock();
+		if subscription.stopped {
+			return Err(Error::InvalidSubscription)
+		}
+		if !subscription.pinned.contains(hash) {
+			return Err(Error::InvalidBlock)
+		}
+		Ok(())
+	}
+
+	/// Answer a query at the block `hash` of `follow_subscription` with a single event.
+	fn respond<T, F>(
+		&self,
+		subscriber: Subscriber<ChainHeadEvent<T>>,
+		follow_subscription: &str,
+		hash: &Block::Hash,
+		query: F,
+	) where
+		T: Serialize + Unpin + Send + 'static,
+		F: FnOnce() -> ChainHeadEvent<T>,
+	{
+		let event = match self.ensure_pinned(follow_subscription, hash) {
+			Ok(()) => query(),
+			Err(Error::InvalidSubscription) => ChainHeadEvent::Disjoint,
+			Err(e) => {
+				let _ = subscriber.reject(e.into());
+				return
+			},
+		};
+
+		self.subscriptions.add(subscriber, |sink| {
+			stream::iter(vec![Ok(Ok(event))])
+				.forward(sink.sink_map_err(|e| warn!("Error sending notifications: {:?}", e)))
+				// we ignore the resulting Stream (if the first stream is over we are unsubscribed)
+				.map(|_| ())
+		});
+	}
+}
+
+impl<BE, Block, Client> ChainHeadApi<Block::Hash> for ChainHead<BE, Block, Client>
+where
+	Block: BlockT + 'static,
+	Block::Hash: Unpin,
+	BE: Backend<Block> + 'static,
+	Client: BlockBackend<Block>
+		+ ExecutorProvider<Block>
+		+ StorageProvider<Block, BE>
+		+ HeaderBackend<Block>
+		+ HeaderMetadata<Block, Error = sp_blockchain::Error>
+		+ BlockchainEvents<Block>
+		+ CallApiAt<Block>
+		+ Send
+		+ Sync
+		+ 'static,
+{
+	type Metadata = crate::Metadata;
+
+	fn follow(
+		&self,
+		_metadata: Self::Metadata,
+		subscriber: Subscriber<FollowEvent<Block::Hash>>,
+		runtime_updates: bool,
+	) {
+		let subscription = Arc::new(Mutex::new(FollowSubscription::new(self.backend.clone())));
+		// Listen to the notifications before reporting the current state, so that no block is
+		// missed in between.
+		let mut notifications = stream::select(
+			self.client.import_notification_stream().map(Notification::Import),
+			self.client.finality_notification_stream().map(Notification::Finality),
+		);
+		let mut follower =
+			Follower::new(self.client.clone(), subscription.clone(), runtime_updates);
+
+		// Created outside of the closure, so that it is also dropped if the closure is never
+		// called.
+		let guard = FollowGuard {
+			subscription: subscription.clone(),
+			follow_subscriptions: self.follow_subscriptions.clone(),
+		};
+
+		let id = self.subscriptions.add(subscriber, |sink| {
+			let mut sink = sink.sink_map_err(|e| warn!("Error sending notifications: {:?}", e));
+			async move {
+				let _guard = guard;
+				let mut events = follower.initial_events();
+				loop {
+					for event in events {
+						let stop = event == FollowEvent::Stop;
+						if sink.send(Ok(event)).await.is_err() || stop {
+							return
+						}
+					}
+					events = match notifications.next().await {
+						Some(notification) => follower.handle(notification),
+						None => return,
+					};
+				}
+			}
+		});
+
+		// The subscription may already be over, in which case it must not be registered.
+		let mut follow_subscriptions = self.follow_subscriptions.write();
+		let mut state = subscription.lock();
+		if !state.stopped {
+			let key = subscription_key(&id);
+			state.key = Some(key.clone());
+			drop(state);
+			follow_subscriptions.insert(key, subscription);
+		}
+	}
+
+	fn unfollow(&self, _metadata: Option<Self::Metadata>, id: SubscriptionId) -> RpcResult<bool> {
+		// The events task releases the subscription once cancelled, but it may not run before the
+		// next call, so release it right away.
+		if let Some(subscription) = self.follow_subscriptions.write().remove(&subscription_key(&id))
+		{
+			let mut subscription = subscription.lock();
+			subscription.key = None;
+			subscription.stop();
+		}
+		Ok(self.subscriptions.cancel(id))
+	}
+
+	fn body(
+		&self,
+		_metadata: Self::Metadata,
+		subscriber: Subscriber<ChainHeadEvent<Vec<Bytes>>>,
+		follow_subscription: String,
+		hash: Block::Hash,
+	) {
+		self.respond(subscriber, &follow_subscription, &hash, || {
+			match self.client.block_body(&BlockId::Hash(hash)) {
+				Ok(Some(body)) => ChainHeadEvent::Done(ChainHeadResult {
+					result: body.into_iter().map(|extrinsic| extrinsic.encode().into()).collect(),
+				}),
+				Ok(None) => ChainHeadEvent::Inaccessible(ErrorEvent {
+					error: "Block body not found".into(),
+				}),
+				Err(e) => ChainHeadEvent::Error(ErrorEvent { error: e.to_string() }),
+			}
+		})
+	}
+
+	fn stop_body(&self, _metadata: Option<Self::Metadata>, id: SubscriptionId) -> RpcResult<bool> {
+		Ok(self.subscriptions.cancel(id))
+	}
+
+	fn call(
+		&self,
+		_metadata: Self::Metadata,
+		subscriber: Subscriber<ChainHeadEvent<Bytes>>,
+		follow_subscription: String,
+		hash: Block::Hash,
+		function: String,
+		call_parameters: Bytes,
+	) {
+		self.respond(subscriber, &follow_subscription, &hash, || {
+			let result = self.client.executor().call(
+				&BlockId::Hash(hash),
+				&function,
+				&*call_parameters,
+				self.client.execution_extensions().strategies().other,
+				None,
+			);
+			match result {
+				Ok(result) => ChainHeadEvent::Done(ChainHeadResult { result: result.into() }),
+				Err(e) => ChainHeadEvent::Error(ErrorEvent { error: e.to_string() }),
+			}
+		})
+	}
+
+	fn stop_call(&self, _metadata: Option<Self::Metadata>, id: SubscriptionId) -> RpcResult<bool> {
+		Ok(self.subscriptions.cancel(id))
+	}
+
+	fn storage(
+		&self,
+		_metadata: Self::Metadata,
+		subscriber: Subscriber<ChainHeadEvent<Option<StorageData>>>,
+		follow_subscription: String,
+		hash: Block::Hash,
+		key: StorageKey,
+		child_key: Option<PrefixedStorageKey>,
+	) {
+		self.respond(subscriber, &follow_subscription, &hash, || {
+			let block = BlockId::Hash(hash);
+			let result = match child_key {
+				Some(child_key) => match ChildType::from_prefixed_key(&child_key) {
+					Some((ChildType::ParentKeyId, storage_key)) => self.client.child_storage(
+						&block,
+						&ChildInfo::new_default(storage_key),
+						&key,
+					),
+					None => Err(sp_blockchain::Error::InvalidChildStorageKey),
+				},
+				None => self.client.storage(&block, &key),
+			};
+			match result {
+				Ok(result) => ChainHeadEvent::Done(ChainHeadResult { result }),
+				Err(e) => ChainHeadEvent::Error(ErrorEvent { error: e.to_string() }),
+			}
+		})
+	}
+
+	fn stop_storage(
+		&self,
+		_metadata: Option<Self::Metadata>,
+		id: SubscriptionId,
+	) -> RpcResult<bool> {
+		Ok(self.subscriptions.cancel(id))
+	}
+
+	fn header(&self, follow_subscription: String, hash: Block::Hash) -> Result<Option<Bytes>> {
+		self.ensure_pinned(&follow_subscription, &hash)?;
+		self.client
+			.header(BlockId::Hash(hash))
+			.map(|header| header.map(|header| header.encode().into()))
+			.map_err(client_err)
+	}
+
+	fn genesis_hash(&self) -> Result<Block::Hash> {
+		Ok(self.genesis_hash)
+	}
+
+	fn unpin(&self, follow_subscription: String, hash: Block::Hash) -> Result<()> {
+		self.ensure_pinned(&follow_subscription, &hash)?;
+		if let Some(subscription) = self.follow_subscriptions.read().get(&follow_subscription) {
+			subscription.lock().unpin(&hash);
+		}
+		Ok(())
+	}
+}
+
+/// The key of a follow subscription, as given by the subscriber to the other methods.
+fn subscription_key(id: &SubscriptionId) -> String {
+	match id {
+		SubscriptionId::Number(n) => n.to_string(),
+		SubscriptionId::String(s) => s.clone(),
+	}
+}
+
+fn client_err(err: sp_blockchain::Error) -> Error {
+	Error::Client(Box::new(err))
+}
@@ -0,0 +1,154 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::testing::TaskExecutor;
+use assert_matches::assert_matches;
+use futures::executor;
+use sc_block_builder::BlockBuilderProvider;
+use sp_consensus::BlockOrigin;
+use substrate_test_runtime_client::{
+	prelude::*,
+	runtime::{Block, H256},
+	Backend,
+};
+
+type Api = ChainHead<Backend, Block, Client<Backend>>;
+
+/// Create the API over a new test client.
+fn new_api() -> (Arc<Client<Backend>>, Api) {
+	let builder = TestClientBuilder::new();
+	let backend = builder.backend();
+	let client = Arc::new(builder.build());
+	let api =
+		ChainHead::new(client.clone(), backend, SubscriptionManager::new(Arc::new(TaskExecutor)));
+	(client, api)
+}
+
+/// Start following the chain, returning the subscription ID and the notifications transport.
+fn follow(api: &Api) -> (String, futures::channel::mpsc::UnboundedReceiver<String>) {
+	let (subscriber, id, transport) = Subscriber::new_test("test");
+	api.follow(Default::default(), subscriber, false);
+	let id = match executor::block_on(id) {
+		Ok(Ok(id)) => subscription_key(&id),
+		_ => panic!("Follow subscription must be accepted"),
+	};
+	(id, transport)
+}
+
+#[test]
+fn follow_reports_initial_state_and_new_blocks() {
+	let (mut client, api) = new_api();
+	let (_, mut transport) = follow(&api);
+
+	// The genesis block is finalized and best.
+	let initialized = executor::block_on(transport.next()).unwrap();
+	assert!(initialized.contains(r#""event":"initialized""#));
+	assert!(initialized.contains(&format!("{:?}", client.genesis_hash())));
+	let best = executor::block_on(transport.next()).unwrap();
+	assert!(best.contains(r#""event":"bestBlockChanged""#));
+
+	let block = client.new_block(Default::default()).unwrap().build().unwrap().block;
+	let block_hash = block.hash();
+	executor::block_on(client.import(BlockOrigin::Own, block)).unwrap();
+
+	let new_block = executor::block_on(transport.next()).unwrap();
+	assert!(new_block.contains(r#""event":"newBlock""#));
+	assert!(new_block.contains(&format!("{:?}", block_hash)));
+	let best = executor::block_on(transport.next()).unwrap();
+	assert!(best.contains(r#""event":"bestBlockChanged""#));
+
+	client.finalize_block(BlockId::Hash(block_hash), None).unwrap();
+	let finalized = executor::block_on(transport.next()).unwrap();
+	assert!(finalized.contains(r#""event":"finalized""#));
+	assert!(finalized.contains(&format!("{:?}", block_hash)));
+}
+
+#[test]
+fn header_requires_pinned_block() {
+	let (mut client, api) = new_api();
+	let genesis_hash = client.genesis_hash();
+	let (id, mut transport) = follow(&api);
+	// Wait for the initial events, the blocks are pinned once reported.
+	executor::block_on((&mut transport).take(2).collect::<Vec<_>>());
+
+	assert_matches!(api.header(id.clone(), genesis_hash), Ok(Some(_)));
+	assert_matches!(api.header(id.clone(), H256::from_low_u64_be(5)), Err(Error::InvalidBlock));
+	assert_matches!(api.header("unknown".into(), genesis_hash), Err(Error::InvalidSubscription));
+
+	// A block imported after the subscription started is pinned once reported.
+	let block = client.new_block(Default::default()).unwrap().build().unwrap().block;
+	let block_hash = block.hash();
+	executor::block_on(client.import(BlockOrigin::Own, block)).unwrap();
+	let (id, mut transport) = follow(&api);
+	executor::block_on((&mut transport).take(3).collect::<Vec<_>>());
+	assert_matches!(api.header(id, block_hash), Ok(Some(_)));
+}
+
+#[test]
+fn unpin_releases_block() {
+	let (client, api) = new_api();
+	let genesis_hash = client.genesis_hash();
+	let (id, mut transport) = follow(&api);
+	// Wait for the initial events, the blocks are pinned once reported.
+	executor::block_on((&mut transport).take(2).collect::<Vec<_>>());
+
+	assert_matches!(api.unpin(id.clone(), genesis_hash), Ok(()));
+	assert_matches!(api.unpin(id.clone(), genesis_hash), Err(Error::InvalidBlock));
+	assert_matches!(api.header(id, genesis_hash), Err(Error::InvalidBlock));
+}
+
+#[test]
+fn unfollow_invalidates_subscription() {
+	let (client, api) = new_api();
+	let genesis_hash = client.genesis_hash();
+	let (id, mut transport) = follow(&api);
+	// Wait for the initial events, the blocks are pinned once reported.
+	executor::block_on((&mut transport).take(2).collect::<Vec<_>>());
+
+	assert_matches!(api.unfollow(None, SubscriptionId::String(id.clone())), Ok(true));
+	assert_matches!(api.header(id, genesis_hash), Err(Error::InvalidSubscription));
+}
+
+#[test]
+fn genesis_hash_is_returned() {
+	let (client, api) = new_api();
+
+	assert_matches!(api.genesis_hash(), Ok(hash) if hash == client.genesis_hash());
+}
+
+#[test]
+fn subscription_is_released_when_the_subscriber_goes_away() {
+	let (client, api) = new_api();
+	let (id, mut transport) = follow(&api);
+	executor::block_on((&mut transport).take(2).collect::<Vec<_>>());
+	assert_eq!(api.follow_subscriptions.read().len(), 1);
+
+	// The events task finishes on the next event it fails to send.
+	drop(transport);
+	let mut client = client;
+	let block = client.new_block(Default::default()).unwrap().build().unwrap().block;
+	executor::block_on(client.import(BlockOrigin::Own, block)).unwrap();
+	for _ in 0..100 {
+		if api.follow_subscriptions.read().is_empty() {
+			break
+		}
+		std::thread::sleep(std::time::Duration::from_millis(10));
+	}
+	assert_matches!(api.header(id, client.genesis_hash()), Err(Error::InvalidSubscription));
+}
@@ -32,11 +32,14 @@ use std::sync::Arc;
 pub use rpc::IoHandlerExtension as RpcExtension;
 pub use sc_rpc_api::{DenyUnsafe, Metadata};
 
+pub mod archive;
 pub mod author;
 pub mod chain;
+pub mod chain_head;
 pub mod offchain;
 pub mod state;
 pub mod system;
+pub mod transaction;
 
 #[cfg(any(test, feature = "test-helpers"))]
 pub mod testing;
@@ -54,6 +57,9 @@ impl SubscriptionTaskExecutor {
 
 impl Spawn for SubscriptionTaskExecutor {
 	fn spawn_obj(&self, future: FutureObj<'static, ()>) -> Result<(), SpawnError> {
+		// Keep the guard of the call spawning the task, e.g. the subscription slot of the RPC
+		// middleware, alive until the task ends.
+		let future = sc_utils::task_guard::bind(future);
 		self.0.spawn("substrate-rpc-subscription", future.map(drop).boxed());
 		Ok(())
 	}
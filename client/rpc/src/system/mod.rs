@@ -24,6 +24,7 @@ use sc_rpc_api::{DenyUnsafe, Receiver};
 use sc_tracing::logging;
 use sc_utils::mpsc::TracingUnboundedSender;
 use sp_runtime::traits::{self, Header as HeaderT};
+use std::time::Duration;
 
 pub use self::{
 	gen_client::Client as SystemClient,
@@ -69,6 +70,14 @@ pub enum Request<B: traits::Block> {
 	NetworkRemoveReservedPeer(String, oneshot::Sender<Result<()>>),
 	/// Must return the list of reserved peers
 	NetworkReservedPeers(oneshot::Sender<Vec<String>>),
+	/// Must switch in or out of reserved-only mode.
+	NetworkSetReservedOnly(bool, oneshot::Sender<()>),
+	/// Must return the reputation of the peer, or any potential parse error.
+	NetworkPeerReputation(String, oneshot::Sender<Result<i32>>),
+	/// Must return any potential parse error.
+	NetworkBanPeer(String, Duration, oneshot::Sender<Result<()>>),
+	/// Must return any potential parse error.
+	NetworkUnbanPeer(String, oneshot::Sender<Result<()>>),
 	/// Must return the node role.
 	NodeRoles(oneshot::Sender<Vec<NodeRole>>),
 	/// Must return the state of the node syncing.
@@ -184,6 +193,62 @@ impl<B: traits::Block> SystemApi<B::Hash, <B::Header as HeaderT>::Number> for Sy
 		Receiver(rx)
 	}
 
+	fn system_set_reserved_only(&self, reserved_only: bool) -> rpc::BoxFuture<rpc::Result<()>> {
+		bail_if_unsafe!(self.deny_unsafe);
+
+		let (tx, rx) = oneshot::channel();
+		let _ = self
+			.send_back
+			.unbounded_send(Request::NetworkSetReservedOnly(reserved_only, tx));
+		async move { rx.await.map_err(|_| rpc::Error::internal_error()) }.boxed()
+	}
+
+	fn system_peer_reputation(&self, peer: String) -> rpc::BoxFuture<rpc::Result<i32>> {
+		bail_if_unsafe!(self.deny_unsafe);
+
+		let (tx, rx) = oneshot::channel();
+		let _ = self.send_back.unbounded_send(Request::NetworkPeerReputation(peer, tx));
+		async move {
+			match rx.await {
+				Ok(Ok(reputation)) => Ok(reputation),
+				Ok(Err(e)) => Err(rpc::Error::from(e)),
+				Err(_) => Err(rpc::Error::internal_error()),
+			}
+		}
+		.boxed()
+	}
+
+	fn system_ban_peer(&self, peer: String, duration_secs: u64) -> rpc::BoxFuture<rpc::Result<()>> {
+		bail_if_unsafe!(self.deny_unsafe);
+
+		let (tx, rx) = oneshot::channel();
+		let duration = Duration::from_secs(duration_secs);
+		let _ = self.send_back.unbounded_send(Request::NetworkBanPeer(peer, duration, tx));
+		async move {
+			match rx.await {
+				Ok(Ok(())) => Ok(()),
+				Ok(Err(e)) => Err(rpc::Error::from(e)),
+				Err(_) => Err(rpc::Error::internal_error()),
+			}
+		}
+		.boxed()
+	}
+
+	fn system_unban_peer(&self, peer: String) -> rpc::BoxFuture<rpc::Result<()>> {
+		bail_if_unsafe!(self.deny_unsafe);
+
+		let (tx, rx) = oneshot::channel();
+		let _ = self.send_back.unbounded_send(Request::NetworkUnbanPeer(peer, tx));
+		async move {
+			match rx.await {
+				Ok(Ok(())) => Ok(()),
+				Ok(Err(e)) => Err(rpc::Error::from(e)),
+				Err(_) => Err(rpc::Error::internal_error()),
+			}
+		}
+		.boxed()
+	}
+
 	fn system_node_roles(&self) -> Receiver<Vec<NodeRole>> {
 		let (tx, rx) = oneshot::channel();
 		let _ = self.send_back.unbounded_send(Request::NodeRoles(tx));
@@ -110,6 +110,24 @@ fn api<T: Into<Option<Status>>>(sync: T) -> System<Block> {
 					let _ = sender
 						.send(vec!["QmSk5HQbn6LhUwDiNMseVUjuRYhEtYj4aUZ6WfWoGURpdV".to_string()]);
 				},
+				Request::NetworkSetReservedOnly(_, sender) => {
+					let _ = sender.send(());
+				},
+				Request::NetworkPeerReputation(peer, sender) => {
+					let _ = match peer.parse::<PeerId>() {
+						Ok(_) => sender.send(Ok(-256)),
+						Err(s) =>
+							sender.send(Err(error::Error::MalformattedPeerArg(s.to_string()))),
+					};
+				},
+				Request::NetworkBanPeer(peer, _, sender) |
+				Request::NetworkUnbanPeer(peer, sender) => {
+					let _ = match peer.parse::<PeerId>() {
+						Ok(_) => sender.send(Ok(())),
+						Err(s) =>
+							sender.send(Err(error::Error::MalformattedPeerArg(s.to_string()))),
+					};
+				},
 				Request::NodeRoles(sender) => {
 					let _ = sender.send(vec![NodeRole::Authority]);
 				},
@@ -292,6 +310,35 @@ fn system_network_remove_reserved() {
 	assert!(executor::block_on(bad_fut).is_err());
 }
 
+#[test]
+fn system_network_set_reserved_only() {
+	assert_eq!(executor::block_on(api(None).system_set_reserved_only(true)), Ok(()));
+}
+
+#[test]
+fn system_network_peer_reputation() {
+	let good_peer_id = "QmSk5HQbn6LhUwDiNMseVUjuRYhEtYj4aUZ6WfWoGURpdV";
+	let bad_peer_id = "/ip4/198.51.100.19/tcp/30333";
+
+	let good_fut = api(None).system_peer_reputation(good_peer_id.into());
+	let bad_fut = api(None).system_peer_reputation(bad_peer_id.into());
+	assert_eq!(executor::block_on(good_fut), Ok(-256));
+	assert!(executor::block_on(bad_fut).is_err());
+}
+
+#[test]
+fn system_network_ban_and_unban() {
+	let good_peer_id = "QmSk5HQbn6LhUwDiNMseVUjuRYhEtYj4aUZ6WfWoGURpdV";
+	let bad_peer_id = "/ip4/198.51.100.19/tcp/30333";
+
+	let ban_fut = api(None).system_ban_peer(good_peer_id.into(), 60);
+	let bad_ban_fut = api(None).system_ban_peer(bad_peer_id.into(), 60);
+	let unban_fut = api(None).system_unban_peer(good_peer_id.into());
+	assert_eq!(executor::block_on(ban_fut), Ok(()));
+	assert!(executor::block_on(bad_ban_fut).is_err());
+	assert_eq!(executor::block_on(unban_fut), Ok(()));
+}
+
 #[test]
 fn system_network_reserved_peers() {
 	assert_eq!(
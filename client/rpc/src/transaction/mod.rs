@@ -0,0 +1,225 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Substrate transaction API, following the `transactionWatch` functions of the JSON-RPC
+//! interface specification.
+
+#[cfg(test)]
+mod tests;
+
+use futures::{future, stream, FutureExt, SinkExt, StreamExt};
+use jsonrpc_pubsub::{manager::SubscriptionManager, typed::Subscriber, SubscriptionId};
+use log::warn;
+use rpc::Result as RpcResult;
+use std::sync::Arc;
+
+use codec::{Decode, Encode};
+use sc_client_api::BlockBackend;
+use sc_transaction_pool_api::{
+	error::{Error as PoolError, IntoPoolError},
+	BlockHash, TransactionFor, TransactionPool, TransactionSource, TransactionStatus,
+};
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+use self::event::{TransactionBlock, TransactionEvent};
+
+pub use sc_rpc_api::transaction::*;
+
+/// Transactions submitted through this API are treated as externals, like the ones of the
+/// author API.
+const TX_SOURCE: TransactionSource = TransactionSource::External;
+
+/// Transaction API for full nodes.
+pub struct Transaction<P, Client> {
+	/// Substrate client.
+	client: Arc<Client>,
+	/// Transactions pool.
+	pool: Arc<P>,
+	/// Subscriptions manager.
+	subscriptions: SubscriptionManager,
+}
+
+impl<P, Client> Transaction<P, Client> {
+	/// Create a new transaction API.
+	pub fn new(client: Arc<Client>, pool: Arc<P>, subscriptions: SubscriptionManager) -> Self {
+		Self { client, pool, subscriptions }
+	}
+}
+
+/// Turns the statuses of a transaction in the pool into events.
+struct TransactionEvents<Client> {
+	client: Arc<Client>,
+	/// The SCALE-encoded transaction.
+	transaction: Bytes,
+	/// Whether the `validated` event has been generated.
+	validated: bool,
+	/// Whether the transaction has been broadcasted to other nodes.
+	broadcasted: bool,
+}
+
+impl<Client> TransactionEvents<Client> {
+	/// The event following `status`, if any.
+	fn handle<Block, Hash>(
+		&mut self,
+		status: TransactionStatus<Hash, Block::Hash>,
+	) -> Option<TransactionEvent<Block::Hash>>
+	where
+		Block: BlockT,
+		Client: BlockBackend<Block>,
+	{
+		match status {
+			TransactionStatus::Future | TransactionStatus::Ready => {
+				if self.validated {
+					return None
+				}
+				self.validated = true;
+				Some(TransactionEvent::Validated)
+			},
+			TransactionStatus::Broadcast(_) => {
+				self.broadcasted = true;
+				None
+			},
+			// The transaction is expected to be in the block: if it can't be found there, there is
+			// no position to report, so the block is skipped.
+			TransactionStatus::InBlock(hash) => self
+				.transaction_block(hash)
+				.map(|block| TransactionEvent::BestChainBlockIncluded { block: Some(block) }),
+			TransactionStatus::Retracted(_) =>
+				Some(TransactionEvent::BestChainBlockIncluded { block: None }),
+			TransactionStatus::Finalized(hash) => Some(match self.transaction_block(hash) {
+				Some(block) => TransactionEvent::Finalized { block },
+				None => TransactionEvent::Error {
+					error: format!("Transaction not found in finalized block {:?}", hash),
+				},
+			}),
+			TransactionStatus::FinalityTimeout(_) => Some(TransactionEvent::Dropped {
+				broadcasted: self.broadcasted,
+				error: "Maximum number of finality watchers has been reached".into(),
+			}),
+			TransactionStatus::Dropped => Some(TransactionEvent::Dropped {
+				broadcasted: self.broadcasted,
+				error: "Extrinsic dropped from the pool due to exceeding limits".into(),
+			}),
+			TransactionStatus::Usurped(_) => Some(TransactionEvent::Invalid {
+				error: "Extrinsic was rendered invalid by another extrinsic".into(),
+			}),
+			TransactionStatus::Invalid =>
+				Some(TransactionEvent::Invalid { error: "Extrinsic marked as invalid".into() }),
+		}
+	}
+
+	/// The position of the transaction in the block `hash`, if it can be found there.
+	fn transaction_block<Block>(&self, hash: Block::Hash) -> Option<TransactionBlock<Block::Hash>>
+	where
+		Block: BlockT,
+		Client: BlockBackend<Block>,
+	{
+		let body = match self.client.block_body(&BlockId::Hash(hash)) {
+			Ok(Some(body)) => body,
+			Ok(None) => {
+				warn!("Body of block {:?} including a watched transaction is missing", hash);
+				return None
+			},
+			Err(e) => {
+				warn!("Failed to retrieve body of block {:?}: {:?}", hash, e);
+				return None
+			},
+		};
+		let index = body.iter().position(|extrinsic| extrinsic.encode() == self.transaction.0);
+		if index.is_none() {
+			warn!("Watched transaction not found in the body of block {:?}", hash);
+		}
+		index.map(|index| TransactionBlock { hash, index })
+	}
+}
+
+/// The event reporting a failed submission.
+fn submission_error<Hash>(error: PoolError) -> TransactionEvent<Hash> {
+	match error {
+		PoolError::ImmediatelyDropped | PoolError::SenderLimitReached =>
+			TransactionEvent::Dropped { broadcasted: false, error: error.to_string() },
+		PoolError::InvalidBlockId(_) | PoolError::RejectedFutureTransaction =>
+			TransactionEvent::Error { error: error.to_string() },
+		error => TransactionEvent::Invalid { error: error.to_string() },
+	}
+}
+
+impl<P, Client> TransactionApi<BlockHash<P>> for Transaction<P, Client>
+where
+	P: TransactionPool + Sync + Send + 'static,
+	P::Hash: Unpin,
+	BlockHash<P>: Unpin,
+	Client: HeaderBackend<P::Block> + BlockBackend<P::Block> + Send + Sync + 'static,
+{
+	type Metadata = crate::Metadata;
+
+	fn submit_and_watch(
+		&self,
+		_metadata: Self::Metadata,
+		subscriber: Subscriber<TransactionEvent<BlockHash<P>>>,
+		transaction: Bytes,
+	) {
+		let best_block_hash = self.client.info().best_hash;
+		let submit = TransactionFor::<P>::decode(&mut &transaction[..]).map(|decoded| {
+			self.pool.submit_and_watch(&BlockId::hash(best_block_hash), TX_SOURCE, decoded)
+		});
+
+		let mut events = TransactionEvents {
+			client: self.client.clone(),
+			transaction,
+			validated: false,
+			broadcasted: false,
+		};
+		let subscriptions = self.subscriptions.clone();
+
+		let future = async move {
+			let submitted = match submit {
+				Ok(submit) => submit.await.map_err(|e| match e.into_pool_error() {
+					Ok(e) => submission_error(e),
+					Err(e) => TransactionEvent::Error { error: e.to_string() },
+				}),
+				Err(e) => Err(TransactionEvent::Invalid { error: e.to_string() }),
+			};
+
+			let stream = match submitted {
+				Ok(tx_stream) => tx_stream
+					.filter_map(move |status| future::ready(events.handle::<P::Block, _>(status)))
+					.boxed(),
+				Err(event) => stream::iter(vec![event]).boxed(),
+			};
+
+			subscriptions.add(subscriber, move |sink| {
+				stream
+					.map(|event| Ok(Ok(event)))
+					.forward(sink.sink_map_err(|e| warn!("Error sending notifications: {:?}", e)))
+					.map(drop)
+			});
+		};
+
+		let res = self.subscriptions.executor().spawn_obj(future.boxed().into());
+		if res.is_err() {
+			warn!("Error spawning subscription RPC task.");
+		}
+	}
+
+	fn unwatch(&self, _metadata: Option<Self::Metadata>, id: SubscriptionId) -> RpcResult<bool> {
+		Ok(self.subscriptions.cancel(id))
+	}
+}
@@ -0,0 +1,137 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use assert_matches::assert_matches;
+use futures::executor;
+use sc_transaction_pool::{BasicPool, FullChainApi};
+use substrate_test_runtime_client::{
+	self,
+	runtime::{Block, Extrinsic, Transfer},
+	AccountKeyring, Backend, Client,
+};
+
+type FullTransactionPool = BasicPool<FullChainApi<Client<Backend>, Block>, Block>;
+
+fn uxt(sender: AccountKeyring, nonce: u64, amount: u64) -> Extrinsic {
+	let tx = Transfer { amount, nonce, from: sender.into(), to: Default::default() };
+	tx.into_signed_tx()
+}
+
+fn setup() -> (Arc<FullTransactionPool>, Transaction<FullTransactionPool, Client<Backend>>) {
+	let client = Arc::new(substrate_test_runtime_client::new());
+	let spawner = sp_core::testing::TaskExecutor::new();
+	let pool = BasicPool::new_full(Default::default(), true.into(), None, spawner, client.clone());
+	let api = Transaction::new(
+		client,
+		pool.clone(),
+		SubscriptionManager::new(Arc::new(crate::testing::TaskExecutor)),
+	);
+	(pool, api)
+}
+
+fn notification(result: &str, id: &SubscriptionId) -> Option<String> {
+	let id = match id {
+		SubscriptionId::String(id) => id,
+		_ => unreachable!(),
+	};
+	Some(format!(
+		r#"{{"jsonrpc":"2.0","method":"test","params":{{"result":{},"subscription":"{}"}}}}"#,
+		result, id,
+	))
+}
+
+#[test]
+fn should_report_validated_then_invalid_when_usurped() {
+	let (pool, api) = setup();
+	let (subscriber, id_rx, data) = Subscriber::new_test("test");
+
+	api.submit_and_watch(
+		Default::default(),
+		subscriber,
+		uxt(AccountKeyring::Alice, 0, 0).encode().into(),
+	);
+	let id = executor::block_on(id_rx).unwrap().unwrap();
+	assert_matches!(id, SubscriptionId::String(_));
+
+	// A transaction with the same nonce and a higher priority replaces the watched one.
+	executor::block_on(pool.submit_one(
+		&BlockId::number(0),
+		TX_SOURCE,
+		uxt(AccountKeyring::Alice, 0, 5),
+	))
+	.unwrap();
+
+	let (res, data) = executor::block_on(data.into_future());
+	assert_eq!(res, notification(r#"{"event":"validated"}"#, &id));
+	let (res, _) = executor::block_on(data.into_future());
+	assert_eq!(
+		res,
+		notification(
+			r#"{"event":"invalid","error":"Extrinsic was rendered invalid by another extrinsic"}"#,
+			&id
+		)
+	);
+}
+
+#[test]
+fn should_report_undecodable_transaction_as_invalid() {
+	let (_, api) = setup();
+	let (subscriber, id_rx, data) = Subscriber::new_test("test");
+
+	api.submit_and_watch(Default::default(), subscriber, vec![0xff].into());
+	let id = executor::block_on(id_rx).unwrap().unwrap();
+	assert_matches!(id, SubscriptionId::String(_));
+
+	let (res, _) = executor::block_on(data.into_future());
+	assert_matches!(res, Some(ref n) if n.contains(r#"{"event":"invalid","error":"#));
+}
+
+#[test]
+fn should_report_already_imported_transaction_as_invalid() {
+	let (pool, api) = setup();
+	let xt = uxt(AccountKeyring::Alice, 0, 0);
+	executor::block_on(pool.submit_one(&BlockId::number(0), TX_SOURCE, xt.clone())).unwrap();
+
+	let (subscriber, id_rx, data) = Subscriber::new_test("test");
+	api.submit_and_watch(Default::default(), subscriber, xt.encode().into());
+	let id = executor::block_on(id_rx).unwrap().unwrap();
+	assert_matches!(id, SubscriptionId::String(_));
+
+	let (res, _) = executor::block_on(data.into_future());
+	assert_matches!(res, Some(ref n) if n.contains(r#"{"event":"invalid","error":"#));
+}
+
+#[test]
+fn should_not_report_blocks_missing_the_transaction() {
+	let client = Arc::new(substrate_test_runtime_client::new());
+	let genesis_hash = client.info().genesis_hash;
+	let mut events = TransactionEvents {
+		client,
+		transaction: uxt(AccountKeyring::Alice, 0, 0).encode().into(),
+		validated: false,
+		broadcasted: false,
+	};
+
+	// The genesis block doesn't include the transaction.
+	assert_eq!(events.handle::<Block, ()>(TransactionStatus::InBlock(genesis_hash)), None);
+	assert_matches!(
+		events.handle::<Block, ()>(TransactionStatus::Finalized(genesis_hash)),
+		Some(TransactionEvent::Error { .. })
+	);
+}
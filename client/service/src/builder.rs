@@ -34,11 +34,12 @@ use sc_client_api::{
 	proof_provider::ProofProvider, BadBlocks, BlockBackend, BlockchainEvents, ExecutorProvider,
 	ForkBlocks, StorageProvider, UsageProvider,
 };
-use sc_client_db::{Backend, DatabaseSettings};
+use sc_client_db::{Backend, ColumnStats, DatabaseSettings};
 use sc_consensus::import_queue::ImportQueue;
 use sc_executor::RuntimeVersionOf;
 use sc_keystore::LocalKeystore;
 use sc_network::{
+	block_relay::BlockRelayParams,
 	block_request_handler::{self, BlockRequestHandler},
 	config::{OnDemand, Role, SyncMode},
 	light_client_requests::{self, handler::LightClientRequestHandler},
@@ -303,6 +304,7 @@ where
 			state_cache_size: config.state_cache_size,
 			state_cache_child_ratio: config.state_cache_child_ratio.map(|v| (v, 100)),
 			state_pruning: config.state_pruning.clone(),
+			override_state_pruning: config.override_state_pruning,
 			source: config.database.clone(),
 			keep_blocks: config.keep_blocks.clone(),
 			transaction_storage: config.transaction_storage.clone(),
@@ -380,6 +382,7 @@ where
 			state_cache_size: config.state_cache_size,
 			state_cache_child_ratio: config.state_cache_child_ratio.map(|v| (v, 100)),
 			state_pruning: config.state_pruning.clone(),
+			override_state_pruning: config.override_state_pruning,
 			source: config.database.clone(),
 			keep_blocks: config.keep_blocks.clone(),
 			transaction_storage: config.transaction_storage.clone(),
@@ -418,6 +421,20 @@ where
 	Ok(Arc::new(Backend::new(settings, CANONICALIZATION_DELAY)?))
 }
 
+/// Create an instance of default DB-backend backend opened read-only, along with the statistics
+/// of the database columns.
+pub fn new_read_only_db_backend<Block>(
+	settings: DatabaseSettings,
+) -> Result<(Arc<Backend<Block>>, Vec<ColumnStats>), sp_blockchain::Error>
+where
+	Block: BlockT,
+{
+	const CANONICALIZATION_DELAY: u64 = 4096;
+
+	let (backend, stats) = Backend::new_read_only(settings, CANONICALIZATION_DELAY)?;
+	Ok((Arc::new(backend), stats))
+}
+
 /// Create an instance of client backed by given backend.
 pub fn new_client<E, Block, RA>(
 	backend: Arc<Backend<Block>>,
@@ -628,6 +645,7 @@ where
 			&config,
 			task_manager.spawn_handle(),
 			client.clone(),
+			backend.clone(),
 			transaction_pool.clone(),
 			keystore.clone(),
 			on_demand.clone(),
@@ -727,6 +745,7 @@ fn gen_handler<TBl, TBackend, TExPool, TRpc, TCl>(
 	config: &Configuration,
 	spawn_handle: SpawnTaskHandle,
 	client: Arc<TCl>,
+	backend: Arc<TBackend>,
 	transaction_pool: Arc<TExPool>,
 	keystore: SyncCryptoStorePtr,
 	on_demand: Option<Arc<OnDemand<TBl>>>,
@@ -756,7 +775,7 @@ where
 	TBl::Hash: Unpin,
 	TBl::Header: Unpin,
 {
-	use sc_rpc::{author, chain, offchain, state, system};
+	use sc_rpc::{archive, author, chain, chain_head, offchain, state, system, transaction};
 
 	let system_info = sc_rpc::system::SystemInfo {
 		chain_name: config.chain_spec.name().into(),
@@ -769,7 +788,7 @@ where
 	let task_executor = sc_rpc::SubscriptionTaskExecutor::new(spawn_handle);
 	let subscriptions = SubscriptionManager::new(Arc::new(task_executor.clone()));
 
-	let (chain, state, child_state) =
+	let (chain, chain_head, archive, state, child_state) =
 		if let (Some(remote_blockchain), Some(on_demand)) = (remote_blockchain, on_demand) {
 			// Light clients
 			let chain = sc_rpc::chain::new_light(
@@ -785,19 +804,30 @@ where
 				on_demand,
 				deny_unsafe,
 			);
-			(chain, state, child_state)
+			(chain, None, None, state, child_state)
 		} else {
 			// Full nodes
 			let chain = sc_rpc::chain::new_full(client.clone(), subscriptions.clone());
+			let chain_head =
+				chain_head::ChainHead::new(client.clone(), backend.clone(), subscriptions.clone());
+			let archive = archive::Archive::new(client.clone(), backend);
 			let (state, child_state) = sc_rpc::state::new_full(
 				client.clone(),
 				subscriptions.clone(),
 				deny_unsafe,
 				config.rpc_max_payload,
 			);
-			(chain, state, child_state)
+			(chain, Some(chain_head), Some(archive), state, child_state)
 		};
 
+	let maybe_chain_head_rpc = chain_head.map(chain_head::ChainHeadApi::to_delegate);
+	let maybe_archive_rpc = archive.map(archive::ArchiveApi::to_delegate);
+
+	let transaction = transaction::Transaction::new(
+		client.clone(),
+		transaction_pool.clone(),
+		subscriptions.clone(),
+	);
 	let author =
 		sc_rpc::author::Author::new(client, transaction_pool, subscriptions, keystore, deny_unsafe);
 	let system = system::System::new(system_info, system_rpc_tx, deny_unsafe);
@@ -812,6 +842,11 @@ where
 			state::StateApi::to_delegate(state),
 			state::ChildStateApi::to_delegate(child_state),
 			chain::ChainApi::to_delegate(chain),
+			(
+				maybe_chain_head_rpc,
+				maybe_archive_rpc,
+				transaction::TransactionApi::to_delegate(transaction),
+			),
 			maybe_offchain_rpc,
 			author::AuthorApi::to_delegate(author),
 			system::SystemApi::to_delegate(system),
@@ -840,6 +875,8 @@ pub struct BuildNetworkParams<'a, TBl: BlockT, TExPool, TImpQu, TCl> {
 		Option<Box<dyn FnOnce(Arc<TCl>) -> Box<dyn BlockAnnounceValidator<TBl> + Send> + Send>>,
 	/// An optional warp sync provider.
 	pub warp_sync: Option<Arc<dyn WarpSyncProvider<TBl>>>,
+	/// An optional block relay, replacing the default block request protocol.
+	pub block_relay: Option<BlockRelayParams<TBl>>,
 }
 
 /// Build the network service, the network status sinks and an RPC sender.
@@ -876,6 +913,7 @@ where
 		on_demand,
 		block_announce_validator_builder,
 		warp_sync,
+		block_relay,
 	} = params;
 
 	let transaction_pool_adapter = Arc::new(TransactionPoolAdapter {
@@ -892,21 +930,24 @@ where
 		Box::new(DefaultBlockAnnounceValidator)
 	};
 
-	let block_request_protocol_config = {
-		if matches!(config.role, Role::Light) {
-			// Allow outgoing requests but deny incoming requests.
-			block_request_handler::generate_protocol_config(&protocol_id)
-		} else {
-			// Allow both outgoing and incoming requests.
-			let (handler, protocol_config) = BlockRequestHandler::new(
-				&protocol_id,
-				client.clone(),
-				config.network.default_peers_set.in_peers as usize +
-					config.network.default_peers_set.out_peers as usize,
-			);
-			spawn_handle.spawn("block_request_handler", handler.run());
-			protocol_config
+	let (block_request_protocol_config, block_relay) = if let Some(params) = block_relay {
+		if let Some(server) = params.server {
+			spawn_handle.spawn("block_relay_server", server);
 		}
+		(params.request_response_config, Some(params.relay))
+	} else if matches!(config.role, Role::Light) {
+		// Allow outgoing requests but deny incoming requests.
+		(block_request_handler::generate_protocol_config(&protocol_id), None)
+	} else {
+		// Allow both outgoing and incoming requests.
+		let (handler, protocol_config) = BlockRequestHandler::new(
+			&protocol_id,
+			client.clone(),
+			config.network.default_peers_set.in_peers as usize +
+				config.network.default_peers_set.out_peers as usize,
+		);
+		spawn_handle.spawn("block_request_handler", handler.run());
+		(protocol_config, None)
 	};
 
 	let state_request_protocol_config = {
@@ -976,6 +1017,7 @@ where
 		block_announce_validator,
 		metrics_registry: config.prometheus_config.as_ref().map(|config| config.registry.clone()),
 		block_request_protocol_config,
+		block_relay,
 		state_request_protocol_config,
 		warp_sync: warp_sync_params,
 		light_client_request_protocol_config,
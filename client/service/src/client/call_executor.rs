@@ -32,7 +32,7 @@ use sp_runtime::{
 };
 use sp_state_machine::{
 	self, backend::Backend as _, ExecutionManager, ExecutionStrategy, Ext, OverlayedChanges,
-	StateMachine, StorageProof,
+	ProofSizeExt, StateMachine, StorageProof,
 };
 use std::{cell::RefCell, panic::UnwindSafe, result, sync::Arc};
 
@@ -241,6 +241,9 @@ where
 					recorder.clone(),
 				);
 
+				let mut extensions = extensions.unwrap_or_default();
+				extensions.register(ProofSizeExt::new(recorder.clone()));
+
 				let mut state_machine = StateMachine::new(
 					&backend,
 					changes_trie_state,
@@ -248,7 +251,7 @@ where
 					&self.executor,
 					method,
 					call_data,
-					extensions.unwrap_or_default(),
+					extensions,
 					&runtime_code,
 					self.spawn_handle.clone(),
 				)
@@ -369,6 +372,7 @@ mod tests {
 			WasmExecutionMethod::Interpreted,
 			Some(128),
 			1,
+			2,
 		);
 
 		let overrides = crate::client::wasm_override::dummy_overrides();
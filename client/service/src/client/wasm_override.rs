@@ -217,6 +217,7 @@ mod tests {
 				WasmExecutionMethod::Interpreted,
 				Some(128),
 				1,
+				2,
 			);
 		let bytes = substrate_test_runtime::wasm_binary_unwrap();
 		let dir = tempfile::tempdir().expect("Create a temporary directory");
@@ -231,6 +232,7 @@ mod tests {
 			WasmExecutionMethod::Interpreted,
 			Some(128),
 			1,
+			2,
 		);
 
 		let version = WasmOverride::runtime_version(&executor, &wasm, Some(128))
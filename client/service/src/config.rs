@@ -19,7 +19,10 @@
 //! Service configuration.
 
 pub use sc_client_api::execution_extensions::{ExecutionStrategies, ExecutionStrategy};
-pub use sc_client_db::{Database, DatabaseSource, KeepBlocks, PruningMode, TransactionStorageMode};
+pub use sc_client_db::{
+	ColumnStats, Database, DatabaseSettings, DatabaseSource, KeepBlocks, PruningMode,
+	TransactionStorageMode,
+};
 pub use sc_executor::WasmExecutionMethod;
 pub use sc_network::{
 	config::{
@@ -29,6 +32,7 @@ pub use sc_network::{
 	},
 	Multiaddr,
 };
+pub use sc_rpc_server::RpcLimits;
 
 use prometheus_endpoint::Registry;
 use sc_chain_spec::ChainSpec;
@@ -69,6 +73,8 @@ pub struct Configuration {
 	pub state_cache_child_ratio: Option<usize>,
 	/// State pruning settings.
 	pub state_pruning: PruningMode,
+	/// Use `state_pruning` even if the database was created with another state pruning mode.
+	pub override_state_pruning: bool,
 	/// Number of blocks to keep in the db.
 	pub keep_blocks: KeepBlocks,
 	/// Transaction storage scheme.
@@ -97,6 +103,10 @@ pub struct Configuration {
 	pub rpc_methods: RpcMethods,
 	/// Maximum payload of rpc request/responses.
 	pub rpc_max_payload: Option<usize>,
+	/// Maximum size of rpc requests, in megabytes. `None` if `rpc_max_payload` applies.
+	pub rpc_max_request_size: Option<usize>,
+	/// Limits enforced on the calls to the HTTP & WS RPC servers.
+	pub rpc_limits: RpcLimits,
 	/// Prometheus endpoint configuration. `None` if disabled.
 	pub prometheus_config: Option<PrometheusConfig>,
 	/// Telemetry service URL. `None` if disabled.
@@ -126,6 +136,10 @@ pub struct Configuration {
 	///
 	/// The default value is 8.
 	pub max_runtime_instances: usize,
+	/// The maximum number of different runtimes kept in the runtime cache.
+	///
+	/// The default value is 2.
+	pub runtime_cache_size: u8,
 	/// Announce block automatically after they have been imported
 	pub announce_block: bool,
 	/// Base path of the configuration
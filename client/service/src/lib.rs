@@ -49,17 +49,17 @@ use sp_runtime::{
 pub use self::{
 	builder::{
 		build_network, build_offchain_workers, new_client, new_db_backend, new_full_client,
-		new_full_parts, new_light_parts, spawn_tasks, BuildNetworkParams, KeystoreContainer,
-		NetworkStarter, NoopRpcExtensionBuilder, RpcExtensionBuilder, SpawnTasksParams,
-		TFullBackend, TFullCallExecutor, TFullClient, TLightBackend, TLightBackendWithHash,
-		TLightCallExecutor, TLightClient, TLightClientWithBackend,
+		new_full_parts, new_light_parts, new_read_only_db_backend, spawn_tasks, BuildNetworkParams,
+		KeystoreContainer, NetworkStarter, NoopRpcExtensionBuilder, RpcExtensionBuilder,
+		SpawnTasksParams, TFullBackend, TFullCallExecutor, TFullClient, TLightBackend,
+		TLightBackendWithHash, TLightCallExecutor, TLightClient, TLightClientWithBackend,
 	},
 	client::{ClientConfig, LocalCallExecutor},
 	error::Error,
 };
 pub use config::{
-	BasePath, Configuration, DatabaseSource, KeepBlocks, PruningMode, Role, RpcMethods, TaskType,
-	TransactionStorageMode,
+	BasePath, ColumnStats, Configuration, DatabaseSettings, DatabaseSource, KeepBlocks,
+	PruningMode, Role, RpcMethods, TaskType, TransactionStorageMode,
 };
 pub use sc_chain_spec::{
 	ChainSpec, ChainType, Extension as ChainSpecExtension, GenericChainSpec, NoExtension,
@@ -262,6 +262,44 @@ async fn build_network_future<
 
 						let _ = sender.send(reserved_peers);
 					}
+					sc_rpc::system::Request::NetworkSetReservedOnly(reserved_only, sender) => {
+						if reserved_only {
+							network.service().deny_unreserved_peers();
+						} else {
+							network.service().accept_unreserved_peers();
+						}
+						let _ = sender.send(());
+					}
+					sc_rpc::system::Request::NetworkPeerReputation(peer_id, sender) => {
+						let _ = match peer_id.parse::<PeerId>() {
+							Ok(peer_id) => sender.send(Ok(network.peer_reputation(&peer_id))),
+							Err(e) => sender.send(Err(sc_rpc::system::error::Error::MalformattedPeerArg(
+								e.to_string(),
+							))),
+						};
+					}
+					sc_rpc::system::Request::NetworkBanPeer(peer_id, duration, sender) => {
+						let _ = match peer_id.parse::<PeerId>() {
+							Ok(peer_id) => {
+								network.service().ban_peer(peer_id, duration);
+								sender.send(Ok(()))
+							}
+							Err(e) => sender.send(Err(sc_rpc::system::error::Error::MalformattedPeerArg(
+								e.to_string(),
+							))),
+						};
+					}
+					sc_rpc::system::Request::NetworkUnbanPeer(peer_id, sender) => {
+						let _ = match peer_id.parse::<PeerId>() {
+							Ok(peer_id) => {
+								network.service().unban_peer(peer_id);
+								sender.send(Ok(()))
+							}
+							Err(e) => sender.send(Err(sc_rpc::system::error::Error::MalformattedPeerArg(
+								e.to_string(),
+							))),
+						};
+					}
 					sc_rpc::system::Request::NodeRoles(sender) => {
 						use sc_rpc::system::NodeRole;
 
@@ -402,9 +440,11 @@ fn start_rpc_servers<
 						rpc_metrics.clone(),
 						rpc_method_names.clone(),
 						"http",
-					),
+					)
+					.with_limits(config.rpc_limits.clone()),
 				)?,
-				config.rpc_max_payload,
+				config.rpc_max_request_size.or(config.rpc_max_payload),
+				config.rpc_limits.trust_proxy_headers,
 				config.tokio_handle.clone(),
 			)
 			.map_err(Error::from)
@@ -421,9 +461,10 @@ fn start_rpc_servers<
 						rpc_metrics.clone(),
 						rpc_method_names.clone(),
 						"ws",
-					),
+					)
+					.with_limits(config.rpc_limits.clone()),
 				)?,
-				config.rpc_max_payload,
+				config.rpc_max_request_size.or(config.rpc_max_payload),
 				server_metrics.clone(),
 				config.tokio_handle.clone(),
 			)
@@ -153,17 +153,17 @@ impl sp_core::traits::SpawnNamed for SpawnTaskHandle {
 /// A wrapper over `SpawnTaskHandle` that will notify a receiver whenever any
 /// task spawned through it fails. The service should be on the receiver side
 /// and will shut itself down whenever it receives any message, i.e. an
-/// essential task has failed.
+/// essential task has failed. The message is the name of the failed task.
 #[derive(Clone)]
 pub struct SpawnEssentialTaskHandle {
-	essential_failed_tx: TracingUnboundedSender<()>,
+	essential_failed_tx: TracingUnboundedSender<&'static str>,
 	inner: SpawnTaskHandle,
 }
 
 impl SpawnEssentialTaskHandle {
 	/// Creates a new `SpawnEssentialTaskHandle`.
 	pub fn new(
-		essential_failed_tx: TracingUnboundedSender<()>,
+		essential_failed_tx: TracingUnboundedSender<&'static str>,
 		spawn_task_handle: SpawnTaskHandle,
 	) -> SpawnEssentialTaskHandle {
 		SpawnEssentialTaskHandle { essential_failed_tx, inner: spawn_task_handle }
@@ -196,6 +196,7 @@ impl SpawnEssentialTaskHandle {
 		let essential_failed = self.essential_failed_tx.clone();
 		let essential_task = std::panic::AssertUnwindSafe(task).catch_unwind().map(move |_| {
 			log::error!("Essential task `{}` failed. Shutting down service.", name);
+			let _ = essential_failed.unbounded_send(name);
 			let _ = essential_failed.close_channel();
 		});
 
@@ -226,9 +227,9 @@ pub struct TaskManager {
 	metrics: Option<Metrics>,
 	/// Send a signal when a spawned essential task has concluded. The next time
 	/// the service future is polled it should complete with an error.
-	essential_failed_tx: TracingUnboundedSender<()>,
+	essential_failed_tx: TracingUnboundedSender<&'static str>,
 	/// A receiver for spawned essential-tasks concluding.
-	essential_failed_rx: TracingUnboundedReceiver<()>,
+	essential_failed_rx: TracingUnboundedReceiver<&'static str>,
 	/// Things to keep alive until the task manager is dropped.
 	keep_alive: Box<dyn std::any::Any + Send>,
 	/// A sender to a stream of background tasks. This is used for the completion future.
@@ -340,7 +341,10 @@ impl TaskManager {
 			.fuse();
 
 			futures::select! {
-				_ = t1 => Err(Error::Other("Essential task failed.".into())),
+				name = t1 => Err(Error::Other(match name {
+					Some(name) => format!("Essential task `{}` failed.", name),
+					None => "Essential task failed.".into(),
+				})),
 				_ = t2 => Ok(()),
 				res = t3 => Err(res.map(|_| ()).expect_err("this future never ends; qed")),
 			}
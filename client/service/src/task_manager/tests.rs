@@ -204,9 +204,10 @@ fn ensure_task_manager_future_ends_with_error_when_essential_task_fails() {
 	runtime.block_on(async { tokio::time::sleep(Duration::from_secs(1)).await });
 	assert_eq!(drop_tester, 2);
 	spawn_essential_handle.spawn("task3", async { panic!("task failed") });
-	runtime
+	let err = runtime
 		.block_on(task_manager.future())
 		.expect_err("future()'s Result must be Err");
+	assert!(err.to_string().contains("task3"));
 	assert_eq!(drop_tester, 2);
 	runtime.block_on(task_manager.clean_shutdown());
 	drop_tester.wait_on_drop();
@@ -260,7 +260,7 @@ impl CallExecutor<Block> for DummyCallExecutor {
 
 fn local_executor() -> NativeElseWasmExecutor<substrate_test_runtime_client::LocalExecutorDispatch>
 {
-	NativeElseWasmExecutor::new(WasmExecutionMethod::Interpreted, None, 8)
+	NativeElseWasmExecutor::new(WasmExecutionMethod::Interpreted, None, 8, 2)
 }
 
 #[test]
@@ -77,7 +77,12 @@ impl sc_executor::NativeExecutionDispatch for ExecutorDispatch {
 }
 
 fn executor() -> sc_executor::NativeElseWasmExecutor<ExecutorDispatch> {
-	sc_executor::NativeElseWasmExecutor::new(sc_executor::WasmExecutionMethod::Interpreted, None, 8)
+	sc_executor::NativeElseWasmExecutor::new(
+		sc_executor::WasmExecutionMethod::Interpreted,
+		None,
+		8,
+		2,
+	)
 }
 
 pub fn prepare_client_with_key_changes() -> (
@@ -1438,6 +1443,7 @@ fn doesnt_import_blocks_that_revert_finality() {
 				state_cache_size: 1 << 20,
 				state_cache_child_ratio: None,
 				state_pruning: PruningMode::ArchiveAll,
+				override_state_pruning: false,
 				keep_blocks: KeepBlocks::All,
 				transaction_storage: TransactionStorageMode::BlockBody,
 				source: DatabaseSource::RocksDb { path: tmp.path().into(), cache_size: 1024 },
@@ -1653,6 +1659,7 @@ fn returns_status_for_pruned_blocks() {
 				state_cache_size: 1 << 20,
 				state_cache_child_ratio: None,
 				state_pruning: PruningMode::keep_blocks(1),
+				override_state_pruning: false,
 				keep_blocks: KeepBlocks::All,
 				transaction_storage: TransactionStorageMode::BlockBody,
 				source: DatabaseSource::RocksDb { path: tmp.path().into(), cache_size: 1024 },
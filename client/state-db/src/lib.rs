@@ -47,7 +47,7 @@ mod pruning;
 mod test;
 
 use codec::Codec;
-use log::trace;
+use log::{trace, warn};
 use noncanonical::NonCanonicalOverlay;
 use parity_util_mem::{malloc_size, MallocSizeOf};
 use parking_lot::RwLock;
@@ -238,18 +238,35 @@ struct StateDbSync<BlockHash: Hash, Key: Hash> {
 	non_canonical: NonCanonicalOverlay<BlockHash, Key>,
 	pruning: Option<RefWindow<BlockHash, Key>>,
 	pinned: HashMap<BlockHash, u32>,
+	/// Whether the pruning mode is to be saved with the next block, as it overrides another one.
+	save_mode: bool,
 }
 
 impl<BlockHash: Hash + MallocSizeOf, Key: Hash + MallocSizeOf> StateDbSync<BlockHash, Key> {
 	fn new<D: MetaDb>(
 		mode: PruningMode,
 		ref_counting: bool,
+		override_mode: bool,
 		db: &D,
 	) -> Result<StateDbSync<BlockHash, Key>, Error<D::Error>> {
 		trace!(target: "state-db", "StateDb settings: {:?}. Ref-counting: {}", mode, ref_counting);
 
 		// Check that settings match
-		Self::check_meta(&mode, db)?;
+		let save_mode = match Self::check_meta(&mode, db) {
+			Err(Error::InvalidPruningMode(db_mode)) if override_mode => {
+				warn!(
+					target: "state-db",
+					"Overriding pruning mode {} stored in the database with {}",
+					db_mode,
+					String::from_utf8_lossy(mode.id()),
+				);
+				true
+			},
+			result => {
+				result?;
+				false
+			},
+		};
 
 		let non_canonical: NonCanonicalOverlay<BlockHash, Key> = NonCanonicalOverlay::new(db)?;
 		let pruning: Option<RefWindow<BlockHash, Key>> = match mode {
@@ -258,7 +275,7 @@ impl<BlockHash: Hash + MallocSizeOf, Key: Hash + MallocSizeOf> StateDbSync<Block
 			PruningMode::ArchiveAll | PruningMode::ArchiveCanonical => None,
 		};
 
-		Ok(StateDbSync { mode, non_canonical, pruning, pinned: Default::default() })
+		Ok(StateDbSync { mode, non_canonical, pruning, pinned: Default::default(), save_mode })
 	}
 
 	fn check_meta<D: MetaDb>(mode: &PruningMode, db: &D) -> Result<(), Error<D::Error>> {
@@ -282,9 +299,11 @@ impl<BlockHash: Hash + MallocSizeOf, Key: Hash + MallocSizeOf> StateDbSync<Block
 		mut changeset: ChangeSet<Key>,
 	) -> Result<CommitSet<Key>, Error<E>> {
 		let mut meta = ChangeSet::default();
-		if number == 0 {
-			// Save pruning mode when writing first block.
+		if number == 0 || self.save_mode {
+			// Save pruning mode when writing first block, or the first block since it is
+			// overridden.
 			meta.inserted.push((to_meta_key(PRUNING_MODE, &()), self.mode.id().into()));
+			self.save_mode = false;
 		}
 
 		match self.mode {
@@ -478,12 +497,17 @@ pub struct StateDb<BlockHash: Hash, Key: Hash> {
 
 impl<BlockHash: Hash + MallocSizeOf, Key: Hash + MallocSizeOf> StateDb<BlockHash, Key> {
 	/// Creates a new instance. Does not expect any metadata in the database.
+	///
+	/// Fails if `mode` differs from the pruning mode stored in the database, unless
+	/// `override_mode` is set. The state kept under the stored mode is then not pruned
+	/// retroactively.
 	pub fn new<D: MetaDb>(
 		mode: PruningMode,
 		ref_counting: bool,
+		override_mode: bool,
 		db: &D,
 	) -> Result<StateDb<BlockHash, Key>, Error<D::Error>> {
-		Ok(StateDb { db: RwLock::new(StateDbSync::new(mode, ref_counting, db)?) })
+		Ok(StateDb { db: RwLock::new(StateDbSync::new(mode, ref_counting, override_mode, db)?) })
 	}
 
 	/// Add a new non-canonical block.
@@ -579,7 +603,7 @@ mod tests {
 
 	fn make_test_db(settings: PruningMode) -> (TestDb, StateDb<H256, H256>) {
 		let mut db = make_db(&[91, 921, 922, 93, 94]);
-		let state_db = StateDb::new(settings, false, &db).unwrap();
+		let state_db = StateDb::new(settings, false, false, &db).unwrap();
 
 		db.commit(
 			&state_db
@@ -694,7 +718,7 @@ mod tests {
 	#[test]
 	fn detects_incompatible_mode() {
 		let mut db = make_db(&[]);
-		let state_db = StateDb::new(PruningMode::ArchiveAll, false, &db).unwrap();
+		let state_db = StateDb::new(PruningMode::ArchiveAll, false, false, &db).unwrap();
 		db.commit(
 			&state_db
 				.insert_block::<io::Error>(
@@ -706,7 +730,39 @@ mod tests {
 				.unwrap(),
 		);
 		let new_mode = PruningMode::Constrained(Constraints { max_blocks: Some(2), max_mem: None });
-		let state_db: Result<StateDb<H256, H256>, _> = StateDb::new(new_mode, false, &db);
+		let state_db: Result<StateDb<H256, H256>, _> = StateDb::new(new_mode, false, false, &db);
 		assert!(state_db.is_err());
 	}
+
+	#[test]
+	fn overrides_incompatible_mode() {
+		let mut db = make_db(&[]);
+		let state_db = StateDb::new(PruningMode::ArchiveAll, false, false, &db).unwrap();
+		db.commit(
+			&state_db
+				.insert_block::<io::Error>(
+					&H256::from_low_u64_be(0),
+					0,
+					&H256::from_low_u64_be(0),
+					make_changeset(&[], &[]),
+				)
+				.unwrap(),
+		);
+		let new_mode = PruningMode::Constrained(Constraints { max_blocks: Some(2), max_mem: None });
+		let state_db: StateDb<H256, H256> =
+			StateDb::new(new_mode.clone(), false, true, &db).unwrap();
+		db.commit(
+			&state_db
+				.insert_block::<io::Error>(
+					&H256::from_low_u64_be(1),
+					1,
+					&H256::from_low_u64_be(0),
+					make_changeset(&[], &[]),
+				)
+				.unwrap(),
+		);
+		// The new mode is saved, so it is accepted without the override from now on.
+		assert!(StateDb::<H256, H256>::new(new_mode, false, false, &db).is_ok());
+		assert!(StateDb::<H256, H256>::new(PruningMode::ArchiveAll, false, false, &db).is_err());
+	}
 }
@@ -18,14 +18,20 @@
 
 use ansi_term::Colour;
 use regex::Regex;
-use std::fmt::{self, Write};
-use tracing::{Event, Level, Subscriber};
+use std::{
+	borrow::Cow,
+	fmt::{self, Write},
+};
+use tracing::{
+	field::{Field, Visit},
+	Event, Level, Subscriber,
+};
 use tracing_log::NormalizeEvent;
 use tracing_subscriber::{
 	field::RecordFields,
 	fmt::{
 		time::{FormatTime, SystemTime},
-		FmtContext, FormatEvent, FormatFields,
+		FmtContext, FormatEvent, FormatFields, FormattedFields,
 	},
 	layer::Context,
 	registry::{LookupSpan, SpanRef},
@@ -45,6 +51,10 @@ pub struct EventFormat<T = SystemTime> {
 	pub enable_color: bool,
 	/// Duplicate INFO, WARN and ERROR messages to stdout.
 	pub dup_to_stdout: bool,
+	/// Write every event as a single line JSON object instead of human readable text.
+	///
+	/// The `display_*` and `enable_color` options are ignored in this mode.
+	pub json: bool,
 }
 
 impl<T> EventFormat<T>
@@ -64,6 +74,10 @@ where
 		S: Subscriber + for<'a> LookupSpan<'a>,
 		N: for<'a> FormatFields<'a> + 'static,
 	{
+		if self.json {
+			return self.format_event_json(ctx, writer, event)
+		}
+
 		let writer = &mut MaybeColorWriter::new(self.enable_color, writer);
 		let normalized_meta = event.normalized_metadata();
 		let meta = normalized_meta.as_ref().unwrap_or_else(|| event.metadata());
@@ -107,6 +121,105 @@ where
 
 		writer.write()
 	}
+
+	/// Write `event` as a JSON object with its metadata, its fields and the fields of all the
+	/// spans it is in.
+	fn format_event_json<'b, S, N>(
+		&self,
+		ctx: CustomFmtContext<'b, S, N>,
+		writer: &mut dyn fmt::Write,
+		event: &Event,
+	) -> fmt::Result
+	where
+		S: Subscriber + for<'a> LookupSpan<'a>,
+		N: for<'a> FormatFields<'a> + 'static,
+	{
+		let normalized_meta = event.normalized_metadata();
+		let meta = normalized_meta.as_ref().unwrap_or_else(|| event.metadata());
+
+		let mut timestamp = String::new();
+		self.timer.format_time(&mut timestamp)?;
+
+		let mut fields = JsonFields::default();
+		event.record(&mut fields);
+
+		let mut prefix = None;
+		let mut spans = Vec::new();
+		if let Some(span) = ctx.lookup_current() {
+			for span in span.scope() {
+				let exts = span.extensions();
+				if prefix.is_none() {
+					prefix =
+						exts.get::<super::layers::Prefix>().map(|p| p.as_str().trim().to_owned());
+				}
+
+				let mut json_span = serde_json::Map::new();
+				json_span.insert("name".into(), span.name().into());
+				if let Some(fields) = exts.get::<FormattedFields<N>>() {
+					if !fields.fields.is_empty() {
+						json_span.insert("fields".into(), strip_colors(&fields.fields).into());
+					}
+				}
+				spans.push(serde_json::Value::Object(json_span));
+			}
+		}
+		// Outermost span first, like in the text output of `tracing-subscriber`.
+		spans.reverse();
+
+		let mut record = serde_json::Map::new();
+		record.insert("timestamp".into(), timestamp.into());
+		record.insert("level".into(), meta.level().to_string().into());
+		record.insert("target".into(), meta.target().into());
+		if let Some(name) = std::thread::current().name() {
+			record.insert("thread".into(), name.into());
+		}
+		if let Some(prefix) = prefix {
+			record.insert("prefix".into(), prefix.into());
+		}
+		record.insert("fields".into(), serde_json::Value::Object(fields.0));
+		if !spans.is_empty() {
+			record.insert("spans".into(), spans.into());
+		}
+
+		let record = serde_json::to_string(&record).map_err(|_| fmt::Error)?;
+		writeln!(writer, "{}", record)
+	}
+}
+
+/// Collects the fields of an event into a JSON object, dropping any terminal colors.
+#[derive(Default)]
+struct JsonFields(serde_json::Map<String, serde_json::Value>);
+
+impl JsonFields {
+	fn insert(&mut self, field: &Field, value: serde_json::Value) {
+		// `tracing-log` records the metadata of `log` records as `log.*` fields. These are
+		// already part of the normalized metadata.
+		if !field.name().starts_with("log.") {
+			self.0.insert(field.name().into(), value);
+		}
+	}
+}
+
+impl Visit for JsonFields {
+	fn record_i64(&mut self, field: &Field, value: i64) {
+		self.insert(field, value.into());
+	}
+
+	fn record_u64(&mut self, field: &Field, value: u64) {
+		self.insert(field, value.into());
+	}
+
+	fn record_bool(&mut self, field: &Field, value: bool) {
+		self.insert(field, value.into());
+	}
+
+	fn record_str(&mut self, field: &Field, value: &str) {
+		self.insert(field, strip_colors(value).into());
+	}
+
+	fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+		self.insert(field, strip_colors(&format!("{:?}", value)).into());
+	}
 }
 
 // NOTE: the following code took inspiration from tracing-subscriber
@@ -324,15 +437,20 @@ impl<'a> MaybeColorWriter<'a> {
 
 	/// Write the buffered content to the `inner_writer`.
 	fn write(&mut self) -> fmt::Result {
-		lazy_static::lazy_static! {
-			static ref RE: Regex = Regex::new("\x1b\\[[^m]+m").expect("Error initializing color regex");
-		}
-
 		if !self.enable_color {
-			let replaced = RE.replace_all(&self.buffer, "");
+			let replaced = strip_colors(&self.buffer);
 			self.inner_writer.write_str(&replaced)
 		} else {
 			self.inner_writer.write_str(&self.buffer)
 		}
 	}
 }
+
+/// Remove all the ANSI terminal color codes from `s`.
+fn strip_colors(s: &str) -> Cow<'_, str> {
+	lazy_static::lazy_static! {
+		static ref RE: Regex = Regex::new("\x1b\\[[^m]+m").expect("Error initializing color regex");
+	}
+
+	RE.replace_all(s, "")
+}
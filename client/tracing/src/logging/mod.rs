@@ -60,6 +60,21 @@ pub enum Error {
 	SetLoggerError(#[from] tracing_log::log_tracer::SetLoggerError),
 }
 
+/// The format in which log records are written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogFormat {
+	/// Human readable text, colored when writing to a terminal.
+	Text,
+	/// One JSON object per record, including the fields of the spans the record was emitted in.
+	Json,
+}
+
+impl Default for LogFormat {
+	fn default() -> Self {
+		Self::Text
+	}
+}
+
 macro_rules! enable_log_reloading {
 	($builder:expr) => {{
 		let builder = $builder.with_filter_reloading();
@@ -88,6 +103,7 @@ fn prepare_subscriber<N, E, F, W>(
 	directives: &str,
 	profiling_targets: Option<&str>,
 	force_colors: Option<bool>,
+	log_format: LogFormat,
 	builder_hook: impl Fn(
 		SubscriberBuilder<
 			format::DefaultFields,
@@ -174,6 +190,7 @@ where
 		display_thread_name: !simple,
 		enable_color,
 		dup_to_stdout: !atty::is(atty::Stream::Stderr) && atty::is(atty::Stream::Stdout),
+		json: log_format == LogFormat::Json,
 	};
 	let builder = FmtSubscriber::builder().with_env_filter(env_filter);
 
@@ -196,6 +213,7 @@ pub struct LoggerBuilder {
 	profiling: Option<(crate::TracingReceiver, String)>,
 	log_reloading: bool,
 	force_colors: Option<bool>,
+	log_format: LogFormat,
 }
 
 impl LoggerBuilder {
@@ -206,6 +224,7 @@ impl LoggerBuilder {
 			profiling: None,
 			log_reloading: true,
 			force_colors: None,
+			log_format: LogFormat::Text,
 		}
 	}
 
@@ -231,6 +250,12 @@ impl LoggerBuilder {
 		self
 	}
 
+	/// Set the format in which log records are written.
+	pub fn with_log_format(&mut self, log_format: LogFormat) -> &mut Self {
+		self.log_format = log_format;
+		self
+	}
+
 	/// Initialize the global logger
 	///
 	/// This sets various global logging and tracing instances and thus may only be called once.
@@ -241,6 +266,7 @@ impl LoggerBuilder {
 					&self.directives,
 					Some(&profiling_targets),
 					self.force_colors,
+					self.log_format,
 					|builder| enable_log_reloading!(builder),
 				)?;
 				let profiling = crate::ProfilingLayer::new(tracing_receiver, &profiling_targets);
@@ -253,6 +279,7 @@ impl LoggerBuilder {
 					&self.directives,
 					Some(&profiling_targets),
 					self.force_colors,
+					self.log_format,
 					|builder| builder,
 				)?;
 				let profiling = crate::ProfilingLayer::new(tracing_receiver, &profiling_targets);
@@ -263,19 +290,25 @@ impl LoggerBuilder {
 			}
 		} else {
 			if self.log_reloading {
-				let subscriber =
-					prepare_subscriber(&self.directives, None, self.force_colors, |builder| {
-						enable_log_reloading!(builder)
-					})?;
+				let subscriber = prepare_subscriber(
+					&self.directives,
+					None,
+					self.force_colors,
+					self.log_format,
+					|builder| enable_log_reloading!(builder),
+				)?;
 
 				tracing::subscriber::set_global_default(subscriber)?;
 
 				Ok(())
 			} else {
-				let subscriber =
-					prepare_subscriber(&self.directives, None, self.force_colors, |builder| {
-						builder
-					})?;
+				let subscriber = prepare_subscriber(
+					&self.directives,
+					None,
+					self.force_colors,
+					self.log_format,
+					|builder| builder,
+				)?;
 
 				tracing::subscriber::set_global_default(subscriber)?;
 
@@ -412,6 +445,45 @@ mod tests {
 		log::info!("{}", EXPECTED_LOG_MESSAGE);
 	}
 
+	#[test]
+	fn json_log_lines() {
+		let executable = env::current_exe().unwrap();
+		let output = Command::new(executable)
+			.env("ENABLE_LOGGING", "1")
+			.args(&["--nocapture", "json_log_lines_entrypoint"])
+			.output()
+			.unwrap();
+
+		let output = String::from_utf8(output.stderr).unwrap();
+		let record: serde_json::Value = serde_json::from_str(output.trim()).unwrap();
+		assert_eq!(record["level"], "INFO");
+		assert_eq!(record["target"], "test-target");
+		assert_eq!(record["prefix"], format!("[{}]", EXPECTED_NODE_NAME));
+		assert_eq!(record["fields"]["message"], EXPECTED_LOG_MESSAGE);
+	}
+
+	/// This is not an actual test, it is used by the `json_log_lines` test.
+	/// The given test will call the test executable and only execute this one test that
+	/// only prints a colored log line prefixed by the node name `EXPECTED_NODE_NAME` in JSON.
+	#[test]
+	fn json_log_lines_entrypoint() {
+		if env::var("ENABLE_LOGGING").is_ok() {
+			let mut builder = LoggerBuilder::new("");
+			builder.with_log_format(LogFormat::Json);
+			builder.init().unwrap();
+			json_log_lines_process();
+		}
+	}
+
+	#[crate::logging::prefix_logs_with(EXPECTED_NODE_NAME)]
+	fn json_log_lines_process() {
+		log::info!(
+			target: "test-target",
+			"{}",
+			ansi_term::Colour::Yellow.paint(EXPECTED_LOG_MESSAGE)
+		);
+	}
+
 	/// This is not an actual test, it is used by the `do_not_write_with_colors_on_tty` test.
 	/// The given test will call the test executable and only execute this one test that
 	/// only prints a log line with some colors in it.
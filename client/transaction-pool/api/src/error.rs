@@ -70,6 +70,9 @@ pub enum Error {
 
 	#[error("The pool is not accepting future transactions")]
 	RejectedFutureTransaction,
+
+	#[error("The sender of the transaction has too many transactions in the pool")]
+	SenderLimitReached,
 }
 
 /// Transaction pool error conversion.
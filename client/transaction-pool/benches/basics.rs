@@ -121,6 +121,25 @@ impl ChainApi for TestApi {
 	) -> Result<Option<<Self::Block as BlockT>::Header>, Self::Error> {
 		Ok(None)
 	}
+
+	/// The blocks form a single chain, where the hash of a block is its number.
+	fn tree_route(
+		&self,
+		from: <Self::Block as BlockT>::Hash,
+		to: <Self::Block as BlockT>::Hash,
+	) -> Result<sp_blockchain::TreeRoute<Self::Block>, Self::Error> {
+		let (from, to) = (from.to_low_u64_be(), to.to_low_u64_be());
+		let block =
+			|number| sp_blockchain::HashAndNumber { number, hash: H256::from_low_u64_be(number) };
+		// The common block is the lowest of the two, either retracted to or enacted from.
+		Ok(if from <= to {
+			sp_blockchain::TreeRoute::new((from..=to).map(block).collect(), 0)
+		} else {
+			let route: Vec<_> = (to..=from).rev().map(block).collect();
+			let pivot = route.len() - 1;
+			sp_blockchain::TreeRoute::new(route, pivot)
+		})
+	}
 }
 
 fn uxt(transfer: Transfer) -> Extrinsic {
@@ -34,6 +34,7 @@ use sc_client_api::{
 	BlockBackend,
 };
 use sp_api::{ApiExt, ProvideRuntimeApi};
+use sp_blockchain::{HeaderMetadata, TreeRoute};
 use sp_core::traits::SpawnEssentialNamed;
 use sp_runtime::{
 	generic::BlockId,
@@ -116,7 +117,7 @@ where
 	Block: BlockT,
 	Client:
 		ProvideRuntimeApi<Block> + BlockBackend<Block> + BlockIdTo<Block> + HeaderBackend<Block>,
-	Client: Send + Sync + 'static,
+	Client: HeaderMetadata<Block, Error = sp_blockchain::Error> + Send + Sync + 'static,
 	Client::Api: TaggedTransactionQueue<Block>,
 {
 	type Block = Block;
@@ -197,6 +198,14 @@ where
 	) -> Result<Option<<Self::Block as BlockT>::Header>, Self::Error> {
 		self.client.header(*at).map_err(Into::into)
 	}
+
+	fn tree_route(
+		&self,
+		from: <Self::Block as BlockT>::Hash,
+		to: <Self::Block as BlockT>::Hash,
+	) -> Result<TreeRoute<Self::Block>, Self::Error> {
+		sp_blockchain::tree_route::<Block, Client>(&*self.client, from, to).map_err(Into::into)
+	}
 }
 
 /// Helper function to validate a transaction using a full chain API.
@@ -211,7 +220,7 @@ where
 	Block: BlockT,
 	Client:
 		ProvideRuntimeApi<Block> + BlockBackend<Block> + BlockIdTo<Block> + HeaderBackend<Block>,
-	Client: Send + Sync + 'static,
+	Client: HeaderMetadata<Block, Error = sp_blockchain::Error> + Send + Sync + 'static,
 	Client::Api: TaggedTransactionQueue<Block>,
 {
 	sp_tracing::within_span!(sp_tracing::Level::TRACE, "validate_transaction";
@@ -273,7 +282,7 @@ where
 	Block: BlockT,
 	Client:
 		ProvideRuntimeApi<Block> + BlockBackend<Block> + BlockIdTo<Block> + HeaderBackend<Block>,
-	Client: Send + Sync + 'static,
+	Client: HeaderMetadata<Block, Error = sp_blockchain::Error> + Send + Sync + 'static,
 	Client::Api: TaggedTransactionQueue<Block>,
 {
 	/// Validates a transaction by calling into the runtime, same as
@@ -307,7 +316,7 @@ impl<Client, F, Block> LightChainApi<Client, F, Block> {
 impl<Client, F, Block> graph::ChainApi for LightChainApi<Client, F, Block>
 where
 	Block: BlockT,
-	Client: HeaderBackend<Block> + 'static,
+	Client: HeaderBackend<Block> + HeaderMetadata<Block, Error = sp_blockchain::Error> + 'static,
 	F: Fetcher<Block> + 'static,
 {
 	type Block = Block;
@@ -412,4 +421,12 @@ where
 	) -> Result<Option<<Self::Block as BlockT>::Header>, Self::Error> {
 		self.client.header(*at).map_err(Into::into)
 	}
+
+	fn tree_route(
+		&self,
+		from: <Self::Block as BlockT>::Hash,
+		to: <Self::Block as BlockT>::Hash,
+	) -> Result<TreeRoute<Self::Block>, Self::Error> {
+		sp_blockchain::tree_route::<Block, Client>(&*self.client, from, to).map_err(Into::into)
+	}
 }
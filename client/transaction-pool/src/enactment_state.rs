@@ -0,0 +1,243 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Tracking of the block the transaction pool has been maintained at.
+
+use sc_transaction_pool_api::ChainEvent;
+use sp_blockchain::TreeRoute;
+use sp_runtime::traits::Block as BlockT;
+use std::sync::Arc;
+
+/// What the pool has to do in response to a [`ChainEvent`].
+#[derive(Debug)]
+pub enum EnactmentAction<Block: BlockT> {
+	/// The block the pool is maintained at stays the same.
+	Skip,
+	/// The pool has to be maintained at the block of the event. Contains the route from the
+	/// block the pool was previously maintained at, if known.
+	HandleEnactment(Option<Arc<TreeRoute<Block>>>),
+}
+
+/// The best and finalized blocks the pool has most recently been maintained at.
+///
+/// Tree routes are computed from the block the pool was last maintained at rather than taken
+/// from the import notifications, so that the pool also follows forks which were finalized
+/// without having been reported as best, and ignores best blocks on forks that are already
+/// pruned by finality.
+pub struct EnactmentState<Block: BlockT> {
+	recent_best_block: Option<Block::Hash>,
+	recent_finalized_block: Option<Block::Hash>,
+}
+
+impl<Block: BlockT> EnactmentState<Block> {
+	/// Creates a new state, not maintained at any block yet.
+	pub fn new() -> Self {
+		Self { recent_best_block: None, recent_finalized_block: None }
+	}
+
+	/// Updates the state with the given event and returns what the pool has to do.
+	///
+	/// `tree_route` computes the route between two blocks.
+	pub fn update<F>(&mut self, event: &ChainEvent<Block>, tree_route: F) -> EnactmentAction<Block>
+	where
+		F: FnOnce(Block::Hash, Block::Hash) -> Result<TreeRoute<Block>, String>,
+	{
+		let (hash, finalized, event_route) = match event {
+			ChainEvent::NewBestBlock { hash, tree_route } => (*hash, false, tree_route.clone()),
+			ChainEvent::Finalized { hash } => (*hash, true, None),
+		};
+
+		// The block has already been finalized, so it has been enacted as well.
+		if self.recent_finalized_block == Some(hash) {
+			return EnactmentAction::Skip
+		}
+
+		let route = match self.recent_best_block {
+			Some(best) if best == hash => None,
+			Some(best) => match tree_route(best, hash) {
+				Ok(route) => Some(Arc::new(route)),
+				Err(e) => {
+					log::debug!(
+						target: "txpool",
+						"Error computing tree route from {:?} to {:?}: {}",
+						best,
+						hash,
+						e,
+					);
+					event_route
+				},
+			},
+			None => event_route,
+		};
+
+		// A best block on a fork that does not contain the finalized block is stale.
+		if let (Some(finalized_hash), Some(route)) = (self.recent_finalized_block, &route) {
+			if route.retracted().iter().any(|block| block.hash == finalized_hash) {
+				log::debug!(
+					target: "txpool",
+					"Skipping maintenance at {:?}, its fork is not finalized",
+					hash,
+				);
+				return EnactmentAction::Skip
+			}
+		}
+
+		if finalized {
+			self.recent_finalized_block = Some(hash);
+
+			// Without enacted blocks, the finalized block is an ancestor of the best block and
+			// has already been enacted.
+			if route.as_ref().map_or(true, |route| route.enacted().is_empty()) {
+				return EnactmentAction::Skip
+			}
+		} else if self.recent_best_block == Some(hash) {
+			return EnactmentAction::Skip
+		}
+
+		self.recent_best_block = Some(hash);
+		EnactmentAction::HandleEnactment(route)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_blockchain::HashAndNumber;
+	use sp_runtime::testing::{Block as RawBlock, ExtrinsicWrapper, H256};
+
+	type Block = RawBlock<ExtrinsicWrapper<u64>>;
+
+	// Blocks used in the tests:
+	//
+	//     A1 - A2 - A3
+	//    /
+	//  G
+	//    \
+	//     B1 - B2
+	fn block(name: &str) -> HashAndNumber<Block> {
+		let (fork, number) = name.split_at(1);
+		let number = number.parse().unwrap();
+		let fork = if fork == "A" { 0xa } else { 0xb };
+		HashAndNumber { number, hash: H256::from_low_u64_be(fork << 8 | number) }
+	}
+
+	fn hash(name: &str) -> H256 {
+		block(name).hash
+	}
+
+	fn name(hash: H256) -> String {
+		let n = hash.to_low_u64_be();
+		format!("{}{}", if n >> 8 == 0xa { "A" } else { "B" }, n & 0xff)
+	}
+
+	fn path(name: &str) -> Vec<HashAndNumber<Block>> {
+		let (fork, number) = name.split_at(1);
+		(1..=number.parse::<u64>().unwrap())
+			.map(|n| block(&format!("{}{}", fork, n)))
+			.collect()
+	}
+
+	// Computes the route between two blocks of the tree above.
+	fn tree_route(from: H256, to: H256) -> Result<TreeRoute<Block>, String> {
+		let (from, to) = (path(&name(from)), path(&name(to)));
+		let common = from.iter().zip(to.iter()).take_while(|(f, t)| f.hash == t.hash).count();
+		let genesis = HashAndNumber { number: 0, hash: H256::zero() };
+		let common_block = if common == 0 { genesis } else { from[common - 1].clone() };
+
+		let mut route: Vec<_> = from[common..].iter().rev().cloned().collect();
+		let pivot = route.len();
+		route.push(common_block);
+		route.extend(to[common..].iter().cloned());
+		Ok(TreeRoute::new(route, pivot))
+	}
+
+	fn new_best(state: &mut EnactmentState<Block>, name: &str) -> EnactmentAction<Block> {
+		state.update(&ChainEvent::NewBestBlock { hash: hash(name), tree_route: None }, tree_route)
+	}
+
+	fn finalized(state: &mut EnactmentState<Block>, name: &str) -> EnactmentAction<Block> {
+		state.update(&ChainEvent::Finalized { hash: hash(name) }, tree_route)
+	}
+
+	fn enacted_and_retracted(action: EnactmentAction<Block>) -> (Vec<String>, Vec<String>) {
+		match action {
+			EnactmentAction::HandleEnactment(Some(route)) => (
+				route.enacted().iter().map(|b| name(b.hash)).collect(),
+				route.retracted().iter().map(|b| name(b.hash)).collect(),
+			),
+			action => panic!("Unexpected action: {:?}", action),
+		}
+	}
+
+	#[test]
+	fn first_best_block_is_enacted() {
+		let mut state = EnactmentState::new();
+
+		assert!(matches!(new_best(&mut state, "A1"), EnactmentAction::HandleEnactment(None)));
+		assert_eq!(state.recent_best_block, Some(hash("A1")));
+	}
+
+	#[test]
+	fn best_block_route_starts_at_previous_best() {
+		let mut state = EnactmentState::new();
+		new_best(&mut state, "A1");
+
+		let (enacted, retracted) = enacted_and_retracted(new_best(&mut state, "A3"));
+		assert_eq!(enacted, vec!["A2", "A3"]);
+		assert!(retracted.is_empty());
+
+		let (enacted, retracted) = enacted_and_retracted(new_best(&mut state, "B2"));
+		assert_eq!(enacted, vec!["B1", "B2"]);
+		assert_eq!(retracted, vec!["A3", "A2", "A1"]);
+	}
+
+	#[test]
+	fn finalizing_ancestor_of_best_block_is_skipped() {
+		let mut state = EnactmentState::new();
+		new_best(&mut state, "A3");
+
+		assert!(matches!(finalized(&mut state, "A2"), EnactmentAction::Skip));
+		assert!(matches!(finalized(&mut state, "A3"), EnactmentAction::Skip));
+		assert_eq!(state.recent_best_block, Some(hash("A3")));
+		assert_eq!(state.recent_finalized_block, Some(hash("A3")));
+	}
+
+	#[test]
+	fn finalizing_other_fork_is_enacted() {
+		let mut state = EnactmentState::new();
+		new_best(&mut state, "A3");
+
+		let (enacted, retracted) = enacted_and_retracted(finalized(&mut state, "B1"));
+		assert_eq!(enacted, vec!["B1"]);
+		assert_eq!(retracted, vec!["A3", "A2", "A1"]);
+		assert_eq!(state.recent_best_block, Some(hash("B1")));
+
+		// The best block being reported afterwards does not enact it a second time.
+		assert!(matches!(new_best(&mut state, "B1"), EnactmentAction::Skip));
+	}
+
+	#[test]
+	fn best_block_on_stale_fork_is_skipped() {
+		let mut state = EnactmentState::new();
+		new_best(&mut state, "B2");
+		finalized(&mut state, "B1");
+
+		assert!(matches!(new_best(&mut state, "A3"), EnactmentAction::Skip));
+		assert_eq!(state.recent_best_block, Some(hash("B2")));
+	}
+}
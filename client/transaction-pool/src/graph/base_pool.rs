@@ -20,7 +20,11 @@
 //!
 //! For a more full-featured pool, have a look at the `pool` module.
 
-use std::{collections::HashSet, fmt, hash, sync::Arc};
+use std::{
+	collections::{HashMap, HashSet},
+	fmt, hash,
+	sync::Arc,
+};
 
 use log::{debug, trace, warn};
 use sc_transaction_pool_api::{error, InPoolTransaction, PoolStatus};
@@ -104,6 +108,8 @@ pub struct Transaction<Hash, Extrinsic> {
 	pub propagate: bool,
 	/// Source of that transaction.
 	pub source: Source,
+	/// Encoded identity of the sender, if it is known.
+	pub sender: Option<Vec<u8>>,
 }
 
 impl<Hash, Extrinsic> AsRef<Extrinsic> for Transaction<Hash, Extrinsic> {
@@ -162,6 +168,7 @@ impl<Hash: Clone, Extrinsic: Clone> Transaction<Hash, Extrinsic> {
 			requires: self.requires.clone(),
 			provides: self.provides.clone(),
 			propagate: self.propagate,
+			sender: self.sender.clone(),
 		}
 	}
 }
@@ -188,6 +195,9 @@ where
 		write!(fmt, "source: {:?}, ", &self.source)?;
 		write!(fmt, "requires: [{}], ", join_tags(&self.requires))?;
 		write!(fmt, "provides: [{}], ", join_tags(&self.provides))?;
+		if let Some(ref sender) = self.sender {
+			write!(fmt, "sender: {}, ", HexDisplay::from(sender))?;
+		}
 		write!(fmt, "data: {:?}", &self.data)?;
 		write!(fmt, "}}")?;
 		Ok(())
@@ -218,6 +228,7 @@ pub struct BasePool<Hash: hash::Hash + Eq, Ex> {
 	/// transactions to future in case they were just stuck in verification.
 	recently_pruned: [HashSet<Tag>; RECENTLY_PRUNED_TAGS],
 	recently_pruned_index: usize,
+	sender_limit: SenderLimit,
 }
 
 impl<Hash: hash::Hash + Member + Serialize, Ex: std::fmt::Debug> Default for BasePool<Hash, Ex> {
@@ -235,9 +246,15 @@ impl<Hash: hash::Hash + Member + Serialize, Ex: std::fmt::Debug> BasePool<Hash,
 			ready: Default::default(),
 			recently_pruned: Default::default(),
 			recently_pruned_index: 0,
+			sender_limit: Default::default(),
 		}
 	}
 
+	/// Limit the number of transactions a single sender may have in the pool.
+	pub fn set_sender_limit(&mut self, sender_limit: SenderLimit) {
+		self.sender_limit = sender_limit;
+	}
+
 	/// Temporary enables future transactions, runs closure and then restores
 	/// `reject_future_transactions` flag back to previous value.
 	///
@@ -280,6 +297,8 @@ impl<Hash: hash::Hash + Member + Serialize, Ex: std::fmt::Debug> BasePool<Hash,
 			if tx.is_ready() { "ready" } else { "future" }
 		);
 
+		self.check_sender_limit(&tx)?;
+
 		// If all tags are not satisfied import to future.
 		if !tx.is_ready() {
 			if self.reject_future_transactions {
@@ -294,6 +313,54 @@ impl<Hash: hash::Hash + Member + Serialize, Ex: std::fmt::Debug> BasePool<Hash,
 		self.import_to_ready(tx)
 	}
 
+	/// Makes sure importing `tx` keeps its sender within the limits.
+	fn check_sender_limit(&self, tx: &WaitingTransaction<Hash, Ex>) -> error::Result<()> {
+		let sender = match tx.transaction.sender {
+			Some(ref sender) if !self.sender_limit.is_unlimited() => sender,
+			_ => return Ok(()),
+		};
+		// Ready transactions providing the same tags are about to be replaced, so they don't
+		// count.
+		let replaced = if tx.is_ready() {
+			let provided_tags = self.ready.provided_tags();
+			let hashes = tx
+				.transaction
+				.provides
+				.iter()
+				.filter_map(|tag| provided_tags.get(tag).cloned())
+				.collect::<HashSet<_>>()
+				.into_iter()
+				.collect::<Vec<_>>();
+			self.ready
+				.by_hashes(&hashes)
+				.into_iter()
+				.flatten()
+				.filter(|other| other.sender.as_ref() == Some(sender))
+				.count()
+		} else {
+			0
+		};
+
+		let future = self.future.sender_count(sender);
+		let ready = self.ready.sender_count(sender).saturating_sub(replaced);
+
+		let exceeds = |limit: Option<usize>, count: usize| limit.map_or(false, |l| count >= l);
+		if exceeds(self.sender_limit.total, ready + future) ||
+			(!tx.is_ready() && exceeds(self.sender_limit.future, future))
+		{
+			debug!(
+				target: "txpool",
+				"[{:?}] Sender has reached its limit ({} ready, {} future)",
+				tx.transaction.hash,
+				ready,
+				future,
+			);
+			return Err(error::Error::SenderLimitReached)
+		}
+
+		Ok(())
+	}
+
 	/// Imports transaction to ready queue.
 	///
 	/// NOTE the transaction has to have all requirements satisfied.
@@ -364,6 +431,11 @@ impl<Hash: hash::Hash + Member + Serialize, Ex: std::fmt::Debug> BasePool<Hash,
 		self.future.all()
 	}
 
+	/// Returns an iterator over future transactions in the pool, with their missing tags.
+	pub fn waiting(&self) -> impl Iterator<Item = &WaitingTransaction<Hash, Ex>> {
+		self.future.waiting()
+	}
+
 	/// Returns pool transactions given list of hashes.
 	///
 	/// Includes both ready and future pool. For every hash in the `hashes`
@@ -514,6 +586,61 @@ impl Limit {
 	}
 }
 
+/// Limits on the transactions of a single sender.
+///
+/// Only applies to transactions with a known sender.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, parity_util_mem::MallocSizeOf)]
+pub struct SenderLimit {
+	/// Maximal number of transactions from a single sender in the pool.
+	pub total: Option<usize>,
+	/// Maximal number of future transactions from a single sender.
+	pub future: Option<usize>,
+}
+
+impl SenderLimit {
+	/// Returns true if no limit is set.
+	pub fn is_unlimited(&self) -> bool {
+		self.total.is_none() && self.future.is_none()
+	}
+}
+
+/// Number of transactions from each known sender in a queue, kept up to date as transactions are
+/// added to and removed from it.
+#[derive(Debug, Default, parity_util_mem::MallocSizeOf)]
+pub struct SenderCounts(HashMap<Vec<u8>, usize>);
+
+impl SenderCounts {
+	/// Counts a transaction added to the queue.
+	pub fn add<Hash, Ex>(&mut self, tx: &Transaction<Hash, Ex>) {
+		if let Some(ref sender) = tx.sender {
+			*self.0.entry(sender.clone()).or_default() += 1;
+		}
+	}
+
+	/// Counts a transaction removed from the queue.
+	pub fn remove<Hash, Ex>(&mut self, tx: &Transaction<Hash, Ex>) {
+		if let Some(ref sender) = tx.sender {
+			let now_empty = self.0.get_mut(sender).map_or(false, |count| {
+				*count -= 1;
+				*count == 0
+			});
+			if now_empty {
+				self.0.remove(sender);
+			}
+		}
+	}
+
+	/// Returns the number of transactions from `sender` in the queue.
+	pub fn get(&self, sender: &[u8]) -> usize {
+		self.0.get(sender).copied().unwrap_or(0)
+	}
+
+	/// Forgets about all transactions.
+	pub fn clear(&mut self) {
+		self.0.clear();
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -534,6 +661,7 @@ mod tests {
 		provides: vec![],
 		propagate: true,
 		source: Source::External,
+		sender: None,
 	};
 
 	#[test]
@@ -1048,4 +1176,151 @@ source: TransactionSource::External, requires: [03, 02], provides: [04], data: [
 		assert_eq!(pool.reject_future_transactions, true);
 		assert_eq!(pool.future.len(), 1);
 	}
+
+	#[test]
+	fn should_limit_transactions_per_sender() {
+		// given
+		let mut pool = pool();
+		pool.set_sender_limit(SenderLimit { total: Some(2), future: None });
+		let sender = Some(vec![1u8]);
+
+		// when
+		pool.import(Transaction {
+			data: vec![1u8],
+			hash: 1,
+			provides: vec![vec![1]],
+			sender: sender.clone(),
+			..DEFAULT_TX.clone()
+		})
+		.unwrap();
+		pool.import(Transaction {
+			data: vec![2u8],
+			hash: 2,
+			requires: vec![vec![0]],
+			provides: vec![vec![2]],
+			sender: sender.clone(),
+			..DEFAULT_TX.clone()
+		})
+		.unwrap();
+		let err = pool
+			.import(Transaction {
+				data: vec![3u8],
+				hash: 3,
+				provides: vec![vec![3]],
+				sender: sender.clone(),
+				..DEFAULT_TX.clone()
+			})
+			.unwrap_err();
+
+		// then
+		assert!(matches!(err, error::Error::SenderLimitReached));
+		// other senders and replacements are still accepted
+		pool.import(Transaction {
+			data: vec![4u8],
+			hash: 4,
+			provides: vec![vec![4]],
+			sender: Some(vec![2u8]),
+			..DEFAULT_TX.clone()
+		})
+		.unwrap();
+		pool.import(Transaction {
+			data: vec![5u8],
+			hash: 5,
+			priority: 10u64,
+			provides: vec![vec![1]],
+			sender,
+			..DEFAULT_TX.clone()
+		})
+		.unwrap();
+		assert_eq!(pool.ready().count(), 2);
+		assert_eq!(pool.future.len(), 1);
+	}
+
+	#[test]
+	fn should_limit_future_transactions_per_sender() {
+		// given
+		let mut pool = pool();
+		pool.set_sender_limit(SenderLimit { total: None, future: Some(1) });
+		let sender = Some(vec![1u8]);
+		pool.import(Transaction {
+			data: vec![1u8],
+			hash: 1,
+			requires: vec![vec![0]],
+			provides: vec![vec![1]],
+			sender: sender.clone(),
+			..DEFAULT_TX.clone()
+		})
+		.unwrap();
+
+		// when
+		let err = pool
+			.import(Transaction {
+				data: vec![2u8],
+				hash: 2,
+				requires: vec![vec![5]],
+				provides: vec![vec![2]],
+				sender: sender.clone(),
+				..DEFAULT_TX.clone()
+			})
+			.unwrap_err();
+
+		// then
+		assert!(matches!(err, error::Error::SenderLimitReached));
+		pool.import(Transaction {
+			data: vec![3u8],
+			hash: 3,
+			provides: vec![vec![3]],
+			sender,
+			..DEFAULT_TX.clone()
+		})
+		.unwrap();
+		assert_eq!(pool.ready().count(), 1);
+		assert_eq!(pool.future.len(), 1);
+	}
+
+	#[test]
+	fn should_count_transactions_per_sender_as_they_move_and_leave() {
+		// given
+		let mut pool = pool();
+		pool.set_sender_limit(SenderLimit { total: Some(2), future: Some(1) });
+		let sender = Some(vec![1u8]);
+		pool.import(Transaction {
+			data: vec![1u8],
+			hash: 1,
+			requires: vec![vec![0]],
+			provides: vec![vec![1]],
+			sender: sender.clone(),
+			..DEFAULT_TX.clone()
+		})
+		.unwrap();
+		pool.import(Transaction {
+			data: vec![2u8],
+			hash: 2,
+			provides: vec![vec![0]],
+			sender: sender.clone(),
+			..DEFAULT_TX.clone()
+		})
+		.unwrap();
+		assert_eq!(pool.ready.sender_count(&[1]), 2);
+		assert_eq!(pool.future.sender_count(&[1]), 0);
+
+		// when
+		pool.prune_tags(vec![vec![0]]);
+		pool.remove_subtree(&[1]);
+
+		// then
+		assert_eq!(pool.ready.sender_count(&[1]), 0);
+		pool.import(Transaction {
+			data: vec![3u8],
+			hash: 3,
+			requires: vec![vec![5]],
+			provides: vec![vec![3]],
+			sender,
+			..DEFAULT_TX.clone()
+		})
+		.unwrap();
+		assert_eq!(pool.future.sender_count(&[1]), 1);
+		pool.clear_future();
+		assert_eq!(pool.future.sender_count(&[1]), 0);
+	}
 }
@@ -38,10 +38,10 @@ pub mod base_pool;
 pub mod watcher;
 
 pub use self::{
-	base_pool::Transaction,
+	base_pool::{SenderLimit, Transaction},
 	pool::{
 		BlockHash, ChainApi, EventStream, ExtrinsicFor, ExtrinsicHash, NumberFor, Options, Pool,
-		TransactionFor,
+		SenderOf, TransactionFor,
 	},
 };
 pub use validated_pool::{IsValidator, ValidatedTransaction};
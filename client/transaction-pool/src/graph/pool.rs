@@ -16,10 +16,11 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use std::{collections::HashMap, sync::Arc};
+use std::{collections::HashMap, fmt, sync::Arc, time::Duration};
 
 use futures::{channel::mpsc::Receiver, Future};
 use sc_transaction_pool_api::error;
+use sp_blockchain::TreeRoute;
 use sp_runtime::{
 	generic::BlockId,
 	traits::{self, Block as BlockT, SaturatedConversion},
@@ -31,6 +32,7 @@ use std::time::Instant;
 
 use super::{
 	base_pool as base,
+	rotator::DEFAULT_BAN_TIME_SECS,
 	validated_pool::{IsValidator, ValidatedPool, ValidatedTransaction},
 	watcher::Watcher,
 };
@@ -97,6 +99,13 @@ pub trait ChainApi: Send + Sync {
 		&self,
 		at: &BlockId<Self::Block>,
 	) -> Result<Option<<Self::Block as BlockT>::Header>, Self::Error>;
+
+	/// Compute a tree-route between two blocks. See [`TreeRoute`] for more details.
+	fn tree_route(
+		&self,
+		from: <Self::Block as BlockT>::Hash,
+		to: <Self::Block as BlockT>::Hash,
+	) -> Result<TreeRoute<Self::Block>, Self::Error>;
 }
 
 /// Pool configuration options.
@@ -106,8 +115,14 @@ pub struct Options {
 	pub ready: base::Limit,
 	/// Future queue limits.
 	pub future: base::Limit,
+	/// Limits on the transactions of a single sender.
+	pub per_sender: base::SenderLimit,
 	/// Reject future transactions.
 	pub reject_future_transactions: bool,
+	/// How long invalid and stale transactions are banned from the pool.
+	pub ban_time: Duration,
+	/// Extracts the senders of transactions, required for `per_sender` limits to apply.
+	pub sender_of: Option<SenderOf>,
 }
 
 impl Default for Options {
@@ -115,11 +130,36 @@ impl Default for Options {
 		Self {
 			ready: base::Limit { count: 8192, total_bytes: 20 * 1024 * 1024 },
 			future: base::Limit { count: 512, total_bytes: 1 * 1024 * 1024 },
+			per_sender: Default::default(),
 			reject_future_transactions: false,
+			ban_time: Duration::from_secs(DEFAULT_BAN_TIME_SECS),
+			sender_of: None,
 		}
 	}
 }
 
+/// Returns the encoded sender of an encoded transaction, if it has one.
+#[derive(Clone)]
+pub struct SenderOf(Arc<dyn Fn(&[u8]) -> Option<Vec<u8>> + Send + Sync>);
+
+impl SenderOf {
+	/// Create a new sender extractor from the given closure.
+	pub fn new(f: impl Fn(&[u8]) -> Option<Vec<u8>> + Send + Sync + 'static) -> Self {
+		Self(Arc::new(f))
+	}
+
+	/// Returns the sender of the given encoded transaction.
+	pub fn sender_of(&self, encoded: &[u8]) -> Option<Vec<u8>> {
+		(self.0)(encoded)
+	}
+}
+
+impl fmt::Debug for SenderOf {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_tuple("SenderOf").finish()
+	}
+}
+
 /// Should we check that the transaction is banned
 /// in the pool, before we verify it?
 #[derive(Copy, Clone)]
@@ -411,6 +451,7 @@ impl<B: ChainApi> Pool<B> {
 				if validity.provides.is_empty() {
 					ValidatedTransaction::Invalid(hash, error::Error::NoTagsProvided.into())
 				} else {
+					let sender = self.validated_pool.sender_of(&xt);
 					ValidatedTransaction::valid_at(
 						block_number.saturated_into::<u64>(),
 						hash,
@@ -418,6 +459,7 @@ impl<B: ChainApi> Pool<B> {
 						xt,
 						bytes,
 						validity,
+						sender,
 					)
 				},
 			Err(TransactionValidityError::Invalid(e)) =>
@@ -584,6 +626,27 @@ mod tests {
 		) -> Result<Option<<Self::Block as BlockT>::Header>, Self::Error> {
 			Ok(None)
 		}
+
+		/// The blocks form a single chain, where the hash of a block is its number.
+		fn tree_route(
+			&self,
+			from: <Self::Block as BlockT>::Hash,
+			to: <Self::Block as BlockT>::Hash,
+		) -> Result<TreeRoute<Self::Block>, Self::Error> {
+			let (from, to) = (from.to_low_u64_be(), to.to_low_u64_be());
+			let block = |number| sp_blockchain::HashAndNumber {
+				number,
+				hash: H256::from_low_u64_be(number),
+			};
+			// The common block is the lowest of the two, either retracted to or enacted from.
+			Ok(if from <= to {
+				TreeRoute::new((from..=to).map(block).collect(), 0)
+			} else {
+				let route: Vec<_> = (to..=from).rev().map(block).collect();
+				let pivot = route.len() - 1;
+				TreeRoute::new(route, pivot)
+			})
+		}
 	}
 
 	fn uxt(transfer: Transfer) -> Extrinsic {
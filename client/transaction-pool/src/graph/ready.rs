@@ -29,7 +29,7 @@ use serde::Serialize;
 use sp_runtime::{traits::Member, transaction_validity::TransactionTag as Tag};
 
 use super::{
-	base_pool::Transaction,
+	base_pool::{SenderCounts, Transaction},
 	future::WaitingTransaction,
 	tracked_map::{self, ReadOnlyTrackedMap, TrackedMap},
 };
@@ -117,6 +117,8 @@ pub struct ReadyTransactions<Hash: hash::Hash + Eq, Ex> {
 	/// Best transactions that are ready to be included to the block without any other previous
 	/// transaction.
 	best: BTreeSet<TransactionRef<Hash, Ex>>,
+	/// Number of ready transactions of each known sender
+	senders: SenderCounts,
 }
 
 impl<Hash, Ex> tracked_map::Size for ReadyTx<Hash, Ex> {
@@ -132,6 +134,7 @@ impl<Hash: hash::Hash + Eq, Ex> Default for ReadyTransactions<Hash, Ex> {
 			provided_tags: Default::default(),
 			ready: Default::default(),
 			best: Default::default(),
+			senders: Default::default(),
 		}
 	}
 }
@@ -221,6 +224,7 @@ impl<Hash: hash::Hash + Member + Serialize, Ex> ReadyTransactions<Hash, Ex> {
 		}
 
 		// insert to Ready
+		self.senders.add(&transaction.transaction);
 		ready.insert(hash, ReadyTx { transaction, unlocks, requires_offset });
 
 		Ok(replaced)
@@ -277,6 +281,7 @@ impl<Hash: hash::Hash + Member + Serialize, Ex> ReadyTransactions<Hash, Ex> {
 		let mut ready = self.ready.write();
 		while let Some(hash) = to_remove.pop() {
 			if let Some(mut tx) = ready.remove(&hash) {
+				self.senders.remove(&tx.transaction.transaction);
 				let invalidated = tx.transaction.transaction.provides.iter().filter(|tag| {
 					provides_tag_filter
 						.as_ref()
@@ -333,6 +338,7 @@ impl<Hash: hash::Hash + Member + Serialize, Ex> ReadyTransactions<Hash, Ex> {
 				.and_then(|hash| self.ready.write().remove(&hash));
 
 			if let Some(tx) = res {
+				self.senders.remove(&tx.transaction.transaction);
 				let unlocks = tx.unlocks;
 
 				// Make sure we remove it from best txs
@@ -466,6 +472,11 @@ impl<Hash: hash::Hash + Member + Serialize, Ex> ReadyTransactions<Hash, Ex> {
 		Ok((removed, unlocks))
 	}
 
+	/// Returns number of transactions from `sender` in this queue.
+	pub fn sender_count(&self, sender: &[u8]) -> usize {
+		self.senders.get(sender)
+	}
+
 	/// Returns number of transactions in this queue.
 	pub fn len(&self) -> usize {
 		self.ready.len()
@@ -559,6 +570,7 @@ mod tests {
 			provides: vec![vec![3], vec![4]],
 			propagate: true,
 			source: Source::External,
+			sender: None,
 		}
 	}
 
@@ -660,6 +672,7 @@ mod tests {
 			provides: vec![],
 			propagate: true,
 			source: Source::External,
+			sender: None,
 		};
 
 		// when
@@ -693,6 +706,7 @@ mod tests {
 			provides: vec![],
 			propagate: true,
 			source: Source::External,
+			sender: None,
 		};
 		import(&mut ready, tx).unwrap();
 
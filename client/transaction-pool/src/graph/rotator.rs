@@ -33,6 +33,9 @@ use super::base_pool::Transaction;
 /// Expected size of the banned extrinsics cache.
 const EXPECTED_SIZE: usize = 2048;
 
+/// The default duration, in seconds, for which extrinsics are banned.
+pub const DEFAULT_BAN_TIME_SECS: u64 = 30 * 60;
+
 /// Pool rotator is responsible to only keep fresh extrinsics in the pool.
 ///
 /// Extrinsics that occupy the pool for too long are culled and temporarily banned from entering
@@ -46,7 +49,14 @@ pub struct PoolRotator<Hash> {
 
 impl<Hash: hash::Hash + Eq> Default for PoolRotator<Hash> {
 	fn default() -> Self {
-		Self { ban_time: Duration::from_secs(60 * 30), banned_until: Default::default() }
+		Self::new(Duration::from_secs(DEFAULT_BAN_TIME_SECS))
+	}
+}
+
+impl<Hash: hash::Hash + Eq> PoolRotator<Hash> {
+	/// Create a new rotator banning extrinsics for `ban_time`.
+	pub fn new(ban_time: Duration) -> Self {
+		Self { ban_time, banned_until: Default::default() }
 	}
 }
 
@@ -107,7 +117,7 @@ mod tests {
 	type Ex = ();
 
 	fn rotator() -> PoolRotator<Hash> {
-		PoolRotator { ban_time: Duration::from_millis(10), ..Default::default() }
+		PoolRotator::new(Duration::from_millis(10))
 	}
 
 	fn tx() -> (Hash, Transaction<Hash, Ex>) {
@@ -122,6 +132,7 @@ mod tests {
 			provides: vec![],
 			propagate: true,
 			source: TransactionSource::External,
+			sender: None,
 		};
 
 		(hash, tx)
@@ -188,6 +199,7 @@ mod tests {
 				provides: vec![],
 				propagate: true,
 				source: TransactionSource::External,
+				sender: None,
 			}
 		}
 
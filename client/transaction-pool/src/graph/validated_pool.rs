@@ -22,6 +22,7 @@ use std::{
 	sync::Arc,
 };
 
+use codec::Encode;
 use futures::channel::mpsc::{channel, Sender};
 use parking_lot::{Mutex, RwLock};
 use retain_mut::RetainMut;
@@ -66,6 +67,7 @@ impl<Hash, Ex, Error> ValidatedTransaction<Hash, Ex, Error> {
 		data: Ex,
 		bytes: usize,
 		validity: ValidTransaction,
+		sender: Option<Vec<u8>>,
 	) -> Self {
 		Self::Valid(base::Transaction {
 			data,
@@ -77,6 +79,7 @@ impl<Hash, Ex, Error> ValidatedTransaction<Hash, Ex, Error> {
 			provides: validity.provides,
 			propagate: validity.propagate,
 			valid_till: at.saturated_into::<u64>().saturating_add(validity.longevity),
+			sender,
 		})
 	}
 }
@@ -124,7 +127,9 @@ where
 impl<B: ChainApi> ValidatedPool<B> {
 	/// Create a new transaction pool.
 	pub fn new(options: Options, is_validator: IsValidator, api: Arc<B>) -> Self {
-		let base_pool = base::BasePool::new(options.reject_future_transactions);
+		let mut base_pool = base::BasePool::new(options.reject_future_transactions);
+		base_pool.set_sender_limit(options.per_sender);
+		let rotator = PoolRotator::new(options.ban_time);
 		Self {
 			is_validator,
 			options,
@@ -132,10 +137,24 @@ impl<B: ChainApi> ValidatedPool<B> {
 			api,
 			pool: RwLock::new(base_pool),
 			import_notification_sinks: Default::default(),
-			rotator: Default::default(),
+			rotator,
 		}
 	}
 
+	/// Returns the sender of the transaction, if the pool is configured to identify them.
+	///
+	/// Senders are only looked up if they are limited, as it takes encoding and decoding the
+	/// transaction again.
+	pub fn sender_of(&self, xt: &ExtrinsicFor<B>) -> Option<Vec<u8>> {
+		if self.options.per_sender.is_unlimited() {
+			return None
+		}
+		self.options
+			.sender_of
+			.as_ref()
+			.and_then(|sender_of| sender_of.sender_of(&xt.encode()))
+	}
+
 	/// Bans given set of hashes.
 	pub fn ban(&self, now: &Instant, hashes: impl IntoIterator<Item = ExtrinsicHash<B>>) {
 		self.rotator.ban(now, hashes)
@@ -643,6 +662,19 @@ impl<B: ChainApi> ValidatedPool<B> {
 			.collect()
 	}
 
+	/// Returns the transactions in the future pool, with the tags each of them is missing.
+	pub fn waiting(&self) -> Vec<(TransactionFor<B>, Vec<Tag>)> {
+		self.pool
+			.read()
+			.waiting()
+			.map(|waiting| {
+				let mut missing_tags = waiting.missing_tags.iter().cloned().collect::<Vec<_>>();
+				missing_tags.sort();
+				(waiting.transaction.clone(), missing_tags)
+			})
+			.collect()
+	}
+
 	/// Returns pool status.
 	pub fn status(&self) -> PoolStatus {
 		self.pool.read().status()
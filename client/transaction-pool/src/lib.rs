@@ -23,6 +23,7 @@
 #![warn(unused_extern_crates)]
 
 mod api;
+mod enactment_state;
 mod graph;
 mod metrics;
 mod revalidation;
@@ -44,7 +45,7 @@ use futures::{
 	future::{self, ready},
 	prelude::*,
 };
-pub use graph::{ChainApi, Options, Pool, Transaction};
+pub use graph::{ChainApi, Options, Pool, SenderLimit, SenderOf, Transaction};
 use parking_lot::Mutex;
 use std::{
 	collections::{HashMap, HashSet},
@@ -56,7 +57,8 @@ use std::{
 use graph::{ExtrinsicHash, IsValidator};
 use sc_transaction_pool_api::{
 	ChainEvent, ImportNotificationStream, MaintainedTransactionPool, PoolFuture, PoolStatus,
-	TransactionFor, TransactionPool, TransactionSource, TransactionStatusStreamFor, TxHash,
+	TransactionFor, TransactionPool, TransactionSource, TransactionStatusStreamFor, TransactionTag,
+	TxHash,
 };
 use sp_core::traits::SpawnEssentialNamed;
 use sp_runtime::{
@@ -65,7 +67,10 @@ use sp_runtime::{
 };
 use std::time::Instant;
 
-use crate::metrics::MetricsLink as PrometheusMetrics;
+use crate::{
+	enactment_state::{EnactmentAction, EnactmentState},
+	metrics::MetricsLink as PrometheusMetrics,
+};
 use prometheus_endpoint::Registry as PrometheusRegistry;
 
 type BoxedReadyIterator<Hash, Data> =
@@ -94,6 +99,7 @@ where
 	revalidation_queue: Arc<revalidation::RevalidationQueue<PoolApi>>,
 	ready_poll: Arc<Mutex<ReadyPoll<ReadyIteratorFor<PoolApi>, Block>>>,
 	metrics: PrometheusMetrics,
+	enactment_state: Arc<Mutex<EnactmentState<Block>>>,
 }
 
 struct ReadyPoll<T, Block: BlockT> {
@@ -187,6 +193,7 @@ where
 				revalidation_strategy: Arc::new(Mutex::new(RevalidationStrategy::Always)),
 				ready_poll: Default::default(),
 				metrics: Default::default(),
+				enactment_state: Arc::new(Mutex::new(EnactmentState::new())),
 			},
 			background_task,
 			notifier,
@@ -230,6 +237,7 @@ where
 			})),
 			ready_poll: Arc::new(Mutex::new(ReadyPoll::new(best_block_number))),
 			metrics: PrometheusMetrics::new(prometheus),
+			enactment_state: Arc::new(Mutex::new(EnactmentState::new())),
 		}
 	}
 
@@ -361,12 +369,19 @@ where
 	fn ready(&self) -> ReadyIteratorFor<PoolApi> {
 		Box::new(self.pool.validated_pool().ready())
 	}
+
+	fn futures(&self) -> Vec<(Arc<Self::InPoolTransaction>, Vec<TransactionTag>)> {
+		self.pool.validated_pool().waiting()
+	}
 }
 
 impl<Block, Client, Fetcher> LightPool<Block, Client, Fetcher>
 where
 	Block: BlockT,
-	Client: sp_blockchain::HeaderBackend<Block> + sc_client_api::UsageProvider<Block> + 'static,
+	Client: sp_blockchain::HeaderBackend<Block>
+		+ sp_blockchain::HeaderMetadata<Block, Error = sp_blockchain::Error>
+		+ sc_client_api::UsageProvider<Block>
+		+ 'static,
 	Fetcher: sc_client_api::Fetcher<Block> + 'static,
 {
 	/// Create new basic transaction pool for a light node with the provided api.
@@ -396,6 +411,7 @@ where
 	Client: sp_api::ProvideRuntimeApi<Block>
 		+ sc_client_api::BlockBackend<Block>
 		+ sc_client_api::blockchain::HeaderBackend<Block>
+		+ sp_blockchain::HeaderMetadata<Block, Error = sp_blockchain::Error>
 		+ sp_runtime::traits::BlockIdTo<Block>
 		+ sc_client_api::ExecutorProvider<Block>
 		+ sc_client_api::UsageProvider<Block>
@@ -437,6 +453,7 @@ where
 	Client: sp_api::ProvideRuntimeApi<Block>
 		+ sc_client_api::BlockBackend<Block>
 		+ sc_client_api::blockchain::HeaderBackend<Block>
+		+ sp_blockchain::HeaderMetadata<Block, Error = sp_blockchain::Error>
 		+ sp_runtime::traits::BlockIdTo<Block>,
 	Client: Send + Sync + 'static,
 	Client::Api: sp_transaction_pool::runtime_api::TaggedTransactionQueue<Block>,
@@ -471,6 +488,7 @@ where
 			.block_id_to_number(at)?
 			.ok_or_else(|| error::Error::BlockIdConversion(format!("{:?}", at)))?;
 
+		let sender = self.pool.validated_pool().sender_of(&xt);
 		let validated = ValidatedTransaction::valid_at(
 			block_number.saturated_into::<u64>(),
 			hash.clone(),
@@ -478,6 +496,7 @@ where
 			xt,
 			bytes,
 			validity,
+			sender,
 		);
 
 		self.pool.validated_pool().submit(vec![validated]).remove(0)
@@ -605,155 +624,189 @@ async fn prune_known_txs_for_block<Block: BlockT, Api: graph::ChainApi<Block = B
 	hashes
 }
 
-impl<PoolApi, Block> MaintainedTransactionPool for BasicPool<PoolApi, Block>
+impl<PoolApi, Block> BasicPool<PoolApi, Block>
 where
 	Block: BlockT,
 	PoolApi: 'static + graph::ChainApi<Block = Block>,
 {
-	fn maintain(&self, event: ChainEvent<Self::Block>) -> Pin<Box<dyn Future<Output = ()> + Send>> {
-		match event {
-			ChainEvent::NewBestBlock { hash, tree_route } => {
-				let pool = self.pool.clone();
-				let api = self.api.clone();
-
-				let id = BlockId::hash(hash);
-				let block_number = match api.block_id_to_number(&id) {
-					Ok(Some(number)) => number,
-					_ => {
-						log::trace!(
-							target: "txpool",
-							"Skipping chain event - no number for that block {:?}",
-							id,
-						);
-						return Box::pin(ready(()))
-					},
-				};
+	/// Updates the enactment state with `event`, returning what should be done about it.
+	fn enactment_action(&self, event: &ChainEvent<Block>) -> EnactmentAction<Block> {
+		let api = self.api.clone();
+		self.enactment_state
+			.lock()
+			.update(event, |from, to| api.tree_route(from, to).map_err(|e| e.to_string()))
+	}
 
-				let next_action = self.revalidation_strategy.lock().next(
-					block_number,
-					Some(std::time::Duration::from_secs(60)),
-					Some(20u32.into()),
+	/// Maintains the pool at the block `hash`, coming from the blocks of `tree_route`.
+	fn handle_enactment(
+		&self,
+		hash: Block::Hash,
+		tree_route: Option<Arc<sp_blockchain::TreeRoute<Block>>>,
+	) -> Pin<Box<dyn Future<Output = ()> + Send>> {
+		let pool = self.pool.clone();
+		let api = self.api.clone();
+
+		let id = BlockId::hash(hash);
+		let block_number = match api.block_id_to_number(&id) {
+			Ok(Some(number)) => number,
+			_ => {
+				log::trace!(
+					target: "txpool",
+					"Skipping chain event - no number for that block {:?}",
+					id,
 				);
-				let revalidation_strategy = self.revalidation_strategy.clone();
-				let revalidation_queue = self.revalidation_queue.clone();
-				let ready_poll = self.ready_poll.clone();
-				let metrics = self.metrics.clone();
+				return Box::pin(ready(()))
+			},
+		};
 
-				async move {
-					// We keep track of everything we prune so that later we won't add
-					// transactions with those hashes from the retracted blocks.
-					let mut pruned_log = HashSet::<ExtrinsicHash<PoolApi>>::new();
-
-					// If there is a tree route, we use this to prune known tx based on the enacted
-					// blocks. Before pruning enacted transactions, we inform the listeners about
-					// retracted blocks and their transactions. This order is important, because
-					// if we enact and retract the same transaction at the same time, we want to
-					// send first the retract and than the prune event.
-					if let Some(ref tree_route) = tree_route {
-						for retracted in tree_route.retracted() {
-							// notify txs awaiting finality that it has been retracted
-							pool.validated_pool().on_block_retracted(retracted.hash.clone());
-						}
+		let next_action = self.revalidation_strategy.lock().next(
+			block_number,
+			Some(std::time::Duration::from_secs(60)),
+			Some(20u32.into()),
+		);
+		let revalidation_strategy = self.revalidation_strategy.clone();
+		let revalidation_queue = self.revalidation_queue.clone();
+		let ready_poll = self.ready_poll.clone();
+		let metrics = self.metrics.clone();
+
+		async move {
+			// We keep track of everything we prune so that later we won't add
+			// transactions with those hashes from the retracted blocks.
+			let mut pruned_log = HashSet::<ExtrinsicHash<PoolApi>>::new();
+
+			// If there is a tree route, we use this to prune known tx based on the enacted
+			// blocks. Before pruning enacted transactions, we inform the listeners about
+			// retracted blocks and their transactions. This order is important, because
+			// if we enact and retract the same transaction at the same time, we want to
+			// send first the retract and than the prune event.
+			if let Some(ref tree_route) = tree_route {
+				for retracted in tree_route.retracted() {
+					// notify txs awaiting finality that it has been retracted
+					pool.validated_pool().on_block_retracted(retracted.hash.clone());
+				}
+
+				// The route may end at the block itself, which is pruned below.
+				future::join_all(tree_route.enacted().iter().filter(|h| h.hash != hash).map(|h| {
+					prune_known_txs_for_block(BlockId::Hash(h.hash.clone()), &*api, &*pool)
+				}))
+				.await
+				.into_iter()
+				.for_each(|enacted_log| {
+					pruned_log.extend(enacted_log);
+				})
+			}
+
+			pruned_log.extend(prune_known_txs_for_block(id.clone(), &*api, &*pool).await);
+
+			metrics.report(|metrics| {
+				metrics.block_transactions_pruned.inc_by(pruned_log.len() as u64)
+			});
+
+			let tree_route = tree_route.filter(|tree_route| !tree_route.retracted().is_empty());
+			if let (true, Some(tree_route)) = (next_action.resubmit, tree_route) {
+				let mut resubmit_transactions = Vec::new();
+
+				for retracted in tree_route.retracted() {
+					let hash = retracted.hash.clone();
 
-						future::join_all(tree_route.enacted().iter().map(|h| {
-							prune_known_txs_for_block(BlockId::Hash(h.hash.clone()), &*api, &*pool)
-						}))
+					let block_transactions = api
+						.block_body(&BlockId::hash(hash))
 						.await
-						.into_iter()
-						.for_each(|enacted_log| {
-							pruned_log.extend(enacted_log);
+						.unwrap_or_else(|e| {
+							log::warn!("Failed to fetch block body {:?}!", e);
+							None
 						})
-					}
+						.unwrap_or_default()
+						.into_iter()
+						.filter(|tx| tx.is_signed().unwrap_or(true));
 
-					pruned_log.extend(prune_known_txs_for_block(id.clone(), &*api, &*pool).await);
+					let mut resubmitted_to_report = 0;
 
-					metrics.report(|metrics| {
-						metrics.block_transactions_pruned.inc_by(pruned_log.len() as u64)
-					});
+					resubmit_transactions.extend(block_transactions.into_iter().filter(|tx| {
+						let tx_hash = pool.hash_of(&tx);
+						let contains = pruned_log.contains(&tx_hash);
 
-					if let (true, Some(tree_route)) = (next_action.resubmit, tree_route) {
-						let mut resubmit_transactions = Vec::new();
-
-						for retracted in tree_route.retracted() {
-							let hash = retracted.hash.clone();
-
-							let block_transactions = api
-								.block_body(&BlockId::hash(hash))
-								.await
-								.unwrap_or_else(|e| {
-									log::warn!("Failed to fetch block body {:?}!", e);
-									None
-								})
-								.unwrap_or_default()
-								.into_iter()
-								.filter(|tx| tx.is_signed().unwrap_or(true));
-
-							let mut resubmitted_to_report = 0;
-
-							resubmit_transactions.extend(block_transactions.into_iter().filter(
-								|tx| {
-									let tx_hash = pool.hash_of(&tx);
-									let contains = pruned_log.contains(&tx_hash);
-
-									// need to count all transactions, not just filtered, here
-									resubmitted_to_report += 1;
-
-									if !contains {
-										log::debug!(
-											target: "txpool",
-											"[{:?}]: Resubmitting from retracted block {:?}",
-											tx_hash,
-											hash,
-										);
-									}
-									!contains
-								},
-							));
-
-							metrics.report(|metrics| {
-								metrics.block_transactions_resubmitted.inc_by(resubmitted_to_report)
-							});
-						}
+						// need to count all transactions, not just filtered, here
+						resubmitted_to_report += 1;
 
-						if let Err(e) = pool
-							.resubmit_at(
-								&id,
-								// These transactions are coming from retracted blocks, we should
-								// simply consider them external.
-								TransactionSource::External,
-								resubmit_transactions,
-							)
-							.await
-						{
+						if !contains {
 							log::debug!(
 								target: "txpool",
-								"[{:?}] Error re-submitting transactions: {:?}",
-								id,
-								e,
-							)
+								"[{:?}]: Resubmitting from retracted block {:?}",
+								tx_hash,
+								hash,
+							);
 						}
-					}
+						!contains
+					}));
 
-					let extra_pool = pool.clone();
-					// After #5200 lands, this arguably might be moved to the
-					// handler of "all blocks notification".
-					ready_poll.lock().trigger(block_number, move || {
-						Box::new(extra_pool.validated_pool().ready())
+					metrics.report(|metrics| {
+						metrics.block_transactions_resubmitted.inc_by(resubmitted_to_report)
 					});
+				}
 
-					if next_action.revalidate {
-						let hashes =
-							pool.validated_pool().ready().map(|tx| tx.hash.clone()).collect();
-						revalidation_queue.revalidate_later(block_number, hashes).await;
-
-						revalidation_strategy.lock().clear();
-					}
+				if let Err(e) = pool
+					.resubmit_at(
+						&id,
+						// These transactions are coming from retracted blocks, we should
+						// simply consider them external.
+						TransactionSource::External,
+						resubmit_transactions,
+					)
+					.await
+				{
+					log::debug!(
+						target: "txpool",
+						"[{:?}] Error re-submitting transactions: {:?}",
+						id,
+						e,
+					)
 				}
-				.boxed()
+			}
+
+			let extra_pool = pool.clone();
+			// After #5200 lands, this arguably might be moved to the
+			// handler of "all blocks notification".
+			ready_poll
+				.lock()
+				.trigger(block_number, move || Box::new(extra_pool.validated_pool().ready()));
+
+			if next_action.revalidate {
+				let hashes = pool.validated_pool().ready().map(|tx| tx.hash.clone()).collect();
+				revalidation_queue.revalidate_later(block_number, hashes).await;
+
+				revalidation_strategy.lock().clear();
+			}
+		}
+		.boxed()
+	}
+}
+
+impl<PoolApi, Block> MaintainedTransactionPool for BasicPool<PoolApi, Block>
+where
+	Block: BlockT,
+	PoolApi: 'static + graph::ChainApi<Block = Block>,
+{
+	fn maintain(&self, event: ChainEvent<Self::Block>) -> Pin<Box<dyn Future<Output = ()> + Send>> {
+		match event {
+			ChainEvent::NewBestBlock { hash, .. } => match self.enactment_action(&event) {
+				EnactmentAction::HandleEnactment(tree_route) =>
+					self.handle_enactment(hash, tree_route),
+				EnactmentAction::Skip => Box::pin(ready(())),
 			},
 			ChainEvent::Finalized { hash } => {
+				// Finality may land on a fork the pool was never maintained at, in which case
+				// the pool is brought up to the finalized block before notifying watchers.
+				let enactment = match self.enactment_action(&event) {
+					EnactmentAction::HandleEnactment(tree_route) =>
+						Some(self.handle_enactment(hash, tree_route)),
+					EnactmentAction::Skip => None,
+				};
 				let pool = self.pool.clone();
 				async move {
+					if let Some(enactment) = enactment {
+						enactment.await;
+					}
 					if let Err(e) = pool.validated_pool().on_block_finalized(hash).await {
 						log::warn!(
 							target: "txpool",
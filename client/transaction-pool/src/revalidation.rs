@@ -103,6 +103,7 @@ async fn batch_revalidate<Api: ChainApi>(
 						ext.data.clone(),
 						api.hash_and_length(&ext.data).1,
 						validity,
+						ext.sender.clone(),
 					),
 				);
 			},
@@ -39,3 +39,4 @@
 pub mod metrics;
 pub mod mpsc;
 pub mod status_sinks;
+pub mod task_guard;
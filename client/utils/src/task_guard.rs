@@ -0,0 +1,84 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Guards kept alive by the tasks spawned on behalf of a call.
+//!
+//! A guard is made current with [`with_guard`] while a call is handled. Every task spawned
+//! through [`bind`] in the meantime, or later from such a task, holds a reference to it, so the
+//! guard is dropped once the call and all of these tasks are done.
+
+use futures::{future::poll_fn, Future, FutureExt};
+use std::{any::Any, cell::RefCell, sync::Arc};
+
+/// A guard shared by the tasks spawned on behalf of a call.
+pub type TaskGuard = Arc<dyn Any + Send + Sync>;
+
+thread_local! {
+	static CURRENT: RefCell<Option<TaskGuard>> = RefCell::new(None);
+}
+
+/// Restores the previous guard when dropped, even if the guarded code panics.
+struct Restore(Option<TaskGuard>);
+
+impl Drop for Restore {
+	fn drop(&mut self) {
+		let guard = CURRENT.with(|current| current.replace(self.0.take()));
+		// Dropped outside of the borrow, as dropping it may run arbitrary code.
+		drop(guard);
+	}
+}
+
+/// Run `f` with `guard` as the current guard.
+pub fn with_guard<R>(guard: Option<TaskGuard>, f: impl FnOnce() -> R) -> R {
+	let _restore = Restore(CURRENT.with(|current| current.replace(guard)));
+	f()
+}
+
+/// Make `future`, which is about to be spawned, hold the current guard and be polled with it.
+pub fn bind<F: Future + Unpin>(mut future: F) -> impl Future<Output = F::Output> {
+	let guard = CURRENT.with(|current| current.borrow().clone());
+	poll_fn(move |cx| with_guard(guard.clone(), || future.poll_unpin(cx)))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use futures::{executor::block_on, future};
+
+	#[test]
+	fn guard_lives_as_long_as_the_bound_tasks() {
+		let guard: TaskGuard = Arc::new(());
+		let weak = Arc::downgrade(&guard);
+		let inner = RefCell::new(None);
+
+		let outer = with_guard(Some(guard), || {
+			bind(future::lazy(|_| {
+				// Spawned from a bound task, so it holds the guard as well.
+				*inner.borrow_mut() = Some(bind(future::ready(())));
+			}))
+		});
+		assert!(weak.upgrade().is_some());
+
+		block_on(outer);
+		let inner = inner.into_inner().unwrap();
+		assert!(weak.upgrade().is_some());
+
+		block_on(inner);
+		assert!(weak.upgrade().is_none());
+	}
+}
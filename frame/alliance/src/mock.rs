@@ -0,0 +1,258 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Test utilities
+
+pub use sp_core::H256;
+pub use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, IdentityLookup},
+	BuildStorage,
+};
+
+pub use frame_support::parameter_types;
+use frame_support::traits::GenesisBuild;
+use frame_system::{EnsureOneOf, EnsureRoot};
+use pallet_collective::EnsureProportionAtLeast;
+use sp_core::u32_trait::{_1, _2, _3, _5};
+
+pub use crate as pallet_alliance;
+
+use super::*;
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+}
+impl frame_system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type Origin = Origin;
+	type Call = Call;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<u64>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: u64 = 1;
+	pub const MaxLocks: u32 = 10;
+}
+impl pallet_balances::Config for Test {
+	type Balance = u64;
+	type DustRemoval = ();
+	type Event = Event;
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type MaxLocks = MaxLocks;
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+}
+
+parameter_types! {
+	pub const MotionDuration: u64 = 3;
+	pub const MaxProposals: u32 = 100;
+	pub const MaxMembers: u32 = 100;
+}
+type AllianceCollective = pallet_collective::Instance1;
+impl pallet_collective::Config<AllianceCollective> for Test {
+	type Origin = Origin;
+	type Proposal = Call;
+	type Event = Event;
+	type MotionDuration = MotionDuration;
+	type MaxProposals = MaxProposals;
+	type MaxMembers = MaxMembers;
+	type DefaultVote = pallet_collective::PrimeDefaultVote;
+	type WeightInfo = ();
+}
+
+/// Accounts `11..=110` have no identity. Accounts `101..=110` are sub-accounts of `1..=10`
+/// respectively, every other account has an identity with a good judgement.
+pub struct AllianceIdentityVerifier;
+impl IdentityVerifier<u64> for AllianceIdentityVerifier {
+	fn has_required_identities(who: &u64) -> bool {
+		!(11..=110).contains(who)
+	}
+
+	fn has_good_judgement(who: &u64) -> bool {
+		!(11..=110).contains(who)
+	}
+
+	fn super_account_id(who: &u64) -> Option<u64> {
+		if (101..=110).contains(who) {
+			Some(who - 100)
+		} else {
+			None
+		}
+	}
+}
+
+pub struct AllianceProposalProvider;
+impl ProposalProvider<u64, H256, Call> for AllianceProposalProvider {
+	fn propose_proposal(
+		who: u64,
+		threshold: u32,
+		proposal: Box<Call>,
+		length_bound: u32,
+	) -> DispatchResultWithPostInfo {
+		AllianceMotion::propose(Origin::signed(who), threshold, proposal, length_bound)
+	}
+
+	fn vote_proposal(
+		who: u64,
+		proposal: H256,
+		index: ProposalIndex,
+		approve: bool,
+	) -> DispatchResultWithPostInfo {
+		AllianceMotion::vote(Origin::signed(who), proposal, index, approve)
+	}
+
+	fn close_proposal(
+		who: u64,
+		proposal_hash: H256,
+		proposal_index: ProposalIndex,
+		proposal_weight_bound: Weight,
+		length_bound: u32,
+	) -> DispatchResultWithPostInfo {
+		AllianceMotion::close(
+			Origin::signed(who),
+			proposal_hash,
+			proposal_index,
+			proposal_weight_bound,
+			length_bound,
+		)
+	}
+
+	fn proposal_of(proposal_hash: H256) -> Option<Call> {
+		AllianceMotion::proposal_of(proposal_hash)
+	}
+}
+
+parameter_types! {
+	pub const MaxFellows: u32 = MaxMembers::get();
+	pub const MaxAllies: u32 = 100;
+	pub const AllyDeposit: u64 = 25;
+	pub const RetirementPeriod: u64 = MotionDuration::get() + 1;
+	pub const MaxUnscrupulousItems: u32 = 100;
+	pub const MaxWebsiteUrlLength: u32 = 255;
+	pub const MaxAnnouncementsCount: u32 = 100;
+	pub const MaxMembersCount: u32 = 100;
+}
+impl Config for Test {
+	type Event = Event;
+	type Proposal = Call;
+	type AdminOrigin =
+		EnsureOneOf<u64, EnsureRoot<u64>, EnsureProportionAtLeast<_2, _3, u64, AllianceCollective>>;
+	type MembershipManager =
+		EnsureOneOf<u64, EnsureRoot<u64>, EnsureProportionAtLeast<_2, _3, u64, AllianceCollective>>;
+	type AnnouncementOrigin =
+		EnsureOneOf<u64, EnsureRoot<u64>, EnsureProportionAtLeast<_1, _5, u64, AllianceCollective>>;
+	type Currency = Balances;
+	type Slashed = ();
+	type InitializeMembers = AllianceMotion;
+	type MembershipChanged = AllianceMotion;
+	type IdentityVerifier = AllianceIdentityVerifier;
+	type ProposalProvider = AllianceProposalProvider;
+	type MaxProposals = MaxProposals;
+	type MaxFellows = MaxFellows;
+	type MaxAllies = MaxAllies;
+	type MaxUnscrupulousItems = MaxUnscrupulousItems;
+	type MaxWebsiteUrlLength = MaxWebsiteUrlLength;
+	type MaxAnnouncementsCount = MaxAnnouncementsCount;
+	type MaxMembersCount = MaxMembersCount;
+	type AllyDeposit = AllyDeposit;
+	type WeightInfo = ();
+	type RetirementPeriod = RetirementPeriod;
+}
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		AllianceMotion: pallet_collective::<Instance1>::{Pallet, Storage, Origin<T>, Event<T>},
+		Alliance: pallet_alliance::{Pallet, Call, Storage, Event<T>, Config<T>},
+	}
+);
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![(1, 50), (2, 50), (3, 50), (4, 50), (5, 30), (6, 50), (7, 50), (8, 50)],
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+
+	GenesisBuild::<Test>::assimilate_storage(
+		&pallet_alliance::GenesisConfig::<Test> {
+			fellows: vec![1, 2],
+			allies: vec![3],
+			phantom: Default::default(),
+		},
+		&mut t,
+	)
+	.unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub fn new_bench_ext() -> sp_io::TestExternalities {
+	GenesisConfig::default().build_storage().unwrap().into()
+}
+
+pub fn test_cid() -> Cid {
+	let result = sp_io::hashing::sha2_256(b"hello world");
+	Cid::new_v0(result)
+}
+
+pub fn make_proposal(value: u64) -> Call {
+	Call::System(frame_system::Call::remark { remark: value.encode() })
+}
+
+pub fn make_set_rule_proposal(rule: Cid) -> Call {
+	Call::Alliance(pallet_alliance::Call::set_rule { rule })
+}
+
+pub fn make_kick_member_proposal(who: u64) -> Call {
+	Call::Alliance(pallet_alliance::Call::kick_member { who })
+}
@@ -0,0 +1,499 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for the alliance pallet.
+
+use sp_runtime::{
+	traits::{BadOrigin, Hash},
+	DispatchError,
+};
+
+use frame_support::{assert_noop, assert_ok, Hashable};
+use frame_system::{EventRecord, Phase};
+
+use super::*;
+use crate::mock::{Event, *};
+
+type AllianceMotionEvent = pallet_collective::Event<Test, pallet_collective::Instance1>;
+
+fn assert_last_event(event: crate::Event<Test>) {
+	assert_eq!(System::events().last().expect("Event expected").event, Event::Alliance(event));
+}
+
+fn url(s: &str) -> UrlOf<Test, ()> {
+	s.as_bytes().to_vec().try_into().unwrap()
+}
+
+#[test]
+fn propose_works() {
+	new_test_ext().execute_with(|| {
+		let proposal = make_proposal(42);
+		let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+		let hash: H256 = proposal.blake2_256().into();
+
+		// only voting member can propose proposal, 4 is ally not have vote rights
+		assert_noop!(
+			Alliance::propose(Origin::signed(3), 3, Box::new(proposal.clone()), proposal_len),
+			Error::<Test, ()>::NoVotingRights
+		);
+
+		assert_ok!(Alliance::propose(
+			Origin::signed(1),
+			3,
+			Box::new(proposal.clone()),
+			proposal_len
+		));
+		assert_eq!(*AllianceMotion::proposals(), vec![hash]);
+		assert_eq!(AllianceMotion::proposal_of(hash), Some(proposal));
+		assert_eq!(
+			System::events(),
+			vec![EventRecord {
+				phase: Phase::Initialization,
+				event: Event::AllianceMotion(AllianceMotionEvent::Proposed(1, 0, hash, 3)),
+				topics: vec![],
+			}]
+		);
+	});
+}
+
+#[test]
+fn vote_works() {
+	new_test_ext().execute_with(|| {
+		let proposal = make_proposal(42);
+		let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+		let hash: H256 = proposal.blake2_256().into();
+		assert_ok!(Alliance::propose(
+			Origin::signed(1),
+			3,
+			Box::new(proposal.clone()),
+			proposal_len
+		));
+
+		// an ally cannot vote
+		assert_noop!(
+			Alliance::vote(Origin::signed(3), hash, 0, true),
+			Error::<Test, ()>::NoVotingRights
+		);
+
+		assert_ok!(Alliance::vote(Origin::signed(2), hash, 0, true));
+		assert_eq!(
+			System::events().last().unwrap().event,
+			Event::AllianceMotion(AllianceMotionEvent::Voted(2, hash, true, 1, 0)),
+		);
+	});
+}
+
+#[test]
+fn close_works() {
+	new_test_ext().execute_with(|| {
+		let proposal = make_proposal(42);
+		let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+		let proposal_weight = proposal.get_dispatch_info().weight;
+		let hash = BlakeTwo256::hash_of(&proposal);
+		assert_ok!(Alliance::propose(
+			Origin::signed(1),
+			2,
+			Box::new(proposal.clone()),
+			proposal_len
+		));
+		assert_ok!(Alliance::vote(Origin::signed(1), hash, 0, true));
+		assert_ok!(Alliance::vote(Origin::signed(2), hash, 0, true));
+
+		// an ally cannot close
+		assert_noop!(
+			Alliance::close(Origin::signed(3), hash, 0, proposal_weight, proposal_len),
+			Error::<Test, ()>::NoVotingRights
+		);
+
+		assert_ok!(Alliance::close(Origin::signed(1), hash, 0, proposal_weight, proposal_len));
+		// the remark is executed with the collective origin, which it does not accept
+		assert!(System::events().iter().any(|r| r.event ==
+			Event::AllianceMotion(AllianceMotionEvent::Executed(
+				hash,
+				Err(DispatchError::BadOrigin)
+			))));
+		assert!(AllianceMotion::proposals().is_empty());
+	});
+}
+
+#[test]
+fn init_members_works() {
+	new_test_ext().execute_with(|| {
+		// alliance must be reset first, no witness data
+		assert_noop!(
+			Alliance::init_members(Origin::root(), vec![8], vec![]),
+			Error::<Test, ()>::AllianceAlreadyInitialized,
+		);
+
+		// give a retirement notice to check later a retiring member not removed
+		assert_ok!(Alliance::give_retirement_notice(Origin::signed(2)));
+		assert!(Alliance::is_member_of(&2, MemberRole::Retiring));
+
+		Members::<Test, ()>::remove(MemberRole::Fellow);
+		Members::<Test, ()>::remove(MemberRole::Ally);
+		pallet_collective::Members::<Test, pallet_collective::Instance1>::kill();
+
+		// must provide at least one fellow
+		assert_noop!(
+			Alliance::init_members(Origin::root(), vec![], vec![4]),
+			Error::<Test, ()>::FellowsMissing,
+		);
+		// members must have identities
+		assert_noop!(
+			Alliance::init_members(Origin::root(), vec![11], vec![]),
+			Error::<Test, ()>::WithoutRequiredIdentityFields,
+		);
+		// only root
+		assert_noop!(Alliance::init_members(Origin::signed(1), vec![8], vec![]), BadOrigin);
+
+		assert_ok!(Alliance::init_members(Origin::root(), vec![8, 5], vec![4]));
+		assert_eq!(Alliance::members(MemberRole::Fellow), vec![5, 8]);
+		assert_eq!(Alliance::members(MemberRole::Ally), vec![4]);
+		assert_eq!(AllianceMotion::members(), vec![5, 8]);
+		assert!(Alliance::is_member_of(&2, MemberRole::Retiring));
+		assert_last_event(crate::Event::MembersInitialized(vec![5, 8], vec![4]));
+	});
+}
+
+#[test]
+fn set_rule_works() {
+	new_test_ext().execute_with(|| {
+		let cid = test_cid();
+		assert_noop!(Alliance::set_rule(Origin::signed(1), cid.clone()), BadOrigin);
+
+		assert_ok!(Alliance::set_rule(Origin::root(), cid.clone()));
+		assert_eq!(Alliance::rule(), Some(cid.clone()));
+		assert_last_event(crate::Event::NewRuleSet(cid));
+	});
+}
+
+#[test]
+fn set_rule_by_motion_works() {
+	new_test_ext().execute_with(|| {
+		let cid = test_cid();
+		let proposal = make_set_rule_proposal(cid.clone());
+		let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+		let proposal_weight = proposal.get_dispatch_info().weight;
+		let hash = BlakeTwo256::hash_of(&proposal);
+		assert_ok!(Alliance::propose(
+			Origin::signed(1),
+			2,
+			Box::new(proposal.clone()),
+			proposal_len
+		));
+		assert_ok!(Alliance::vote(Origin::signed(1), hash, 0, true));
+		assert_ok!(Alliance::vote(Origin::signed(2), hash, 0, true));
+		assert_ok!(Alliance::close(Origin::signed(1), hash, 0, proposal_weight, proposal_len));
+
+		assert_eq!(Alliance::rule(), Some(cid));
+	});
+}
+
+#[test]
+fn announce_works() {
+	new_test_ext().execute_with(|| {
+		let cid = test_cid();
+
+		assert_noop!(Alliance::announce(Origin::signed(2), cid.clone()), BadOrigin);
+
+		assert_ok!(Alliance::announce(Origin::root(), cid.clone()));
+		assert_eq!(Alliance::announcements(), vec![cid.clone()]);
+		assert_last_event(crate::Event::Announced(cid));
+	});
+}
+
+#[test]
+fn remove_announcement_works() {
+	new_test_ext().execute_with(|| {
+		let cid = test_cid();
+		assert_noop!(
+			Alliance::remove_announcement(Origin::root(), cid.clone()),
+			Error::<Test, ()>::MissingAnnouncement,
+		);
+
+		assert_ok!(Alliance::announce(Origin::root(), cid.clone()));
+		assert_ok!(Alliance::remove_announcement(Origin::root(), cid.clone()));
+		assert!(Alliance::announcements().is_empty());
+		assert_last_event(crate::Event::AnnouncementRemoved(cid));
+	});
+}
+
+#[test]
+fn join_alliance_works() {
+	new_test_ext().execute_with(|| {
+		// check already member
+		assert_noop!(Alliance::join_alliance(Origin::signed(1)), Error::<Test, ()>::AlreadyMember);
+
+		// check already listed as unscrupulous
+		assert_ok!(Alliance::add_unscrupulous_items(
+			Origin::root(),
+			vec![UnscrupulousItem::AccountId(4)]
+		));
+		assert_noop!(
+			Alliance::join_alliance(Origin::signed(4)),
+			Error::<Test, ()>::AccountNonGrata
+		);
+		assert_ok!(Alliance::remove_unscrupulous_items(
+			Origin::root(),
+			vec![UnscrupulousItem::AccountId(4)]
+		));
+
+		// check deposit funds
+		assert_noop!(
+			Alliance::join_alliance(Origin::signed(9)),
+			Error::<Test, ()>::InsufficientFunds
+		);
+
+		// success to submit
+		assert_ok!(Alliance::join_alliance(Origin::signed(4)));
+		assert_eq!(Alliance::deposit_of(4), Some(25));
+		assert_eq!(Balances::reserved_balance(4), 25);
+		assert_eq!(Alliance::members(MemberRole::Ally), vec![3, 4]);
+		assert_last_event(crate::Event::NewAllyJoined(4, None, Some(25)));
+
+		// a sub-account inherits the identity of its super account
+		assert_ok!(Balances::transfer(Origin::signed(6), 106, 30));
+		assert_ok!(Alliance::join_alliance(Origin::signed(106)));
+		assert_eq!(Alliance::members(MemberRole::Ally), vec![3, 4, 106]);
+
+		// check identity
+		assert_noop!(
+			Alliance::join_alliance(Origin::signed(11)),
+			Error::<Test, ()>::WithoutRequiredIdentityFields
+		);
+	});
+}
+
+#[test]
+fn join_alliance_before_initialization_fails() {
+	new_test_ext().execute_with(|| {
+		Members::<Test, ()>::remove(MemberRole::Fellow);
+		Members::<Test, ()>::remove(MemberRole::Ally);
+
+		assert_noop!(
+			Alliance::join_alliance(Origin::signed(4)),
+			Error::<Test, ()>::AllianceNotYetInitialized
+		);
+	});
+}
+
+#[test]
+fn nominate_ally_works() {
+	new_test_ext().execute_with(|| {
+		// check already member
+		assert_noop!(
+			Alliance::nominate_ally(Origin::signed(1), 2),
+			Error::<Test, ()>::AlreadyMember
+		);
+
+		// only voting member(fellow) have nominate right
+		assert_noop!(
+			Alliance::nominate_ally(Origin::signed(3), 4),
+			Error::<Test, ()>::NoVotingRights
+		);
+
+		// check already listed as unscrupulous
+		assert_ok!(Alliance::add_unscrupulous_items(
+			Origin::root(),
+			vec![UnscrupulousItem::AccountId(4)]
+		));
+		assert_noop!(
+			Alliance::nominate_ally(Origin::signed(1), 4),
+			Error::<Test, ()>::AccountNonGrata
+		);
+		assert_ok!(Alliance::remove_unscrupulous_items(
+			Origin::root(),
+			vec![UnscrupulousItem::AccountId(4)]
+		));
+
+		// check identity
+		assert_noop!(
+			Alliance::nominate_ally(Origin::signed(1), 11),
+			Error::<Test, ()>::WithoutRequiredIdentityFields
+		);
+
+		// success to nominate, no deposit required
+		assert_ok!(Alliance::nominate_ally(Origin::signed(1), 9));
+		assert_eq!(Alliance::deposit_of(9), None);
+		assert_eq!(Alliance::members(MemberRole::Ally), vec![3, 9]);
+		assert_last_event(crate::Event::NewAllyJoined(9, Some(1), None));
+	});
+}
+
+#[test]
+fn elevate_ally_works() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(Alliance::elevate_ally(Origin::signed(1), 3), BadOrigin);
+		assert_noop!(Alliance::elevate_ally(Origin::root(), 4), Error::<Test, ()>::NotAlly);
+
+		assert_ok!(Alliance::elevate_ally(Origin::root(), 3));
+		assert_eq!(Alliance::members(MemberRole::Ally), Vec::<u64>::new());
+		assert_eq!(Alliance::members(MemberRole::Fellow), vec![1, 2, 3]);
+		assert_eq!(AllianceMotion::members(), vec![1, 2, 3]);
+		assert_last_event(crate::Event::AllyElevated(3));
+	});
+}
+
+#[test]
+fn give_retirement_notice_work() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Alliance::give_retirement_notice(Origin::signed(4)),
+			Error::<Test, ()>::NotMember
+		);
+
+		assert_ok!(Alliance::give_retirement_notice(Origin::signed(2)));
+		assert_eq!(Alliance::members(MemberRole::Fellow), vec![1]);
+		assert_eq!(Alliance::members(MemberRole::Retiring), vec![2]);
+		assert_eq!(AllianceMotion::members(), vec![1]);
+		assert_eq!(Alliance::retiring_members(2), Some(1 + RetirementPeriod::get()));
+		assert_last_event(crate::Event::MemberRetirementPeriodStarted(2));
+
+		assert_noop!(
+			Alliance::give_retirement_notice(Origin::signed(2)),
+			Error::<Test, ()>::AlreadyRetiring
+		);
+	});
+}
+
+#[test]
+fn retire_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Alliance::join_alliance(Origin::signed(4)));
+		assert_noop!(
+			Alliance::retire(Origin::signed(4)),
+			Error::<Test, ()>::RetirementNoticeNotGiven
+		);
+
+		assert_ok!(Alliance::give_retirement_notice(Origin::signed(4)));
+		assert_noop!(
+			Alliance::retire(Origin::signed(4)),
+			Error::<Test, ()>::RetirementPeriodNotPassed
+		);
+
+		System::set_block_number(1 + RetirementPeriod::get());
+		assert_ok!(Alliance::retire(Origin::signed(4)));
+		assert!(!Alliance::is_member(&4));
+		assert_eq!(Alliance::retiring_members(4), None);
+		assert_eq!(Alliance::deposit_of(4), None);
+		assert_eq!(Balances::reserved_balance(4), 0);
+		assert_last_event(crate::Event::MemberRetired(4, Some(25)));
+	});
+}
+
+#[test]
+fn kick_member_works() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(Alliance::kick_member(Origin::signed(4), 4), BadOrigin);
+		assert_noop!(Alliance::kick_member(Origin::root(), 4), Error::<Test, ()>::NotMember);
+
+		assert_ok!(Alliance::join_alliance(Origin::signed(4)));
+		assert_eq!(Balances::free_balance(4), 25);
+
+		assert_ok!(Alliance::kick_member(Origin::root(), 4));
+		assert!(!Alliance::is_member(&4));
+		assert_eq!(Alliance::deposit_of(4), None);
+		assert_eq!(Balances::reserved_balance(4), 0);
+		assert_eq!(Balances::free_balance(4), 25);
+		assert_last_event(crate::Event::MemberKicked(4, Some(25)));
+	});
+}
+
+#[test]
+fn kick_member_by_motion_works() {
+	new_test_ext().execute_with(|| {
+		let proposal = make_kick_member_proposal(3);
+		let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+		let proposal_weight = proposal.get_dispatch_info().weight;
+		let hash = BlakeTwo256::hash_of(&proposal);
+		assert_ok!(Alliance::propose(
+			Origin::signed(1),
+			2,
+			Box::new(proposal.clone()),
+			proposal_len
+		));
+		assert_ok!(Alliance::vote(Origin::signed(1), hash, 0, true));
+		assert_ok!(Alliance::vote(Origin::signed(2), hash, 0, true));
+		assert_ok!(Alliance::close(Origin::signed(1), hash, 0, proposal_weight, proposal_len));
+
+		assert!(!Alliance::is_member(&3));
+	});
+}
+
+#[test]
+fn add_unscrupulous_items_works() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(Alliance::add_unscrupulous_items(Origin::signed(2), vec![]), BadOrigin);
+
+		assert_ok!(Alliance::add_unscrupulous_items(
+			Origin::root(),
+			vec![UnscrupulousItem::AccountId(3), UnscrupulousItem::Website(url("abc"))]
+		));
+		assert_eq!(Alliance::unscrupulous_accounts().into_inner(), vec![3]);
+		assert_eq!(Alliance::unscrupulous_websites().into_inner(), vec![url("abc")]);
+
+		assert_noop!(
+			Alliance::add_unscrupulous_items(Origin::root(), vec![UnscrupulousItem::AccountId(3)]),
+			Error::<Test, ()>::AlreadyUnscrupulous
+		);
+	});
+}
+
+#[test]
+fn remove_unscrupulous_items_works() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(Alliance::remove_unscrupulous_items(Origin::signed(2), vec![]), BadOrigin);
+
+		assert_noop!(
+			Alliance::remove_unscrupulous_items(
+				Origin::root(),
+				vec![UnscrupulousItem::AccountId(3)]
+			),
+			Error::<Test, ()>::NotListedAsUnscrupulous
+		);
+
+		assert_ok!(Alliance::add_unscrupulous_items(
+			Origin::root(),
+			vec![UnscrupulousItem::AccountId(3)]
+		));
+		assert_eq!(Alliance::unscrupulous_accounts(), vec![3]);
+		assert_ok!(Alliance::remove_unscrupulous_items(
+			Origin::root(),
+			vec![UnscrupulousItem::AccountId(3)]
+		));
+		assert_eq!(Alliance::unscrupulous_accounts(), Vec::<u64>::new());
+		assert_last_event(crate::Event::UnscrupulousItemRemoved(vec![
+			UnscrupulousItem::AccountId(3),
+		]));
+	});
+}
+
+#[test]
+fn abdicate_fellow_status_works() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Alliance::abdicate_fellow_status(Origin::signed(3)),
+			Error::<Test, ()>::NoVotingRights
+		);
+
+		assert_ok!(Alliance::abdicate_fellow_status(Origin::signed(2)));
+		assert_eq!(Alliance::members(MemberRole::Fellow), vec![1]);
+		assert_eq!(Alliance::members(MemberRole::Ally), vec![2, 3]);
+		assert_eq!(AllianceMotion::members(), vec![1]);
+		assert_last_event(crate::Event::FellowAbdicated(2));
+	});
+}
@@ -0,0 +1,77 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Types used by the alliance pallet.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{traits::ConstU32, BoundedVec, RuntimeDebug};
+use scale_info::TypeInfo;
+use sp_std::{convert::TryInto, prelude::*};
+
+/// A Multihash instance that only supports the basic functionality and no hashing.
+#[derive(Clone, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub struct Multihash {
+	/// The code of the Multihash.
+	pub code: u64,
+	/// The digest.
+	pub digest: BoundedVec<u8, ConstU32<68>>, // 4 byte dig size + 64 bytes hash digest
+}
+
+impl Multihash {
+	/// Returns the size of the digest.
+	pub fn size(&self) -> usize {
+		self.digest.len()
+	}
+}
+
+/// The version of the CID.
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub enum Version {
+	/// CID version 0.
+	V0,
+	/// CID version 1.
+	V1,
+}
+
+/// Representation of a CID.
+#[derive(Clone, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub struct Cid {
+	/// The version of CID.
+	pub version: Version,
+	/// The codec of CID.
+	pub codec: u64,
+	/// The multihash of CID.
+	pub hash: Multihash,
+}
+
+impl Cid {
+	/// Creates a new CIDv0 from a SHA2-256 digest.
+	///
+	/// Digests longer than the maximum multihash digest size are truncated.
+	pub fn new_v0(sha2_256_digest: impl Into<Vec<u8>>) -> Self {
+		/// DAG-PB multicodec code
+		const DAG_PB: u64 = 0x70;
+		/// The SHA_256 multicodec code
+		const SHA2_256: u64 = 0x12;
+
+		let mut digest: Vec<u8> = sha2_256_digest.into();
+		digest.truncate(68);
+		let digest = digest.try_into().expect("digest is truncated to the bound; qed");
+
+		Self { version: Version::V0, codec: DAG_PB, hash: Multihash { code: SHA2_256, digest } }
+	}
+}
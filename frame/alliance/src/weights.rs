@@ -0,0 +1,482 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Autogenerated weights for pallet_alliance
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2021-10-11, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 128
+
+// Executed Command:
+// ./target/release/substrate
+// benchmark
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_alliance
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./frame/alliance/src/weights.rs
+// --template=./.maintain/frame-weight-template.hbs
+
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_alliance.
+pub trait WeightInfo {
+	fn propose(b: u32, m: u32, p: u32, ) -> Weight;
+	fn vote(m: u32, ) -> Weight;
+	fn close_early_disapproved(m: u32, p: u32, ) -> Weight;
+	fn close_early_approved(b: u32, m: u32, p: u32, ) -> Weight;
+	fn close_disapproved(m: u32, p: u32, ) -> Weight;
+	fn close_approved(b: u32, m: u32, p: u32, ) -> Weight;
+	fn init_members(x: u32, y: u32, ) -> Weight;
+	fn set_rule() -> Weight;
+	fn announce() -> Weight;
+	fn remove_announcement() -> Weight;
+	fn join_alliance() -> Weight;
+	fn nominate_ally() -> Weight;
+	fn elevate_ally() -> Weight;
+	fn give_retirement_notice() -> Weight;
+	fn retire() -> Weight;
+	fn kick_member() -> Weight;
+	fn add_unscrupulous_items(n: u32, l: u32, ) -> Weight;
+	fn remove_unscrupulous_items(n: u32, l: u32, ) -> Weight;
+	fn abdicate_fellow_status() -> Weight;
+}
+
+/// Weights for pallet_alliance using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: Alliance Members (r:1 w:0)
+	// Storage: AllianceMotion ProposalOf (r:1 w:1)
+	// Storage: AllianceMotion Proposals (r:1 w:1)
+	// Storage: AllianceMotion ProposalCount (r:1 w:1)
+	// Storage: AllianceMotion Voting (r:0 w:1)
+	fn propose(b: u32, m: u32, p: u32, ) -> Weight {
+		(39_992_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((4_000 as Weight).saturating_mul(b as Weight))
+			// Standard Error: 2_000
+			.saturating_add((84_000 as Weight).saturating_mul(m as Weight))
+			// Standard Error: 9_000
+			.saturating_add((362_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Alliance Members (r:1 w:0)
+	// Storage: AllianceMotion Voting (r:1 w:1)
+	fn vote(m: u32, ) -> Weight {
+		(36_649_000 as Weight)
+			// Standard Error: 2_000
+			.saturating_add((90_000 as Weight).saturating_mul(m as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Alliance Members (r:1 w:0)
+	// Storage: AllianceMotion Voting (r:1 w:1)
+	// Storage: AllianceMotion Members (r:1 w:0)
+	// Storage: AllianceMotion Proposals (r:1 w:1)
+	// Storage: AllianceMotion ProposalOf (r:0 w:1)
+	fn close_early_disapproved(m: u32, p: u32, ) -> Weight {
+		(50_373_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((69_000 as Weight).saturating_mul(m as Weight))
+			// Standard Error: 8_000
+			.saturating_add((339_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Alliance Members (r:1 w:0)
+	// Storage: AllianceMotion Voting (r:1 w:1)
+	// Storage: AllianceMotion Members (r:1 w:0)
+	// Storage: AllianceMotion ProposalOf (r:1 w:1)
+	// Storage: AllianceMotion Proposals (r:1 w:1)
+	fn close_early_approved(b: u32, m: u32, p: u32, ) -> Weight {
+		(62_457_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((1_000 as Weight).saturating_mul(b as Weight))
+			// Standard Error: 1_000
+			.saturating_add((65_000 as Weight).saturating_mul(m as Weight))
+			// Standard Error: 8_000
+			.saturating_add((358_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Alliance Members (r:1 w:0)
+	// Storage: AllianceMotion Voting (r:1 w:1)
+	// Storage: AllianceMotion Members (r:1 w:0)
+	// Storage: AllianceMotion Prime (r:1 w:0)
+	// Storage: AllianceMotion Proposals (r:1 w:1)
+	// Storage: AllianceMotion ProposalOf (r:0 w:1)
+	fn close_disapproved(m: u32, p: u32, ) -> Weight {
+		(59_631_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((53_000 as Weight).saturating_mul(m as Weight))
+			// Standard Error: 9_000
+			.saturating_add((362_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Alliance Members (r:1 w:0)
+	// Storage: AllianceMotion Voting (r:1 w:1)
+	// Storage: AllianceMotion Members (r:1 w:0)
+	// Storage: AllianceMotion Prime (r:1 w:0)
+	// Storage: AllianceMotion ProposalOf (r:1 w:1)
+	// Storage: AllianceMotion Proposals (r:1 w:1)
+	fn close_approved(b: u32, m: u32, p: u32, ) -> Weight {
+		(71_870_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((1_000 as Weight).saturating_mul(b as Weight))
+			// Standard Error: 1_000
+			.saturating_add((60_000 as Weight).saturating_mul(m as Weight))
+			// Standard Error: 8_000
+			.saturating_add((357_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Alliance Members (r:2 w:2)
+	// Storage: AllianceMotion Members (r:1 w:1)
+	// Storage: AllianceMotion Prime (r:0 w:1)
+	fn init_members(x: u32, y: u32, ) -> Weight {
+		(0 as Weight)
+			// Standard Error: 6_000
+			.saturating_add((271_000 as Weight).saturating_mul(x as Weight))
+			// Standard Error: 4_000
+			.saturating_add((180_000 as Weight).saturating_mul(y as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Alliance Rule (r:0 w:1)
+	fn set_rule() -> Weight {
+		(18_189_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Alliance Announcements (r:1 w:1)
+	fn announce() -> Weight {
+		(21_106_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Alliance Announcements (r:1 w:1)
+	fn remove_announcement() -> Weight {
+		(22_208_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Alliance Members (r:3 w:1)
+	// Storage: Alliance UnscrupulousAccounts (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Alliance DepositOf (r:0 w:1)
+	fn join_alliance() -> Weight {
+		(53_771_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Alliance Members (r:4 w:1)
+	// Storage: Alliance UnscrupulousAccounts (r:1 w:0)
+	fn nominate_ally() -> Weight {
+		(41_912_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Alliance Members (r:3 w:2)
+	// Storage: AllianceMotion Proposals (r:1 w:0)
+	// Storage: AllianceMotion Members (r:0 w:1)
+	// Storage: AllianceMotion Prime (r:0 w:1)
+	fn elevate_ally() -> Weight {
+		(36_811_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Alliance Members (r:3 w:2)
+	// Storage: AllianceMotion Proposals (r:1 w:0)
+	// Storage: AllianceMotion Members (r:0 w:1)
+	// Storage: AllianceMotion Prime (r:0 w:1)
+	// Storage: Alliance RetiringMembers (r:0 w:1)
+	fn give_retirement_notice() -> Weight {
+		(41_079_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: Alliance RetiringMembers (r:1 w:1)
+	// Storage: Alliance Members (r:1 w:1)
+	// Storage: Alliance DepositOf (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn retire() -> Weight {
+		(42_703_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Alliance Members (r:3 w:2)
+	// Storage: AllianceMotion Proposals (r:1 w:0)
+	// Storage: Alliance DepositOf (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: AllianceMotion Members (r:0 w:1)
+	// Storage: AllianceMotion Prime (r:0 w:1)
+	// Storage: Alliance RetiringMembers (r:0 w:1)
+	fn kick_member() -> Weight {
+		(61_370_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+	// Storage: Alliance UnscrupulousAccounts (r:1 w:1)
+	// Storage: Alliance UnscrupulousWebsites (r:1 w:1)
+	fn add_unscrupulous_items(n: u32, l: u32, ) -> Weight {
+		(0 as Weight)
+			// Standard Error: 34_000
+			.saturating_add((1_385_000 as Weight).saturating_mul(n as Weight))
+			// Standard Error: 2_000
+			.saturating_add((119_000 as Weight).saturating_mul(l as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Alliance UnscrupulousAccounts (r:1 w:1)
+	// Storage: Alliance UnscrupulousWebsites (r:1 w:1)
+	fn remove_unscrupulous_items(n: u32, l: u32, ) -> Weight {
+		(0 as Weight)
+			// Standard Error: 2_996_000
+			.saturating_add((119_875_000 as Weight).saturating_mul(n as Weight))
+			// Standard Error: 1_000
+			.saturating_add((7_000 as Weight).saturating_mul(l as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Alliance Members (r:3 w:2)
+	// Storage: AllianceMotion Proposals (r:1 w:0)
+	// Storage: AllianceMotion Members (r:0 w:1)
+	// Storage: AllianceMotion Prime (r:0 w:1)
+	fn abdicate_fellow_status() -> Weight {
+		(38_906_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: Alliance Members (r:1 w:0)
+	// Storage: AllianceMotion ProposalOf (r:1 w:1)
+	// Storage: AllianceMotion Proposals (r:1 w:1)
+	// Storage: AllianceMotion ProposalCount (r:1 w:1)
+	// Storage: AllianceMotion Voting (r:0 w:1)
+	fn propose(b: u32, m: u32, p: u32, ) -> Weight {
+		(39_992_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((4_000 as Weight).saturating_mul(b as Weight))
+			// Standard Error: 2_000
+			.saturating_add((84_000 as Weight).saturating_mul(m as Weight))
+			// Standard Error: 9_000
+			.saturating_add((362_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Alliance Members (r:1 w:0)
+	// Storage: AllianceMotion Voting (r:1 w:1)
+	fn vote(m: u32, ) -> Weight {
+		(36_649_000 as Weight)
+			// Standard Error: 2_000
+			.saturating_add((90_000 as Weight).saturating_mul(m as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Alliance Members (r:1 w:0)
+	// Storage: AllianceMotion Voting (r:1 w:1)
+	// Storage: AllianceMotion Members (r:1 w:0)
+	// Storage: AllianceMotion Proposals (r:1 w:1)
+	// Storage: AllianceMotion ProposalOf (r:0 w:1)
+	fn close_early_disapproved(m: u32, p: u32, ) -> Weight {
+		(50_373_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((69_000 as Weight).saturating_mul(m as Weight))
+			// Standard Error: 8_000
+			.saturating_add((339_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Alliance Members (r:1 w:0)
+	// Storage: AllianceMotion Voting (r:1 w:1)
+	// Storage: AllianceMotion Members (r:1 w:0)
+	// Storage: AllianceMotion ProposalOf (r:1 w:1)
+	// Storage: AllianceMotion Proposals (r:1 w:1)
+	fn close_early_approved(b: u32, m: u32, p: u32, ) -> Weight {
+		(62_457_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((1_000 as Weight).saturating_mul(b as Weight))
+			// Standard Error: 1_000
+			.saturating_add((65_000 as Weight).saturating_mul(m as Weight))
+			// Standard Error: 8_000
+			.saturating_add((358_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Alliance Members (r:1 w:0)
+	// Storage: AllianceMotion Voting (r:1 w:1)
+	// Storage: AllianceMotion Members (r:1 w:0)
+	// Storage: AllianceMotion Prime (r:1 w:0)
+	// Storage: AllianceMotion Proposals (r:1 w:1)
+	// Storage: AllianceMotion ProposalOf (r:0 w:1)
+	fn close_disapproved(m: u32, p: u32, ) -> Weight {
+		(59_631_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((53_000 as Weight).saturating_mul(m as Weight))
+			// Standard Error: 9_000
+			.saturating_add((362_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Alliance Members (r:1 w:0)
+	// Storage: AllianceMotion Voting (r:1 w:1)
+	// Storage: AllianceMotion Members (r:1 w:0)
+	// Storage: AllianceMotion Prime (r:1 w:0)
+	// Storage: AllianceMotion ProposalOf (r:1 w:1)
+	// Storage: AllianceMotion Proposals (r:1 w:1)
+	fn close_approved(b: u32, m: u32, p: u32, ) -> Weight {
+		(71_870_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((1_000 as Weight).saturating_mul(b as Weight))
+			// Standard Error: 1_000
+			.saturating_add((60_000 as Weight).saturating_mul(m as Weight))
+			// Standard Error: 8_000
+			.saturating_add((357_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Alliance Members (r:2 w:2)
+	// Storage: AllianceMotion Members (r:1 w:1)
+	// Storage: AllianceMotion Prime (r:0 w:1)
+	fn init_members(x: u32, y: u32, ) -> Weight {
+		(0 as Weight)
+			// Standard Error: 6_000
+			.saturating_add((271_000 as Weight).saturating_mul(x as Weight))
+			// Standard Error: 4_000
+			.saturating_add((180_000 as Weight).saturating_mul(y as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Alliance Rule (r:0 w:1)
+	fn set_rule() -> Weight {
+		(18_189_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Alliance Announcements (r:1 w:1)
+	fn announce() -> Weight {
+		(21_106_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Alliance Announcements (r:1 w:1)
+	fn remove_announcement() -> Weight {
+		(22_208_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Alliance Members (r:3 w:1)
+	// Storage: Alliance UnscrupulousAccounts (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Alliance DepositOf (r:0 w:1)
+	fn join_alliance() -> Weight {
+		(53_771_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Alliance Members (r:4 w:1)
+	// Storage: Alliance UnscrupulousAccounts (r:1 w:0)
+	fn nominate_ally() -> Weight {
+		(41_912_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Alliance Members (r:3 w:2)
+	// Storage: AllianceMotion Proposals (r:1 w:0)
+	// Storage: AllianceMotion Members (r:0 w:1)
+	// Storage: AllianceMotion Prime (r:0 w:1)
+	fn elevate_ally() -> Weight {
+		(36_811_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Alliance Members (r:3 w:2)
+	// Storage: AllianceMotion Proposals (r:1 w:0)
+	// Storage: AllianceMotion Members (r:0 w:1)
+	// Storage: AllianceMotion Prime (r:0 w:1)
+	// Storage: Alliance RetiringMembers (r:0 w:1)
+	fn give_retirement_notice() -> Weight {
+		(41_079_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	// Storage: Alliance RetiringMembers (r:1 w:1)
+	// Storage: Alliance Members (r:1 w:1)
+	// Storage: Alliance DepositOf (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn retire() -> Weight {
+		(42_703_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Alliance Members (r:3 w:2)
+	// Storage: AllianceMotion Proposals (r:1 w:0)
+	// Storage: Alliance DepositOf (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: AllianceMotion Members (r:0 w:1)
+	// Storage: AllianceMotion Prime (r:0 w:1)
+	// Storage: Alliance RetiringMembers (r:0 w:1)
+	fn kick_member() -> Weight {
+		(61_370_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
+	// Storage: Alliance UnscrupulousAccounts (r:1 w:1)
+	// Storage: Alliance UnscrupulousWebsites (r:1 w:1)
+	fn add_unscrupulous_items(n: u32, l: u32, ) -> Weight {
+		(0 as Weight)
+			// Standard Error: 34_000
+			.saturating_add((1_385_000 as Weight).saturating_mul(n as Weight))
+			// Standard Error: 2_000
+			.saturating_add((119_000 as Weight).saturating_mul(l as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Alliance UnscrupulousAccounts (r:1 w:1)
+	// Storage: Alliance UnscrupulousWebsites (r:1 w:1)
+	fn remove_unscrupulous_items(n: u32, l: u32, ) -> Weight {
+		(0 as Weight)
+			// Standard Error: 2_996_000
+			.saturating_add((119_875_000 as Weight).saturating_mul(n as Weight))
+			// Standard Error: 1_000
+			.saturating_add((7_000 as Weight).saturating_mul(l as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Alliance Members (r:3 w:2)
+	// Storage: AllianceMotion Proposals (r:1 w:0)
+	// Storage: AllianceMotion Members (r:0 w:1)
+	// Storage: AllianceMotion Prime (r:0 w:1)
+	fn abdicate_fellow_status() -> Weight {
+		(38_906_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+}
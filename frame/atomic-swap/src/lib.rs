@@ -29,6 +29,11 @@
 //! is used to allow the target to approve (claim) the swap. If the swap is not
 //! claimed within a specified duration of time, the sender may cancel it.
 //!
+//! What is being swapped is defined by the [`SwapAction`] configured for the runtime.
+//! [`BalanceSwapAction`] swaps native balances and [`FungiblesSwapAction`] swaps any
+//! fungible asset which can be held. Swap actions must have a bounded encoded length,
+//! so that the proof size of claiming a swap is bounded too.
+//!
 //! ## Interface
 //!
 //! ### Dispatchable Functions
@@ -42,16 +47,19 @@
 
 mod tests;
 
-use codec::{Decode, Encode};
+use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::{
 	dispatch::DispatchResult,
-	traits::{BalanceStatus, Currency, Get, ReservableCurrency},
+	traits::{
+		tokens::fungibles::{Inspect, MutateHold},
+		BalanceStatus, Currency, Get, ReservableCurrency,
+	},
 	weights::Weight,
 	RuntimeDebugNoBound,
 };
 use scale_info::TypeInfo;
 use sp_io::hashing::blake2_256;
-use sp_runtime::RuntimeDebug;
+use sp_runtime::{traits::Saturating, RuntimeDebug};
 use sp_std::{
 	marker::PhantomData,
 	ops::{Deref, DerefMut},
@@ -70,6 +78,14 @@ pub struct PendingSwap<T: Config> {
 	pub end_block: T::BlockNumber,
 }
 
+impl<T: Config> MaxEncodedLen for PendingSwap<T> {
+	fn max_encoded_len() -> usize {
+		T::AccountId::max_encoded_len()
+			.saturating_add(T::SwapAction::max_encoded_len())
+			.saturating_add(T::BlockNumber::max_encoded_len())
+	}
+}
+
 /// Hashed proof type.
 pub type HashedProof = [u8; 32];
 
@@ -130,6 +146,15 @@ where
 	}
 }
 
+impl<AccountId, C> MaxEncodedLen for BalanceSwapAction<AccountId, C>
+where
+	C: ReservableCurrency<AccountId>,
+{
+	fn max_encoded_len() -> usize {
+		<C as Currency<AccountId>>::Balance::max_encoded_len()
+	}
+}
+
 impl<T: Config, AccountId, C> SwapAction<AccountId, T> for BalanceSwapAction<AccountId, C>
 where
 	C: ReservableCurrency<AccountId>,
@@ -151,6 +176,62 @@ where
 	}
 }
 
+/// A swap action that transfers some amount of a fungible asset, which is held for the duration
+/// of the swap.
+#[derive(Clone, RuntimeDebug, Eq, PartialEq, Encode, Decode, TypeInfo)]
+#[scale_info(skip_type_params(A))]
+pub struct FungiblesSwapAction<AccountId, A: MutateHold<AccountId>> {
+	asset: <A as Inspect<AccountId>>::AssetId,
+	amount: <A as Inspect<AccountId>>::Balance,
+	_marker: PhantomData<A>,
+}
+
+impl<AccountId, A> FungiblesSwapAction<AccountId, A>
+where
+	A: MutateHold<AccountId>,
+{
+	/// Create a new swap action of `amount` of `asset`.
+	pub fn new(
+		asset: <A as Inspect<AccountId>>::AssetId,
+		amount: <A as Inspect<AccountId>>::Balance,
+	) -> Self {
+		Self { asset, amount, _marker: PhantomData }
+	}
+}
+
+impl<AccountId, A> MaxEncodedLen for FungiblesSwapAction<AccountId, A>
+where
+	A: MutateHold<AccountId>,
+	<A as Inspect<AccountId>>::AssetId: MaxEncodedLen,
+	<A as Inspect<AccountId>>::Balance: MaxEncodedLen,
+{
+	fn max_encoded_len() -> usize {
+		<A as Inspect<AccountId>>::AssetId::max_encoded_len()
+			.saturating_add(<A as Inspect<AccountId>>::Balance::max_encoded_len())
+	}
+}
+
+impl<T: Config, AccountId, A> SwapAction<AccountId, T> for FungiblesSwapAction<AccountId, A>
+where
+	A: MutateHold<AccountId>,
+{
+	fn reserve(&self, source: &AccountId) -> DispatchResult {
+		A::hold(self.asset, source, self.amount)
+	}
+
+	fn claim(&self, source: &AccountId, target: &AccountId) -> bool {
+		A::transfer_held(self.asset, source, target, self.amount, false, false).is_ok()
+	}
+
+	fn weight(&self) -> Weight {
+		T::DbWeight::get().reads_writes(2, 2)
+	}
+
+	fn cancel(&self, source: &AccountId) {
+		let _ = A::release(self.asset, source, self.amount, true);
+	}
+}
+
 pub use pallet::*;
 
 #[frame_support::pallet]
@@ -165,7 +246,9 @@ pub mod pallet {
 		/// The overarching event type.
 		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 		/// Swap action.
-		type SwapAction: SwapAction<Self::AccountId, Self> + Parameter;
+		///
+		/// Its maximum encoded length bounds the proof size of claiming and cancelling a swap.
+		type SwapAction: SwapAction<Self::AccountId, Self> + Parameter + MaxEncodedLen;
 		/// Limit of proof size.
 		///
 		/// Atomic swap is only atomic if once the proof is revealed, both parties can submit the
@@ -182,6 +265,7 @@ pub mod pallet {
 
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::generate_storage_info]
 	pub struct Pallet<T>(PhantomData<T>);
 
 	#[pallet::storage]
@@ -264,7 +348,7 @@ pub mod pallet {
 			let swap = PendingSwap {
 				source,
 				action,
-				end_block: frame_system::Pallet::<T>::block_number() + duration,
+				end_block: frame_system::Pallet::<T>::block_number().saturating_add(duration),
 			};
 			PendingSwaps::<T>::insert(target.clone(), hashed_proof.clone(), swap.clone());
 
@@ -3,7 +3,7 @@
 use super::*;
 use crate as pallet_atomic_swap;
 
-use frame_support::parameter_types;
+use frame_support::{assert_noop, assert_ok, parameter_types};
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
@@ -147,3 +147,40 @@ fn two_party_successful_swap() {
 		assert_eq!(Balances::free_balance(B), 200 + 50);
 	});
 }
+
+#[test]
+fn swap_can_only_be_cancelled_after_duration() {
+	new_test_ext().execute_with(|| {
+		let proof: [u8; 2] = [4, 2];
+		let hashed_proof = blake2_256(&proof);
+
+		System::set_block_number(1);
+		AtomicSwap::create_swap(Origin::signed(A), B, hashed_proof, BalanceSwapAction::new(50), 10)
+			.unwrap();
+
+		// Only the source may cancel.
+		assert_noop!(
+			AtomicSwap::cancel_swap(Origin::signed(B), B, hashed_proof),
+			Error::<Test>::SourceMismatch
+		);
+		// Not before the duration has passed.
+		System::set_block_number(10);
+		assert_noop!(
+			AtomicSwap::cancel_swap(Origin::signed(A), B, hashed_proof),
+			Error::<Test>::DurationNotPassed
+		);
+		System::set_block_number(11);
+		assert_ok!(AtomicSwap::cancel_swap(Origin::signed(A), B, hashed_proof));
+
+		assert_eq!(Balances::free_balance(A), 100);
+		assert_eq!(Balances::reserved_balance(A), 0);
+		assert!(PendingSwaps::<Test>::get(B, hashed_proof).is_none());
+	});
+}
+
+#[test]
+fn pending_swap_has_bounded_size() {
+	// Source, balance and end block.
+	assert_eq!(PendingSwap::<Test>::max_encoded_len(), 8 + 8 + 8);
+	assert_eq!(BalanceSwapAction::<u64, Balances>::max_encoded_len(), 8);
+}
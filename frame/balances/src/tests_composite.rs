@@ -22,7 +22,7 @@
 use crate::{self as pallet_balances, decl_tests, Config, Pallet};
 use frame_support::{
 	parameter_types,
-	weights::{DispatchInfo, IdentityFee, Weight},
+	weights::{ConstantMultiplier, DispatchInfo, IdentityFee, Weight},
 };
 use pallet_transaction_payment::CurrencyAdapter;
 use sp_core::H256;
@@ -79,8 +79,8 @@ parameter_types! {
 }
 impl pallet_transaction_payment::Config for Test {
 	type OnChargeTransaction = CurrencyAdapter<Pallet<Test>, ()>;
-	type TransactionByteFee = TransactionByteFee;
 	type WeightToFee = IdentityFee<u64>;
+	type LengthToFee = ConstantMultiplier<u64, TransactionByteFee>;
 	type FeeMultiplierUpdate = ();
 }
 
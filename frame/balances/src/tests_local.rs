@@ -23,7 +23,7 @@ use crate::{self as pallet_balances, decl_tests, Config, Pallet};
 use frame_support::{
 	parameter_types,
 	traits::StorageMapShim,
-	weights::{DispatchInfo, IdentityFee, Weight},
+	weights::{ConstantMultiplier, DispatchInfo, IdentityFee, Weight},
 };
 use pallet_transaction_payment::CurrencyAdapter;
 use sp_core::H256;
@@ -81,8 +81,8 @@ parameter_types! {
 }
 impl pallet_transaction_payment::Config for Test {
 	type OnChargeTransaction = CurrencyAdapter<Pallet<Test>, ()>;
-	type TransactionByteFee = TransactionByteFee;
 	type WeightToFee = IdentityFee<u64>;
+	type LengthToFee = ConstantMultiplier<u64, TransactionByteFee>;
 	type FeeMultiplierUpdate = ();
 }
 parameter_types! {
@@ -20,7 +20,11 @@
 #![cfg(test)]
 
 use crate::{self as pallet_balances, Config, Pallet};
-use frame_support::{parameter_types, traits::StorageMapShim, weights::IdentityFee};
+use frame_support::{
+	parameter_types,
+	traits::StorageMapShim,
+	weights::{ConstantMultiplier, IdentityFee},
+};
 use pallet_transaction_payment::CurrencyAdapter;
 use sp_core::H256;
 use sp_io;
@@ -83,8 +87,8 @@ parameter_types! {
 }
 impl pallet_transaction_payment::Config for Test {
 	type OnChargeTransaction = CurrencyAdapter<Pallet<Test>, ()>;
-	type TransactionByteFee = TransactionByteFee;
 	type WeightToFee = IdentityFee<u64>;
+	type LengthToFee = ConstantMultiplier<u64, TransactionByteFee>;
 	type FeeMultiplierUpdate = ();
 }
 
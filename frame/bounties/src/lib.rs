@@ -123,6 +123,13 @@ pub struct Bounty<AccountId, Balance, BlockNumber> {
 	status: BountyStatus<AccountId, BlockNumber>,
 }
 
+impl<AccountId: Clone, Balance, BlockNumber: Clone> Bounty<AccountId, Balance, BlockNumber> {
+	/// Getter for bounty status, to be used for child bounties.
+	pub fn get_status(&self) -> BountyStatus<AccountId, BlockNumber> {
+		self.status.clone()
+	}
+}
+
 /// The status of a bounty proposal.
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
 pub enum BountyStatus<AccountId, BlockNumber> {
@@ -156,6 +163,25 @@ pub enum BountyStatus<AccountId, BlockNumber> {
 	},
 }
 
+/// Interface for managing the child bounties of a bounty.
+pub trait ChildBountyManager<Balance> {
+	/// Get the number of active child bounties of a parent bounty.
+	fn child_bounties_count(bounty_id: BountyIndex) -> BountyIndex;
+
+	/// Take the total curator fees paid out to the child bounty curators of a parent bounty.
+	fn children_curator_fees(bounty_id: BountyIndex) -> Balance;
+}
+
+impl<Balance: Zero> ChildBountyManager<Balance> for () {
+	fn child_bounties_count(_bounty_id: BountyIndex) -> BountyIndex {
+		Default::default()
+	}
+
+	fn children_curator_fees(_bounty_id: BountyIndex) -> Balance {
+		Zero::zero()
+	}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -200,6 +226,9 @@ pub mod pallet {
 
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
+
+		/// The child bounty manager.
+		type ChildBountyManager: ChildBountyManager<BalanceOf<Self>>;
 	}
 
 	#[pallet::error]
@@ -223,6 +252,8 @@ pub mod pallet {
 		PendingPayout,
 		/// The bounties cannot be claimed/closed because it's still in the countdown period.
 		Premature,
+		/// The bounty cannot be closed because it has active child bounties.
+		HasActiveChildBounty,
 	}
 
 	#[pallet::event]
@@ -510,6 +541,13 @@ pub mod pallet {
 
 			Bounties::<T>::try_mutate_exists(bounty_id, |maybe_bounty| -> DispatchResult {
 				let mut bounty = maybe_bounty.as_mut().ok_or(Error::<T>::InvalidIndex)?;
+
+				// Ensure no active child bounties before processing the call.
+				ensure!(
+					T::ChildBountyManager::child_bounties_count(bounty_id) == 0,
+					Error::<T>::HasActiveChildBounty
+				);
+
 				match &bounty.status {
 					BountyStatus::Active { curator, .. } => {
 						ensure!(signer == *curator, Error::<T>::RequireCurator);
@@ -557,7 +595,11 @@ pub mod pallet {
 					);
 					let bounty_account = Self::bounty_account_id(bounty_id);
 					let balance = T::Currency::free_balance(&bounty_account);
-					let fee = bounty.fee.min(balance); // just to be safe
+					let mut fee = bounty.fee.min(balance); // just to be safe
+											// The fees paid out to the curators of child bounties are taken from the
+											// parent curator's fee.
+					let children_fee = T::ChildBountyManager::children_curator_fees(bounty_id);
+					fee = fee.saturating_sub(children_fee);
 					let payout = balance.saturating_sub(fee);
 					let err_amount = T::Currency::unreserve(&curator, bounty.curator_deposit);
 					debug_assert!(err_amount.is_zero());
@@ -603,6 +645,12 @@ pub mod pallet {
 				|maybe_bounty| -> DispatchResultWithPostInfo {
 					let bounty = maybe_bounty.as_ref().ok_or(Error::<T>::InvalidIndex)?;
 
+					// Child bounties have to be closed before the parent bounty.
+					ensure!(
+						T::ChildBountyManager::child_bounties_count(bounty_id) == 0,
+						Error::<T>::HasActiveChildBounty
+					);
+
 					match &bounty.status {
 						BountyStatus::Proposed => {
 							// The reject origin would like to cancel a proposed bounty.
@@ -110,6 +110,8 @@ parameter_types! {
 	pub const DataDepositPerByte: u64 = 1;
 	pub const TreasuryPalletId: PalletId = PalletId(*b"py/trsry");
 	pub const MaxApprovals: u32 = 100;
+	pub const PayoutPeriod: u64 = 5;
+	pub TreasuryAccount: u128 = Treasury::account_id();
 }
 // impl pallet_treasury::Config for Test {
 impl pallet_treasury::Config for Test {
@@ -127,6 +129,9 @@ impl pallet_treasury::Config for Test {
 	type WeightInfo = ();
 	type SpendFunds = Bounties;
 	type MaxApprovals = MaxApprovals;
+	type SpendOrigin = frame_system::EnsureNever<u64>;
+	type Paymaster = frame_support::traits::tokens::PayFromAccount<Balances, TreasuryAccount, u128>;
+	type PayoutPeriod = PayoutPeriod;
 }
 parameter_types! {
 	pub const BountyDepositBase: u64 = 80;
@@ -146,6 +151,7 @@ impl Config for Test {
 	type DataDepositPerByte = DataDepositPerByte;
 	type MaximumReasonLength = MaximumReasonLength;
 	type WeightInfo = ();
+	type ChildBountyManager = ();
 }
 
 type TreasuryError = pallet_treasury::Error<Test>;
@@ -125,6 +125,11 @@ use frame_support::{
 	},
 	weights::{DispatchClass, DispatchInfo, GetDispatchInfo},
 };
+#[cfg(feature = "try-runtime")]
+use frame_support::{
+	traits::{TryState, TryStateSelect},
+	weights::Weight,
+};
 use frame_system::DigestOf;
 use sp_runtime::{
 	generic::Digest,
@@ -222,9 +227,20 @@ where
 
 	/// Execute given block, but don't do any of the [`final_checks`].
 	///
+	/// Afterwards, the try-state checks of the pallets picked by `select` are executed against the
+	/// resulting state, see [`Self::try_state`].
+	///
+	/// Returns the weight of the block, along with the weight consumed by the try-state checks.
+	///
 	/// Should only be used for testing.
 	#[cfg(feature = "try-runtime")]
-	pub fn execute_block_no_check(block: Block) -> frame_support::weights::Weight {
+	pub fn execute_block_no_check(
+		block: Block,
+		select: TryStateSelect,
+	) -> Result<(Weight, Weight), &'static str>
+	where
+		AllPallets: TryState<System::BlockNumber>,
+	{
 		Self::initialize_block(block.header());
 		Self::initial_checks(&block);
 
@@ -248,7 +264,27 @@ where
 			);
 		}
 
-		frame_system::Pallet::<System>::block_weight().total()
+		let block_weight = frame_system::Pallet::<System>::block_weight().total();
+		let try_state_weight = Self::try_state(select)?;
+
+		Ok((block_weight, try_state_weight))
+	}
+
+	/// Execute the try-state checks of `frame_system` and of the pallets in `AllPallets` that are
+	/// picked by `select`, against the current state.
+	///
+	/// Returns the weight consumed by the checks.
+	///
+	/// This should only be used for testing.
+	#[cfg(feature = "try-runtime")]
+	pub fn try_state(select: TryStateSelect) -> Result<Weight, &'static str>
+	where
+		AllPallets: TryState<System::BlockNumber>,
+	{
+		let n = frame_system::Pallet::<System>::block_number();
+		<(frame_system::Pallet<System>, AllPallets) as TryState<System::BlockNumber>>::try_state(
+			n, select,
+		)
 	}
 
 	/// Execute all `OnRuntimeUpgrade` of this runtime, including the pre and post migration checks.
@@ -578,7 +614,7 @@ mod tests {
 	use frame_support::{
 		assert_err, parameter_types,
 		traits::{Currency, LockIdentifier, LockableCurrency, WithdrawReasons},
-		weights::{IdentityFee, RuntimeDbWeight, Weight, WeightToFeePolynomial},
+		weights::{ConstantMultiplier, IdentityFee, RuntimeDbWeight, Weight, WeightToFee},
 	};
 	use frame_system::{Call as SystemCall, ChainContext, LastRuntimeUpgradeInfo};
 	use hex_literal::hex;
@@ -778,8 +814,8 @@ mod tests {
 	}
 	impl pallet_transaction_payment::Config for Runtime {
 		type OnChargeTransaction = CurrencyAdapter<Balances, ()>;
-		type TransactionByteFee = TransactionByteFee;
 		type WeightToFee = IdentityFee<Balance>;
+		type LengthToFee = ConstantMultiplier<Balance, TransactionByteFee>;
 		type FeeMultiplierUpdate = ();
 	}
 	impl custom::Config for Runtime {}
@@ -1353,7 +1389,8 @@ mod tests {
 					custom_runtime_upgrade_weight +
 					runtime_upgrade_weight +
 					frame_system_on_initialize_weight +
-					on_initialize_weight + base_block_weight,
+					on_initialize_weight +
+					base_block_weight,
 			);
 		});
 	}
@@ -20,14 +20,14 @@
 #![cfg(feature = "runtime-benchmarks")]
 
 use super::*;
-use frame_benchmarking::{benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
 use frame_support::{
 	dispatch::UnfilteredDispatchable,
 	traits::{Currency, EnsureOrigin, Get},
 };
 use frame_system::RawOrigin;
 use sp_arithmetic::Perquintill;
-use sp_runtime::traits::{Bounded, Zero};
+use sp_runtime::traits::{Bounded, StaticLookup, Zero};
 use sp_std::prelude::*;
 
 use crate::Pallet as Gilt;
@@ -97,6 +97,18 @@ benchmarks! {
 		assert!(Active::<T>::get(0).is_none());
 	}
 
+	transfer {
+		let caller: T::AccountId = whitelisted_caller();
+		let dest: T::AccountId = account("dest", 0, 0);
+		T::Currency::make_free_balance_be(&caller, T::MinFreeze::get() * BalanceOf::<T>::from(3u32));
+		T::Currency::make_free_balance_be(&dest, T::Currency::minimum_balance());
+		Gilt::<T>::place_bid(RawOrigin::Signed(caller.clone()).into(), T::MinFreeze::get(), 1)?;
+		Gilt::<T>::enlarge(T::MinFreeze::get(), 1);
+	}: _(RawOrigin::Signed(caller.clone()), 0, T::Lookup::unlookup(dest.clone()))
+	verify {
+		assert_eq!(Active::<T>::get(0).map(|g| g.who), Some(dest));
+	}
+
 	pursue_target_noop {
 	}: { Gilt::<T>::pursue_target(0) }
 
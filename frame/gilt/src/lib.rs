@@ -56,6 +56,10 @@
 //! account. This may happen no earlier than queue's period after the point at which the gilt is
 //! issued.
 //!
+//! An issued gilt acts as a receipt for the frozen funds and may be handed over to another account
+//! with `transfer`; the reserved balance moves along with it and the new owner becomes the one
+//! able to thaw it.
+//!
 //! ## Suggested Values
 //!
 //! - `QueueCount`: 300
@@ -79,12 +83,12 @@ pub mod pallet {
 	pub use crate::weights::WeightInfo;
 	use frame_support::{
 		pallet_prelude::*,
-		traits::{Currency, OnUnbalanced, ReservableCurrency},
+		traits::{BalanceStatus, Currency, OnUnbalanced, ReservableCurrency},
 	};
 	use frame_system::pallet_prelude::*;
 	use scale_info::TypeInfo;
 	use sp_arithmetic::{PerThing, Perquintill};
-	use sp_runtime::traits::{Saturating, Zero};
+	use sp_runtime::traits::{Saturating, StaticLookup, Zero};
 	use sp_std::prelude::*;
 
 	type BalanceOf<T> =
@@ -285,6 +289,9 @@ pub mod pallet {
 		/// An expired gilt has been thawed.
 		/// \[ index, who, original_amount, additional_amount \]
 		GiltThawed(ActiveIndex, T::AccountId, BalanceOf<T>, BalanceOf<T>),
+		/// An active gilt has been transferred to a new owner.
+		/// \[ index, from, to \]
+		GiltTransferred(ActiveIndex, T::AccountId, T::AccountId),
 	}
 
 	#[pallet::error]
@@ -501,6 +508,40 @@ pub mod pallet {
 
 			Ok(().into())
 		}
+
+		/// Transfer an active gilt to another account. The funds reserved under the gilt are moved
+		/// into the reserved balance of `dest`, which then becomes the owner of the gilt.
+		///
+		/// Origin must be Signed and the account must be the owner of the gilt of the given index.
+		///
+		/// - `index`: The index of the gilt to be transferred.
+		/// - `dest`: The account to become the new owner of the gilt. It must already exist.
+		#[pallet::weight(T::WeightInfo::transfer())]
+		pub fn transfer(
+			origin: OriginFor<T>,
+			#[pallet::compact] index: ActiveIndex,
+			dest: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let dest = T::Lookup::lookup(dest)?;
+
+			let mut gilt = Active::<T>::get(index).ok_or(Error::<T>::Unknown)?;
+			ensure!(gilt.who == who, Error::<T>::NotOwner);
+
+			let err_amt = T::Currency::repatriate_reserved(
+				&gilt.who,
+				&dest,
+				gilt.amount,
+				BalanceStatus::Reserved,
+			)?;
+			debug_assert!(err_amt.is_zero());
+
+			gilt.who = dest.clone();
+			Active::<T>::insert(index, gilt);
+
+			Self::deposit_event(Event::GiltTransferred(index, who, dest));
+			Ok(().into())
+		}
 	}
 
 	/// Issuance information returned by `issuance()`.
@@ -320,6 +320,30 @@ fn basic_thaw_works() {
 	});
 }
 
+#[test]
+fn transfer_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		assert_ok!(Gilt::place_bid(Origin::signed(1), 50, 1));
+		Gilt::enlarge(50, 1);
+
+		assert_noop!(Gilt::transfer(Origin::signed(2), 0, 3), Error::<Test>::NotOwner);
+		assert_noop!(Gilt::transfer(Origin::signed(1), 1, 3), Error::<Test>::Unknown);
+
+		assert_ok!(Gilt::transfer(Origin::signed(1), 0, 2));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::reserved_balance(2), 50);
+		assert_eq!(Active::<Test>::get(0).unwrap().who, 2);
+
+		// Only the new owner may thaw it.
+		run_to_block(4);
+		assert_noop!(Gilt::thaw(Origin::signed(1), 0), Error::<Test>::NotOwner);
+		assert_ok!(Gilt::thaw(Origin::signed(2), 0));
+		assert_eq!(Balances::free_balance(1), 50);
+		assert_eq!(Balances::free_balance(2), 150);
+	});
+}
+
 #[test]
 fn thaw_when_issuance_higher_works() {
 	new_test_ext().execute_with(|| {
@@ -53,6 +53,7 @@ pub trait WeightInfo {
 	fn pursue_target_noop() -> Weight;
 	fn pursue_target_per_item(b: u32, ) -> Weight;
 	fn pursue_target_per_queue(q: u32, ) -> Weight;
+	fn transfer() -> Weight;
 }
 
 /// Weights for pallet_gilt using the Substrate node and recommended hardware.
@@ -126,6 +127,13 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(q as Weight)))
 	}
+	// Storage: Gilt Active (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn transfer() -> Weight {
+		(47_152_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
 }
 
 // For backwards compatibility and tests
@@ -198,4 +206,11 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
 			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(q as Weight)))
 	}
+	// Storage: Gilt Active (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn transfer() -> Weight {
+		(47_152_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
 }
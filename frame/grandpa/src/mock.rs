@@ -225,6 +225,8 @@ impl pallet_offences::Config for Test {
 	type Event = Event;
 	type IdentificationTuple = pallet_session::historical::IdentificationTuple<Self>;
 	type OnOffenceHandler = Staking;
+	type DeferredOffencesBudget = ();
+	type MaxDeferredOffences = ();
 }
 
 parameter_types! {
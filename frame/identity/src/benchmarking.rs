@@ -44,14 +44,16 @@ fn add_registrars<T: Config>(r: u32) -> Result<(), &'static str> {
 			i.into(),
 			10u32.into(),
 		)?;
-		let fields =
-			IdentityFields(
-				IdentityField::Display |
-					IdentityField::Legal | IdentityField::Web |
-					IdentityField::Riot | IdentityField::Email |
-					IdentityField::PgpFingerprint |
-					IdentityField::Image | IdentityField::Twitter,
-			);
+		let fields = IdentityFields(
+			IdentityField::Display |
+				IdentityField::Legal |
+				IdentityField::Web |
+				IdentityField::Riot |
+				IdentityField::Email |
+				IdentityField::PgpFingerprint |
+				IdentityField::Image |
+				IdentityField::Twitter,
+		);
 		Identity::<T>::set_fields(RawOrigin::Signed(registrar.clone()).into(), i.into(), fields)?;
 	}
 
@@ -116,6 +118,36 @@ fn create_identity_info<T: Config>(num_fields: u32) -> IdentityInfo<T::MaxAdditi
 	return info
 }
 
+// Adds `authority` as a username authority with the longest allowed suffix.
+fn add_authority<T: Config>(authority: &T::AccountId) -> Result<Vec<u8>, &'static str> {
+	let suffix = vec![b'a'; T::MaxSuffixLength::get() as usize];
+	Identity::<T>::add_username_authority(
+		RawOrigin::Root.into(),
+		T::Lookup::unlookup(authority.clone()),
+		suffix.clone(),
+		10,
+	)?;
+	Ok(suffix)
+}
+
+// Grants a pending username of the longest allowed length to `who`, returning the full username.
+fn queue_username<T: Config>(who: &T::AccountId) -> Result<Username<T>, &'static str> {
+	let authority: T::AccountId = account("authority", 0, SEED);
+	let suffix = add_authority::<T>(&authority)?;
+	let name_len = T::MaxUsernameLength::get() as usize - suffix.len() - 1;
+	let name = vec![b'a'; name_len];
+	Identity::<T>::set_username_for(
+		RawOrigin::Signed(authority).into(),
+		T::Lookup::unlookup(who.clone()),
+		name.clone(),
+		None,
+	)?;
+	let mut full = name;
+	full.push(b'.');
+	full.extend_from_slice(&suffix);
+	full.try_into().map_err(|_| "username too long")
+}
+
 benchmarks! {
 	add_registrar {
 		let r in 1 .. T::MaxRegistrars::get() - 1 => add_registrars::<T>(r)?;
@@ -411,6 +443,60 @@ benchmarks! {
 		ensure!(!SuperOf::<T>::contains_key(&caller), "Sub not removed");
 	}
 
+	add_username_authority {
+		let authority: T::AccountId = account("authority", 0, SEED);
+		let suffix = vec![b'a'; T::MaxSuffixLength::get() as usize];
+	}: _(RawOrigin::Root, T::Lookup::unlookup(authority.clone()), suffix, 10)
+	verify {
+		ensure!(UsernameAuthorities::<T>::contains_key(&authority), "Authority not added");
+	}
+
+	remove_username_authority {
+		let authority: T::AccountId = account("authority", 0, SEED);
+		add_authority::<T>(&authority)?;
+	}: _(RawOrigin::Root, T::Lookup::unlookup(authority.clone()))
+	verify {
+		ensure!(!UsernameAuthorities::<T>::contains_key(&authority), "Authority not removed");
+	}
+
+	set_username_for {
+		let authority: T::AccountId = whitelisted_caller();
+		let suffix = add_authority::<T>(&authority)?;
+		let who: T::AccountId = account("target", 0, SEED);
+		let name = vec![b'a'; T::MaxUsernameLength::get() as usize - suffix.len() - 1];
+	}: _(RawOrigin::Signed(authority.clone()), T::Lookup::unlookup(who), name, None)
+	verify {
+		ensure!(UsernameAuthorities::<T>::get(&authority).unwrap().allocation == 9, "Username not set");
+	}
+
+	accept_username {
+		let caller: T::AccountId = whitelisted_caller();
+		let username = queue_username::<T>(&caller)?;
+	}: _(RawOrigin::Signed(caller.clone()), username.clone())
+	verify {
+		ensure!(AccountOfUsername::<T>::get(&username) == Some(caller), "Username not accepted");
+	}
+
+	remove_expired_approval {
+		let caller: T::AccountId = whitelisted_caller();
+		let who: T::AccountId = account("target", 0, SEED);
+		let username = queue_username::<T>(&who)?;
+		let expiration = frame_system::Pallet::<T>::block_number() + T::PendingUsernameExpiration::get();
+		frame_system::Pallet::<T>::set_block_number(expiration + 1u32.into());
+	}: _(RawOrigin::Signed(caller), username.clone())
+	verify {
+		ensure!(!PendingUsernames::<T>::contains_key(&username), "Approval not removed");
+	}
+
+	set_primary_username {
+		let caller: T::AccountId = whitelisted_caller();
+		let username = queue_username::<T>(&caller)?;
+		Identity::<T>::accept_username(RawOrigin::Signed(caller.clone()).into(), username.clone())?;
+		UsernameOf::<T>::remove(&caller);
+	}: _(RawOrigin::Signed(caller.clone()), username.clone())
+	verify {
+		ensure!(UsernameOf::<T>::get(&caller) == Some(username), "Primary username not set");
+	}
 }
 
 impl_benchmark_test_suite!(Identity, crate::tests::new_test_ext(), crate::tests::Test);
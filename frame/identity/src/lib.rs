@@ -40,6 +40,16 @@
 //! The number of registrars should be limited, and the deposit made sufficiently large, to ensure
 //! no state-bloat attack is viable.
 //!
+//! ### Usernames
+//!
+//! Independently of identities, an account may hold usernames. Usernames are granted by username
+//! authorities, which are added by the `UsernameAuthorityOrigin` together with a suffix and an
+//! allocation of usernames they may grant. A username is made of the requested name and the
+//! authority's suffix, e.g. `alice.wallet`. The account either pre-approves the username by
+//! signing it, in which case the grant takes effect immediately, or accepts it on-chain within
+//! `PendingUsernameExpiration` blocks. No deposit is required. An account may hold several
+//! usernames, one of which is its primary username.
+//!
 //! ## Interface
 //!
 //! ### Dispatchable Functions
@@ -50,6 +60,9 @@
 //! * `clear_identity` - Remove an account's associated identity; the deposit is returned.
 //! * `request_judgement` - Request a judgement from a registrar, paying a fee.
 //! * `cancel_request` - Cancel the previous request for a judgement.
+//! * `accept_username` - Accept a username that an authority granted to the caller.
+//! * `remove_expired_approval` - Remove a username grant that was never accepted.
+//! * `set_primary_username` - Set one of the caller's usernames as its primary username.
 //!
 //! #### For general users with sub-identities
 //! * `set_subs` - Set the sub-accounts of an identity.
@@ -63,9 +76,15 @@
 //! * `set_fields` - Set the fields that a registrar cares about in their judgements.
 //! * `provide_judgement` - Provide a judgement to an identity.
 //!
+//! #### For username authorities
+//! * `set_username_for` - Grant a username to an account, either directly with a signature of the
+//!   account or pending its acceptance.
+//!
 //! #### For super-users
 //! * `add_registrar` - Add a new registrar to the system.
 //! * `kill_identity` - Forcibly remove the associated identity; the deposit is lost.
+//! * `add_username_authority` - Add an account with the ability to grant usernames.
+//! * `remove_username_authority` - Remove an authority's ability to grant usernames.
 //!
 //! [`Call`]: ./enum.Call.html
 //! [`Config`]: ./trait.Config.html
@@ -78,15 +97,23 @@ mod tests;
 mod types;
 pub mod weights;
 
-use frame_support::traits::{BalanceStatus, Currency, OnUnbalanced, ReservableCurrency};
-use sp_runtime::traits::{AppendZerosInput, Saturating, StaticLookup, Zero};
-use sp_std::{convert::TryInto, prelude::*};
+use frame_support::{
+	traits::{BalanceStatus, Currency, OnUnbalanced, ReservableCurrency},
+	BoundedVec,
+};
+use sp_runtime::traits::{
+	AppendZerosInput, IdentifyAccount, Saturating, StaticLookup, Verify, Zero,
+};
+use sp_std::{
+	convert::{TryFrom, TryInto},
+	prelude::*,
+};
 pub use weights::WeightInfo;
 
 pub use pallet::*;
 pub use types::{
-	Data, IdentityField, IdentityFields, IdentityInfo, Judgement, RegistrarIndex, RegistrarInfo,
-	Registration,
+	AuthorityProperties, Data, IdentityField, IdentityFields, IdentityInfo, Judgement,
+	RegistrarIndex, RegistrarInfo, Registration,
 };
 
 type BalanceOf<T> =
@@ -94,6 +121,9 @@ type BalanceOf<T> =
 type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
 	<T as frame_system::Config>::AccountId,
 >>::NegativeImbalance;
+type Suffix<T> = BoundedVec<u8, <T as Config>::MaxSuffixLength>;
+/// A full username, including the suffix of the authority that granted it.
+pub type Username<T> = BoundedVec<u8, <T as Config>::MaxUsernameLength>;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -146,6 +176,27 @@ pub mod pallet {
 		/// The origin which may add or remove registrars. Root can always do this.
 		type RegistrarOrigin: EnsureOrigin<Self::Origin>;
 
+		/// Signature type with which an account pre-approves a username off-chain.
+		type OffchainSignature: Verify<Signer = Self::SigningPublicKey> + Parameter;
+
+		/// Public key corresponding to an on-chain `Self::AccountId`.
+		type SigningPublicKey: IdentifyAccount<AccountId = Self::AccountId>;
+
+		/// The origin which may add or remove username authorities. Root can always do this.
+		type UsernameAuthorityOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The number of blocks within which a granted username must be accepted.
+		#[pallet::constant]
+		type PendingUsernameExpiration: Get<Self::BlockNumber>;
+
+		/// The maximum length of an authority's suffix.
+		#[pallet::constant]
+		type MaxSuffixLength: Get<u32>;
+
+		/// The maximum length of a username, including the suffix and its delimiter.
+		#[pallet::constant]
+		type MaxUsernameLength: Get<u32>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -202,6 +253,33 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// The accounts which may grant usernames, along with their suffix and remaining allocation.
+	#[pallet::storage]
+	#[pallet::getter(fn authority)]
+	pub(super) type UsernameAuthorities<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, AuthorityProperties<Suffix<T>>, OptionQuery>;
+
+	/// Reverse lookup from a username to the account holding it.
+	#[pallet::storage]
+	#[pallet::getter(fn account_of_username)]
+	pub(super) type AccountOfUsername<T: Config> =
+		StorageMap<_, Blake2_128Concat, Username<T>, T::AccountId, OptionQuery>;
+
+	/// The primary username of an account.
+	///
+	/// TWOX-NOTE: OK ― `AccountId` is a secure hash.
+	#[pallet::storage]
+	#[pallet::getter(fn username_of)]
+	pub(super) type UsernameOf<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, Username<T>, OptionQuery>;
+
+	/// Usernames granted by an authority which the receiving account has not accepted yet,
+	/// together with the account and the block number after which the grant expires.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_username)]
+	pub(super) type PendingUsernames<T: Config> =
+		StorageMap<_, Blake2_128Concat, Username<T>, (T::AccountId, T::BlockNumber), OptionQuery>;
+
 	#[pallet::error]
 	pub enum Error<T> {
 		/// Too many subs-accounts.
@@ -236,6 +314,22 @@ pub mod pallet {
 		NotSub,
 		/// Sub-account isn't owned by sender.
 		NotOwned,
+		/// The sender is not a username authority.
+		NotUsernameAuthority,
+		/// The authority has no usernames left to grant.
+		NoAllocation,
+		/// The suffix is empty, too long or contains invalid characters.
+		InvalidSuffix,
+		/// The username is empty, too long or contains invalid characters.
+		InvalidUsername,
+		/// The username is already held or pending acceptance.
+		UsernameTaken,
+		/// The username does not exist.
+		NoUsername,
+		/// The signature does not match the username and account.
+		InvalidSignature,
+		/// The username grant has not expired yet.
+		NotExpired,
 	}
 
 	#[pallet::event]
@@ -263,6 +357,19 @@ pub mod pallet {
 		/// A sub-identity was cleared, and the given deposit repatriated from the
 		/// main identity account to the sub-identity account. \[sub, main, deposit\]
 		SubIdentityRevoked(T::AccountId, T::AccountId, BalanceOf<T>),
+		/// A username authority was added. \[authority\]
+		AuthorityAdded(T::AccountId),
+		/// A username authority was removed. \[authority\]
+		AuthorityRemoved(T::AccountId),
+		/// A username was assigned to an account. \[who, username\]
+		UsernameSet(T::AccountId, Username<T>),
+		/// A username was granted and awaits acceptance by the account.
+		/// \[who, username, expiration\]
+		UsernameQueued(T::AccountId, Username<T>, T::BlockNumber),
+		/// A username grant expired without being accepted. \[who, username\]
+		PreapprovalExpired(T::AccountId, Username<T>),
+		/// A username was set as the primary username of an account. \[who, username\]
+		PrimaryUsernameSet(T::AccountId, Username<T>),
 	}
 
 	#[pallet::call]
@@ -958,6 +1065,159 @@ pub mod pallet {
 			});
 			Ok(())
 		}
+
+		/// Add an `authority` which may grant usernames ending in `.suffix`, up to `allocation`
+		/// of them.
+		///
+		/// The dispatch origin for this call must be `T::UsernameAuthorityOrigin`. Adding an
+		/// existing authority again overwrites its suffix and allocation.
+		///
+		/// - `suffix`: lowercase alphanumeric characters only.
+		///
+		/// Emits `AuthorityAdded` if successful.
+		#[pallet::weight(T::WeightInfo::add_username_authority())]
+		pub fn add_username_authority(
+			origin: OriginFor<T>,
+			authority: <T::Lookup as StaticLookup>::Source,
+			suffix: Vec<u8>,
+			allocation: u32,
+		) -> DispatchResult {
+			T::UsernameAuthorityOrigin::ensure_origin(origin)?;
+			let authority = T::Lookup::lookup(authority)?;
+			ensure!(Self::is_valid_name(&suffix), Error::<T>::InvalidSuffix);
+			let suffix = Suffix::<T>::try_from(suffix).map_err(|_| Error::<T>::InvalidSuffix)?;
+
+			UsernameAuthorities::<T>::insert(
+				&authority,
+				AuthorityProperties { suffix, allocation },
+			);
+
+			Self::deposit_event(Event::AuthorityAdded(authority));
+			Ok(())
+		}
+
+		/// Remove a username `authority`. Usernames it granted remain valid.
+		///
+		/// The dispatch origin for this call must be `T::UsernameAuthorityOrigin`.
+		///
+		/// Emits `AuthorityRemoved` if successful.
+		#[pallet::weight(T::WeightInfo::remove_username_authority())]
+		pub fn remove_username_authority(
+			origin: OriginFor<T>,
+			authority: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			T::UsernameAuthorityOrigin::ensure_origin(origin)?;
+			let authority = T::Lookup::lookup(authority)?;
+			UsernameAuthorities::<T>::take(&authority).ok_or(Error::<T>::NotUsernameAuthority)?;
+
+			Self::deposit_event(Event::AuthorityRemoved(authority));
+			Ok(())
+		}
+
+		/// Grant the username `username.suffix` to `who`, where `suffix` is the one of the
+		/// calling authority.
+		///
+		/// The dispatch origin for this call must be _Signed_ by a username authority with some
+		/// allocation left.
+		///
+		/// - `username`: lowercase alphanumeric characters only, without the suffix.
+		/// - `signature`: the signature of `who` over the full username. If provided and valid, the
+		///   username is assigned right away, otherwise it is queued for `who` to accept it within
+		///   `PendingUsernameExpiration` blocks.
+		///
+		/// Emits `UsernameSet` or `UsernameQueued` if successful.
+		#[pallet::weight(T::WeightInfo::set_username_for())]
+		pub fn set_username_for(
+			origin: OriginFor<T>,
+			who: <T::Lookup as StaticLookup>::Source,
+			username: Vec<u8>,
+			signature: Option<T::OffchainSignature>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let mut authority =
+				UsernameAuthorities::<T>::get(&sender).ok_or(Error::<T>::NotUsernameAuthority)?;
+			ensure!(authority.allocation > 0, Error::<T>::NoAllocation);
+			ensure!(Self::is_valid_name(&username), Error::<T>::InvalidUsername);
+
+			let mut full_username = username;
+			full_username.push(b'.');
+			full_username.extend_from_slice(&authority.suffix);
+			let username =
+				Username::<T>::try_from(full_username).map_err(|_| Error::<T>::InvalidUsername)?;
+			ensure!(
+				!AccountOfUsername::<T>::contains_key(&username) &&
+					!PendingUsernames::<T>::contains_key(&username),
+				Error::<T>::UsernameTaken
+			);
+
+			let who = T::Lookup::lookup(who)?;
+			if let Some(signature) = signature {
+				ensure!(signature.verify(&username[..], &who), Error::<T>::InvalidSignature);
+				Self::insert_username(&who, username);
+			} else {
+				let expiration = frame_system::Pallet::<T>::block_number()
+					.saturating_add(T::PendingUsernameExpiration::get());
+				PendingUsernames::<T>::insert(&username, (who.clone(), expiration));
+				Self::deposit_event(Event::UsernameQueued(who, username, expiration));
+			}
+
+			authority.allocation.saturating_dec();
+			UsernameAuthorities::<T>::insert(&sender, authority);
+			Ok(())
+		}
+
+		/// Accept a username granted to the sender by an authority.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the account the username was
+		/// granted to.
+		///
+		/// Emits `UsernameSet` if successful.
+		#[pallet::weight(T::WeightInfo::accept_username())]
+		pub fn accept_username(origin: OriginFor<T>, username: Username<T>) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let (who, _) = PendingUsernames::<T>::get(&username).ok_or(Error::<T>::NoUsername)?;
+			ensure!(who == sender, Error::<T>::InvalidUsername);
+
+			PendingUsernames::<T>::remove(&username);
+			Self::insert_username(&who, username);
+			Ok(())
+		}
+
+		/// Remove a username grant which expired without being accepted.
+		///
+		/// The dispatch origin for this call must be _Signed_. The call is free if successful.
+		///
+		/// Emits `PreapprovalExpired` if successful.
+		#[pallet::weight(T::WeightInfo::remove_expired_approval())]
+		pub fn remove_expired_approval(
+			origin: OriginFor<T>,
+			username: Username<T>,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let (who, expiration) =
+				PendingUsernames::<T>::get(&username).ok_or(Error::<T>::NoUsername)?;
+			ensure!(frame_system::Pallet::<T>::block_number() > expiration, Error::<T>::NotExpired);
+
+			PendingUsernames::<T>::remove(&username);
+			Self::deposit_event(Event::PreapprovalExpired(who, username));
+			Ok(Pays::No.into())
+		}
+
+		/// Set one of the sender's usernames as its primary username.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the account holding `username`.
+		///
+		/// Emits `PrimaryUsernameSet` if successful.
+		#[pallet::weight(T::WeightInfo::set_primary_username())]
+		pub fn set_primary_username(origin: OriginFor<T>, username: Username<T>) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let who = AccountOfUsername::<T>::get(&username).ok_or(Error::<T>::NoUsername)?;
+			ensure!(who == sender, Error::<T>::InvalidUsername);
+
+			UsernameOf::<T>::insert(&who, &username);
+			Self::deposit_event(Event::PrimaryUsernameSet(who, username));
+			Ok(())
+		}
 	}
 }
 
@@ -970,4 +1230,19 @@ impl<T: Config> Pallet<T> {
 			.filter_map(|a| SuperOf::<T>::get(&a).map(|x| (a, x.1)))
 			.collect()
 	}
+
+	/// Whether `name` is a valid username or suffix: non-empty and made of lowercase
+	/// alphanumeric characters only.
+	fn is_valid_name(name: &[u8]) -> bool {
+		!name.is_empty() && name.iter().all(|c| c.is_ascii_digit() || c.is_ascii_lowercase())
+	}
+
+	/// Assign `username` to `who`, making it the primary username if `who` has none yet.
+	fn insert_username(who: &T::AccountId, username: Username<T>) {
+		AccountOfUsername::<T>::insert(&username, who);
+		if !UsernameOf::<T>::contains_key(who) {
+			UsernameOf::<T>::insert(who, &username);
+		}
+		Self::deposit_event(Event::UsernameSet(who.clone(), username));
+	}
 }
@@ -21,11 +21,13 @@ use super::*;
 use crate as pallet_identity;
 
 use codec::{Decode, Encode};
-use frame_support::{assert_noop, assert_ok, ord_parameter_types, parameter_types, BoundedVec};
+use frame_support::{
+	assert_noop, assert_ok, ord_parameter_types, parameter_types, weights::Pays, BoundedVec,
+};
 use frame_system::{EnsureOneOf, EnsureRoot, EnsureSignedBy};
 use sp_core::H256;
 use sp_runtime::{
-	testing::Header,
+	testing::{Header, TestSignature, UintAuthorityId},
 	traits::{BadOrigin, BlakeTwo256, IdentityLookup},
 };
 
@@ -95,6 +97,9 @@ parameter_types! {
 	pub const MaxSubAccounts: u32 = 2;
 	pub const MaxAdditionalFields: u32 = 2;
 	pub const MaxRegistrars: u32 = 20;
+	pub const PendingUsernameExpiration: u64 = 100;
+	pub const MaxSuffixLength: u32 = 7;
+	pub const MaxUsernameLength: u32 = 32;
 }
 ord_parameter_types! {
 	pub const One: u64 = 1;
@@ -114,6 +119,12 @@ impl pallet_identity::Config for Test {
 	type MaxRegistrars = MaxRegistrars;
 	type RegistrarOrigin = EnsureOneOrRoot;
 	type ForceOrigin = EnsureTwoOrRoot;
+	type OffchainSignature = TestSignature;
+	type SigningPublicKey = UintAuthorityId;
+	type UsernameAuthorityOrigin = EnsureOneOrRoot;
+	type PendingUsernameExpiration = PendingUsernameExpiration;
+	type MaxSuffixLength = MaxSuffixLength;
+	type MaxUsernameLength = MaxUsernameLength;
 	type WeightInfo = ();
 }
 
@@ -500,3 +511,183 @@ fn setting_account_id_should_work() {
 		assert_ok!(Identity::set_account_id(Origin::signed(4), 0, 3));
 	});
 }
+
+fn username(name: &[u8]) -> Username<Test> {
+	name.to_vec().try_into().unwrap()
+}
+
+#[test]
+fn adding_and_removing_username_authorities_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Identity::add_username_authority(Origin::signed(2), 10, b"wallet".to_vec(), 5),
+			BadOrigin
+		);
+		assert_noop!(
+			Identity::add_username_authority(Origin::signed(1), 10, b"Wallet".to_vec(), 5),
+			Error::<Test>::InvalidSuffix
+		);
+		assert_noop!(
+			Identity::add_username_authority(Origin::signed(1), 10, b"longwallet".to_vec(), 5),
+			Error::<Test>::InvalidSuffix
+		);
+		assert_ok!(Identity::add_username_authority(Origin::signed(1), 10, b"wallet".to_vec(), 5));
+		assert_eq!(
+			Identity::authority(10),
+			Some(AuthorityProperties {
+				suffix: b"wallet".to_vec().try_into().unwrap(),
+				allocation: 5
+			})
+		);
+
+		assert_ok!(Identity::remove_username_authority(Origin::root(), 10));
+		assert_eq!(Identity::authority(10), None);
+		assert_noop!(
+			Identity::remove_username_authority(Origin::root(), 10),
+			Error::<Test>::NotUsernameAuthority
+		);
+	});
+}
+
+#[test]
+fn setting_username_with_signature_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Identity::add_username_authority(Origin::signed(1), 10, b"wallet".to_vec(), 1));
+		let name = username(b"alice.wallet");
+
+		assert_noop!(
+			Identity::set_username_for(
+				Origin::signed(10),
+				20,
+				b"alice".to_vec(),
+				Some(TestSignature(30, name.to_vec()))
+			),
+			Error::<Test>::InvalidSignature
+		);
+		assert_ok!(Identity::set_username_for(
+			Origin::signed(10),
+			20,
+			b"alice".to_vec(),
+			Some(TestSignature(20, name.to_vec()))
+		));
+		assert_eq!(Identity::account_of_username(&name), Some(20));
+		assert_eq!(Identity::username_of(20), Some(name));
+		assert_eq!(Identity::authority(10).unwrap().allocation, 0);
+
+		assert_noop!(
+			Identity::set_username_for(Origin::signed(10), 30, b"bob".to_vec(), None),
+			Error::<Test>::NoAllocation
+		);
+		assert_noop!(
+			Identity::set_username_for(Origin::signed(20), 30, b"bob".to_vec(), None),
+			Error::<Test>::NotUsernameAuthority
+		);
+	});
+}
+
+#[test]
+fn setting_invalid_or_taken_username_should_fail() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Identity::add_username_authority(Origin::signed(1), 10, b"wallet".to_vec(), 5));
+		assert_noop!(
+			Identity::set_username_for(Origin::signed(10), 20, vec![], None),
+			Error::<Test>::InvalidUsername
+		);
+		assert_noop!(
+			Identity::set_username_for(Origin::signed(10), 20, b"Alice".to_vec(), None),
+			Error::<Test>::InvalidUsername
+		);
+		assert_noop!(
+			Identity::set_username_for(Origin::signed(10), 20, b"alice.bob".to_vec(), None),
+			Error::<Test>::InvalidUsername
+		);
+		assert_noop!(
+			Identity::set_username_for(Origin::signed(10), 20, vec![b'a'; 26], None),
+			Error::<Test>::InvalidUsername
+		);
+
+		assert_ok!(Identity::set_username_for(Origin::signed(10), 20, b"alice".to_vec(), None));
+		assert_noop!(
+			Identity::set_username_for(Origin::signed(10), 30, b"alice".to_vec(), None),
+			Error::<Test>::UsernameTaken
+		);
+		assert_eq!(Identity::authority(10).unwrap().allocation, 4);
+	});
+}
+
+#[test]
+fn accepting_username_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Identity::add_username_authority(Origin::signed(1), 10, b"wallet".to_vec(), 5));
+		assert_ok!(Identity::set_username_for(Origin::signed(10), 20, b"alice".to_vec(), None));
+		let name = username(b"alice.wallet");
+		assert_eq!(Identity::pending_username(&name), Some((20, 100)));
+		assert_eq!(Identity::account_of_username(&name), None);
+
+		assert_noop!(
+			Identity::accept_username(Origin::signed(30), name.clone()),
+			Error::<Test>::InvalidUsername
+		);
+		assert_noop!(
+			Identity::accept_username(Origin::signed(20), username(b"bob.wallet")),
+			Error::<Test>::NoUsername
+		);
+		assert_ok!(Identity::accept_username(Origin::signed(20), name.clone()));
+		assert_eq!(Identity::pending_username(&name), None);
+		assert_eq!(Identity::account_of_username(&name), Some(20));
+		assert_eq!(Identity::username_of(20), Some(name));
+	});
+}
+
+#[test]
+fn removing_expired_approval_should_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Identity::add_username_authority(Origin::signed(1), 10, b"wallet".to_vec(), 5));
+		assert_ok!(Identity::set_username_for(Origin::signed(10), 20, b"alice".to_vec(), None));
+		let name = username(b"alice.wallet");
+
+		System::set_block_number(101);
+		assert_noop!(
+			Identity::remove_expired_approval(Origin::signed(30), name.clone()),
+			Error::<Test>::NotExpired
+		);
+		System::set_block_number(102);
+		let info = Identity::remove_expired_approval(Origin::signed(30), name.clone()).unwrap();
+		assert_eq!(info.pays_fee, Pays::No);
+		assert_eq!(Identity::pending_username(&name), None);
+		assert_noop!(
+			Identity::accept_username(Origin::signed(20), name),
+			Error::<Test>::NoUsername
+		);
+	});
+}
+
+#[test]
+fn setting_primary_username_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Identity::add_username_authority(Origin::signed(1), 10, b"wallet".to_vec(), 5));
+		let alice = username(b"alice.wallet");
+		let bob = username(b"bob.wallet");
+		assert_ok!(Identity::set_username_for(
+			Origin::signed(10),
+			20,
+			b"alice".to_vec(),
+			Some(TestSignature(20, alice.to_vec()))
+		));
+		assert_ok!(Identity::set_username_for(
+			Origin::signed(10),
+			20,
+			b"bob".to_vec(),
+			Some(TestSignature(20, bob.to_vec()))
+		));
+		assert_eq!(Identity::username_of(20), Some(alice));
+
+		assert_noop!(
+			Identity::set_primary_username(Origin::signed(30), bob.clone()),
+			Error::<Test>::InvalidUsername
+		);
+		assert_ok!(Identity::set_primary_username(Origin::signed(20), bob.clone()));
+		assert_eq!(Identity::username_of(20), Some(bob));
+	});
+}
@@ -471,3 +471,13 @@ mod tests {
 		}
 	}
 }
+
+/// Information on a username authority.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct AuthorityProperties<Suffix> {
+	/// The suffix appended to usernames granted by this authority. For example, a suffix of
+	/// `wallet` results in usernames of the form `name.wallet`.
+	pub suffix: Suffix,
+	/// The number of usernames that this authority can still grant.
+	pub allocation: u32,
+}
@@ -61,6 +61,12 @@ pub trait WeightInfo {
 	fn rename_sub(s: u32, ) -> Weight;
 	fn remove_sub(s: u32, ) -> Weight;
 	fn quit_sub(s: u32, ) -> Weight;
+	fn add_username_authority() -> Weight;
+	fn remove_username_authority() -> Weight;
+	fn set_username_for() -> Weight;
+	fn accept_username() -> Weight;
+	fn remove_expired_approval() -> Weight;
+	fn set_primary_username() -> Weight;
 }
 
 /// Weights for pallet_identity using the Substrate node and recommended hardware.
@@ -230,6 +236,47 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2 as Weight))
 			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 	}
+	// Storage: Identity UsernameAuthorities (r:0 w:1)
+	fn add_username_authority() -> Weight {
+		(13_873_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Identity UsernameAuthorities (r:1 w:1)
+	fn remove_username_authority() -> Weight {
+		(10_653_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Identity UsernameAuthorities (r:1 w:1)
+	// Storage: Identity AccountOfUsername (r:1 w:1)
+	// Storage: Identity PendingUsernames (r:1 w:0)
+	// Storage: Identity UsernameOf (r:1 w:1)
+	fn set_username_for() -> Weight {
+		(75_928_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Identity PendingUsernames (r:1 w:1)
+	// Storage: Identity UsernameOf (r:1 w:1)
+	// Storage: Identity AccountOfUsername (r:0 w:1)
+	fn accept_username() -> Weight {
+		(27_341_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Identity PendingUsernames (r:1 w:1)
+	fn remove_expired_approval() -> Weight {
+		(15_825_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Identity AccountOfUsername (r:1 w:0)
+	// Storage: Identity UsernameOf (r:0 w:1)
+	fn set_primary_username() -> Weight {
+		(20_159_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
 }
 
 // For backwards compatibility and tests
@@ -398,4 +445,45 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
 	}
+	// Storage: Identity UsernameAuthorities (r:0 w:1)
+	fn add_username_authority() -> Weight {
+		(13_873_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Identity UsernameAuthorities (r:1 w:1)
+	fn remove_username_authority() -> Weight {
+		(10_653_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Identity UsernameAuthorities (r:1 w:1)
+	// Storage: Identity AccountOfUsername (r:1 w:1)
+	// Storage: Identity PendingUsernames (r:1 w:0)
+	// Storage: Identity UsernameOf (r:1 w:1)
+	fn set_username_for() -> Weight {
+		(75_928_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Identity PendingUsernames (r:1 w:1)
+	// Storage: Identity UsernameOf (r:1 w:1)
+	// Storage: Identity AccountOfUsername (r:0 w:1)
+	fn accept_username() -> Weight {
+		(27_341_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Identity PendingUsernames (r:1 w:1)
+	fn remove_expired_approval() -> Weight {
+		(15_825_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Identity AccountOfUsername (r:1 w:0)
+	// Storage: Identity UsernameOf (r:0 w:1)
+	fn set_primary_username() -> Weight {
+		(20_159_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
 }
@@ -97,7 +97,7 @@ use sp_staking::{
 	offence::{Kind, Offence, ReportOffence},
 	SessionIndex,
 };
-use sp_std::{convert::TryInto, prelude::*};
+use sp_std::{convert::TryInto, marker::PhantomData, prelude::*};
 pub use weights::WeightInfo;
 
 pub mod sr25519 {
@@ -354,11 +354,20 @@ pub mod pallet {
 		/// chance the authority will produce a block and they won't be necessary.
 		type NextSessionRotation: EstimateNextSessionRotation<Self::BlockNumber>;
 
+		/// The number of consecutive sessions in which a validator may neither send a heartbeat
+		/// nor author a block before it is reported as offline. A value of `0` is treated as `1`.
+		#[pallet::constant]
+		type OfflineThreshold: Get<u32>;
+
+		/// The curve determining the fraction of the exposure of offline validators which is
+		/// slashed.
+		type SlashCurve: UnresponsivenessSlashCurve;
+
 		/// A type that gives us the ability to submit unresponsiveness offence reports.
 		type ReportUnresponsiveness: ReportOffence<
 			Self::AccountId,
 			IdentificationTuple<Self>,
-			UnresponsivenessOffence<IdentificationTuple<Self>>,
+			UnresponsivenessOffence<IdentificationTuple<Self>, Self::SlashCurve>,
 		>;
 
 		/// A configuration for base priority of unsigned transactions.
@@ -445,6 +454,13 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// The last session that a validator was found unresponsive in, along with the number of
+	/// consecutive sessions up to and including that one it was found unresponsive in.
+	#[pallet::storage]
+	#[pallet::getter(fn missed_sessions)]
+	pub(crate) type MissedSessions<T: Config> =
+		StorageMap<_, Twox64Concat, ValidatorId<T>, (SessionIndex, u32)>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub keys: Vec<T::AuthorityId>,
@@ -766,7 +782,7 @@ impl<T: Config> Pallet<T> {
 		//
 		// At index `idx`:
 		// 1. A (ImOnline) public key to be used by a validator at index `idx` to send im-online
-		//          heartbeats.
+		//    heartbeats.
 		let authorities = Keys::<T>::get();
 
 		// local keystore
@@ -889,16 +905,30 @@ impl<T: Config> OneSessionHandler<T::AccountId> for Pallet<T> {
 		let keys = Keys::<T>::get();
 		let current_validators = T::ValidatorSet::validators();
 
-		let offenders = current_validators
-			.into_iter()
-			.enumerate()
-			.filter(|(index, id)| !Self::is_online_aux(*index as u32, id))
-			.filter_map(|(_, id)| {
-				<T::ValidatorSet as ValidatorSetWithIdentification<T::AccountId>>::IdentificationOf::convert(
-					id.clone()
-				).map(|full_id| (id, full_id))
-			})
-			.collect::<Vec<IdentificationTuple<T>>>();
+		let threshold = T::OfflineThreshold::get().max(1);
+		let mut offenders = Vec::<IdentificationTuple<T>>::new();
+		for (index, id) in current_validators.into_iter().enumerate() {
+			if Self::is_online_aux(index as u32, &id) {
+				MissedSessions::<T>::remove(&id);
+				continue
+			}
+			// The sessions missed before don't count if the validator was online, or not a
+			// validator at all, in the previous session.
+			let missed = match MissedSessions::<T>::get(&id) {
+				Some((last, missed)) if last.saturating_add(1) == session_index =>
+					missed.saturating_add(1),
+				_ => 1,
+			};
+			MissedSessions::<T>::insert(&id, (session_index, missed));
+			if missed >= threshold {
+				if let Some(full_id) = <T::ValidatorSet as ValidatorSetWithIdentification<
+					T::AccountId,
+				>>::IdentificationOf::convert(id.clone())
+				{
+					offenders.push((id, full_id));
+				}
+			}
+		}
 
 		// Remove all received heartbeats and number of authored blocks from the
 		// current session, they have already been processed and won't be needed
@@ -912,7 +942,8 @@ impl<T: Config> OneSessionHandler<T::AccountId> for Pallet<T> {
 			Self::deposit_event(Event::<T>::SomeOffline(offenders.clone()));
 
 			let validator_set_count = keys.len() as u32;
-			let offence = UnresponsivenessOffence { session_index, validator_set_count, offenders };
+			let offence =
+				UnresponsivenessOffence::new(session_index, validator_set_count, offenders);
 			if let Err(e) = T::ReportUnresponsiveness::report_offence(vec![], offence) {
 				sp_runtime::print(e);
 			}
@@ -924,10 +955,38 @@ impl<T: Config> OneSessionHandler<T::AccountId> for Pallet<T> {
 	}
 }
 
+/// A function of the number of offline validators and of the size of the validator set, giving
+/// the fraction of the exposure of each offline validator which is slashed.
+pub trait UnresponsivenessSlashCurve {
+	/// The fraction to slash when `offenders` out of `validator_set_count` validators are offline.
+	fn slash_fraction(offenders: u32, validator_set_count: u32) -> Perbill;
+}
+
+/// The default slash curve: 10% of the validators can be offline with no slash, but after that,
+/// it linearly climbs up to 7% when 13/30 are offline (around 5% when 1/3 are offline).
+#[derive(RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Clone, PartialEq, Eq))]
+pub struct DefaultSlashCurve;
+
+impl UnresponsivenessSlashCurve for DefaultSlashCurve {
+	fn slash_fraction(offenders: u32, validator_set_count: u32) -> Perbill {
+		// the formula is min((3 * (k - (n / 10 + 1))) / n, 1) * 0.07
+		if let Some(threshold) = offenders.checked_sub(validator_set_count / 10 + 1) {
+			let x = Perbill::from_rational(3 * threshold, validator_set_count);
+			x.saturating_mul(Perbill::from_percent(7))
+		} else {
+			Perbill::default()
+		}
+	}
+}
+
 /// An offence that is filed if a validator didn't send a heartbeat message.
+///
+/// The slash applied for it follows the curve `C`.
 #[derive(RuntimeDebug, TypeInfo)]
 #[cfg_attr(feature = "std", derive(Clone, PartialEq, Eq))]
-pub struct UnresponsivenessOffence<Offender> {
+#[scale_info(skip_type_params(C))]
+pub struct UnresponsivenessOffence<Offender, C = DefaultSlashCurve> {
 	/// The current session index in which we report the unresponsive validators.
 	///
 	/// It acts as a time measure for unresponsiveness reports and effectively will always point
@@ -937,9 +996,24 @@ pub struct UnresponsivenessOffence<Offender> {
 	pub validator_set_count: u32,
 	/// Authorities that were unresponsive during the current era.
 	pub offenders: Vec<Offender>,
+	/// The slash curve of the offence.
+	slash_curve: PhantomData<C>,
 }
 
-impl<Offender: Clone> Offence<Offender> for UnresponsivenessOffence<Offender> {
+impl<Offender, C> UnresponsivenessOffence<Offender, C> {
+	/// Create a new offence of `offenders` in the given session.
+	pub fn new(
+		session_index: SessionIndex,
+		validator_set_count: u32,
+		offenders: Vec<Offender>,
+	) -> Self {
+		Self { session_index, validator_set_count, offenders, slash_curve: PhantomData }
+	}
+}
+
+impl<Offender: Clone, C: UnresponsivenessSlashCurve> Offence<Offender>
+	for UnresponsivenessOffence<Offender, C>
+{
 	const ID: Kind = *b"im-online:offlin";
 	type TimeSlot = SessionIndex;
 
@@ -960,14 +1034,6 @@ impl<Offender: Clone> Offence<Offender> for UnresponsivenessOffence<Offender> {
 	}
 
 	fn slash_fraction(offenders: u32, validator_set_count: u32) -> Perbill {
-		// the formula is min((3 * (k - (n / 10 + 1))) / n, 1) * 0.07
-		// basically, 10% can be offline with no slash, but after that, it linearly climbs up to 7%
-		// when 13/30 are offline (around 5% when 1/3 are offline).
-		if let Some(threshold) = offenders.checked_sub(validator_set_count / 10 + 1) {
-			let x = Perbill::from_rational(3 * threshold, validator_set_count);
-			x.saturating_mul(Perbill::from_percent(7))
-		} else {
-			Perbill::default()
-		}
+		C::slash_fraction(offenders, validator_set_count)
 	}
 }
@@ -220,6 +220,7 @@ parameter_types! {
 	pub const MaxKeys: u32 = 10_000;
 	pub const MaxPeerInHeartbeats: u32 = 10_000;
 	pub const MaxPeerDataEncodingSize: u32 = 1_000;
+	pub static OfflineThreshold: u32 = 1;
 }
 
 impl Config for Runtime {
@@ -227,6 +228,8 @@ impl Config for Runtime {
 	type Event = Event;
 	type ValidatorSet = Historical;
 	type NextSessionRotation = TestNextSessionRotation;
+	type OfflineThreshold = OfflineThreshold;
+	type SlashCurve = crate::DefaultSlashCurve;
 	type ReportUnresponsiveness = OffenceHandler;
 	type UnsignedPriority = UnsignedPriority;
 	type WeightInfo = ();
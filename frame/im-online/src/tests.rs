@@ -77,14 +77,7 @@ fn should_report_offline_validators() {
 		let offences = OFFENCES.with(|l| l.replace(vec![]));
 		assert_eq!(
 			offences,
-			vec![(
-				vec![],
-				UnresponsivenessOffence {
-					session_index: 2,
-					validator_set_count: 3,
-					offenders: vec![(1, 1), (2, 2), (3, 3),],
-				}
-			)]
+			vec![(vec![], UnresponsivenessOffence::new(2, 3, vec![(1, 1), (2, 2), (3, 3)]))]
 		);
 
 		// should not report when heartbeat is sent
@@ -97,18 +90,51 @@ fn should_report_offline_validators() {
 		let offences = OFFENCES.with(|l| l.replace(vec![]));
 		assert_eq!(
 			offences,
-			vec![(
-				vec![],
-				UnresponsivenessOffence {
-					session_index: 3,
-					validator_set_count: 6,
-					offenders: vec![(5, 5), (6, 6),],
-				}
-			)]
+			vec![(vec![], UnresponsivenessOffence::new(3, 6, vec![(5, 5), (6, 6)]))]
 		);
 	});
 }
 
+#[test]
+fn should_only_report_validators_offline_for_threshold_sessions() {
+	new_test_ext().execute_with(|| {
+		// given
+		OfflineThreshold::set(2);
+		let block = 1;
+		System::set_block_number(block);
+		advance_session();
+		let validators = vec![1, 2, 3, 4, 5, 6];
+		VALIDATORS.with(|l| *l.borrow_mut() = Some(validators.clone()));
+		advance_session();
+
+		// when
+		advance_session();
+
+		// then
+		assert_eq!(OFFENCES.with(|l| l.replace(vec![])), vec![]);
+		assert_eq!(ImOnline::missed_sessions(1), Some((2, 1)));
+
+		// the counter of validators sending a heartbeat is reset
+		for (idx, v) in validators.into_iter().take(4).enumerate() {
+			let _ = heartbeat(block, 3, idx as u32, v.into(), Session::validators()).unwrap();
+		}
+		advance_session();
+		assert_eq!(OFFENCES.with(|l| l.replace(vec![])), vec![]);
+		assert_eq!(ImOnline::missed_sessions(1), None);
+		assert_eq!(ImOnline::missed_sessions(5), Some((3, 1)));
+
+		// only the validators offline for two sessions in a row are reported
+		advance_session();
+		let offences = OFFENCES.with(|l| l.replace(vec![]));
+		assert_eq!(
+			offences,
+			vec![(vec![], UnresponsivenessOffence::new(4, 6, vec![(5, 5), (6, 6)]))]
+		);
+		assert_eq!(ImOnline::missed_sessions(1), Some((4, 1)));
+		assert_eq!(ImOnline::missed_sessions(5), Some((4, 2)));
+	});
+}
+
 fn heartbeat(
 	block_number: u64,
 	session_index: u32,
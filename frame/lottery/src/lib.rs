@@ -44,6 +44,11 @@
 //! `MaxGenerateRandom` configuration can help mitigate this by generating new
 //! numbers until we hit the limit or we find a "fair" number. This is best
 //! effort only.
+//!
+//! Besides ticket purchases, the pot can be funded with any negative imbalance,
+//! since the pallet implements `OnUnbalanced`. A runtime can for example route a
+//! configurable portion of its transaction fees into the pot by rationing the
+//! imbalance in its fee handler and passing one share to this pallet.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -58,7 +63,10 @@ use codec::{Decode, Encode};
 use frame_support::{
 	dispatch::{DispatchResult, Dispatchable, GetDispatchInfo},
 	ensure,
-	traits::{Currency, ExistenceRequirement::KeepAlive, Get, Randomness, ReservableCurrency},
+	traits::{
+		Currency, ExistenceRequirement::KeepAlive, Get, Imbalance, OnUnbalanced, Randomness,
+		ReservableCurrency,
+	},
 	PalletId, RuntimeDebug,
 };
 pub use pallet::*;
@@ -71,6 +79,9 @@ pub use weights::WeightInfo;
 
 type BalanceOf<T> =
 	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
+	<T as frame_system::Config>::AccountId,
+>>::NegativeImbalance;
 
 // Any runtime call can be encoded into two bytes which represent the pallet and call index.
 // We use this to uniquely match someone's incoming call with the calls configured for the lottery.
@@ -179,6 +190,8 @@ pub mod pallet {
 		Winner(T::AccountId, BalanceOf<T>),
 		/// A ticket has been bought!
 		TicketBought(T::AccountId, CallIndex),
+		/// Some funds have been deposited into the pot. \[deposit\]
+		Deposit(BalanceOf<T>),
 	}
 
 	#[pallet::error]
@@ -352,7 +365,8 @@ pub mod pallet {
 			// Make sure pot exists.
 			let lottery_account = Self::account_id();
 			if T::Currency::total_balance(&lottery_account).is_zero() {
-				T::Currency::deposit_creating(&lottery_account, T::Currency::minimum_balance());
+				let _ =
+					T::Currency::deposit_creating(&lottery_account, T::Currency::minimum_balance());
 			}
 			Self::deposit_event(Event::<T>::LotteryStarted);
 			Ok(())
@@ -486,3 +500,14 @@ impl<T: Config> Pallet<T> {
 		random_number
 	}
 }
+
+impl<T: Config> OnUnbalanced<NegativeImbalanceOf<T>> for Pallet<T> {
+	fn on_nonzero_unbalanced(amount: NegativeImbalanceOf<T>) {
+		let numeric_amount = amount.peek();
+
+		// The pot account may not exist yet if no lottery was ever started.
+		let _ = T::Currency::resolve_creating(&Self::account_id(), amount);
+
+		Self::deposit_event(Event::Deposit(numeric_amount));
+	}
+}
@@ -18,7 +18,7 @@
 //! Tests for the module.
 
 use super::*;
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_noop, assert_ok, traits::OnUnbalanced};
 use mock::{
 	new_test_ext, run_to_block, Balances, BalancesCall, Call, Lottery, Origin, SystemCall, Test,
 };
@@ -251,3 +251,30 @@ fn start_lottery_will_create_account() {
 		assert_eq!(Balances::total_balance(&Lottery::account_id()), 1);
 	});
 }
+
+#[test]
+fn unbalanced_funds_go_to_pot() {
+	new_test_ext().execute_with(|| {
+		let price = 10;
+		let length = 20;
+		let delay = 5;
+		let calls = vec![Call::Balances(BalancesCall::transfer { dest: 0, value: 0 })];
+		assert_ok!(Lottery::set_calls(Origin::root(), calls));
+		assert_ok!(Lottery::start_lottery(Origin::root(), price, length, delay, false));
+
+		// Part of some fees are routed into the pot.
+		let fees = Balances::issue(50);
+		Lottery::on_unbalanced(fees);
+		assert_eq!(Lottery::pot().1, 50);
+
+		// A single ticket wins the ticket price and the routed funds.
+		let call = Box::new(Call::Balances(BalancesCall::transfer { dest: 2, value: 20 }));
+		assert_ok!(Lottery::buy_ticket(Origin::signed(1), call));
+		assert_eq!(Lottery::pot().1, 60);
+
+		run_to_block(25);
+		assert_eq!(Lottery::pot().1, 0);
+		// Started with 100, paid 20 to account 2 and 10 for the ticket.
+		assert_eq!(Balances::free_balance(1), 100 - 20 - 10 + 60);
+	});
+}
@@ -18,7 +18,7 @@
 use crate::{mock::*, *};
 
 use frame_support::traits::OnInitialize;
-use pallet_mmr_primitives::{Compact, Proof};
+use pallet_mmr_primitives::{BatchProof, Compact, Proof};
 use sp_core::{
 	offchain::{testing::TestOffchainExt, OffchainDbExt, OffchainWorkerExt},
 	H256,
@@ -247,6 +247,116 @@ fn should_generate_proofs_correctly() {
 	});
 }
 
+#[test]
+fn should_generate_batch_proof_correctly() {
+	let _ = env_logger::try_init();
+	let mut ext = new_test_ext();
+	// given
+	ext.execute_with(|| init_chain(7));
+	ext.persist_offchain_overlay();
+
+	// Try to generate proofs now. This requires the offchain extensions to be present
+	// to retrieve full leaf data.
+	register_offchain_ext(&mut ext);
+	ext.execute_with(|| {
+		// when a proof is generated for a single leaf, it matches the leaf proof
+		let (leaves, proof) = crate::Pallet::<Test>::generate_batch_proof(vec![4]).unwrap();
+		let (leaf, single_proof) = crate::Pallet::<Test>::generate_proof(4).unwrap();
+
+		// then
+		assert_eq!(leaves, vec![leaf]);
+		assert_eq!(
+			proof,
+			BatchProof { leaf_indices: vec![4], leaf_count: 7, items: single_proof.items }
+		);
+
+		// when a proof is generated for multiple leaves
+		let (leaves, proof) = crate::Pallet::<Test>::generate_batch_proof(vec![0, 4, 5]).unwrap();
+
+		// then
+		assert_eq!(
+			leaves,
+			vec![
+				Compact::new(((0, H256::repeat_byte(1)).into(), LeafData::new(1).into(),)),
+				Compact::new(((4, H256::repeat_byte(5)).into(), LeafData::new(5).into(),)),
+				Compact::new(((5, H256::repeat_byte(6)).into(), LeafData::new(6).into(),)),
+			]
+		);
+		assert_eq!(proof.leaf_indices, vec![0, 4, 5]);
+		assert_eq!(proof.leaf_count, 7);
+	});
+}
+
+#[test]
+fn should_verify_batch_proof() {
+	let _ = env_logger::try_init();
+
+	// Start off with chain initialisation and storing indexing data off-chain
+	// (MMR Leafs)
+	let mut ext = new_test_ext();
+	ext.execute_with(|| init_chain(7));
+	ext.persist_offchain_overlay();
+
+	// Try to generate proof now. This requires the offchain extensions to be present
+	// to retrieve full leaf data.
+	register_offchain_ext(&mut ext);
+	let (leaves, proof) = ext.execute_with(|| {
+		// when
+		crate::Pallet::<Test>::generate_batch_proof(vec![0, 4, 5]).unwrap()
+	});
+
+	// Now to verify the proof, we really shouldn't require offchain storage or extension.
+	// Hence we initialize the storage once again, using different externalities and then
+	// verify.
+	let mut ext2 = new_test_ext();
+	ext2.execute_with(|| {
+		init_chain(7);
+		// then
+		assert_eq!(crate::Pallet::<Test>::verify_leaves(leaves.clone(), proof.clone()), Ok(()));
+
+		// the leaves have to match the order of the indices
+		let mut reordered = leaves.clone();
+		reordered.swap(0, 1);
+		assert_eq!(
+			crate::Pallet::<Test>::verify_leaves(reordered, proof.clone()),
+			Err(primitives::Error::Verify)
+		);
+
+		// and there has to be one leaf per index
+		assert_eq!(
+			crate::Pallet::<Test>::verify_leaves(leaves[..2].to_vec(), proof),
+			Err(primitives::Error::Verify)
+		);
+	});
+}
+
+#[test]
+fn batch_verification_should_be_stateless() {
+	let _ = env_logger::try_init();
+
+	// Start off with chain initialisation and storing indexing data off-chain
+	// (MMR Leafs)
+	let mut ext = new_test_ext();
+	ext.execute_with(|| init_chain(7));
+	ext.persist_offchain_overlay();
+
+	// Try to generate proof now. This requires the offchain extensions to be present
+	// to retrieve full leaf data.
+	register_offchain_ext(&mut ext);
+	let (leaves, proof) = ext.execute_with(|| {
+		// when
+		crate::Pallet::<Test>::generate_batch_proof(vec![0, 4, 5]).unwrap()
+	});
+	let root = ext.execute_with(|| crate::Pallet::<Test>::mmr_root_hash());
+
+	// Verify proof without relying on any on-chain data.
+	let leaves = leaves.into_iter().map(crate::primitives::DataOrHash::Data).collect();
+	assert_eq!(
+		crate::verify_leaves_proof::<<Test as Config>::Hashing, _>(root, leaves, proof),
+		Ok(())
+	);
+}
+
 #[test]
 fn should_verify() {
 	let _ = env_logger::try_init();
@@ -0,0 +1,110 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Message queue pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::{benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_system::RawOrigin;
+
+use crate::Pallet as MessageQueue;
+
+/// Enqueue a message of maximal length from `origin` and return its payload.
+fn enqueue_max_message<T: Config>(origin: &MessageOriginOf<T>) -> Vec<u8> {
+	let message = vec![0u8; MaxMessageLenOf::<T>::get() as usize];
+	MessageQueue::<T>::do_enqueue_message(origin, &message);
+	message
+}
+
+benchmarks! {
+	where_clause { where MessageOriginOf<T>: From<u32> }
+
+	bump_service_head {
+		let first: MessageOriginOf<T> = 0.into();
+		let second: MessageOriginOf<T> = 1.into();
+		enqueue_max_message::<T>(&first);
+		enqueue_max_message::<T>(&second);
+		let mut weight = WeightCounter::from_limit(Weight::MAX);
+	}: {
+		MessageQueue::<T>::bump_service_head(&mut weight);
+	} verify {
+		assert_eq!(ServiceHead::<T>::get(), Some(second));
+	}
+
+	service_queue_base {
+		let origin: MessageOriginOf<T> = 0.into();
+		// A ready queue without any pages left to service which will be unknit.
+		let neighbours = MessageQueue::<T>::ready_ring_knit(&origin)
+			.map_err(|_| "Ready ring must be consistent")?;
+		BookStateFor::<T>::mutate(&origin, |b| b.ready_neighbours = Some(neighbours));
+		let mut weight = WeightCounter::from_limit(Weight::MAX);
+	}: {
+		MessageQueue::<T>::service_queue(origin, &mut weight, Weight::MAX);
+	} verify {
+		assert_eq!(ServiceHead::<T>::get(), None);
+	}
+
+	service_page_base {
+		let origin: MessageOriginOf<T> = 0.into();
+		enqueue_max_message::<T>(&origin);
+		let mut book_state = BookStateFor::<T>::get(&origin);
+		// Only enough weight to load and store the page again.
+		let mut weight = WeightCounter::from_limit(T::WeightInfo::service_page_base());
+	}: {
+		MessageQueue::<T>::service_page(&origin, &mut book_state, &mut weight, Weight::MAX);
+	} verify {
+		assert!(Pages::<T>::get(&origin, 0).is_some());
+	}
+
+	service_page_item {
+		let origin: MessageOriginOf<T> = 0.into();
+		enqueue_max_message::<T>(&origin);
+		let mut book_state = BookStateFor::<T>::get(&origin);
+		let mut page = Pages::<T>::get(&origin, 0).ok_or("Page must exist")?;
+		let mut weight = WeightCounter::from_limit(Weight::MAX);
+	}: {
+		MessageQueue::<T>::service_page_item(
+			&origin,
+			0,
+			&mut book_state,
+			&mut page,
+			&mut weight,
+			Weight::MAX,
+		);
+	} verify {
+		assert!(page.is_complete());
+	}
+
+	execute_overweight {
+		let caller: T::AccountId = whitelisted_caller();
+		let origin: MessageOriginOf<T> = 0.into();
+		enqueue_max_message::<T>(&origin);
+		// Mark the message as overweight, as servicing would have done.
+		let mut page = Pages::<T>::get(&origin, 0).ok_or("Page must exist")?;
+		page.skip_first(false);
+		Pages::<T>::insert(&origin, 0, page);
+		BookStateFor::<T>::mutate(&origin, |b| b.begin = 1);
+	}: _(RawOrigin::Signed(caller), origin.clone(), 0, 0, Weight::MAX)
+	verify {
+		assert!(Pages::<T>::get(&origin, 0).is_none());
+	}
+}
+
+impl_benchmark_test_suite!(MessageQueue, crate::mock::new_test_ext(), crate::mock::Test);
@@ -0,0 +1,912 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Message Queue Pallet
+//!
+//! A generic, weight-bounded store of messages waiting to be processed.
+//!
+//! - [`Config`]
+//! - [`Call`]
+//!
+//! ## Overview
+//!
+//! Messages are enqueued by producers through the [`EnqueueMessage`] trait and are handed to
+//! the configured [`ProcessMessage`] implementation, one at a time, when the queues are serviced.
+//!
+//! Every message origin has its own queue ("book"), made of a sequence of pages. A page holds as
+//! many messages as fit into `HeapSize` bytes. Books which contain unprocessed messages are
+//! linked together in a ring, the "ready ring"; the `ServiceHead` points to the book to service
+//! next and is rotated after each book is serviced, so that all origins get their turn.
+//!
+//! Queues are serviced in `on_initialize`, using up to `ServiceWeight`, and in `on_idle` using
+//! whatever weight is left in the block.
+//!
+//! Messages which need more weight than could ever be given to them while servicing are marked
+//! as overweight and skipped. They stay in storage and may be executed by anyone afterwards
+//! with enough weight through `execute_overweight`.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! * `execute_overweight` - Execute a message which was skipped for being overweight.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+pub mod mock_helpers;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+	pallet_prelude::*,
+	storage::bounded_vec::BoundedSlice,
+	traits::{
+		EnqueueMessage, ExecuteOverweightError, Footprint, OnQueueChanged, ProcessMessage,
+		ProcessMessageError, ServiceQueues,
+	},
+	BoundedVec, CloneNoBound, DefaultNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+};
+use frame_system::pallet_prelude::*;
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{Hash, Saturating, Zero},
+	RuntimeDebug,
+};
+use sp_std::{convert::TryFrom, prelude::*};
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+/// Type for identifying a page.
+pub type PageIndex = u32;
+
+/// The origin of the messages which are processed by the `MessageProcessor`.
+pub type MessageOriginOf<T> = <<T as Config>::MessageProcessor as ProcessMessage>::Origin;
+
+/// Data encoded and prefixed to the encoded `MessageItem`.
+#[derive(Encode, Decode, PartialEq, MaxEncodedLen, RuntimeDebug)]
+pub struct ItemHeader {
+	/// The length of this item, not including the size of this header. The next item of the page
+	/// follows immediately on from the end of this item.
+	payload_len: u32,
+	/// Whether this item has been processed.
+	is_processed: bool,
+}
+
+/// A page of messages. Pages always contain at least one item.
+#[derive(
+	CloneNoBound, Encode, Decode, DefaultNoBound, PartialEqNoBound, RuntimeDebugNoBound, TypeInfo,
+)]
+#[scale_info(skip_type_params(HeapSize))]
+pub struct Page<HeapSize: Get<u32>> {
+	/// Messages remaining to be processed; this includes overweight messages which have been
+	/// skipped.
+	remaining: u32,
+	/// The size of all remaining messages to be processed.
+	remaining_size: u32,
+	/// The number of items before the `first` item in this page.
+	first_index: u32,
+	/// The heap-offset of the header of the first message item in this page which is ready for
+	/// processing.
+	first: u32,
+	/// The heap-offset of the header of the last message item in this page.
+	last: u32,
+	/// The heap. Each item is an encoded `ItemHeader` followed by its payload.
+	heap: BoundedVec<u8, HeapSize>,
+}
+
+impl<HeapSize: Get<u32>> Page<HeapSize> {
+	/// Create a [`Page`] from one unprocessed message.
+	fn from_message(message: &[u8]) -> Self {
+		let mut page = Self::default();
+		let appended = page.try_append_message(message);
+		debug_assert!(appended.is_ok(), "a single message must always fit into a page; qed");
+		page
+	}
+
+	/// Try to append one message to a page.
+	fn try_append_message(&mut self, message: &[u8]) -> Result<(), ()> {
+		let pos = self.heap.len();
+		let header = ItemHeader { payload_len: message.len() as u32, is_processed: false };
+		let len = pos.saturating_add(ItemHeader::max_encoded_len()).saturating_add(message.len());
+		if len > HeapSize::get() as usize {
+			return Err(())
+		}
+
+		let mut heap = sp_std::mem::take(&mut self.heap).into_inner();
+		header.using_encoded(|h| heap.extend_from_slice(h));
+		heap.extend_from_slice(message);
+		self.heap = BoundedVec::try_from(heap).map_err(|_| ())?;
+		self.last = pos as u32;
+		self.remaining.saturating_inc();
+		self.remaining_size.saturating_accrue(message.len() as u32);
+		Ok(())
+	}
+
+	/// Decode the item header at heap-offset `pos`, returning it along with the position of its
+	/// payload.
+	fn header_at(&self, pos: usize) -> Option<(ItemHeader, usize)> {
+		let header = ItemHeader::decode(&mut self.heap.get(pos..)?).ok()?;
+		let payload_pos = pos.saturating_add(ItemHeader::max_encoded_len());
+		if payload_pos.saturating_add(header.payload_len as usize) > self.heap.len() {
+			return None
+		}
+		Some((header, payload_pos))
+	}
+
+	/// Overwrite the item header at heap-offset `pos`.
+	fn set_header_at(&mut self, pos: usize, header: &ItemHeader) {
+		let heap = &mut self.heap;
+		header.using_encoded(|h| {
+			if let Some(slot) = heap.get_mut(pos..pos.saturating_add(h.len())) {
+				slot.copy_from_slice(h)
+			}
+		});
+	}
+
+	/// Returns the payload of the first message ready for processing, if any.
+	fn peek_first(&self) -> Option<Vec<u8>> {
+		if self.first > self.last {
+			return None
+		}
+		let (header, payload_pos) = self.header_at(self.first as usize)?;
+		self.heap
+			.get(payload_pos..payload_pos.saturating_add(header.payload_len as usize))
+			.map(|p| p.to_vec())
+	}
+
+	/// Point `first` at the next message, marking the first one as processed if `is_processed`
+	/// is true.
+	fn skip_first(&mut self, is_processed: bool) {
+		let pos = self.first as usize;
+		if let Some((mut header, _)) = self.header_at(pos) {
+			if is_processed && !header.is_processed {
+				header.is_processed = true;
+				self.remaining.saturating_dec();
+				self.remaining_size.saturating_reduce(header.payload_len);
+				self.set_header_at(pos, &header);
+			}
+			self.first
+				.saturating_accrue(ItemHeader::max_encoded_len() as u32 + header.payload_len);
+			self.first_index.saturating_inc();
+		}
+	}
+
+	/// Return the heap-offset, processed flag and payload of the message with the given `index`.
+	fn peek_index(&self, index: usize) -> Option<(usize, bool, Vec<u8>)> {
+		let mut pos = 0;
+		let mut i = 0;
+		while pos < self.heap.len() {
+			let (header, payload_pos) = self.header_at(pos)?;
+			let end = payload_pos.saturating_add(header.payload_len as usize);
+			if i == index {
+				return Some((pos, header.is_processed, self.heap.get(payload_pos..end)?.to_vec()))
+			}
+			pos = end;
+			i += 1;
+		}
+		None
+	}
+
+	/// Set the `is_processed` flag for the item at `pos` to be `true` if not already and decrement
+	/// the `remaining` counter of the page.
+	fn note_processed_at_pos(&mut self, pos: usize) {
+		if let Some((mut header, _)) = self.header_at(pos) {
+			if !header.is_processed {
+				header.is_processed = true;
+				self.remaining.saturating_dec();
+				self.remaining_size.saturating_reduce(header.payload_len);
+				self.set_header_at(pos, &header);
+			}
+		}
+	}
+
+	/// Returns whether the page is *complete* which means that no messages remain.
+	fn is_complete(&self) -> bool {
+		self.remaining.is_zero()
+	}
+}
+
+/// A single link in the double-linked Ready Ring list.
+#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct Neighbours<MessageOrigin> {
+	/// The previous queue.
+	prev: MessageOrigin,
+	/// The next queue.
+	next: MessageOrigin,
+}
+
+/// The state of a queue as represented by a book of its pages.
+///
+/// Each queue has exactly one book which holds all of its pages. All pages of a book combined
+/// contain all of the messages of its queue; hence the name *Book*.
+#[derive(Clone, Encode, Decode, RuntimeDebug, PartialEq, TypeInfo)]
+pub struct BookState<MessageOrigin> {
+	/// The first page with some items to be processed in it. If this is `>= end`, then there are
+	/// no pages with items to be processed in them.
+	begin: PageIndex,
+	/// One more than the last page with some items to be processed in it.
+	end: PageIndex,
+	/// The number of pages stored at present.
+	count: PageIndex,
+	/// If this book has any ready pages, then this will be `Some` with the previous and next
+	/// neighbours. This wraps around.
+	ready_neighbours: Option<Neighbours<MessageOrigin>>,
+	/// The number of unprocessed messages stored at present.
+	message_count: u64,
+	/// The total size of all unprocessed messages stored at present.
+	size: u64,
+}
+
+impl<MessageOrigin> Default for BookState<MessageOrigin> {
+	fn default() -> Self {
+		Self { begin: 0, end: 0, count: 0, ready_neighbours: None, message_count: 0, size: 0 }
+	}
+}
+
+/// The maximal length of a single message, given the heap size of a page.
+pub struct MaxMessageLenOf<T>(PhantomData<T>);
+impl<T: Config> Get<u32> for MaxMessageLenOf<T> {
+	fn get() -> u32 {
+		T::HeapSize::get().saturating_sub(ItemHeader::max_encoded_len() as u32)
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(_);
+
+	/// The module configuration trait.
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		/// Processor for a message.
+		type MessageProcessor: ProcessMessage;
+
+		/// Code to be called when a message queue changes - either with items introduced or
+		/// removed.
+		type QueueChangeHandler: OnQueueChanged<MessageOriginOf<Self>>;
+
+		/// The size of the page; this implies the maximum message size which can be sent.
+		///
+		/// A good value depends on the expected message sizes, their weights, the weight that is
+		/// available for processing them and the maximal needed message size. The maximal message
+		/// size is slightly lower than this as defined by [`MaxMessageLenOf`].
+		#[pallet::constant]
+		type HeapSize: Get<u32>;
+
+		/// The amount of weight (if any) which should be provided to the message queue for
+		/// servicing enqueued items in `on_initialize`.
+		///
+		/// This may be legitimately `None` in the case that you will call
+		/// `ServiceQueues::service_queues` manually or rely on `on_idle` alone.
+		#[pallet::constant]
+		type ServiceWeight: Get<Option<Weight>>;
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Message discarded due to an error in the `MessageProcessor` (usually a format error).
+		/// \[hash, origin, error\]
+		ProcessingFailed(T::Hash, MessageOriginOf<T>, ProcessMessageError),
+		/// Message is processed. \[hash, origin, weight_used, success\]
+		Processed(T::Hash, MessageOriginOf<T>, Weight, bool),
+		/// Message placed in overweight queue. \[hash, origin, page_index, message_index\]
+		OverweightEnqueued(T::Hash, MessageOriginOf<T>, PageIndex, u32),
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The referenced page does not exist.
+		NoPage,
+		/// The referenced message could not be found.
+		NoMessage,
+		/// The message was already processed and cannot be processed again.
+		AlreadyProcessed,
+		/// The message is queued for future execution.
+		Queued,
+		/// There is temporarily not enough weight to continue servicing messages.
+		InsufficientWeight,
+	}
+
+	/// The index of the first and last (non-empty) pages.
+	#[pallet::storage]
+	pub type BookStateFor<T: Config> =
+		StorageMap<_, Twox64Concat, MessageOriginOf<T>, BookState<MessageOriginOf<T>>, ValueQuery>;
+
+	/// The origin at which we should begin servicing.
+	#[pallet::storage]
+	pub type ServiceHead<T: Config> = StorageValue<_, MessageOriginOf<T>, OptionQuery>;
+
+	/// The map of page indices to pages.
+	#[pallet::storage]
+	pub type Pages<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		MessageOriginOf<T>,
+		Twox64Concat,
+		PageIndex,
+		Page<T::HeapSize>,
+		OptionQuery,
+	>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+			if let Some(weight_limit) = T::ServiceWeight::get() {
+				Self::service_queues_impl(weight_limit, weight_limit)
+			} else {
+				0
+			}
+		}
+
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			// Messages which do not fit into the remaining weight but would fit into the regular
+			// servicing are left for `on_initialize`.
+			let overweight_limit = T::ServiceWeight::get().unwrap_or(0).max(remaining_weight);
+			Self::service_queues_impl(remaining_weight, overweight_limit)
+		}
+
+		fn integrity_test() {
+			assert!(MaxMessageLenOf::<T>::get() > 0, "HeapSize too low to fit any message header");
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Execute an overweight message.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `message_origin`: The origin from which the message to be executed arrived.
+		/// - `page`: The page in the queue in which the message to be executed is sitting.
+		/// - `index`: The index into the queue of the message to be executed.
+		/// - `weight_limit`: The maximum amount of weight allowed to be consumed in the execution
+		///   of the message.
+		///
+		/// Benchmark complexity considerations: O(index + weight_limit).
+		#[pallet::weight(T::WeightInfo::execute_overweight().saturating_add(*weight_limit))]
+		pub fn execute_overweight(
+			origin: OriginFor<T>,
+			message_origin: MessageOriginOf<T>,
+			page: PageIndex,
+			index: u32,
+			weight_limit: Weight,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let actual_weight =
+				Self::do_execute_overweight(message_origin, page, index, weight_limit)?;
+			Ok(Some(T::WeightInfo::execute_overweight().saturating_add(actual_weight)).into())
+		}
+	}
+}
+
+/// The status of a page after trying to execute its next message.
+#[derive(PartialEq, RuntimeDebug)]
+enum PageExecutionStatus {
+	/// The execution bailed because there was not enough weight remaining.
+	Bailed,
+	/// There are no more messages in the page which can be processed.
+	NoMore,
+}
+
+/// The status after trying to execute the next item of a page.
+#[derive(PartialEq, RuntimeDebug)]
+enum ItemExecutionStatus {
+	/// The execution bailed because there was not enough weight remaining.
+	Bailed,
+	/// The item did not exist.
+	NoItem,
+	/// Whether the execution of an item resulted in it being processed.
+	///
+	/// One reason for `false` would be permanently overweight.
+	Executed(bool),
+}
+
+/// The status of an attempt to process a message.
+#[derive(PartialEq, RuntimeDebug)]
+enum MessageExecutionStatus {
+	/// There is not enough weight remaining at present.
+	InsufficientWeight,
+	/// There will never be enough weight.
+	Overweight,
+	/// The message was processed successfully.
+	Processed,
+	/// The message was processed and resulted in a permanent error.
+	Unprocessable,
+}
+
+/// Meters consumed weight against a hard limit.
+struct WeightCounter {
+	used: Weight,
+	limit: Weight,
+}
+
+impl WeightCounter {
+	fn from_limit(limit: Weight) -> Self {
+		Self { used: 0, limit }
+	}
+
+	/// Consume `weight` if it is within the limit, returning whether it was.
+	fn check_accrue(&mut self, weight: Weight) -> bool {
+		let test = self.used.saturating_add(weight);
+		if test > self.limit {
+			return false
+		}
+		self.used = test;
+		true
+	}
+
+	/// Consume `weight`, saturating at the limit.
+	fn saturating_accrue(&mut self, weight: Weight) {
+		self.used = self.used.saturating_add(weight).min(self.limit);
+	}
+
+	fn remaining(&self) -> Weight {
+		self.limit.saturating_sub(self.used)
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Knit `origin` into the ready ring right at the end.
+	///
+	/// Return the two ready ring neighbours of `origin`.
+	fn ready_ring_knit(origin: &MessageOriginOf<T>) -> Result<Neighbours<MessageOriginOf<T>>, ()> {
+		if let Some(head) = ServiceHead::<T>::get() {
+			let mut head_book_state = BookStateFor::<T>::get(&head);
+			let mut head_neighbours = head_book_state.ready_neighbours.take().ok_or(())?;
+			let tail = head_neighbours.prev;
+			head_neighbours.prev = origin.clone();
+			head_book_state.ready_neighbours = Some(head_neighbours);
+			BookStateFor::<T>::insert(&head, head_book_state);
+
+			let mut tail_book_state = BookStateFor::<T>::get(&tail);
+			let mut tail_neighbours = tail_book_state.ready_neighbours.take().ok_or(())?;
+			tail_neighbours.next = origin.clone();
+			tail_book_state.ready_neighbours = Some(tail_neighbours);
+			BookStateFor::<T>::insert(&tail, tail_book_state);
+
+			Ok(Neighbours { next: head, prev: tail })
+		} else {
+			ServiceHead::<T>::put(origin);
+			Ok(Neighbours { next: origin.clone(), prev: origin.clone() })
+		}
+	}
+
+	/// Remove `origin` from the ready ring, joining its two neighbours.
+	fn ready_ring_unknit(origin: &MessageOriginOf<T>, neighbours: Neighbours<MessageOriginOf<T>>) {
+		if &neighbours.next == origin {
+			// `origin` was the only ready queue.
+			ServiceHead::<T>::kill();
+			return
+		}
+
+		BookStateFor::<T>::mutate(&neighbours.next, |book_state| {
+			if let Some(ref mut n) = book_state.ready_neighbours {
+				n.prev = neighbours.prev.clone()
+			}
+		});
+		BookStateFor::<T>::mutate(&neighbours.prev, |book_state| {
+			if let Some(ref mut n) = book_state.ready_neighbours {
+				n.next = neighbours.next.clone()
+			}
+		});
+		if ServiceHead::<T>::get().as_ref() == Some(origin) {
+			ServiceHead::<T>::put(neighbours.next);
+		}
+	}
+
+	/// Tries to bump the current `ServiceHead` to the next ready queue.
+	///
+	/// Returns the current head if it got bumped and `None` otherwise.
+	fn bump_service_head(weight: &mut WeightCounter) -> Option<MessageOriginOf<T>> {
+		if !weight.check_accrue(T::WeightInfo::bump_service_head()) {
+			return None
+		}
+
+		let head = ServiceHead::<T>::get()?;
+		let next = BookStateFor::<T>::get(&head).ready_neighbours?.next;
+		ServiceHead::<T>::put(&next);
+		Some(head)
+	}
+
+	fn do_enqueue_message(origin: &MessageOriginOf<T>, message: &[u8]) {
+		let mut book_state = BookStateFor::<T>::get(origin);
+		book_state.message_count.saturating_inc();
+		book_state.size.saturating_accrue(message.len() as u64);
+
+		if book_state.end > book_state.begin {
+			debug_assert!(book_state.ready_neighbours.is_some(), "Must be in ready ring if ready");
+			// Already have a page in progress - attempt to append.
+			let last = book_state.end - 1;
+			if let Some(mut page) = Pages::<T>::get(origin, last) {
+				if page.try_append_message(message).is_ok() {
+					Pages::<T>::insert(origin, last, page);
+					BookStateFor::<T>::insert(origin, book_state);
+					return
+				}
+			} else {
+				debug_assert!(false, "Corruption: referenced page doesn't exist.");
+			}
+		} else {
+			debug_assert!(book_state.ready_neighbours.is_none(), "Must not be in ready ring");
+			// Insert into ready queue.
+			match Self::ready_ring_knit(origin) {
+				Ok(neighbours) => book_state.ready_neighbours = Some(neighbours),
+				Err(()) => debug_assert!(false, "Ring state invalid when knitting"),
+			}
+		}
+
+		// No room on the page or no page - link in a new page.
+		book_state.end.saturating_inc();
+		book_state.count.saturating_inc();
+		Pages::<T>::insert(origin, book_state.end - 1, Page::<T::HeapSize>::from_message(message));
+		BookStateFor::<T>::insert(origin, book_state);
+	}
+
+	/// Try to execute a single message that was marked as overweight.
+	///
+	/// Returns the actual weight which was used to process the message.
+	pub fn do_execute_overweight(
+		origin: MessageOriginOf<T>,
+		page_index: PageIndex,
+		index: u32,
+		weight_limit: Weight,
+	) -> Result<Weight, Error<T>> {
+		let mut book_state = BookStateFor::<T>::get(&origin);
+		let mut page = Pages::<T>::get(&origin, page_index).ok_or(Error::<T>::NoPage)?;
+		let (pos, is_processed, payload) =
+			page.peek_index(index as usize).ok_or(Error::<T>::NoMessage)?;
+		ensure!(!is_processed, Error::<T>::AlreadyProcessed);
+		// Messages which were not skipped yet will be serviced regularly.
+		ensure!(
+			page_index < book_state.begin ||
+				(page_index == book_state.begin && pos < page.first as usize),
+			Error::<T>::Queued
+		);
+
+		let mut weight = WeightCounter::from_limit(weight_limit);
+		match Self::process_message_payload(
+			origin.clone(),
+			page_index,
+			index,
+			&payload,
+			&mut weight,
+			Weight::MAX,
+		) {
+			MessageExecutionStatus::InsufficientWeight | MessageExecutionStatus::Overweight =>
+				Err(Error::<T>::InsufficientWeight),
+			MessageExecutionStatus::Processed | MessageExecutionStatus::Unprocessable => {
+				page.note_processed_at_pos(pos);
+				book_state.message_count.saturating_dec();
+				book_state.size.saturating_reduce(payload.len() as u64);
+				if page.is_complete() {
+					Pages::<T>::remove(&origin, page_index);
+					book_state.count.saturating_dec();
+				} else {
+					Pages::<T>::insert(&origin, page_index, page);
+				}
+				T::QueueChangeHandler::on_queue_changed(
+					origin.clone(),
+					book_state.message_count,
+					book_state.size,
+				);
+				BookStateFor::<T>::insert(&origin, book_state);
+				Ok(weight.used)
+			},
+		}
+	}
+
+	/// Service the queues until `weight_limit` is exhausted or no progress can be made.
+	///
+	/// Messages which need more than `overweight_limit` are marked as overweight.
+	fn service_queues_impl(weight_limit: Weight, overweight_limit: Weight) -> Weight {
+		let mut weight = WeightCounter::from_limit(weight_limit);
+
+		let mut next = match Self::bump_service_head(&mut weight) {
+			Some(h) => h,
+			None => return weight.used,
+		};
+		// The last queue that did not make any progress.
+		// The loop aborts as soon as it arrives at this queue again without making any progress
+		// on other queues in between.
+		let mut last_no_progress = None;
+
+		loop {
+			let (progressed, n) = Self::service_queue(next.clone(), &mut weight, overweight_limit);
+			next = match n {
+				Some(n) =>
+					if !progressed {
+						if last_no_progress == Some(n.clone()) {
+							break
+						}
+						if last_no_progress.is_none() {
+							last_no_progress = Some(next.clone())
+						}
+						n
+					} else {
+						last_no_progress = None;
+						n
+					},
+				None => break,
+			}
+		}
+		weight.used
+	}
+
+	/// Service as many messages of a queue as possible.
+	///
+	/// Returns whether the execution made progress and the origin of the next ready queue, if
+	/// any.
+	fn service_queue(
+		origin: MessageOriginOf<T>,
+		weight: &mut WeightCounter,
+		overweight_limit: Weight,
+	) -> (bool, Option<MessageOriginOf<T>>) {
+		if !weight.check_accrue(T::WeightInfo::service_queue_base()) {
+			return (false, None)
+		}
+
+		let mut book_state = BookStateFor::<T>::get(&origin);
+		let mut total_processed = 0;
+		while book_state.end > book_state.begin {
+			let (processed, status) =
+				Self::service_page(&origin, &mut book_state, weight, overweight_limit);
+			total_processed.saturating_accrue(processed);
+			if status == PageExecutionStatus::Bailed {
+				break
+			}
+		}
+
+		let mut next_ready = None;
+		if book_state.begin >= book_state.end {
+			// No longer ready - unknit.
+			if let Some(neighbours) = book_state.ready_neighbours.take() {
+				if neighbours.next != origin {
+					next_ready = Some(neighbours.next.clone());
+				}
+				Self::ready_ring_unknit(&origin, neighbours);
+			} else {
+				debug_assert!(false, "Freshly processed queue must have been ready");
+			}
+		} else {
+			next_ready = book_state.ready_neighbours.as_ref().map(|x| x.next.clone());
+		}
+		BookStateFor::<T>::insert(&origin, &book_state);
+		if total_processed > 0 {
+			T::QueueChangeHandler::on_queue_changed(
+				origin,
+				book_state.message_count,
+				book_state.size,
+			);
+		}
+		(total_processed > 0, next_ready)
+	}
+
+	/// Service as many messages of the first page of a book as possible.
+	///
+	/// Returns the number of processed messages and the status of the page.
+	fn service_page(
+		origin: &MessageOriginOf<T>,
+		book_state: &mut BookState<MessageOriginOf<T>>,
+		weight: &mut WeightCounter,
+		overweight_limit: Weight,
+	) -> (u32, PageExecutionStatus) {
+		let page_index = book_state.begin;
+		let mut page = match Pages::<T>::get(origin, page_index) {
+			Some(p) => p,
+			None => {
+				debug_assert!(false, "message-queue: referenced page not found");
+				book_state.begin.saturating_inc();
+				return (0, PageExecutionStatus::NoMore)
+			},
+		};
+		if !weight.check_accrue(T::WeightInfo::service_page_base()) {
+			return (0, PageExecutionStatus::Bailed)
+		}
+
+		let mut total_processed = 0;
+		// Execute as many messages as possible.
+		let status = loop {
+			match Self::service_page_item(
+				origin,
+				page_index,
+				book_state,
+				&mut page,
+				weight,
+				overweight_limit,
+			) {
+				ItemExecutionStatus::Bailed => break PageExecutionStatus::Bailed,
+				ItemExecutionStatus::NoItem => break PageExecutionStatus::NoMore,
+				// Keep going as long as we make progress...
+				ItemExecutionStatus::Executed(true) => total_processed.saturating_inc(),
+				ItemExecutionStatus::Executed(false) => (),
+			}
+		};
+
+		if page.is_complete() {
+			debug_assert!(
+				status != PageExecutionStatus::Bailed,
+				"we never bail if a page became complete"
+			);
+			Pages::<T>::remove(origin, page_index);
+			book_state.count.saturating_dec();
+		} else {
+			Pages::<T>::insert(origin, page_index, page);
+		}
+		if status == PageExecutionStatus::NoMore {
+			book_state.begin.saturating_inc();
+		}
+		(total_processed, status)
+	}
+
+	/// Execute the next message of a page.
+	fn service_page_item(
+		origin: &MessageOriginOf<T>,
+		page_index: PageIndex,
+		book_state: &mut BookState<MessageOriginOf<T>>,
+		page: &mut Page<T::HeapSize>,
+		weight: &mut WeightCounter,
+		overweight_limit: Weight,
+	) -> ItemExecutionStatus {
+		let payload = match page.peek_first() {
+			Some(m) => m,
+			None => return ItemExecutionStatus::NoItem,
+		};
+		if !weight.check_accrue(T::WeightInfo::service_page_item()) {
+			return ItemExecutionStatus::Bailed
+		}
+
+		let is_processed = match Self::process_message_payload(
+			origin.clone(),
+			page_index,
+			page.first_index,
+			&payload,
+			weight,
+			overweight_limit,
+		) {
+			MessageExecutionStatus::InsufficientWeight => return ItemExecutionStatus::Bailed,
+			MessageExecutionStatus::Processed | MessageExecutionStatus::Unprocessable => true,
+			MessageExecutionStatus::Overweight => false,
+		};
+
+		if is_processed {
+			book_state.message_count.saturating_dec();
+			book_state.size.saturating_reduce(payload.len() as u64);
+		}
+		page.skip_first(is_processed);
+		ItemExecutionStatus::Executed(is_processed)
+	}
+
+	/// Process a single message.
+	///
+	/// The base weight of this function needs to be accounted for by the caller. `weight` is the
+	/// remaining weight to process the message. `overweight_limit` is the maximum weight that a
+	/// message can ever consume. Messages above this limit are marked as permanently overweight.
+	fn process_message_payload(
+		origin: MessageOriginOf<T>,
+		page_index: PageIndex,
+		message_index: u32,
+		message: &[u8],
+		weight: &mut WeightCounter,
+		overweight_limit: Weight,
+	) -> MessageExecutionStatus {
+		let hash = T::Hashing::hash(message);
+		use ProcessMessageError::Overweight;
+		match T::MessageProcessor::process_message(message, origin.clone(), weight.remaining()) {
+			Err(Overweight(w)) if w > overweight_limit => {
+				// Permanently overweight.
+				Self::deposit_event(Event::<T>::OverweightEnqueued(
+					hash,
+					origin,
+					page_index,
+					message_index,
+				));
+				MessageExecutionStatus::Overweight
+			},
+			Err(Overweight(_)) => {
+				// Temporarily overweight - save progress and stop processing this
+				// queue.
+				MessageExecutionStatus::InsufficientWeight
+			},
+			Err(error) => {
+				// Permanent error - drop
+				Self::deposit_event(Event::<T>::ProcessingFailed(hash, origin, error));
+				MessageExecutionStatus::Unprocessable
+			},
+			Ok((success, weight_used)) => {
+				// Success
+				weight.saturating_accrue(weight_used);
+				Self::deposit_event(Event::<T>::Processed(hash, origin, weight_used, success));
+				MessageExecutionStatus::Processed
+			},
+		}
+	}
+}
+
+impl<T: Config> ServiceQueues for Pallet<T> {
+	type OverweightMessageAddress = (MessageOriginOf<T>, PageIndex, u32);
+
+	fn service_queues(weight_limit: Weight) -> Weight {
+		Self::service_queues_impl(weight_limit, weight_limit)
+	}
+
+	fn execute_overweight(
+		weight_limit: Weight,
+		(message_origin, page, index): Self::OverweightMessageAddress,
+	) -> Result<Weight, ExecuteOverweightError> {
+		Pallet::<T>::do_execute_overweight(message_origin, page, index, weight_limit).map_err(|e| {
+			match e {
+				Error::<T>::InsufficientWeight => ExecuteOverweightError::InsufficientWeight,
+				_ => ExecuteOverweightError::NotFound,
+			}
+		})
+	}
+}
+
+impl<T: Config> EnqueueMessage<MessageOriginOf<T>> for Pallet<T> {
+	type MaxMessageLen = MaxMessageLenOf<T>;
+
+	fn enqueue_message(
+		message: BoundedSlice<u8, Self::MaxMessageLen>,
+		origin: <T::MessageProcessor as ProcessMessage>::Origin,
+	) {
+		Self::do_enqueue_message(&origin, message.into());
+		let book_state = BookStateFor::<T>::get(&origin);
+		T::QueueChangeHandler::on_queue_changed(origin, book_state.message_count, book_state.size);
+	}
+
+	fn enqueue_messages<'a>(
+		messages: impl Iterator<Item = BoundedSlice<'a, u8, Self::MaxMessageLen>>,
+		origin: <T::MessageProcessor as ProcessMessage>::Origin,
+	) {
+		for message in messages {
+			Self::do_enqueue_message(&origin, message.into());
+		}
+		let book_state = BookStateFor::<T>::get(&origin);
+		T::QueueChangeHandler::on_queue_changed(origin, book_state.message_count, book_state.size);
+	}
+
+	fn sweep_queue(origin: MessageOriginOf<T>) {
+		let mut book_state = BookStateFor::<T>::get(&origin);
+		book_state.begin = book_state.end;
+		if let Some(neighbours) = book_state.ready_neighbours.take() {
+			Self::ready_ring_unknit(&origin, neighbours);
+		}
+		BookStateFor::<T>::insert(&origin, &book_state);
+	}
+
+	fn footprint(origin: MessageOriginOf<T>) -> Footprint {
+		let book_state = BookStateFor::<T>::get(&origin);
+		Footprint { count: book_state.message_count, size: book_state.size }
+	}
+}
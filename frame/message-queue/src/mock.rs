@@ -0,0 +1,178 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Message queue test environment.
+
+use super::*;
+
+use crate as pallet_message_queue;
+use frame_support::{
+	parameter_types,
+	traits::{ConstU32, Everything},
+};
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, IdentityLookup},
+};
+use std::{cell::RefCell, collections::BTreeMap};
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		MessageQueue: pallet_message_queue::{Pallet, Call, Storage, Event<T>},
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub BlockWeights: frame_system::limits::BlockWeights =
+		frame_system::limits::BlockWeights::simple_max(1024);
+}
+impl frame_system::Config for Test {
+	type BaseCallFilter = Everything;
+	type BlockWeights = BlockWeights;
+	type BlockLength = ();
+	type DbWeight = ();
+	type Origin = Origin;
+	type Call = Call;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+}
+
+parameter_types! {
+	pub static ServiceWeight: Option<Weight> = Some(100);
+}
+
+impl Config for Test {
+	type Event = Event;
+	type WeightInfo = UnitWeightInfo;
+	type MessageProcessor = RecordingMessageProcessor;
+	type QueueChangeHandler = RecordingQueueChangeHandler;
+	type HeapSize = ConstU32<24>;
+	type ServiceWeight = ServiceWeight;
+}
+
+/// Every servicing step costs one unit of weight, so that message weights dominate in tests.
+pub struct UnitWeightInfo;
+impl WeightInfo for UnitWeightInfo {
+	fn bump_service_head() -> Weight {
+		1
+	}
+	fn service_queue_base() -> Weight {
+		1
+	}
+	fn service_page_base() -> Weight {
+		1
+	}
+	fn service_page_item() -> Weight {
+		1
+	}
+	fn execute_overweight() -> Weight {
+		1
+	}
+}
+
+thread_local! {
+	pub static MESSAGES_PROCESSED: RefCell<Vec<(Vec<u8>, u32)>> = RefCell::new(vec![]);
+	pub static QUEUE_CHANGES: RefCell<BTreeMap<u32, (u64, u64)>> = RefCell::new(BTreeMap::new());
+}
+
+/// Records all processed messages.
+///
+/// A message of the form `weight=N` needs `N` weight to be processed, `badformat` fails with a
+/// permanent error and any other message needs a weight of `1`.
+pub struct RecordingMessageProcessor;
+impl ProcessMessage for RecordingMessageProcessor {
+	type Origin = u32;
+
+	fn process_message(
+		message: &[u8],
+		origin: Self::Origin,
+		weight_limit: Weight,
+	) -> Result<(bool, Weight), ProcessMessageError> {
+		if message == b"badformat" {
+			return Err(ProcessMessageError::BadFormat)
+		}
+		let required = match message.strip_prefix(b"weight=") {
+			Some(w) => std::str::from_utf8(w)
+				.ok()
+				.and_then(|w| w.parse().ok())
+				.ok_or(ProcessMessageError::Corrupt)?,
+			None => 1,
+		};
+		if required > weight_limit {
+			return Err(ProcessMessageError::Overweight(required))
+		}
+		MESSAGES_PROCESSED.with(|m| m.borrow_mut().push((message.to_vec(), origin)));
+		Ok((true, required))
+	}
+}
+
+/// Records the latest footprint reported for every queue.
+pub struct RecordingQueueChangeHandler;
+impl OnQueueChanged<u32> for RecordingQueueChangeHandler {
+	fn on_queue_changed(id: u32, items_count: u64, items_size: u64) {
+		QUEUE_CHANGES.with(|q| q.borrow_mut().insert(id, (items_count, items_size)));
+	}
+}
+
+/// The messages processed so far, in order, along with their origin.
+pub fn messages_processed() -> Vec<(Vec<u8>, u32)> {
+	MESSAGES_PROCESSED.with(|m| m.borrow().clone())
+}
+
+/// The latest footprint reported for `origin`.
+pub fn queue_change(origin: u32) -> Option<(u64, u64)> {
+	QUEUE_CHANGES.with(|q| q.borrow().get(&origin).cloned())
+}
+
+/// Enqueue `message` from `origin`.
+pub fn enqueue(message: &[u8], origin: u32) {
+	MessageQueue::enqueue_message(BoundedSlice::try_from(message).unwrap(), origin);
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	MESSAGES_PROCESSED.with(|m| m.borrow_mut().clear());
+	QUEUE_CHANGES.with(|q| q.borrow_mut().clear());
+	let t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
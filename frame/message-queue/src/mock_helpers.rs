@@ -0,0 +1,47 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Std setup helpers for testing and benchmarking.
+//!
+//! Cannot be put into mock.rs since benchmarks require no-std and mock.rs is std.
+
+use codec::FullCodec;
+use frame_support::{
+	traits::{ProcessMessage, ProcessMessageError},
+	weights::Weight,
+};
+use scale_info::TypeInfo;
+use sp_std::{fmt::Debug, marker::PhantomData};
+
+/// A message processor which accepts every message and uses no weight to process it.
+///
+/// Only meant for runtimes which have no real consumer of messages yet.
+pub struct NoopMessageProcessor<Origin>(PhantomData<Origin>);
+impl<Origin> ProcessMessage for NoopMessageProcessor<Origin>
+where
+	Origin: FullCodec + Clone + Eq + PartialEq + TypeInfo + Debug,
+{
+	type Origin = Origin;
+
+	fn process_message(
+		_message: &[u8],
+		_origin: Self::Origin,
+		_weight_limit: Weight,
+	) -> Result<(bool, Weight), ProcessMessageError> {
+		Ok((true, 0))
+	}
+}
@@ -0,0 +1,251 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Message queue pallet tests.
+
+use super::*;
+use crate::mock::*;
+
+use frame_support::{assert_noop, assert_ok, traits::Hooks};
+use sp_core::H256;
+use sp_runtime::traits::BlakeTwo256;
+
+fn msg(x: &str) -> Vec<u8> {
+	x.as_bytes().to_vec()
+}
+
+fn processed(x: &str, origin: u32) -> (Vec<u8>, u32) {
+	(msg(x), origin)
+}
+
+fn hashed(x: &str) -> H256 {
+	BlakeTwo256::hash(x.as_bytes())
+}
+
+#[test]
+fn enqueue_within_one_page_works() {
+	new_test_ext().execute_with(|| {
+		enqueue(b"a", 0);
+		enqueue(b"b", 0);
+		assert_eq!(Pages::<Test>::iter().count(), 1);
+		assert_eq!(MessageQueue::footprint(0), Footprint { count: 2, size: 2 });
+		assert_eq!(queue_change(0), Some((2, 2)));
+		assert_eq!(ServiceHead::<Test>::get(), Some(0));
+
+		MessageQueue::on_initialize(1);
+		assert_eq!(messages_processed(), vec![processed("a", 0), processed("b", 0)]);
+		System::assert_has_event(mock::Event::MessageQueue(crate::Event::Processed(
+			hashed("b"),
+			0,
+			1,
+			true,
+		)));
+		// The page is gone and the queue is not ready anymore.
+		assert_eq!(Pages::<Test>::iter().count(), 0);
+		assert_eq!(ServiceHead::<Test>::get(), None);
+		assert_eq!(MessageQueue::footprint(0), Footprint::default());
+		assert_eq!(queue_change(0), Some((0, 0)));
+	});
+}
+
+#[test]
+fn enqueue_spanning_pages_works() {
+	new_test_ext().execute_with(|| {
+		// Each page fits four one-byte messages.
+		for m in ["a", "b", "c", "d", "e"] {
+			enqueue(m.as_bytes(), 0);
+		}
+		assert_eq!(Pages::<Test>::iter().count(), 2);
+		let book = BookStateFor::<Test>::get(0);
+		assert_eq!((book.begin, book.end, book.count), (0, 2, 2));
+
+		MessageQueue::on_initialize(1);
+		assert_eq!(messages_processed().len(), 5);
+		assert_eq!(messages_processed()[4], processed("e", 0));
+		assert_eq!(Pages::<Test>::iter().count(), 0);
+		let book = BookStateFor::<Test>::get(0);
+		assert_eq!((book.begin, book.end, book.count), (2, 2, 0));
+	});
+}
+
+#[test]
+fn service_queues_rotates_through_origins() {
+	new_test_ext().execute_with(|| {
+		enqueue(b"a", 0);
+		enqueue(b"b", 1);
+		enqueue(b"c", 2);
+
+		// Just enough weight to service a single queue with one message.
+		let weight = 5;
+		assert_eq!(MessageQueue::service_queues(weight), weight);
+		assert_eq!(messages_processed(), vec![processed("a", 0)]);
+		assert_eq!(ServiceHead::<Test>::get(), Some(1));
+
+		MessageQueue::service_queues(weight);
+		MessageQueue::service_queues(weight);
+		assert_eq!(
+			messages_processed(),
+			vec![processed("a", 0), processed("b", 1), processed("c", 2)]
+		);
+		assert_eq!(ServiceHead::<Test>::get(), None);
+		// Nothing left to do.
+		assert_eq!(MessageQueue::service_queues(weight), 1);
+	});
+}
+
+#[test]
+fn permanently_overweight_message_is_skipped() {
+	new_test_ext().execute_with(|| {
+		enqueue(b"weight=200", 0);
+		enqueue(b"a", 0);
+
+		MessageQueue::on_initialize(1);
+		assert_eq!(messages_processed(), vec![processed("a", 0)]);
+		System::assert_has_event(mock::Event::MessageQueue(crate::Event::OverweightEnqueued(
+			hashed("weight=200"),
+			0,
+			0,
+			0,
+		)));
+		// The page with the overweight message is kept, but the queue is not ready anymore.
+		assert_eq!(Pages::<Test>::iter().count(), 1);
+		assert_eq!(ServiceHead::<Test>::get(), None);
+		assert_eq!(MessageQueue::footprint(0), Footprint { count: 1, size: 10 });
+
+		// It is not serviced again.
+		MessageQueue::on_initialize(2);
+		assert_eq!(messages_processed().len(), 1);
+	});
+}
+
+#[test]
+fn execute_overweight_works() {
+	new_test_ext().execute_with(|| {
+		enqueue(b"weight=200", 0);
+		MessageQueue::on_initialize(1);
+		assert_eq!(Pages::<Test>::iter().count(), 1);
+
+		assert_noop!(
+			MessageQueue::execute_overweight(Origin::signed(1), 0, 0, 0, 199),
+			Error::<Test>::InsufficientWeight
+		);
+		assert_noop!(
+			MessageQueue::execute_overweight(Origin::signed(1), 0, 0, 1, 200),
+			Error::<Test>::NoMessage
+		);
+		assert_noop!(
+			MessageQueue::execute_overweight(Origin::signed(1), 0, 1, 0, 200),
+			Error::<Test>::NoPage
+		);
+
+		assert_ok!(MessageQueue::execute_overweight(Origin::signed(1), 0, 0, 0, 200));
+		assert_eq!(messages_processed(), vec![processed("weight=200", 0)]);
+		assert_eq!(Pages::<Test>::iter().count(), 0);
+		assert_eq!(MessageQueue::footprint(0), Footprint::default());
+
+		assert_noop!(
+			MessageQueue::execute_overweight(Origin::signed(1), 0, 0, 0, 200),
+			Error::<Test>::NoPage
+		);
+	});
+}
+
+#[test]
+fn execute_overweight_rejects_queued_and_processed_messages() {
+	new_test_ext().execute_with(|| {
+		enqueue(b"weight=200", 0);
+		enqueue(b"a", 0);
+		// `weight=200` is still queued for regular servicing.
+		assert_noop!(
+			MessageQueue::execute_overweight(Origin::signed(1), 0, 0, 0, 200),
+			Error::<Test>::Queued
+		);
+
+		enqueue(b"b", 0);
+		MessageQueue::on_initialize(1);
+		// Page 0 is kept since it has the overweight message, `a` was already processed.
+		assert_noop!(
+			MessageQueue::execute_overweight(Origin::signed(1), 0, 0, 1, 200),
+			Error::<Test>::AlreadyProcessed
+		);
+	});
+}
+
+#[test]
+fn temporarily_overweight_message_waits_for_more_weight() {
+	new_test_ext().execute_with(|| {
+		enqueue(b"weight=50", 0);
+
+		// Not enough weight left in the block, but it fits into the regular servicing.
+		MessageQueue::on_idle(1, 30);
+		assert!(messages_processed().is_empty());
+		assert_eq!(ServiceHead::<Test>::get(), Some(0));
+
+		MessageQueue::on_initialize(2);
+		assert_eq!(messages_processed(), vec![processed("weight=50", 0)]);
+	});
+}
+
+#[test]
+fn on_idle_services_queues() {
+	new_test_ext().execute_with(|| {
+		ServiceWeight::set(None);
+		enqueue(b"a", 0);
+
+		assert_eq!(MessageQueue::on_initialize(1), 0);
+		assert!(messages_processed().is_empty());
+
+		MessageQueue::on_idle(1, 100);
+		assert_eq!(messages_processed(), vec![processed("a", 0)]);
+	});
+}
+
+#[test]
+fn unprocessable_message_is_dropped() {
+	new_test_ext().execute_with(|| {
+		enqueue(b"badformat", 0);
+		enqueue(b"a", 0);
+
+		MessageQueue::on_initialize(1);
+		assert_eq!(messages_processed(), vec![processed("a", 0)]);
+		System::assert_has_event(mock::Event::MessageQueue(crate::Event::ProcessingFailed(
+			hashed("badformat"),
+			0,
+			ProcessMessageError::BadFormat,
+		)));
+		assert_eq!(Pages::<Test>::iter().count(), 0);
+		assert_eq!(MessageQueue::footprint(0), Footprint::default());
+	});
+}
+
+#[test]
+fn sweep_queue_stops_servicing() {
+	new_test_ext().execute_with(|| {
+		enqueue(b"a", 0);
+		enqueue(b"b", 1);
+
+		MessageQueue::sweep_queue(0);
+		assert_eq!(ServiceHead::<Test>::get(), Some(1));
+		MessageQueue::on_initialize(1);
+		assert_eq!(messages_processed(), vec![processed("b", 1)]);
+
+		// Enqueueing again makes the queue ready.
+		enqueue(b"c", 0);
+		MessageQueue::on_initialize(2);
+		assert_eq!(messages_processed(), vec![processed("b", 1), processed("c", 0)]);
+	});
+}
@@ -0,0 +1,637 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Nfts pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use enumflags2::BitFlags;
+use frame_benchmarking::{
+	account, benchmarks_instance_pallet, impl_benchmark_test_suite, whitelist_account,
+	whitelisted_caller,
+};
+use frame_support::{
+	assert_ok,
+	dispatch::UnfilteredDispatchable,
+	traits::{EnsureOrigin, Get},
+	BoundedVec,
+};
+use frame_system::RawOrigin as SystemOrigin;
+use sp_runtime::traits::{Bounded, One};
+use sp_std::{convert::TryInto, prelude::*};
+
+use crate::Pallet as Nfts;
+
+const SEED: u32 = 0;
+
+fn create_collection<T: Config<I>, I: 'static>(
+) -> (T::CollectionId, T::AccountId, <T::Lookup as StaticLookup>::Source) {
+	let caller: T::AccountId = whitelisted_caller();
+	let caller_lookup = T::Lookup::unlookup(caller.clone());
+	let collection = Default::default();
+	T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
+	assert_ok!(Nfts::<T, I>::force_create(
+		SystemOrigin::Root.into(),
+		collection,
+		caller_lookup.clone(),
+		default_collection_config::<T, I>()
+	));
+	(collection, caller, caller_lookup)
+}
+
+fn add_collection_metadata<T: Config<I>, I: 'static>(
+) -> (T::AccountId, <T::Lookup as StaticLookup>::Source) {
+	let caller = Collection::<T, I>::get(T::CollectionId::default()).unwrap().owner;
+	if caller != whitelisted_caller() {
+		whitelist_account!(caller);
+	}
+	let caller_lookup = T::Lookup::unlookup(caller.clone());
+	assert_ok!(Nfts::<T, I>::set_collection_metadata(
+		SystemOrigin::Signed(caller.clone()).into(),
+		Default::default(),
+		vec![0; T::StringLimit::get() as usize].try_into().unwrap(),
+	));
+	(caller, caller_lookup)
+}
+
+fn mint_item<T: Config<I>, I: 'static>(
+	index: u16,
+) -> (T::ItemId, T::AccountId, <T::Lookup as StaticLookup>::Source) {
+	let caller = Collection::<T, I>::get(T::CollectionId::default()).unwrap().owner;
+	if caller != whitelisted_caller() {
+		whitelist_account!(caller);
+	}
+	let caller_lookup = T::Lookup::unlookup(caller.clone());
+	let item = index.into();
+	assert_ok!(Nfts::<T, I>::mint(
+		SystemOrigin::Signed(caller.clone()).into(),
+		Default::default(),
+		item,
+		caller_lookup.clone(),
+		None,
+	));
+	(item, caller, caller_lookup)
+}
+
+fn add_item_metadata<T: Config<I>, I: 'static>(
+	item: T::ItemId,
+) -> (T::AccountId, <T::Lookup as StaticLookup>::Source) {
+	let caller = Collection::<T, I>::get(T::CollectionId::default()).unwrap().owner;
+	if caller != whitelisted_caller() {
+		whitelist_account!(caller);
+	}
+	let caller_lookup = T::Lookup::unlookup(caller.clone());
+	assert_ok!(Nfts::<T, I>::set_metadata(
+		SystemOrigin::Signed(caller.clone()).into(),
+		Default::default(),
+		item,
+		vec![0; T::StringLimit::get() as usize].try_into().unwrap(),
+	));
+	(caller, caller_lookup)
+}
+
+fn add_item_attribute<T: Config<I>, I: 'static>(
+	item: T::ItemId,
+) -> (BoundedVec<u8, T::KeyLimit>, T::AccountId, <T::Lookup as StaticLookup>::Source) {
+	let caller = Collection::<T, I>::get(T::CollectionId::default()).unwrap().owner;
+	if caller != whitelisted_caller() {
+		whitelist_account!(caller);
+	}
+	let caller_lookup = T::Lookup::unlookup(caller.clone());
+	let key: BoundedVec<_, _> = vec![0; T::KeyLimit::get() as usize].try_into().unwrap();
+	assert_ok!(Nfts::<T, I>::set_attribute(
+		SystemOrigin::Signed(caller.clone()).into(),
+		Default::default(),
+		Some(item),
+		key.clone(),
+		vec![0; T::ValueLimit::get() as usize].try_into().unwrap(),
+	));
+	(key, caller, caller_lookup)
+}
+
+fn assert_last_event<T: Config<I>, I: 'static>(generic_event: <T as Config<I>>::Event) {
+	let events = frame_system::Pallet::<T>::events();
+	let system_event: <T as frame_system::Config>::Event = generic_event.into();
+	// compare to the last event record
+	let frame_system::EventRecord { event, .. } = &events[events.len() - 1];
+	assert_eq!(event, &system_event);
+}
+
+fn make_collection_config<T: Config<I>, I: 'static>(
+	disable_settings: BitFlags<CollectionSetting>,
+) -> CollectionConfigFor<T, I> {
+	CollectionConfig {
+		settings: CollectionSettings::from_disabled(disable_settings),
+		max_supply: None,
+		mint_settings: MintSettings::default(),
+	}
+}
+
+fn default_collection_config<T: Config<I>, I: 'static>() -> CollectionConfigFor<T, I> {
+	make_collection_config::<T, I>(BitFlags::empty())
+}
+
+fn default_item_config() -> ItemConfig {
+	ItemConfig { settings: ItemSettings::all_enabled() }
+}
+
+benchmarks_instance_pallet! {
+	create {
+		let collection = T::CollectionId::default();
+		let origin = SystemOrigin::Signed(whitelisted_caller()).into();
+		let caller: T::AccountId = whitelisted_caller();
+		let admin = T::Lookup::unlookup(caller.clone());
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
+		let call = Call::<T, I>::create {
+			collection,
+			admin,
+			config: default_collection_config::<T, I>(),
+		};
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_last_event::<T, I>(Event::Created(collection, caller.clone(), caller).into());
+	}
+
+	force_create {
+		let caller: T::AccountId = whitelisted_caller();
+		let caller_lookup = T::Lookup::unlookup(caller.clone());
+	}: _(SystemOrigin::Root, Default::default(), caller_lookup, default_collection_config::<T, I>())
+	verify {
+		assert_last_event::<T, I>(Event::ForceCreated(Default::default(), caller).into());
+	}
+
+	destroy {
+		let n in 0 .. 1_000;
+		let m in 0 .. 1_000;
+		let a in 0 .. 1_000;
+
+		let (collection, caller, caller_lookup) = create_collection::<T, I>();
+		add_collection_metadata::<T, I>();
+		// Item metadata can only be set for minted items.
+		for i in 0..n.max(m) {
+			mint_item::<T, I>(i as u16);
+		}
+		for i in 0..m {
+			add_item_metadata::<T, I>((i as u16).into());
+		}
+		for i in 0..a {
+			add_item_attribute::<T, I>((i as u16).into());
+		}
+		let witness = Collection::<T, I>::get(collection).unwrap().destroy_witness();
+	}: _(SystemOrigin::Signed(caller), collection, witness)
+	verify {
+		assert_last_event::<T, I>(Event::Destroyed(collection).into());
+	}
+
+	mint {
+		let (collection, caller, caller_lookup) = create_collection::<T, I>();
+		let item = T::ItemId::default();
+	}: _(SystemOrigin::Signed(caller.clone()), collection, item, caller_lookup, None)
+	verify {
+		assert_last_event::<T, I>(Event::Issued(collection, item, caller).into());
+	}
+
+	force_mint {
+		let (collection, caller, caller_lookup) = create_collection::<T, I>();
+		let item = T::ItemId::default();
+	}: _(SystemOrigin::Signed(caller.clone()), collection, item, caller_lookup, default_item_config())
+	verify {
+		assert_last_event::<T, I>(Event::Issued(collection, item, caller).into());
+	}
+
+	burn {
+		let (collection, caller, caller_lookup) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+	}: _(SystemOrigin::Signed(caller.clone()), collection, item)
+	verify {
+		assert_last_event::<T, I>(Event::Burned(collection, item, caller).into());
+	}
+
+	transfer {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+	}: _(SystemOrigin::Signed(caller.clone()), collection, item, target_lookup)
+	verify {
+		assert_last_event::<T, I>(Event::Transferred(collection, item, caller, target).into());
+	}
+
+	lock_item_transfer {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+	}: _(SystemOrigin::Signed(caller.clone()), collection, item)
+	verify {
+		assert_last_event::<T, I>(Event::ItemTransferLocked(collection, item).into());
+	}
+
+	unlock_item_transfer {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		Nfts::<T, I>::lock_item_transfer(
+			SystemOrigin::Signed(caller.clone()).into(),
+			collection,
+			item,
+		)?;
+	}: _(SystemOrigin::Signed(caller.clone()), collection, item)
+	verify {
+		assert_last_event::<T, I>(Event::ItemTransferUnlocked(collection, item).into());
+	}
+
+	lock_collection {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let lock_settings = CollectionSettings::from_disabled(
+			CollectionSetting::TransferableItems |
+				CollectionSetting::UnlockedMetadata |
+				CollectionSetting::UnlockedAttributes |
+				CollectionSetting::UnlockedMaxSupply,
+		);
+	}: _(SystemOrigin::Signed(caller.clone()), collection, lock_settings)
+	verify {
+		assert_last_event::<T, I>(Event::CollectionLocked(collection).into());
+	}
+
+	transfer_ownership {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+		T::Currency::make_free_balance_be(&target, T::Currency::minimum_balance());
+	}: _(SystemOrigin::Signed(caller), collection, target_lookup)
+	verify {
+		assert_last_event::<T, I>(Event::OwnerChanged(collection, target).into());
+	}
+
+	set_team {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let target0 = T::Lookup::unlookup(account("target", 0, SEED));
+		let target1 = T::Lookup::unlookup(account("target", 1, SEED));
+		let target2 = T::Lookup::unlookup(account("target", 2, SEED));
+	}: _(SystemOrigin::Signed(caller), collection, target0, target1, target2)
+	verify {
+		assert_last_event::<T, I>(Event::TeamChanged(
+			collection,
+			account("target", 0, SEED),
+			account("target", 1, SEED),
+			account("target", 2, SEED),
+		).into());
+	}
+
+	force_collection_config {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let origin = T::ForceOrigin::successful_origin();
+		let call = Call::<T, I>::force_collection_config {
+			collection,
+			config: make_collection_config::<T, I>(CollectionSetting::DepositRequired.into()),
+		};
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_last_event::<T, I>(Event::CollectionConfigChanged(collection).into());
+	}
+
+	lock_item_properties {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		let lock_metadata = true;
+		let lock_attributes = true;
+	}: _(SystemOrigin::Signed(caller), collection, item, lock_metadata, lock_attributes)
+	verify {
+		assert_last_event::<T, I>(
+			Event::ItemPropertiesLocked(collection, item, lock_metadata, lock_attributes).into()
+		);
+	}
+
+	set_attribute {
+		let key: BoundedVec<_, _> = vec![0u8; T::KeyLimit::get() as usize].try_into().unwrap();
+		let value: BoundedVec<_, _> = vec![0u8; T::ValueLimit::get() as usize].try_into().unwrap();
+
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		add_item_metadata::<T, I>(item);
+	}: _(SystemOrigin::Signed(caller), collection, Some(item), key.clone(), value.clone())
+	verify {
+		assert_last_event::<T, I>(Event::AttributeSet(collection, Some(item), key, value).into());
+	}
+
+	clear_attribute {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		add_item_metadata::<T, I>(item);
+		let (key, ..) = add_item_attribute::<T, I>(item);
+	}: _(SystemOrigin::Signed(caller), collection, Some(item), key.clone())
+	verify {
+		assert_last_event::<T, I>(Event::AttributeCleared(collection, Some(item), key).into());
+	}
+
+	set_metadata {
+		let data: BoundedVec<_, _> = vec![0u8; T::StringLimit::get() as usize].try_into().unwrap();
+
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+	}: _(SystemOrigin::Signed(caller), collection, item, data.clone())
+	verify {
+		assert_last_event::<T, I>(Event::ItemMetadataSet(collection, item, data).into());
+	}
+
+	clear_metadata {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		add_item_metadata::<T, I>(item);
+	}: _(SystemOrigin::Signed(caller), collection, item)
+	verify {
+		assert_last_event::<T, I>(Event::ItemMetadataCleared(collection, item).into());
+	}
+
+	set_collection_metadata {
+		let data: BoundedVec<_, _> = vec![0u8; T::StringLimit::get() as usize].try_into().unwrap();
+
+		let (collection, caller, _) = create_collection::<T, I>();
+	}: _(SystemOrigin::Signed(caller), collection, data.clone())
+	verify {
+		assert_last_event::<T, I>(Event::CollectionMetadataSet(collection, data).into());
+	}
+
+	clear_collection_metadata {
+		let (collection, caller, _) = create_collection::<T, I>();
+		add_collection_metadata::<T, I>();
+	}: _(SystemOrigin::Signed(caller), collection)
+	verify {
+		assert_last_event::<T, I>(Event::CollectionMetadataCleared(collection).into());
+	}
+
+	approve_transfer {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		let delegate: T::AccountId = account("delegate", 0, SEED);
+		let delegate_lookup = T::Lookup::unlookup(delegate.clone());
+	}: _(SystemOrigin::Signed(caller.clone()), collection, item, delegate_lookup)
+	verify {
+		assert_last_event::<T, I>(Event::TransferApproved(collection, item, caller, delegate).into());
+	}
+
+	cancel_approval {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		let delegate: T::AccountId = account("delegate", 0, SEED);
+		let delegate_lookup = T::Lookup::unlookup(delegate.clone());
+		let origin = SystemOrigin::Signed(caller.clone()).into();
+		Nfts::<T, I>::approve_transfer(origin, collection, item, delegate_lookup.clone())?;
+	}: _(SystemOrigin::Signed(caller.clone()), collection, item, Some(delegate_lookup))
+	verify {
+		assert_last_event::<T, I>(Event::ApprovalCancelled(collection, item, caller, delegate).into());
+	}
+
+	set_collection_max_supply {
+		let (collection, caller, _) = create_collection::<T, I>();
+	}: _(SystemOrigin::Signed(caller.clone()), collection, u32::MAX)
+	verify {
+		assert_last_event::<T, I>(Event::CollectionMaxSupplySet(collection, u32::MAX).into());
+	}
+
+	update_mint_settings {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let mint_settings = MintSettings {
+			mint_type: MintType::HolderOf(T::CollectionId::default()),
+			start_block: Some(One::one()),
+			end_block: Some(One::one()),
+			price: Some(ItemPrice::<T, I>::from(1u32)),
+			default_item_settings: ItemSettings::all_enabled(),
+		};
+	}: _(SystemOrigin::Signed(caller.clone()), collection, mint_settings)
+	verify {
+		assert_last_event::<T, I>(Event::CollectionMintSettingsUpdated(collection).into());
+	}
+
+	set_price {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		let delegate: T::AccountId = account("delegate", 0, SEED);
+		let delegate_lookup = T::Lookup::unlookup(delegate.clone());
+		let price = ItemPrice::<T, I>::from(100u32);
+	}: _(SystemOrigin::Signed(caller.clone()), collection, item, Some(price), Some(delegate_lookup))
+	verify {
+		assert_last_event::<T, I>(Event::ItemPriceSet(collection, item, price, Some(delegate)).into());
+	}
+
+	buy_item {
+		let (collection, seller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		let buyer: T::AccountId = account("buyer", 0, SEED);
+		let buyer_lookup = T::Lookup::unlookup(buyer.clone());
+		let price = ItemPrice::<T, I>::from(0u32);
+		let origin = SystemOrigin::Signed(seller.clone()).into();
+		Nfts::<T, I>::set_price(origin, collection, item, Some(price), Some(buyer_lookup))?;
+		T::Currency::make_free_balance_be(&buyer, DepositBalanceOf::<T, I>::max_value());
+	}: _(SystemOrigin::Signed(buyer.clone()), collection, item, price)
+	verify {
+		assert_last_event::<T, I>(Event::ItemBought(collection, item, price, seller, buyer).into());
+	}
+
+	create_swap {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item1, ..) = mint_item::<T, I>(0);
+		let (item2, ..) = mint_item::<T, I>(1);
+		let price = ItemPrice::<T, I>::from(100u32);
+		let price_direction = PriceDirection::Receive;
+		let price_with_direction = PriceWithDirection { amount: price, direction: price_direction };
+		let duration = T::MaxDeadlineDuration::get();
+		frame_system::Pallet::<T>::set_block_number(One::one());
+	}: _(
+		SystemOrigin::Signed(caller.clone()),
+		collection,
+		item1,
+		collection,
+		Some(item2),
+		Some(price_with_direction.clone()),
+		duration
+	)
+	verify {
+		let current_block = frame_system::Pallet::<T>::block_number();
+		assert_last_event::<T, I>(Event::SwapCreated(
+			collection,
+			item1,
+			collection,
+			Some(item2),
+			Some(price_with_direction),
+			duration.saturating_add(current_block),
+		).into());
+	}
+
+	cancel_swap {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item1, ..) = mint_item::<T, I>(0);
+		let (item2, ..) = mint_item::<T, I>(1);
+		let price = ItemPrice::<T, I>::from(100u32);
+		let origin = SystemOrigin::Signed(caller.clone()).into();
+		let duration = T::MaxDeadlineDuration::get();
+		let price_direction = PriceDirection::Receive;
+		let price_with_direction = PriceWithDirection { amount: price, direction: price_direction };
+		frame_system::Pallet::<T>::set_block_number(One::one());
+		Nfts::<T, I>::create_swap(
+			origin,
+			collection,
+			item1,
+			collection,
+			Some(item2),
+			Some(price_with_direction),
+			duration,
+		)?;
+	}: _(SystemOrigin::Signed(caller.clone()), collection, item1)
+	verify {
+		assert_last_event::<T, I>(Event::SwapCancelled(collection, item1).into());
+	}
+
+	claim_swap {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item1, ..) = mint_item::<T, I>(0);
+		let (item2, ..) = mint_item::<T, I>(1);
+		let price = ItemPrice::<T, I>::from(0u32);
+		let duration = T::MaxDeadlineDuration::get();
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+		T::Currency::make_free_balance_be(&target, DepositBalanceOf::<T, I>::max_value());
+		let origin = SystemOrigin::Signed(caller.clone());
+		frame_system::Pallet::<T>::set_block_number(One::one());
+		Nfts::<T, I>::transfer(origin.clone().into(), collection, item2, target_lookup)?;
+		let price_direction = PriceDirection::Receive;
+		let price_with_direction = PriceWithDirection { amount: price, direction: price_direction };
+		Nfts::<T, I>::create_swap(
+			origin.clone().into(),
+			collection,
+			item1,
+			collection,
+			Some(item2),
+			Some(price_with_direction.clone()),
+			duration,
+		)?;
+	}: _(
+		SystemOrigin::Signed(target.clone()),
+		collection,
+		item2,
+		collection,
+		item1,
+		Some(price_with_direction.clone())
+	)
+	verify {
+		assert_last_event::<T, I>(Event::SwapClaimed(
+			collection,
+			item2,
+			target,
+			collection,
+			item1,
+			caller,
+			Some(price_with_direction),
+		).into());
+	}
+
+	mint_pre_signed {
+		let n in 0 .. T::MaxAttributesPerCall::get();
+		let (caller_public, caller) = T::Helper::signer();
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
+		let caller_lookup = T::Lookup::unlookup(caller.clone());
+
+		let collection = T::CollectionId::default();
+		assert_ok!(Nfts::<T, I>::force_create(
+			SystemOrigin::Root.into(),
+			collection,
+			caller_lookup.clone(),
+			default_collection_config::<T, I>()
+		));
+
+		let metadata = vec![0u8; T::StringLimit::get() as usize];
+		let mut attributes = vec![];
+		let attribute_value = vec![0u8; T::ValueLimit::get() as usize];
+		for i in 0..n {
+			let attribute_key = i.encode();
+			attributes.push((attribute_key, attribute_value.clone()));
+		}
+		let mint_data = PreSignedMint {
+			collection,
+			item: T::ItemId::default(),
+			attributes,
+			metadata: metadata.clone(),
+			only_account: None,
+			deadline: One::one(),
+		};
+		let message = Encode::encode(&mint_data);
+		let signature = T::Helper::sign(&caller_public, &message);
+
+		let target: T::AccountId = account("target", 0, SEED);
+		T::Currency::make_free_balance_be(&target, DepositBalanceOf::<T, I>::max_value());
+		frame_system::Pallet::<T>::set_block_number(One::one());
+	}: _(SystemOrigin::Signed(target.clone()), Box::new(mint_data), signature, caller)
+	verify {
+		let metadata: BoundedVec<_, _> = metadata.try_into().unwrap();
+		assert_last_event::<T, I>(
+			Event::ItemMetadataSet(collection, T::ItemId::default(), metadata).into()
+		);
+	}
+
+	set_attributes_pre_signed {
+		let n in 0 .. T::MaxAttributesPerCall::get();
+		let (collection, ..) = create_collection::<T, I>();
+
+		let item_owner: T::AccountId = account("item_owner", 0, SEED);
+		let item_owner_lookup = T::Lookup::unlookup(item_owner.clone());
+
+		let (signer_public, signer) = T::Helper::signer();
+
+		T::Currency::make_free_balance_be(&item_owner, DepositBalanceOf::<T, I>::max_value());
+
+		let item = T::ItemId::default();
+		assert_ok!(Nfts::<T, I>::force_mint(
+			SystemOrigin::Root.into(),
+			collection,
+			item,
+			item_owner_lookup.clone(),
+			default_item_config(),
+		));
+
+		let caller = Collection::<T, I>::get(collection).unwrap().owner;
+		let signer_lookup = T::Lookup::unlookup(signer.clone());
+		assert_ok!(Nfts::<T, I>::set_team(
+			SystemOrigin::Signed(caller).into(),
+			collection,
+			signer_lookup.clone(),
+			signer_lookup.clone(),
+			signer_lookup,
+		));
+
+		let mut attributes = vec![];
+		let attribute_value = vec![0u8; T::ValueLimit::get() as usize];
+		for i in 0..n {
+			let attribute_key = i.encode();
+			attributes.push((attribute_key, attribute_value.clone()));
+		}
+		let pre_signed_data = PreSignedAttributes {
+			collection,
+			item,
+			attributes,
+			deadline: One::one(),
+		};
+		let message = Encode::encode(&pre_signed_data);
+		let signature = T::Helper::sign(&signer_public, &message);
+
+		frame_system::Pallet::<T>::set_block_number(One::one());
+	}: _(SystemOrigin::Signed(item_owner.clone()), pre_signed_data, signature, signer.clone())
+	verify {
+		assert_last_event::<T, I>(Event::PreSignedAttributesSet(collection, item, signer).into());
+	}
+}
+
+impl_benchmark_test_suite!(Nfts, crate::mock::new_test_ext(), crate::mock::Test);
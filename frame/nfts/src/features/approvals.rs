@@ -0,0 +1,74 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::*;
+use frame_support::pallet_prelude::*;
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	pub(crate) fn do_approve_transfer(
+		maybe_check_origin: Option<T::AccountId>,
+		collection: T::CollectionId,
+		item: T::ItemId,
+		delegate: T::AccountId,
+	) -> DispatchResult {
+		let mut details =
+			Item::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::UnknownItem)?;
+
+		let collection_config = Self::get_collection_config(&collection)?;
+		ensure!(
+			collection_config.is_setting_enabled(CollectionSetting::TransferableItems),
+			Error::<T, I>::ItemsNonTransferable
+		);
+
+		if let Some(check_origin) = maybe_check_origin {
+			let is_admin = Self::has_role(&collection, &check_origin, CollectionRole::Admin);
+			let permitted = is_admin || check_origin == details.owner;
+			ensure!(permitted, Error::<T, I>::NoPermission);
+		}
+
+		details.approved = Some(delegate.clone());
+		Item::<T, I>::insert(&collection, &item, &details);
+
+		Self::deposit_event(Event::TransferApproved(collection, item, details.owner, delegate));
+		Ok(())
+	}
+
+	pub(crate) fn do_cancel_approval(
+		maybe_check_origin: Option<T::AccountId>,
+		collection: T::CollectionId,
+		item: T::ItemId,
+		maybe_check_delegate: Option<T::AccountId>,
+	) -> DispatchResult {
+		let mut details =
+			Item::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::UnknownItem)?;
+
+		if let Some(check_origin) = maybe_check_origin {
+			let is_admin = Self::has_role(&collection, &check_origin, CollectionRole::Admin);
+			let permitted = is_admin || check_origin == details.owner;
+			ensure!(permitted, Error::<T, I>::NoPermission);
+		}
+
+		let old = details.approved.take().ok_or(Error::<T, I>::NoDelegate)?;
+		if let Some(check_delegate) = maybe_check_delegate {
+			ensure!(check_delegate == old, Error::<T, I>::WrongDelegate);
+		}
+
+		Item::<T, I>::insert(&collection, &item, &details);
+		Self::deposit_event(Event::ApprovalCancelled(collection, item, details.owner, old));
+		Ok(())
+	}
+}
@@ -0,0 +1,176 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::*;
+use frame_support::pallet_prelude::*;
+use sp_std::convert::TryFrom;
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Set an attribute, reserving the deposit from `maybe_depositor`.
+	///
+	/// When `maybe_depositor` is `None` no deposit is taken.
+	pub(crate) fn do_set_attribute(
+		collection: T::CollectionId,
+		maybe_item: Option<T::ItemId>,
+		key: BoundedVec<u8, T::KeyLimit>,
+		value: BoundedVec<u8, T::ValueLimit>,
+		maybe_depositor: Option<T::AccountId>,
+	) -> DispatchResult {
+		let mut collection_details =
+			Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
+		let collection_config = Self::get_collection_config(&collection)?;
+		Self::ensure_attributes_unlocked(&collection, maybe_item, &collection_config)?;
+
+		let attribute = Attribute::<T, I>::get((collection, maybe_item, &key));
+		if attribute.is_none() {
+			collection_details.attributes.saturating_inc();
+		}
+
+		let deposit_required =
+			collection_config.is_setting_enabled(CollectionSetting::DepositRequired);
+		let (deposit_account, deposit_amount) = match maybe_depositor {
+			Some(depositor) if deposit_required => {
+				let amount = T::DepositPerByte::get()
+					.saturating_mul(((key.len() + value.len()) as u32).into())
+					.saturating_add(T::AttributeDepositBase::get());
+				(depositor, amount)
+			},
+			Some(depositor) => (depositor, Zero::zero()),
+			None => (collection_details.owner.clone(), Zero::zero()),
+		};
+
+		// Release the old deposit to whoever has paid it and reserve the new one.
+		let old_deposit = attribute.map(|(_, deposit)| deposit);
+		match old_deposit {
+			Some(old) if old.account == deposit_account =>
+				if deposit_amount > old.amount {
+					T::Currency::reserve(&deposit_account, deposit_amount - old.amount)?;
+				} else if deposit_amount < old.amount {
+					T::Currency::unreserve(&deposit_account, old.amount - deposit_amount);
+				},
+			Some(old) => {
+				T::Currency::reserve(&deposit_account, deposit_amount)?;
+				T::Currency::unreserve(&old.account, old.amount);
+			},
+			None => T::Currency::reserve(&deposit_account, deposit_amount)?,
+		}
+
+		let deposit = AccountDeposit { account: deposit_account, amount: deposit_amount };
+		Attribute::<T, I>::insert((&collection, maybe_item, &key), (&value, deposit));
+		Collection::<T, I>::insert(collection, &collection_details);
+		Self::deposit_event(Event::AttributeSet(collection, maybe_item, key, value));
+		Ok(())
+	}
+
+	pub(crate) fn do_clear_attribute(
+		maybe_check_origin: Option<T::AccountId>,
+		collection: T::CollectionId,
+		maybe_item: Option<T::ItemId>,
+		key: BoundedVec<u8, T::KeyLimit>,
+	) -> DispatchResult {
+		if let Some(check_origin) = &maybe_check_origin {
+			ensure!(
+				Self::has_role(&collection, check_origin, CollectionRole::Admin),
+				Error::<T, I>::NoPermission
+			);
+		}
+
+		let mut collection_details =
+			Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
+		let collection_config = Self::get_collection_config(&collection)?;
+		// Only the `ForceOrigin` may clear locked attributes.
+		if maybe_check_origin.is_some() {
+			Self::ensure_attributes_unlocked(&collection, maybe_item, &collection_config)?;
+		}
+
+		if let Some((_, deposit)) = Attribute::<T, I>::take((collection, maybe_item, &key)) {
+			collection_details.attributes.saturating_dec();
+			T::Currency::unreserve(&deposit.account, deposit.amount);
+			Collection::<T, I>::insert(collection, &collection_details);
+			Self::deposit_event(Event::AttributeCleared(collection, maybe_item, key));
+		}
+		Ok(())
+	}
+
+	pub(crate) fn do_set_attributes_pre_signed(
+		origin: T::AccountId,
+		data: PreSignedAttributesOf<T, I>,
+		signer: T::AccountId,
+	) -> DispatchResult {
+		let PreSignedAttributes { collection, item, attributes, deadline } = data;
+
+		ensure!(
+			attributes.len() <= T::MaxAttributesPerCall::get() as usize,
+			Error::<T, I>::MaxAttributesLimitReached
+		);
+		Self::ensure_valid_deadline(deadline)?;
+
+		let item_details =
+			Item::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::UnknownItem)?;
+		ensure!(item_details.owner == origin, Error::<T, I>::NoPermission);
+		ensure!(
+			Self::has_role(&collection, &signer, CollectionRole::Admin),
+			Error::<T, I>::NoPermission
+		);
+
+		for (key, value) in attributes {
+			Self::do_set_attribute(
+				collection,
+				Some(item),
+				Self::construct_attribute_key(key)?,
+				Self::construct_attribute_value(value)?,
+				Some(origin.clone()),
+			)?;
+		}
+		Self::deposit_event(Event::PreSignedAttributesSet(collection, item, signer));
+		Ok(())
+	}
+
+	fn ensure_attributes_unlocked(
+		collection: &T::CollectionId,
+		maybe_item: Option<T::ItemId>,
+		collection_config: &CollectionConfigFor<T, I>,
+	) -> DispatchResult {
+		match maybe_item {
+			None => ensure!(
+				collection_config.is_setting_enabled(CollectionSetting::UnlockedAttributes),
+				Error::<T, I>::LockedCollectionAttributes
+			),
+			Some(item) => {
+				// Attributes may be set for items which haven't been minted yet.
+				let maybe_is_locked = ItemConfigOf::<T, I>::get(collection, item)
+					.map(|c| c.has_disabled_setting(ItemSetting::UnlockedAttributes));
+				ensure!(!maybe_is_locked.unwrap_or(false), Error::<T, I>::LockedItemAttributes);
+			},
+		};
+		Ok(())
+	}
+
+	/// A helper method to construct an attribute's key.
+	pub(crate) fn construct_attribute_key(
+		key: Vec<u8>,
+	) -> Result<BoundedVec<u8, T::KeyLimit>, DispatchError> {
+		Ok(BoundedVec::try_from(key).map_err(|_| Error::<T, I>::IncorrectData)?)
+	}
+
+	/// A helper method to construct an attribute's value.
+	pub(crate) fn construct_attribute_value(
+		value: Vec<u8>,
+	) -> Result<BoundedVec<u8, T::ValueLimit>, DispatchError> {
+		Ok(BoundedVec::try_from(value).map_err(|_| Error::<T, I>::IncorrectData)?)
+	}
+}
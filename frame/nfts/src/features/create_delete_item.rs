@@ -0,0 +1,174 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::*;
+use frame_support::pallet_prelude::*;
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	pub(crate) fn do_mint(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		maybe_depositor: Option<T::AccountId>,
+		mint_to: T::AccountId,
+		item_config: ItemConfig,
+		with_details_and_config: impl FnOnce(
+			&CollectionDetailsFor<T, I>,
+			&CollectionConfigFor<T, I>,
+		) -> DispatchResult,
+	) -> DispatchResult {
+		ensure!(!Item::<T, I>::contains_key(collection, item), Error::<T, I>::AlreadyExists);
+
+		Collection::<T, I>::try_mutate(
+			&collection,
+			|maybe_collection_details| -> DispatchResult {
+				let collection_details =
+					maybe_collection_details.as_mut().ok_or(Error::<T, I>::UnknownCollection)?;
+
+				let collection_config = Self::get_collection_config(&collection)?;
+				with_details_and_config(collection_details, &collection_config)?;
+
+				if let Some(max_supply) = collection_config.max_supply {
+					ensure!(collection_details.items < max_supply, Error::<T, I>::MaxSupplyReached);
+				}
+
+				let items =
+					collection_details.items.checked_add(1).ok_or(ArithmeticError::Overflow)?;
+				collection_details.items = items;
+
+				let deposit_amount = match collection_config
+					.is_setting_enabled(CollectionSetting::DepositRequired)
+				{
+					true => T::ItemDeposit::get(),
+					false => Zero::zero(),
+				};
+				let deposit_account = match maybe_depositor {
+					None => collection_details.owner.clone(),
+					Some(depositor) => depositor,
+				};
+
+				// The item was burned before with some of its settings disabled: the config was
+				// preserved and has to match.
+				if let Some(existing_config) = ItemConfigOf::<T, I>::get(&collection, &item) {
+					ensure!(existing_config == item_config, Error::<T, I>::InconsistentItemConfig);
+				}
+
+				T::Currency::reserve(&deposit_account, deposit_amount)?;
+
+				ItemConfigOf::<T, I>::insert(&collection, &item, item_config);
+				Account::<T, I>::insert((&mint_to, &collection, &item), ());
+				let deposit = AccountDeposit { account: deposit_account, amount: deposit_amount };
+				let details = ItemDetails { owner: mint_to.clone(), approved: None, deposit };
+				Item::<T, I>::insert(&collection, &item, details);
+				Ok(())
+			},
+		)?;
+
+		Self::deposit_event(Event::Issued(collection, item, mint_to));
+		Ok(())
+	}
+
+	pub(crate) fn do_mint_pre_signed(
+		mint_to: T::AccountId,
+		mint_data: PreSignedMintOf<T, I>,
+		signer: T::AccountId,
+	) -> DispatchResult {
+		let PreSignedMint { collection, item, attributes, metadata, deadline, only_account } =
+			mint_data;
+		let metadata = Self::construct_metadata(metadata)?;
+
+		ensure!(
+			attributes.len() <= T::MaxAttributesPerCall::get() as usize,
+			Error::<T, I>::MaxAttributesLimitReached
+		);
+		if let Some(account) = only_account {
+			ensure!(account == mint_to, Error::<T, I>::WrongOrigin);
+		}
+		Self::ensure_valid_deadline(deadline)?;
+
+		ensure!(
+			Self::has_role(&collection, &signer, CollectionRole::Issuer),
+			Error::<T, I>::NoPermission
+		);
+
+		let item_config = ItemConfig { settings: Self::get_default_item_settings(&collection)? };
+		Self::do_mint(
+			collection,
+			item,
+			Some(mint_to.clone()),
+			mint_to.clone(),
+			item_config,
+			|_, _| Ok(()),
+		)?;
+
+		for (key, value) in attributes {
+			Self::do_set_attribute(
+				collection,
+				Some(item),
+				Self::construct_attribute_key(key)?,
+				Self::construct_attribute_value(value)?,
+				Some(mint_to.clone()),
+			)?;
+		}
+		if !metadata.len().is_zero() {
+			Self::do_set_item_metadata(None, collection, item, metadata, Some(mint_to))?;
+		}
+		Ok(())
+	}
+
+	pub(crate) fn do_burn(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		with_details: impl FnOnce(&ItemDetailsFor<T, I>) -> DispatchResult,
+	) -> DispatchResult {
+		let owner = Collection::<T, I>::try_mutate(
+			&collection,
+			|maybe_collection_details| -> Result<T::AccountId, DispatchError> {
+				let collection_details =
+					maybe_collection_details.as_mut().ok_or(Error::<T, I>::UnknownCollection)?;
+				let details =
+					Item::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::UnknownItem)?;
+				with_details(&details)?;
+
+				// Return the deposit.
+				T::Currency::unreserve(&details.deposit.account, details.deposit.amount);
+				collection_details.items.saturating_dec();
+
+				if let Some(metadata) = ItemMetadataOf::<T, I>::take(&collection, &item) {
+					T::Currency::unreserve(&metadata.deposit.account, metadata.deposit.amount);
+					collection_details.item_metadatas.saturating_dec();
+				}
+
+				Ok(details.owner)
+			},
+		)?;
+
+		Item::<T, I>::remove(&collection, &item);
+		Account::<T, I>::remove((&owner, &collection, &item));
+		ItemPriceOf::<T, I>::remove(&collection, &item);
+		PendingSwapOf::<T, I>::remove(&collection, &item);
+
+		// NOTE: if the item's settings are not empty (e.g. the item's attributes are locked) then
+		// we keep the config record so the locks survive a re-mint.
+		let config = Self::get_item_config(&collection, &item)?;
+		if !config.has_disabled_settings() {
+			ItemConfigOf::<T, I>::remove(&collection, &item);
+		}
+
+		Self::deposit_event(Event::Burned(collection, item, owner));
+		Ok(())
+	}
+}
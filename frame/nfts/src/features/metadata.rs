@@ -0,0 +1,215 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::*;
+use frame_support::pallet_prelude::*;
+use sp_std::convert::TryFrom;
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Set the metadata of an `item`.
+	///
+	/// The deposit is taken from `maybe_depositor` if given, from the collection admin
+	/// `maybe_check_origin` otherwise. When both are `None` no deposit is taken.
+	pub(crate) fn do_set_item_metadata(
+		maybe_check_origin: Option<T::AccountId>,
+		collection: T::CollectionId,
+		item: T::ItemId,
+		data: BoundedVec<u8, T::StringLimit>,
+		maybe_depositor: Option<T::AccountId>,
+	) -> DispatchResult {
+		if let Some(check_origin) = &maybe_check_origin {
+			ensure!(
+				Self::has_role(&collection, check_origin, CollectionRole::Admin),
+				Error::<T, I>::NoPermission
+			);
+		}
+
+		let mut collection_details =
+			Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
+		let collection_config = Self::get_collection_config(&collection)?;
+
+		let item_config = Self::get_item_config(&collection, &item)?;
+		ensure!(
+			maybe_check_origin.is_none() ||
+				item_config.is_setting_enabled(ItemSetting::UnlockedMetadata),
+			Error::<T, I>::LockedItemMetadata
+		);
+
+		ItemMetadataOf::<T, I>::try_mutate_exists(collection, item, |metadata| {
+			if metadata.is_none() {
+				collection_details.item_metadatas.saturating_inc();
+			}
+			let old_deposit = metadata.take().map(|m| m.deposit);
+
+			let deposit_required =
+				collection_config.is_setting_enabled(CollectionSetting::DepositRequired);
+			let (deposit_account, deposit_amount) = match maybe_depositor.or(maybe_check_origin) {
+				Some(depositor) if deposit_required => {
+					let amount = T::DepositPerByte::get()
+						.saturating_mul(((data.len()) as u32).into())
+						.saturating_add(T::MetadataDepositBase::get());
+					(depositor, amount)
+				},
+				Some(depositor) => (depositor, Zero::zero()),
+				None => (collection_details.owner.clone(), Zero::zero()),
+			};
+
+			match old_deposit {
+				Some(old) if old.account == deposit_account =>
+					if deposit_amount > old.amount {
+						T::Currency::reserve(&deposit_account, deposit_amount - old.amount)?;
+					} else if deposit_amount < old.amount {
+						T::Currency::unreserve(&deposit_account, old.amount - deposit_amount);
+					},
+				Some(old) => {
+					T::Currency::reserve(&deposit_account, deposit_amount)?;
+					T::Currency::unreserve(&old.account, old.amount);
+				},
+				None => T::Currency::reserve(&deposit_account, deposit_amount)?,
+			}
+
+			*metadata = Some(ItemMetadata {
+				deposit: AccountDeposit { account: deposit_account, amount: deposit_amount },
+				data: data.clone(),
+			});
+
+			Collection::<T, I>::insert(&collection, &collection_details);
+			Self::deposit_event(Event::ItemMetadataSet(collection, item, data));
+			Ok(())
+		})
+	}
+
+	pub(crate) fn do_clear_item_metadata(
+		maybe_check_origin: Option<T::AccountId>,
+		collection: T::CollectionId,
+		item: T::ItemId,
+	) -> DispatchResult {
+		if let Some(check_origin) = &maybe_check_origin {
+			ensure!(
+				Self::has_role(&collection, check_origin, CollectionRole::Admin),
+				Error::<T, I>::NoPermission
+			);
+		}
+
+		let mut collection_details =
+			Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
+
+		let item_config = Self::get_item_config(&collection, &item)?;
+		ensure!(
+			maybe_check_origin.is_none() ||
+				item_config.is_setting_enabled(ItemSetting::UnlockedMetadata),
+			Error::<T, I>::LockedItemMetadata
+		);
+
+		ItemMetadataOf::<T, I>::try_mutate_exists(collection, item, |metadata| {
+			let metadata = metadata.take().ok_or(Error::<T, I>::UnknownItem)?;
+			collection_details.item_metadatas.saturating_dec();
+			T::Currency::unreserve(&metadata.deposit.account, metadata.deposit.amount);
+			Collection::<T, I>::insert(&collection, &collection_details);
+
+			Self::deposit_event(Event::ItemMetadataCleared(collection, item));
+			Ok(())
+		})
+	}
+
+	pub(crate) fn do_set_collection_metadata(
+		maybe_check_origin: Option<T::AccountId>,
+		collection: T::CollectionId,
+		data: BoundedVec<u8, T::StringLimit>,
+	) -> DispatchResult {
+		if let Some(check_origin) = &maybe_check_origin {
+			ensure!(
+				Self::has_role(&collection, check_origin, CollectionRole::Admin),
+				Error::<T, I>::NoPermission
+			);
+		}
+
+		let collection_config = Self::get_collection_config(&collection)?;
+		ensure!(
+			maybe_check_origin.is_none() ||
+				collection_config.is_setting_enabled(CollectionSetting::UnlockedMetadata),
+			Error::<T, I>::LockedCollectionMetadata
+		);
+
+		let mut details =
+			Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
+
+		CollectionMetadataOf::<T, I>::try_mutate_exists(collection, |metadata| {
+			let old_deposit = metadata.take().map_or(Zero::zero(), |m| m.deposit);
+			details.owner_deposit.saturating_reduce(old_deposit);
+			let mut deposit = Zero::zero();
+			if maybe_check_origin.is_some() &&
+				collection_config.is_setting_enabled(CollectionSetting::DepositRequired)
+			{
+				deposit = T::DepositPerByte::get()
+					.saturating_mul(((data.len()) as u32).into())
+					.saturating_add(T::MetadataDepositBase::get());
+			}
+			if deposit > old_deposit {
+				T::Currency::reserve(&details.owner, deposit - old_deposit)?;
+			} else if deposit < old_deposit {
+				T::Currency::unreserve(&details.owner, old_deposit - deposit);
+			}
+			details.owner_deposit.saturating_accrue(deposit);
+
+			Collection::<T, I>::insert(&collection, details);
+
+			*metadata = Some(CollectionMetadata { deposit, data: data.clone() });
+
+			Self::deposit_event(Event::CollectionMetadataSet(collection, data));
+			Ok(())
+		})
+	}
+
+	pub(crate) fn do_clear_collection_metadata(
+		maybe_check_origin: Option<T::AccountId>,
+		collection: T::CollectionId,
+	) -> DispatchResult {
+		if let Some(check_origin) = &maybe_check_origin {
+			ensure!(
+				Self::has_role(&collection, check_origin, CollectionRole::Admin),
+				Error::<T, I>::NoPermission
+			);
+		}
+
+		let mut details =
+			Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
+		let collection_config = Self::get_collection_config(&collection)?;
+
+		ensure!(
+			maybe_check_origin.is_none() ||
+				collection_config.is_setting_enabled(CollectionSetting::UnlockedMetadata),
+			Error::<T, I>::LockedCollectionMetadata
+		);
+
+		CollectionMetadataOf::<T, I>::try_mutate_exists(collection, |metadata| {
+			let deposit = metadata.take().ok_or(Error::<T, I>::UnknownCollection)?.deposit;
+			T::Currency::unreserve(&details.owner, deposit);
+			details.owner_deposit.saturating_reduce(deposit);
+			Collection::<T, I>::insert(&collection, details);
+			Self::deposit_event(Event::CollectionMetadataCleared(collection));
+			Ok(())
+		})
+	}
+
+	/// A helper method to construct metadata.
+	pub(crate) fn construct_metadata(
+		metadata: Vec<u8>,
+	) -> Result<BoundedVec<u8, T::StringLimit>, DispatchError> {
+		Ok(BoundedVec::try_from(metadata).map_err(|_| Error::<T, I>::IncorrectMetadata)?)
+	}
+}
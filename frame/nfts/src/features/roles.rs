@@ -0,0 +1,100 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::*;
+use frame_support::pallet_prelude::*;
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	pub(crate) fn do_set_team(
+		origin: T::AccountId,
+		collection: T::CollectionId,
+		issuer: T::AccountId,
+		admin: T::AccountId,
+		freezer: T::AccountId,
+	) -> DispatchResult {
+		Collection::<T, I>::try_mutate(collection, |maybe_details| {
+			let details = maybe_details.as_mut().ok_or(Error::<T, I>::UnknownCollection)?;
+			ensure!(origin == details.owner, Error::<T, I>::NoPermission);
+
+			// delete previous values
+			CollectionRoleOf::<T, I>::remove_prefix(&collection, None);
+
+			let account_to_role = Self::group_roles_by_account(vec![
+				(issuer.clone(), CollectionRole::Issuer),
+				(admin.clone(), CollectionRole::Admin),
+				(freezer.clone(), CollectionRole::Freezer),
+			]);
+			for (account, roles) in account_to_role {
+				CollectionRoleOf::<T, I>::insert(&collection, &account, roles);
+			}
+
+			Self::deposit_event(Event::TeamChanged(collection, issuer, admin, freezer));
+			Ok(())
+		})
+	}
+
+	/// Returns true if a specified account has a provided role within that collection.
+	///
+	/// - `collection_id`: A collection to check the role in.
+	/// - `account_id`: An account to check the role for.
+	/// - `role`: A role to validate.
+	///
+	/// Returns boolean.
+	pub(crate) fn has_role(
+		collection_id: &T::CollectionId,
+		account_id: &T::AccountId,
+		role: CollectionRole,
+	) -> bool {
+		CollectionRoleOf::<T, I>::get(&collection_id, &account_id)
+			.map_or(false, |roles| roles.has_role(role))
+	}
+
+	/// Assigns `roles` to `account` within `collection`.
+	pub(crate) fn set_collection_roles(
+		collection: T::CollectionId,
+		account: T::AccountId,
+		roles: Vec<CollectionRole>,
+	) {
+		let mut account_roles = CollectionRoles::none();
+		for role in roles {
+			account_roles.add_role(role);
+		}
+		CollectionRoleOf::<T, I>::insert(&collection, &account, account_roles);
+	}
+
+	/// Groups provided roles by account, given one account could have multiple roles.
+	///
+	/// - `input`: A vector of (Account, Role) tuples.
+	///
+	/// Returns a grouped vector.
+	pub fn group_roles_by_account(
+		input: Vec<(T::AccountId, CollectionRole)>,
+	) -> Vec<(T::AccountId, CollectionRoles)> {
+		let mut result: Vec<(T::AccountId, CollectionRoles)> = Vec::new();
+		for (account, role) in input {
+			match result.iter_mut().find(|(a, _)| a == &account) {
+				Some((_, roles)) => roles.add_role(role),
+				None => {
+					let mut roles = CollectionRoles::none();
+					roles.add_role(role);
+					result.push((account, roles));
+				},
+			}
+		}
+		result
+	}
+}
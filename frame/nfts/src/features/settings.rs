@@ -0,0 +1,204 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::*;
+use frame_support::pallet_prelude::*;
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	pub(crate) fn do_force_collection_config(
+		collection: T::CollectionId,
+		config: CollectionConfigFor<T, I>,
+	) -> DispatchResult {
+		ensure!(Collection::<T, I>::contains_key(&collection), Error::<T, I>::UnknownCollection);
+		CollectionConfigOf::<T, I>::insert(&collection, config);
+		Self::deposit_event(Event::CollectionConfigChanged(collection));
+		Ok(())
+	}
+
+	pub(crate) fn do_set_collection_max_supply(
+		maybe_check_owner: Option<T::AccountId>,
+		collection: T::CollectionId,
+		max_supply: u32,
+	) -> DispatchResult {
+		let collection_config = Self::get_collection_config(&collection)?;
+		ensure!(
+			collection_config.is_setting_enabled(CollectionSetting::UnlockedMaxSupply),
+			Error::<T, I>::MaxSupplyLocked
+		);
+
+		let details =
+			Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
+		if let Some(check_owner) = &maybe_check_owner {
+			ensure!(check_owner == &details.owner, Error::<T, I>::NoPermission);
+		}
+
+		ensure!(details.items <= max_supply, Error::<T, I>::MaxSupplyTooSmall);
+
+		CollectionConfigOf::<T, I>::try_mutate(collection, |maybe_config| {
+			let config = maybe_config.as_mut().ok_or(Error::<T, I>::NoConfig)?;
+			config.max_supply = Some(max_supply);
+			Self::deposit_event(Event::CollectionMaxSupplySet(collection, max_supply));
+			Ok(())
+		})
+	}
+
+	pub(crate) fn do_update_mint_settings(
+		maybe_check_origin: Option<T::AccountId>,
+		collection: T::CollectionId,
+		mint_settings: MintSettings<
+			BalanceOf<T, I>,
+			<T as SystemConfig>::BlockNumber,
+			T::CollectionId,
+		>,
+	) -> DispatchResult {
+		if let Some(check_origin) = &maybe_check_origin {
+			ensure!(
+				Self::has_role(&collection, check_origin, CollectionRole::Issuer),
+				Error::<T, I>::NoPermission
+			);
+		}
+
+		CollectionConfigOf::<T, I>::try_mutate(collection, |maybe_config| {
+			let config = maybe_config.as_mut().ok_or(Error::<T, I>::NoConfig)?;
+			config.mint_settings = mint_settings;
+			Self::deposit_event(Event::CollectionMintSettingsUpdated(collection));
+			Ok(())
+		})
+	}
+
+	/// Checks whether `caller` may mint into `collection` according to its mint settings and
+	/// charges the mint price, if any.
+	///
+	/// Issuers of the collection may always mint for free.
+	pub(crate) fn check_mint_settings(
+		collection: &T::CollectionId,
+		caller: &T::AccountId,
+		collection_details: &CollectionDetailsFor<T, I>,
+		collection_config: &CollectionConfigFor<T, I>,
+		witness_data: Option<MintWitness<T::ItemId>>,
+	) -> DispatchResult {
+		if Self::has_role(collection, caller, CollectionRole::Issuer) {
+			return Ok(())
+		}
+
+		let mint_settings = collection_config.mint_settings;
+		let now = frame_system::Pallet::<T>::block_number();
+		if let Some(start_block) = mint_settings.start_block {
+			ensure!(start_block <= now, Error::<T, I>::MintNotStarted);
+		}
+		if let Some(end_block) = mint_settings.end_block {
+			ensure!(end_block >= now, Error::<T, I>::MintEnded);
+		}
+
+		match mint_settings.mint_type {
+			MintType::Issuer => return Err(Error::<T, I>::NoPermission.into()),
+			MintType::HolderOf(collection_id) => {
+				let MintWitness { owned_item } = witness_data.ok_or(Error::<T, I>::BadWitness)?;
+
+				let owns_item =
+					Account::<T, I>::contains_key((caller, &collection_id, &owned_item));
+				ensure!(owns_item, Error::<T, I>::BadWitness);
+
+				let claim_key = (collection, &collection_id, &owned_item);
+				ensure!(
+					!HolderOfClaims::<T, I>::contains_key(claim_key),
+					Error::<T, I>::AlreadyClaimed
+				);
+				HolderOfClaims::<T, I>::insert(claim_key, ());
+			},
+			MintType::Public => {},
+		}
+
+		if let Some(price) = mint_settings.price {
+			T::Currency::transfer(
+				caller,
+				&collection_details.owner,
+				price,
+				ExistenceRequirement::KeepAlive,
+			)?;
+		}
+
+		Ok(())
+	}
+
+	/// Get the config of a collection.
+	pub(crate) fn get_collection_config(
+		collection_id: &T::CollectionId,
+	) -> Result<CollectionConfigFor<T, I>, DispatchError> {
+		let config =
+			CollectionConfigOf::<T, I>::get(&collection_id).ok_or(Error::<T, I>::NoConfig)?;
+		Ok(config)
+	}
+
+	/// Get the config of an item.
+	pub(crate) fn get_item_config(
+		collection_id: &T::CollectionId,
+		item_id: &T::ItemId,
+	) -> Result<ItemConfig, DispatchError> {
+		let config = ItemConfigOf::<T, I>::get(&collection_id, &item_id)
+			.ok_or(Error::<T, I>::UnknownItem)?;
+		Ok(config)
+	}
+
+	/// Get the default item settings of a collection.
+	pub(crate) fn get_default_item_settings(
+		collection_id: &T::CollectionId,
+	) -> Result<ItemSettings, DispatchError> {
+		let collection_config = Self::get_collection_config(collection_id)?;
+		Ok(collection_config.mint_settings.default_item_settings)
+	}
+
+	/// Ensures `deadline` hasn't passed and lies within `MaxDeadlineDuration` from now.
+	pub(crate) fn ensure_valid_deadline(
+		deadline: <T as SystemConfig>::BlockNumber,
+	) -> DispatchResult {
+		let now = frame_system::Pallet::<T>::block_number();
+		ensure!(deadline >= now, Error::<T, I>::DeadlineExpired);
+		ensure!(
+			deadline.saturating_sub(now) <= T::MaxDeadlineDuration::get(),
+			Error::<T, I>::WrongDuration
+		);
+		Ok(())
+	}
+
+	/// Verifies that `signature` of `data` was made by `signer`.
+	///
+	/// Signatures of the data wrapped into `<Bytes>..</Bytes>`, as produced by most wallets, are
+	/// accepted as well.
+	pub(crate) fn validate_signature(
+		data: &Vec<u8>,
+		signature: &T::OffchainSignature,
+		signer: &T::AccountId,
+	) -> DispatchResult {
+		if signature.verify(&**data, signer) {
+			return Ok(())
+		}
+
+		// NOTE: for security reasons modern UIs implicitly wrap the data requested to sign into
+		// <Bytes></Bytes>, that's why we support both wrapped and raw versions.
+		let prefix = b"<Bytes>";
+		let suffix = b"</Bytes>";
+		let mut wrapped: Vec<u8> = Vec::with_capacity(data.len() + prefix.len() + suffix.len());
+		wrapped.extend(prefix);
+		wrapped.extend(data);
+		wrapped.extend(suffix);
+
+		ensure!(signature.verify(&*wrapped, signer), Error::<T, I>::WrongSignature);
+
+		Ok(())
+	}
+}
@@ -0,0 +1,201 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Implementations for `nonfungibles` traits.
+
+use super::*;
+use frame_support::{
+	traits::{
+		tokens::nonfungibles::{Create, Inspect, InspectEnumerable, Mutate, Transfer},
+		Get,
+	},
+	BoundedSlice,
+};
+use sp_runtime::DispatchResult;
+use sp_std::convert::TryFrom;
+
+impl<T: Config<I>, I: 'static> Inspect<<T as SystemConfig>::AccountId> for Pallet<T, I> {
+	type InstanceId = T::ItemId;
+	type ClassId = T::CollectionId;
+
+	fn owner(
+		collection: &Self::ClassId,
+		item: &Self::InstanceId,
+	) -> Option<<T as SystemConfig>::AccountId> {
+		Item::<T, I>::get(collection, item).map(|a| a.owner)
+	}
+
+	fn class_owner(collection: &Self::ClassId) -> Option<<T as SystemConfig>::AccountId> {
+		Collection::<T, I>::get(collection).map(|a| a.owner)
+	}
+
+	/// Returns the attribute value of `item` of `collection` corresponding to `key`.
+	///
+	/// When `key` is empty, we return the item metadata value.
+	///
+	/// By default this is `None`; no attributes are defined.
+	fn attribute(
+		collection: &Self::ClassId,
+		item: &Self::InstanceId,
+		key: &[u8],
+	) -> Option<Vec<u8>> {
+		if key.is_empty() {
+			// We make the empty key map to the item metadata value.
+			ItemMetadataOf::<T, I>::get(collection, item).map(|m| m.data.into())
+		} else {
+			let key = BoundedSlice::<_, _>::try_from(key).ok()?;
+			Attribute::<T, I>::get((collection, Some(item), key)).map(|a| a.0.into())
+		}
+	}
+
+	/// Returns the attribute value of `collection` corresponding to `key`.
+	///
+	/// When `key` is empty, we return the collection metadata value.
+	///
+	/// By default this is `None`; no attributes are defined.
+	fn class_attribute(collection: &Self::ClassId, key: &[u8]) -> Option<Vec<u8>> {
+		if key.is_empty() {
+			// We make the empty key map to the collection metadata value.
+			CollectionMetadataOf::<T, I>::get(collection).map(|m| m.data.into())
+		} else {
+			let key = BoundedSlice::<_, _>::try_from(key).ok()?;
+			Attribute::<T, I>::get((collection, Option::<T::ItemId>::None, key)).map(|a| a.0.into())
+		}
+	}
+
+	/// Returns `true` if the `item` of `collection` may be transferred.
+	///
+	/// Default implementation is that all items are transferable.
+	fn can_transfer(collection: &Self::ClassId, item: &Self::InstanceId) -> bool {
+		match (
+			CollectionConfigOf::<T, I>::get(collection),
+			ItemConfigOf::<T, I>::get(collection, item),
+		) {
+			(Some(cc), Some(ic))
+				if cc.is_setting_enabled(CollectionSetting::TransferableItems) &&
+					ic.is_setting_enabled(ItemSetting::Transferable) =>
+				true,
+			_ => false,
+		}
+	}
+}
+
+impl<T: Config<I>, I: 'static> Create<<T as SystemConfig>::AccountId> for Pallet<T, I> {
+	/// Create a `collection` of nonfungible items to be owned by `who` and managed by `admin`.
+	fn create_class(
+		collection: &Self::ClassId,
+		who: &T::AccountId,
+		admin: &T::AccountId,
+	) -> DispatchResult {
+		Self::do_create_collection(
+			*collection,
+			who.clone(),
+			admin.clone(),
+			CollectionConfig::default(),
+			T::CollectionDeposit::get(),
+			Event::Created(*collection, who.clone(), admin.clone()),
+		)
+	}
+}
+
+impl<T: Config<I>, I: 'static> Mutate<<T as SystemConfig>::AccountId> for Pallet<T, I> {
+	fn mint_into(
+		collection: &Self::ClassId,
+		item: &Self::InstanceId,
+		who: &T::AccountId,
+	) -> DispatchResult {
+		Self::do_mint(*collection, *item, None, who.clone(), ItemConfig::default(), |_, _| Ok(()))
+	}
+
+	fn burn_from(collection: &Self::ClassId, item: &Self::InstanceId) -> DispatchResult {
+		Self::do_burn(*collection, *item, |_| Ok(()))
+	}
+
+	/// Set the attribute `key` of `item` of `collection` to `value`.
+	///
+	/// No deposit is taken for attributes set through this interface.
+	fn set_attribute(
+		collection: &Self::ClassId,
+		item: &Self::InstanceId,
+		key: &[u8],
+		value: &[u8],
+	) -> DispatchResult {
+		Self::do_set_attribute(
+			*collection,
+			Some(*item),
+			Self::construct_attribute_key(key.to_vec())?,
+			Self::construct_attribute_value(value.to_vec())?,
+			None,
+		)
+	}
+
+	/// Set the attribute `key` of `collection` to `value`.
+	///
+	/// No deposit is taken for attributes set through this interface.
+	fn set_class_attribute(collection: &Self::ClassId, key: &[u8], value: &[u8]) -> DispatchResult {
+		Self::do_set_attribute(
+			*collection,
+			None,
+			Self::construct_attribute_key(key.to_vec())?,
+			Self::construct_attribute_value(value.to_vec())?,
+			None,
+		)
+	}
+}
+
+impl<T: Config<I>, I: 'static> Transfer<T::AccountId> for Pallet<T, I> {
+	fn transfer(
+		collection: &Self::ClassId,
+		item: &Self::InstanceId,
+		destination: &T::AccountId,
+	) -> DispatchResult {
+		Self::do_transfer(*collection, *item, destination.clone(), |_, _| Ok(()))
+	}
+}
+
+impl<T: Config<I>, I: 'static> InspectEnumerable<T::AccountId> for Pallet<T, I> {
+	/// Returns an iterator of the collections in existence.
+	///
+	/// NOTE: iterating this list invokes a storage read per item.
+	fn classes() -> Box<dyn Iterator<Item = Self::ClassId>> {
+		Box::new(Collection::<T, I>::iter_keys())
+	}
+
+	/// Returns an iterator of the items of a `collection` in existence.
+	///
+	/// NOTE: iterating this list invokes a storage read per item.
+	fn instances(collection: &Self::ClassId) -> Box<dyn Iterator<Item = Self::InstanceId>> {
+		Box::new(Item::<T, I>::iter_key_prefix(collection))
+	}
+
+	/// Returns an iterator of the items of all collections owned by `who`.
+	///
+	/// NOTE: iterating this list invokes a storage read per item.
+	fn owned(who: &T::AccountId) -> Box<dyn Iterator<Item = (Self::ClassId, Self::InstanceId)>> {
+		Box::new(Account::<T, I>::iter_key_prefix((who,)))
+	}
+
+	/// Returns an iterator of the items of `collection` owned by `who`.
+	///
+	/// NOTE: iterating this list invokes a storage read per item.
+	fn owned_in_class(
+		collection: &Self::ClassId,
+		who: &T::AccountId,
+	) -> Box<dyn Iterator<Item = Self::InstanceId>> {
+		Box::new(Account::<T, I>::iter_key_prefix((who, collection)))
+	}
+}
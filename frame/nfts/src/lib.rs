@@ -0,0 +1,1402 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # NFTs Module
+//!
+//! A simple, secure module for dealing with non-fungible items.
+//!
+//! Compared to the `uniques` pallet this adds:
+//!
+//! * Collection roles (`Issuer`, `Admin`, `Freezer`) which may be held by several accounts.
+//! * Collection and item settings which allow to permanently lock metadata, attributes,
+//!   transferability and the max supply.
+//! * Per-collection mint settings: issuer-only, public or holder-of-another-collection mints, with
+//!   an optional price and mint window.
+//! * Mints and attributes pre-signed off-chain by a collection issuer or admin.
+//! * Atomic item-for-item swaps, optionally topped up with a balance transfer.
+//!
+//! ## Related Modules
+//!
+//! * [`System`](../frame_system/index.html)
+//! * [`Support`](../frame_support/index.html)
+
+// Ensure we're `no_std` when compiling for Wasm.
+#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(feature = "runtime-benchmarks", recursion_limit = "512")]
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+pub mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+mod features;
+mod impl_nonfungibles;
+mod macros;
+mod types;
+pub use types::*;
+
+use codec::{Decode, Encode, HasCompact};
+use frame_support::{
+	traits::{BalanceStatus::Reserved, Currency, ExistenceRequirement, ReservableCurrency},
+	transactional,
+};
+use frame_system::Config as SystemConfig;
+use sp_runtime::{
+	traits::{IdentifyAccount, Saturating, StaticLookup, Verify, Zero},
+	ArithmeticError, RuntimeDebug,
+};
+use sp_std::prelude::*;
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<Public, AccountId, Signature> {
+	/// Returns a new signer together with the account it identifies.
+	fn signer() -> (Public, AccountId);
+	/// Signs `message` with the key of `signer`.
+	fn sign(signer: &Public, message: &[u8]) -> Signature;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl BenchmarkHelper<sp_runtime::MultiSigner, sp_runtime::AccountId32, sp_runtime::MultiSignature>
+	for ()
+{
+	fn signer() -> (sp_runtime::MultiSigner, sp_runtime::AccountId32) {
+		let public = sp_io::crypto::sr25519_generate(0.into(), None);
+		let account = sp_runtime::MultiSigner::Sr25519(public).into_account();
+		(public.into(), account)
+	}
+
+	fn sign(signer: &sp_runtime::MultiSigner, message: &[u8]) -> sp_runtime::MultiSignature {
+		use sp_std::convert::TryInto;
+		sp_runtime::MultiSignature::Sr25519(
+			sp_io::crypto::sr25519_sign(0.into(), &signer.clone().try_into().unwrap(), message)
+				.unwrap(),
+		)
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T, I = ()>(_);
+
+	#[pallet::config]
+	/// The module configuration trait.
+	pub trait Config<I: 'static = ()>: frame_system::Config {
+		/// The overarching event type.
+		type Event: From<Event<Self, I>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Identifier for the collection of item.
+		type CollectionId: Member + Parameter + Default + Copy + HasCompact;
+
+		/// The type used to identify a unique item within a collection.
+		type ItemId: Member + Parameter + Default + Copy + HasCompact + From<u16>;
+
+		/// The currency mechanism, used for paying for reserves.
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// The origin which may forcibly create or destroy an item or otherwise alter privileged
+		/// attributes.
+		type ForceOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The basic amount of funds that must be reserved for collection.
+		#[pallet::constant]
+		type CollectionDeposit: Get<DepositBalanceOf<Self, I>>;
+
+		/// The basic amount of funds that must be reserved for an item.
+		#[pallet::constant]
+		type ItemDeposit: Get<DepositBalanceOf<Self, I>>;
+
+		/// The basic amount of funds that must be reserved when adding metadata to your item.
+		#[pallet::constant]
+		type MetadataDepositBase: Get<DepositBalanceOf<Self, I>>;
+
+		/// The basic amount of funds that must be reserved when adding an attribute to an item.
+		#[pallet::constant]
+		type AttributeDepositBase: Get<DepositBalanceOf<Self, I>>;
+
+		/// The additional funds that must be reserved for the number of bytes store in metadata,
+		/// either "normal" metadata or attribute metadata.
+		#[pallet::constant]
+		type DepositPerByte: Get<DepositBalanceOf<Self, I>>;
+
+		/// The maximum length of data stored on-chain.
+		#[pallet::constant]
+		type StringLimit: Get<u32>;
+
+		/// The maximum length of an attribute key.
+		#[pallet::constant]
+		type KeyLimit: Get<u32>;
+
+		/// The maximum length of an attribute value.
+		#[pallet::constant]
+		type ValueLimit: Get<u32>;
+
+		/// The max number of attributes a user could set per call.
+		#[pallet::constant]
+		type MaxAttributesPerCall: Get<u32>;
+
+		/// The max duration in blocks for deadlines, i.e. of swaps and pre-signed data.
+		#[pallet::constant]
+		type MaxDeadlineDuration: Get<<Self as SystemConfig>::BlockNumber>;
+
+		/// Off-Chain signature type.
+		///
+		/// Can verify whether an `Self::OffchainPublic` created a signature.
+		type OffchainSignature: Verify<Signer = Self::OffchainPublic> + Parameter;
+
+		/// Off-Chain public key.
+		///
+		/// Must identify as an on-chain `Self::AccountId`.
+		type OffchainPublic: IdentifyAccount<AccountId = Self::AccountId>;
+
+		#[cfg(feature = "runtime-benchmarks")]
+		/// A set of helper functions for benchmarking.
+		type Helper: BenchmarkHelper<Self::OffchainPublic, Self::AccountId, Self::OffchainSignature>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::storage]
+	/// Details of a collection.
+	pub(super) type Collection<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		CollectionDetails<T::AccountId, DepositBalanceOf<T, I>>,
+	>;
+
+	#[pallet::storage]
+	/// The items held by any given account; set out this way so that items owned by a single
+	/// account can be enumerated.
+	pub(super) type Account<T: Config<I>, I: 'static = ()> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::AccountId>, // owner
+			NMapKey<Blake2_128Concat, T::CollectionId>,
+			NMapKey<Blake2_128Concat, T::ItemId>,
+		),
+		(),
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// The roles held by accounts within a collection.
+	pub(super) type CollectionRoleOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::AccountId,
+		CollectionRoles,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// The items in existence and their ownership details.
+	pub(super) type Item<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		ItemDetails<T::AccountId, DepositBalanceOf<T, I>>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// Metadata of a collection.
+	pub(super) type CollectionMetadataOf<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		CollectionMetadata<DepositBalanceOf<T, I>, T::StringLimit>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// Metadata of an item.
+	pub(super) type ItemMetadataOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		ItemMetadata<T::AccountId, DepositBalanceOf<T, I>, T::StringLimit>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// Attributes of a collection or an item.
+	pub(super) type Attribute<T: Config<I>, I: 'static = ()> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::CollectionId>,
+			NMapKey<Blake2_128Concat, Option<T::ItemId>>,
+			NMapKey<Blake2_128Concat, BoundedVec<u8, T::KeyLimit>>,
+		),
+		(BoundedVec<u8, T::ValueLimit>, AccountDeposit<T::AccountId, DepositBalanceOf<T, I>>),
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// A price of an item.
+	pub(super) type ItemPriceOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		(ItemPrice<T, I>, Option<T::AccountId>),
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// Handles all the pending swaps.
+	pub(super) type PendingSwapOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		PendingSwapFor<T, I>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// Config of a collection.
+	pub(super) type CollectionConfigOf<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::CollectionId, CollectionConfigFor<T, I>, OptionQuery>;
+
+	#[pallet::storage]
+	/// Config of an item.
+	pub(super) type ItemConfigOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		ItemConfig,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// Items of other collections which have already been used to claim a `HolderOf` mint in a
+	/// collection.
+	pub(super) type HolderOfClaims<T: Config<I>, I: 'static = ()> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::CollectionId>, // minted collection
+			NMapKey<Blake2_128Concat, T::CollectionId>, // held collection
+			NMapKey<Blake2_128Concat, T::ItemId>,       // held item
+		),
+		(),
+		OptionQuery,
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config<I>, I: 'static = ()> {
+		/// A `collection` was created. \[ collection, creator, owner \]
+		Created(T::CollectionId, T::AccountId, T::AccountId),
+		/// A `collection` was force-created. \[ collection, owner \]
+		ForceCreated(T::CollectionId, T::AccountId),
+		/// A `collection` was destroyed. \[ collection \]
+		Destroyed(T::CollectionId),
+		/// An `item` was issued. \[ collection, item, owner \]
+		Issued(T::CollectionId, T::ItemId, T::AccountId),
+		/// An `item` was transferred. \[ collection, item, from, to \]
+		Transferred(T::CollectionId, T::ItemId, T::AccountId, T::AccountId),
+		/// An `item` was destroyed. \[ collection, item, owner \]
+		Burned(T::CollectionId, T::ItemId, T::AccountId),
+		/// An `item` became non-transferable. \[ collection, item \]
+		ItemTransferLocked(T::CollectionId, T::ItemId),
+		/// An `item` became transferable. \[ collection, item \]
+		ItemTransferUnlocked(T::CollectionId, T::ItemId),
+		/// `item` metadata or attributes were locked. \[ collection, item, lock_metadata,
+		/// lock_attributes \]
+		ItemPropertiesLocked(T::CollectionId, T::ItemId, bool, bool),
+		/// Some `collection` was locked. \[ collection \]
+		CollectionLocked(T::CollectionId),
+		/// The owner changed. \[ collection, new_owner \]
+		OwnerChanged(T::CollectionId, T::AccountId),
+		/// The management team changed. \[ collection, issuer, admin, freezer \]
+		TeamChanged(T::CollectionId, T::AccountId, T::AccountId, T::AccountId),
+		/// An `item` of a `collection` has been approved by the `owner` for transfer by
+		/// a `delegate`. \[ collection, item, owner, delegate \]
+		TransferApproved(T::CollectionId, T::ItemId, T::AccountId, T::AccountId),
+		/// An approval for a `delegate` account to transfer the `item` of an item
+		/// `collection` was cancelled by its `owner`. \[ collection, item, owner, delegate \]
+		ApprovalCancelled(T::CollectionId, T::ItemId, T::AccountId, T::AccountId),
+		/// A `collection` has had its config changed by the `Force` origin. \[ collection \]
+		CollectionConfigChanged(T::CollectionId),
+		/// New metadata has been set for a `collection`. \[ collection, data \]
+		CollectionMetadataSet(T::CollectionId, BoundedVec<u8, T::StringLimit>),
+		/// Metadata has been cleared for a `collection`. \[ collection \]
+		CollectionMetadataCleared(T::CollectionId),
+		/// New metadata has been set for an item. \[ collection, item, data \]
+		ItemMetadataSet(T::CollectionId, T::ItemId, BoundedVec<u8, T::StringLimit>),
+		/// Metadata has been cleared for an item. \[ collection, item \]
+		ItemMetadataCleared(T::CollectionId, T::ItemId),
+		/// New attribute metadata has been set for a `collection` or `item`.
+		/// \[ collection, maybe_item, key, value \]
+		AttributeSet(
+			T::CollectionId,
+			Option<T::ItemId>,
+			BoundedVec<u8, T::KeyLimit>,
+			BoundedVec<u8, T::ValueLimit>,
+		),
+		/// Attribute metadata has been cleared for a `collection` or `item`.
+		/// \[ collection, maybe_item, key \]
+		AttributeCleared(T::CollectionId, Option<T::ItemId>, BoundedVec<u8, T::KeyLimit>),
+		/// Max supply has been set for a collection. \[ collection, max_supply \]
+		CollectionMaxSupplySet(T::CollectionId, u32),
+		/// Mint settings for a collection had changed. \[ collection \]
+		CollectionMintSettingsUpdated(T::CollectionId),
+		/// The price was set for the item. \[ collection, item, price, whitelisted_buyer \]
+		ItemPriceSet(T::CollectionId, T::ItemId, ItemPrice<T, I>, Option<T::AccountId>),
+		/// The price for the item was removed. \[ collection, item \]
+		ItemPriceRemoved(T::CollectionId, T::ItemId),
+		/// An item was bought. \[ collection, item, price, seller, buyer \]
+		ItemBought(T::CollectionId, T::ItemId, ItemPrice<T, I>, T::AccountId, T::AccountId),
+		/// An `item` swap intent was created.
+		/// \[ offered_collection, offered_item, desired_collection, desired_item, price, deadline
+		/// \]
+		SwapCreated(
+			T::CollectionId,
+			T::ItemId,
+			T::CollectionId,
+			Option<T::ItemId>,
+			Option<PriceWithDirection<ItemPrice<T, I>>>,
+			<T as SystemConfig>::BlockNumber,
+		),
+		/// The swap was cancelled. \[ offered_collection, offered_item \]
+		SwapCancelled(T::CollectionId, T::ItemId),
+		/// The swap has been claimed.
+		/// \[ sent_collection, sent_item, sent_item_owner, received_collection, received_item,
+		/// received_item_owner, price \]
+		SwapClaimed(
+			T::CollectionId,
+			T::ItemId,
+			T::AccountId,
+			T::CollectionId,
+			T::ItemId,
+			T::AccountId,
+			Option<PriceWithDirection<ItemPrice<T, I>>>,
+		),
+		/// New attributes have been set for an `item` of the `collection`.
+		/// \[ collection, item, signer \]
+		PreSignedAttributesSet(T::CollectionId, T::ItemId, T::AccountId),
+	}
+
+	#[pallet::error]
+	pub enum Error<T, I = ()> {
+		/// The signing account has no permission to do the operation.
+		NoPermission,
+		/// The given collection ID is unknown.
+		UnknownCollection,
+		/// The item ID has already been used for an item.
+		AlreadyExists,
+		/// Invalid witness data given.
+		BadWitness,
+		/// The collection ID is already taken.
+		InUse,
+		/// Items within that collection are non-transferable.
+		ItemsNonTransferable,
+		/// The item is locked (non-transferable).
+		ItemLocked,
+		/// Item's attributes are locked.
+		LockedItemAttributes,
+		/// Collection's attributes are locked.
+		LockedCollectionAttributes,
+		/// Item's metadata is locked.
+		LockedItemMetadata,
+		/// Collection's metadata is locked.
+		LockedCollectionMetadata,
+		/// The delegate turned out to be different to what was expected.
+		WrongDelegate,
+		/// There is no delegate approved.
+		NoDelegate,
+		/// All items have been minted.
+		MaxSupplyReached,
+		/// The max supply is locked and can't be changed.
+		MaxSupplyLocked,
+		/// The provided max supply is less than the number of items a collection already has.
+		MaxSupplyTooSmall,
+		/// The given item ID is unknown.
+		UnknownItem,
+		/// Swap doesn't exist.
+		UnknownSwap,
+		/// Item is not for sale.
+		NotForSale,
+		/// The provided bid is too low.
+		BidTooLow,
+		/// The deadline has already expired.
+		DeadlineExpired,
+		/// The duration provided should be less than or equal to `MaxDeadlineDuration`.
+		WrongDuration,
+		/// The provided setting can't be set.
+		WrongSetting,
+		/// Item's config already exists and should be equal to the provided one.
+		InconsistentItemConfig,
+		/// Config for a collection or an item can't be found.
+		NoConfig,
+		/// Mint has not started yet.
+		MintNotStarted,
+		/// Mint has already ended.
+		MintEnded,
+		/// The provided item has already been used to claim a mint.
+		AlreadyClaimed,
+		/// The provided data is incorrect.
+		IncorrectData,
+		/// The extrinsic was sent by the wrong origin.
+		WrongOrigin,
+		/// The provided signature is incorrect.
+		WrongSignature,
+		/// The provided metadata might be too long.
+		IncorrectMetadata,
+		/// Can't set more attributes per one call.
+		MaxAttributesLimitReached,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {}
+
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// Get the owner of the item, if the item exists.
+		pub fn owner(collection: T::CollectionId, item: T::ItemId) -> Option<T::AccountId> {
+			Item::<T, I>::get(collection, item).map(|i| i.owner)
+		}
+
+		/// Get the owner of the collection, if the collection exists.
+		pub fn collection_owner(collection: T::CollectionId) -> Option<T::AccountId> {
+			Collection::<T, I>::get(collection).map(|i| i.owner)
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// Issue a new collection of non-fungible items from a public origin.
+		///
+		/// This new collection has no items initially and its owner is the origin.
+		///
+		/// The origin must be Signed and the sender must have sufficient funds free.
+		///
+		/// `CollectionDeposit` funds of sender are reserved.
+		///
+		/// Parameters:
+		/// - `collection`: The identifier of the new collection. This must not be currently in use.
+		/// - `admin`: The admin of this collection. The admin is the initial address of each
+		/// member of the collection's admin team.
+		/// - `config`: The configuration of this collection.
+		///
+		/// Emits `Created` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::create())]
+		pub fn create(
+			origin: OriginFor<T>,
+			#[pallet::compact] collection: T::CollectionId,
+			admin: <T::Lookup as StaticLookup>::Source,
+			config: CollectionConfigFor<T, I>,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			let admin = T::Lookup::lookup(admin)?;
+
+			// DepositRequired can be disabled by calling the force_create() only
+			ensure!(
+				!config.has_disabled_setting(CollectionSetting::DepositRequired),
+				Error::<T, I>::WrongSetting
+			);
+
+			Self::do_create_collection(
+				collection,
+				owner.clone(),
+				admin.clone(),
+				config,
+				T::CollectionDeposit::get(),
+				Event::Created(collection, owner, admin),
+			)
+		}
+
+		/// Issue a new collection of non-fungible items from a privileged origin.
+		///
+		/// This new collection has no items initially.
+		///
+		/// The origin must conform to `ForceOrigin`.
+		///
+		/// Unlike `create`, no funds are reserved.
+		///
+		/// - `collection`: The identifier of the new collection. This must not be currently in use.
+		/// - `owner`: The owner of this collection of items. The owner has full superuser
+		///   permissions over this item, but may later change and configure the permissions using
+		///   `transfer_ownership` and `set_team`.
+		/// - `config`: The configuration of this collection.
+		///
+		/// Emits `ForceCreated` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::force_create())]
+		pub fn force_create(
+			origin: OriginFor<T>,
+			#[pallet::compact] collection: T::CollectionId,
+			owner: <T::Lookup as StaticLookup>::Source,
+			config: CollectionConfigFor<T, I>,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			let owner = T::Lookup::lookup(owner)?;
+
+			Self::do_create_collection(
+				collection,
+				owner.clone(),
+				owner.clone(),
+				config,
+				Zero::zero(),
+				Event::ForceCreated(collection, owner),
+			)
+		}
+
+		/// Destroy a collection of fungible items.
+		///
+		/// The origin must conform to `ForceOrigin` or must be `Signed` and the sender must be the
+		/// owner of the `collection`.
+		///
+		/// - `collection`: The identifier of the collection to be destroyed.
+		/// - `witness`: Information on the items minted in the collection. This must be
+		/// correct.
+		///
+		/// Emits `Destroyed` event when successful.
+		///
+		/// Weight: `O(n + m)` where:
+		/// - `n = witness.items`
+		/// - `m = witness.item_metadatas`
+		/// - `a = witness.attributes`
+		#[pallet::weight(T::WeightInfo::destroy(
+			witness.items,
+			witness.item_metadatas,
+			witness.attributes,
+		))]
+		pub fn destroy(
+			origin: OriginFor<T>,
+			#[pallet::compact] collection: T::CollectionId,
+			witness: DestroyWitness,
+		) -> DispatchResultWithPostInfo {
+			let maybe_check_owner = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some).map_err(DispatchError::from))?;
+			let details = Self::do_destroy_collection(collection, witness, maybe_check_owner)?;
+
+			Ok(Some(T::WeightInfo::destroy(
+				details.items,
+				details.item_metadatas,
+				details.attributes,
+			))
+			.into())
+		}
+
+		/// Mint an item of a particular collection.
+		///
+		/// The origin must be Signed and the sender must comply with the `mint_settings` rules.
+		///
+		/// - `collection`: The collection of the item to be minted.
+		/// - `item`: An identifier of the new item.
+		/// - `mint_to`: Account into which the item will be minted.
+		/// - `witness_data`: When the mint type is `HolderOf(collection_id)`, then the owned
+		///   item_id from that collection needs to be provided within the witness data object.
+		///
+		/// Note: the deposit will be taken from the `origin` and not the `owner` of the `item`.
+		///
+		/// Emits `Issued` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::mint())]
+		#[transactional]
+		pub fn mint(
+			origin: OriginFor<T>,
+			#[pallet::compact] collection: T::CollectionId,
+			#[pallet::compact] item: T::ItemId,
+			mint_to: <T::Lookup as StaticLookup>::Source,
+			witness_data: Option<MintWitness<T::ItemId>>,
+		) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+			let mint_to = T::Lookup::lookup(mint_to)?;
+			let item_config =
+				ItemConfig { settings: Self::get_default_item_settings(&collection)? };
+
+			Self::do_mint(
+				collection,
+				item,
+				Some(caller.clone()),
+				mint_to,
+				item_config,
+				|collection_details, collection_config| {
+					Self::check_mint_settings(
+						&collection,
+						&caller,
+						collection_details,
+						collection_config,
+						witness_data,
+					)
+				},
+			)
+		}
+
+		/// Mint an item of a particular collection from a privileged origin.
+		///
+		/// The origin must conform to `ForceOrigin` or must be `Signed` and the sender must be the
+		/// Issuer of the `collection`.
+		///
+		/// - `collection`: The collection of the item to be minted.
+		/// - `item`: An identifier of the new item.
+		/// - `mint_to`: Account into which the item will be minted.
+		/// - `item_config`: A config of the new item.
+		///
+		/// Emits `Issued` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::force_mint())]
+		pub fn force_mint(
+			origin: OriginFor<T>,
+			#[pallet::compact] collection: T::CollectionId,
+			#[pallet::compact] item: T::ItemId,
+			mint_to: <T::Lookup as StaticLookup>::Source,
+			item_config: ItemConfig,
+		) -> DispatchResult {
+			let maybe_check_origin = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some).map_err(DispatchError::from))?;
+			let mint_to = T::Lookup::lookup(mint_to)?;
+
+			if let Some(check_origin) = maybe_check_origin {
+				ensure!(
+					Self::has_role(&collection, &check_origin, CollectionRole::Issuer),
+					Error::<T, I>::NoPermission
+				);
+			}
+			Self::do_mint(collection, item, None, mint_to, item_config, |_, _| Ok(()))
+		}
+
+		/// Destroy a single item.
+		///
+		/// The origin must be Signed and the signing account must be the owner of the `item`.
+		///
+		/// Any metadata of the item is removed and its deposit returned.
+		///
+		/// - `collection`: The collection of the item to be burned.
+		/// - `item`: The item to be burned.
+		///
+		/// Emits `Burned`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::burn())]
+		pub fn burn(
+			origin: OriginFor<T>,
+			#[pallet::compact] collection: T::CollectionId,
+			#[pallet::compact] item: T::ItemId,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+
+			Self::do_burn(collection, item, |details| {
+				ensure!(details.owner == origin, Error::<T, I>::NoPermission);
+				Ok(())
+			})
+		}
+
+		/// Move an item from the sender account to another.
+		///
+		/// Origin must be Signed and the signing account must be either:
+		/// - the Owner of the `item`;
+		/// - the approved delegate for the `item` (in this case, the approval is reset).
+		///
+		/// Arguments:
+		/// - `collection`: The collection of the item to be transferred.
+		/// - `item`: The item to be transferred.
+		/// - `dest`: The account to receive ownership of the item.
+		///
+		/// Emits `Transferred`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::transfer())]
+		pub fn transfer(
+			origin: OriginFor<T>,
+			#[pallet::compact] collection: T::CollectionId,
+			#[pallet::compact] item: T::ItemId,
+			dest: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let dest = T::Lookup::lookup(dest)?;
+
+			Self::do_transfer(collection, item, dest, |_, details| {
+				if details.owner != origin {
+					let approved = details.approved.take().map_or(false, |i| i == origin);
+					ensure!(approved, Error::<T, I>::NoPermission);
+				}
+				Ok(())
+			})
+		}
+
+		/// Disallow further unprivileged transfer of an item.
+		///
+		/// Origin must be Signed and the sender should be the Freezer of the `collection`.
+		///
+		/// - `collection`: The collection of the item to be changed.
+		/// - `item`: The item to become non-transferable.
+		///
+		/// Emits `ItemTransferLocked`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::lock_item_transfer())]
+		pub fn lock_item_transfer(
+			origin: OriginFor<T>,
+			#[pallet::compact] collection: T::CollectionId,
+			#[pallet::compact] item: T::ItemId,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_lock_item_transfer(origin, collection, item)
+		}
+
+		/// Re-allow unprivileged transfer of an item.
+		///
+		/// Origin must be Signed and the sender should be the Freezer of the `collection`.
+		///
+		/// - `collection`: The collection of the item to be changed.
+		/// - `item`: The item to become transferable.
+		///
+		/// Emits `ItemTransferUnlocked`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::unlock_item_transfer())]
+		pub fn unlock_item_transfer(
+			origin: OriginFor<T>,
+			#[pallet::compact] collection: T::CollectionId,
+			#[pallet::compact] item: T::ItemId,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_unlock_item_transfer(origin, collection, item)
+		}
+
+		/// Disallows specified settings for the whole collection.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the `collection`.
+		///
+		/// - `collection`: The collection to be locked.
+		/// - `lock_settings`: The settings to be locked.
+		///
+		/// Note: it's possible to only lock(set) the setting, but not to unset it.
+		///
+		/// Emits `CollectionLocked`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::lock_collection())]
+		pub fn lock_collection(
+			origin: OriginFor<T>,
+			#[pallet::compact] collection: T::CollectionId,
+			lock_settings: CollectionSettings,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_lock_collection(origin, collection, lock_settings)
+		}
+
+		/// Change the Owner of a collection.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the `collection`.
+		///
+		/// - `collection`: The collection whose owner should be changed.
+		/// - `owner`: The new Owner of this collection.
+		///
+		/// The deposit reserved by the current owner for the collection and its metadata is moved
+		/// to the new owner.
+		///
+		/// Emits `OwnerChanged`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::transfer_ownership())]
+		pub fn transfer_ownership(
+			origin: OriginFor<T>,
+			#[pallet::compact] collection: T::CollectionId,
+			owner: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let owner = T::Lookup::lookup(owner)?;
+			Self::do_transfer_ownership(origin, collection, owner)
+		}
+
+		/// Change the Issuer, Admin and Freezer of a collection.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the `collection`.
+		///
+		/// - `collection`: The collection whose team should be changed.
+		/// - `issuer`: The new Issuer of this collection.
+		/// - `admin`: The new Admin of this collection.
+		/// - `freezer`: The new Freezer of this collection.
+		///
+		/// Emits `TeamChanged`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_team())]
+		pub fn set_team(
+			origin: OriginFor<T>,
+			#[pallet::compact] collection: T::CollectionId,
+			issuer: <T::Lookup as StaticLookup>::Source,
+			admin: <T::Lookup as StaticLookup>::Source,
+			freezer: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let issuer = T::Lookup::lookup(issuer)?;
+			let admin = T::Lookup::lookup(admin)?;
+			let freezer = T::Lookup::lookup(freezer)?;
+			Self::do_set_team(origin, collection, issuer, admin, freezer)
+		}
+
+		/// Change the config of a collection.
+		///
+		/// Origin must be `ForceOrigin`.
+		///
+		/// - `collection`: The identifier of the collection.
+		/// - `config`: The new config of this collection.
+		///
+		/// Emits `CollectionConfigChanged`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::force_collection_config())]
+		pub fn force_collection_config(
+			origin: OriginFor<T>,
+			#[pallet::compact] collection: T::CollectionId,
+			config: CollectionConfigFor<T, I>,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			Self::do_force_collection_config(collection, config)
+		}
+
+		/// Approve an item to be transferred by a delegated third-party account.
+		///
+		/// Origin must be Signed and must be the owner of the `item`.
+		///
+		/// - `collection`: The collection of the item to be approved for delegated transfer.
+		/// - `item`: The item to be approved for delegated transfer.
+		/// - `delegate`: The account to delegate permission to transfer the item.
+		///
+		/// Emits `TransferApproved` on success.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::approve_transfer())]
+		pub fn approve_transfer(
+			origin: OriginFor<T>,
+			#[pallet::compact] collection: T::CollectionId,
+			#[pallet::compact] item: T::ItemId,
+			delegate: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			let maybe_check_origin = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some).map_err(DispatchError::from))?;
+			let delegate = T::Lookup::lookup(delegate)?;
+			Self::do_approve_transfer(maybe_check_origin, collection, item, delegate)
+		}
+
+		/// Cancel the prior approval for the transfer of an item by a delegate.
+		///
+		/// Origin must be either:
+		/// - the `Force` origin;
+		/// - `Signed` with the signer being the Owner of the `item`;
+		///
+		/// Arguments:
+		/// - `collection`: The collection of the item of whose approval will be cancelled.
+		/// - `item`: The item of the collection of whose approval will be cancelled.
+		/// - `maybe_check_delegate`: If `Some` will ensure that the given account is the one to
+		///   which permission of transfer is delegated.
+		///
+		/// Emits `ApprovalCancelled` on success.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::cancel_approval())]
+		pub fn cancel_approval(
+			origin: OriginFor<T>,
+			#[pallet::compact] collection: T::CollectionId,
+			#[pallet::compact] item: T::ItemId,
+			maybe_check_delegate: Option<<T::Lookup as StaticLookup>::Source>,
+		) -> DispatchResult {
+			let maybe_check_origin = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some).map_err(DispatchError::from))?;
+			let maybe_check_delegate = maybe_check_delegate.map(T::Lookup::lookup).transpose()?;
+			Self::do_cancel_approval(maybe_check_origin, collection, item, maybe_check_delegate)
+		}
+
+		/// Disallows changing the metadata or attributes of the item.
+		///
+		/// Origin must be either `ForceOrigin` or Signed and the sender should be the Admin
+		/// of the `collection`.
+		///
+		/// - `collection`: The collection if the `item`.
+		/// - `item`: An item to be locked.
+		/// - `lock_metadata`: Specifies whether the metadata should be locked.
+		/// - `lock_attributes`: Specifies whether the attributes should be locked.
+		///
+		/// Note: `lock_attributes` affects the attributes set by the collection admin only,
+		/// pre-signed attributes are affected as well.
+		///
+		/// Emits `ItemPropertiesLocked`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::lock_item_properties())]
+		pub fn lock_item_properties(
+			origin: OriginFor<T>,
+			#[pallet::compact] collection: T::CollectionId,
+			#[pallet::compact] item: T::ItemId,
+			lock_metadata: bool,
+			lock_attributes: bool,
+		) -> DispatchResult {
+			let maybe_check_origin = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some).map_err(DispatchError::from))?;
+			Self::do_lock_item_properties(
+				maybe_check_origin,
+				collection,
+				item,
+				lock_metadata,
+				lock_attributes,
+			)
+		}
+
+		/// Set an attribute for a collection or item.
+		///
+		/// Origin must be either `ForceOrigin` or Signed and the sender should be the Admin of the
+		/// `collection`.
+		///
+		/// If the origin is Signed, then funds of signer are reserved according to the formula:
+		/// `AttributeDepositBase + DepositPerByte * (key.len + value.len)` taking into
+		/// account any already reserved funds.
+		///
+		/// - `collection`: The identifier of the collection whose item's metadata to set.
+		/// - `maybe_item`: The identifier of the item whose metadata to set.
+		/// - `key`: The key of the attribute.
+		/// - `value`: The value to which to set the attribute.
+		///
+		/// Emits `AttributeSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_attribute())]
+		pub fn set_attribute(
+			origin: OriginFor<T>,
+			#[pallet::compact] collection: T::CollectionId,
+			maybe_item: Option<T::ItemId>,
+			key: BoundedVec<u8, T::KeyLimit>,
+			value: BoundedVec<u8, T::ValueLimit>,
+		) -> DispatchResult {
+			let maybe_check_origin = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some).map_err(DispatchError::from))?;
+			if let Some(check_origin) = &maybe_check_origin {
+				ensure!(
+					Self::has_role(&collection, check_origin, CollectionRole::Admin),
+					Error::<T, I>::NoPermission
+				);
+			}
+			Self::do_set_attribute(collection, maybe_item, key, value, maybe_check_origin)
+		}
+
+		/// Clear an attribute for a collection or item.
+		///
+		/// Origin must be either `ForceOrigin` or Signed and the sender should be the Admin of the
+		/// `collection`.
+		///
+		/// Any deposit is freed for the account which has paid it.
+		///
+		/// - `collection`: The identifier of the collection whose item's metadata to clear.
+		/// - `maybe_item`: The identifier of the item whose metadata to clear.
+		/// - `key`: The key of the attribute.
+		///
+		/// Emits `AttributeCleared`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::clear_attribute())]
+		pub fn clear_attribute(
+			origin: OriginFor<T>,
+			#[pallet::compact] collection: T::CollectionId,
+			maybe_item: Option<T::ItemId>,
+			key: BoundedVec<u8, T::KeyLimit>,
+		) -> DispatchResult {
+			let maybe_check_origin = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some).map_err(DispatchError::from))?;
+			Self::do_clear_attribute(maybe_check_origin, collection, maybe_item, key)
+		}
+
+		/// Set the metadata for an item.
+		///
+		/// Origin must be either `ForceOrigin` or Signed and the sender should be the Admin of the
+		/// `collection`.
+		///
+		/// If the origin is Signed, then funds of signer are reserved according to the formula:
+		/// `MetadataDepositBase + DepositPerByte * data.len` taking into
+		/// account any already reserved funds.
+		///
+		/// - `collection`: The identifier of the collection whose item's metadata to set.
+		/// - `item`: The identifier of the item whose metadata to set.
+		/// - `data`: The general information of this item. Limited in length by `StringLimit`.
+		///
+		/// Emits `ItemMetadataSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_metadata())]
+		pub fn set_metadata(
+			origin: OriginFor<T>,
+			#[pallet::compact] collection: T::CollectionId,
+			#[pallet::compact] item: T::ItemId,
+			data: BoundedVec<u8, T::StringLimit>,
+		) -> DispatchResult {
+			let maybe_check_origin = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some).map_err(DispatchError::from))?;
+			Self::do_set_item_metadata(maybe_check_origin, collection, item, data, None)
+		}
+
+		/// Clear the metadata for an item.
+		///
+		/// Origin must be either `ForceOrigin` or Signed and the sender should be the Admin of the
+		/// `collection`.
+		///
+		/// Any deposit is freed for the account which has paid it.
+		///
+		/// - `collection`: The identifier of the collection whose item's metadata to clear.
+		/// - `item`: The identifier of the item whose metadata to clear.
+		///
+		/// Emits `ItemMetadataCleared`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::clear_metadata())]
+		pub fn clear_metadata(
+			origin: OriginFor<T>,
+			#[pallet::compact] collection: T::CollectionId,
+			#[pallet::compact] item: T::ItemId,
+		) -> DispatchResult {
+			let maybe_check_origin = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some).map_err(DispatchError::from))?;
+			Self::do_clear_item_metadata(maybe_check_origin, collection, item)
+		}
+
+		/// Set the metadata for a collection.
+		///
+		/// Origin must be either `ForceOrigin` or `Signed` and the sender should be the Admin of
+		/// the `collection`.
+		///
+		/// If the origin is `Signed`, then funds of the collection owner are reserved according to
+		/// the formula: `MetadataDepositBase + DepositPerByte * data.len` taking into account any
+		/// already reserved funds.
+		///
+		/// - `collection`: The identifier of the item whose metadata to update.
+		/// - `data`: The general information of this item. Limited in length by `StringLimit`.
+		///
+		/// Emits `CollectionMetadataSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_collection_metadata())]
+		pub fn set_collection_metadata(
+			origin: OriginFor<T>,
+			#[pallet::compact] collection: T::CollectionId,
+			data: BoundedVec<u8, T::StringLimit>,
+		) -> DispatchResult {
+			let maybe_check_origin = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some).map_err(DispatchError::from))?;
+			Self::do_set_collection_metadata(maybe_check_origin, collection, data)
+		}
+
+		/// Clear the metadata for a collection.
+		///
+		/// Origin must be either `ForceOrigin` or `Signed` and the sender should be the Admin of
+		/// the `collection`.
+		///
+		/// Any deposit is freed for the collection's owner.
+		///
+		/// - `collection`: The identifier of the collection whose metadata to clear.
+		///
+		/// Emits `CollectionMetadataCleared`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::clear_collection_metadata())]
+		pub fn clear_collection_metadata(
+			origin: OriginFor<T>,
+			#[pallet::compact] collection: T::CollectionId,
+		) -> DispatchResult {
+			let maybe_check_origin = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some).map_err(DispatchError::from))?;
+			Self::do_clear_collection_metadata(maybe_check_origin, collection)
+		}
+
+		/// Set the maximum number of items a collection could have.
+		///
+		/// Origin must be either `ForceOrigin` or `Signed` and the sender should be the Owner of
+		/// the `collection`.
+		///
+		/// - `collection`: The identifier of the collection to change.
+		/// - `max_supply`: The maximum number of items a collection could have.
+		///
+		/// Emits `CollectionMaxSupplySet` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_collection_max_supply())]
+		pub fn set_collection_max_supply(
+			origin: OriginFor<T>,
+			#[pallet::compact] collection: T::CollectionId,
+			max_supply: u32,
+		) -> DispatchResult {
+			let maybe_check_owner = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some).map_err(DispatchError::from))?;
+			Self::do_set_collection_max_supply(maybe_check_owner, collection, max_supply)
+		}
+
+		/// Update mint settings.
+		///
+		/// Origin must be either `ForceOrigin` or `Signed` and the sender should be the Issuer
+		/// of the `collection`.
+		///
+		/// - `collection`: The identifier of the collection to change.
+		/// - `mint_settings`: The new mint settings.
+		///
+		/// Emits `CollectionMintSettingsUpdated` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::update_mint_settings())]
+		pub fn update_mint_settings(
+			origin: OriginFor<T>,
+			#[pallet::compact] collection: T::CollectionId,
+			mint_settings: MintSettings<
+				BalanceOf<T, I>,
+				<T as SystemConfig>::BlockNumber,
+				T::CollectionId,
+			>,
+		) -> DispatchResult {
+			let maybe_check_origin = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some).map_err(DispatchError::from))?;
+			Self::do_update_mint_settings(maybe_check_origin, collection, mint_settings)
+		}
+
+		/// Set (or reset) the price for an item.
+		///
+		/// Origin must be Signed and must be the owner of the `item`.
+		///
+		/// - `collection`: The collection of the item.
+		/// - `item`: The item to set the price for.
+		/// - `price`: The price for the item. Pass `None`, to reset the price.
+		/// - `whitelisted_buyer`: Restricts the buy operation to a specific account.
+		///
+		/// Emits `ItemPriceSet` on success if the price is not `None`.
+		/// Emits `ItemPriceRemoved` on success if the price is `None`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_price())]
+		pub fn set_price(
+			origin: OriginFor<T>,
+			#[pallet::compact] collection: T::CollectionId,
+			#[pallet::compact] item: T::ItemId,
+			price: Option<ItemPrice<T, I>>,
+			whitelisted_buyer: Option<<T::Lookup as StaticLookup>::Source>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let whitelisted_buyer = whitelisted_buyer.map(T::Lookup::lookup).transpose()?;
+			Self::do_set_price(collection, item, origin, price, whitelisted_buyer)
+		}
+
+		/// Allows to buy an item if it's up for sale.
+		///
+		/// Origin must be Signed and must not be the owner of the `item`.
+		///
+		/// - `collection`: The collection of the item.
+		/// - `item`: The item the sender wants to buy.
+		/// - `bid_price`: The price the sender is willing to pay.
+		///
+		/// Emits `ItemBought` on success.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::buy_item())]
+		#[transactional]
+		pub fn buy_item(
+			origin: OriginFor<T>,
+			#[pallet::compact] collection: T::CollectionId,
+			#[pallet::compact] item: T::ItemId,
+			bid_price: ItemPrice<T, I>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_buy_item(collection, item, origin, bid_price)
+		}
+
+		/// Register a new atomic swap, declaring an intention to send an `item` in exchange for
+		/// `desired_item` from origin to target on the current blockchain.
+		/// The target can execute the swap during the specified `duration` of blocks (if set).
+		/// Additionally, the price could be set for the desired `item`.
+		///
+		/// Origin must be Signed and must be an owner of the `item`.
+		///
+		/// - `offered_collection`: The collection of the item.
+		/// - `offered_item`: The item an owner wants to give.
+		/// - `desired_collection`: The collection of the desired item.
+		/// - `maybe_desired_item`: The desired item an owner wants to receive.
+		/// - `maybe_price`: The price an owner is willing to pay or receive for the desired `item`.
+		/// - `duration`: A deadline for the swap. It must not exceed `MaxDeadlineDuration`.
+		///
+		/// Emits `SwapCreated` on success.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::create_swap())]
+		pub fn create_swap(
+			origin: OriginFor<T>,
+			#[pallet::compact] offered_collection: T::CollectionId,
+			#[pallet::compact] offered_item: T::ItemId,
+			#[pallet::compact] desired_collection: T::CollectionId,
+			maybe_desired_item: Option<T::ItemId>,
+			maybe_price: Option<PriceWithDirection<ItemPrice<T, I>>>,
+			duration: <T as SystemConfig>::BlockNumber,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_create_swap(
+				origin,
+				offered_collection,
+				offered_item,
+				desired_collection,
+				maybe_desired_item,
+				maybe_price,
+				duration,
+			)
+		}
+
+		/// Cancel an atomic swap.
+		///
+		/// Origin must be Signed.
+		/// Origin must be an owner of the `item` if the deadline hasn't expired.
+		///
+		/// - `offered_collection`: The collection of the item.
+		/// - `offered_item`: The item an owner wants to give.
+		///
+		/// Emits `SwapCancelled` on success.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::cancel_swap())]
+		pub fn cancel_swap(
+			origin: OriginFor<T>,
+			#[pallet::compact] offered_collection: T::CollectionId,
+			#[pallet::compact] offered_item: T::ItemId,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_cancel_swap(origin, offered_collection, offered_item)
+		}
+
+		/// Claim an atomic swap.
+		/// This method executes a pending swap, that was created by a counterpart before.
+		///
+		/// Origin must be Signed and must be an owner of the `item`.
+		///
+		/// - `send_collection`: The collection of the item to be sent.
+		/// - `send_item`: The item to be sent.
+		/// - `receive_collection`: The collection of the item to be received.
+		/// - `receive_item`: The item to be received.
+		/// - `witness_price`: A price that was previously agreed on.
+		///
+		/// Emits `SwapClaimed` on success.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::claim_swap())]
+		#[transactional]
+		pub fn claim_swap(
+			origin: OriginFor<T>,
+			#[pallet::compact] send_collection: T::CollectionId,
+			#[pallet::compact] send_item: T::ItemId,
+			#[pallet::compact] receive_collection: T::CollectionId,
+			#[pallet::compact] receive_item: T::ItemId,
+			witness_price: Option<PriceWithDirection<ItemPrice<T, I>>>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_claim_swap(
+				origin,
+				send_collection,
+				send_item,
+				receive_collection,
+				receive_item,
+				witness_price,
+			)
+		}
+
+		/// Mint an item by providing the pre-signed approval.
+		///
+		/// Origin must be Signed.
+		///
+		/// - `mint_data`: The pre-signed approval that consists of the information about the item,
+		///   its metadata, attributes, who can mint it (`None` for anyone) and until what block
+		///   number.
+		/// - `signature`: The signature of the `data` object.
+		/// - `signer`: The `data` object's signer. Should be an Issuer of the collection.
+		///
+		/// Emits `Issued` on success.
+		/// Emits `AttributeSet` if the attributes were provided.
+		/// Emits `ItemMetadataSet` if the metadata was not empty.
+		///
+		/// Weight: `O(attributes)`
+		#[pallet::weight(T::WeightInfo::mint_pre_signed(mint_data.attributes.len() as u32))]
+		#[transactional]
+		pub fn mint_pre_signed(
+			origin: OriginFor<T>,
+			mint_data: Box<PreSignedMintOf<T, I>>,
+			signature: T::OffchainSignature,
+			signer: T::AccountId,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::validate_signature(&Encode::encode(&mint_data), &signature, &signer)?;
+			Self::do_mint_pre_signed(origin, *mint_data, signer)
+		}
+
+		/// Set attributes for an item by providing the pre-signed approval.
+		///
+		/// Origin must be Signed and must be an owner of the `data.item`.
+		///
+		/// - `data`: The pre-signed approval that consists of the information about the item,
+		///   attributes to update and until what block number.
+		/// - `signature`: The signature of the `data` object.
+		/// - `signer`: The `data` object's signer. Should be an Admin of the collection.
+		///
+		/// Emits `AttributeSet` for each provided attribute.
+		/// Emits `PreSignedAttributesSet` on success.
+		///
+		/// Weight: `O(attributes)`
+		#[pallet::weight(T::WeightInfo::set_attributes_pre_signed(data.attributes.len() as u32))]
+		#[transactional]
+		pub fn set_attributes_pre_signed(
+			origin: OriginFor<T>,
+			data: PreSignedAttributesOf<T, I>,
+			signature: T::OffchainSignature,
+			signer: T::AccountId,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::validate_signature(&Encode::encode(&data), &signature, &signer)?;
+			Self::do_set_attributes_pre_signed(origin, data, signer)
+		}
+	}
+}
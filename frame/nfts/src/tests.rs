@@ -0,0 +1,1357 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for Nfts pallet.
+
+use crate::{mock::*, Event, *};
+use enumflags2::BitFlags;
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{
+		tokens::nonfungibles::{Create, Inspect, InspectEnumerable, Mutate},
+		Currency,
+	},
+};
+use pallet_balances::Error as BalancesError;
+use sp_core::{sr25519, Pair};
+use sp_runtime::{traits::IdentifyAccount, MultiSignature, MultiSigner};
+use sp_std::convert::TryInto;
+
+fn account(id: u8) -> AccountId {
+	[id; 32].into()
+}
+
+fn items() -> Vec<(AccountId, u32, u32)> {
+	let mut r: Vec<_> = Account::<Test>::iter().map(|x| x.0).collect();
+	r.sort();
+	let mut s: Vec<_> = Item::<Test>::iter().map(|x| (x.2.owner, x.0, x.1)).collect();
+	s.sort();
+	assert_eq!(r, s);
+	for collection in Item::<Test>::iter()
+		.map(|x| x.0)
+		.scan(None, |s, item| {
+			if s.map_or(false, |last| last == item) {
+				*s = Some(item);
+				Some(None)
+			} else {
+				Some(Some(item))
+			}
+		})
+		.filter_map(|item| item)
+	{
+		let details = Collection::<Test>::get(collection).unwrap();
+		let items = Item::<Test>::iter_prefix(collection).count() as u32;
+		assert_eq!(details.items, items);
+	}
+	r
+}
+
+fn collections() -> Vec<(AccountId, u32)> {
+	let mut r: Vec<_> = Collection::<Test>::iter().map(|x| (x.1.owner, x.0)).collect();
+	r.sort();
+	r
+}
+
+fn events() -> Vec<Event<Test>> {
+	let result = System::events()
+		.into_iter()
+		.map(|r| r.event)
+		.filter_map(|e| if let mock::Event::Nfts(inner) = e { Some(inner) } else { None })
+		.collect::<Vec<_>>();
+
+	System::reset_events();
+
+	result
+}
+
+macro_rules! bvec {
+	($( $x:tt )*) => {
+		vec![$( $x )*].try_into().unwrap()
+	}
+}
+
+fn attributes(collection: u32) -> Vec<(Option<u32>, Vec<u8>, Vec<u8>)> {
+	let mut s: Vec<_> = Attribute::<Test>::iter_prefix((collection,))
+		.map(|(k, v)| (k.0, k.1.into(), v.0.into()))
+		.collect();
+	s.sort();
+	s
+}
+
+fn collection_config_from_disabled_settings(
+	settings: BitFlags<CollectionSetting>,
+) -> CollectionConfigFor<Test> {
+	CollectionConfig {
+		settings: CollectionSettings::from_disabled(settings),
+		max_supply: None,
+		mint_settings: MintSettings::default(),
+	}
+}
+
+fn collection_config_with_all_settings_enabled() -> CollectionConfigFor<Test> {
+	CollectionConfig {
+		settings: CollectionSettings::all_enabled(),
+		max_supply: None,
+		mint_settings: MintSettings::default(),
+	}
+}
+
+fn default_collection_config() -> CollectionConfigFor<Test> {
+	collection_config_from_disabled_settings(CollectionSetting::DepositRequired.into())
+}
+
+fn default_item_config() -> ItemConfig {
+	ItemConfig { settings: ItemSettings::all_enabled() }
+}
+
+fn item_config_from_disabled_settings(settings: BitFlags<ItemSetting>) -> ItemConfig {
+	ItemConfig { settings: ItemSettings::from_disabled(settings) }
+}
+
+#[test]
+fn basic_setup_works() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(items(), vec![]);
+	});
+}
+
+#[test]
+fn basic_minting_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(Origin::root(), 0, account(1), default_collection_config()));
+		assert_ok!(Nfts::mint(Origin::signed(account(1)), 0, 42, account(1), None));
+		assert_eq!(items(), vec![(account(1), 0, 42)]);
+
+		assert_ok!(Nfts::force_create(Origin::root(), 1, account(2), default_collection_config()));
+		assert_ok!(Nfts::mint(Origin::signed(account(2)), 1, 69, account(1), None));
+		assert_eq!(items(), vec![(account(1), 0, 42), (account(1), 1, 69)]);
+	});
+}
+
+#[test]
+fn lifecycle_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		Balances::make_free_balance_be(&account(2), 100);
+		assert_ok!(Nfts::create(
+			Origin::signed(account(1)),
+			0,
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_eq!(Balances::reserved_balance(&account(1)), 2);
+
+		assert_ok!(Nfts::set_collection_metadata(Origin::signed(account(1)), 0, bvec![0, 0]));
+		assert_eq!(Balances::reserved_balance(&account(1)), 5);
+		assert!(CollectionMetadataOf::<Test>::contains_key(0));
+
+		assert_ok!(Nfts::force_mint(
+			Origin::signed(account(1)),
+			0,
+			42,
+			account(10),
+			default_item_config()
+		));
+		assert_eq!(Balances::reserved_balance(&account(1)), 6);
+		assert_ok!(Nfts::force_mint(
+			Origin::signed(account(1)),
+			0,
+			69,
+			account(20),
+			default_item_config()
+		));
+		assert_eq!(Balances::reserved_balance(&account(1)), 7);
+		assert_eq!(items(), vec![(account(10), 0, 42), (account(20), 0, 69)]);
+		assert_eq!(Collection::<Test>::get(0).unwrap().items, 2);
+		assert_eq!(Collection::<Test>::get(0).unwrap().item_metadatas, 0);
+
+		assert_ok!(Nfts::set_metadata(Origin::signed(account(1)), 0, 42, bvec![42, 42]));
+		assert_eq!(Balances::reserved_balance(&account(1)), 10);
+		assert!(ItemMetadataOf::<Test>::contains_key(0, 42));
+		assert_ok!(Nfts::set_metadata(Origin::signed(account(1)), 0, 69, bvec![69, 69]));
+		assert_eq!(Balances::reserved_balance(&account(1)), 13);
+		assert!(ItemMetadataOf::<Test>::contains_key(0, 69));
+
+		let w = Collection::<Test>::get(0).unwrap().destroy_witness();
+		assert_eq!(w.items, 2);
+		assert_eq!(w.item_metadatas, 2);
+		assert_ok!(Nfts::destroy(Origin::signed(account(1)), 0, w));
+		assert_eq!(Balances::reserved_balance(&account(1)), 0);
+
+		assert!(!Collection::<Test>::contains_key(0));
+		assert!(!CollectionConfigOf::<Test>::contains_key(0));
+		assert!(!Item::<Test>::contains_key(0, 42));
+		assert!(!Item::<Test>::contains_key(0, 69));
+		assert!(!ItemConfigOf::<Test>::contains_key(0, 42));
+		assert!(!CollectionMetadataOf::<Test>::contains_key(0));
+		assert!(!ItemMetadataOf::<Test>::contains_key(0, 42));
+		assert!(!ItemMetadataOf::<Test>::contains_key(0, 69));
+		assert_eq!(CollectionRoleOf::<Test>::iter_prefix(0).count(), 0);
+		assert_eq!(items(), vec![]);
+	});
+}
+
+#[test]
+fn destroy_with_bad_witness_should_not_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		assert_ok!(Nfts::create(
+			Origin::signed(account(1)),
+			0,
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+
+		let w = Collection::<Test>::get(0).unwrap().destroy_witness();
+		assert_ok!(Nfts::mint(Origin::signed(account(1)), 0, 42, account(1), None));
+		assert_noop!(Nfts::destroy(Origin::signed(account(1)), 0, w), Error::<Test>::BadWitness);
+	});
+}
+
+#[test]
+fn create_requires_deposit_setting() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		assert_noop!(
+			Nfts::create(Origin::signed(account(1)), 0, account(1), default_collection_config()),
+			Error::<Test>::WrongSetting
+		);
+		assert_noop!(
+			Nfts::create(
+				Origin::signed(account(3)),
+				0,
+				account(3),
+				collection_config_with_all_settings_enabled()
+			),
+			BalancesError::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn transfer_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(Origin::root(), 0, account(1), default_collection_config()));
+		assert_ok!(Nfts::force_mint(
+			Origin::signed(account(1)),
+			0,
+			42,
+			account(2),
+			default_item_config()
+		));
+
+		assert_ok!(Nfts::transfer(Origin::signed(account(2)), 0, 42, account(3)));
+		assert_eq!(items(), vec![(account(3), 0, 42)]);
+		assert_noop!(
+			Nfts::transfer(Origin::signed(account(2)), 0, 42, account(4)),
+			Error::<Test>::NoPermission
+		);
+
+		assert_ok!(Nfts::approve_transfer(Origin::signed(account(3)), 0, 42, account(2)));
+		assert_ok!(Nfts::transfer(Origin::signed(account(2)), 0, 42, account(4)));
+
+		// validate we can't transfer non-transferable items
+		let collection_id = 1;
+		assert_ok!(Nfts::force_create(
+			Origin::root(),
+			collection_id,
+			account(1),
+			collection_config_from_disabled_settings(
+				CollectionSetting::TransferableItems | CollectionSetting::DepositRequired
+			)
+		));
+
+		assert_ok!(Nfts::force_mint(
+			Origin::signed(account(1)),
+			collection_id,
+			1,
+			account(1),
+			default_item_config()
+		));
+
+		assert_noop!(
+			Nfts::transfer(Origin::signed(account(1)), collection_id, 1, account(42)),
+			Error::<Test>::ItemsNonTransferable
+		);
+	});
+}
+
+#[test]
+fn locking_transfer_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(Origin::root(), 0, account(1), default_collection_config()));
+		assert_ok!(Nfts::force_mint(
+			Origin::signed(account(1)),
+			0,
+			42,
+			account(1),
+			default_item_config()
+		));
+		assert_ok!(Nfts::lock_item_transfer(Origin::signed(account(1)), 0, 42));
+		assert_noop!(
+			Nfts::transfer(Origin::signed(account(1)), 0, 42, account(2)),
+			Error::<Test>::ItemLocked
+		);
+
+		assert_ok!(Nfts::unlock_item_transfer(Origin::signed(account(1)), 0, 42));
+		assert_ok!(Nfts::lock_collection(
+			Origin::signed(account(1)),
+			0,
+			CollectionSettings::from_disabled(CollectionSetting::TransferableItems.into())
+		));
+		assert_noop!(
+			Nfts::transfer(Origin::signed(account(1)), 0, 42, account(2)),
+			Error::<Test>::ItemsNonTransferable
+		);
+
+		assert_ok!(Nfts::force_collection_config(
+			Origin::root(),
+			0,
+			collection_config_with_all_settings_enabled(),
+		));
+		assert_ok!(Nfts::transfer(Origin::signed(account(1)), 0, 42, account(2)));
+	});
+}
+
+#[test]
+fn origin_guards_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(Origin::root(), 0, account(1), default_collection_config()));
+		assert_ok!(Nfts::mint(Origin::signed(account(1)), 0, 42, account(1), None));
+
+		Balances::make_free_balance_be(&account(2), 100);
+		assert_noop!(
+			Nfts::transfer_ownership(Origin::signed(account(2)), 0, account(2)),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Nfts::set_team(Origin::signed(account(2)), 0, account(2), account(2), account(2)),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Nfts::lock_item_transfer(Origin::signed(account(2)), 0, 42),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Nfts::unlock_item_transfer(Origin::signed(account(2)), 0, 42),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Nfts::mint(Origin::signed(account(2)), 0, 69, account(2), None),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(Nfts::burn(Origin::signed(account(2)), 0, 42), Error::<Test>::NoPermission);
+		let w = Collection::<Test>::get(0).unwrap().destroy_witness();
+		assert_noop!(Nfts::destroy(Origin::signed(account(2)), 0, w), Error::<Test>::NoPermission);
+	});
+}
+
+#[test]
+fn transfer_owner_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		Balances::make_free_balance_be(&account(2), 100);
+		Balances::make_free_balance_be(&account(3), 100);
+		assert_ok!(Nfts::create(
+			Origin::signed(account(1)),
+			0,
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_eq!(collections(), vec![(account(1), 0)]);
+		assert_ok!(Nfts::transfer_ownership(Origin::signed(account(1)), 0, account(2)));
+		assert_eq!(collections(), vec![(account(2), 0)]);
+		assert_eq!(Balances::total_balance(&account(1)), 98);
+		assert_eq!(Balances::total_balance(&account(2)), 102);
+		assert_eq!(Balances::reserved_balance(&account(1)), 0);
+		assert_eq!(Balances::reserved_balance(&account(2)), 2);
+
+		assert_noop!(
+			Nfts::transfer_ownership(Origin::signed(account(1)), 0, account(1)),
+			Error::<Test>::NoPermission
+		);
+
+		// Mint and set metadata now and make sure that deposit gets transferred back.
+		assert_ok!(Nfts::set_collection_metadata(Origin::signed(account(1)), 0, bvec![0u8; 20]));
+		assert_ok!(Nfts::mint(Origin::signed(account(1)), 0, 42, account(1), None));
+		assert_eq!(Balances::reserved_balance(&account(1)), 1);
+		assert_ok!(Nfts::transfer_ownership(Origin::signed(account(2)), 0, account(3)));
+		assert_eq!(collections(), vec![(account(3), 0)]);
+		assert_eq!(Balances::total_balance(&account(2)), 79);
+		assert_eq!(Balances::total_balance(&account(3)), 123);
+		assert_eq!(Balances::reserved_balance(&account(2)), 0);
+		assert_eq!(Balances::reserved_balance(&account(3)), 23);
+
+		// The item deposit stays with whoever paid it.
+		assert_eq!(Balances::reserved_balance(&account(1)), 1);
+	});
+}
+
+#[test]
+fn set_team_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(Origin::root(), 0, account(1), default_collection_config()));
+		assert_ok!(Nfts::set_team(
+			Origin::signed(account(1)),
+			0,
+			account(2),
+			account(3),
+			account(4)
+		));
+
+		assert_ok!(Nfts::mint(Origin::signed(account(2)), 0, 42, account(2), None));
+		assert_ok!(Nfts::lock_item_transfer(Origin::signed(account(4)), 0, 42));
+		assert_ok!(Nfts::unlock_item_transfer(Origin::signed(account(4)), 0, 42));
+		assert_ok!(Nfts::set_metadata(Origin::signed(account(3)), 0, 42, bvec![0u8; 10]));
+
+		// The old admin lost its roles.
+		assert_noop!(
+			Nfts::mint(Origin::signed(account(1)), 0, 69, account(1), None),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Nfts::set_metadata(Origin::signed(account(4)), 0, 42, bvec![0u8; 10]),
+			Error::<Test>::NoPermission
+		);
+
+		// One account may hold several roles.
+		assert_ok!(Nfts::set_team(
+			Origin::signed(account(1)),
+			0,
+			account(2),
+			account(2),
+			account(3)
+		));
+		let roles = CollectionRoleOf::<Test>::get(0, account(2)).unwrap();
+		assert!(roles.has_role(CollectionRole::Issuer));
+		assert!(roles.has_role(CollectionRole::Admin));
+		assert!(!roles.has_role(CollectionRole::Freezer));
+		assert!(!CollectionRoleOf::<Test>::contains_key(0, account(4)));
+	});
+}
+
+#[test]
+fn set_collection_metadata_should_work() {
+	new_test_ext().execute_with(|| {
+		// Cannot add metadata to unknown item
+		assert_noop!(
+			Nfts::set_collection_metadata(Origin::root(), 0, bvec![0u8; 20]),
+			Error::<Test>::NoConfig,
+		);
+		assert_ok!(Nfts::force_create(
+			Origin::root(),
+			0,
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		// Cannot add metadata to unowned item
+		assert_noop!(
+			Nfts::set_collection_metadata(Origin::signed(account(2)), 0, bvec![0u8; 20]),
+			Error::<Test>::NoPermission,
+		);
+
+		// Successfully add metadata and take deposit
+		Balances::make_free_balance_be(&account(1), 30);
+		assert_ok!(Nfts::set_collection_metadata(Origin::signed(account(1)), 0, bvec![0u8; 20]));
+		assert_eq!(Balances::free_balance(&account(1)), 9);
+		assert!(CollectionMetadataOf::<Test>::contains_key(0));
+
+		// Force origin works, too.
+		assert_ok!(Nfts::set_collection_metadata(Origin::root(), 0, bvec![0u8; 18]));
+
+		// Update deposit
+		assert_ok!(Nfts::set_collection_metadata(Origin::signed(account(1)), 0, bvec![0u8; 15]));
+		assert_eq!(Balances::free_balance(&account(1)), 14);
+		assert_ok!(Nfts::set_collection_metadata(Origin::signed(account(1)), 0, bvec![0u8; 25]));
+		assert_eq!(Balances::free_balance(&account(1)), 4);
+
+		// Cannot over-reserve
+		assert_noop!(
+			Nfts::set_collection_metadata(Origin::signed(account(1)), 0, bvec![0u8; 40]),
+			BalancesError::<Test, _>::InsufficientBalance,
+		);
+
+		// Can't set or clear metadata once frozen
+		assert_ok!(Nfts::set_collection_metadata(Origin::signed(account(1)), 0, bvec![0u8; 15]));
+		assert_ok!(Nfts::lock_collection(
+			Origin::signed(account(1)),
+			0,
+			CollectionSettings::from_disabled(CollectionSetting::UnlockedMetadata.into())
+		));
+		assert_noop!(
+			Nfts::set_collection_metadata(Origin::signed(account(1)), 0, bvec![0u8; 15]),
+			Error::<Test, _>::LockedCollectionMetadata,
+		);
+		assert_noop!(
+			Nfts::clear_collection_metadata(Origin::signed(account(1)), 0),
+			Error::<Test>::LockedCollectionMetadata
+		);
+
+		// Clear Metadata
+		assert_ok!(Nfts::set_collection_metadata(Origin::root(), 0, bvec![0u8; 15]));
+		assert_noop!(
+			Nfts::clear_collection_metadata(Origin::signed(account(2)), 0),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Nfts::clear_collection_metadata(Origin::root(), 1),
+			Error::<Test>::UnknownCollection
+		);
+		assert_ok!(Nfts::clear_collection_metadata(Origin::root(), 0));
+		assert!(!CollectionMetadataOf::<Test>::contains_key(0));
+	});
+}
+
+#[test]
+fn set_item_metadata_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 30);
+
+		// Cannot add metadata to unknown item
+		assert_ok!(Nfts::force_create(
+			Origin::root(),
+			0,
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::mint(Origin::signed(account(1)), 0, 42, account(1), None));
+		// Cannot add metadata to unowned item
+		assert_noop!(
+			Nfts::set_metadata(Origin::signed(account(2)), 0, 42, bvec![0u8; 20]),
+			Error::<Test>::NoPermission,
+		);
+
+		// Successfully add metadata and take deposit
+		assert_ok!(Nfts::set_metadata(Origin::signed(account(1)), 0, 42, bvec![0u8; 20]));
+		assert_eq!(Balances::free_balance(&account(1)), 8);
+		assert!(ItemMetadataOf::<Test>::contains_key(0, 42));
+
+		// Update deposit
+		assert_ok!(Nfts::set_metadata(Origin::signed(account(1)), 0, 42, bvec![0u8; 15]));
+		assert_eq!(Balances::free_balance(&account(1)), 13);
+		assert_ok!(Nfts::set_metadata(Origin::signed(account(1)), 0, 42, bvec![0u8; 25]));
+		assert_eq!(Balances::free_balance(&account(1)), 3);
+
+		// Cannot over-reserve
+		assert_noop!(
+			Nfts::set_metadata(Origin::signed(account(1)), 0, 42, bvec![0u8; 40]),
+			BalancesError::<Test, _>::InsufficientBalance,
+		);
+
+		// Can't set or clear metadata once frozen
+		assert_ok!(Nfts::set_metadata(Origin::signed(account(1)), 0, 42, bvec![0u8; 15]));
+		assert_ok!(Nfts::lock_item_properties(Origin::signed(account(1)), 0, 42, true, false));
+		assert_noop!(
+			Nfts::set_metadata(Origin::signed(account(1)), 0, 42, bvec![0u8; 15]),
+			Error::<Test, _>::LockedItemMetadata,
+		);
+		assert_noop!(
+			Nfts::clear_metadata(Origin::signed(account(1)), 0, 42),
+			Error::<Test>::LockedItemMetadata,
+		);
+
+		// Clear Metadata
+		assert_ok!(Nfts::set_metadata(Origin::root(), 0, 42, bvec![0u8; 15]));
+		assert_noop!(
+			Nfts::clear_metadata(Origin::signed(account(2)), 0, 42),
+			Error::<Test>::NoPermission,
+		);
+		assert_noop!(
+			Nfts::clear_metadata(Origin::signed(account(1)), 1, 42),
+			Error::<Test>::NoPermission,
+		);
+		assert_ok!(Nfts::clear_metadata(Origin::root(), 0, 42));
+		assert!(!ItemMetadataOf::<Test>::contains_key(0, 42));
+		assert_eq!(Balances::free_balance(&account(1)), 29);
+	});
+}
+
+#[test]
+fn set_attribute_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+
+		assert_ok!(Nfts::force_create(
+			Origin::root(),
+			0,
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+
+		assert_ok!(Nfts::set_attribute(Origin::signed(account(1)), 0, None, bvec![0], bvec![0]));
+		assert_ok!(Nfts::set_attribute(Origin::signed(account(1)), 0, Some(0), bvec![0], bvec![0]));
+		assert_ok!(Nfts::set_attribute(Origin::signed(account(1)), 0, Some(0), bvec![1], bvec![0]));
+		assert_eq!(
+			attributes(0),
+			vec![
+				(None, bvec![0], bvec![0]),
+				(Some(0), bvec![0], bvec![0]),
+				(Some(0), bvec![1], bvec![0]),
+			]
+		);
+		assert_eq!(Balances::reserved_balance(account(1)), 9);
+		assert_eq!(Collection::<Test>::get(0).unwrap().attributes, 3);
+
+		assert_ok!(Nfts::set_attribute(
+			Origin::signed(account(1)),
+			0,
+			None,
+			bvec![0],
+			bvec![0; 10]
+		));
+		assert_eq!(
+			attributes(0),
+			vec![
+				(None, bvec![0], bvec![0; 10]),
+				(Some(0), bvec![0], bvec![0]),
+				(Some(0), bvec![1], bvec![0]),
+			]
+		);
+		assert_eq!(Balances::reserved_balance(account(1)), 18);
+
+		assert_ok!(Nfts::clear_attribute(Origin::signed(account(1)), 0, Some(0), bvec![1]));
+		assert_eq!(
+			attributes(0),
+			vec![(None, bvec![0], bvec![0; 10]), (Some(0), bvec![0], bvec![0]),]
+		);
+		assert_eq!(Balances::reserved_balance(account(1)), 15);
+
+		assert_noop!(
+			Nfts::set_attribute(Origin::signed(account(2)), 0, None, bvec![0], bvec![0]),
+			Error::<Test>::NoPermission
+		);
+
+		let w = Collection::<Test>::get(0).unwrap().destroy_witness();
+		assert_ok!(Nfts::destroy(Origin::signed(account(1)), 0, w));
+		assert_eq!(attributes(0), vec![]);
+		assert_eq!(Balances::reserved_balance(account(1)), 0);
+	});
+}
+
+#[test]
+fn set_attribute_should_respect_lock() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+
+		assert_ok!(Nfts::force_create(
+			Origin::root(),
+			0,
+			account(1),
+			collection_config_with_all_settings_enabled(),
+		));
+		assert_ok!(Nfts::mint(Origin::signed(account(1)), 0, 0, account(1), None));
+		assert_ok!(Nfts::mint(Origin::signed(account(1)), 0, 1, account(1), None));
+
+		assert_ok!(Nfts::set_attribute(Origin::signed(account(1)), 0, None, bvec![0], bvec![0]));
+		assert_ok!(Nfts::set_attribute(Origin::signed(account(1)), 0, Some(0), bvec![0], bvec![0]));
+		assert_ok!(Nfts::set_attribute(Origin::signed(account(1)), 0, Some(1), bvec![0], bvec![0]));
+		assert_eq!(Balances::reserved_balance(account(1)), 11);
+
+		assert_ok!(Nfts::set_collection_metadata(Origin::signed(account(1)), 0, bvec![]));
+		assert_ok!(Nfts::lock_collection(
+			Origin::signed(account(1)),
+			0,
+			CollectionSettings::from_disabled(CollectionSetting::UnlockedAttributes.into())
+		));
+
+		let e = Error::<Test>::LockedCollectionAttributes;
+		assert_noop!(
+			Nfts::set_attribute(Origin::signed(account(1)), 0, None, bvec![0], bvec![0]),
+			e
+		);
+		assert_ok!(Nfts::set_attribute(Origin::signed(account(1)), 0, Some(0), bvec![0], bvec![1]));
+
+		assert_ok!(Nfts::lock_item_properties(Origin::signed(account(1)), 0, 0, false, true));
+		assert_noop!(
+			Nfts::set_attribute(Origin::signed(account(1)), 0, Some(0), bvec![0], bvec![1]),
+			Error::<Test>::LockedItemAttributes
+		);
+		assert_noop!(
+			Nfts::clear_attribute(Origin::signed(account(1)), 0, Some(0), bvec![0]),
+			Error::<Test>::LockedItemAttributes
+		);
+		assert_ok!(Nfts::set_attribute(Origin::signed(account(1)), 0, Some(1), bvec![0], bvec![1]));
+
+		// The force origin may still clear locked attributes.
+		assert_ok!(Nfts::clear_attribute(Origin::root(), 0, Some(0), bvec![0]));
+	});
+}
+
+#[test]
+fn max_supply_should_work() {
+	new_test_ext().execute_with(|| {
+		let collection_id = 0;
+		let user_id = account(1);
+		let max_supply = 2;
+
+		// validate set_collection_max_supply
+		assert_ok!(Nfts::force_create(
+			Origin::root(),
+			collection_id,
+			user_id.clone(),
+			default_collection_config()
+		));
+		assert!(CollectionConfigOf::<Test>::get(collection_id).unwrap().max_supply.is_none());
+
+		assert_ok!(Nfts::set_collection_max_supply(
+			Origin::signed(user_id.clone()),
+			collection_id,
+			max_supply
+		));
+		assert_eq!(
+			CollectionConfigOf::<Test>::get(collection_id).unwrap().max_supply,
+			Some(max_supply)
+		);
+		assert!(
+			events().contains(&Event::<Test>::CollectionMaxSupplySet(collection_id, max_supply))
+		);
+
+		assert_ok!(Nfts::set_collection_max_supply(
+			Origin::signed(user_id.clone()),
+			collection_id,
+			max_supply + 1
+		));
+		assert_ok!(Nfts::lock_collection(
+			Origin::signed(user_id.clone()),
+			collection_id,
+			CollectionSettings::from_disabled(CollectionSetting::UnlockedMaxSupply.into())
+		));
+		assert_noop!(
+			Nfts::set_collection_max_supply(
+				Origin::signed(user_id.clone()),
+				collection_id,
+				max_supply + 2
+			),
+			Error::<Test>::MaxSupplyLocked
+		);
+
+		// validate we can't mint more to max supply
+		assert_ok!(Nfts::mint(
+			Origin::signed(user_id.clone()),
+			collection_id,
+			0,
+			user_id.clone(),
+			None
+		));
+		assert_ok!(Nfts::mint(
+			Origin::signed(user_id.clone()),
+			collection_id,
+			1,
+			user_id.clone(),
+			None
+		));
+		assert_ok!(Nfts::mint(
+			Origin::signed(user_id.clone()),
+			collection_id,
+			2,
+			user_id.clone(),
+			None
+		));
+		assert_noop!(
+			Nfts::mint(Origin::signed(user_id.clone()), collection_id, 3, user_id, None),
+			Error::<Test>::MaxSupplyReached
+		);
+	});
+}
+
+#[test]
+fn mint_settings_should_work() {
+	new_test_ext().execute_with(|| {
+		let collection_id = 0;
+		let user_id = account(1);
+		Balances::make_free_balance_be(&user_id, 100);
+		Balances::make_free_balance_be(&account(2), 100);
+
+		assert_ok!(Nfts::force_create(
+			Origin::root(),
+			collection_id,
+			user_id.clone(),
+			default_collection_config()
+		));
+
+		// Only issuers may mint by default.
+		assert_noop!(
+			Nfts::mint(Origin::signed(account(2)), collection_id, 0, account(2), None),
+			Error::<Test>::NoPermission
+		);
+
+		assert_ok!(Nfts::update_mint_settings(
+			Origin::signed(user_id.clone()),
+			collection_id,
+			MintSettings {
+				mint_type: MintType::Public,
+				price: Some(1),
+				start_block: Some(2),
+				end_block: Some(3),
+				default_item_settings: ItemSettings::from_disabled(
+					ItemSetting::Transferable.into()
+				),
+			}
+		));
+
+		assert_noop!(
+			Nfts::mint(Origin::signed(account(2)), collection_id, 0, account(2), None),
+			Error::<Test>::MintNotStarted
+		);
+
+		System::set_block_number(2);
+		assert_ok!(Nfts::mint(Origin::signed(account(2)), collection_id, 0, account(2), None));
+		assert_eq!(Balances::total_balance(&account(2)), 99);
+		assert_eq!(Balances::total_balance(&user_id), 101);
+		assert_eq!(
+			ItemConfigOf::<Test>::get(collection_id, 0).unwrap(),
+			item_config_from_disabled_settings(ItemSetting::Transferable.into())
+		);
+		assert_noop!(
+			Nfts::transfer(Origin::signed(account(2)), collection_id, 0, account(3)),
+			Error::<Test>::ItemLocked
+		);
+
+		System::set_block_number(4);
+		assert_noop!(
+			Nfts::mint(Origin::signed(account(2)), collection_id, 1, account(2), None),
+			Error::<Test>::MintEnded
+		);
+
+		// Issuers don't pay and ignore the mint window.
+		assert_ok!(Nfts::mint(Origin::signed(user_id.clone()), collection_id, 1, user_id, None));
+		assert_eq!(Balances::total_balance(&account(2)), 99);
+	});
+}
+
+#[test]
+fn holder_of_mint_should_work() {
+	new_test_ext().execute_with(|| {
+		let user_id = account(1);
+		assert_ok!(Nfts::force_create(
+			Origin::root(),
+			0,
+			user_id.clone(),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::force_create(
+			Origin::root(),
+			1,
+			user_id.clone(),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::update_mint_settings(
+			Origin::signed(user_id.clone()),
+			1,
+			MintSettings { mint_type: MintType::HolderOf(0), ..Default::default() }
+		));
+		assert_ok!(Nfts::mint(Origin::signed(user_id.clone()), 0, 42, account(2), None));
+
+		assert_noop!(
+			Nfts::mint(Origin::signed(account(2)), 1, 0, account(2), None),
+			Error::<Test>::BadWitness
+		);
+		assert_noop!(
+			Nfts::mint(
+				Origin::signed(account(3)),
+				1,
+				0,
+				account(3),
+				Some(MintWitness { owned_item: 42 })
+			),
+			Error::<Test>::BadWitness
+		);
+		assert_ok!(Nfts::mint(
+			Origin::signed(account(2)),
+			1,
+			0,
+			account(2),
+			Some(MintWitness { owned_item: 42 })
+		));
+
+		// The same item can't be used twice, even after a transfer.
+		assert_ok!(Nfts::transfer(Origin::signed(account(2)), 0, 42, account(3)));
+		assert_noop!(
+			Nfts::mint(
+				Origin::signed(account(3)),
+				1,
+				1,
+				account(3),
+				Some(MintWitness { owned_item: 42 })
+			),
+			Error::<Test>::AlreadyClaimed
+		);
+	});
+}
+
+#[test]
+fn burn_works() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		assert_ok!(Nfts::force_create(
+			Origin::root(),
+			0,
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::set_team(
+			Origin::signed(account(1)),
+			0,
+			account(2),
+			account(3),
+			account(4)
+		));
+
+		assert_noop!(Nfts::burn(Origin::signed(account(5)), 0, 42), Error::<Test>::UnknownItem);
+
+		assert_ok!(Nfts::force_mint(
+			Origin::signed(account(2)),
+			0,
+			42,
+			account(5),
+			default_item_config()
+		));
+		assert_ok!(Nfts::force_mint(
+			Origin::signed(account(2)),
+			0,
+			69,
+			account(5),
+			item_config_from_disabled_settings(ItemSetting::Transferable.into())
+		));
+		assert_eq!(Balances::reserved_balance(account(1)), 2);
+
+		assert_noop!(Nfts::burn(Origin::signed(account(0)), 0, 42), Error::<Test>::NoPermission);
+
+		assert_ok!(Nfts::burn(Origin::signed(account(5)), 0, 42));
+		assert_ok!(Nfts::burn(Origin::signed(account(5)), 0, 69));
+		assert_eq!(Balances::reserved_balance(account(1)), 0);
+
+		// The config of a burned item with disabled settings is kept and has to be respected.
+		assert!(!ItemConfigOf::<Test>::contains_key(0, 42));
+		assert!(ItemConfigOf::<Test>::contains_key(0, 69));
+		assert_noop!(
+			Nfts::force_mint(Origin::signed(account(2)), 0, 69, account(5), default_item_config()),
+			Error::<Test>::InconsistentItemConfig
+		);
+	});
+}
+
+#[test]
+fn approval_lifecycle_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(Origin::root(), 0, account(1), default_collection_config()));
+		assert_ok!(Nfts::force_mint(
+			Origin::signed(account(1)),
+			0,
+			42,
+			account(2),
+			default_item_config()
+		));
+		assert_ok!(Nfts::approve_transfer(Origin::signed(account(2)), 0, 42, account(3)));
+		assert_ok!(Nfts::transfer(Origin::signed(account(3)), 0, 42, account(4)));
+		assert_noop!(
+			Nfts::transfer(Origin::signed(account(3)), 0, 42, account(3)),
+			Error::<Test>::NoPermission
+		);
+		assert!(Item::<Test>::get(0, 42).unwrap().approved.is_none());
+
+		assert_ok!(Nfts::approve_transfer(Origin::signed(account(4)), 0, 42, account(2)));
+		assert_noop!(
+			Nfts::cancel_approval(Origin::signed(account(4)), 0, 42, Some(account(3))),
+			Error::<Test>::WrongDelegate
+		);
+		assert_ok!(Nfts::cancel_approval(Origin::signed(account(4)), 0, 42, Some(account(2))));
+		assert_noop!(
+			Nfts::cancel_approval(Origin::signed(account(4)), 0, 42, None),
+			Error::<Test>::NoDelegate
+		);
+		assert_noop!(
+			Nfts::transfer(Origin::signed(account(2)), 0, 42, account(2)),
+			Error::<Test>::NoPermission
+		);
+
+		// The collection admin may approve, too.
+		assert_ok!(Nfts::approve_transfer(Origin::signed(account(1)), 0, 42, account(3)));
+		assert_ok!(Nfts::transfer(Origin::signed(account(3)), 0, 42, account(3)));
+	});
+}
+
+#[test]
+fn set_price_and_buy_item_should_work() {
+	new_test_ext().execute_with(|| {
+		let user_1 = account(1);
+		let user_2 = account(2);
+		let user_3 = account(3);
+		let initial_balance = 100;
+
+		Balances::make_free_balance_be(&user_1, initial_balance);
+		Balances::make_free_balance_be(&user_2, initial_balance);
+		Balances::make_free_balance_be(&user_3, initial_balance);
+
+		assert_ok!(Nfts::force_create(
+			Origin::root(),
+			0,
+			user_1.clone(),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::mint(Origin::signed(user_1.clone()), 0, 1, user_1.clone(), None));
+		assert_ok!(Nfts::mint(Origin::signed(user_1.clone()), 0, 2, user_1.clone(), None));
+
+		assert_ok!(Nfts::set_price(Origin::signed(user_1.clone()), 0, 1, Some(20), None));
+		assert_ok!(Nfts::set_price(
+			Origin::signed(user_1.clone()),
+			0,
+			2,
+			Some(30),
+			Some(user_3.clone())
+		));
+		assert_noop!(
+			Nfts::set_price(Origin::signed(user_2.clone()), 0, 1, Some(1), None),
+			Error::<Test>::NoPermission
+		);
+
+		assert_noop!(
+			Nfts::buy_item(Origin::signed(user_2.clone()), 0, 1, 10),
+			Error::<Test>::BidTooLow
+		);
+		assert_ok!(Nfts::buy_item(Origin::signed(user_2.clone()), 0, 1, 25));
+		assert_eq!(Balances::total_balance(&user_1), initial_balance + 20);
+		assert_eq!(Balances::total_balance(&user_2), initial_balance - 20);
+		assert_eq!(Item::<Test>::get(0, 1).unwrap().owner, user_2);
+		assert!(!ItemPriceOf::<Test>::contains_key(0, 1));
+		assert!(events().contains(&Event::<Test>::ItemBought(0, 1, 20, user_1, user_2.clone())));
+
+		// Only the whitelisted buyer can buy the item.
+		assert_noop!(
+			Nfts::buy_item(Origin::signed(user_2.clone()), 0, 2, 30),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Nfts::buy_item(Origin::signed(user_3), 0, 2, 30));
+
+		assert_noop!(Nfts::buy_item(Origin::signed(user_2), 0, 2, 30), Error::<Test>::NotForSale);
+	});
+}
+
+#[test]
+fn create_cancel_and_claim_swap_should_work() {
+	new_test_ext().execute_with(|| {
+		let user_1 = account(1);
+		let user_2 = account(2);
+		let initial_balance = 100;
+		let price = 10;
+		let duration = 2;
+
+		Balances::make_free_balance_be(&user_1, initial_balance);
+		Balances::make_free_balance_be(&user_2, initial_balance);
+
+		assert_ok!(Nfts::force_create(
+			Origin::root(),
+			0,
+			user_1.clone(),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::mint(Origin::signed(user_1.clone()), 0, 1, user_1.clone(), None));
+		assert_ok!(Nfts::mint(Origin::signed(user_1.clone()), 0, 2, user_2.clone(), None));
+
+		let price_with_direction =
+			PriceWithDirection { amount: price, direction: PriceDirection::Receive };
+		assert_noop!(
+			Nfts::create_swap(
+				Origin::signed(user_1.clone()),
+				0,
+				1,
+				0,
+				Some(2),
+				Some(price_with_direction.clone()),
+				MaxDeadlineDuration::get() + 1,
+			),
+			Error::<Test>::WrongDuration
+		);
+		assert_noop!(
+			Nfts::create_swap(
+				Origin::signed(user_2.clone()),
+				0,
+				1,
+				0,
+				Some(2),
+				Some(price_with_direction.clone()),
+				duration,
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Nfts::create_swap(
+			Origin::signed(user_1.clone()),
+			0,
+			1,
+			0,
+			Some(2),
+			Some(price_with_direction.clone()),
+			duration,
+		));
+		assert!(PendingSwapOf::<Test>::contains_key(0, 1));
+
+		// Only the item owner may cancel the swap before the deadline.
+		assert_noop!(
+			Nfts::cancel_swap(Origin::signed(user_2.clone()), 0, 1),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Nfts::cancel_swap(Origin::signed(user_1.clone()), 0, 1));
+		assert!(!PendingSwapOf::<Test>::contains_key(0, 1));
+
+		assert_ok!(Nfts::create_swap(
+			Origin::signed(user_1.clone()),
+			0,
+			1,
+			0,
+			Some(2),
+			Some(price_with_direction.clone()),
+			duration,
+		));
+		// The witness price has to match.
+		assert_noop!(
+			Nfts::claim_swap(
+				Origin::signed(user_2.clone()),
+				0,
+				2,
+				0,
+				1,
+				Some(PriceWithDirection { amount: price + 1, direction: PriceDirection::Receive }),
+			),
+			Error::<Test>::UnknownSwap
+		);
+		assert_ok!(Nfts::claim_swap(
+			Origin::signed(user_2.clone()),
+			0,
+			2,
+			0,
+			1,
+			Some(price_with_direction.clone()),
+		));
+		assert_eq!(Item::<Test>::get(0, 1).unwrap().owner, user_2);
+		assert_eq!(Item::<Test>::get(0, 2).unwrap().owner, user_1);
+		assert_eq!(Balances::total_balance(&user_1), initial_balance + price);
+		assert_eq!(Balances::total_balance(&user_2), initial_balance - price);
+		assert!(!PendingSwapOf::<Test>::contains_key(0, 1));
+
+		// Anybody may remove an expired swap.
+		assert_ok!(Nfts::create_swap(
+			Origin::signed(user_1.clone()),
+			0,
+			2,
+			0,
+			Some(1),
+			None,
+			duration,
+		));
+		System::set_block_number(1 + duration + 1);
+		assert_noop!(
+			Nfts::claim_swap(Origin::signed(user_2.clone()), 0, 1, 0, 2, None),
+			Error::<Test>::DeadlineExpired
+		);
+		assert_ok!(Nfts::cancel_swap(Origin::signed(user_2), 0, 2));
+	});
+}
+
+#[test]
+fn pre_signed_mints_should_work() {
+	new_test_ext().execute_with(|| {
+		let user_0 = account(0);
+		let user_1_pair = sr25519::Pair::from_string("//Alice", None).unwrap();
+		let user_1_signer = MultiSigner::Sr25519(user_1_pair.public());
+		let user_1 = user_1_signer.clone().into_account();
+		let mint_data = PreSignedMint {
+			collection: 0,
+			item: 0,
+			attributes: vec![(vec![0], vec![1]), (vec![2], vec![3])],
+			metadata: vec![0, 1],
+			only_account: None,
+			deadline: 10000,
+		};
+		let message = Encode::encode(&mint_data);
+		let signature = MultiSignature::Sr25519(user_1_pair.sign(&message));
+		let user_2 = account(2);
+
+		Balances::make_free_balance_be(&user_0, 100);
+		Balances::make_free_balance_be(&user_2, 100);
+		assert_ok!(Nfts::create(
+			Origin::signed(user_0.clone()),
+			0,
+			user_1.clone(),
+			collection_config_with_all_settings_enabled(),
+		));
+
+		assert_ok!(Nfts::mint_pre_signed(
+			Origin::signed(user_2.clone()),
+			Box::new(mint_data.clone()),
+			signature.clone(),
+			user_1.clone(),
+		));
+		assert_eq!(items(), vec![(user_2.clone(), 0, 0)]);
+		assert_eq!(attributes(0), vec![(Some(0), vec![0], vec![1]), (Some(0), vec![2], vec![3])]);
+		assert!(ItemMetadataOf::<Test>::contains_key(0, 0));
+		// item deposit + 2 attributes + metadata
+		assert_eq!(Balances::reserved_balance(user_2.clone()), 1 + 3 + 3 + 3);
+
+		assert_noop!(
+			Nfts::mint_pre_signed(
+				Origin::signed(user_2.clone()),
+				Box::new(mint_data),
+				signature.clone(),
+				user_1.clone(),
+			),
+			Error::<Test>::AlreadyExists
+		);
+
+		// Only the issuer may sign, and the signature has to match.
+		let mint_data = PreSignedMint {
+			collection: 0,
+			item: 1,
+			attributes: vec![],
+			metadata: vec![],
+			only_account: Some(account(3)),
+			deadline: 10000,
+		};
+		assert_noop!(
+			Nfts::mint_pre_signed(
+				Origin::signed(user_2.clone()),
+				Box::new(mint_data.clone()),
+				signature,
+				user_1.clone(),
+			),
+			Error::<Test>::WrongSignature
+		);
+		let signature = MultiSignature::Sr25519(user_1_pair.sign(&Encode::encode(&mint_data)));
+		assert_noop!(
+			Nfts::mint_pre_signed(
+				Origin::signed(user_2),
+				Box::new(mint_data.clone()),
+				signature.clone(),
+				user_1.clone(),
+			),
+			Error::<Test>::WrongOrigin
+		);
+
+		System::set_block_number(10002);
+		assert_noop!(
+			Nfts::mint_pre_signed(
+				Origin::signed(account(3)),
+				Box::new(mint_data),
+				signature,
+				user_1,
+			),
+			Error::<Test>::DeadlineExpired
+		);
+	});
+}
+
+#[test]
+fn pre_signed_attributes_should_work() {
+	new_test_ext().execute_with(|| {
+		let user_1_pair = sr25519::Pair::from_string("//Alice", None).unwrap();
+		let user_1_signer = MultiSigner::Sr25519(user_1_pair.public());
+		let user_1 = user_1_signer.into_account();
+		let user_2 = account(2);
+		let pre_signed_data = PreSignedAttributes {
+			collection: 0,
+			item: 0,
+			attributes: vec![(vec![0], vec![1]), (vec![2], vec![3])],
+			deadline: 10000,
+		};
+		let message = Encode::encode(&pre_signed_data);
+		let signature = MultiSignature::Sr25519(user_1_pair.sign(&message));
+
+		Balances::make_free_balance_be(&user_1, 100);
+		Balances::make_free_balance_be(&user_2, 100);
+		assert_ok!(Nfts::create(
+			Origin::signed(user_1.clone()),
+			0,
+			user_1.clone(),
+			collection_config_with_all_settings_enabled(),
+		));
+		assert_ok!(Nfts::mint(Origin::signed(user_1.clone()), 0, 0, user_2.clone(), None));
+
+		// Only the item owner may submit the signed attributes.
+		assert_noop!(
+			Nfts::set_attributes_pre_signed(
+				Origin::signed(account(3)),
+				pre_signed_data.clone(),
+				signature.clone(),
+				user_1.clone(),
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Nfts::set_attributes_pre_signed(
+			Origin::signed(user_2.clone()),
+			pre_signed_data,
+			signature,
+			user_1.clone(),
+		));
+		assert_eq!(attributes(0), vec![(Some(0), vec![0], vec![1]), (Some(0), vec![2], vec![3])]);
+		assert_eq!(Balances::reserved_balance(user_2.clone()), 6);
+		assert!(events().contains(&Event::<Test>::PreSignedAttributesSet(0, 0, user_1)));
+
+		// A signer which isn't an admin is rejected.
+		let user_3_pair = sr25519::Pair::from_string("//Bob", None).unwrap();
+		let user_3 = MultiSigner::Sr25519(user_3_pair.public()).into_account();
+		let pre_signed_data = PreSignedAttributes {
+			collection: 0,
+			item: 0,
+			attributes: vec![(vec![4], vec![5])],
+			deadline: 10000,
+		};
+		let signature =
+			MultiSignature::Sr25519(user_3_pair.sign(&Encode::encode(&pre_signed_data)));
+		assert_noop!(
+			Nfts::set_attributes_pre_signed(
+				Origin::signed(user_2),
+				pre_signed_data,
+				signature,
+				user_3,
+			),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn nonfungibles_traits_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		assert_ok!(<Nfts as Create<_>>::create_class(&0, &account(1), &account(1)));
+		assert_eq!(Balances::reserved_balance(&account(1)), 2);
+		assert_eq!(<Nfts as Inspect<_>>::class_owner(&0), Some(account(1)));
+
+		assert_ok!(<Nfts as Mutate<_>>::mint_into(&0, &42, &account(2)));
+		assert_eq!(<Nfts as Inspect<_>>::owner(&0, &42), Some(account(2)));
+		assert!(<Nfts as Inspect<_>>::can_transfer(&0, &42));
+
+		assert_ok!(<Nfts as Mutate<_>>::set_attribute(&0, &42, &[1], &[2]));
+		assert_ok!(<Nfts as Mutate<_>>::set_class_attribute(&0, &[3], &[4]));
+		assert_eq!(<Nfts as Inspect<_>>::attribute(&0, &42, &[1]), Some(vec![2]));
+		assert_eq!(<Nfts as Inspect<_>>::class_attribute(&0, &[3]), Some(vec![4]));
+		// No deposit is taken for attributes set through the trait.
+		assert_eq!(Balances::reserved_balance(&account(1)), 3);
+
+		assert_eq!(<Nfts as InspectEnumerable<_>>::classes().collect::<Vec<_>>(), vec![0]);
+		assert_eq!(<Nfts as InspectEnumerable<_>>::instances(&0).collect::<Vec<_>>(), vec![42]);
+		assert_eq!(
+			<Nfts as InspectEnumerable<_>>::owned(&account(2)).collect::<Vec<_>>(),
+			vec![(0, 42)]
+		);
+
+		assert_ok!(Nfts::lock_item_transfer(Origin::signed(account(1)), 0, 42));
+		assert!(!<Nfts as Inspect<_>>::can_transfer(&0, &42));
+
+		assert_ok!(<Nfts as Mutate<_>>::burn_from(&0, &42));
+		assert_eq!(<Nfts as Inspect<_>>::owner(&0, &42), None);
+	});
+}
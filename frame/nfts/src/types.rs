@@ -0,0 +1,415 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Various basic types for use in the NFTs pallet.
+
+use super::*;
+use crate::macros::*;
+use codec::{EncodeLike, MaxEncodedLen};
+use enumflags2::BitFlags;
+use frame_support::{traits::Get, BoundedVec};
+use scale_info::{build::Fields, meta_type, Path, Type, TypeInfo, TypeParameter};
+
+pub(super) type DepositBalanceOf<T, I = ()> =
+	<<T as Config<I>>::Currency as Currency<<T as SystemConfig>::AccountId>>::Balance;
+pub(super) type CollectionDetailsFor<T, I> =
+	CollectionDetails<<T as SystemConfig>::AccountId, DepositBalanceOf<T, I>>;
+pub(super) type ItemDetailsFor<T, I> =
+	ItemDetails<<T as SystemConfig>::AccountId, DepositBalanceOf<T, I>>;
+pub(super) type BalanceOf<T, I = ()> =
+	<<T as Config<I>>::Currency as Currency<<T as SystemConfig>::AccountId>>::Balance;
+pub(super) type ItemPrice<T, I = ()> = BalanceOf<T, I>;
+pub(super) type CollectionConfigFor<T, I = ()> = CollectionConfig<
+	BalanceOf<T, I>,
+	<T as SystemConfig>::BlockNumber,
+	<T as Config<I>>::CollectionId,
+>;
+pub(super) type PendingSwapFor<T, I = ()> = PendingSwap<
+	<T as Config<I>>::CollectionId,
+	<T as Config<I>>::ItemId,
+	PriceWithDirection<ItemPrice<T, I>>,
+	<T as SystemConfig>::BlockNumber,
+>;
+pub(super) type PreSignedMintOf<T, I = ()> = PreSignedMint<
+	<T as Config<I>>::CollectionId,
+	<T as Config<I>>::ItemId,
+	<T as SystemConfig>::AccountId,
+	<T as SystemConfig>::BlockNumber,
+>;
+pub(super) type PreSignedAttributesOf<T, I = ()> = PreSignedAttributes<
+	<T as Config<I>>::CollectionId,
+	<T as Config<I>>::ItemId,
+	<T as SystemConfig>::BlockNumber,
+>;
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct CollectionDetails<AccountId, DepositBalance> {
+	/// Can change `owner`, `issuer`, `freezer` and `admin` accounts.
+	pub(super) owner: AccountId,
+	/// The total balance deposited by the owner for the collection itself and its metadata.
+	/// Moves with the ownership of the collection.
+	pub(super) owner_deposit: DepositBalance,
+	/// The total number of outstanding items of this collection.
+	pub(super) items: u32,
+	/// The total number of outstanding item metadata of this collection.
+	pub(super) item_metadatas: u32,
+	/// The total number of attributes for this collection.
+	pub(super) attributes: u32,
+}
+
+/// Witness data for the destroy transactions.
+#[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct DestroyWitness {
+	/// The total number of outstanding items of this collection.
+	#[codec(compact)]
+	pub items: u32,
+	/// The total number of items in this collection that have outstanding item metadata.
+	#[codec(compact)]
+	pub item_metadatas: u32,
+	/// The total number of attributes for this collection.
+	#[codec(compact)]
+	pub attributes: u32,
+}
+
+impl<AccountId, DepositBalance> CollectionDetails<AccountId, DepositBalance> {
+	pub fn destroy_witness(&self) -> DestroyWitness {
+		DestroyWitness {
+			items: self.items,
+			item_metadatas: self.item_metadatas,
+			attributes: self.attributes,
+		}
+	}
+}
+
+/// Witness data for items mint transactions.
+#[derive(Clone, Encode, Decode, Default, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct MintWitness<ItemId> {
+	/// Provide the id of the item in a required collection.
+	pub owned_item: ItemId,
+}
+
+/// A reserved deposit together with the account which paid for it.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct AccountDeposit<AccountId, DepositBalance> {
+	/// The account which has reserved the funds.
+	pub(super) account: AccountId,
+	/// The amount of funds reserved.
+	pub(super) amount: DepositBalance,
+}
+
+/// Information concerning the ownership of a single unique item.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct ItemDetails<AccountId, DepositBalance> {
+	/// The owner of this item.
+	pub(super) owner: AccountId,
+	/// The approved transferrer of this item, if one is set.
+	pub(super) approved: Option<AccountId>,
+	/// The amount held in the pallet's default account for this item.
+	pub(super) deposit: AccountDeposit<AccountId, DepositBalance>,
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[scale_info(skip_type_params(StringLimit))]
+pub struct CollectionMetadata<DepositBalance, StringLimit: Get<u32>> {
+	/// The balance deposited for this metadata.
+	///
+	/// This pays for the data stored in this struct.
+	pub(super) deposit: DepositBalance,
+	/// General information concerning this collection. Limited in length by `StringLimit`. This
+	/// will generally be either a JSON dump or the hash of some JSON which can be found on a
+	/// hash-addressable global publication system such as IPFS.
+	pub(super) data: BoundedVec<u8, StringLimit>,
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[scale_info(skip_type_params(StringLimit))]
+pub struct ItemMetadata<AccountId, DepositBalance, StringLimit: Get<u32>> {
+	/// The balance deposited for this metadata.
+	///
+	/// This pays for the data stored in this struct.
+	pub(super) deposit: AccountDeposit<AccountId, DepositBalance>,
+	/// General information concerning this item. Limited in length by `StringLimit`. This will
+	/// generally be either a JSON dump or the hash of some JSON which can be found on a
+	/// hash-addressable global publication system such as IPFS.
+	pub(super) data: BoundedVec<u8, StringLimit>,
+}
+
+/// The direction in which the price of a swap is paid.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum PriceDirection {
+	/// The creator of the swap pays the price to the claimer.
+	Send,
+	/// The claimer of the swap pays the price to the creator.
+	Receive,
+}
+
+/// Holds the details about the price.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct PriceWithDirection<Amount> {
+	/// An amount.
+	pub amount: Amount,
+	/// A direction (send or receive).
+	pub direction: PriceDirection,
+}
+
+/// Information about the pending swap.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct PendingSwap<CollectionId, ItemId, ItemPriceWithDirection, Deadline> {
+	/// A collection of the item user wants to receive.
+	pub(super) desired_collection: CollectionId,
+	/// An item user wants to receive.
+	pub(super) desired_item: Option<ItemId>,
+	/// A price for the desired `item` with the direction.
+	pub(super) price: Option<ItemPriceWithDirection>,
+	/// A deadline for the swap.
+	pub(super) deadline: Deadline,
+}
+
+/// Support for up to 64 user-enabled features on a collection.
+#[repr(u64)]
+#[derive(Clone, Copy, PartialEq, Eq, BitFlags, RuntimeDebug, TypeInfo)]
+pub enum CollectionSetting {
+	/// Items in this collection are transferable.
+	TransferableItems = 0b0000_0001,
+	/// The metadata of this collection can be modified.
+	UnlockedMetadata = 0b0000_0010,
+	/// Attributes of this collection can be modified.
+	UnlockedAttributes = 0b0000_0100,
+	/// The supply of this collection can be modified.
+	UnlockedMaxSupply = 0b0000_1000,
+	/// When this isn't set then the deposit is not required for creating items, metadata or
+	/// attributes.
+	DepositRequired = 0b0001_0000,
+}
+
+/// Wrapper type for `BitFlags<CollectionSetting>` that implements `Codec`.
+///
+/// The flags represent the settings which have been *disabled*.
+#[derive(Clone, Copy, PartialEq, Eq, Default, RuntimeDebug)]
+pub struct CollectionSettings(pub BitFlags<CollectionSetting>);
+
+impl CollectionSettings {
+	/// All the settings are enabled.
+	pub fn all_enabled() -> Self {
+		Self(BitFlags::empty())
+	}
+	/// Only the provided settings are disabled.
+	pub fn from_disabled(settings: BitFlags<CollectionSetting>) -> Self {
+		Self(settings)
+	}
+	/// Returns the disabled settings.
+	pub fn get_disabled(&self) -> BitFlags<CollectionSetting> {
+		self.0
+	}
+	/// Returns `true` if the `setting` is disabled.
+	pub fn is_disabled(&self, setting: CollectionSetting) -> bool {
+		self.0.contains(setting)
+	}
+}
+
+impl_codec_bitflags!(CollectionSettings, u64, CollectionSetting);
+
+/// Mint type. Can the NFT be created by anyone, or only the creator of the collection,
+/// or only by wallets that already hold an NFT from a certain collection?
+/// The ownership of a privately minted NFT is still publicly visible.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum MintType<CollectionId> {
+	/// Only an `Issuer` could mint items.
+	Issuer,
+	/// Anyone could mint items.
+	Public,
+	/// Only holders of items in specified collection could mint new items.
+	HolderOf(CollectionId),
+}
+
+/// Holds the information about minting.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct MintSettings<Price, BlockNumber, CollectionId> {
+	/// Whether anyone can mint or if minters are restricted to some subset.
+	pub mint_type: MintType<CollectionId>,
+	/// An optional price per mint.
+	pub price: Option<Price>,
+	/// When the mint starts.
+	pub start_block: Option<BlockNumber>,
+	/// When the mint ends.
+	pub end_block: Option<BlockNumber>,
+	/// Default settings each item will get during the mint.
+	pub default_item_settings: ItemSettings,
+}
+
+impl<Price, BlockNumber, CollectionId> Default for MintSettings<Price, BlockNumber, CollectionId> {
+	fn default() -> Self {
+		Self {
+			mint_type: MintType::Issuer,
+			price: None,
+			start_block: None,
+			end_block: None,
+			default_item_settings: ItemSettings::all_enabled(),
+		}
+	}
+}
+
+/// Collection's configuration.
+#[derive(Clone, Copy, Decode, Default, Encode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct CollectionConfig<Price, BlockNumber, CollectionId> {
+	/// Collection's settings.
+	pub settings: CollectionSettings,
+	/// Collection's max supply.
+	pub max_supply: Option<u32>,
+	/// Default settings each item will get during the mint.
+	pub mint_settings: MintSettings<Price, BlockNumber, CollectionId>,
+}
+
+impl<Price, BlockNumber, CollectionId> CollectionConfig<Price, BlockNumber, CollectionId> {
+	pub fn is_setting_enabled(&self, setting: CollectionSetting) -> bool {
+		!self.settings.is_disabled(setting)
+	}
+	pub fn has_disabled_setting(&self, setting: CollectionSetting) -> bool {
+		self.settings.is_disabled(setting)
+	}
+	pub fn enable_setting(&mut self, setting: CollectionSetting) {
+		self.settings.0.remove(setting);
+	}
+	pub fn disable_setting(&mut self, setting: CollectionSetting) {
+		self.settings.0.insert(setting);
+	}
+}
+
+/// Support for up to 64 user-enabled features on an item.
+#[repr(u64)]
+#[derive(Clone, Copy, PartialEq, Eq, BitFlags, RuntimeDebug, TypeInfo)]
+pub enum ItemSetting {
+	/// This item is transferable.
+	Transferable = 0b0000_0001,
+	/// The metadata of this item can be modified.
+	UnlockedMetadata = 0b0000_0010,
+	/// Attributes of this item can be modified.
+	UnlockedAttributes = 0b0000_0100,
+}
+
+/// Wrapper type for `BitFlags<ItemSetting>` that implements `Codec`.
+///
+/// The flags represent the settings which have been *disabled*.
+#[derive(Clone, Copy, PartialEq, Eq, Default, RuntimeDebug)]
+pub struct ItemSettings(pub BitFlags<ItemSetting>);
+
+impl ItemSettings {
+	/// All the settings are enabled.
+	pub fn all_enabled() -> Self {
+		Self(BitFlags::empty())
+	}
+	/// Only the provided settings are disabled.
+	pub fn from_disabled(settings: BitFlags<ItemSetting>) -> Self {
+		Self(settings)
+	}
+	/// Returns the disabled settings.
+	pub fn get_disabled(&self) -> BitFlags<ItemSetting> {
+		self.0
+	}
+	/// Returns `true` if the `setting` is disabled.
+	pub fn is_disabled(&self, setting: ItemSetting) -> bool {
+		self.0.contains(setting)
+	}
+}
+
+impl_codec_bitflags!(ItemSettings, u64, ItemSetting);
+
+/// Item's configuration.
+#[derive(Encode, Decode, Default, PartialEq, RuntimeDebug, Clone, Copy, Eq, TypeInfo)]
+pub struct ItemConfig {
+	/// Item's settings.
+	pub settings: ItemSettings,
+}
+
+impl ItemConfig {
+	pub fn is_setting_enabled(&self, setting: ItemSetting) -> bool {
+		!self.settings.is_disabled(setting)
+	}
+	pub fn has_disabled_setting(&self, setting: ItemSetting) -> bool {
+		self.settings.is_disabled(setting)
+	}
+	pub fn has_disabled_settings(&self) -> bool {
+		!self.settings.get_disabled().is_empty()
+	}
+	pub fn enable_setting(&mut self, setting: ItemSetting) {
+		self.settings.0.remove(setting);
+	}
+	pub fn disable_setting(&mut self, setting: ItemSetting) {
+		self.settings.0.insert(setting);
+	}
+}
+
+/// Support for up to 8 different roles for collections.
+#[repr(u8)]
+#[derive(Clone, Copy, PartialEq, Eq, BitFlags, RuntimeDebug, TypeInfo)]
+pub enum CollectionRole {
+	/// Can mint items.
+	Issuer = 0b0000_0001,
+	/// Can freeze items.
+	Freezer = 0b0000_0010,
+	/// Can thaw items, force transfers and burn items from any account.
+	Admin = 0b0000_0100,
+}
+
+/// A wrapper type that implements `Codec`.
+#[derive(Clone, Copy, PartialEq, Eq, Default, RuntimeDebug)]
+pub struct CollectionRoles(pub BitFlags<CollectionRole>);
+
+impl CollectionRoles {
+	pub fn none() -> Self {
+		Self(BitFlags::empty())
+	}
+	pub fn has_role(&self, role: CollectionRole) -> bool {
+		self.0.contains(role)
+	}
+	pub fn add_role(&mut self, role: CollectionRole) {
+		self.0.insert(role);
+	}
+}
+
+impl_codec_bitflags!(CollectionRoles, u8, CollectionRole);
+
+/// Data which is signed off-chain by a collection issuer to allow anyone to mint an item.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct PreSignedMint<CollectionId, ItemId, AccountId, Deadline> {
+	/// A collection of the item to be minted.
+	pub collection: CollectionId,
+	/// Item's ID.
+	pub item: ItemId,
+	/// Additional item's key-value attributes.
+	pub attributes: Vec<(Vec<u8>, Vec<u8>)>,
+	/// Additional item's metadata.
+	pub metadata: Vec<u8>,
+	/// Restrict the claim to a particular account.
+	pub only_account: Option<AccountId>,
+	/// A deadline for the signature.
+	pub deadline: Deadline,
+}
+
+/// Data which is signed off-chain by a collection admin to allow an item owner to set
+/// attributes.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct PreSignedAttributes<CollectionId, ItemId, Deadline> {
+	/// Collection's ID.
+	pub collection: CollectionId,
+	/// Item's ID.
+	pub item: ItemId,
+	/// Key-value attributes.
+	pub attributes: Vec<(Vec<u8>, Vec<u8>)>,
+	/// A deadline for the signature.
+	pub deadline: Deadline,
+}
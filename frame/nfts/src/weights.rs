@@ -0,0 +1,694 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Autogenerated weights for pallet_nfts
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2021-10-06, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 128
+
+// Executed Command:
+// target/release/substrate
+// benchmark
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_nfts
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./frame/nfts/src/weights.rs
+// --template=./.maintain/frame-weight-template.hbs
+
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_nfts.
+pub trait WeightInfo {
+	fn create() -> Weight;
+	fn force_create() -> Weight;
+	fn destroy(n: u32, m: u32, a: u32, ) -> Weight;
+	fn mint() -> Weight;
+	fn force_mint() -> Weight;
+	fn burn() -> Weight;
+	fn transfer() -> Weight;
+	fn lock_item_transfer() -> Weight;
+	fn unlock_item_transfer() -> Weight;
+	fn lock_collection() -> Weight;
+	fn transfer_ownership() -> Weight;
+	fn set_team() -> Weight;
+	fn force_collection_config() -> Weight;
+	fn approve_transfer() -> Weight;
+	fn cancel_approval() -> Weight;
+	fn lock_item_properties() -> Weight;
+	fn set_attribute() -> Weight;
+	fn clear_attribute() -> Weight;
+	fn set_metadata() -> Weight;
+	fn clear_metadata() -> Weight;
+	fn set_collection_metadata() -> Weight;
+	fn clear_collection_metadata() -> Weight;
+	fn set_collection_max_supply() -> Weight;
+	fn update_mint_settings() -> Weight;
+	fn set_price() -> Weight;
+	fn buy_item() -> Weight;
+	fn create_swap() -> Weight;
+	fn cancel_swap() -> Weight;
+	fn claim_swap() -> Weight;
+	fn mint_pre_signed(n: u32, ) -> Weight;
+	fn set_attributes_pre_signed(n: u32, ) -> Weight;
+}
+
+/// Weights for pallet_nfts using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: Nfts Collection (r:1 w:1)
+	// Storage: Nfts CollectionRoleOf (r:0 w:1)
+	// Storage: Nfts CollectionConfigOf (r:0 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn create() -> Weight {
+		(44_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: Nfts Collection (r:1 w:1)
+	// Storage: Nfts CollectionRoleOf (r:0 w:1)
+	// Storage: Nfts CollectionConfigOf (r:0 w:1)
+	fn force_create() -> Weight {
+		(25_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Nfts Collection (r:1 w:1)
+	// Storage: Nfts Item (r:1 w:0)
+	// Storage: Nfts Attribute (r:0 w:1000)
+	// Storage: Nfts CollectionMetadataOf (r:0 w:1)
+	// Storage: Nfts ItemMetadataOf (r:0 w:1000)
+	// Storage: Nfts CollectionRoleOf (r:0 w:1)
+	// Storage: Nfts CollectionConfigOf (r:0 w:1)
+	// Storage: Nfts ItemConfigOf (r:0 w:1000)
+	// Storage: Nfts Account (r:0 w:20)
+	fn destroy(n: u32, m: u32, a: u32, ) -> Weight {
+		(0 as Weight)
+			// Standard Error: 12_000
+			.saturating_add((18_000_000 as Weight).saturating_mul(n as Weight))
+			// Standard Error: 12_000
+			.saturating_add((1_100_000 as Weight).saturating_mul(m as Weight))
+			// Standard Error: 12_000
+			.saturating_add((1_000_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(m as Weight)))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(a as Weight)))
+	}
+	// Storage: Nfts Item (r:1 w:1)
+	// Storage: Nfts Collection (r:1 w:1)
+	// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	// Storage: Nfts CollectionRoleOf (r:1 w:0)
+	// Storage: Nfts ItemConfigOf (r:1 w:1)
+	// Storage: Nfts Account (r:0 w:1)
+	fn mint() -> Weight {
+		(58_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Nfts Item (r:1 w:1)
+	// Storage: Nfts Collection (r:1 w:1)
+	// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	// Storage: Nfts ItemConfigOf (r:1 w:1)
+	// Storage: Nfts Account (r:0 w:1)
+	fn force_mint() -> Weight {
+		(57_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Nfts Collection (r:1 w:1)
+	// Storage: Nfts Item (r:1 w:1)
+	// Storage: Nfts ItemConfigOf (r:1 w:1)
+	// Storage: Nfts ItemMetadataOf (r:0 w:1)
+	// Storage: Nfts Account (r:0 w:1)
+	// Storage: Nfts ItemPriceOf (r:0 w:1)
+	// Storage: Nfts PendingSwapOf (r:0 w:1)
+	fn burn() -> Weight {
+		(59_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+	}
+	// Storage: Nfts Collection (r:1 w:0)
+	// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	// Storage: Nfts ItemConfigOf (r:1 w:0)
+	// Storage: Nfts Item (r:1 w:1)
+	// Storage: Nfts Account (r:0 w:2)
+	// Storage: Nfts ItemPriceOf (r:0 w:1)
+	// Storage: Nfts PendingSwapOf (r:0 w:1)
+	fn transfer() -> Weight {
+		(47_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: Nfts CollectionRoleOf (r:1 w:0)
+	// Storage: Nfts ItemConfigOf (r:1 w:1)
+	fn lock_item_transfer() -> Weight {
+		(29_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Nfts CollectionRoleOf (r:1 w:0)
+	// Storage: Nfts ItemConfigOf (r:1 w:1)
+	fn unlock_item_transfer() -> Weight {
+		(29_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Nfts Collection (r:1 w:0)
+	// Storage: Nfts CollectionConfigOf (r:1 w:1)
+	fn lock_collection() -> Weight {
+		(26_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Nfts Collection (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn transfer_ownership() -> Weight {
+		(35_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Nfts Collection (r:1 w:0)
+	// Storage: Nfts CollectionRoleOf (r:0 w:4)
+	fn set_team() -> Weight {
+		(37_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Nfts Collection (r:1 w:0)
+	// Storage: Nfts CollectionConfigOf (r:0 w:1)
+	fn force_collection_config() -> Weight {
+		(23_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Nfts Collection (r:1 w:0)
+	// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	// Storage: Nfts Item (r:1 w:1)
+	// Storage: Nfts CollectionRoleOf (r:1 w:0)
+	fn approve_transfer() -> Weight {
+		(33_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Nfts Collection (r:1 w:0)
+	// Storage: Nfts Item (r:1 w:1)
+	// Storage: Nfts CollectionRoleOf (r:1 w:0)
+	fn cancel_approval() -> Weight {
+		(32_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Nfts Item (r:1 w:0)
+	// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	// Storage: Nfts ItemConfigOf (r:1 w:1)
+	fn lock_item_properties() -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Nfts CollectionRoleOf (r:1 w:0)
+	// Storage: Nfts Collection (r:1 w:1)
+	// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	// Storage: Nfts ItemConfigOf (r:1 w:0)
+	// Storage: Nfts Attribute (r:1 w:1)
+	fn set_attribute() -> Weight {
+		(61_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Nfts Attribute (r:1 w:1)
+	// Storage: Nfts CollectionRoleOf (r:1 w:0)
+	// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	// Storage: Nfts ItemConfigOf (r:1 w:0)
+	// Storage: Nfts Collection (r:1 w:1)
+	fn clear_attribute() -> Weight {
+		(56_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Nfts CollectionRoleOf (r:1 w:0)
+	// Storage: Nfts Collection (r:1 w:1)
+	// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	// Storage: Nfts ItemConfigOf (r:1 w:0)
+	// Storage: Nfts ItemMetadataOf (r:1 w:1)
+	fn set_metadata() -> Weight {
+		(52_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Nfts CollectionRoleOf (r:1 w:0)
+	// Storage: Nfts Collection (r:1 w:1)
+	// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	// Storage: Nfts ItemConfigOf (r:1 w:0)
+	// Storage: Nfts ItemMetadataOf (r:1 w:1)
+	fn clear_metadata() -> Weight {
+		(51_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Nfts Collection (r:1 w:1)
+	// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	// Storage: Nfts CollectionMetadataOf (r:1 w:1)
+	fn set_collection_metadata() -> Weight {
+		(49_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Nfts Collection (r:1 w:1)
+	// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	// Storage: Nfts CollectionMetadataOf (r:1 w:1)
+	fn clear_collection_metadata() -> Weight {
+		(47_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Nfts Collection (r:1 w:0)
+	// Storage: Nfts CollectionConfigOf (r:1 w:1)
+	fn set_collection_max_supply() -> Weight {
+		(28_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Nfts CollectionRoleOf (r:1 w:0)
+	// Storage: Nfts CollectionConfigOf (r:1 w:1)
+	fn update_mint_settings() -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Nfts Item (r:1 w:0)
+	// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	// Storage: Nfts ItemConfigOf (r:1 w:0)
+	// Storage: Nfts ItemPriceOf (r:0 w:1)
+	fn set_price() -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Nfts Item (r:1 w:1)
+	// Storage: Nfts ItemPriceOf (r:1 w:1)
+	// Storage: Nfts Collection (r:1 w:0)
+	// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	// Storage: Nfts ItemConfigOf (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Nfts Account (r:0 w:2)
+	// Storage: Nfts PendingSwapOf (r:0 w:1)
+	fn buy_item() -> Weight {
+		(65_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+	}
+	// Storage: Nfts Item (r:2 w:0)
+	// Storage: Nfts PendingSwapOf (r:0 w:1)
+	fn create_swap() -> Weight {
+		(32_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Nfts Item (r:1 w:0)
+	// Storage: Nfts PendingSwapOf (r:1 w:1)
+	fn cancel_swap() -> Weight {
+		(31_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Nfts Item (r:2 w:2)
+	// Storage: Nfts PendingSwapOf (r:1 w:2)
+	// Storage: Nfts Collection (r:1 w:0)
+	// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	// Storage: Nfts ItemConfigOf (r:2 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Nfts Account (r:0 w:4)
+	// Storage: Nfts ItemPriceOf (r:0 w:2)
+	fn claim_swap() -> Weight {
+		(92_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+	}
+	// Storage: Nfts CollectionRoleOf (r:2 w:0)
+	// Storage: Nfts Item (r:1 w:1)
+	// Storage: Nfts Collection (r:1 w:1)
+	// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	// Storage: Nfts ItemConfigOf (r:1 w:1)
+	// Storage: Nfts ItemMetadataOf (r:1 w:1)
+	// Storage: Nfts Attribute (r:10 w:10)
+	// Storage: Nfts Account (r:0 w:1)
+	fn mint_pre_signed(n: u32, ) -> Weight {
+		(94_000_000 as Weight)
+			// Standard Error: 12_000
+			.saturating_add((9_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: Nfts Item (r:1 w:0)
+	// Storage: Nfts CollectionRoleOf (r:1 w:0)
+	// Storage: Nfts Collection (r:1 w:1)
+	// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	// Storage: Nfts ItemConfigOf (r:1 w:0)
+	// Storage: Nfts Attribute (r:10 w:10)
+	fn set_attributes_pre_signed(n: u32, ) -> Weight {
+		(65_000_000 as Weight)
+			// Standard Error: 12_000
+			.saturating_add((9_500_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(n as Weight)))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: Nfts Collection (r:1 w:1)
+	// Storage: Nfts CollectionRoleOf (r:0 w:1)
+	// Storage: Nfts CollectionConfigOf (r:0 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn create() -> Weight {
+		(44_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	// Storage: Nfts Collection (r:1 w:1)
+	// Storage: Nfts CollectionRoleOf (r:0 w:1)
+	// Storage: Nfts CollectionConfigOf (r:0 w:1)
+	fn force_create() -> Weight {
+		(25_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Nfts Collection (r:1 w:1)
+	// Storage: Nfts Item (r:1 w:0)
+	// Storage: Nfts Attribute (r:0 w:1000)
+	// Storage: Nfts CollectionMetadataOf (r:0 w:1)
+	// Storage: Nfts ItemMetadataOf (r:0 w:1000)
+	// Storage: Nfts CollectionRoleOf (r:0 w:1)
+	// Storage: Nfts CollectionConfigOf (r:0 w:1)
+	// Storage: Nfts ItemConfigOf (r:0 w:1000)
+	// Storage: Nfts Account (r:0 w:20)
+	fn destroy(n: u32, m: u32, a: u32, ) -> Weight {
+		(0 as Weight)
+			// Standard Error: 12_000
+			.saturating_add((18_000_000 as Weight).saturating_mul(n as Weight))
+			// Standard Error: 12_000
+			.saturating_add((1_100_000 as Weight).saturating_mul(m as Weight))
+			// Standard Error: 12_000
+			.saturating_add((1_000_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(m as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(a as Weight)))
+	}
+	// Storage: Nfts Item (r:1 w:1)
+	// Storage: Nfts Collection (r:1 w:1)
+	// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	// Storage: Nfts CollectionRoleOf (r:1 w:0)
+	// Storage: Nfts ItemConfigOf (r:1 w:1)
+	// Storage: Nfts Account (r:0 w:1)
+	fn mint() -> Weight {
+		(58_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Nfts Item (r:1 w:1)
+	// Storage: Nfts Collection (r:1 w:1)
+	// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	// Storage: Nfts ItemConfigOf (r:1 w:1)
+	// Storage: Nfts Account (r:0 w:1)
+	fn force_mint() -> Weight {
+		(57_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Nfts Collection (r:1 w:1)
+	// Storage: Nfts Item (r:1 w:1)
+	// Storage: Nfts ItemConfigOf (r:1 w:1)
+	// Storage: Nfts ItemMetadataOf (r:0 w:1)
+	// Storage: Nfts Account (r:0 w:1)
+	// Storage: Nfts ItemPriceOf (r:0 w:1)
+	// Storage: Nfts PendingSwapOf (r:0 w:1)
+	fn burn() -> Weight {
+		(59_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
+	}
+	// Storage: Nfts Collection (r:1 w:0)
+	// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	// Storage: Nfts ItemConfigOf (r:1 w:0)
+	// Storage: Nfts Item (r:1 w:1)
+	// Storage: Nfts Account (r:0 w:2)
+	// Storage: Nfts ItemPriceOf (r:0 w:1)
+	// Storage: Nfts PendingSwapOf (r:0 w:1)
+	fn transfer() -> Weight {
+		(47_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	// Storage: Nfts CollectionRoleOf (r:1 w:0)
+	// Storage: Nfts ItemConfigOf (r:1 w:1)
+	fn lock_item_transfer() -> Weight {
+		(29_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Nfts CollectionRoleOf (r:1 w:0)
+	// Storage: Nfts ItemConfigOf (r:1 w:1)
+	fn unlock_item_transfer() -> Weight {
+		(29_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Nfts Collection (r:1 w:0)
+	// Storage: Nfts CollectionConfigOf (r:1 w:1)
+	fn lock_collection() -> Weight {
+		(26_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Nfts Collection (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn transfer_ownership() -> Weight {
+		(35_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Nfts Collection (r:1 w:0)
+	// Storage: Nfts CollectionRoleOf (r:0 w:4)
+	fn set_team() -> Weight {
+		(37_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Nfts Collection (r:1 w:0)
+	// Storage: Nfts CollectionConfigOf (r:0 w:1)
+	fn force_collection_config() -> Weight {
+		(23_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Nfts Collection (r:1 w:0)
+	// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	// Storage: Nfts Item (r:1 w:1)
+	// Storage: Nfts CollectionRoleOf (r:1 w:0)
+	fn approve_transfer() -> Weight {
+		(33_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Nfts Collection (r:1 w:0)
+	// Storage: Nfts Item (r:1 w:1)
+	// Storage: Nfts CollectionRoleOf (r:1 w:0)
+	fn cancel_approval() -> Weight {
+		(32_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Nfts Item (r:1 w:0)
+	// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	// Storage: Nfts ItemConfigOf (r:1 w:1)
+	fn lock_item_properties() -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Nfts CollectionRoleOf (r:1 w:0)
+	// Storage: Nfts Collection (r:1 w:1)
+	// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	// Storage: Nfts ItemConfigOf (r:1 w:0)
+	// Storage: Nfts Attribute (r:1 w:1)
+	fn set_attribute() -> Weight {
+		(61_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Nfts Attribute (r:1 w:1)
+	// Storage: Nfts CollectionRoleOf (r:1 w:0)
+	// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	// Storage: Nfts ItemConfigOf (r:1 w:0)
+	// Storage: Nfts Collection (r:1 w:1)
+	fn clear_attribute() -> Weight {
+		(56_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Nfts CollectionRoleOf (r:1 w:0)
+	// Storage: Nfts Collection (r:1 w:1)
+	// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	// Storage: Nfts ItemConfigOf (r:1 w:0)
+	// Storage: Nfts ItemMetadataOf (r:1 w:1)
+	fn set_metadata() -> Weight {
+		(52_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Nfts CollectionRoleOf (r:1 w:0)
+	// Storage: Nfts Collection (r:1 w:1)
+	// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	// Storage: Nfts ItemConfigOf (r:1 w:0)
+	// Storage: Nfts ItemMetadataOf (r:1 w:1)
+	fn clear_metadata() -> Weight {
+		(51_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Nfts Collection (r:1 w:1)
+	// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	// Storage: Nfts CollectionMetadataOf (r:1 w:1)
+	fn set_collection_metadata() -> Weight {
+		(49_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Nfts Collection (r:1 w:1)
+	// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	// Storage: Nfts CollectionMetadataOf (r:1 w:1)
+	fn clear_collection_metadata() -> Weight {
+		(47_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Nfts Collection (r:1 w:0)
+	// Storage: Nfts CollectionConfigOf (r:1 w:1)
+	fn set_collection_max_supply() -> Weight {
+		(28_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Nfts CollectionRoleOf (r:1 w:0)
+	// Storage: Nfts CollectionConfigOf (r:1 w:1)
+	fn update_mint_settings() -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Nfts Item (r:1 w:0)
+	// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	// Storage: Nfts ItemConfigOf (r:1 w:0)
+	// Storage: Nfts ItemPriceOf (r:0 w:1)
+	fn set_price() -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Nfts Item (r:1 w:1)
+	// Storage: Nfts ItemPriceOf (r:1 w:1)
+	// Storage: Nfts Collection (r:1 w:0)
+	// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	// Storage: Nfts ItemConfigOf (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Nfts Account (r:0 w:2)
+	// Storage: Nfts PendingSwapOf (r:0 w:1)
+	fn buy_item() -> Weight {
+		(65_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
+	}
+	// Storage: Nfts Item (r:2 w:0)
+	// Storage: Nfts PendingSwapOf (r:0 w:1)
+	fn create_swap() -> Weight {
+		(32_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Nfts Item (r:1 w:0)
+	// Storage: Nfts PendingSwapOf (r:1 w:1)
+	fn cancel_swap() -> Weight {
+		(31_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Nfts Item (r:2 w:2)
+	// Storage: Nfts PendingSwapOf (r:1 w:2)
+	// Storage: Nfts Collection (r:1 w:0)
+	// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	// Storage: Nfts ItemConfigOf (r:2 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Nfts Account (r:0 w:4)
+	// Storage: Nfts ItemPriceOf (r:0 w:2)
+	fn claim_swap() -> Weight {
+		(92_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
+	}
+	// Storage: Nfts CollectionRoleOf (r:2 w:0)
+	// Storage: Nfts Item (r:1 w:1)
+	// Storage: Nfts Collection (r:1 w:1)
+	// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	// Storage: Nfts ItemConfigOf (r:1 w:1)
+	// Storage: Nfts ItemMetadataOf (r:1 w:1)
+	// Storage: Nfts Attribute (r:10 w:10)
+	// Storage: Nfts Account (r:0 w:1)
+	fn mint_pre_signed(n: u32, ) -> Weight {
+		(94_000_000 as Weight)
+			// Standard Error: 12_000
+			.saturating_add((9_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: Nfts Item (r:1 w:0)
+	// Storage: Nfts CollectionRoleOf (r:1 w:0)
+	// Storage: Nfts Collection (r:1 w:1)
+	// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	// Storage: Nfts ItemConfigOf (r:1 w:0)
+	// Storage: Nfts Attribute (r:10 w:10)
+	fn set_attributes_pre_signed(n: u32, ) -> Weight {
+		(65_000_000 as Weight)
+			// Standard Error: 12_000
+			.saturating_add((9_500_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(n as Weight)))
+	}
+}
@@ -36,7 +36,10 @@ use sp_staking::offence::{Offence, ReportOffence};
 use pallet_babe::BabeEquivocationOffence;
 use pallet_balances::Config as BalancesConfig;
 use pallet_grandpa::{GrandpaEquivocationOffence, GrandpaTimeSlot};
-use pallet_im_online::{Config as ImOnlineConfig, Pallet as ImOnline, UnresponsivenessOffence};
+use pallet_im_online::{
+	Config as ImOnlineConfig, Pallet as ImOnline, UnresponsivenessOffence,
+	UnresponsivenessSlashCurve,
+};
 use pallet_offences::{Config as OffencesConfig, Pallet as Offences};
 use pallet_session::{
 	historical::{Config as HistoricalConfig, IdentificationTuple},
@@ -269,14 +272,10 @@ benchmarks! {
 		let keys =  ImOnline::<T>::keys();
 		let validator_set_count = keys.len() as u32;
 
-		let slash_fraction = UnresponsivenessOffence::<T::AccountId>::slash_fraction(
+		let slash_fraction = <T as ImOnlineConfig>::SlashCurve::slash_fraction(
 			offenders.len() as u32, validator_set_count,
 		);
-		let offence = UnresponsivenessOffence {
-			session_index: 0,
-			validator_set_count,
-			offenders,
-		};
+		let offence = UnresponsivenessOffence::new(0, validator_set_count, offenders);
 		assert_eq!(System::<T>::event_count(), 0);
 	}: {
 		let _ = <T as ImOnlineConfig>::ReportUnresponsiveness::report_offence(
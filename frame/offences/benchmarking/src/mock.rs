@@ -186,6 +186,8 @@ impl pallet_im_online::Config for Test {
 	type Event = Event;
 	type ValidatorSet = Historical;
 	type NextSessionRotation = pallet_session::PeriodicSessions<Period, Offset>;
+	type OfflineThreshold = ();
+	type SlashCurve = pallet_im_online::DefaultSlashCurve;
 	type ReportUnresponsiveness = Offences;
 	type UnsignedPriority = ();
 	type WeightInfo = ();
@@ -198,6 +200,8 @@ impl pallet_offences::Config for Test {
 	type Event = Event;
 	type IdentificationTuple = pallet_session::historical::IdentificationTuple<Self>;
 	type OnOffenceHandler = Staking;
+	type DeferredOffencesBudget = ();
+	type MaxDeferredOffences = ();
 }
 
 impl<T> frame_system::offchain::SendTransactionTypes<T> for Test
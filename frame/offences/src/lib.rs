@@ -18,6 +18,11 @@
 //! # Offences Pallet
 //!
 //! Tracks reported offences
+//!
+//! Reported offences are passed to the `OnOffenceHandler` right away, unless
+//! `Config::DeferredOffencesBudget` is non-zero. In that case they are queued and applied in
+//! `on_initialize`, oldest first, until the budget of the block is consumed. Offences reported
+//! while the queue holds `Config::MaxDeferredOffences` are applied right away.
 
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -27,7 +32,7 @@ mod mock;
 mod tests;
 
 use codec::{Decode, Encode};
-use frame_support::weights::Weight;
+use frame_support::{traits::Get, weights::Weight};
 use sp_runtime::{traits::Hash, Perbill};
 use sp_staking::{
 	offence::{Kind, Offence, OffenceDetails, OffenceError, OnOffenceHandler, ReportOffence},
@@ -43,6 +48,14 @@ type OpaqueTimeSlot = Vec<u8>;
 /// A type alias for a report identifier.
 type ReportIdOf<T> = <T as frame_system::Config>::Hash;
 
+/// Type of data stored as a deferred offence: the offenders, their slash fractions and the session
+/// index of the offence.
+pub type DeferredOffenceOf<T> = (
+	Vec<OffenceDetails<<T as frame_system::Config>::AccountId, <T as Config>::IdentificationTuple>>,
+	Vec<Perbill>,
+	SessionIndex,
+);
+
 pub trait WeightInfo {
 	fn report_offence_im_online(r: u32, o: u32, n: u32) -> Weight;
 	fn report_offence_grandpa(r: u32, n: u32) -> Weight;
@@ -84,6 +97,15 @@ pub mod pallet {
 		type IdentificationTuple: Parameter + Ord;
 		/// A handler called for every offence report.
 		type OnOffenceHandler: OnOffenceHandler<Self::AccountId, Self::IdentificationTuple, Weight>;
+		/// The weight which may be consumed by applying deferred offences in a block.
+		///
+		/// If zero, offences are not deferred but passed to the `OnOffenceHandler` as soon as
+		/// they are reported.
+		#[pallet::constant]
+		type DeferredOffencesBudget: Get<Weight>;
+		/// The maximum number of offences which may be deferred at once.
+		#[pallet::constant]
+		type MaxDeferredOffences: Get<u32>;
 	}
 
 	/// The primary structure that holds all offence records keyed by report identifiers.
@@ -123,6 +145,13 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// Offences which have been reported but not yet passed to the `OnOffenceHandler`, oldest
+	/// first.
+	#[pallet::storage]
+	#[pallet::getter(fn deferred_offences)]
+	pub type DeferredOffences<T: Config> =
+		StorageValue<_, BoundedVec<DeferredOffenceOf<T>, T::MaxDeferredOffences>, ValueQuery>;
+
 	/// Events type.
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -135,8 +164,12 @@ pub mod pallet {
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_now: T::BlockNumber) -> Weight {
+			Self::process_deferred_offences(T::DeferredOffencesBudget::get())
+		}
+
 		fn on_runtime_upgrade() -> Weight {
-			migration::remove_deferred_storage::<T>()
+			migration::bound_deferred_offences::<T>()
 		}
 	}
 }
@@ -168,11 +201,13 @@ where
 		let slash_perbill: Vec<_> =
 			(0..concurrent_offenders.len()).map(|_| new_fraction.clone()).collect();
 
-		T::OnOffenceHandler::on_offence(
-			&concurrent_offenders,
-			&slash_perbill,
-			offence.session_index(),
-		);
+		let deferred = (concurrent_offenders, slash_perbill, offence.session_index());
+		if T::DeferredOffencesBudget::get() == 0 ||
+			<DeferredOffences<T>>::try_append(&deferred).is_err()
+		{
+			let (concurrent_offenders, slash_perbill, session_index) = deferred;
+			T::OnOffenceHandler::on_offence(&concurrent_offenders, &slash_perbill, session_index);
+		}
 
 		// Deposit the event.
 		Self::deposit_event(Event::Offence(O::ID, time_slot.encode()));
@@ -191,6 +226,42 @@ where
 }
 
 impl<T: Config> Pallet<T> {
+	/// Pass deferred offences to the `OnOffenceHandler`, oldest first, until `max_weight` is
+	/// consumed.
+	///
+	/// At least one offence is applied if any is queued, so the consumed weight can exceed
+	/// `max_weight` by the weight of the last applied offence. Returns the consumed weight.
+	pub fn process_deferred_offences(max_weight: Weight) -> Weight {
+		let deferred = <DeferredOffences<T>>::get();
+		if deferred.is_empty() {
+			return T::DbWeight::get().reads(1)
+		}
+
+		let mut consumed: Weight = 0;
+		let mut applied = 0;
+		for (offenders, slash_fraction, session) in deferred.iter() {
+			if applied > 0 && consumed >= max_weight {
+				break
+			}
+			let weight = T::OnOffenceHandler::on_offence(offenders, slash_fraction, *session);
+			consumed = consumed.saturating_add(weight);
+			applied += 1;
+		}
+
+		let deferred = deferred
+			.try_mutate(|deferred| {
+				deferred.drain(..applied);
+			})
+			.expect("removing offences keeps the queue within its bound; qed");
+		if deferred.is_empty() {
+			<DeferredOffences<T>>::kill();
+		} else {
+			<DeferredOffences<T>>::put(deferred);
+		}
+
+		consumed.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+	}
+
 	/// Compute the ID for the given report properties.
 	///
 	/// The report id depends on the offence kind, time slot and the id of offender.
@@ -15,47 +15,69 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::{Config, OffenceDetails, Perbill, SessionIndex};
+use super::{Config, DeferredOffenceOf};
 use frame_support::{
-	generate_storage_alias, pallet_prelude::ValueQuery, traits::Get, weights::Weight,
+	generate_storage_alias, pallet_prelude::ValueQuery, traits::Get, weights::Weight, BoundedVec,
 };
 use sp_staking::offence::OnOffenceHandler;
-use sp_std::vec::Vec;
+use sp_std::{convert::TryFrom, vec::Vec};
 
-/// Type of data stored as a deferred offence
-type DeferredOffenceOf<T> = (
-	Vec<OffenceDetails<<T as frame_system::Config>::AccountId, <T as Config>::IdentificationTuple>>,
-	Vec<Perbill>,
-	SessionIndex,
-);
-
-// Deferred reports that have been rejected by the offence handler and need to be submitted
-// at a later time.
+// The deferred offences, read without the bound, as older versions of the pallet did not enforce
+// one.
 generate_storage_alias!(
 	Offences,
 	DeferredOffences<T: Config> => Value<Vec<DeferredOffenceOf<T>>, ValueQuery>
 );
 
-pub fn remove_deferred_storage<T: Config>() -> Weight {
-	let mut weight = T::DbWeight::get().reads_writes(1, 1);
-	let deferred = <DeferredOffences<T>>::take();
+/// Apply the oldest deferred offences until the queue fits in `Config::MaxDeferredOffences`, or
+/// all of them if deferring is disabled.
+pub fn bound_deferred_offences<T: Config>() -> Weight {
+	let mut weight = T::DbWeight::get().reads(1);
+	let mut deferred = <DeferredOffences<T>>::get();
+	let max_deferred = if T::DeferredOffencesBudget::get() == 0 {
+		0
+	} else {
+		T::MaxDeferredOffences::get() as usize
+	};
+	if deferred.len() <= max_deferred {
+		return weight
+	}
+
+	let kept = deferred.split_off(deferred.len() - max_deferred);
 	log::info!(target: "runtime::offences", "have {} deferred offences, applying.", deferred.len());
 	for (offences, perbill, session) in deferred.iter() {
-		let consumed = T::OnOffenceHandler::on_offence(&offences, &perbill, *session);
+		let consumed = T::OnOffenceHandler::on_offence(offences, perbill, *session);
 		weight = weight.saturating_add(consumed);
 	}
 
-	weight
+	if kept.is_empty() {
+		<crate::DeferredOffences<T>>::kill();
+	} else {
+		let kept = BoundedVec::try_from(kept).expect("`kept` is at most the bound; qed");
+		<crate::DeferredOffences<T>>::put(kept);
+	}
+	weight.saturating_add(T::DbWeight::get().writes(1))
 }
 
 #[cfg(test)]
 mod test {
 	use super::*;
-	use crate::mock::{new_test_ext, with_on_offence_fractions, Offences, Runtime as T};
+	use crate::mock::{
+		new_test_ext, with_on_offence_fractions, DeferredOffencesBudget, MaxDeferredOffences,
+		Offences, Runtime as T,
+	};
 	use frame_support::traits::OnRuntimeUpgrade;
 	use sp_runtime::Perbill;
 	use sp_staking::offence::OffenceDetails;
 
+	fn deferred_offence(offender: u64) -> DeferredOffenceOf<T> {
+		(
+			vec![OffenceDetails { offender, reporters: vec![] }],
+			vec![Perbill::from_percent(5 + 1 * 100 / 5)],
+			1,
+		)
+	}
+
 	#[test]
 	fn should_resubmit_deferred_offences() {
 		new_test_ext().execute_with(|| {
@@ -65,20 +87,8 @@ mod test {
 				assert_eq!(f.clone(), vec![]);
 			});
 
-			let offence_details = OffenceDetails::<
-				<T as frame_system::Config>::AccountId,
-				<T as Config>::IdentificationTuple,
-			> {
-				offender: 5,
-				reporters: vec![],
-			};
-
 			// push deferred offence
-			<DeferredOffences<T>>::append((
-				vec![offence_details],
-				vec![Perbill::from_percent(5 + 1 * 100 / 5)],
-				1,
-			));
+			<DeferredOffences<T>>::append(deferred_offence(5));
 
 			// when
 			assert_eq!(
@@ -93,4 +103,34 @@ mod test {
 			});
 		})
 	}
+
+	#[test]
+	fn should_keep_deferred_offences_within_bound() {
+		new_test_ext().execute_with(|| {
+			// given
+			DeferredOffencesBudget::set(150);
+			MaxDeferredOffences::set(2);
+			for offender in 1..=3 {
+				<DeferredOffences<T>>::append(deferred_offence(offender));
+			}
+
+			// when
+			Offences::on_runtime_upgrade();
+
+			// then the oldest offence is applied
+			with_on_offence_fractions(|f| {
+				assert_eq!(f.clone(), vec![Perbill::from_percent(5 + 1 * 100 / 5)]);
+			});
+			assert_eq!(
+				Offences::deferred_offences().into_inner(),
+				vec![deferred_offence(2), deferred_offence(3)]
+			);
+
+			// and nothing is left to do
+			assert_eq!(
+				Offences::on_runtime_upgrade(),
+				<T as frame_system::Config>::DbWeight::get().reads(1),
+			);
+		})
+	}
 }
@@ -21,9 +21,10 @@
 
 use super::*;
 use crate::mock::{
-	new_test_ext, offence_reports, report_id, with_on_offence_fractions, Event, Offence, Offences,
-	System, KIND,
+	new_test_ext, offence_reports, report_id, set_offence_weight, with_on_offence_fractions,
+	DeferredOffencesBudget, Event, MaxDeferredOffences, Offence, Offences, Runtime, System, KIND,
 };
+use frame_support::traits::OnInitialize;
 use frame_system::{EventRecord, Phase};
 use sp_runtime::Perbill;
 
@@ -286,3 +287,81 @@ fn should_properly_sort_offences() {
 		);
 	});
 }
+
+#[test]
+fn should_defer_offences_when_budget_is_set() {
+	new_test_ext().execute_with(|| {
+		// given
+		DeferredOffencesBudget::set(150);
+		let offence = Offence { validator_set_count: 5, time_slot: 42, offenders: vec![5] };
+
+		// when
+		Offences::report_offence(vec![], offence).unwrap();
+
+		// then
+		with_on_offence_fractions(|f| {
+			assert_eq!(f.clone(), vec![]);
+		});
+		assert_eq!(
+			Offences::deferred_offences().into_inner(),
+			vec![(
+				vec![OffenceDetails { offender: 5, reporters: vec![] }],
+				vec![Perbill::from_percent(25)],
+				1
+			)]
+		);
+
+		Offences::on_initialize(2);
+		with_on_offence_fractions(|f| {
+			assert_eq!(f.clone(), vec![Perbill::from_percent(25)]);
+		});
+		assert!(!<DeferredOffences<Runtime>>::exists());
+	});
+}
+
+#[test]
+fn should_apply_deferred_offences_within_budget() {
+	new_test_ext().execute_with(|| {
+		// given
+		DeferredOffencesBudget::set(150);
+		set_offence_weight(100);
+		for time_slot in 1..=3 {
+			let offence = Offence { validator_set_count: 5, time_slot, offenders: vec![5] };
+			Offences::report_offence(vec![], offence).unwrap();
+		}
+		assert_eq!(Offences::deferred_offences().len(), 3);
+
+		// when
+		let db_weight = <Runtime as frame_system::Config>::DbWeight::get();
+		assert_eq!(Offences::on_initialize(2), 200 + db_weight.reads_writes(1, 1));
+
+		// then
+		assert_eq!(Offences::deferred_offences().len(), 1);
+
+		// at least one offence is applied, whatever its weight
+		assert_eq!(Offences::process_deferred_offences(0), 100 + db_weight.reads_writes(1, 1));
+		assert!(Offences::deferred_offences().is_empty());
+		assert_eq!(Offences::on_initialize(3), db_weight.reads(1));
+	});
+}
+
+#[test]
+fn should_apply_offences_right_away_when_queue_is_full() {
+	new_test_ext().execute_with(|| {
+		// given
+		DeferredOffencesBudget::set(150);
+		MaxDeferredOffences::set(1);
+		let offence = Offence { validator_set_count: 5, time_slot: 1, offenders: vec![5] };
+		Offences::report_offence(vec![], offence).unwrap();
+
+		// when
+		let offence = Offence { validator_set_count: 5, time_slot: 2, offenders: vec![5] };
+		Offences::report_offence(vec![], offence).unwrap();
+
+		// then
+		with_on_offence_fractions(|f| {
+			assert_eq!(f.clone(), vec![Perbill::from_percent(25)]);
+		});
+		assert_eq!(Offences::deferred_offences().len(), 1);
+	});
+}
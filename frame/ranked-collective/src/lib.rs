@@ -0,0 +1,629 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Ranked collective system.
+//!
+//! - [`Config`]
+//! - [`Call`]
+//!
+//! ## Overview
+//!
+//! This is a membership pallet providing a `Polling` based voting system. Each member of the
+//! collective has a _rank_, an integer starting at zero. New members are inducted at rank zero by
+//! the [`Config::AdminOrigin`], which may later promote or demote them one rank at a time, or
+//! remove them entirely.
+//!
+//! Members may vote on any poll of the configured [`Config::Polls`] (usually a referenda pallet
+//! instance) whose class (the minimum rank required to vote) is at or below their own rank. The
+//! weight of each vote grows with how far the voter's rank exceeds the poll's class: a member of
+//! rank `r` voting on a poll of class `c` contributes `r - c + 1` votes. The support of a poll is
+//! the proportion of all members eligible to vote on it who have voted aye, irrespective of rank.
+//!
+//! Several origin guards are provided ([`EnsureRanked`], [`EnsureMember`] and
+//! [`EnsureRankedMember`]) so that other pallets may require a signed origin from a member of at
+//! least some rank.
+//!
+//! Votes of polls which have concluded may be removed by anyone with `cleanup_poll`.
+//!
+//! The pallet implements [`RankedMembers`], allowing other pallets (e.g. a salary pallet) to
+//! query and alter the ranks of members.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::{traits::Saturating, ArithmeticError::Overflow, Perbill, RuntimeDebug};
+use sp_std::{marker::PhantomData, prelude::*};
+
+use frame_support::{
+	dispatch::{DispatchError, DispatchResult, DispatchResultWithPostInfo},
+	ensure,
+	traits::{EnsureOrigin, PollStatus, Polling, RankedMembers, VoteTally},
+	weights::{Pays, PostDispatchInfo},
+	CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+};
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+/// A number of members.
+pub type MemberIndex = u32;
+
+/// Member rank.
+pub type Rank = u16;
+
+/// Votes.
+pub type Votes = u32;
+
+/// Aggregated votes for an ongoing poll by members of the ranked collective.
+#[derive(
+	CloneNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, TypeInfo, Encode, Decode,
+)]
+#[scale_info(skip_type_params(M))]
+pub struct Tally<M: GetMaxVoters> {
+	/// The number of members which have voted aye, irrespective of their rank.
+	bare_ayes: MemberIndex,
+	/// The number of aye votes, weighted by rank.
+	ayes: Votes,
+	/// The number of nay votes, weighted by rank.
+	nays: Votes,
+	/// Dummy.
+	dummy: PhantomData<M>,
+}
+
+impl<M: GetMaxVoters> Tally<M> {
+	/// Create a tally from its raw parts.
+	pub fn from_parts(bare_ayes: MemberIndex, ayes: Votes, nays: Votes) -> Self {
+		Tally { bare_ayes, ayes, nays, dummy: PhantomData }
+	}
+}
+
+/// The tally type used by the ranked collective `T`, instance `I`.
+pub type TallyOf<T, I = ()> = Tally<Pallet<T, I>>;
+/// The poll index type used by the ranked collective `T`, instance `I`.
+pub type PollIndexOf<T, I = ()> = <<T as Config<I>>::Polls as Polling<TallyOf<T, I>>>::Index;
+
+impl<M: GetMaxVoters> VoteTally<Votes, Rank> for Tally<M> {
+	fn new(_: Rank) -> Self {
+		Self { bare_ayes: 0, ayes: 0, nays: 0, dummy: PhantomData }
+	}
+
+	fn ayes(&self, _: Rank) -> Votes {
+		self.bare_ayes
+	}
+
+	fn support(&self, class: Rank) -> Perbill {
+		Perbill::from_rational(self.bare_ayes, M::get_max_voters(class))
+	}
+
+	fn approval(&self, _: Rank) -> Perbill {
+		Perbill::from_rational(self.ayes, 1.max(self.ayes.saturating_add(self.nays)))
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn unanimity(class: Rank) -> Self {
+		Self {
+			bare_ayes: M::get_max_voters(class),
+			ayes: M::get_max_voters(class),
+			nays: 0,
+			dummy: PhantomData,
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn rejection(class: Rank) -> Self {
+		Self { bare_ayes: 0, ayes: 0, nays: M::get_max_voters(class), dummy: PhantomData }
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn from_requirements(support: Perbill, approval: Perbill, class: Rank) -> Self {
+		let c = M::get_max_voters(class);
+		let ayes = support * c;
+		let nays =
+			((ayes as u64) * 1_000_000_000u64 / approval.deconstruct().max(1) as u64) as u32 - ayes;
+		Self { bare_ayes: ayes, ayes, nays, dummy: PhantomData }
+	}
+}
+
+/// Record needed for every member.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct MemberRecord {
+	/// The rank of the member.
+	rank: Rank,
+}
+
+impl MemberRecord {
+	/// Create a new member record of the given `rank`.
+	pub fn new(rank: Rank) -> Self {
+		Self { rank }
+	}
+
+	/// The rank of the member.
+	pub fn rank(&self) -> Rank {
+		self.rank
+	}
+}
+
+/// Record needed for every vote.
+#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum VoteRecord {
+	/// Vote was an aye with given vote weight.
+	Aye(Votes),
+	/// Vote was a nay with given vote weight.
+	Nay(Votes),
+}
+
+impl From<(bool, Votes)> for VoteRecord {
+	fn from((aye, votes): (bool, Votes)) -> Self {
+		match aye {
+			true => VoteRecord::Aye(votes),
+			false => VoteRecord::Nay(votes),
+		}
+	}
+}
+
+/// Something that can tell the number of members eligible to vote on polls of a given class.
+pub trait GetMaxVoters {
+	/// Return the maximum number of members eligible to vote on polls of `class`.
+	fn get_max_voters(class: Rank) -> MemberIndex;
+}
+impl<T: Config<I>, I: 'static> GetMaxVoters for Pallet<T, I> {
+	fn get_max_voters(class: Rank) -> MemberIndex {
+		MemberCount::<T, I>::get(class)
+	}
+}
+
+/// Guard to ensure that the given origin is a member of the collective. The rank of the member is
+/// the `Success` value.
+pub struct EnsureRanked<T, I, const MIN_RANK: u16>(PhantomData<(T, I)>);
+impl<T: Config<I>, I: 'static, const MIN_RANK: u16> EnsureOrigin<T::Origin>
+	for EnsureRanked<T, I, MIN_RANK>
+{
+	type Success = Rank;
+
+	fn try_origin(o: T::Origin) -> Result<Self::Success, T::Origin> {
+		let who = frame_system::EnsureSigned::try_origin(o)?;
+		match Members::<T, I>::get(&who) {
+			Some(MemberRecord { rank, .. }) if rank >= MIN_RANK => Ok(rank),
+			_ => Err(frame_system::RawOrigin::Signed(who).into()),
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn successful_origin() -> T::Origin {
+		EnsureRankedMember::<T, I, MIN_RANK>::successful_origin()
+	}
+}
+
+/// Guard to ensure that the given origin is a member of the collective. The account ID of the
+/// member is the `Success` value.
+pub struct EnsureMember<T, I, const MIN_RANK: u16>(PhantomData<(T, I)>);
+impl<T: Config<I>, I: 'static, const MIN_RANK: u16> EnsureOrigin<T::Origin>
+	for EnsureMember<T, I, MIN_RANK>
+{
+	type Success = T::AccountId;
+
+	fn try_origin(o: T::Origin) -> Result<Self::Success, T::Origin> {
+		let who = frame_system::EnsureSigned::try_origin(o)?;
+		match Members::<T, I>::get(&who) {
+			Some(MemberRecord { rank, .. }) if rank >= MIN_RANK => Ok(who),
+			_ => Err(frame_system::RawOrigin::Signed(who).into()),
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn successful_origin() -> T::Origin {
+		EnsureRankedMember::<T, I, MIN_RANK>::successful_origin()
+	}
+}
+
+/// Guard to ensure that the given origin is a member of the collective. The pair of including both
+/// the account ID and the rank of the member is the `Success` value.
+pub struct EnsureRankedMember<T, I, const MIN_RANK: u16>(PhantomData<(T, I)>);
+impl<T: Config<I>, I: 'static, const MIN_RANK: u16> EnsureOrigin<T::Origin>
+	for EnsureRankedMember<T, I, MIN_RANK>
+{
+	type Success = (T::AccountId, Rank);
+
+	fn try_origin(o: T::Origin) -> Result<Self::Success, T::Origin> {
+		let who = frame_system::EnsureSigned::try_origin(o)?;
+		match Members::<T, I>::get(&who) {
+			Some(MemberRecord { rank, .. }) if rank >= MIN_RANK => Ok((who, rank)),
+			_ => Err(frame_system::RawOrigin::Signed(who).into()),
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn successful_origin() -> T::Origin {
+		let who = IndexToId::<T, I>::get(MIN_RANK, 0)
+			.expect("Must be at least one member at rank for benchmarking");
+		frame_system::RawOrigin::Signed(who).into()
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T, I = ()>(PhantomData<(T, I)>);
+
+	#[pallet::config]
+	pub trait Config<I: 'static = ()>: frame_system::Config {
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		/// The runtime event type.
+		type Event: From<Event<Self, I>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The origin required to add, promote, demote or remove a member.
+		type AdminOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The polling system used for our voting.
+		type Polls: Polling<
+			TallyOf<Self, I>,
+			Votes = Votes,
+			Class = Rank,
+			Moment = Self::BlockNumber,
+		>;
+	}
+
+	/// The number of members in the collective who have at least the rank according to the index
+	/// of the vec.
+	#[pallet::storage]
+	pub type MemberCount<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, Rank, MemberIndex, ValueQuery>;
+
+	/// The current members of the collective.
+	#[pallet::storage]
+	pub type Members<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, T::AccountId, MemberRecord>;
+
+	/// The index of each ranks's member into the group of members who have at least that rank.
+	#[pallet::storage]
+	pub type IdToIndex<T: Config<I>, I: 'static = ()> =
+		StorageDoubleMap<_, Twox64Concat, Rank, Twox64Concat, T::AccountId, MemberIndex>;
+
+	/// The members in the collective by index. All indices in the range `0..MemberCount` will
+	/// return `Some`, however a member's index is not guaranteed to remain unchanged over time.
+	#[pallet::storage]
+	pub type IndexToId<T: Config<I>, I: 'static = ()> =
+		StorageDoubleMap<_, Twox64Concat, Rank, Twox64Concat, MemberIndex, T::AccountId>;
+
+	/// Votes on a given proposal, if it is ongoing.
+	#[pallet::storage]
+	pub type Voting<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		PollIndexOf<T, I>,
+		Twox64Concat,
+		T::AccountId,
+		VoteRecord,
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config<I>, I: 'static = ()> {
+		/// A member has been added to the collective at rank zero. \[who\]
+		MemberAdded(T::AccountId),
+		/// A member's rank has been changed. \[who, rank\]
+		RankChanged(T::AccountId, Rank),
+		/// A member has been removed from the collective; `rank` was their rank at the time.
+		/// \[who, rank\]
+		MemberRemoved(T::AccountId, Rank),
+		/// A member has voted on a poll; `vote` is the vote record with its rank-weighted votes
+		/// and `tally` is the tally of the poll after the vote. \[who, poll, vote, tally\]
+		Voted(T::AccountId, PollIndexOf<T, I>, VoteRecord, TallyOf<T, I>),
+	}
+
+	#[pallet::error]
+	pub enum Error<T, I = ()> {
+		/// Account is already a member.
+		AlreadyMember,
+		/// Account is not a member.
+		NotMember,
+		/// The given poll index is unknown or has closed.
+		NotPolling,
+		/// The given poll is still ongoing.
+		Ongoing,
+		/// There are no further records to be removed.
+		NoneRemaining,
+		/// Unexpected error in state.
+		Corruption,
+		/// The member's rank is too low to vote.
+		RankTooLow,
+		/// The information provided is incorrect.
+		InvalidWitness,
+	}
+
+	#[pallet::call]
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// Introduce a new member.
+		///
+		/// - `origin`: Must be the `AdminOrigin`.
+		/// - `who`: Account of non-member which will become a member.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::add_member())]
+		pub fn add_member(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			Self::do_add_member(who)
+		}
+
+		/// Increment the rank of an existing member by one.
+		///
+		/// - `origin`: Must be the `AdminOrigin`.
+		/// - `who`: Account of existing member.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::promote_member(0))]
+		pub fn promote_member(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			Self::do_promote_member(who)
+		}
+
+		/// Decrement the rank of an existing member by one. If the member is already at rank zero,
+		/// then they are removed entirely.
+		///
+		/// - `origin`: Must be the `AdminOrigin`.
+		/// - `who`: Account of existing member of rank greater than zero.
+		///
+		/// Weight: `O(1)`, less if the member's index is highest in its rank.
+		#[pallet::weight(T::WeightInfo::demote_member(0))]
+		pub fn demote_member(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			Self::do_demote_member(who)
+		}
+
+		/// Remove the member entirely.
+		///
+		/// - `origin`: Must be the `AdminOrigin`.
+		/// - `who`: Account of existing member of rank greater than zero.
+		/// - `min_rank`: The rank of the member or greater.
+		///
+		/// Weight: `O(min_rank)`.
+		#[pallet::weight(T::WeightInfo::remove_member(*min_rank as u32))]
+		pub fn remove_member(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+			min_rank: Rank,
+		) -> DispatchResultWithPostInfo {
+			T::AdminOrigin::ensure_origin(origin)?;
+			let MemberRecord { rank, .. } = Self::ensure_member(&who)?;
+			ensure!(min_rank >= rank, Error::<T, I>::InvalidWitness);
+
+			for r in 0..=rank {
+				Self::remove_from_rank(&who, r)?;
+			}
+			Members::<T, I>::remove(&who);
+			Self::deposit_event(Event::MemberRemoved(who, rank));
+			Ok(PostDispatchInfo {
+				actual_weight: Some(T::WeightInfo::remove_member(rank as u32)),
+				pays_fee: Pays::Yes,
+			})
+		}
+
+		/// Add an aye or nay vote for the sender to the given proposal.
+		///
+		/// - `origin`: Must be `Signed` by a member account.
+		/// - `poll`: Index of a poll which is ongoing.
+		/// - `aye`: `true` if the vote is to approve the proposal, `false` otherwise.
+		///
+		/// Transaction fees are be waived if the member is voting on any particular proposal
+		/// for the first time and the call is successful. Subsequent vote changes will charge a
+		/// fee.
+		///
+		/// Weight: `O(1)`, less if there was no previous vote on the poll by the member.
+		#[pallet::weight(T::WeightInfo::vote())]
+		pub fn vote(
+			origin: OriginFor<T>,
+			poll: PollIndexOf<T, I>,
+			aye: bool,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let record = Self::ensure_member(&who)?;
+			use VoteRecord::*;
+			let mut pays = Pays::Yes;
+
+			let (tally, vote) = T::Polls::try_access_poll(
+				poll,
+				|mut status| -> Result<(TallyOf<T, I>, VoteRecord), DispatchError> {
+					match status {
+						PollStatus::None | PollStatus::Completed(..) =>
+							Err(Error::<T, I>::NotPolling.into()),
+						PollStatus::Ongoing(ref mut tally, min_rank) => {
+							match Voting::<T, I>::get(poll, &who) {
+								Some(Aye(votes)) => {
+									tally.bare_ayes.saturating_dec();
+									tally.ayes.saturating_reduce(votes);
+								},
+								Some(Nay(votes)) => tally.nays.saturating_reduce(votes),
+								None => pays = Pays::No,
+							}
+							let votes = Self::rank_to_votes(record.rank, min_rank)?;
+							let vote = VoteRecord::from((aye, votes));
+							match aye {
+								true => {
+									tally.bare_ayes.saturating_inc();
+									tally.ayes.saturating_accrue(votes);
+								},
+								false => tally.nays.saturating_accrue(votes),
+							}
+							Voting::<T, I>::insert(poll, &who, vote);
+							Ok((tally.clone(), vote))
+						},
+					}
+				},
+			)?;
+			Self::deposit_event(Event::Voted(who, poll, vote, tally));
+			Ok(pays.into())
+		}
+
+		/// Remove votes from the given poll. It must have ended.
+		///
+		/// - `origin`: Must be `Signed` by any account.
+		/// - `poll_index`: Index of a poll which is completed and for which votes continue to
+		///   exist.
+		/// - `max`: Maximum number of vote items from remove in this call.
+		///
+		/// Transaction fees are waived if the operation is successful.
+		///
+		/// Weight `O(max)` (less if there are fewer items to remove than `max`).
+		#[pallet::weight(T::WeightInfo::cleanup_poll(*max))]
+		pub fn cleanup_poll(
+			origin: OriginFor<T>,
+			poll_index: PollIndexOf<T, I>,
+			max: u32,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			ensure!(T::Polls::as_ongoing(poll_index).is_none(), Error::<T, I>::Ongoing);
+
+			let voters = Voting::<T, I>::iter_key_prefix(poll_index)
+				.take(max as usize)
+				.collect::<Vec<_>>();
+			let count = voters.len() as u32;
+			ensure!(count > 0, Error::<T, I>::NoneRemaining);
+			for who in voters.iter() {
+				Voting::<T, I>::remove(poll_index, who);
+			}
+
+			Ok(PostDispatchInfo {
+				actual_weight: Some(T::WeightInfo::cleanup_poll(count)),
+				pays_fee: Pays::No,
+			})
+		}
+	}
+
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		fn ensure_member(who: &T::AccountId) -> Result<MemberRecord, DispatchError> {
+			Members::<T, I>::get(who).ok_or(Error::<T, I>::NotMember.into())
+		}
+
+		fn rank_to_votes(rank: Rank, min: Rank) -> Result<Votes, DispatchError> {
+			let excess = rank.checked_sub(min).ok_or(Error::<T, I>::RankTooLow)?;
+			Ok(excess as Votes + 1)
+		}
+
+		fn remove_from_rank(who: &T::AccountId, rank: Rank) -> DispatchResult {
+			let last_index = MemberCount::<T, I>::get(rank).saturating_sub(1);
+			let index = IdToIndex::<T, I>::get(rank, who).ok_or(Error::<T, I>::Corruption)?;
+			if index != last_index {
+				let last =
+					IndexToId::<T, I>::get(rank, last_index).ok_or(Error::<T, I>::Corruption)?;
+				IdToIndex::<T, I>::insert(rank, &last, index);
+				IndexToId::<T, I>::insert(rank, index, &last);
+			}
+			MemberCount::<T, I>::mutate(rank, |r| r.saturating_dec());
+			IdToIndex::<T, I>::remove(rank, who);
+			IndexToId::<T, I>::remove(rank, last_index);
+			Ok(())
+		}
+
+		/// Add a member to the collective at rank zero.
+		///
+		/// This is not a dispatchable and does not check the origin; it is intended for use by
+		/// other pallets and for benchmarking.
+		pub fn do_add_member(who: T::AccountId) -> DispatchResult {
+			ensure!(!Members::<T, I>::contains_key(&who), Error::<T, I>::AlreadyMember);
+			let index = MemberCount::<T, I>::get(0);
+			let count = index.checked_add(1).ok_or(Overflow)?;
+
+			Members::<T, I>::insert(&who, MemberRecord { rank: 0 });
+			IdToIndex::<T, I>::insert(0, &who, index);
+			IndexToId::<T, I>::insert(0, index, &who);
+			MemberCount::<T, I>::insert(0, count);
+			Self::deposit_event(Event::MemberAdded(who));
+			Ok(())
+		}
+
+		/// Promote a member of the collective by one rank.
+		///
+		/// This is not a dispatchable and does not check the origin; it is intended for use by
+		/// other pallets and for benchmarking.
+		pub fn do_promote_member(who: T::AccountId) -> DispatchResult {
+			let record = Self::ensure_member(&who)?;
+			let rank = record.rank.checked_add(1).ok_or(Overflow)?;
+			let index = MemberCount::<T, I>::get(rank);
+			MemberCount::<T, I>::insert(rank, index.checked_add(1).ok_or(Overflow)?);
+			IdToIndex::<T, I>::insert(rank, &who, index);
+			IndexToId::<T, I>::insert(rank, index, &who);
+			Members::<T, I>::insert(&who, MemberRecord { rank });
+			Self::deposit_event(Event::RankChanged(who, rank));
+			Ok(())
+		}
+
+		/// Demote a member of the collective by one rank, removing them entirely if they are
+		/// already at rank zero.
+		///
+		/// This is not a dispatchable and does not check the origin; it is intended for use by
+		/// other pallets and for benchmarking.
+		pub fn do_demote_member(who: T::AccountId) -> DispatchResult {
+			let mut record = Self::ensure_member(&who)?;
+			let rank = record.rank;
+
+			Self::remove_from_rank(&who, rank)?;
+			let maybe_rank = rank.checked_sub(1);
+			match maybe_rank {
+				None => {
+					Members::<T, I>::remove(&who);
+					Self::deposit_event(Event::MemberRemoved(who, 0));
+				},
+				Some(rank) => {
+					record.rank = rank;
+					Members::<T, I>::insert(&who, &record);
+					Self::deposit_event(Event::RankChanged(who, rank));
+				},
+			}
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config<I>, I: 'static> RankedMembers for Pallet<T, I> {
+	type AccountId = T::AccountId;
+	type Rank = Rank;
+
+	fn min_rank() -> Self::Rank {
+		0
+	}
+
+	fn rank_of(who: &Self::AccountId) -> Option<Self::Rank> {
+		Members::<T, I>::get(who).map(|record| record.rank)
+	}
+
+	fn induct(who: &Self::AccountId) -> DispatchResult {
+		Self::do_add_member(who.clone())
+	}
+
+	fn promote(who: &Self::AccountId) -> DispatchResult {
+		Self::do_promote_member(who.clone())
+	}
+
+	fn demote(who: &Self::AccountId) -> DispatchResult {
+		Self::do_demote_member(who.clone())
+	}
+}
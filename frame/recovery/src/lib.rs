@@ -93,7 +93,9 @@
 //! attacker is also able to get enough friends to vouch for the recovery attempt.
 //! In the case where the account owner prevents a malicious recovery process,
 //! this pallet makes it near-zero cost to re-configure the recovery settings and
-//! remove/replace friends who are acting inappropriately.
+//! remove/replace friends who are acting inappropriately. Using `update_recovery`,
+//! this can be done without first closing the active recovery processes; any vouch
+//! from a friend who has been removed no longer counts towards the threshold.
 //!
 //! ### Safety Considerations
 //!
@@ -138,6 +140,8 @@
 //!   recovery process for.
 //! * `as_recovered` - Send a transaction as an account that you have recovered. See other functions
 //!   below.
+//! * `cancel_recovered` - Cancel your ability to send transactions as an account that you have
+//!   recovered.
 //!
 //! #### For the Recoverable Account
 //!
@@ -145,6 +149,8 @@
 //!   deposit.
 //! * `remove_recovery` - Remove the recovery configuration from the account, making it
 //!   un-recoverable.
+//! * `update_recovery` - Change the friends, threshold or delay period of the recovery
+//!   configuration, keeping any active recovery processes.
 //!
 //! #### For Super Users
 //!
@@ -156,12 +162,16 @@
 
 use codec::{Decode, Encode};
 use scale_info::TypeInfo;
-use sp_runtime::traits::{CheckedAdd, CheckedMul, Dispatchable, SaturatedConversion};
+use sp_runtime::{
+	traits::{CheckedAdd, CheckedMul, Dispatchable, SaturatedConversion, Zero},
+	ArithmeticError,
+};
 use sp_std::prelude::*;
 
 use frame_support::{
-	dispatch::PostDispatchInfo,
-	traits::{BalanceStatus, Currency, ReservableCurrency},
+	dispatch::{DispatchError, DispatchResult, PostDispatchInfo},
+	ensure,
+	traits::{BalanceStatus, Currency, Get, ReservableCurrency},
 	weights::GetDispatchInfo,
 	RuntimeDebug,
 };
@@ -278,6 +288,8 @@ pub mod pallet {
 		AccountRecovered(T::AccountId, T::AccountId),
 		/// A recovery process has been removed for an \[account\].
 		RecoveryRemoved(T::AccountId),
+		/// The recovery configuration has been updated for an \[account\].
+		RecoveryUpdated(T::AccountId),
 	}
 
 	#[pallet::error]
@@ -451,19 +463,8 @@ pub mod pallet {
 			// Check account is not already set up for recovery
 			ensure!(!<Recoverable<T>>::contains_key(&who), Error::<T>::AlreadyRecoverable);
 			// Check user input is valid
-			ensure!(threshold >= 1, Error::<T>::ZeroThreshold);
-			ensure!(!friends.is_empty(), Error::<T>::NotEnoughFriends);
-			ensure!(threshold as usize <= friends.len(), Error::<T>::NotEnoughFriends);
-			let max_friends = T::MaxFriends::get() as usize;
-			ensure!(friends.len() <= max_friends, Error::<T>::MaxFriends);
-			ensure!(Self::is_sorted_and_unique(&friends), Error::<T>::NotSorted);
-			// Total deposit is base fee + number of friends * factor fee
-			let friend_deposit = T::FriendDepositFactor::get()
-				.checked_mul(&friends.len().saturated_into())
-				.ok_or(ArithmeticError::Overflow)?;
-			let total_deposit = T::ConfigDepositBase::get()
-				.checked_add(&friend_deposit)
-				.ok_or(ArithmeticError::Overflow)?;
+			Self::ensure_valid_config(&friends, threshold)?;
+			let total_deposit = Self::config_deposit(friends.len())?;
 			// Reserve the deposit
 			T::Currency::reserve(&who, total_deposit)?;
 			// Create the recovery configuration
@@ -609,11 +610,13 @@ pub mod pallet {
 				.checked_add(&recovery_config.delay_period)
 				.ok_or(ArithmeticError::Overflow)?;
 			ensure!(recoverable_block_number <= current_block_number, Error::<T>::DelayPeriod);
-			// Make sure the threshold is met
-			ensure!(
-				recovery_config.threshold as usize <= active_recovery.friends.len(),
-				Error::<T>::Threshold
-			);
+			// Make sure the threshold is met, only counting those who are still friends
+			let vouches = active_recovery
+				.friends
+				.iter()
+				.filter(|f| Self::is_friend(&recovery_config.friends, f))
+				.count();
+			ensure!(recovery_config.threshold as usize <= vouches, Error::<T>::Threshold);
 			frame_system::Pallet::<T>::inc_consumers(&who).map_err(|_| Error::<T>::BadState)?;
 			// Create the recovery storage item
 			Proxy::<T>::insert(&who, &account);
@@ -716,10 +719,94 @@ pub mod pallet {
 			frame_system::Pallet::<T>::dec_consumers(&who);
 			Ok(())
 		}
+
+		/// Update the recovery configuration of your account, without affecting any active
+		/// recovery processes.
+		///
+		/// Vouches made by accounts which are no longer in the list of friends will not count
+		/// towards the threshold of an active recovery process.
+		///
+		/// Payment: The recovery configuration deposit is adjusted to
+		/// `ConfigDepositBase` + `FriendDepositFactor` * #_of_friends, reserving or unreserving
+		/// the difference.
+		///
+		/// The dispatch origin for this call must be _Signed_ and must be a
+		/// recoverable account (i.e. has a recovery configuration).
+		///
+		/// Parameters:
+		/// - `friends`: A list of friends you trust to vouch for recovery attempts. Should be
+		///   ordered and contain no duplicate values.
+		/// - `threshold`: The number of friends that must vouch for a recovery attempt before the
+		///   account can be recovered. Should be less than or equal to the length of the list of
+		///   friends.
+		/// - `delay_period`: The number of blocks after a recovery attempt is initialized that
+		///   needs to pass before the account can be recovered.
+		///
+		/// # <weight>
+		/// - Key: F (len of friends)
+		/// - One storage read to get the recovery configuration. O(1), Codec O(F)
+		/// - A check that the friends list is sorted and unique. O(F)
+		/// - One currency reserve or unreserve operation. O(X)
+		/// - One storage write. O(1). Codec O(F).
+		/// - One event.
+		///
+		/// Total Complexity: O(F + X)
+		/// # </weight>
+		#[pallet::weight(100_000_000)]
+		pub fn update_recovery(
+			origin: OriginFor<T>,
+			friends: Vec<T::AccountId>,
+			threshold: u16,
+			delay_period: T::BlockNumber,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let old_config = Self::recovery_config(&who).ok_or(Error::<T>::NotRecoverable)?;
+			// Check user input is valid
+			Self::ensure_valid_config(&friends, threshold)?;
+			let total_deposit = Self::config_deposit(friends.len())?;
+			// Reserve or unreserve the difference in deposit
+			if total_deposit > old_config.deposit {
+				T::Currency::reserve(&who, total_deposit - old_config.deposit)?;
+			} else {
+				let err_amount = T::Currency::unreserve(&who, old_config.deposit - total_deposit);
+				debug_assert!(err_amount.is_zero());
+			}
+			// Replace the recovery configuration
+			let recovery_config =
+				RecoveryConfig { delay_period, deposit: total_deposit, friends, threshold };
+			<Recoverable<T>>::insert(&who, recovery_config);
+
+			Self::deposit_event(Event::<T>::RecoveryUpdated(who));
+			Ok(())
+		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
+	/// Check that `friends` and `threshold` make up a valid recovery configuration.
+	fn ensure_valid_config(friends: &Vec<T::AccountId>, threshold: u16) -> DispatchResult {
+		ensure!(threshold >= 1, Error::<T>::ZeroThreshold);
+		ensure!(!friends.is_empty(), Error::<T>::NotEnoughFriends);
+		ensure!(threshold as usize <= friends.len(), Error::<T>::NotEnoughFriends);
+		let max_friends = T::MaxFriends::get() as usize;
+		ensure!(friends.len() <= max_friends, Error::<T>::MaxFriends);
+		ensure!(Self::is_sorted_and_unique(friends), Error::<T>::NotSorted);
+		Ok(())
+	}
+
+	/// The deposit for a recovery configuration of `friends_len` friends.
+	///
+	/// Total deposit is base fee + number of friends * factor fee.
+	fn config_deposit(friends_len: usize) -> Result<BalanceOf<T>, DispatchError> {
+		let friend_deposit = T::FriendDepositFactor::get()
+			.checked_mul(&friends_len.saturated_into())
+			.ok_or(ArithmeticError::Overflow)?;
+		let total_deposit = T::ConfigDepositBase::get()
+			.checked_add(&friend_deposit)
+			.ok_or(ArithmeticError::Overflow)?;
+		Ok(total_deposit)
+	}
+
 	/// Check that friends list is sorted and has no duplicates.
 	fn is_sorted_and_unique(friends: &Vec<T::AccountId>) -> bool {
 		friends.windows(2).all(|w| w[0] < w[1])
@@ -422,3 +422,72 @@ fn remove_recovery_works() {
 		assert_ok!(Recovery::remove_recovery(Origin::signed(5)));
 	});
 }
+
+#[test]
+fn update_recovery_handles_basic_errors() {
+	new_test_ext().execute_with(|| {
+		// Cannot update an unrecoverable account
+		assert_noop!(
+			Recovery::update_recovery(Origin::signed(5), vec![2, 3, 4], 3, 10),
+			Error::<Test>::NotRecoverable
+		);
+		assert_ok!(Recovery::create_recovery(Origin::signed(5), vec![2, 3, 4], 3, 10));
+		// The new configuration must be valid
+		assert_noop!(
+			Recovery::update_recovery(Origin::signed(5), vec![2], 0, 0),
+			Error::<Test>::ZeroThreshold
+		);
+		assert_noop!(
+			Recovery::update_recovery(Origin::signed(5), vec![2, 3], 3, 0),
+			Error::<Test>::NotEnoughFriends
+		);
+		assert_noop!(
+			Recovery::update_recovery(Origin::signed(5), vec![1, 2, 3, 4], 4, 0),
+			Error::<Test>::MaxFriends
+		);
+		assert_noop!(
+			Recovery::update_recovery(Origin::signed(5), vec![3, 2], 2, 0),
+			Error::<Test>::NotSorted
+		);
+	});
+}
+
+#[test]
+fn update_recovery_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Recovery::create_recovery(Origin::signed(5), vec![2, 3, 4], 3, 10));
+		assert_eq!(Balances::reserved_balance(5), 13);
+		// An active recovery process is kept through the update
+		assert_ok!(Recovery::initiate_recovery(Origin::signed(1), 5));
+		// Fewer friends, so part of the deposit is returned
+		assert_ok!(Recovery::update_recovery(Origin::signed(5), vec![2, 3], 2, 20));
+		assert_eq!(Balances::reserved_balance(5), 12);
+		let recovery_config =
+			RecoveryConfig { delay_period: 20, deposit: 12, friends: vec![2, 3], threshold: 2 };
+		assert_eq!(Recovery::recovery_config(5), Some(recovery_config));
+		assert!(Recovery::active_recovery(5, 1).is_some());
+		// More friends, so more deposit is reserved
+		assert_ok!(Recovery::update_recovery(Origin::signed(5), vec![2, 3, 4], 1, 20));
+		assert_eq!(Balances::reserved_balance(5), 13);
+	});
+}
+
+#[test]
+fn update_recovery_ignores_vouches_of_removed_friends() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Recovery::create_recovery(Origin::signed(5), vec![2, 3, 4], 2, 10));
+		// Account 1 attempts a malicious recovery, tricking friends 2 and 3
+		assert_ok!(Recovery::initiate_recovery(Origin::signed(1), 5));
+		assert_ok!(Recovery::vouch_recovery(Origin::signed(2), 5, 1));
+		assert_ok!(Recovery::vouch_recovery(Origin::signed(3), 5, 1));
+		// Account 5 notices, and replaces friend 3 without closing the recovery
+		assert_ok!(Recovery::update_recovery(Origin::signed(5), vec![2, 4, 6], 2, 10));
+		run_to_block(11);
+		// The vouch of account 3 no longer counts
+		assert_noop!(Recovery::claim_recovery(Origin::signed(1), 5), Error::<Test>::Threshold);
+		// Account 5 can still claim the deposit of the attacker
+		assert_ok!(Recovery::close_recovery(Origin::signed(5), 1));
+		assert_eq!(Balances::total_balance(&1), 90);
+		assert_eq!(Balances::total_balance(&5), 110);
+	});
+}
@@ -0,0 +1,203 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Referenda pallet benchmarking.
+
+use super::*;
+use crate::Pallet as Referenda;
+use frame_benchmarking::{
+	account, benchmarks_instance_pallet, impl_benchmark_test_suite, whitelist_account,
+};
+use frame_support::{
+	assert_ok,
+	traits::{Currency, EnsureOrigin},
+};
+use frame_system::RawOrigin;
+use sp_runtime::traits::{Bounded, Hash};
+
+const SEED: u32 = 0;
+
+fn funded_account<T: Config<I>, I: 'static>(name: &'static str, index: u32) -> T::AccountId {
+	let caller: T::AccountId = account(name, index, SEED);
+	T::Currency::make_free_balance_be(&caller, BalanceOf::<T, I>::max_value() / 2u32.into());
+	caller
+}
+
+fn create_referendum<T: Config<I>, I: 'static>() -> (T::AccountId, ReferendumIndex) {
+	let caller = funded_account::<T, I>("caller", 0);
+	whitelist_account!(caller);
+	let origin: PalletsOriginOf<T, I> = RawOrigin::Root.into();
+	let proposal: CallOf<T, I> = Call::<T, I>::nudge_referendum { index: 0 }.into();
+	assert_ok!(Referenda::<T, I>::submit(
+		RawOrigin::Signed(caller.clone()).into(),
+		Box::new(origin),
+		Box::new(proposal),
+		DispatchTime::After(0u32.into()),
+	));
+	let index = ReferendumCount::<T, I>::get() - 1;
+	(caller, index)
+}
+
+fn place_deposit<T: Config<I>, I: 'static>(index: ReferendumIndex) {
+	let caller = funded_account::<T, I>("caller", 0);
+	whitelist_account!(caller);
+	assert_ok!(Referenda::<T, I>::place_decision_deposit(RawOrigin::Signed(caller).into(), index));
+}
+
+fn nudge<T: Config<I>, I: 'static>(index: ReferendumIndex) {
+	assert_ok!(Referenda::<T, I>::nudge_referendum(RawOrigin::Root.into(), index));
+}
+
+fn info<T: Config<I>, I: 'static>(index: ReferendumIndex) -> &'static TrackInfoOf<T, I> {
+	let status = Referenda::<T, I>::ensure_ongoing(index).unwrap();
+	T::Tracks::info(status.track).expect("Id value returned from T::Tracks")
+}
+
+fn make_passing<T: Config<I>, I: 'static>(index: ReferendumIndex) {
+	Referenda::<T, I>::access_poll(index, |status| {
+		if let PollStatus::Ongoing(tally, class) = status {
+			*tally = T::Tally::unanimity(class);
+		}
+	});
+}
+
+fn skip_prepare_period<T: Config<I>, I: 'static>(index: ReferendumIndex) {
+	let status = Referenda::<T, I>::ensure_ongoing(index).unwrap();
+	let prepare_period_over = status.submitted + info::<T, I>(index).prepare_period;
+	frame_system::Pallet::<T>::set_block_number(prepare_period_over);
+}
+
+fn skip_confirm_period<T: Config<I>, I: 'static>(index: ReferendumIndex) {
+	let status = Referenda::<T, I>::ensure_ongoing(index).unwrap();
+	let confirm_period_over = status.deciding.unwrap().confirming.unwrap();
+	frame_system::Pallet::<T>::set_block_number(confirm_period_over);
+}
+
+/// Create an ongoing referendum which is being decided.
+fn create_deciding<T: Config<I>, I: 'static>() -> ReferendumIndex {
+	let (_caller, index) = create_referendum::<T, I>();
+	place_deposit::<T, I>(index);
+	skip_prepare_period::<T, I>(index);
+	nudge::<T, I>(index);
+	index
+}
+
+fn is_deciding<T: Config<I>, I: 'static>(index: ReferendumIndex) -> bool {
+	matches!(
+		Referenda::<T, I>::ensure_ongoing(index),
+		Ok(ReferendumStatus { deciding: Some(_), .. })
+	)
+}
+
+benchmarks_instance_pallet! {
+	submit {
+		let origin: PalletsOriginOf<T, I> = RawOrigin::Root.into();
+		let proposal: CallOf<T, I> = Call::<T, I>::nudge_referendum { index: 0 }.into();
+		let caller = funded_account::<T, I>("caller", 0);
+		whitelist_account!(caller);
+	}: _(
+		RawOrigin::Signed(caller),
+		Box::new(origin),
+		Box::new(proposal.clone()),
+		DispatchTime::After(0u32.into())
+	) verify {
+		let index = ReferendumCount::<T, I>::get().checked_sub(1).unwrap();
+		let status = Referenda::<T, I>::ensure_ongoing(index).unwrap();
+		assert_eq!(status.proposal_hash, T::Hashing::hash_of(&proposal));
+	}
+
+	place_decision_deposit {
+		let (caller, index) = create_referendum::<T, I>();
+		skip_prepare_period::<T, I>(index);
+	}: _(RawOrigin::Signed(caller), index)
+	verify {
+		assert!(is_deciding::<T, I>(index));
+	}
+
+	refund_decision_deposit {
+		let (caller, index) = create_referendum::<T, I>();
+		place_deposit::<T, I>(index);
+		assert_ok!(Referenda::<T, I>::cancel(T::CancelOrigin::successful_origin(), index));
+	}: _(RawOrigin::Signed(caller), index)
+	verify {
+		assert!(matches!(ReferendumInfoFor::<T, I>::get(index), Some(ReferendumInfo::Cancelled(_, _, None))));
+	}
+
+	refund_submission_deposit {
+		let (caller, index) = create_referendum::<T, I>();
+		assert_ok!(Referenda::<T, I>::cancel(T::CancelOrigin::successful_origin(), index));
+	}: _(RawOrigin::Signed(caller), index)
+	verify {
+		assert!(matches!(ReferendumInfoFor::<T, I>::get(index), Some(ReferendumInfo::Cancelled(_, None, _))));
+	}
+
+	cancel {
+		let index = create_deciding::<T, I>();
+		let origin = T::CancelOrigin::successful_origin();
+	}: {
+		assert_ok!(Referenda::<T, I>::cancel(origin, index));
+	}
+	verify {
+		assert!(matches!(ReferendumInfoFor::<T, I>::get(index), Some(ReferendumInfo::Cancelled(..))));
+	}
+
+	kill {
+		let index = create_deciding::<T, I>();
+		let origin = T::KillOrigin::successful_origin();
+	}: {
+		assert_ok!(Referenda::<T, I>::kill(origin, index));
+	}
+	verify {
+		assert!(matches!(ReferendumInfoFor::<T, I>::get(index), Some(ReferendumInfo::Killed(..))));
+	}
+
+	// Worst case: the referendum ends its confirmation period, is approved and its proposal gets
+	// scheduled for enactment.
+	nudge_referendum {
+		let index = create_deciding::<T, I>();
+		make_passing::<T, I>(index);
+		nudge::<T, I>(index);
+		skip_confirm_period::<T, I>(index);
+	}: _(RawOrigin::Root, index)
+	verify {
+		assert!(matches!(ReferendumInfoFor::<T, I>::get(index), Some(ReferendumInfo::Approved(..))));
+	}
+
+	// Worst case: the track queue is full and the referendum most approved will begin being
+	// decided.
+	one_fewer_deciding {
+		let first = create_deciding::<T, I>();
+		let track = Referenda::<T, I>::ensure_ongoing(first).unwrap().track;
+		let max_deciding = info::<T, I>(first).max_deciding;
+		for _ in 1..max_deciding {
+			create_deciding::<T, I>();
+		}
+		for _ in 0..T::MaxQueued::get() {
+			let (_caller, index) = create_referendum::<T, I>();
+			place_deposit::<T, I>(index);
+			skip_prepare_period::<T, I>(index);
+			nudge::<T, I>(index);
+		}
+		assert_eq!(TrackQueue::<T, I>::get(track).len() as u32, T::MaxQueued::get());
+		assert_ok!(Referenda::<T, I>::cancel(T::CancelOrigin::successful_origin(), first));
+	}: _(RawOrigin::Root, track)
+	verify {
+		assert_eq!(TrackQueue::<T, I>::get(track).len() as u32, T::MaxQueued::get() - 1);
+	}
+}
+
+impl_benchmark_test_suite!(Referenda, crate::mock::new_test_ext(), crate::mock::Test);
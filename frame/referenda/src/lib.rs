@@ -0,0 +1,1039 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Referenda Pallet
+//!
+//! - [`Config`]
+//! - [`Call`]
+//!
+//! ## Overview
+//!
+//! A pallet for executing referenda. No voting logic is present here, and the `Polling` and
+//! `PollStatus` traits are used to allow the voting logic (likely in a pallet) to be utilized.
+//!
+//! A referendum is a vote on whether a proposal should be dispatched from a particular origin. The
+//! origin is used to determine which one of several _tracks_ that a referendum happens under.
+//! Tracks each have their own configuration which governs the voting process and parameters.
+//!
+//! A referendum's lifecycle has three main stages: Preparation, deciding and conclusion.
+//! Referenda are considered "ongoing" immediately after submission until their eventual
+//! conclusion, and votes may be cast throughout.
+//!
+//! In order to progress from preparing to being decided, three things must be in place:
+//! - There must have been a *Decision Deposit* placed, an amount determined by the track. Anyone
+//! may place this deposit.
+//! - A period must have elapsed since submission of the referendum. This period is known as the
+//! *Preparation Period* and is determined by the track.
+//! - The track must not already be at capacity with referendum being decided. The maximum number of
+//! referenda which may be being decided simultaneously is determined by the track.
+//!
+//! In order to become concluded, one of three things must happen:
+//! - The referendum should remain in an unbroken _Passing_ state for a period of time. This
+//! is known as the _Confirmation Period_ and is determined by the track. A referendum is considered
+//! _Passing_ when there is a sufficiently high support and approval, given the amount of time it
+//! has been being decided. Generally the threshold for what counts as being "sufficiently high"
+//! will reduce over time. The curves setting these thresholds are determined by the track. In this
+//! case, the referendum is considered _Approved_ and the proposal is scheduled for dispatch.
+//! - The referendum reaches the end of its deciding phase without being _Passing_. It ends in
+//! rejection and the proposal is not dispatched.
+//! - The referendum is cancelled.
+//!
+//! A general time-out is also in place and referenda which exist in preparation for too long may
+//! conclude without ever entering into a deciding stage.
+//!
+//! Once a referendum is concluded, the decision deposit may be refunded.
+//!
+//! Referenda are serviced by alarms set with the scheduler, so that they progress through their
+//! lifecycle without any further intervention.
+//!
+//! ## Terms
+//! - *Support*: The number of aye-votes, pre-conviction, as a proportion of the total number of
+//!   pre-conviction votes able to be cast in the population.
+
+#![recursion_limit = "256"]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Codec, Encode, HasCompact};
+use frame_support::{
+	ensure,
+	traits::{
+		schedule::{
+			Anon as ScheduleAnon, DispatchTime, Named as ScheduleNamed, HARD_DEADLINE,
+			LOWEST_PRIORITY,
+		},
+		Currency, Get, LockIdentifier, OnUnbalanced, PollStatus, Polling, ReservableCurrency,
+		VoteTally,
+	},
+	BoundedVec,
+};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{AtLeast32BitUnsigned, Dispatchable, Hash, One, Saturating, Zero},
+	DispatchError, Perbill,
+};
+use sp_std::{fmt::Debug, prelude::*};
+
+mod types;
+pub mod weights;
+pub use pallet::*;
+pub use types::{
+	BalanceOf, CallOf, Curve, DecidingStatus, DecidingStatusOf, Deposit, NegativeImbalanceOf,
+	PalletsOriginOf, ReferendumIndex, ReferendumInfo, ReferendumInfoOf, ReferendumStatus,
+	ReferendumStatusOf, ScheduleAddressOf, TallyOf, TrackIdOf, TrackInfo, TrackInfoOf, TracksInfo,
+	VotesOf,
+};
+pub use weights::WeightInfo;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
+const ASSEMBLY_ID: LockIdentifier = *b"assembly";
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{pallet_prelude::*, traits::EnsureOrigin, Parameter};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::DispatchResult;
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T, I = ()>(_);
+
+	#[pallet::config]
+	pub trait Config<I: 'static = ()>: frame_system::Config + Sized {
+		// System level stuff.
+		type Call: Parameter + Dispatchable<Origin = Self::Origin> + From<Call<Self, I>>;
+		type Event: From<Event<Self, I>> + IsType<<Self as frame_system::Config>::Event>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+		/// The Scheduler.
+		type Scheduler: ScheduleAnon<Self::BlockNumber, CallOf<Self, I>, PalletsOriginOf<Self, I>>
+			+ ScheduleNamed<Self::BlockNumber, CallOf<Self, I>, PalletsOriginOf<Self, I>>;
+		/// Overarching type of all pallets origins.
+		type PalletsOrigin: Parameter + From<frame_system::RawOrigin<Self::AccountId>>;
+		/// Currency type for this pallet.
+		type Currency: ReservableCurrency<Self::AccountId>;
+		// Origins and unbalances.
+		/// Origin from which any vote may be cancelled.
+		type CancelOrigin: EnsureOrigin<Self::Origin>;
+		/// Origin from which any vote may be killed.
+		type KillOrigin: EnsureOrigin<Self::Origin>;
+		/// Handler for the unbalanced reduction when slashing a preimage deposit.
+		type Slash: OnUnbalanced<NegativeImbalanceOf<Self, I>>;
+		/// The counting type for votes. Usually just balance.
+		type Votes: AtLeast32BitUnsigned + Copy + Parameter + Member + HasCompact;
+		/// The tallying type.
+		type Tally: VoteTally<Self::Votes, TrackIdOf<Self, I>>
+			+ Clone
+			+ Codec
+			+ Eq
+			+ Debug
+			+ TypeInfo;
+
+		// Constants
+		/// The minimum amount to be used as a deposit for a public referendum proposal.
+		#[pallet::constant]
+		type SubmissionDeposit: Get<BalanceOf<Self, I>>;
+
+		/// Maximum size of the referendum queue for a single track.
+		#[pallet::constant]
+		type MaxQueued: Get<u32>;
+
+		/// The number of blocks after submission that a referendum must begin being decided by.
+		/// Once this passes, then anyone may cancel the referendum.
+		#[pallet::constant]
+		type UndecidingTimeout: Get<Self::BlockNumber>;
+
+		/// Quantization level for the referendum wakeup scheduler. A higher number will result in
+		/// fewer storage reads/writes needed for smaller voters, but also result in delays to the
+		/// automatic referendum status changes. Explicit servicing instructions are unaffected.
+		#[pallet::constant]
+		type AlarmInterval: Get<Self::BlockNumber>;
+
+		// The other stuff.
+		/// Information concerning the different referendum tracks.
+		type Tracks: TracksInfo<
+			BalanceOf<Self, I>,
+			Self::BlockNumber,
+			Origin = PalletsOriginOf<Self, I>,
+		>;
+	}
+
+	/// The next free referendum index, aka the number of referenda started so far.
+	#[pallet::storage]
+	pub type ReferendumCount<T, I = ()> = StorageValue<_, ReferendumIndex, ValueQuery>;
+
+	/// Information concerning any given referendum.
+	#[pallet::storage]
+	pub type ReferendumInfoFor<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, ReferendumIndex, ReferendumInfoOf<T, I>>;
+
+	/// The proposal of any referendum which is ongoing.
+	#[pallet::storage]
+	pub type ProposalOf<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, ReferendumIndex, CallOf<T, I>>;
+
+	/// The sorted list of referenda ready to be decided but not yet being decided, ordered by
+	/// conviction-weighted approvals.
+	///
+	/// This should be empty if `DecidingCount` is less than `TrackInfo::max_deciding`.
+	#[pallet::storage]
+	pub type TrackQueue<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		TrackIdOf<T, I>,
+		BoundedVec<(ReferendumIndex, T::Votes), T::MaxQueued>,
+		ValueQuery,
+	>;
+
+	/// The number of referenda being decided currently.
+	#[pallet::storage]
+	pub type DecidingCount<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, TrackIdOf<T, I>, u32, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config<I>, I: 'static = ()> {
+		/// A referendum has been submitted. \[index, track, proposal_hash\]
+		Submitted(ReferendumIndex, TrackIdOf<T, I>, T::Hash),
+		/// The decision deposit has been placed. \[index, who, amount\]
+		DecisionDepositPlaced(ReferendumIndex, T::AccountId, BalanceOf<T, I>),
+		/// The decision deposit has been refunded. \[index, who, amount\]
+		DecisionDepositRefunded(ReferendumIndex, T::AccountId, BalanceOf<T, I>),
+		/// The submission deposit has been refunded. \[index, who, amount\]
+		SubmissionDepositRefunded(ReferendumIndex, T::AccountId, BalanceOf<T, I>),
+		/// A deposit has been slashed. \[who, amount\]
+		DepositSlashed(T::AccountId, BalanceOf<T, I>),
+		/// A referendum has moved into the deciding phase.
+		/// \[index, track, proposal_hash, tally\]
+		DecisionStarted(ReferendumIndex, TrackIdOf<T, I>, T::Hash, T::Tally),
+		/// A referendum has entered the confirmation period. \[index\]
+		ConfirmStarted(ReferendumIndex),
+		/// A referendum has left the confirmation period without becoming approved. \[index\]
+		ConfirmAborted(ReferendumIndex),
+		/// A referendum has ended its confirmation phase and is ready for approval.
+		/// \[index, tally\]
+		Confirmed(ReferendumIndex, T::Tally),
+		/// A proposal has been rejected by referendum. \[index, tally\]
+		Rejected(ReferendumIndex, T::Tally),
+		/// A referendum has been timed out without being decided. \[index, tally\]
+		TimedOut(ReferendumIndex, T::Tally),
+		/// A referendum has been cancelled. \[index, tally\]
+		Cancelled(ReferendumIndex, T::Tally),
+		/// A referendum has been killed. \[index, tally\]
+		Killed(ReferendumIndex, T::Tally),
+	}
+
+	#[pallet::error]
+	pub enum Error<T, I = ()> {
+		/// Referendum is not ongoing.
+		NotOngoing,
+		/// Referendum's decision deposit is already paid.
+		HasDeposit,
+		/// The track identifier given was invalid.
+		BadTrack,
+		/// There are already a full complement of referendums in progress for this track.
+		Full,
+		/// The queue of the track is empty.
+		QueueEmpty,
+		/// The referendum index provided is invalid in this context.
+		BadReferendum,
+		/// There was nothing to do in the advancement.
+		NothingToDo,
+		/// No track exists for the proposal origin.
+		NoTrack,
+		/// Any deposit cannot be refunded until after the decision is over.
+		Unfinished,
+		/// The deposit refunder is not the depositor.
+		NoPermission,
+		/// The deposit cannot be refunded since none was made.
+		NoDeposit,
+	}
+
+	#[pallet::call]
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// Propose a referendum on a privileged action.
+		///
+		/// - `origin`: must be `Signed` and the account must have `SubmissionDeposit` funds
+		///   available.
+		/// - `proposal_origin`: The origin from which the proposal should be executed.
+		/// - `proposal`: The proposal.
+		/// - `enactment_moment`: The moment that the proposal should be enacted.
+		///
+		/// Emits `Submitted`.
+		#[pallet::weight(T::WeightInfo::submit())]
+		pub fn submit(
+			origin: OriginFor<T>,
+			proposal_origin: Box<PalletsOriginOf<T, I>>,
+			proposal: Box<CallOf<T, I>>,
+			enactment_moment: DispatchTime<T::BlockNumber>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let track =
+				T::Tracks::track_for(&proposal_origin).map_err(|_| Error::<T, I>::NoTrack)?;
+			let submission_deposit = Self::take_deposit(who, T::SubmissionDeposit::get())?;
+			let index = ReferendumCount::<T, I>::mutate(|x| {
+				let r = *x;
+				*x += 1;
+				r
+			});
+			let now = frame_system::Pallet::<T>::block_number();
+			let nudge_call = Call::nudge_referendum { index };
+			let proposal_hash = T::Hashing::hash_of(&proposal);
+			let status = ReferendumStatus {
+				track,
+				origin: *proposal_origin,
+				proposal_hash,
+				enactment: enactment_moment,
+				submitted: now,
+				submission_deposit,
+				decision_deposit: None,
+				deciding: None,
+				tally: TallyOf::<T, I>::new(track),
+				in_queue: false,
+				alarm: Self::set_alarm(nudge_call, now.saturating_add(T::UndecidingTimeout::get())),
+			};
+			ReferendumInfoFor::<T, I>::insert(index, ReferendumInfo::Ongoing(status));
+			ProposalOf::<T, I>::insert(index, *proposal);
+
+			Self::deposit_event(Event::<T, I>::Submitted(index, track, proposal_hash));
+			Ok(())
+		}
+
+		/// Post the Decision Deposit for a referendum.
+		///
+		/// - `origin`: must be `Signed` and the account must have funds available for the
+		///   referendum's track's Decision Deposit.
+		/// - `index`: The index of the submitted referendum whose Decision Deposit is yet to be
+		///   posted.
+		///
+		/// Emits `DecisionDepositPlaced`.
+		#[pallet::weight(T::WeightInfo::place_decision_deposit())]
+		pub fn place_decision_deposit(
+			origin: OriginFor<T>,
+			index: ReferendumIndex,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let mut status = Self::ensure_ongoing(index)?;
+			ensure!(status.decision_deposit.is_none(), Error::<T, I>::HasDeposit);
+			let track = Self::track(status.track).ok_or(Error::<T, I>::NoTrack)?;
+			status.decision_deposit =
+				Some(Self::take_deposit(who.clone(), track.decision_deposit)?);
+			let now = frame_system::Pallet::<T>::block_number();
+			let (info, _) = Self::service_referendum(now, index, status);
+			Self::store_info(index, info);
+			let e = Event::<T, I>::DecisionDepositPlaced(index, who, track.decision_deposit);
+			Self::deposit_event(e);
+			Ok(())
+		}
+
+		/// Refund the Decision Deposit for a closed referendum back to the depositor.
+		///
+		/// - `origin`: must be `Signed`.
+		/// - `index`: The index of a closed referendum whose Decision Deposit has not yet been
+		///   refunded.
+		///
+		/// Emits `DecisionDepositRefunded`.
+		#[pallet::weight(T::WeightInfo::refund_decision_deposit())]
+		pub fn refund_decision_deposit(
+			origin: OriginFor<T>,
+			index: ReferendumIndex,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let mut info =
+				ReferendumInfoFor::<T, I>::get(index).ok_or(Error::<T, I>::BadReferendum)?;
+			let deposit = info
+				.take_decision_deposit()
+				.map_err(|_| Error::<T, I>::Unfinished)?
+				.ok_or(Error::<T, I>::NoDeposit)?;
+			Self::refund_deposit(Some(deposit.clone()));
+			ReferendumInfoFor::<T, I>::insert(index, info);
+			let e = Event::<T, I>::DecisionDepositRefunded(index, deposit.who, deposit.amount);
+			Self::deposit_event(e);
+			Ok(())
+		}
+
+		/// Refund the Submission Deposit for an approved or cancelled referendum back to the
+		/// depositor.
+		///
+		/// - `origin`: must be `Signed`.
+		/// - `index`: The index of an approved or cancelled referendum whose Submission Deposit has
+		///   not yet been refunded.
+		///
+		/// Emits `SubmissionDepositRefunded`.
+		#[pallet::weight(T::WeightInfo::refund_submission_deposit())]
+		pub fn refund_submission_deposit(
+			origin: OriginFor<T>,
+			index: ReferendumIndex,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let mut info =
+				ReferendumInfoFor::<T, I>::get(index).ok_or(Error::<T, I>::BadReferendum)?;
+			let deposit = info
+				.take_submission_deposit()
+				.map_err(|_| Error::<T, I>::BadReferendum)?
+				.ok_or(Error::<T, I>::NoDeposit)?;
+			Self::refund_deposit(Some(deposit.clone()));
+			ReferendumInfoFor::<T, I>::insert(index, info);
+			let e = Event::<T, I>::SubmissionDepositRefunded(index, deposit.who, deposit.amount);
+			Self::deposit_event(e);
+			Ok(())
+		}
+
+		/// Cancel an ongoing referendum.
+		///
+		/// - `origin`: must be the `CancelOrigin`.
+		/// - `index`: The index of the referendum to be cancelled.
+		///
+		/// Emits `Cancelled`.
+		#[pallet::weight(T::WeightInfo::cancel())]
+		pub fn cancel(origin: OriginFor<T>, index: ReferendumIndex) -> DispatchResult {
+			T::CancelOrigin::ensure_origin(origin)?;
+			let mut status = Self::ensure_ongoing(index)?;
+			Self::conclude_early(index, &mut status);
+			Self::deposit_event(Event::<T, I>::Cancelled(index, status.tally));
+			let info = ReferendumInfo::Cancelled(
+				frame_system::Pallet::<T>::block_number(),
+				Some(status.submission_deposit),
+				status.decision_deposit,
+			);
+			ReferendumInfoFor::<T, I>::insert(index, info);
+			Ok(())
+		}
+
+		/// Cancel an ongoing referendum and slash the deposits.
+		///
+		/// - `origin`: must be the `KillOrigin`.
+		/// - `index`: The index of the referendum to be cancelled.
+		///
+		/// Emits `Killed` and `DepositSlashed`.
+		#[pallet::weight(T::WeightInfo::kill())]
+		pub fn kill(origin: OriginFor<T>, index: ReferendumIndex) -> DispatchResult {
+			T::KillOrigin::ensure_origin(origin)?;
+			let mut status = Self::ensure_ongoing(index)?;
+			Self::conclude_early(index, &mut status);
+			Self::deposit_event(Event::<T, I>::Killed(index, status.tally));
+			Self::slash_deposit(Some(status.submission_deposit.clone()));
+			Self::slash_deposit(status.decision_deposit.clone());
+			let info = ReferendumInfo::Killed(frame_system::Pallet::<T>::block_number());
+			ReferendumInfoFor::<T, I>::insert(index, info);
+			Ok(())
+		}
+
+		/// Advance a referendum onto its next logical state. Only used internally.
+		///
+		/// - `origin`: must be `Root`.
+		/// - `index`: the referendum to be advanced.
+		#[pallet::weight(T::WeightInfo::nudge_referendum())]
+		pub fn nudge_referendum(origin: OriginFor<T>, index: ReferendumIndex) -> DispatchResult {
+			ensure_root(origin)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			let mut status = Self::ensure_ongoing(index)?;
+			// This is our wake-up, so we can disregard the alarm.
+			status.alarm = None;
+			let (info, dirty) = Self::service_referendum(now, index, status);
+			if dirty {
+				Self::store_info(index, info);
+			}
+			Ok(())
+		}
+
+		/// Advance a track onto its next logical state. Only used internally.
+		///
+		/// - `origin`: must be `Root`.
+		/// - `track`: the track to be advanced.
+		///
+		/// Action item for when there is now one fewer referendum in the deciding phase and the
+		/// `DecidingCount` is not yet updated. This means that we should either:
+		/// - begin deciding another referendum (and leave `DecidingCount` alone); or
+		/// - decrement `DecidingCount`.
+		#[pallet::weight(T::WeightInfo::one_fewer_deciding())]
+		pub fn one_fewer_deciding(origin: OriginFor<T>, track: TrackIdOf<T, I>) -> DispatchResult {
+			ensure_root(origin)?;
+			let track_info = T::Tracks::info(track).ok_or(Error::<T, I>::BadTrack)?;
+			let mut track_queue = TrackQueue::<T, I>::get(track);
+			if let Some((index, mut status)) = Self::next_for_deciding(&mut track_queue) {
+				let now = frame_system::Pallet::<T>::block_number();
+				let alarm = Self::begin_deciding(&mut status, index, now, track_info);
+				Self::ensure_alarm_at(&mut status, index, alarm);
+				ReferendumInfoFor::<T, I>::insert(index, ReferendumInfo::Ongoing(status));
+			} else {
+				DecidingCount::<T, I>::mutate(track, |x| x.saturating_dec());
+			}
+			TrackQueue::<T, I>::insert(track, track_queue);
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config<I>, I: 'static> Polling<T::Tally> for Pallet<T, I> {
+	type Index = ReferendumIndex;
+	type Votes = VotesOf<T, I>;
+	type Moment = T::BlockNumber;
+	type Class = TrackIdOf<T, I>;
+
+	fn classes() -> Vec<Self::Class> {
+		T::Tracks::tracks().iter().map(|x| x.0).collect()
+	}
+
+	fn access_poll<R>(
+		index: Self::Index,
+		f: impl FnOnce(PollStatus<&mut T::Tally, T::BlockNumber, TrackIdOf<T, I>>) -> R,
+	) -> R {
+		match ReferendumInfoFor::<T, I>::get(index) {
+			Some(ReferendumInfo::Ongoing(mut status)) => {
+				let result = f(PollStatus::Ongoing(&mut status.tally, status.track));
+				let now = frame_system::Pallet::<T>::block_number();
+				Self::ensure_alarm_at(&mut status, index, now + One::one());
+				ReferendumInfoFor::<T, I>::insert(index, ReferendumInfo::Ongoing(status));
+				result
+			},
+			Some(ReferendumInfo::Approved(end, ..)) => f(PollStatus::Completed(end, true)),
+			Some(ReferendumInfo::Rejected(end, ..)) => f(PollStatus::Completed(end, false)),
+			_ => f(PollStatus::None),
+		}
+	}
+
+	fn try_access_poll<R>(
+		index: Self::Index,
+		f: impl FnOnce(
+			PollStatus<&mut T::Tally, T::BlockNumber, TrackIdOf<T, I>>,
+		) -> Result<R, DispatchError>,
+	) -> Result<R, DispatchError> {
+		match ReferendumInfoFor::<T, I>::get(index) {
+			Some(ReferendumInfo::Ongoing(mut status)) => {
+				let result = f(PollStatus::Ongoing(&mut status.tally, status.track))?;
+				let now = frame_system::Pallet::<T>::block_number();
+				Self::ensure_alarm_at(&mut status, index, now + One::one());
+				ReferendumInfoFor::<T, I>::insert(index, ReferendumInfo::Ongoing(status));
+				Ok(result)
+			},
+			Some(ReferendumInfo::Approved(end, ..)) => f(PollStatus::Completed(end, true)),
+			Some(ReferendumInfo::Rejected(end, ..)) => f(PollStatus::Completed(end, false)),
+			_ => f(PollStatus::None),
+		}
+	}
+
+	fn as_ongoing(index: Self::Index) -> Option<(T::Tally, TrackIdOf<T, I>)> {
+		Self::ensure_ongoing(index).ok().map(|x| (x.tally, x.track))
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn create_ongoing(class: Self::Class) -> Result<Self::Index, ()> {
+		let index = ReferendumCount::<T, I>::mutate(|x| {
+			let r = *x;
+			*x += 1;
+			r
+		});
+		let now = frame_system::Pallet::<T>::block_number();
+		let dummy_account_id =
+			codec::Decode::decode(&mut sp_runtime::traits::TrailingZeroInput::new(&b"dummy"[..]))
+				.expect("infinite length input; no invalid inputs for type; qed");
+		let mut status = ReferendumStatusOf::<T, I> {
+			track: class,
+			origin: frame_system::RawOrigin::Root.into(),
+			proposal_hash: <T::Hashing as Hash>::hash_of(&index),
+			enactment: DispatchTime::After(Zero::zero()),
+			submitted: now,
+			submission_deposit: Deposit { who: dummy_account_id, amount: Zero::zero() },
+			decision_deposit: None,
+			deciding: None,
+			tally: TallyOf::<T, I>::new(class),
+			in_queue: false,
+			alarm: None,
+		};
+		Self::ensure_alarm_at(&mut status, index, sp_runtime::traits::Bounded::max_value());
+		ReferendumInfoFor::<T, I>::insert(index, ReferendumInfo::Ongoing(status));
+		Ok(index)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn end_ongoing(index: Self::Index, approved: bool) -> Result<(), ()> {
+		let mut status = Self::ensure_ongoing(index).map_err(|_| ())?;
+		Self::ensure_no_alarm(&mut status);
+		let now = frame_system::Pallet::<T>::block_number();
+		let info = if approved {
+			ReferendumInfo::Approved(now, Some(status.submission_deposit), status.decision_deposit)
+		} else {
+			ReferendumInfo::Rejected(now, Some(status.submission_deposit), status.decision_deposit)
+		};
+		ReferendumInfoFor::<T, I>::insert(index, info);
+		ProposalOf::<T, I>::remove(index);
+		Ok(())
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn max_ongoing() -> (Self::Class, u32) {
+		let r = T::Tracks::tracks()
+			.iter()
+			.max_by_key(|(_, info)| info.max_deciding)
+			.expect("Always one class");
+		(r.0, r.1.max_deciding)
+	}
+}
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Check that referendum `index` is in the `Ongoing` state and return the `ReferendumStatus`
+	/// value, or `Err` otherwise.
+	pub fn ensure_ongoing(
+		index: ReferendumIndex,
+	) -> Result<ReferendumStatusOf<T, I>, DispatchError> {
+		match ReferendumInfoFor::<T, I>::get(index) {
+			Some(ReferendumInfo::Ongoing(status)) => Ok(status),
+			_ => Err(Error::<T, I>::NotOngoing.into()),
+		}
+	}
+
+	/// Store the new `info` of referendum `index`, removing its proposal if it is no longer
+	/// ongoing.
+	fn store_info(index: ReferendumIndex, info: ReferendumInfoOf<T, I>) {
+		if !matches!(info, ReferendumInfo::Ongoing(..)) {
+			ProposalOf::<T, I>::remove(index);
+		}
+		ReferendumInfoFor::<T, I>::insert(index, info);
+	}
+
+	/// Tidy up the ongoing referendum `index` which is being concluded before its time: cancel
+	/// its alarm, remove it from its track's queue or free up its deciding slot, and remove its
+	/// proposal.
+	fn conclude_early(index: ReferendumIndex, status: &mut ReferendumStatusOf<T, I>) {
+		Self::ensure_no_alarm(status);
+		if status.deciding.is_some() {
+			Self::note_one_fewer_deciding(status.track);
+		} else if status.in_queue {
+			TrackQueue::<T, I>::mutate(status.track, |q| q.retain(|x| x.0 != index));
+		}
+		ProposalOf::<T, I>::remove(index);
+	}
+
+	/// Schedule the proposal of referendum `index` for enactment, no earlier than the minimum
+	/// enactment period of its track.
+	fn schedule_enactment(
+		index: ReferendumIndex,
+		track: &TrackInfoOf<T, I>,
+		desired: DispatchTime<T::BlockNumber>,
+		origin: PalletsOriginOf<T, I>,
+	) {
+		let call = match ProposalOf::<T, I>::take(index) {
+			Some(call) => call,
+			None => {
+				debug_assert!(false, "LOGIC ERROR: ongoing referendum without a proposal");
+				return
+			},
+		};
+		let now = frame_system::Pallet::<T>::block_number();
+		let earliest_allowed = now.saturating_add(track.min_enactment_period.max(One::one()));
+		let desired = desired.evaluate(now);
+		let ok = T::Scheduler::schedule_named(
+			(ASSEMBLY_ID, "enactment", index).encode(),
+			DispatchTime::At(desired.max(earliest_allowed)),
+			None,
+			HARD_DEADLINE,
+			origin,
+			call,
+		)
+		.is_ok();
+		debug_assert!(ok, "LOGIC ERROR: bake_referendum/schedule_named failed");
+	}
+
+	/// Set an alarm to dispatch `call` at block number `when`.
+	fn set_alarm(
+		call: impl Into<CallOf<T, I>>,
+		when: T::BlockNumber,
+	) -> Option<(T::BlockNumber, ScheduleAddressOf<T, I>)> {
+		let alarm_interval = T::AlarmInterval::get().max(One::one());
+		let when = (when + alarm_interval - One::one()) / alarm_interval * alarm_interval;
+		let maybe_result = T::Scheduler::schedule(
+			DispatchTime::At(when),
+			None,
+			LOWEST_PRIORITY / 2,
+			frame_system::RawOrigin::Root.into(),
+			call.into(),
+		)
+		.ok()
+		.map(|x| (when, x));
+		debug_assert!(
+			maybe_result.is_some(),
+			"Unable to schedule a new alarm at #{:?} (now: #{:?})?!",
+			when,
+			frame_system::Pallet::<T>::block_number()
+		);
+		maybe_result
+	}
+
+	/// Mutate a referendum's `status` into the correct deciding state.
+	///
+	/// - `now` is the current block number.
+	/// - `track` is the track info for the referendum.
+	///
+	/// This will properly set up the `confirming` item.
+	fn begin_deciding(
+		status: &mut ReferendumStatusOf<T, I>,
+		index: ReferendumIndex,
+		now: T::BlockNumber,
+		track: &TrackInfoOf<T, I>,
+	) -> T::BlockNumber {
+		let is_passing = Self::is_passing(
+			&status.tally,
+			status.track,
+			Zero::zero(),
+			track.decision_period,
+			&track.min_support,
+			&track.min_approval,
+		);
+		status.in_queue = false;
+		Self::deposit_event(Event::<T, I>::DecisionStarted(
+			index,
+			status.track,
+			status.proposal_hash,
+			status.tally.clone(),
+		));
+		let confirming = if is_passing {
+			Self::deposit_event(Event::<T, I>::ConfirmStarted(index));
+			Some(now.saturating_add(track.confirm_period))
+		} else {
+			None
+		};
+		let deciding_status = DecidingStatus { since: now, confirming };
+		let alarm = Self::decision_time(&deciding_status, &status.tally, status.track, track);
+		status.deciding = Some(deciding_status);
+		alarm.max(now.saturating_add(One::one()))
+	}
+
+	/// If it returns `Some`, deciding has begun and it needs waking at the given block number. The
+	/// second item is the flag for whether it is confirming or not.
+	///
+	/// If `None`, then it is queued and should be nudged automatically as the queue gets drained.
+	fn ready_for_deciding(
+		now: T::BlockNumber,
+		track: &TrackInfoOf<T, I>,
+		index: ReferendumIndex,
+		status: &mut ReferendumStatusOf<T, I>,
+	) -> Option<T::BlockNumber> {
+		let deciding_count = DecidingCount::<T, I>::get(status.track);
+		if deciding_count < track.max_deciding {
+			// Begin deciding.
+			DecidingCount::<T, I>::insert(status.track, deciding_count.saturating_add(1));
+			Some(Self::begin_deciding(status, index, now, track))
+		} else {
+			// Add to queue.
+			status.in_queue = true;
+			let ayes = status.tally.ayes(status.track);
+			TrackQueue::<T, I>::mutate(status.track, |q| Self::insert_sorted(q, index, ayes));
+			None
+		}
+	}
+
+	/// Insert referendum `index` into the track `queue`, which is sorted by ascending `ayes`. If
+	/// the queue is full, then the referendum with the fewest ayes is dropped, which might be the
+	/// one being inserted.
+	fn insert_sorted(
+		queue: &mut BoundedVec<(ReferendumIndex, T::Votes), T::MaxQueued>,
+		index: ReferendumIndex,
+		ayes: T::Votes,
+	) {
+		let pos = queue.binary_search_by_key(&ayes, |x| x.1).unwrap_or_else(|x| x);
+		if queue.len() < T::MaxQueued::get() as usize {
+			let _ = queue.try_insert(pos, (index, ayes));
+		} else if pos > 0 {
+			queue.remove(0);
+			let _ = queue.try_insert(pos - 1, (index, ayes));
+		}
+	}
+
+	/// Grab the index and status for the referendum which is the highest priority of those for
+	/// the given track which are ready for being decided.
+	fn next_for_deciding(
+		track_queue: &mut BoundedVec<(u32, VotesOf<T, I>), T::MaxQueued>,
+	) -> Option<(ReferendumIndex, ReferendumStatusOf<T, I>)> {
+		while !track_queue.is_empty() {
+			let (index, _) = track_queue.remove(track_queue.len() - 1);
+			if let Ok(s) = Self::ensure_ongoing(index) {
+				return Some((index, s))
+			}
+			// Otherwise the referendum already timed out or was cancelled.
+		}
+		None
+	}
+
+	/// Schedule a call to `one_fewer_deciding` function via the dispatchable
+	/// `defer_one_fewer_deciding`. We could theoretically call it immediately (and it would be
+	/// overall more efficient), however the weights become rather less easy to measure.
+	fn note_one_fewer_deciding(track: TrackIdOf<T, I>) {
+		// Set an alarm call for the next block to nudge the track along.
+		let now = frame_system::Pallet::<T>::block_number();
+		let next_block = now + One::one();
+		let call = Call::one_fewer_deciding { track };
+		Self::set_alarm(call, next_block);
+	}
+
+	/// Ensure that a `service_referendum` alarm happens for the referendum `index` at `alarm`.
+	///
+	/// This will do nothing if the alarm is already set.
+	///
+	/// Returns `false` if nothing changed.
+	fn ensure_alarm_at(
+		status: &mut ReferendumStatusOf<T, I>,
+		index: ReferendumIndex,
+		alarm: T::BlockNumber,
+	) -> bool {
+		if status.alarm.as_ref().map_or(true, |&(when, _)| when != alarm) {
+			// Either no alarm or one that was different
+			Self::ensure_no_alarm(status);
+			status.alarm = Self::set_alarm(Call::nudge_referendum { index }, alarm);
+			true
+		} else {
+			false
+		}
+	}
+
+	/// Advance the state of a referendum, which comes down to:
+	/// - If it's ready to be decided, start deciding;
+	/// - If it's not ready to be decided and non-deciding timeout has passed, fail;
+	/// - If it's ongoing and passing, ensure confirming; if at end of confirmation period, pass.
+	/// - If it's ongoing and not passing, stop confirming; if it has reached end time, fail.
+	///
+	/// Weight will be a bit different depending on what it does, but it's designed so as not to
+	/// differ dramatically, especially if `MaxQueue` is kept small. In particular _there are no
+	/// balance operations in here_.
+	///
+	/// In terms of storage, every call to it is expected to access:
+	/// - The scheduler, either to insert, remove or alter an entry.
+	/// - `TrackQueue`, which should be a `BoundedVec` with a low limit (8-16).
+	/// - `DecidingCount`.
+	///
+	/// Both of the two storage items will only have as many items as there are different tracks,
+	/// perhaps around 10 and should be whitelisted.
+	///
+	/// The heaviest branch is likely to be when a proposal is placed into, or moved within, the
+	/// `TrackQueue`. Basically this happens when a referendum is in the deciding queue and receives
+	/// a vote, or when it moves into the deciding queue.
+	fn service_referendum(
+		now: T::BlockNumber,
+		index: ReferendumIndex,
+		mut status: ReferendumStatusOf<T, I>,
+	) -> (ReferendumInfoOf<T, I>, bool) {
+		let mut dirty = false;
+		// Should it begin being decided?
+		let track = match Self::track(status.track) {
+			Some(x) => x,
+			None => return (ReferendumInfo::Ongoing(status), false),
+		};
+		let timeout = status.submitted + T::UndecidingTimeout::get();
+		// Default the alarm to the submission timeout.
+		let mut alarm = timeout;
+		match &mut status.deciding {
+			None => {
+				// Are we already queued for deciding?
+				if status.in_queue {
+					// Does our position in the queue need updating?
+					let ayes = status.tally.ayes(status.track);
+					let mut queue = TrackQueue::<T, I>::get(status.track);
+					let maybe_old_pos = queue.iter().position(|(x, _)| *x == index);
+					if let Some(old_pos) = maybe_old_pos {
+						if queue[old_pos].1 != ayes {
+							queue.remove(old_pos);
+							Self::insert_sorted(&mut queue, index, ayes);
+						}
+					} else {
+						// We fell out of the queue; try to get back in.
+						Self::insert_sorted(&mut queue, index, ayes);
+					}
+					TrackQueue::<T, I>::insert(status.track, queue);
+				} else {
+					// Are we ready for deciding?
+					if status.decision_deposit.is_some() {
+						let prepare_end = status.submitted.saturating_add(track.prepare_period);
+						if now >= prepare_end {
+							if let Some(set_alarm) =
+								Self::ready_for_deciding(now, track, index, &mut status)
+							{
+								alarm = set_alarm;
+							}
+							dirty = true;
+						} else {
+							alarm = alarm.min(prepare_end);
+						}
+					}
+				}
+				// If we didn't move into being decided, then check the timeout.
+				if status.deciding.is_none() && now >= timeout && !status.in_queue {
+					// Too long without being decided - end it.
+					Self::ensure_no_alarm(&mut status);
+					Self::deposit_event(Event::<T, I>::TimedOut(index, status.tally));
+					return (
+						ReferendumInfo::TimedOut(
+							now,
+							Some(status.submission_deposit),
+							status.decision_deposit,
+						),
+						true,
+					)
+				}
+			},
+			Some(deciding) => {
+				let is_passing = Self::is_passing(
+					&status.tally,
+					status.track,
+					now.saturating_sub(deciding.since),
+					track.decision_period,
+					&track.min_support,
+					&track.min_approval,
+				);
+				if is_passing {
+					match deciding.confirming {
+						Some(t) if now >= t => {
+							// Passed!
+							Self::ensure_no_alarm(&mut status);
+							Self::note_one_fewer_deciding(status.track);
+							let (desired, origin) = (status.enactment, status.origin);
+							Self::schedule_enactment(index, track, desired, origin);
+							Self::deposit_event(Event::<T, I>::Confirmed(index, status.tally));
+							return (
+								ReferendumInfo::Approved(
+									now,
+									Some(status.submission_deposit),
+									status.decision_deposit,
+								),
+								true,
+							)
+						},
+						Some(_) => {},
+						None => {
+							// Start confirming
+							dirty = true;
+							deciding.confirming = Some(now.saturating_add(track.confirm_period));
+							Self::deposit_event(Event::<T, I>::ConfirmStarted(index));
+						},
+					}
+				} else {
+					if now >= deciding.since.saturating_add(track.decision_period) {
+						// Failed!
+						Self::ensure_no_alarm(&mut status);
+						Self::note_one_fewer_deciding(status.track);
+						Self::deposit_event(Event::<T, I>::Rejected(index, status.tally));
+						return (
+							ReferendumInfo::Rejected(
+								now,
+								Some(status.submission_deposit),
+								status.decision_deposit,
+							),
+							true,
+						)
+					}
+					if deciding.confirming.is_some() {
+						// Stop confirming
+						dirty = true;
+						deciding.confirming = None;
+						Self::deposit_event(Event::<T, I>::ConfirmAborted(index));
+					}
+				}
+				alarm = Self::decision_time(deciding, &status.tally, status.track, track);
+			},
+		}
+
+		let dirty_alarm = if status.in_queue {
+			// Queued referenda are woken up when they get a vote or when they leave the queue.
+			Self::ensure_no_alarm(&mut status)
+		} else {
+			Self::ensure_alarm_at(&mut status, index, alarm.max(now.saturating_add(One::one())))
+		};
+		(ReferendumInfo::Ongoing(status), dirty_alarm || dirty)
+	}
+
+	/// Determine the point at which a referendum will be accepted, move into confirmation with the
+	/// given `tally` or end with rejection (whichever happens sooner).
+	fn decision_time(
+		deciding: &DecidingStatusOf<T>,
+		tally: &T::Tally,
+		track_id: TrackIdOf<T, I>,
+		track: &TrackInfoOf<T, I>,
+	) -> T::BlockNumber {
+		deciding.confirming.unwrap_or_else(|| {
+			// Set alarm to the point where the current voting would make it pass.
+			let approval = tally.approval(track_id);
+			let support = tally.support(track_id);
+			let until_approval = track.min_approval.delay(approval);
+			let until_support = track.min_support.delay(support);
+			let offset = until_support.max(until_approval);
+			deciding.since.saturating_add(offset * track.decision_period)
+		})
+	}
+
+	/// Cancel the alarm in `status`, if one exists.
+	fn ensure_no_alarm(status: &mut ReferendumStatusOf<T, I>) -> bool {
+		if let Some((_, last_alarm)) = status.alarm.take() {
+			// Incorrect alarm - cancel it.
+			let _ = T::Scheduler::cancel(last_alarm);
+			true
+		} else {
+			false
+		}
+	}
+
+	/// Reserve a deposit and return the `Deposit` instance.
+	fn take_deposit(
+		who: T::AccountId,
+		amount: BalanceOf<T, I>,
+	) -> Result<Deposit<T::AccountId, BalanceOf<T, I>>, DispatchError> {
+		T::Currency::reserve(&who, amount)?;
+		Ok(Deposit { who, amount })
+	}
+
+	/// Return a deposit, if `Some`.
+	fn refund_deposit(deposit: Option<Deposit<T::AccountId, BalanceOf<T, I>>>) {
+		if let Some(Deposit { who, amount }) = deposit {
+			T::Currency::unreserve(&who, amount);
+		}
+	}
+
+	/// Slash a deposit, if `Some`.
+	fn slash_deposit(deposit: Option<Deposit<T::AccountId, BalanceOf<T, I>>>) {
+		if let Some(Deposit { who, amount }) = deposit {
+			T::Slash::on_unbalanced(T::Currency::slash_reserved(&who, amount).0);
+			Self::deposit_event(Event::<T, I>::DepositSlashed(who, amount));
+		}
+	}
+
+	/// Get the track info value for the track `id`.
+	fn track(id: TrackIdOf<T, I>) -> Option<&'static TrackInfoOf<T, I>> {
+		T::Tracks::info(id)
+	}
+
+	/// Determine whether the given `tally` would result in a referendum passing at `elapsed` blocks
+	/// into a total decision `period`, given the two curves for `support_needed` and
+	/// `approval_needed`.
+	fn is_passing(
+		tally: &T::Tally,
+		track_id: TrackIdOf<T, I>,
+		elapsed: T::BlockNumber,
+		period: T::BlockNumber,
+		support_needed: &Curve,
+		approval_needed: &Curve,
+	) -> bool {
+		let x = Perbill::from_rational(elapsed.min(period), period);
+		support_needed.passing(x, tally.support(track_id)) &&
+			approval_needed.passing(x, tally.approval(track_id))
+	}
+}
@@ -0,0 +1,433 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The crate's tests.
+
+use super::*;
+use crate::mock::*;
+use codec::{Decode, Encode};
+use frame_support::{assert_noop, assert_ok, traits::Contains};
+use pallet_balances::Error as BalancesError;
+use sp_runtime::traits::BadOrigin;
+
+#[test]
+fn params_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(ReferendumCount::<Test>::get(), 0);
+		assert_eq!(Balances::free_balance(42), 0);
+		assert_eq!(Balances::total_issuance(), 600);
+	});
+}
+
+#[test]
+fn basic_happy_path_works() {
+	new_test_ext().execute_with(|| {
+		// #1: submit
+		assert_ok!(propose_set_balance(1, 1, 1));
+		assert_eq!(Balances::reserved_balance(&1), 2);
+		assert_eq!(ReferendumCount::<Test>::get(), 1);
+		assert_eq!(ProposalOf::<Test>::get(0), Some(set_balance_proposal(1)));
+		assert_ok!(Referenda::place_decision_deposit(Origin::signed(2), 0));
+		run_to(4);
+		assert_eq!(DecidingCount::<Test>::get(0), 0);
+		run_to(5);
+		// #5: 4 blocks after submission - start deciding.
+		assert_eq!(DecidingCount::<Test>::get(0), 1);
+		assert_eq!(deciding_and_failing_since(0), 5);
+		set_tally(0, 100, 0);
+		run_to(6);
+		// #6: Passing; start confirming.
+		assert_eq!(confirming_until(0), 8);
+		run_to(7);
+		assert_eq!(confirming_until(0), 8);
+		run_to(8);
+		// #8: Confirmed.
+		assert_eq!(approved_since(0), 8);
+		assert_eq!(ProposalOf::<Test>::get(0), None);
+		run_to(9);
+		// #9: Should now have one fewer deciding.
+		assert_eq!(DecidingCount::<Test>::get(0), 0);
+		run_to(11);
+		assert_eq!(Balances::free_balance(42), 0);
+		run_to(12);
+		// #12: Enacted after the minimum enactment period.
+		assert_eq!(Balances::free_balance(42), 1);
+	});
+}
+
+#[test]
+fn insta_confirm_then_kill_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(propose_set_balance(1, 1, 1));
+		assert_ok!(Referenda::place_decision_deposit(Origin::signed(2), 0));
+		set_tally(0, 100, 0);
+		run_to(5);
+		// Begins deciding in a passing state, so starts confirming right away.
+		assert_eq!(deciding_since(0), 5);
+		assert_eq!(confirming_until(0), 7);
+		assert_ok!(Referenda::kill(Origin::root(), 0));
+		assert_eq!(killed_since(0), 5);
+		// Both deposits are slashed.
+		assert_eq!(Balances::free_balance(1), 98);
+		assert_eq!(Balances::free_balance(2), 90);
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_eq!(Balances::reserved_balance(&2), 0);
+		run_to(6);
+		assert_eq!(DecidingCount::<Test>::get(0), 0);
+	});
+}
+
+#[test]
+fn confirm_then_reconfirm_with_elapsed_trigger_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(propose_set_balance(1, 1, 1));
+		assert_ok!(Referenda::place_decision_deposit(Origin::signed(2), 0));
+		run_to(5);
+		set_tally(0, 100, 99);
+		run_to(6);
+		// Support is enough, but approval is just above 50%, so passing only late in the period.
+		assert_eq!(deciding_and_failing_since(0), 5);
+		run_to(7);
+		assert_eq!(deciding_and_failing_since(0), 5);
+		run_to(8);
+		// #8: 3/4 through the period, approval needed is 62.5%... still failing.
+		assert_eq!(deciding_and_failing_since(0), 5);
+		set_tally(0, 100, 0);
+		run_to(9);
+		assert_eq!(confirming_until(0), 11);
+		set_tally(0, 0, 100);
+		run_to(10);
+		// #10: Aborted confirmation, then end of decision period; rejected.
+		assert_eq!(rejected_since(0), 10);
+	});
+}
+
+#[test]
+fn queueing_works() {
+	new_test_ext().execute_with(|| {
+		// Submit a proposal into a track with a queue len of 1.
+		assert_ok!(propose_set_balance(5, 2, 0));
+		assert_ok!(Referenda::place_decision_deposit(Origin::signed(5), 0));
+
+		run_to(2);
+
+		// Submit 3 more proposals into the same queue.
+		for i in 1..=3 {
+			assert_ok!(propose_set_balance(i, 2, 0));
+			assert_ok!(Referenda::place_decision_deposit(Origin::signed(i), i as u32));
+		}
+		assert_eq!(ReferendumCount::<Test>::get(), 4);
+
+		run_to(5);
+		// One should be being decided.
+		assert_eq!(DecidingCount::<Test>::get(0), 1);
+		assert_eq!(deciding_and_failing_since(0), 5);
+		for i in 1..=3 {
+			assert_eq!(waiting_since(i), 2);
+		}
+
+		// Vote to set order.
+		set_tally(1, 1, 10);
+		set_tally(2, 2, 20);
+		set_tally(3, 3, 30);
+		run_to(6);
+		let queue = TrackQueue::<Test>::get(0);
+		assert_eq!(queue.into_inner(), vec![(1, 1), (2, 2), (3, 3)]);
+
+		// Cancel the first.
+		assert_ok!(Referenda::cancel(Origin::signed(4), 0));
+		assert_eq!(cancelled_since(0), 6);
+
+		// The other with the most approvals (#3) should be being decided.
+		run_to(7);
+		assert_eq!(DecidingCount::<Test>::get(0), 1);
+		assert_eq!(deciding_and_failing_since(3), 7);
+
+		// Vote on the remaining two to change order.
+		set_tally(1, 30, 31);
+		set_tally(2, 20, 20);
+		run_to(8);
+		let queue = TrackQueue::<Test>::get(0);
+		assert_eq!(queue.into_inner(), vec![(2, 20), (1, 30)]);
+
+		// Let the decision period of #3 end unsuccessfully.
+		run_to(11);
+		assert_eq!(rejected_since(3), 11);
+
+		// #1 (the one with the most approvals) should now be being decided.
+		run_to(12);
+		assert_eq!(deciding_and_failing_since(1), 12);
+
+		// Let it end unsuccessfully.
+		run_to(16);
+		assert_eq!(rejected_since(1), 16);
+
+		// #2 should now be being decided. It will (barely) pass.
+		run_to(17);
+		assert_eq!(deciding_and_failing_since(2), 17);
+
+		// #2 moves into confirming at the last moment with a 50% approval.
+		run_to(21);
+		assert_eq!(confirming_until(2), 23);
+
+		// #2 gets approved.
+		run_to(23);
+		assert_eq!(approved_since(2), 23);
+		run_to(24);
+		assert_eq!(DecidingCount::<Test>::get(0), 0);
+	});
+}
+
+#[test]
+fn auto_timeouts_should_happen_with_nothing_but_submit() {
+	new_test_ext().execute_with(|| {
+		// #1: submit
+		assert_ok!(propose_set_balance(1, 1, 1));
+		run_to(20);
+		assert!(matches!(ReferendumInfoFor::<Test>::get(0), Some(ReferendumInfo::Ongoing(..))));
+		run_to(21);
+		// #11: Timed out - ended.
+		assert_eq!(timed_out_since(0), 21);
+		assert_eq!(ProposalOf::<Test>::get(0), None);
+		// The submission deposit is not refundable.
+		assert_noop!(
+			Referenda::refund_submission_deposit(Origin::signed(1), 0),
+			Error::<Test>::BadReferendum
+		);
+	});
+}
+
+#[test]
+fn tracks_are_distinguished() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Referenda::submit(
+			Origin::signed(1),
+			Box::new(frame_system::RawOrigin::Root.into()),
+			Box::new(set_balance_proposal(1)),
+			DispatchTime::At(10),
+		));
+		assert_ok!(Referenda::submit(
+			Origin::signed(2),
+			Box::new(frame_system::RawOrigin::None.into()),
+			Box::new(set_balance_proposal(2)),
+			DispatchTime::At(20),
+		));
+
+		assert_ok!(Referenda::place_decision_deposit(Origin::signed(3), 0));
+		assert_ok!(Referenda::place_decision_deposit(Origin::signed(4), 1));
+
+		let mut i = ReferendumInfoFor::<Test>::iter().collect::<Vec<_>>();
+		i.sort_by_key(|x| x.0);
+		assert_eq!(
+			i,
+			vec![
+				(
+					0,
+					ReferendumInfo::Ongoing(ReferendumStatus {
+						track: 0,
+						origin: OriginCaller::system(frame_system::RawOrigin::Root),
+						proposal_hash: set_balance_proposal_hash(1),
+						enactment: DispatchTime::At(10),
+						submitted: 1,
+						submission_deposit: Deposit { who: 1, amount: 2 },
+						decision_deposit: Some(Deposit { who: 3, amount: 10 }),
+						deciding: None,
+						tally: Tally { ayes: 0, nays: 0 },
+						in_queue: false,
+						alarm: Some((5, (5, 0))),
+					})
+				),
+				(
+					1,
+					ReferendumInfo::Ongoing(ReferendumStatus {
+						track: 1,
+						origin: OriginCaller::system(frame_system::RawOrigin::None),
+						proposal_hash: set_balance_proposal_hash(2),
+						enactment: DispatchTime::At(20),
+						submitted: 1,
+						submission_deposit: Deposit { who: 2, amount: 2 },
+						decision_deposit: Some(Deposit { who: 4, amount: 1 }),
+						deciding: None,
+						tally: Tally { ayes: 0, nays: 0 },
+						in_queue: false,
+						alarm: Some((3, (3, 0))),
+					})
+				),
+			]
+		);
+	});
+}
+
+#[test]
+fn submit_errors_work() {
+	new_test_ext().execute_with(|| {
+		let h = set_balance_proposal(1);
+		// No track for Signed origins.
+		assert_noop!(
+			Referenda::submit(
+				Origin::signed(1),
+				Box::new(frame_system::RawOrigin::Signed(2).into()),
+				Box::new(h.clone()),
+				DispatchTime::At(10),
+			),
+			Error::<Test>::NoTrack
+		);
+
+		// No funds for deposit
+		assert_noop!(
+			Referenda::submit(
+				Origin::signed(10),
+				Box::new(frame_system::RawOrigin::Root.into()),
+				Box::new(h),
+				DispatchTime::At(10),
+			),
+			BalancesError::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn decision_deposit_errors_work() {
+	new_test_ext().execute_with(|| {
+		let e = Error::<Test>::NotOngoing;
+		assert_noop!(Referenda::place_decision_deposit(Origin::signed(2), 0), e);
+
+		assert_ok!(propose_set_balance(1, 1, 1));
+		let e = BalancesError::<Test>::InsufficientBalance;
+		assert_noop!(Referenda::place_decision_deposit(Origin::signed(10), 0), e);
+
+		assert_ok!(Referenda::place_decision_deposit(Origin::signed(2), 0));
+		let e = Error::<Test>::HasDeposit;
+		assert_noop!(Referenda::place_decision_deposit(Origin::signed(2), 0), e);
+	});
+}
+
+#[test]
+fn refund_deposit_works() {
+	new_test_ext().execute_with(|| {
+		let e = Error::<Test>::BadReferendum;
+		assert_noop!(Referenda::refund_decision_deposit(Origin::signed(1), 0), e);
+
+		assert_ok!(propose_set_balance(1, 1, 1));
+		let e = Error::<Test>::NoDeposit;
+		assert_noop!(Referenda::refund_decision_deposit(Origin::signed(2), 0), e);
+
+		assert_ok!(Referenda::place_decision_deposit(Origin::signed(2), 0));
+		let e = Error::<Test>::Unfinished;
+		assert_noop!(Referenda::refund_decision_deposit(Origin::signed(3), 0), e);
+		let e = Error::<Test>::BadReferendum;
+		assert_noop!(Referenda::refund_submission_deposit(Origin::signed(3), 0), e);
+
+		run_to(11);
+		assert_ok!(Referenda::refund_decision_deposit(Origin::signed(3), 0));
+		assert_eq!(Balances::free_balance(2), 100);
+		let e = Error::<Test>::NoDeposit;
+		assert_noop!(Referenda::refund_decision_deposit(Origin::signed(3), 0), e);
+	});
+}
+
+#[test]
+fn cancel_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(propose_set_balance(1, 1, 1));
+		assert_ok!(Referenda::place_decision_deposit(Origin::signed(2), 0));
+		run_to(8);
+
+		assert_ok!(Referenda::cancel(Origin::signed(4), 0));
+		assert_ok!(Referenda::refund_decision_deposit(Origin::signed(3), 0));
+		assert_ok!(Referenda::refund_submission_deposit(Origin::signed(3), 0));
+		assert_eq!(cancelled_since(0), 8);
+		assert_eq!(Balances::free_balance(1), 100);
+		assert_eq!(Balances::free_balance(2), 100);
+		let e = Error::<Test>::NoDeposit;
+		assert_noop!(Referenda::refund_submission_deposit(Origin::signed(3), 0), e);
+		run_to(9);
+		assert_eq!(DecidingCount::<Test>::get(0), 0);
+	});
+}
+
+#[test]
+fn cancel_errors_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(propose_set_balance(1, 1, 1));
+		assert_ok!(Referenda::place_decision_deposit(Origin::signed(2), 0));
+		run_to(8);
+		assert_noop!(Referenda::cancel(Origin::signed(1), 0), BadOrigin);
+
+		assert_ok!(Referenda::kill(Origin::root(), 0));
+		let e = Error::<Test>::NotOngoing;
+		assert_noop!(Referenda::cancel(Origin::signed(4), 0), e);
+	});
+}
+
+#[test]
+fn kill_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(propose_set_balance(1, 1, 1));
+		assert_ok!(Referenda::place_decision_deposit(Origin::signed(2), 0));
+		run_to(8);
+
+		assert_ok!(Referenda::kill(Origin::root(), 0));
+		let e = Error::<Test>::NoDeposit;
+		assert_noop!(Referenda::refund_decision_deposit(Origin::signed(3), 0), e);
+		assert_eq!(killed_since(0), 8);
+		assert_eq!(Balances::free_balance(1), 98);
+		assert_eq!(Balances::free_balance(2), 90);
+		assert_eq!(Balances::total_issuance(), 588);
+	});
+}
+
+#[test]
+fn kill_errors_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(propose_set_balance(1, 1, 1));
+		assert_ok!(Referenda::place_decision_deposit(Origin::signed(2), 0));
+		run_to(8);
+		assert_noop!(Referenda::kill(Origin::signed(4), 0), BadOrigin);
+
+		assert_ok!(Referenda::cancel(Origin::signed(4), 0));
+		let e = Error::<Test>::NotOngoing;
+		assert_noop!(Referenda::kill(Origin::root(), 0), e);
+	});
+}
+
+#[test]
+fn set_balance_proposal_is_correctly_filtered_out() {
+	for i in 0..10 {
+		let call = crate::mock::Call::decode(&mut &set_balance_proposal(i).encode()[..]).unwrap();
+		assert!(!<Test as frame_system::Config>::BaseCallFilter::contains(&call));
+	}
+}
+
+#[test]
+fn cancelling_a_queued_referendum_removes_it_from_the_queue() {
+	new_test_ext().execute_with(|| {
+		for i in 0..2 {
+			assert_ok!(propose_set_balance(1, 2, 0));
+			assert_ok!(Referenda::place_decision_deposit(Origin::signed(2), i));
+		}
+		run_to(5);
+		assert_eq!(deciding_since(0), 5);
+		assert_eq!(TrackQueue::<Test>::get(0).into_inner(), vec![(1, 0)]);
+
+		assert_ok!(Referenda::cancel(Origin::signed(4), 1));
+		assert!(TrackQueue::<Test>::get(0).is_empty());
+		// The deciding slot is not given up.
+		run_to(6);
+		assert_eq!(DecidingCount::<Test>::get(0), 1);
+	});
+}
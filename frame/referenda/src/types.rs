@@ -0,0 +1,415 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Miscellaneous additional datatypes.
+
+use super::*;
+use codec::{Decode, Encode, EncodeLike};
+use frame_support::{traits::schedule::Anon, Parameter};
+use scale_info::TypeInfo;
+use sp_arithmetic::{FixedI64, FixedPointNumber};
+use sp_runtime::{PerThing, RuntimeDebug};
+use sp_std::fmt::Debug;
+
+pub type BalanceOf<T, I = ()> =
+	<<T as Config<I>>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+pub type NegativeImbalanceOf<T, I> = <<T as Config<I>>::Currency as Currency<
+	<T as frame_system::Config>::AccountId,
+>>::NegativeImbalance;
+pub type CallOf<T, I> = <T as Config<I>>::Call;
+pub type VotesOf<T, I> = <T as Config<I>>::Votes;
+pub type TallyOf<T, I> = <T as Config<I>>::Tally;
+pub type PalletsOriginOf<T, I> = <T as Config<I>>::PalletsOrigin;
+pub type ReferendumInfoOf<T, I> = ReferendumInfo<
+	TrackIdOf<T, I>,
+	PalletsOriginOf<T, I>,
+	<T as frame_system::Config>::BlockNumber,
+	<T as frame_system::Config>::Hash,
+	BalanceOf<T, I>,
+	TallyOf<T, I>,
+	<T as frame_system::Config>::AccountId,
+	ScheduleAddressOf<T, I>,
+>;
+pub type ReferendumStatusOf<T, I> = ReferendumStatus<
+	TrackIdOf<T, I>,
+	PalletsOriginOf<T, I>,
+	<T as frame_system::Config>::BlockNumber,
+	<T as frame_system::Config>::Hash,
+	BalanceOf<T, I>,
+	TallyOf<T, I>,
+	<T as frame_system::Config>::AccountId,
+	ScheduleAddressOf<T, I>,
+>;
+pub type DecidingStatusOf<T> = DecidingStatus<<T as frame_system::Config>::BlockNumber>;
+pub type TrackInfoOf<T, I = ()> =
+	TrackInfo<BalanceOf<T, I>, <T as frame_system::Config>::BlockNumber>;
+pub type TrackIdOf<T, I> = <<T as Config<I>>::Tracks as TracksInfo<
+	BalanceOf<T, I>,
+	<T as frame_system::Config>::BlockNumber,
+>>::Id;
+pub type ScheduleAddressOf<T, I> = <<T as Config<I>>::Scheduler as Anon<
+	<T as frame_system::Config>::BlockNumber,
+	CallOf<T, I>,
+	PalletsOriginOf<T, I>,
+>>::Address;
+
+/// A referendum index.
+pub type ReferendumIndex = u32;
+
+/// Details of a deposit which has been placed.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct Deposit<AccountId, Balance> {
+	/// The account which placed the deposit.
+	pub who: AccountId,
+	/// The amount of the deposit.
+	pub amount: Balance,
+}
+
+/// Details of a referendum which is being decided.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct DecidingStatus<BlockNumber> {
+	/// When this referendum began being "decided". If confirming, then the end will actually be
+	/// delayed until the end of the confirmation period.
+	pub since: BlockNumber,
+	/// If `Some`, then the referendum has entered confirmation stage and will end at
+	/// the block number as long as it doesn't lose its approval in the meantime.
+	pub confirming: Option<BlockNumber>,
+}
+
+/// Information concerning a single track on which referenda may be submitted.
+#[derive(Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct TrackInfo<Balance, Moment> {
+	/// Name of this track.
+	pub name: &'static str,
+	/// A limit for the number of referenda on this track that can be being decided at once.
+	/// For Root origin this should generally be just one.
+	pub max_deciding: u32,
+	/// Amount that must be placed on deposit before a decision can be made.
+	pub decision_deposit: Balance,
+	/// Amount of time this must be submitted for before a decision can be made.
+	pub prepare_period: Moment,
+	/// Amount of time that a decision may take to be approved prior to cancellation.
+	pub decision_period: Moment,
+	/// Amount of time that the approval criteria must hold before it can be approved.
+	pub confirm_period: Moment,
+	/// Minimum amount of time that an approved proposal must be in the dispatch queue.
+	pub min_enactment_period: Moment,
+	/// Minimum approval as a function of the proportion of the decision period elapsed.
+	pub min_approval: Curve,
+	/// Minimum support as a function of the proportion of the decision period elapsed.
+	pub min_support: Curve,
+}
+
+/// Information on the voting tracks.
+pub trait TracksInfo<Balance, Moment> {
+	/// The identifier for a track.
+	type Id: Copy + Parameter + Ord + PartialOrd + Send + Sync + 'static;
+
+	/// The origin type from which a track is implied.
+	type Origin;
+
+	/// Return the array of known tracks and their information.
+	fn tracks() -> &'static [(Self::Id, TrackInfo<Balance, Moment>)];
+
+	/// Determine the voting track for the given `origin`.
+	fn track_for(origin: &Self::Origin) -> Result<Self::Id, ()>;
+
+	/// Return the track info for track `id`, by default this just looks it up in `Self::tracks()`.
+	fn info(id: Self::Id) -> Option<&'static TrackInfo<Balance, Moment>> {
+		Self::tracks().iter().find(|x| x.0 == id).map(|x| &x.1)
+	}
+}
+
+/// Info regarding an ongoing referendum.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ReferendumStatus<
+	TrackId: Eq + PartialEq + Debug + Encode + Decode + TypeInfo + Clone,
+	Origin: Eq + PartialEq + Debug + Encode + Decode + TypeInfo + Clone,
+	Moment: Parameter + Eq + PartialEq + Debug + Encode + Decode + TypeInfo + Clone + EncodeLike,
+	Hash: Eq + PartialEq + Debug + Encode + Decode + TypeInfo + Clone,
+	Balance: Eq + PartialEq + Debug + Encode + Decode + TypeInfo + Clone,
+	Tally: Eq + PartialEq + Debug + Encode + Decode + TypeInfo + Clone,
+	AccountId: Eq + PartialEq + Debug + Encode + Decode + TypeInfo + Clone,
+	ScheduleAddress: Eq + PartialEq + Debug + Encode + Decode + TypeInfo + Clone,
+> {
+	/// The track of this referendum.
+	pub track: TrackId,
+	/// The origin for this referendum.
+	pub origin: Origin,
+	/// The hash of the proposal up for referendum.
+	pub proposal_hash: Hash,
+	/// The time the proposal should be scheduled for enactment.
+	pub enactment: DispatchTime<Moment>,
+	/// The time of submission. Once `UndecidingTimeout` passes, it may be closed by anyone if it
+	/// `deciding` is `None`.
+	pub submitted: Moment,
+	/// The deposit reserved for the submission of this referendum.
+	pub submission_deposit: Deposit<AccountId, Balance>,
+	/// The deposit reserved for this referendum to be decided.
+	pub decision_deposit: Option<Deposit<AccountId, Balance>>,
+	/// The status of a decision being made. If `None`, it has not entered the deciding period.
+	pub deciding: Option<DecidingStatus<Moment>>,
+	/// The current tally of votes in this referendum.
+	pub tally: Tally,
+	/// Whether we have been placed in the queue for being decided or not.
+	pub in_queue: bool,
+	/// The next scheduled wake-up, if `Some`.
+	pub alarm: Option<(Moment, ScheduleAddress)>,
+}
+
+/// Info regarding a referendum, present or past.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum ReferendumInfo<
+	TrackId: Eq + PartialEq + Debug + Encode + Decode + TypeInfo + Clone,
+	Origin: Eq + PartialEq + Debug + Encode + Decode + TypeInfo + Clone,
+	Moment: Parameter + Eq + PartialEq + Debug + Encode + Decode + TypeInfo + Clone + EncodeLike,
+	Hash: Eq + PartialEq + Debug + Encode + Decode + TypeInfo + Clone,
+	Balance: Eq + PartialEq + Debug + Encode + Decode + TypeInfo + Clone,
+	Tally: Eq + PartialEq + Debug + Encode + Decode + TypeInfo + Clone,
+	AccountId: Eq + PartialEq + Debug + Encode + Decode + TypeInfo + Clone,
+	ScheduleAddress: Eq + PartialEq + Debug + Encode + Decode + TypeInfo + Clone,
+> {
+	/// Referendum has been submitted and is being voted on.
+	Ongoing(
+		ReferendumStatus<TrackId, Origin, Moment, Hash, Balance, Tally, AccountId, ScheduleAddress>,
+	),
+	/// Referendum finished with approval. Submission deposit is held.
+	Approved(Moment, Option<Deposit<AccountId, Balance>>, Option<Deposit<AccountId, Balance>>),
+	/// Referendum finished with rejection. Submission deposit is held.
+	Rejected(Moment, Option<Deposit<AccountId, Balance>>, Option<Deposit<AccountId, Balance>>),
+	/// Referendum finished with cancelation. Submission deposit is held.
+	Cancelled(Moment, Option<Deposit<AccountId, Balance>>, Option<Deposit<AccountId, Balance>>),
+	/// Referendum finished and was never decided. Submission deposit is held.
+	TimedOut(Moment, Option<Deposit<AccountId, Balance>>, Option<Deposit<AccountId, Balance>>),
+	/// Referendum finished with a kill.
+	Killed(Moment),
+}
+
+impl<
+		TrackId: Eq + PartialEq + Debug + Encode + Decode + TypeInfo + Clone,
+		Origin: Eq + PartialEq + Debug + Encode + Decode + TypeInfo + Clone,
+		Moment: Parameter + Eq + PartialEq + Debug + Encode + Decode + TypeInfo + Clone + EncodeLike,
+		Hash: Eq + PartialEq + Debug + Encode + Decode + TypeInfo + Clone,
+		Balance: Eq + PartialEq + Debug + Encode + Decode + TypeInfo + Clone,
+		Tally: Eq + PartialEq + Debug + Encode + Decode + TypeInfo + Clone,
+		AccountId: Eq + PartialEq + Debug + Encode + Decode + TypeInfo + Clone,
+		ScheduleAddress: Eq + PartialEq + Debug + Encode + Decode + TypeInfo + Clone,
+	> ReferendumInfo<TrackId, Origin, Moment, Hash, Balance, Tally, AccountId, ScheduleAddress>
+{
+	/// Take the Decision Deposit from `self`, if there is one. Returns an `Err` if `self` is not
+	/// in a valid state for the Decision Deposit to be refunded.
+	pub fn take_decision_deposit(&mut self) -> Result<Option<Deposit<AccountId, Balance>>, ()> {
+		use ReferendumInfo::*;
+		match self {
+			Ongoing(x) if x.decision_deposit.is_none() => Ok(None),
+			// Cannot refund deposit if Ongoing as this breaks assumptions.
+			Ongoing(_) => Err(()),
+			Approved(_, _, d) | Rejected(_, _, d) | TimedOut(_, _, d) | Cancelled(_, _, d) =>
+				Ok(d.take()),
+			Killed(_) => Ok(None),
+		}
+	}
+
+	/// Take the Submission Deposit from `self`, if there is one and it's in a valid state to be
+	/// taken. Returns an `Err` if `self` is not in a valid state for the Submission Deposit to be
+	/// refunded.
+	pub fn take_submission_deposit(&mut self) -> Result<Option<Deposit<AccountId, Balance>>, ()> {
+		use ReferendumInfo::*;
+		match self {
+			// Only the submission deposits of approved or cancelled referenda are refundable.
+			Approved(_, s, _) | Cancelled(_, s, _) => Ok(s.take()),
+			_ => Err(()),
+		}
+	}
+}
+
+/// Type for describing a curve over the 2-dimensional space of axes between 0-1, as represented
+/// by `(Perbill, Perbill)`.
+#[derive(Clone, Eq, PartialEq, RuntimeDebug)]
+pub enum Curve {
+	/// Linear curve starting at `(0, ceil)`, proceeding linearly to `(length, floor)`, then
+	/// remaining at `floor` until the end of the period.
+	LinearDecreasing { length: Perbill, floor: Perbill, ceil: Perbill },
+	/// Stepped curve, beginning at `(0, begin)`, then remaining constant for `period`, at which
+	/// point it steps down to `(period, begin - step)`. It then remains constant for another
+	/// `period` before stepping down to `(period * 2, begin - step * 2)`. This pattern continues
+	/// but the `y` component has a lower limit of `end`.
+	SteppedDecreasing { begin: Perbill, end: Perbill, step: Perbill, period: Perbill },
+	/// A reciprocal (`K/(x+S)-T`) curve: `factor` is `K`, `x_offset` is `S` and `y_offset` is
+	/// `T`.
+	Reciprocal { factor: FixedI64, x_offset: FixedI64, y_offset: FixedI64 },
+}
+
+/// Convert a `Perbill` into a `FixedI64`; both have a denominator of one billion.
+fn perbill_to_fixed(x: Perbill) -> FixedI64 {
+	FixedI64::from_inner(x.deconstruct() as i64)
+}
+
+/// Convert a number of billionths into a `Perbill`, clamping it into the range `0..=1`.
+fn clamp_to_perbill(parts: i128) -> Perbill {
+	Perbill::from_parts(parts.max(0).min(Perbill::ACCURACY as i128) as u32)
+}
+
+impl Curve {
+	/// Determine the `y` value for the given `x` value.
+	pub fn threshold(&self, x: Perbill) -> Perbill {
+		match self {
+			Self::LinearDecreasing { length, floor, ceil } => {
+				if length.is_zero() {
+					return *floor
+				}
+				let progress =
+					Perbill::from_rational(x.min(*length).deconstruct(), length.deconstruct());
+				ceil.saturating_sub(progress * ceil.saturating_sub(*floor))
+			},
+			Self::SteppedDecreasing { begin, end, step, period } => {
+				if period.is_zero() {
+					return *end
+				}
+				let steps = x.deconstruct() / period.deconstruct();
+				let drop = Perbill::from_parts(step.deconstruct().saturating_mul(steps));
+				begin.saturating_sub(drop).max(*end)
+			},
+			Self::Reciprocal { factor, x_offset, y_offset } => {
+				let divisor =
+					perbill_to_fixed(x).into_inner() as i128 + x_offset.into_inner() as i128;
+				if divisor <= 0 {
+					return Perbill::one()
+				}
+				let scaled = factor.into_inner() as i128 * FixedI64::DIV as i128;
+				clamp_to_perbill(scaled.div_euclid(divisor) - y_offset.into_inner() as i128)
+			},
+		}
+	}
+
+	/// Determine the smallest `x` value such that `passing` returns `true` when passed along with
+	/// the given `y` value.
+	///
+	/// If `passing` never returns `true` for any value of `x` when paired with `y`, then
+	/// `Perbill::one` may be returned.
+	pub fn delay(&self, y: Perbill) -> Perbill {
+		match self {
+			Self::LinearDecreasing { length, floor, ceil } =>
+				if y < *floor {
+					Perbill::one()
+				} else if y >= *ceil {
+					Perbill::zero()
+				} else {
+					// Round up, so that the curve at the returned point is no higher than `y`.
+					let num = (ceil.deconstruct() - y.deconstruct()) as u64;
+					let den = (ceil.deconstruct() - floor.deconstruct()) as u64;
+					let parts = (num * length.deconstruct() as u64 + den - 1) / den;
+					Perbill::from_parts(parts as u32)
+				},
+			Self::SteppedDecreasing { begin, end, step, period } =>
+				if y < *end {
+					Perbill::one()
+				} else if y >= *begin {
+					Perbill::zero()
+				} else if step.is_zero() {
+					Perbill::one()
+				} else {
+					let gap = (begin.deconstruct() - y.deconstruct()) as u64;
+					let steps = (gap + step.deconstruct() as u64 - 1) / step.deconstruct() as u64;
+					let parts = steps.saturating_mul(period.deconstruct() as u64);
+					Perbill::from_parts(parts.min(Perbill::ACCURACY as u64) as u32)
+				},
+			Self::Reciprocal { factor, x_offset, y_offset } => {
+				// `x = K/(y+T) - S`, rounded up so that the curve at `x` is no higher than `y`.
+				let divisor =
+					perbill_to_fixed(y).into_inner() as i128 + y_offset.into_inner() as i128;
+				if divisor <= 0 {
+					return Perbill::one()
+				}
+				let scaled = factor.into_inner() as i128 * FixedI64::DIV as i128;
+				let x = -((-scaled).div_euclid(divisor)) - x_offset.into_inner() as i128;
+				if x > Perbill::ACCURACY as i128 {
+					Perbill::one()
+				} else {
+					clamp_to_perbill(x)
+				}
+			},
+		}
+	}
+
+	/// Return `true` iff the `y` value is greater than the curve at the `x`.
+	pub fn passing(&self, x: Perbill, y: Perbill) -> bool {
+		y >= self.threshold(x)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn percent(x: u32) -> Perbill {
+		Perbill::from_percent(x)
+	}
+
+	#[test]
+	fn linear_curve_works() {
+		let c =
+			Curve::LinearDecreasing { length: percent(50), floor: percent(10), ceil: percent(90) };
+		assert_eq!(c.threshold(percent(0)), percent(90));
+		assert_eq!(c.threshold(percent(25)), percent(50));
+		assert_eq!(c.threshold(percent(50)), percent(10));
+		assert_eq!(c.threshold(percent(100)), percent(10));
+		assert_eq!(c.delay(percent(100)), percent(0));
+		assert_eq!(c.delay(percent(50)), percent(25));
+		assert_eq!(c.delay(percent(10)), percent(50));
+		assert_eq!(c.delay(percent(5)), percent(100));
+	}
+
+	#[test]
+	fn stepped_curve_works() {
+		let c = Curve::SteppedDecreasing {
+			begin: percent(80),
+			end: percent(30),
+			step: percent(10),
+			period: percent(15),
+		};
+		assert_eq!(c.threshold(percent(0)), percent(80));
+		assert_eq!(c.threshold(percent(15)), percent(70));
+		assert_eq!(c.threshold(percent(29)), percent(70));
+		assert_eq!(c.threshold(percent(60)), percent(40));
+		assert_eq!(c.threshold(percent(100)), percent(30));
+		assert_eq!(c.delay(percent(80)), percent(0));
+		assert_eq!(c.delay(percent(75)), percent(15));
+		assert_eq!(c.delay(percent(70)), percent(15));
+		assert_eq!(c.delay(percent(30)), percent(75));
+		assert_eq!(c.delay(percent(20)), percent(100));
+	}
+
+	#[test]
+	fn reciprocal_curve_works() {
+		// `y = 0.1 / (x + 0.1)`, which goes from 100% at `x = 0` to ~9% at `x = 1`.
+		let c = Curve::Reciprocal {
+			factor: FixedI64::saturating_from_rational(1, 10),
+			x_offset: FixedI64::saturating_from_rational(1, 10),
+			y_offset: FixedI64::zero(),
+		};
+		assert_eq!(c.threshold(percent(0)), percent(100));
+		assert_eq!(c.threshold(percent(40)), percent(20));
+		assert_eq!(c.delay(percent(100)), percent(0));
+		assert_eq!(c.delay(percent(20)), percent(40));
+		assert_eq!(c.delay(percent(5)), percent(100));
+		for i in 0..=100 {
+			let y = percent(i);
+			assert!(c.passing(c.delay(y), y) || c.delay(y) == Perbill::one());
+		}
+	}
+}
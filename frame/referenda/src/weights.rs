@@ -0,0 +1,191 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Autogenerated weights for pallet_referenda
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2021-10-20, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 128
+
+// Executed Command:
+// target/release/substrate
+// benchmark
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_referenda
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./frame/referenda/src/weights.rs
+// --template=./.maintain/frame-weight-template.hbs
+
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_referenda.
+pub trait WeightInfo {
+	fn submit() -> Weight;
+	fn place_decision_deposit() -> Weight;
+	fn refund_decision_deposit() -> Weight;
+	fn refund_submission_deposit() -> Weight;
+	fn cancel() -> Weight;
+	fn kill() -> Weight;
+	fn nudge_referendum() -> Weight;
+	fn one_fewer_deciding() -> Weight;
+}
+
+/// Weights for pallet_referenda using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: Scheduler Agenda (r:1 w:1)
+	// Storage: Referenda ReferendumCount (r:1 w:1)
+	// Storage: Referenda ReferendumInfoFor (r:0 w:1)
+	// Storage: Referenda ProposalOf (r:0 w:1)
+	fn submit() -> Weight {
+		(38_530_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Referenda ReferendumInfoFor (r:1 w:1)
+	// Storage: Referenda DecidingCount (r:1 w:1)
+	// Storage: Scheduler Agenda (r:2 w:2)
+	fn place_decision_deposit() -> Weight {
+		(52_104_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Referenda ReferendumInfoFor (r:1 w:1)
+	fn refund_decision_deposit() -> Weight {
+		(24_297_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Referenda ReferendumInfoFor (r:1 w:1)
+	fn refund_submission_deposit() -> Weight {
+		(24_071_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Referenda ReferendumInfoFor (r:1 w:1)
+	// Storage: Scheduler Agenda (r:2 w:2)
+	// Storage: Referenda ProposalOf (r:0 w:1)
+	fn cancel() -> Weight {
+		(36_547_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Referenda ReferendumInfoFor (r:1 w:1)
+	// Storage: Scheduler Agenda (r:2 w:2)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Referenda ProposalOf (r:0 w:1)
+	fn kill() -> Weight {
+		(62_180_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: Referenda ReferendumInfoFor (r:1 w:1)
+	// Storage: Scheduler Agenda (r:3 w:3)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Referenda ProposalOf (r:1 w:1)
+	fn nudge_referendum() -> Weight {
+		(56_762_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+	// Storage: Referenda TrackQueue (r:1 w:1)
+	// Storage: Referenda ReferendumInfoFor (r:1 w:1)
+	// Storage: Scheduler Agenda (r:1 w:1)
+	fn one_fewer_deciding() -> Weight {
+		(41_918_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: Scheduler Agenda (r:1 w:1)
+	// Storage: Referenda ReferendumCount (r:1 w:1)
+	// Storage: Referenda ReferendumInfoFor (r:0 w:1)
+	// Storage: Referenda ProposalOf (r:0 w:1)
+	fn submit() -> Weight {
+		(38_530_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Referenda ReferendumInfoFor (r:1 w:1)
+	// Storage: Referenda DecidingCount (r:1 w:1)
+	// Storage: Scheduler Agenda (r:2 w:2)
+	fn place_decision_deposit() -> Weight {
+		(52_104_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Referenda ReferendumInfoFor (r:1 w:1)
+	fn refund_decision_deposit() -> Weight {
+		(24_297_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Referenda ReferendumInfoFor (r:1 w:1)
+	fn refund_submission_deposit() -> Weight {
+		(24_071_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Referenda ReferendumInfoFor (r:1 w:1)
+	// Storage: Scheduler Agenda (r:2 w:2)
+	// Storage: Referenda ProposalOf (r:0 w:1)
+	fn cancel() -> Weight {
+		(36_547_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Referenda ReferendumInfoFor (r:1 w:1)
+	// Storage: Scheduler Agenda (r:2 w:2)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Referenda ProposalOf (r:0 w:1)
+	fn kill() -> Weight {
+		(62_180_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	// Storage: Referenda ReferendumInfoFor (r:1 w:1)
+	// Storage: Scheduler Agenda (r:3 w:3)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Referenda ProposalOf (r:1 w:1)
+	fn nudge_referendum() -> Weight {
+		(56_762_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
+	// Storage: Referenda TrackQueue (r:1 w:1)
+	// Storage: Referenda ReferendumInfoFor (r:1 w:1)
+	// Storage: Scheduler Agenda (r:1 w:1)
+	fn one_fewer_deciding() -> Weight {
+		(41_918_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+}
@@ -0,0 +1,169 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Salary pallet benchmarking.
+
+use super::*;
+#[allow(unused_imports)]
+use crate::Pallet as Salary;
+
+use frame_benchmarking::{account, benchmarks_instance_pallet, impl_benchmark_test_suite};
+use frame_support::assert_ok;
+use frame_system::RawOrigin;
+
+const SEED: u32 = 0;
+
+fn assert_last_event<T: Config<I>, I: 'static>(generic_event: <T as Config<I>>::Event) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+/// Make `who` a member with a non-zero salary, promoting them as necessary.
+fn ensure_member_with_salary<T: Config<I>, I: 'static>(who: &T::AccountId) {
+	// Induct if not a member.
+	if T::Members::rank_of(who).is_none() {
+		assert_ok!(T::Members::induct(who));
+	}
+	// Promote until they have a salary.
+	for _ in 0..255 {
+		let r = T::Members::rank_of(who).expect("prior guard ensures `who` is a member; qed");
+		if !T::Salary::get_salary(r, who).is_zero() {
+			break
+		}
+		assert_ok!(T::Members::promote(who));
+	}
+}
+
+/// Start the salary system with `who` inducted, and move on to the next cycle so that claims
+/// may be made.
+fn start_with_claimant<T: Config<I>, I: 'static>(who: &T::AccountId) {
+	ensure_member_with_salary::<T, I>(who);
+	assert_ok!(Salary::<T, I>::init(RawOrigin::Signed(who.clone()).into()));
+	assert_ok!(Salary::<T, I>::induct(RawOrigin::Signed(who.clone()).into()));
+	next_cycle::<T, I>();
+	assert_ok!(Salary::<T, I>::bump(RawOrigin::Signed(who.clone()).into()));
+}
+
+fn next_cycle<T: Config<I>, I: 'static>() {
+	let now = frame_system::Pallet::<T>::block_number();
+	frame_system::Pallet::<T>::set_block_number(now + Salary::<T, I>::cycle_period());
+}
+
+fn to_payout_period<T: Config<I>, I: 'static>() {
+	let now = frame_system::Pallet::<T>::block_number();
+	frame_system::Pallet::<T>::set_block_number(now + T::RegistrationPeriod::get());
+}
+
+benchmarks_instance_pallet! {
+	init {
+		let caller: T::AccountId = account("caller", 0, SEED);
+	}: _(RawOrigin::Signed(caller.clone()))
+	verify {
+		assert!(Status::<T, I>::get().is_some());
+		assert_last_event::<T, I>(Event::CycleStarted(0).into());
+	}
+
+	bump {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		Salary::<T, I>::init(RawOrigin::Signed(caller.clone()).into())?;
+		next_cycle::<T, I>();
+	}: _(RawOrigin::Signed(caller.clone()))
+	verify {
+		assert_eq!(Status::<T, I>::get().unwrap().cycle_index, 1);
+		assert_last_event::<T, I>(Event::CycleStarted(1).into());
+	}
+
+	induct {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		ensure_member_with_salary::<T, I>(&caller);
+		Salary::<T, I>::init(RawOrigin::Signed(caller.clone()).into())?;
+	}: _(RawOrigin::Signed(caller.clone()))
+	verify {
+		assert!(Claimant::<T, I>::contains_key(&caller));
+		assert_last_event::<T, I>(Event::Inducted(caller).into());
+	}
+
+	register {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		start_with_claimant::<T, I>(&caller);
+	}: _(RawOrigin::Signed(caller.clone()))
+	verify {
+		assert!(matches!(
+			Claimant::<T, I>::get(&caller).map(|c| c.status),
+			Some(ClaimState::Registered(_))
+		));
+	}
+
+	payout {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		start_with_claimant::<T, I>(&caller);
+		Salary::<T, I>::register(RawOrigin::Signed(caller.clone()).into())?;
+		to_payout_period::<T, I>();
+
+		let rank = T::Members::rank_of(&caller).ok_or("Caller must be a member")?;
+		let salary = T::Salary::get_salary(rank, &caller);
+		T::Paymaster::ensure_successful(&caller, (), salary);
+	}: _(RawOrigin::Signed(caller.clone()))
+	verify {
+		assert!(matches!(
+			Claimant::<T, I>::get(&caller).map(|c| c.status),
+			Some(ClaimState::Attempted { .. })
+		));
+	}
+
+	payout_other {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		start_with_claimant::<T, I>(&caller);
+		Salary::<T, I>::register(RawOrigin::Signed(caller.clone()).into())?;
+		to_payout_period::<T, I>();
+
+		let rank = T::Members::rank_of(&caller).ok_or("Caller must be a member")?;
+		let salary = T::Salary::get_salary(rank, &caller);
+		let recipient: T::AccountId = account("recipient", 0, SEED);
+		T::Paymaster::ensure_successful(&recipient, (), salary);
+	}: _(RawOrigin::Signed(caller.clone()), recipient)
+	verify {
+		assert!(matches!(
+			Claimant::<T, I>::get(&caller).map(|c| c.status),
+			Some(ClaimState::Attempted { .. })
+		));
+	}
+
+	check_payment {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		start_with_claimant::<T, I>(&caller);
+		Salary::<T, I>::register(RawOrigin::Signed(caller.clone()).into())?;
+		to_payout_period::<T, I>();
+
+		let rank = T::Members::rank_of(&caller).ok_or("Caller must be a member")?;
+		let salary = T::Salary::get_salary(rank, &caller);
+		T::Paymaster::ensure_successful(&caller, (), salary);
+		Salary::<T, I>::payout(RawOrigin::Signed(caller.clone()).into())?;
+		let id = match Claimant::<T, I>::get(&caller).map(|c| c.status) {
+			Some(ClaimState::Attempted { id, .. }) => id,
+			_ => return Err("Payment must have been attempted".into()),
+		};
+		T::Paymaster::ensure_concluded(id);
+	}: _(RawOrigin::Signed(caller.clone()))
+	verify {
+		assert!(!matches!(
+			Claimant::<T, I>::get(&caller).map(|c| c.status),
+			Some(ClaimState::Attempted { .. })
+		));
+	}
+}
+
+impl_benchmark_test_suite!(Salary, crate::tests::new_test_ext(), crate::tests::Test);
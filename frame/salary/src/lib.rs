@@ -0,0 +1,478 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Salary pallet.
+//!
+//! - [`Config`]
+//! - [`Call`]
+//!
+//! ## Overview
+//!
+//! This pallet makes periodic payments to the members of a [`RankedMembers`] collective, with the
+//! amount paid to each member determined by their rank through [`Config::Salary`].
+//!
+//! Payments are made in cycles. Each cycle begins with a registration period of
+//! [`Config::RegistrationPeriod`] blocks during which members may `register` their claim for the
+//! cycle, after which a payout period of [`Config::PayoutPeriod`] blocks follows during which they
+//! may claim their `payout` (or have it paid to another account with `payout_other`). Anyone may
+//! `bump` the pallet into the next cycle once both periods have elapsed.
+//!
+//! The total of all payments in a cycle is capped by [`Config::Budget`]. Registered members are
+//! paid first; should the registered claims exceed the budget, each of them is paid the same
+//! proportion of their salary. Members who did not register may still claim a payout during the
+//! payout period, but are only paid out of what remains of the budget, first come first served.
+//!
+//! Payments are made through [`Config::Paymaster`] and may not complete immediately. The claimant
+//! calls `check_payment` to poll the outcome; should the payment have failed, the claim is reset
+//! so that it may be retried.
+//!
+//! Members must be inducted into the payroll with `induct` before they can claim, and may not
+//! claim for the cycle in which they were inducted.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{Saturating, Zero},
+	Perbill, RuntimeDebug,
+};
+use sp_std::{marker::PhantomData, prelude::*};
+
+use frame_support::{
+	dispatch::DispatchResultWithPostInfo,
+	ensure,
+	traits::{
+		tokens::{Pay, PaymentStatus},
+		Get, RankedMembers,
+	},
+	weights::Pays,
+};
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+/// Payroll cycle.
+pub type CycleIndex = u32;
+
+/// The status of the pallet instance.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+pub struct StatusType<CycleIndex, BlockNumber, Balance> {
+	/// The index of the "current cycle" (i.e. the last cycle being processed).
+	pub cycle_index: CycleIndex,
+	/// The first block of the "current cycle" (i.e. the last cycle being processed).
+	pub cycle_start: BlockNumber,
+	/// The total budget available for all payments in the current cycle.
+	pub budget: Balance,
+	/// The total amount of the payments registered in the current cycle.
+	pub total_registrations: Balance,
+	/// The total amount of unregistered payments which have been made in the current cycle.
+	pub total_unregistered_paid: Balance,
+}
+
+/// The state of a specific payment claim.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+pub enum ClaimState<Balance, Id> {
+	/// No claim recorded.
+	Nothing,
+	/// Amount reserved when last active.
+	Registered(Balance),
+	/// Amount attempted to be paid when last active as well as the identity of the payment.
+	Attempted { registered: Option<Balance>, id: Id, amount: Balance },
+}
+
+use ClaimState::*;
+
+/// The status of a single payee/claimant.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+pub struct ClaimantStatus<CycleIndex, Balance, Id> {
+	/// The most recent cycle in which the claimant was active.
+	pub last_active: CycleIndex,
+	/// The state of the payment/claim with in the above cycle.
+	pub status: ClaimState<Balance, Id>,
+}
+
+/// Something that can determine the salary of a member of a collective.
+pub trait GetSalary<Rank, AccountId, Balance> {
+	/// Retrieve the salary for a given rank. The account ID is also supplied in case this changes
+	/// things.
+	fn get_salary(rank: Rank, who: &AccountId) -> Balance;
+}
+
+/// The balance type used by the paymaster of the salary pallet `T`, instance `I`.
+pub type BalanceOf<T, I = ()> = <<T as Config<I>>::Paymaster as Pay>::Balance;
+/// The payment identifier type used by the paymaster of the salary pallet `T`, instance `I`.
+pub type IdOf<T, I = ()> = <<T as Config<I>>::Paymaster as Pay>::Id;
+/// The rank type used by the members of the salary pallet `T`, instance `I`.
+pub type RankOf<T, I = ()> = <<T as Config<I>>::Members as RankedMembers>::Rank;
+/// The status type of the salary pallet `T`, instance `I`.
+pub type StatusOf<T, I = ()> =
+	StatusType<CycleIndex, <T as frame_system::Config>::BlockNumber, BalanceOf<T, I>>;
+/// The claimant status type of the salary pallet `T`, instance `I`.
+pub type ClaimantStatusOf<T, I = ()> = ClaimantStatus<CycleIndex, BalanceOf<T, I>, IdOf<T, I>>;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T, I = ()>(PhantomData<(T, I)>);
+
+	#[pallet::config]
+	pub trait Config<I: 'static = ()>: frame_system::Config {
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		/// The runtime event type.
+		type Event: From<Event<Self, I>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Means by which we can make payments to accounts. This also defines the currency and the
+		/// balance which we use to denote that currency.
+		type Paymaster: Pay<Beneficiary = <Self as frame_system::Config>::AccountId, AssetKind = ()>;
+
+		/// The current membership of payees.
+		type Members: RankedMembers<AccountId = <Self as frame_system::Config>::AccountId>;
+
+		/// The maximum payout to be made for a single period to an active member of the given rank.
+		type Salary: GetSalary<RankOf<Self, I>, Self::AccountId, BalanceOf<Self, I>>;
+
+		/// The number of blocks at the beginning of each cycle during which members may register
+		/// their claim for the cycle.
+		#[pallet::constant]
+		type RegistrationPeriod: Get<Self::BlockNumber>;
+
+		/// The number of blocks after the registration period during which members may claim
+		/// their payout.
+		#[pallet::constant]
+		type PayoutPeriod: Get<Self::BlockNumber>;
+
+		/// The total budget per cycle.
+		///
+		/// This may change over the course of a cycle without any problem.
+		#[pallet::constant]
+		type Budget: Get<BalanceOf<Self, I>>;
+	}
+
+	/// The overall status of the system.
+	#[pallet::storage]
+	pub type Status<T: Config<I>, I: 'static = ()> = StorageValue<_, StatusOf<T, I>, OptionQuery>;
+
+	/// The status of a claimant.
+	#[pallet::storage]
+	pub type Claimant<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, T::AccountId, ClaimantStatusOf<T, I>, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config<I>, I: 'static = ()> {
+		/// A member has been inducted into the payroll. \[who\]
+		Inducted(T::AccountId),
+		/// A member has registered for a payout of `amount` in the current cycle.
+		/// \[who, amount\]
+		Registered(T::AccountId, BalanceOf<T, I>),
+		/// A payment of `amount` has been made to `beneficiary` for the claim of `who`; `id` is
+		/// the identifier of the payment. \[who, beneficiary, amount, id\]
+		Paid(T::AccountId, T::AccountId, BalanceOf<T, I>, IdOf<T, I>),
+		/// The next cycle has begun. \[index\]
+		CycleStarted(CycleIndex),
+	}
+
+	#[pallet::error]
+	pub enum Error<T, I = ()> {
+		/// The salary system has already been started.
+		AlreadyStarted,
+		/// The account is not a ranked member.
+		NotMember,
+		/// The account is already inducted.
+		AlreadyInducted,
+		/// The account is not yet inducted into the system.
+		NotInducted,
+		/// The member does not have a current valid claim.
+		NoClaim,
+		/// The member's claim is zero.
+		ClaimZero,
+		/// Current cycle's registration period is over.
+		TooLate,
+		/// Current cycle's payment period is not yet begun.
+		TooEarly,
+		/// Cycle is not yet over.
+		NotYet,
+		/// The payout cycles have not yet started.
+		NotStarted,
+		/// There was some issue with the mechanism of payment.
+		PayError,
+		/// The payment has neither failed nor succeeded yet.
+		Inconclusive,
+		/// The cycle is after that in which the payment was made.
+		NotCurrent,
+	}
+
+	#[pallet::call]
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// Start the first payout cycle.
+		///
+		/// - `origin`: A `Signed` origin of an account.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::init())]
+		pub fn init(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(!Status::<T, I>::exists(), Error::<T, I>::AlreadyStarted);
+			let status = StatusType {
+				cycle_index: Zero::zero(),
+				cycle_start: now,
+				budget: T::Budget::get(),
+				total_registrations: Zero::zero(),
+				total_unregistered_paid: Zero::zero(),
+			};
+			Status::<T, I>::put(&status);
+
+			Self::deposit_event(Event::<T, I>::CycleStarted(status.cycle_index));
+			Ok(Pays::No.into())
+		}
+
+		/// Move to next payout cycle, assuming that the present block is now within that cycle.
+		///
+		/// - `origin`: A `Signed` origin of an account.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::bump())]
+		pub fn bump(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			let cycle_period = Self::cycle_period();
+			let mut status = Status::<T, I>::get().ok_or(Error::<T, I>::NotStarted)?;
+			ensure!(now >= status.cycle_start.saturating_add(cycle_period), Error::<T, I>::NotYet);
+			status.cycle_index.saturating_inc();
+			status.cycle_start.saturating_accrue(cycle_period);
+			status.budget = T::Budget::get();
+			status.total_registrations = Zero::zero();
+			status.total_unregistered_paid = Zero::zero();
+			Status::<T, I>::put(&status);
+
+			Self::deposit_event(Event::<T, I>::CycleStarted(status.cycle_index));
+			Ok(Pays::No.into())
+		}
+
+		/// Induct oneself into the payout system.
+		///
+		/// - `origin`: A `Signed` origin of a ranked member who is not yet inducted.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::induct())]
+		pub fn induct(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let cycle_index = Status::<T, I>::get().ok_or(Error::<T, I>::NotStarted)?.cycle_index;
+			T::Members::rank_of(&who).ok_or(Error::<T, I>::NotMember)?;
+			ensure!(!Claimant::<T, I>::contains_key(&who), Error::<T, I>::AlreadyInducted);
+
+			Claimant::<T, I>::insert(
+				&who,
+				ClaimantStatus { last_active: cycle_index, status: Nothing },
+			);
+
+			Self::deposit_event(Event::<T, I>::Inducted(who));
+			Ok(Pays::No.into())
+		}
+
+		/// Register for a payout in the current cycle.
+		///
+		/// - `origin`: A `Signed` origin of an inducted ranked member.
+		///
+		/// This may only be called during the registration period of the cycle.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::register())]
+		pub fn register(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let rank = T::Members::rank_of(&who).ok_or(Error::<T, I>::NotMember)?;
+			let mut status = Status::<T, I>::get().ok_or(Error::<T, I>::NotStarted)?;
+			let mut claimant = Claimant::<T, I>::get(&who).ok_or(Error::<T, I>::NotInducted)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(
+				now < status.cycle_start.saturating_add(T::RegistrationPeriod::get()),
+				Error::<T, I>::TooLate
+			);
+			ensure!(claimant.last_active < status.cycle_index, Error::<T, I>::NoClaim);
+			let payout = T::Salary::get_salary(rank, &who);
+			ensure!(!payout.is_zero(), Error::<T, I>::ClaimZero);
+			claimant.last_active = status.cycle_index;
+			claimant.status = Registered(payout);
+			status.total_registrations.saturating_accrue(payout);
+
+			Claimant::<T, I>::insert(&who, &claimant);
+			Status::<T, I>::put(&status);
+
+			Self::deposit_event(Event::<T, I>::Registered(who, payout));
+			Ok(Pays::No.into())
+		}
+
+		/// Request a payout.
+		///
+		/// - `origin`: A `Signed` origin of an inducted ranked member.
+		///
+		/// This may only be called during the payout period of the cycle. Registered members are
+		/// paid their registered amount (scaled down should the registrations exceed the budget);
+		/// unregistered members are paid out of what remains of the budget.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::payout())]
+		pub fn payout(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::do_payout(who.clone(), who)?;
+			Ok(Pays::No.into())
+		}
+
+		/// Request a payout to a secondary account.
+		///
+		/// - `origin`: A `Signed` origin of an inducted ranked member.
+		/// - `beneficiary`: The account to receive payment.
+		///
+		/// This is otherwise identical to `payout`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::payout_other())]
+		pub fn payout_other(
+			origin: OriginFor<T>,
+			beneficiary: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::do_payout(who, beneficiary)?;
+			Ok(Pays::No.into())
+		}
+
+		/// Update a payment's status; if it failed, alter the state so the payment can be retried.
+		///
+		/// - `origin`: A `Signed` origin of an account which is a member of `Members` who has
+		///   received a payment this cycle.
+		///
+		/// This must be called within the same cycle as the failed payment. It will fail with
+		/// `Inconclusive` if the payment has neither failed nor succeeded yet.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::check_payment())]
+		pub fn check_payment(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let mut status = Status::<T, I>::get().ok_or(Error::<T, I>::NotStarted)?;
+			let mut claimant = Claimant::<T, I>::get(&who).ok_or(Error::<T, I>::NotInducted)?;
+			ensure!(claimant.last_active == status.cycle_index, Error::<T, I>::NotCurrent);
+			let (id, registered, amount) = match claimant.status {
+				Attempted { id, registered, amount } => (id, registered, amount),
+				_ => return Err(Error::<T, I>::NoClaim.into()),
+			};
+			match T::Paymaster::check_payment(id) {
+				PaymentStatus::Failure => {
+					// Payment failed: we reset back to the status prior to payment.
+					if let Some(amount) = registered {
+						// Account registered; this makes it simple to roll back and allow retry.
+						claimant.status = Registered(amount);
+					} else {
+						// Account didn't register; we set it to `Nothing` but must decrement
+						// the `last_active` also to ensure a retry works.
+						claimant.last_active.saturating_dec();
+						claimant.status = Nothing;
+						// Since it is not registered, we must walk back our counter for what has
+						// been paid.
+						status.total_unregistered_paid.saturating_reduce(amount);
+					}
+				},
+				PaymentStatus::Success => claimant.status = Nothing,
+				_ => return Err(Error::<T, I>::Inconclusive.into()),
+			}
+			Claimant::<T, I>::insert(&who, &claimant);
+			Status::<T, I>::put(&status);
+
+			Ok(Pays::No.into())
+		}
+	}
+
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// The total length of a cycle, i.e. the registration period followed by the payout
+		/// period.
+		pub fn cycle_period() -> T::BlockNumber {
+			T::RegistrationPeriod::get().saturating_add(T::PayoutPeriod::get())
+		}
+
+		fn do_payout(who: T::AccountId, beneficiary: T::AccountId) -> DispatchResult {
+			let mut status = Status::<T, I>::get().ok_or(Error::<T, I>::NotStarted)?;
+			let mut claimant = Claimant::<T, I>::get(&who).ok_or(Error::<T, I>::NotInducted)?;
+
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(
+				now >= status.cycle_start.saturating_add(T::RegistrationPeriod::get()),
+				Error::<T, I>::TooEarly,
+			);
+
+			let (payout, registered) = match claimant.status {
+				Registered(unpaid) if claimant.last_active == status.cycle_index => {
+					// Registered for this cycle. Pay accordingly.
+					let payout = if status.total_registrations <= status.budget {
+						// Can pay in full.
+						unpaid
+					} else {
+						// Must be reduced pro-rata.
+						Perbill::from_rational(status.budget, status.total_registrations)
+							.mul_floor(unpaid)
+					};
+					(payout, Some(unpaid))
+				},
+				_ if claimant.last_active < status.cycle_index => {
+					// Not registered for this cycle. Pay from whatever is left.
+					let rank = T::Members::rank_of(&who).ok_or(Error::<T, I>::NotMember)?;
+					let ideal_payout = T::Salary::get_salary(rank, &who);
+
+					let pot = status
+						.budget
+						.saturating_sub(status.total_registrations)
+						.saturating_sub(status.total_unregistered_paid);
+
+					let payout = ideal_payout.min(pot);
+					ensure!(!payout.is_zero(), Error::<T, I>::ClaimZero);
+
+					status.total_unregistered_paid.saturating_accrue(payout);
+					(payout, None)
+				},
+				_ => return Err(Error::<T, I>::NoClaim.into()),
+			};
+
+			claimant.last_active = status.cycle_index;
+
+			let id =
+				T::Paymaster::pay(&beneficiary, (), payout).map_err(|_| Error::<T, I>::PayError)?;
+
+			claimant.status = Attempted { registered, id, amount: payout };
+
+			Claimant::<T, I>::insert(&who, &claimant);
+			Status::<T, I>::put(&status);
+
+			Self::deposit_event(Event::<T, I>::Paid(who, beneficiary, payout, id));
+			Ok(())
+		}
+	}
+}
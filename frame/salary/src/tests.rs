@@ -0,0 +1,509 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The crate's tests.
+
+use std::{cell::RefCell, collections::BTreeMap};
+
+use super::*;
+use crate as pallet_salary;
+use frame_support::{assert_noop, assert_ok, parameter_types, traits::Everything};
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, IdentityLookup},
+	DispatchResult,
+};
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Salary: pallet_salary::{Pallet, Call, Storage, Event<T>},
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+}
+impl frame_system::Config for Test {
+	type BaseCallFilter = Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type Origin = Origin;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Call = Call;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+}
+
+thread_local! {
+	pub static PAID: RefCell<BTreeMap<u64, u64>> = RefCell::new(BTreeMap::new());
+	pub static STATUS: RefCell<BTreeMap<u64, PaymentStatus>> = RefCell::new(BTreeMap::new());
+	pub static LAST_ID: RefCell<u64> = RefCell::new(0u64);
+	pub static CLUB: RefCell<BTreeMap<u64, u64>> = RefCell::new(BTreeMap::new());
+}
+
+fn paid(who: u64) -> u64 {
+	PAID.with(|p| p.borrow().get(&who).cloned().unwrap_or(0))
+}
+fn unpay(who: u64, amount: u64) {
+	PAID.with(|p| p.borrow_mut().entry(who).or_default().saturating_reduce(amount))
+}
+fn set_status(id: u64, s: PaymentStatus) {
+	STATUS.with(|m| m.borrow_mut().insert(id, s));
+}
+
+/// Records payments to beneficiaries; every payment stays in progress until its status is set.
+pub struct TestPay;
+impl Pay for TestPay {
+	type Beneficiary = u64;
+	type Balance = u64;
+	type Id = u64;
+	type AssetKind = ();
+	type Error = ();
+
+	fn pay(
+		who: &Self::Beneficiary,
+		_: Self::AssetKind,
+		amount: Self::Balance,
+	) -> Result<Self::Id, Self::Error> {
+		PAID.with(|paid| *paid.borrow_mut().entry(*who).or_default() += amount);
+		Ok(LAST_ID.with(|lid| {
+			let x = *lid.borrow();
+			lid.replace(x + 1);
+			x
+		}))
+	}
+	fn check_payment(id: Self::Id) -> PaymentStatus {
+		STATUS.with(|s| s.borrow().get(&id).cloned().unwrap_or(PaymentStatus::Unknown))
+	}
+	#[cfg(feature = "runtime-benchmarks")]
+	fn ensure_successful(_: &Self::Beneficiary, _: Self::AssetKind, _: Self::Balance) {}
+	#[cfg(feature = "runtime-benchmarks")]
+	fn ensure_concluded(id: Self::Id) {
+		set_status(id, PaymentStatus::Failure)
+	}
+}
+
+/// A ranked membership kept in memory, where each rank is one step up from the last.
+pub struct TestClub;
+impl RankedMembers for TestClub {
+	type AccountId = u64;
+	type Rank = u64;
+
+	fn min_rank() -> Self::Rank {
+		0
+	}
+	fn rank_of(who: &Self::AccountId) -> Option<Self::Rank> {
+		CLUB.with(|club| club.borrow().get(who).cloned())
+	}
+	fn induct(who: &Self::AccountId) -> DispatchResult {
+		CLUB.with(|club| club.borrow_mut().insert(*who, 0));
+		Ok(())
+	}
+	fn promote(who: &Self::AccountId) -> DispatchResult {
+		CLUB.with(|club| {
+			club.borrow_mut().entry(*who).and_modify(|r| *r += 1);
+		});
+		Ok(())
+	}
+	fn demote(who: &Self::AccountId) -> DispatchResult {
+		CLUB.with(|club| {
+			let mut club = club.borrow_mut();
+			match club.get(who).cloned() {
+				None => Err(sp_runtime::DispatchError::CannotLookup),
+				Some(0) => {
+					club.remove(who);
+					Ok(())
+				},
+				Some(rank) => {
+					club.insert(*who, rank - 1);
+					Ok(())
+				},
+			}
+		})
+	}
+}
+
+fn set_rank(who: u64, rank: u64) {
+	CLUB.with(|club| club.borrow_mut().insert(who, rank));
+}
+
+/// Pays ten units per rank.
+pub struct TenPerRank;
+impl GetSalary<u64, u64, u64> for TenPerRank {
+	fn get_salary(rank: u64, _: &u64) -> u64 {
+		rank * 10
+	}
+}
+
+parameter_types! {
+	pub const RegistrationPeriod: u64 = 2;
+	pub const PayoutPeriod: u64 = 2;
+	pub static Budget: u64 = 10;
+}
+
+impl Config for Test {
+	type WeightInfo = ();
+	type Event = Event;
+	type Paymaster = TestPay;
+	type Members = TestClub;
+	type Salary = TenPerRank;
+	type RegistrationPeriod = RegistrationPeriod;
+	type PayoutPeriod = PayoutPeriod;
+	type Budget = Budget;
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	PAID.with(|p| p.borrow_mut().clear());
+	STATUS.with(|s| s.borrow_mut().clear());
+	LAST_ID.with(|l| l.replace(0));
+	CLUB.with(|c| c.borrow_mut().clear());
+	let t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+fn next_block() {
+	System::set_block_number(System::block_number() + 1);
+}
+
+fn run_to(n: u64) {
+	while System::block_number() < n {
+		next_block();
+	}
+}
+
+/// Start the salary system and induct `who`, all at rank 1.
+fn init_with(who: &[u64]) {
+	assert_ok!(Salary::init(Origin::signed(1)));
+	for &i in who {
+		set_rank(i, 1);
+		assert_ok!(Salary::induct(Origin::signed(i)));
+	}
+}
+
+#[test]
+fn basic_stuff() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Status::<Test>::get(), None);
+		assert_noop!(Salary::register(Origin::signed(1)), Error::<Test>::NotMember);
+		set_rank(1, 1);
+		assert_noop!(Salary::register(Origin::signed(1)), Error::<Test>::NotStarted);
+		assert_noop!(Salary::payout(Origin::signed(1)), Error::<Test>::NotStarted);
+	});
+}
+
+#[test]
+fn can_start() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Salary::init(Origin::signed(1)));
+		assert_eq!(
+			Status::<Test>::get(),
+			Some(StatusType {
+				cycle_index: 0,
+				cycle_start: 1,
+				budget: 10,
+				total_registrations: 0,
+				total_unregistered_paid: 0,
+			})
+		);
+		assert_noop!(Salary::init(Origin::signed(1)), Error::<Test>::AlreadyStarted);
+	});
+}
+
+#[test]
+fn bump_works() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(Salary::bump(Origin::signed(1)), Error::<Test>::NotStarted);
+		assert_ok!(Salary::init(Origin::signed(1)));
+		run_to(4);
+		assert_noop!(Salary::bump(Origin::signed(1)), Error::<Test>::NotYet);
+
+		run_to(5);
+		Budget::set(20);
+		assert_ok!(Salary::bump(Origin::signed(1)));
+		System::assert_last_event(Event::Salary(crate::Event::CycleStarted(1)));
+		assert_eq!(
+			Status::<Test>::get(),
+			Some(StatusType {
+				cycle_index: 1,
+				cycle_start: 5,
+				budget: 20,
+				total_registrations: 0,
+				total_unregistered_paid: 0,
+			})
+		);
+
+		// Cycles stay aligned even if they are bumped late.
+		run_to(11);
+		assert_ok!(Salary::bump(Origin::signed(1)));
+		assert_eq!(Status::<Test>::get().unwrap().cycle_start, 9);
+	});
+}
+
+#[test]
+fn induct_works() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(Salary::induct(Origin::signed(1)), Error::<Test>::NotStarted);
+		assert_ok!(Salary::init(Origin::signed(1)));
+		assert_noop!(Salary::induct(Origin::signed(1)), Error::<Test>::NotMember);
+		set_rank(1, 1);
+		assert_ok!(Salary::induct(Origin::signed(1)));
+		System::assert_last_event(Event::Salary(crate::Event::Inducted(1)));
+		assert_eq!(
+			Claimant::<Test>::get(1),
+			Some(ClaimantStatus { last_active: 0, status: Nothing })
+		);
+		assert_noop!(Salary::induct(Origin::signed(1)), Error::<Test>::AlreadyInducted);
+	});
+}
+
+#[test]
+fn no_claim_in_cycle_of_induction() {
+	new_test_ext().execute_with(|| {
+		init_with(&[1]);
+		assert_noop!(Salary::register(Origin::signed(1)), Error::<Test>::NoClaim);
+		run_to(3);
+		assert_noop!(Salary::payout(Origin::signed(1)), Error::<Test>::NoClaim);
+	});
+}
+
+#[test]
+fn unregistered_payment_works() {
+	new_test_ext().execute_with(|| {
+		init_with(&[1]);
+		run_to(5);
+		assert_ok!(Salary::bump(Origin::signed(1)));
+		assert_noop!(Salary::payout(Origin::signed(1)), Error::<Test>::TooEarly);
+
+		run_to(7);
+		assert_ok!(Salary::payout(Origin::signed(1)));
+		System::assert_last_event(Event::Salary(crate::Event::Paid(1, 1, 10, 0)));
+		assert_eq!(paid(1), 10);
+		assert_eq!(Status::<Test>::get().unwrap().total_unregistered_paid, 10);
+		assert_noop!(Salary::payout(Origin::signed(1)), Error::<Test>::NoClaim);
+
+		// Only the member which has been inducted may claim.
+		assert_noop!(Salary::payout(Origin::signed(2)), Error::<Test>::NotInducted);
+	});
+}
+
+#[test]
+fn registered_payment_works() {
+	new_test_ext().execute_with(|| {
+		init_with(&[1]);
+		run_to(5);
+		assert_ok!(Salary::bump(Origin::signed(1)));
+		assert_ok!(Salary::register(Origin::signed(1)));
+		System::assert_last_event(Event::Salary(crate::Event::Registered(1, 10)));
+		assert_eq!(
+			Claimant::<Test>::get(1),
+			Some(ClaimantStatus { last_active: 1, status: Registered(10) })
+		);
+		assert_noop!(Salary::register(Origin::signed(1)), Error::<Test>::NoClaim);
+
+		run_to(7);
+		assert_noop!(Salary::register(Origin::signed(1)), Error::<Test>::TooLate);
+		assert_ok!(Salary::payout(Origin::signed(1)));
+		assert_eq!(paid(1), 10);
+		assert_eq!(
+			Claimant::<Test>::get(1),
+			Some(ClaimantStatus {
+				last_active: 1,
+				status: Attempted { registered: Some(10), id: 0, amount: 10 },
+			})
+		);
+		assert_noop!(Salary::payout(Origin::signed(1)), Error::<Test>::NoClaim);
+	});
+}
+
+#[test]
+fn payout_other_works() {
+	new_test_ext().execute_with(|| {
+		init_with(&[1]);
+		run_to(5);
+		assert_ok!(Salary::bump(Origin::signed(1)));
+		run_to(7);
+		assert_ok!(Salary::payout_other(Origin::signed(1), 2));
+		System::assert_last_event(Event::Salary(crate::Event::Paid(1, 2, 10, 0)));
+		assert_eq!(paid(1), 0);
+		assert_eq!(paid(2), 10);
+	});
+}
+
+#[test]
+fn zero_claims_fail() {
+	new_test_ext().execute_with(|| {
+		init_with(&[1]);
+		set_rank(1, 0);
+		run_to(5);
+		assert_ok!(Salary::bump(Origin::signed(1)));
+		assert_noop!(Salary::register(Origin::signed(1)), Error::<Test>::ClaimZero);
+		run_to(7);
+		assert_noop!(Salary::payout(Origin::signed(1)), Error::<Test>::ClaimZero);
+	});
+}
+
+#[test]
+fn non_members_cannot_claim() {
+	new_test_ext().execute_with(|| {
+		init_with(&[1]);
+		assert_ok!(TestClub::demote(&1));
+		assert_ok!(TestClub::demote(&1));
+		run_to(5);
+		assert_ok!(Salary::bump(Origin::signed(1)));
+		assert_noop!(Salary::register(Origin::signed(1)), Error::<Test>::NotMember);
+		run_to(7);
+		assert_noop!(Salary::payout(Origin::signed(1)), Error::<Test>::NotMember);
+	});
+}
+
+#[test]
+fn registrations_beyond_budget_are_paid_pro_rata() {
+	new_test_ext().execute_with(|| {
+		init_with(&[1, 2]);
+		set_rank(2, 2);
+		Budget::set(15);
+		run_to(5);
+		assert_ok!(Salary::bump(Origin::signed(1)));
+		assert_ok!(Salary::register(Origin::signed(1)));
+		assert_ok!(Salary::register(Origin::signed(2)));
+		assert_eq!(Status::<Test>::get().unwrap().total_registrations, 30);
+
+		run_to(7);
+		assert_ok!(Salary::payout(Origin::signed(1)));
+		assert_ok!(Salary::payout(Origin::signed(2)));
+		assert_eq!(paid(1), 5);
+		assert_eq!(paid(2), 10);
+	});
+}
+
+#[test]
+fn unregistered_payments_come_from_what_is_left() {
+	new_test_ext().execute_with(|| {
+		init_with(&[1, 2, 3]);
+		set_rank(2, 2);
+		Budget::set(25);
+		run_to(5);
+		assert_ok!(Salary::bump(Origin::signed(1)));
+		assert_ok!(Salary::register(Origin::signed(1)));
+
+		run_to(7);
+		// Registered claims are guaranteed even if unregistered claims are made first.
+		assert_ok!(Salary::payout(Origin::signed(2)));
+		assert_eq!(paid(2), 15);
+		assert_noop!(Salary::payout(Origin::signed(3)), Error::<Test>::ClaimZero);
+		assert_ok!(Salary::payout(Origin::signed(1)));
+		assert_eq!(paid(1), 10);
+	});
+}
+
+#[test]
+fn check_payment_works() {
+	new_test_ext().execute_with(|| {
+		init_with(&[1]);
+		assert_noop!(Salary::check_payment(Origin::signed(1)), Error::<Test>::NoClaim);
+		run_to(5);
+		assert_ok!(Salary::bump(Origin::signed(1)));
+		run_to(7);
+		assert_ok!(Salary::payout(Origin::signed(1)));
+
+		set_status(0, PaymentStatus::InProgress);
+		assert_noop!(Salary::check_payment(Origin::signed(1)), Error::<Test>::Inconclusive);
+		set_status(0, PaymentStatus::Success);
+		assert_ok!(Salary::check_payment(Origin::signed(1)));
+		assert_eq!(
+			Claimant::<Test>::get(1),
+			Some(ClaimantStatus { last_active: 1, status: Nothing })
+		);
+		assert_noop!(Salary::payout(Origin::signed(1)), Error::<Test>::NoClaim);
+
+		run_to(9);
+		assert_ok!(Salary::bump(Origin::signed(1)));
+		assert_noop!(Salary::check_payment(Origin::signed(1)), Error::<Test>::NotCurrent);
+	});
+}
+
+#[test]
+fn failed_registered_payment_can_be_retried() {
+	new_test_ext().execute_with(|| {
+		init_with(&[1]);
+		run_to(5);
+		assert_ok!(Salary::bump(Origin::signed(1)));
+		assert_ok!(Salary::register(Origin::signed(1)));
+		run_to(7);
+		assert_ok!(Salary::payout(Origin::signed(1)));
+
+		unpay(1, 10);
+		set_status(0, PaymentStatus::Failure);
+		assert_ok!(Salary::check_payment(Origin::signed(1)));
+		assert_eq!(
+			Claimant::<Test>::get(1),
+			Some(ClaimantStatus { last_active: 1, status: Registered(10) })
+		);
+
+		assert_ok!(Salary::payout(Origin::signed(1)));
+		assert_eq!(paid(1), 10);
+	});
+}
+
+#[test]
+fn failed_unregistered_payment_can_be_retried() {
+	new_test_ext().execute_with(|| {
+		init_with(&[1]);
+		run_to(5);
+		assert_ok!(Salary::bump(Origin::signed(1)));
+		run_to(7);
+		assert_ok!(Salary::payout(Origin::signed(1)));
+
+		unpay(1, 10);
+		set_status(0, PaymentStatus::Failure);
+		assert_ok!(Salary::check_payment(Origin::signed(1)));
+		assert_eq!(
+			Claimant::<Test>::get(1),
+			Some(ClaimantStatus { last_active: 0, status: Nothing })
+		);
+		assert_eq!(Status::<Test>::get().unwrap().total_unregistered_paid, 0);
+
+		assert_ok!(Salary::payout(Origin::signed(1)));
+		assert_eq!(paid(1), 10);
+	});
+}
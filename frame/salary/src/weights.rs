@@ -0,0 +1,170 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Autogenerated weights for pallet_salary
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2021-10-30, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 128
+
+// Executed Command:
+// target/release/substrate
+// benchmark
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_salary
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./frame/salary/src/weights.rs
+// --template=./.maintain/frame-weight-template.hbs
+
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_salary.
+pub trait WeightInfo {
+	fn init() -> Weight;
+	fn bump() -> Weight;
+	fn induct() -> Weight;
+	fn register() -> Weight;
+	fn payout() -> Weight;
+	fn payout_other() -> Weight;
+	fn check_payment() -> Weight;
+}
+
+/// Weights for pallet_salary using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: Salary Status (r:1 w:1)
+	fn init() -> Weight {
+		(10_778_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Salary Status (r:1 w:1)
+	fn bump() -> Weight {
+		(12_096_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Salary Status (r:1 w:0)
+	// Storage: RankedCollective Members (r:1 w:0)
+	// Storage: Salary Claimant (r:1 w:1)
+	fn induct() -> Weight {
+		(18_401_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: RankedCollective Members (r:1 w:0)
+	// Storage: Salary Status (r:1 w:1)
+	// Storage: Salary Claimant (r:1 w:1)
+	fn register() -> Weight {
+		(22_537_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Salary Status (r:1 w:1)
+	// Storage: Salary Claimant (r:1 w:1)
+	// Storage: RankedCollective Members (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	fn payout() -> Weight {
+		(51_920_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Salary Status (r:1 w:1)
+	// Storage: Salary Claimant (r:1 w:1)
+	// Storage: RankedCollective Members (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	fn payout_other() -> Weight {
+		(52_714_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Salary Status (r:1 w:1)
+	// Storage: Salary Claimant (r:1 w:1)
+	fn check_payment() -> Weight {
+		(13_215_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: Salary Status (r:1 w:1)
+	fn init() -> Weight {
+		(10_778_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Salary Status (r:1 w:1)
+	fn bump() -> Weight {
+		(12_096_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Salary Status (r:1 w:0)
+	// Storage: RankedCollective Members (r:1 w:0)
+	// Storage: Salary Claimant (r:1 w:1)
+	fn induct() -> Weight {
+		(18_401_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: RankedCollective Members (r:1 w:0)
+	// Storage: Salary Status (r:1 w:1)
+	// Storage: Salary Claimant (r:1 w:1)
+	fn register() -> Weight {
+		(22_537_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Salary Status (r:1 w:1)
+	// Storage: Salary Claimant (r:1 w:1)
+	// Storage: RankedCollective Members (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	fn payout() -> Weight {
+		(51_920_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Salary Status (r:1 w:1)
+	// Storage: Salary Claimant (r:1 w:1)
+	// Storage: RankedCollective Members (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	fn payout_other() -> Weight {
+		(52_714_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Salary Status (r:1 w:1)
+	// Storage: Salary Claimant (r:1 w:1)
+	fn check_payment() -> Weight {
+		(13_215_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+}
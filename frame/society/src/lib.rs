@@ -137,7 +137,8 @@
 //!
 //! These selected bids become candidates and move on to the Candidate phase.
 //! Bids that were not selected stay in the bidder pool until they are selected or
-//! a user chooses to "unbid".
+//! a user chooses to "unbid". At most `MaxBids` bids are kept; should a new bid exceed this,
+//! the largest bid is dropped.
 //!
 //! #### Candidate Phase
 //!
@@ -171,7 +172,9 @@
 //! These slashed funds are given to a random user who voted the same as the
 //! selected vote as a reward for participating in the vote.
 //!
-//! If the candidate wins the vote, they receive their bid reward as a future payout.
+//! If the candidate wins the vote, they receive their bid reward as a future payout. Should the
+//! society already hold `MaxMembersCount` members, the candidate is instead placed back into the
+//! bidder pool.
 //! If the bid was placed by a voucher, they will receive their portion of the reward,
 //! before the rest is paid to the winning candidate.
 //!
@@ -189,7 +192,9 @@
 //!
 //! Regular participation involves voting on candidates who want to join the membership
 //! society, and by voting in the right way, a member will accumulate future payouts.
-//! When a payout matures, members are able to claim those payouts.
+//! When a payout matures, members are able to claim those payouts. A member has at most
+//! `MaxPayouts` payouts pending; any further payout is merged into a pending one which matures
+//! no earlier than the new payout would have.
 //!
 //! Members can also vouch for users to join the society, and request a "tip" from
 //! the fees the new member would collect by joining the society. This vouching
@@ -240,7 +245,7 @@
 //! * `judge_suspended_candidate` - The suspension judgement origin is able to
 //! make judgement on a suspended candidate.
 //! * `set_max_membership` - The ROOT origin can update the maximum member count for the society.
-//! The max membership count must be greater than 1.
+//! The max membership count must be greater than 1 and no greater than `MaxMembersCount`.
 
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -251,11 +256,13 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+pub mod migrations;
+
 use frame_support::{
 	pallet_prelude::*,
 	traits::{
 		BalanceStatus, ChangeMembers, Currency, EnsureOrigin, ExistenceRequirement::AllowDeath,
-		Imbalance, OnUnbalanced, Randomness, ReservableCurrency,
+		Imbalance, OnUnbalanced, Randomness, ReservableCurrency, StorageVersion,
 	},
 	PalletId,
 };
@@ -272,10 +279,13 @@ use sp_runtime::{
 	},
 	Percent, RuntimeDebug,
 };
-use sp_std::prelude::*;
+use sp_std::{convert::TryFrom, prelude::*};
 
 pub use pallet::*;
 
+/// The current storage version.
+const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 type BalanceOf<T, I> =
 	<<T as Config<I>>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 type NegativeImbalanceOf<T, I> = <<T as Config<I>>::Currency as Currency<
@@ -371,6 +381,7 @@ pub mod pallet {
 
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T, I = ()>(_);
 
 	#[pallet::config]
@@ -430,6 +441,20 @@ pub mod pallet {
 		/// The maximum number of candidates that we accept per round.
 		#[pallet::constant]
 		type MaxCandidateIntake: Get<u32>;
+
+		/// The maximum number of members the society can ever hold. The `MaxMembers` set by
+		/// `found` and `set_max_members` can be no greater than this.
+		#[pallet::constant]
+		type MaxMembersCount: Get<u32>;
+
+		/// The maximum number of bids kept at once. Should a new bid exceed this, the bid with
+		/// the largest value is dropped.
+		#[pallet::constant]
+		type MaxBids: Get<u32>;
+
+		/// The maximum number of pending payouts of any one member.
+		#[pallet::constant]
+		type MaxPayouts: Get<u32>;
 	}
 
 	#[pallet::error]
@@ -531,8 +556,11 @@ pub mod pallet {
 	/// The current set of candidates; bidders that are attempting to become members.
 	#[pallet::storage]
 	#[pallet::getter(fn candidates)]
-	pub type Candidates<T: Config<I>, I: 'static = ()> =
-		StorageValue<_, Vec<Bid<T::AccountId, BalanceOf<T, I>>>, ValueQuery>;
+	pub type Candidates<T: Config<I>, I: 'static = ()> = StorageValue<
+		_,
+		BoundedVec<Bid<T::AccountId, BalanceOf<T, I>>, T::MaxCandidateIntake>,
+		ValueQuery,
+	>;
 
 	/// The set of suspended candidates.
 	#[pallet::storage]
@@ -558,7 +586,7 @@ pub mod pallet {
 	#[pallet::storage]
 	#[pallet::getter(fn members)]
 	pub type Members<T: Config<I>, I: 'static = ()> =
-		StorageValue<_, Vec<T::AccountId>, ValueQuery>;
+		StorageValue<_, BoundedVec<T::AccountId, T::MaxMembersCount>, ValueQuery>;
 
 	/// The set of suspended members.
 	#[pallet::storage]
@@ -569,7 +597,7 @@ pub mod pallet {
 	/// The current bids, stored ordered by the value of the bid.
 	#[pallet::storage]
 	pub(super) type Bids<T: Config<I>, I: 'static = ()> =
-		StorageValue<_, Vec<Bid<T::AccountId, BalanceOf<T, I>>>, ValueQuery>;
+		StorageValue<_, BoundedVec<Bid<T::AccountId, BalanceOf<T, I>>, T::MaxBids>, ValueQuery>;
 
 	/// Members currently vouching or banned from vouching again
 	#[pallet::storage]
@@ -583,7 +611,7 @@ pub mod pallet {
 		_,
 		Twox64Concat,
 		T::AccountId,
-		Vec<(T::BlockNumber, BalanceOf<T, I>)>,
+		BoundedVec<(T::BlockNumber, BalanceOf<T, I>), T::MaxPayouts>,
 		ValueQuery,
 	>;
 
@@ -622,7 +650,7 @@ pub mod pallet {
 
 			// Run a candidate/membership rotation
 			if (n % T::RotationPeriod::get()).is_zero() {
-				members = <Members<T, I>>::get();
+				members = <Members<T, I>>::get().into_inner();
 				Self::rotate_period(&mut members);
 
 				weight += weights.max_block / 20;
@@ -632,7 +660,7 @@ pub mod pallet {
 			if (n % T::ChallengePeriod::get()).is_zero() {
 				// Only read members if not already read.
 				if members.is_empty() {
-					members = <Members<T, I>>::get();
+					members = <Members<T, I>>::get().into_inner();
 				}
 				Self::rotate_challenge(&mut members);
 
@@ -641,6 +669,15 @@ pub mod pallet {
 
 			weight
 		}
+
+		fn on_runtime_upgrade() -> Weight {
+			migrations::migrate_to_v1::<T, I>()
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			migrations::post_migrate_to_v1::<T, I>()
+		}
 	}
 
 	#[pallet::genesis_config]
@@ -664,6 +701,10 @@ pub mod pallet {
 	#[pallet::genesis_build]
 	impl<T: Config<I>, I: 'static> GenesisBuild<T, I> for GenesisConfig<T, I> {
 		fn build(&self) {
+			assert!(
+				self.max_members <= T::MaxMembersCount::get(),
+				"max_members may not exceed MaxMembersCount"
+			);
 			Pot::<T, I>::put(self.pot);
 			MaxMembers::<T, I>::put(self.max_members);
 			let first_member = self.members.first();
@@ -673,6 +714,8 @@ pub mod pallet {
 			};
 			let mut m = self.members.clone();
 			m.sort();
+			let m = BoundedVec::<_, T::MaxMembersCount>::try_from(m)
+				.expect("genesis members may not exceed MaxMembersCount");
 			Members::<T, I>::put(m);
 		}
 	}
@@ -700,16 +743,16 @@ pub mod pallet {
 		/// - Storage Writes:
 		/// 	- One storage mutate to add a new bid to the vector O(B) (TODO: possible optimization
 		///    w/ read)
-		/// 	- Up to one storage removal if bid.len() > MAX_BID_COUNT. O(1)
+		/// 	- Up to one storage removal if bids.len() > MaxBids. O(1)
 		/// - Notable Computation:
 		/// 	- O(B + C + log M) search to check user is not already a part of society.
 		/// 	- O(log B) search to insert the new bid sorted.
 		/// - External Pallet Operations:
 		/// 	- One balance reserve operation. O(X)
-		/// 	- Up to one balance unreserve operation if bids.len() > MAX_BID_COUNT.
+		/// 	- Up to one balance unreserve operation if bids.len() > MaxBids.
 		/// - Events:
 		/// 	- One event for new bid.
-		/// 	- Up to one event for AutoUnbid if bid.len() > MAX_BID_COUNT.
+		/// 	- Up to one event for AutoUnbid if bids.len() > MaxBids.
 		///
 		/// Total Complexity: O(M + B + C + logM + logB + X)
 		/// # </weight>
@@ -728,7 +771,7 @@ pub mod pallet {
 			let deposit = T::CandidateDeposit::get();
 			T::Currency::reserve(&who, deposit)?;
 
-			Self::put_bid(bids, &who, value.clone(), BidKind::Deposit(deposit));
+			Self::put_bid(bids.into_inner(), &who, value.clone(), BidKind::Deposit(deposit));
 			Self::deposit_event(Event::<T, I>::Bid(who, value));
 			Ok(())
 		}
@@ -810,17 +853,17 @@ pub mod pallet {
 		/// 	- One storage write to insert vouching status to the member. O(1)
 		/// 	- One storage mutate to add a new bid to the vector O(B) (TODO: possible optimization
 		///    w/ read)
-		/// 	- Up to one storage removal if bid.len() > MAX_BID_COUNT. O(1)
+		/// 	- Up to one storage removal if bids.len() > MaxBids. O(1)
 		/// - Notable Computation:
 		/// 	- O(log M) search to check sender is a member.
 		/// 	- O(B + C + log M) search to check user is not already a part of society.
 		/// 	- O(log B) search to insert the new bid sorted.
 		/// - External Pallet Operations:
 		/// 	- One balance reserve operation. O(X)
-		/// 	- Up to one balance unreserve operation if bids.len() > MAX_BID_COUNT.
+		/// 	- Up to one balance unreserve operation if bids.len() > MaxBids.
 		/// - Events:
 		/// 	- One event for vouch.
-		/// 	- Up to one event for AutoUnbid if bid.len() > MAX_BID_COUNT.
+		/// 	- Up to one event for AutoUnbid if bids.len() > MaxBids.
 		///
 		/// Total Complexity: O(M + B + C + logM + logB + X)
 		/// # </weight>
@@ -848,7 +891,12 @@ pub mod pallet {
 			ensure!(!<Vouching<T, I>>::contains_key(&voucher), Error::<T, I>::AlreadyVouching);
 
 			<Vouching<T, I>>::insert(&voucher, VouchingStatus::Vouching);
-			Self::put_bid(bids, &who, value.clone(), BidKind::Vouch(voucher.clone(), tip));
+			Self::put_bid(
+				bids.into_inner(),
+				&who,
+				value.clone(),
+				BidKind::Vouch(voucher.clone(), tip),
+			);
 			Self::deposit_event(Event::<T, I>::Vouch(who, value, voucher));
 			Ok(())
 		}
@@ -1013,7 +1061,8 @@ pub mod pallet {
 		///
 		/// Parameters:
 		/// - `founder` - The first member and head of the newly founded society.
-		/// - `max_members` - The initial max number of members for the society.
+		/// - `max_members` - The initial max number of members for the society. Must be greater
+		///   than 1 and no greater than `MaxMembersCount`.
 		/// - `rules` - The rules of this society concerning membership.
 		///
 		/// # <weight>
@@ -1033,6 +1082,7 @@ pub mod pallet {
 			T::FounderSetOrigin::ensure_origin(origin)?;
 			ensure!(!<Head<T, I>>::exists(), Error::<T, I>::AlreadyFounded);
 			ensure!(max_members > 1, Error::<T, I>::MaxMembers);
+			ensure!(max_members <= T::MaxMembersCount::get(), Error::<T, I>::MaxMembers);
 			// This should never fail in the context of this function...
 			<MaxMembers<T, I>>::put(max_members);
 			Self::add_member(&founder)?;
@@ -1222,7 +1272,7 @@ pub mod pallet {
 						// Founder has taken no judgement, and candidate is placed back into the
 						// pool.
 						let bids = <Bids<T, I>>::get();
-						Self::put_bid(bids, &who, value, kind);
+						Self::put_bid(bids.into_inner(), &who, value, kind);
 					},
 				}
 
@@ -1235,7 +1285,7 @@ pub mod pallet {
 		}
 
 		/// Allows root origin to change the maximum number of members in society.
-		/// Max membership count must be greater than 1.
+		/// Max membership count must be greater than 1 and no greater than `MaxMembersCount`.
 		///
 		/// The dispatch origin for this call must be from _ROOT_.
 		///
@@ -1252,6 +1302,7 @@ pub mod pallet {
 		pub fn set_max_members(origin: OriginFor<T>, max: u32) -> DispatchResult {
 			ensure_root(origin)?;
 			ensure!(max > 1, Error::<T, I>::MaxMembers);
+			ensure!(max <= T::MaxMembersCount::get(), Error::<T, I>::MaxMembers);
 			MaxMembers::<T, I>::put(max);
 			Self::deposit_event(Event::<T, I>::NewMaxMembers(max));
 			Ok(())
@@ -1293,15 +1344,13 @@ fn pick_usize<'a, R: RngCore>(rng: &mut R, max: usize) -> usize {
 
 impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	/// Puts a bid into storage ordered by smallest to largest value.
-	/// Allows a maximum of 1000 bids in queue, removing largest value people first.
+	/// Allows a maximum of `MaxBids` bids in queue, removing largest value people first.
 	fn put_bid(
 		mut bids: Vec<Bid<T::AccountId, BalanceOf<T, I>>>,
 		who: &T::AccountId,
 		value: BalanceOf<T, I>,
 		bid_kind: BidKind<T::AccountId, BalanceOf<T, I>>,
 	) {
-		const MAX_BID_COUNT: usize = 1000;
-
 		match bids.binary_search_by(|bid| bid.value.cmp(&value)) {
 			// Insert new elements after the existing ones. This ensures new bids
 			// with the same bid value are further down the list than existing ones.
@@ -1328,8 +1377,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			Err(pos) => bids.insert(pos, Bid { value, who: who.clone(), kind: bid_kind }),
 		}
 		// Keep it reasonably small.
-		if bids.len() > MAX_BID_COUNT {
-			let Bid { who: popped, kind, .. } = bids.pop().expect("b.len() > 1000; qed");
+		while bids.len() > T::MaxBids::get() as usize {
+			let Bid { who: popped, kind, .. } = bids.pop().expect("b.len() > MaxBids; qed");
 			match kind {
 				BidKind::Deposit(deposit) => {
 					let err_amount = T::Currency::unreserve(&popped, deposit);
@@ -1342,6 +1391,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			Self::deposit_event(Event::<T, I>::AutoUnbid(popped));
 		}
 
+		let bids = BoundedVec::<_, T::MaxBids>::try_from(bids)
+			.expect("bids were truncated to MaxBids above; qed");
 		<Bids<T, I>>::put(bids);
 	}
 
@@ -1373,7 +1424,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		match members.binary_search(who) {
 			// Add the new member
 			Err(i) => {
-				members.insert(i, who.clone());
+				members.try_insert(i, who.clone()).map_err(|_| Error::<T, I>::MaxMembers)?;
 				T::MembershipChanged::change_members_sorted(&[who.clone()], &[], &members);
 				<Members<T, I>>::put(members);
 				Ok(())
@@ -1423,7 +1474,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			let candidates = <Candidates<T, I>>::take();
 			// NOTE: This may cause member length to surpass `MaxMembers`, but results in no
 			// consensus critical issues or side-effects. This is auto-correcting as members fall
-			// out of society.
+			// out of society. It may never surpass `MaxMembersCount`, however.
 			members.reserve(candidates.len());
 
 			let maturity = <frame_system::Pallet<T>>::block_number() +
@@ -1437,6 +1488,13 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			let accepted = candidates
 				.into_iter()
 				.filter_map(|Bid { value, who: candidate, kind }| {
+					// There is no room for another member; place the candidate back into the
+					// pool of bids.
+					if members.len() >= T::MaxMembersCount::get() as usize {
+						Self::put_bid(<Bids<T, I>>::get().into_inner(), &candidate, value, kind);
+						return None
+					}
+
 					let mut approval_count = 0;
 
 					// Creates a vector of (vote, member) for the given candidate
@@ -1569,7 +1627,10 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				// NOTE: This may cause member length to surpass `MaxMembers`, but results in no
 				// consensus critical issues or side-effects. This is auto-correcting as members
 				// fall out of society.
-				<Members<T, I>>::put(&members[..]);
+				let bounded_members =
+					BoundedVec::<_, T::MaxMembersCount>::try_from(members.clone())
+						.expect("candidates are only accepted while below MaxMembersCount; qed");
+				<Members<T, I>>::put(bounded_members);
 				<Head<T, I>>::put(&primary);
 
 				T::MembershipChanged::change_members_sorted(&accounts, &[], &members);
@@ -1601,7 +1662,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	/// Attempt to slash the payout of some member. Return the total amount that was deducted.
 	fn slash_payout(who: &T::AccountId, value: BalanceOf<T, I>) -> BalanceOf<T, I> {
 		let mut rest = value;
-		let mut payouts = <Payouts<T, I>>::get(who);
+		let mut payouts = <Payouts<T, I>>::get(who).into_inner();
 		if !payouts.is_empty() {
 			let mut dropped = 0;
 			for (_, amount) in payouts.iter_mut() {
@@ -1616,18 +1677,31 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 					break
 				}
 			}
-			<Payouts<T, I>>::insert(who, &payouts[dropped..]);
+			let payouts = BoundedVec::<_, T::MaxPayouts>::try_from(payouts.split_off(dropped))
+				.expect("remaining payouts are a subset of bounded payouts; qed");
+			<Payouts<T, I>>::insert(who, payouts);
 		}
 		value - rest
 	}
 
 	/// Bump the payout amount of `who`, to be unlocked at the given block number.
+	///
+	/// If `who` already has `MaxPayouts` payouts pending, `value` is added to the first payout
+	/// maturing after `when`. If there is none, it is added to the last payout, whose maturity
+	/// is postponed to `when`. Either way, `value` is never unlocked earlier than requested.
 	fn bump_payout(who: &T::AccountId, when: T::BlockNumber, value: BalanceOf<T, I>) {
 		if !value.is_zero() {
 			<Payouts<T, I>>::mutate(who, |payouts| {
 				match payouts.binary_search_by_key(&when, |x| x.0) {
 					Ok(index) => payouts[index].1 += value,
-					Err(index) => payouts.insert(index, (when, value)),
+					Err(index) =>
+						if payouts.try_insert(index, (when, value)).is_err() {
+							if let Some(next) = payouts.get_mut(index) {
+								next.1 += value;
+							} else if let Some(last) = payouts.len().checked_sub(1) {
+								payouts[last] = (when, payouts[last].1 + value);
+							}
+						},
 				}
 			});
 		}
@@ -1693,7 +1767,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				if approval_count <= rejection_count {
 					// User has failed the challenge
 					Self::suspend_member(&defender);
-					*members = Self::members();
+					*members = Self::members().into_inner();
 				}
 
 				// Clean up all votes.
@@ -1755,7 +1829,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	pub fn take_selected(
 		members_len: usize,
 		pot: BalanceOf<T, I>,
-	) -> Vec<Bid<T::AccountId, BalanceOf<T, I>>> {
+	) -> BoundedVec<Bid<T::AccountId, BalanceOf<T, I>>, T::MaxCandidateIntake> {
 		let max_members = MaxMembers::<T, I>::get() as usize;
 		let mut max_selections: usize =
 			(T::MaxCandidateIntake::get() as usize).min(max_members.saturating_sub(members_len));
@@ -1806,9 +1880,10 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 					<Bids<T, I>>::put(bids);
 				}
 			}
-			selected
+			BoundedVec::try_from(selected)
+				.expect("no more than MaxCandidateIntake bids are selected; qed")
 		} else {
-			vec![]
+			Default::default()
 		}
 	}
 }
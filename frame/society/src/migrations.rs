@@ -0,0 +1,137 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Storage migrations for the society pallet.
+
+use super::*;
+use frame_support::traits::{GetStorageVersion, StorageVersion};
+
+type BidOf<T, I> = Bid<<T as frame_system::Config>::AccountId, BalanceOf<T, I>>;
+
+/// Bound the members, candidates, bids and payouts of a society which was founded before they were
+/// bounded, and set the storage version to 1.
+///
+/// - Members over `MaxMembersCount` are suspended, the founder and the head last, so they can be
+///   judged by the founder. `MaxMembers` is lowered to `MaxMembersCount`.
+/// - Candidates over `MaxCandidateIntake` are put back into the bid pool.
+/// - Bids over `MaxBids` are dropped as if outbid by the others.
+/// - Payouts of a member over `MaxPayouts` are merged into a single one, unlocked with the last of
+///   them.
+pub fn migrate_to_v1<T: Config<I>, I: 'static>() -> Weight {
+	if Pallet::<T, I>::on_chain_storage_version() >= 1 {
+		log::info!(target: "runtime::society", "storage already migrated, skipping.");
+		return T::DbWeight::get().reads(1)
+	}
+
+	let mut reads = 7;
+	let mut writes = 5;
+
+	let max_members = T::MaxMembersCount::get();
+	MaxMembers::<T, I>::mutate(|max| *max = (*max).min(max_members));
+
+	let founder = Founder::<T, I>::get();
+	let head = Head::<T, I>::get();
+	let mut suspended = Vec::new();
+	let _ = Members::<T, I>::translate::<Vec<T::AccountId>, _>(|members| {
+		let members = members?;
+		let is_spared =
+			|who: &T::AccountId| Some(who) == founder.as_ref() || Some(who) == head.as_ref();
+		let (mut kept, others): (Vec<_>, Vec<_>) = members.into_iter().partition(is_spared);
+		kept.extend(others);
+		suspended = kept.split_off((max_members as usize).min(kept.len()));
+		kept.sort();
+		Some(BoundedVec::try_from(kept).expect("kept members were truncated to the bound; qed"))
+	});
+	if !suspended.is_empty() {
+		log::warn!(
+			target: "runtime::society",
+			"suspending {} members over `MaxMembersCount`.",
+			suspended.len(),
+		);
+		suspended.sort();
+		for who in &suspended {
+			SuspendedMembers::<T, I>::insert(who, true);
+			Strikes::<T, I>::remove(who);
+			Pallet::<T, I>::deposit_event(Event::<T, I>::MemberSuspended(who.clone()));
+		}
+		T::MembershipChanged::change_members_sorted(&[], &suspended, &Members::<T, I>::get());
+		writes += 2 * suspended.len() as u64;
+	}
+
+	let mut requeued = Vec::new();
+	let _ = Candidates::<T, I>::translate::<Vec<BidOf<T, I>>, _>(|candidates| {
+		let mut candidates = candidates?;
+		requeued =
+			candidates.split_off((T::MaxCandidateIntake::get() as usize).min(candidates.len()));
+		Some(BoundedVec::try_from(candidates).expect("candidates were truncated to the bound; qed"))
+	});
+
+	let mut bids = Vec::new();
+	let _ = Bids::<T, I>::translate::<Vec<BidOf<T, I>>, _>(|old| {
+		bids = old.unwrap_or_default();
+		None
+	});
+	// `put_bid` drops the bids over the bound, so put the highest one back through it.
+	bids.extend(requeued);
+	bids.sort_by_key(|bid| bid.value);
+	if let Some(Bid { who, value, kind }) = bids.pop() {
+		Pallet::<T, I>::put_bid(bids, &who, value, kind);
+	}
+
+	let max_payouts = T::MaxPayouts::get() as usize;
+	Payouts::<T, I>::translate::<Vec<(T::BlockNumber, BalanceOf<T, I>)>, _>(|who, mut payouts| {
+		reads += 1;
+		writes += 1;
+		if payouts.len() > max_payouts {
+			let merged = payouts.split_off(max_payouts.saturating_sub(1));
+			let when = merged.last().map(|(when, _)| *when).expect("merged is not empty; qed");
+			let value =
+				merged.into_iter().fold(Zero::zero(), |total: BalanceOf<T, I>, (_, value)| {
+					total.saturating_add(value)
+				});
+			payouts.push((when, value));
+		}
+		let payouts = BoundedVec::try_from(payouts).ok();
+		if payouts.is_none() {
+			log::warn!(target: "runtime::society", "dropping payouts of {:?}.", who);
+		}
+		payouts
+	});
+
+	StorageVersion::new(1).put::<Pallet<T, I>>();
+	T::DbWeight::get().reads_writes(reads, writes)
+}
+
+/// Check that the storage is migrated and within its bounds.
+#[cfg(feature = "try-runtime")]
+pub fn post_migrate_to_v1<T: Config<I>, I: 'static>() -> Result<(), &'static str> {
+	ensure!(Pallet::<T, I>::on_chain_storage_version() == 1, "storage version not updated");
+	ensure!(
+		MaxMembers::<T, I>::get() <= T::MaxMembersCount::get(),
+		"`MaxMembers` over `MaxMembersCount`"
+	);
+	ensure!(
+		!Members::<T, I>::exists() || Members::<T, I>::try_get().is_ok(),
+		"members not bounded"
+	);
+	ensure!(
+		!Candidates::<T, I>::exists() || Candidates::<T, I>::try_get().is_ok(),
+		"candidates not bounded"
+	);
+	ensure!(!Bids::<T, I>::exists() || Bids::<T, I>::try_get().is_ok(), "bids not bounded");
+	Ok(())
+}
@@ -55,6 +55,9 @@ parameter_types! {
 	pub const BlockHashCount: u64 = 250;
 	pub const ExistentialDeposit: u64 = 1;
 	pub const MaxCandidateIntake: u32 = 10;
+	pub const MaxMembersCount: u32 = 200;
+	pub const MaxBids: u32 = 1000;
+	pub const MaxPayouts: u32 = 4;
 	pub const SocietyPalletId: PalletId = PalletId(*b"py/socie");
 	pub BlockWeights: frame_system::limits::BlockWeights =
 		frame_system::limits::BlockWeights::simple_max(1024);
@@ -118,6 +121,9 @@ impl Config for Test {
 	type SuspensionJudgementOrigin = EnsureSignedBy<SuspensionJudgementSetAccount, u128>;
 	type ChallengePeriod = ChallengePeriod;
 	type MaxCandidateIntake = MaxCandidateIntake;
+	type MaxMembersCount = MaxMembersCount;
+	type MaxBids = MaxBids;
+	type MaxPayouts = MaxPayouts;
 	type PalletId = SocietyPalletId;
 }
 
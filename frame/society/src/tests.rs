@@ -147,7 +147,7 @@ fn bidding_works() {
 		// No more candidates satisfy the requirements
 		assert_eq!(Society::candidates(), vec![]);
 		assert_ok!(Society::defender_vote(Origin::signed(10), true)); // Keep defender around
-															  // Next period
+																// Next period
 		run_to_block(16);
 		// Same members
 		assert_eq!(Society::members(), vec![10, 30, 40, 50]);
@@ -285,6 +285,26 @@ fn slash_payout_multi_works() {
 	});
 }
 
+#[test]
+fn bump_payout_merges_when_full() {
+	EnvBuilder::new().execute(|| {
+		// fill up the payouts, max 4
+		Society::bump_payout(&20, 5, 100);
+		Society::bump_payout(&20, 10, 100);
+		Society::bump_payout(&20, 15, 100);
+		Society::bump_payout(&20, 20, 100);
+		// a payout maturing in between is added to the next one to mature
+		Society::bump_payout(&20, 12, 50);
+		assert_eq!(Payouts::<Test>::get(20), vec![(5, 100), (10, 100), (15, 150), (20, 100)]);
+		// a payout maturing after all others postpones the last one
+		Society::bump_payout(&20, 25, 50);
+		assert_eq!(Payouts::<Test>::get(20), vec![(5, 100), (10, 100), (15, 150), (25, 150)]);
+		// a payout maturing at the same time is simply added
+		Society::bump_payout(&20, 5, 50);
+		assert_eq!(Payouts::<Test>::get(20), vec![(5, 150), (10, 100), (15, 150), (25, 150)]);
+	});
+}
+
 #[test]
 fn suspended_member_life_cycle_works() {
 	EnvBuilder::new().execute(|| {
@@ -840,6 +860,47 @@ fn max_limits_work() {
 	});
 }
 
+#[test]
+fn max_members_count_is_a_hard_limit() {
+	EnvBuilder::new().with_pot(100000).with_max_members(200).execute(|| {
+		// `MaxMembers` may not exceed `MaxMembersCount`
+		assert_noop!(Society::set_max_members(Origin::root(), 201), Error::<Test, _>::MaxMembers);
+		for i in 100..110 {
+			let _ = Balances::make_free_balance_be(&(i as u128), 1000);
+			assert_ok!(Society::bid(Origin::signed(i as u128), i));
+		}
+		// Fill up membership to 196
+		for i in 2000..2195 {
+			assert_ok!(Society::add_member(&(i as u128)));
+		}
+		assert_eq!(Society::members().len(), 196);
+		// Rotate period
+		run_to_block(4);
+		// Only 4 candidates possible
+		assert_eq!(Society::candidates().len(), 4);
+		// Members are filled up to `MaxMembersCount` before the candidates are judged
+		for i in 2195..2199 {
+			assert_ok!(Society::add_member(&(i as u128)));
+		}
+		assert_eq!(Society::members().len(), 200);
+		for i in 100..104 {
+			assert_ok!(Society::vote(Origin::signed(10), i, true));
+		}
+		// Rotate period
+		run_to_block(8);
+		// None of the candidates could be accepted; they are bidding again
+		assert_eq!(Society::members().len(), 200);
+		assert_eq!(Society::candidates().len(), 0);
+		let bids = <Bids<Test>>::get();
+		assert_eq!(bids.len(), 10);
+		for i in 100..104 {
+			assert!(bids.iter().any(|b| b.who == i));
+			assert_eq!(<SuspendedCandidates<Test>>::get(i), None);
+			assert_eq!(Balances::reserved_balance(i), 25);
+		}
+	});
+}
+
 #[test]
 fn zero_bid_works() {
 	// This tests:
@@ -907,3 +968,52 @@ fn bids_ordered_correctly() {
 		assert_eq!(<Bids<Test>>::get(), final_list);
 	});
 }
+
+#[test]
+fn migration_bounds_stored_sets() {
+	use frame_support::{
+		storage::unhashed,
+		traits::{GetStorageVersion, StorageVersion},
+	};
+
+	EnvBuilder::new().execute(|| {
+		// given a society stored before its sets were bounded
+		StorageVersion::new(0).put::<Society>();
+		Head::<Test>::put(201);
+		let members = (1..=201).collect::<Vec<u128>>();
+		unhashed::put(&Members::<Test>::hashed_key(), &members);
+		let candidates = (0..12u64)
+			.map(|i| create_bid(i, 300 + i as u128, BidKind::Deposit(25)))
+			.collect::<Vec<_>>();
+		unhashed::put(&Candidates::<Test>::hashed_key(), &candidates);
+		let payouts = vec![(5u64, 100u64), (10, 100), (15, 100), (20, 100), (25, 100)];
+		unhashed::put(&Payouts::<Test>::hashed_key_for(20), &payouts);
+
+		// when
+		migrations::migrate_to_v1::<Test, ()>();
+
+		// then
+		assert_eq!(Society::on_chain_storage_version(), 1);
+		// the founder and the head are spared
+		let members = Society::members();
+		assert_eq!(members.len(), 200);
+		assert!(members.contains(&10) && members.contains(&201));
+		assert_eq!(Society::suspended_member(200), true);
+		// the candidates over the intake bid again
+		assert_eq!(Society::candidates().len(), 10);
+		assert_eq!(
+			<Bids<Test>>::get(),
+			vec![
+				create_bid(10, 310, BidKind::Deposit(25)),
+				create_bid(11, 311, BidKind::Deposit(25))
+			]
+		);
+		// the last payouts are merged, without being unlocked earlier
+		assert_eq!(Payouts::<Test>::get(20), vec![(5, 100), (10, 100), (15, 100), (25, 200)]);
+
+		// the migration only runs once
+		unhashed::put(&Members::<Test>::hashed_key(), &(1..=201).collect::<Vec<u128>>());
+		migrations::migrate_to_v1::<Test, ()>();
+		assert!(Society::members().is_empty());
+	});
+}
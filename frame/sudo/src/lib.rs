@@ -35,6 +35,8 @@
 //!
 //! * `sudo` - Make a `Root` call to a dispatchable function.
 //! * `set_key` - Assign a new account to be the sudo key.
+//! * `sudo_as` - Make a `Signed` call to a dispatchable function from a given account.
+//! * `remove_key` - Permanently remove the sudo key, disabling the pallet.
 //!
 //! ## Usage
 //!
@@ -147,7 +149,7 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			// This is a public call, so we ensure that the origin is some signed account.
 			let sender = ensure_signed(origin)?;
-			ensure!(sender == Self::key(), Error::<T>::RequireSudo);
+			ensure!(Self::key().as_ref() == Some(&sender), Error::<T>::RequireSudo);
 
 			let res = call.dispatch_bypass_filter(frame_system::RawOrigin::Root.into());
 			Self::deposit_event(Event::Sudid(res.map(|_| ()).map_err(|e| e.error)));
@@ -173,7 +175,7 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			// This is a public call, so we ensure that the origin is some signed account.
 			let sender = ensure_signed(origin)?;
-			ensure!(sender == Self::key(), Error::<T>::RequireSudo);
+			ensure!(Self::key().as_ref() == Some(&sender), Error::<T>::RequireSudo);
 
 			let res = call.dispatch_bypass_filter(frame_system::RawOrigin::Root.into());
 			Self::deposit_event(Event::Sudid(res.map(|_| ()).map_err(|e| e.error)));
@@ -198,10 +200,10 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			// This is a public call, so we ensure that the origin is some signed account.
 			let sender = ensure_signed(origin)?;
-			ensure!(sender == Self::key(), Error::<T>::RequireSudo);
+			ensure!(Self::key().as_ref() == Some(&sender), Error::<T>::RequireSudo);
 			let new = T::Lookup::lookup(new)?;
 
-			Self::deposit_event(Event::KeyChanged(Self::key()));
+			Self::deposit_event(Event::KeyChanged(sender));
 			<Key<T>>::put(new);
 			// Sudo user does not pay a fee.
 			Ok(Pays::No.into())
@@ -235,7 +237,7 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			// This is a public call, so we ensure that the origin is some signed account.
 			let sender = ensure_signed(origin)?;
-			ensure!(sender == Self::key(), Error::<T>::RequireSudo);
+			ensure!(Self::key().as_ref() == Some(&sender), Error::<T>::RequireSudo);
 
 			let who = T::Lookup::lookup(who)?;
 
@@ -245,6 +247,28 @@ pub mod pallet {
 			// Sudo user does not pay a fee.
 			Ok(Pays::No.into())
 		}
+
+		/// Authenticates the current sudo key and permanently removes it. After this, none of the
+		/// calls of this pallet can be dispatched anymore.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// # <weight>
+		/// - O(1).
+		/// - Limited storage reads.
+		/// - One DB change.
+		/// # </weight>
+		#[pallet::weight(0)]
+		pub fn remove_key(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			// This is a public call, so we ensure that the origin is some signed account.
+			let sender = ensure_signed(origin)?;
+			ensure!(Self::key().as_ref() == Some(&sender), Error::<T>::RequireSudo);
+
+			<Key<T>>::kill();
+			Self::deposit_event(Event::KeyRemoved(sender));
+			// Sudo user does not pay a fee.
+			Ok(Pays::No.into())
+		}
 	}
 
 	#[pallet::event]
@@ -256,6 +280,8 @@ pub mod pallet {
 		KeyChanged(T::AccountId),
 		/// A sudo just took place. \[result\]
 		SudoAsDone(DispatchResult),
+		/// The sudo key has been removed; the old key is supplied. \[old_sudoer\]
+		KeyRemoved(T::AccountId),
 	}
 
 	#[pallet::error]
@@ -265,10 +291,10 @@ pub mod pallet {
 		RequireSudo,
 	}
 
-	/// The `AccountId` of the sudo key.
+	/// The `AccountId` of the sudo key, if it has not been removed.
 	#[pallet::storage]
 	#[pallet::getter(fn key)]
-	pub(super) type Key<T: Config> = StorageValue<_, T::AccountId, ValueQuery>;
+	pub(super) type Key<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
 
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
@@ -28,7 +28,7 @@ use mock::{
 fn test_setup_works() {
 	// Environment setup, logger storage, and sudo `key` retrieval should work as expected.
 	new_test_ext(1).execute_with(|| {
-		assert_eq!(Sudo::key(), 1u64);
+		assert_eq!(Sudo::key(), Some(1u64));
 		assert!(Logger::i32_log().is_empty());
 		assert!(Logger::account_log().is_empty());
 	});
@@ -105,7 +105,7 @@ fn set_key_basics() {
 	new_test_ext(1).execute_with(|| {
 		// A root `key` can change the root `key`
 		assert_ok!(Sudo::set_key(Origin::signed(1), 2));
-		assert_eq!(Sudo::key(), 2u64);
+		assert_eq!(Sudo::key(), Some(2u64));
 	});
 
 	new_test_ext(1).execute_with(|| {
@@ -130,6 +130,27 @@ fn set_key_emits_events_correctly() {
 	});
 }
 
+#[test]
+fn remove_key_works() {
+	new_test_ext(1).execute_with(|| {
+		// Set block number to 1 because events are not emitted on block 0.
+		System::set_block_number(1);
+
+		// A non-root `key` cannot remove the root `key`.
+		assert_noop!(Sudo::remove_key(Origin::signed(2)), Error::<Test>::RequireSudo);
+
+		assert_ok!(Sudo::remove_key(Origin::signed(1)));
+		assert_eq!(Sudo::key(), None);
+		System::assert_has_event(TestEvent::Sudo(Event::KeyRemoved(1)));
+
+		// Once removed, sudo can never be used again.
+		let call = Box::new(Call::Logger(LoggerCall::privileged_i32_log { i: 42, weight: 1_000 }));
+		assert_noop!(Sudo::sudo(Origin::signed(1), call), Error::<Test>::RequireSudo);
+		assert_noop!(Sudo::set_key(Origin::signed(1), 1), Error::<Test>::RequireSudo);
+		assert_noop!(Sudo::remove_key(Origin::signed(1)), Error::<Test>::RequireSudo);
+	});
+}
+
 #[test]
 fn sudo_as_basics() {
 	new_test_ext(1).execute_with(|| {
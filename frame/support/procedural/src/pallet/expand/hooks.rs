@@ -179,6 +179,31 @@ pub fn expand_hooks(def: &mut Def) -> proc_macro2::TokenStream {
 			}
 		}
 
+		#[cfg(feature = "try-runtime")]
+		impl<#type_impl_gen>
+			#frame_support::traits::TryState<<T as #frame_system::Config>::BlockNumber>
+			for #pallet_ident<#type_use_gen> #where_clause
+		{
+			fn try_state_checks() -> #frame_support::sp_std::vec::Vec<
+				#frame_support::traits::TryStateCheck<<T as #frame_system::Config>::BlockNumber>
+			> {
+				let pallet_name = <
+					<T as #frame_system::Config>::PalletInfo
+					as
+					#frame_support::traits::PalletInfo
+				>::name::<Self>().unwrap_or("<unknown pallet name>");
+
+				#frame_support::sp_std::vec![(
+					pallet_name,
+					<
+						Self
+						as
+						#frame_support::traits::Hooks<<T as #frame_system::Config>::BlockNumber>
+					>::try_state,
+				)]
+			}
+		}
+
 		impl<#type_impl_gen>
 			#frame_support::traits::IntegrityTest
 			for #pallet_ident<#type_use_gen> #where_clause
@@ -2153,6 +2153,13 @@ macro_rules! decl_module {
 			}
 		}
 
+		// Implement TryState for the module, `decl_module` pallets don't declare any checks.
+		#[cfg(feature = "try-runtime")]
+		impl<$trait_instance: $trait_name $(<I>, $instance: $instantiable)?>
+			$crate::traits::TryState<<$trait_instance as $system::Config>::BlockNumber>
+			for $mod_type<$trait_instance $(, $instance)?> where $( $other_where_bounds )*
+		{}
+
 		// Implement GetCallName for the Call.
 		impl<$trait_instance: $trait_name $(<I>, $instance: $instantiable)?> $crate::dispatch::GetCallName
 			for $call_type<$trait_instance $(, $instance)?> where $( $other_where_bounds )*
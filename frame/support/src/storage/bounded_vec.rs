@@ -320,6 +320,24 @@ impl<T: PartialEq, S: Get<u32>> PartialEq<Vec<T>> for BoundedVec<T, S> {
 
 impl<T, S> Eq for BoundedVec<T, S> where T: Eq {}
 
+impl<T, S> PartialOrd for BoundedVec<T, S>
+where
+	T: PartialOrd,
+{
+	fn partial_cmp(&self, other: &Self) -> Option<sp_std::cmp::Ordering> {
+		self.0.partial_cmp(&other.0)
+	}
+}
+
+impl<T, S> Ord for BoundedVec<T, S>
+where
+	T: Ord,
+{
+	fn cmp(&self, other: &Self) -> sp_std::cmp::Ordering {
+		self.0.cmp(&other.0)
+	}
+}
+
 impl<T, S> StorageDecodeLength for BoundedVec<T, S> {}
 
 impl<T, S: Get<u32>> StorageTryAppend<T> for BoundedVec<T, S> {
@@ -35,7 +35,7 @@ mod members;
 pub use members::{AllowAll, DenyAll, Filter};
 pub use members::{
 	AsContains, ChangeMembers, Contains, ContainsLengthBound, Everything, InitializeMembers,
-	IsInVec, Nothing, SortedMembers,
+	IsInVec, Nothing, RankedMembers, SortedMembers,
 };
 
 mod validation;
@@ -52,7 +52,8 @@ mod misc;
 pub use misc::{
 	Backing, ConstU32, EnsureInherentsAreFirst, EstimateCallFee, ExecuteBlock, ExtrinsicCall, Get,
 	GetBacking, GetDefault, HandleLifetime, IsSubType, IsType, Len, OffchainWorker,
-	OnKilledAccount, OnNewAccount, SameOrOther, Time, TryDrop, UnixTime, WrapperOpaque,
+	OnKilledAccount, OnNewAccount, PreimageProvider, PreimageRecipient, SameOrOther, Time, TryDrop,
+	UnixTime, WrapperOpaque,
 };
 
 mod stored_map;
@@ -75,6 +76,11 @@ pub use hooks::{
 #[cfg(feature = "try-runtime")]
 pub use hooks::{OnRuntimeUpgradeHelpersExt, ON_RUNTIME_UPGRADE_PREFIX};
 
+mod try_runtime;
+pub use try_runtime::TryStateSelect;
+#[cfg(feature = "try-runtime")]
+pub use try_runtime::{TryState, TryStateCheck};
+
 pub mod schedule;
 mod storage;
 pub use storage::{
@@ -84,5 +90,13 @@ pub use storage::{
 mod dispatch;
 pub use dispatch::{EnsureOrigin, OriginTrait, UnfilteredDispatchable};
 
+mod messages;
+pub use messages::{
+	EnqueueMessage, ExecuteOverweightError, Footprint, OnQueueChanged, ProcessMessage,
+	ProcessMessageError, ServiceQueues,
+};
+
 mod voting;
-pub use voting::{CurrencyToVote, SaturatingCurrencyToVote, U128CurrencyToVote};
+pub use voting::{
+	CurrencyToVote, PollStatus, Polling, SaturatingCurrencyToVote, U128CurrencyToVote, VoteTally,
+};
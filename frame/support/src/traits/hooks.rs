@@ -268,6 +268,17 @@ pub trait Hooks<BlockNumber> {
 		Ok(())
 	}
 
+	/// Execute some checks to ensure the internal state of the pallet is consistent, at block `n`.
+	///
+	/// Return the weight consumed by the checks, so that callers can spread them over multiple
+	/// invocations on large runtimes. See [`crate::traits::TryState`] for how pallets are selected.
+	///
+	/// This hook is never meant to be executed on-chain but is meant to be used by testing tools.
+	#[cfg(feature = "try-runtime")]
+	fn try_state(_n: BlockNumber) -> Result<crate::weights::Weight, &'static str> {
+		Ok(0)
+	}
+
 	/// Implementing this function on a module allows you to perform long-running tasks
 	/// that make (by default) validators generate transactions that feed results
 	/// of those long-running computations back on chain.
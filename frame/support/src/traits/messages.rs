@@ -0,0 +1,146 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Traits for managing message queuing and handling.
+
+use crate::{
+	storage::bounded_vec::BoundedSlice,
+	traits::{ConstU32, Get},
+	weights::Weight,
+};
+use codec::{Decode, Encode, FullCodec};
+use scale_info::TypeInfo;
+use sp_core::RuntimeDebug;
+use sp_std::fmt::Debug;
+
+/// Errors that can happen when attempting to process a message with
+/// [`ProcessMessage::process_message`].
+#[derive(Copy, Clone, Eq, PartialEq, Encode, Decode, TypeInfo, RuntimeDebug)]
+pub enum ProcessMessageError {
+	/// The message data format is unknown (e.g. unrecognised header).
+	BadFormat,
+	/// The message data is bad (e.g. decoding returns an error).
+	Corrupt,
+	/// The message format is unknown or unsupported.
+	Unsupported,
+	/// Message processing was not attempted because it was not certain that the weight limit
+	/// would be respected. The parameter gives the maximum weight which the message could take
+	/// to process.
+	Overweight(Weight),
+}
+
+/// Can process messages from a specific origin.
+pub trait ProcessMessage {
+	/// The transport from where a message originates.
+	type Origin: FullCodec + Clone + Eq + PartialEq + TypeInfo + Debug;
+
+	/// Process the given message, using no more than `weight_limit` in weight to do so.
+	///
+	/// Returns whether the message was successfully processed and the weight it consumed.
+	fn process_message(
+		message: &[u8],
+		origin: Self::Origin,
+		weight_limit: Weight,
+	) -> Result<(bool, Weight), ProcessMessageError>;
+}
+
+/// Errors that can happen when attempting to execute an overweight message with
+/// [`ServiceQueues::execute_overweight`].
+#[derive(Eq, PartialEq, RuntimeDebug)]
+pub enum ExecuteOverweightError {
+	/// The referenced message was not found.
+	NotFound,
+	/// The available weight was insufficient to execute the message.
+	InsufficientWeight,
+}
+
+/// Can service queues and execute overweight messages.
+pub trait ServiceQueues {
+	/// Addresses a specific overweight message.
+	type OverweightMessageAddress;
+
+	/// Service all message queues in some fair manner.
+	///
+	/// - `weight_limit`: The maximum amount of dynamic weight that this call can use.
+	///
+	/// Returns the dynamic weight used by this call; is never greater than `weight_limit`.
+	fn service_queues(weight_limit: Weight) -> Weight;
+
+	/// Executes a message that could not be executed by [`Self::service_queues()`] because it was
+	/// temporarily overweight.
+	fn execute_overweight(
+		_weight_limit: Weight,
+		_address: Self::OverweightMessageAddress,
+	) -> Result<Weight, ExecuteOverweightError> {
+		Err(ExecuteOverweightError::NotFound)
+	}
+}
+
+/// The resource footprint of a queue.
+#[derive(Default, Copy, Clone, Eq, PartialEq, RuntimeDebug)]
+pub struct Footprint {
+	/// The number of messages in the queue.
+	pub count: u64,
+	/// The total size of the messages in the queue, in bytes.
+	pub size: u64,
+}
+
+/// Can enqueue messages for multiple origins.
+pub trait EnqueueMessage<Origin> {
+	/// The maximal length any enqueued message may have.
+	type MaxMessageLen: Get<u32>;
+
+	/// Enqueue a single `message` from a specific `origin`.
+	fn enqueue_message(message: BoundedSlice<u8, Self::MaxMessageLen>, origin: Origin);
+
+	/// Enqueue multiple `messages` from a specific `origin`.
+	fn enqueue_messages<'a>(
+		messages: impl Iterator<Item = BoundedSlice<'a, u8, Self::MaxMessageLen>>,
+		origin: Origin,
+	);
+
+	/// Stop servicing the queue of `origin`; its remaining messages are no longer processed
+	/// automatically.
+	fn sweep_queue(origin: Origin);
+
+	/// Return the state footprint of the given queue.
+	fn footprint(origin: Origin) -> Footprint;
+}
+
+impl<Origin> EnqueueMessage<Origin> for () {
+	type MaxMessageLen = ConstU32<0>;
+	fn enqueue_message(_: BoundedSlice<u8, Self::MaxMessageLen>, _: Origin) {}
+	fn enqueue_messages<'a>(
+		_: impl Iterator<Item = BoundedSlice<'a, u8, Self::MaxMessageLen>>,
+		_: Origin,
+	) {
+	}
+	fn sweep_queue(_: Origin) {}
+	fn footprint(_: Origin) -> Footprint {
+		Footprint::default()
+	}
+}
+
+/// Handler for when the items in a queue change.
+pub trait OnQueueChanged<Id> {
+	/// Note that the queue `id` now has `items_count` items in it, taking up `items_size` bytes.
+	fn on_queue_changed(id: Id, items_count: u64, items_size: u64);
+}
+
+impl<Id> OnQueueChanged<Id> for () {
+	fn on_queue_changed(_: Id, _: u64, _: u64) {}
+}
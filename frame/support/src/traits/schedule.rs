@@ -18,7 +18,8 @@
 //! Traits and associated utilities for scheduling dispatchables in FRAME.
 
 use codec::{Codec, Decode, Encode, EncodeLike};
-use sp_runtime::{DispatchError, RuntimeDebug};
+use scale_info::TypeInfo;
+use sp_runtime::{traits::Saturating, DispatchError, RuntimeDebug};
 use sp_std::{fmt::Debug, prelude::*};
 
 /// Information relating to the period of a scheduled task. First item is the length of the
@@ -31,7 +32,7 @@ pub type Period<BlockNumber> = (BlockNumber, u32);
 pub type Priority = u8;
 
 /// The dispatch time of a scheduled task.
-#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug)]
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
 pub enum DispatchTime<BlockNumber> {
 	/// At specified block.
 	At(BlockNumber),
@@ -39,6 +40,16 @@ pub enum DispatchTime<BlockNumber> {
 	After(BlockNumber),
 }
 
+impl<BlockNumber: Saturating + Copy> DispatchTime<BlockNumber> {
+	/// Resolve this dispatch time to an absolute block number, given the current block `since`.
+	pub fn evaluate(&self, since: BlockNumber) -> BlockNumber {
+		match &self {
+			Self::At(m) => *m,
+			Self::After(m) => m.saturating_add(since),
+		}
+	}
+}
+
 /// The highest priority. We invert the value so that normal sorting will place the highest
 /// priority at the beginning of the list.
 pub const HIGHEST_PRIORITY: Priority = 0;
@@ -51,7 +62,7 @@ pub const LOWEST_PRIORITY: Priority = 255;
 /// A type that can be used as a scheduler.
 pub trait Anon<BlockNumber, Call, Origin> {
 	/// An address which can be used for removing a scheduled task.
-	type Address: Codec + Clone + Eq + EncodeLike + Debug;
+	type Address: Codec + Clone + Eq + EncodeLike + Debug + TypeInfo;
 
 	/// Schedule a dispatch to happen at the beginning of some block in the future.
 	///
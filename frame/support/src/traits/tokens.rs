@@ -24,8 +24,10 @@ pub mod imbalance;
 mod misc;
 pub mod nonfungible;
 pub mod nonfungibles;
+mod pay;
 pub use imbalance::Imbalance;
 pub use misc::{
 	AssetId, Balance, BalanceConversion, BalanceStatus, DepositConsequence, ExistenceRequirement,
 	WithdrawConsequence, WithdrawReasons,
 };
+pub use pay::{Pay, PayFromAccount, PaymentStatus};
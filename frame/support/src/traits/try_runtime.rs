@@ -0,0 +1,216 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Try-runtime specific traits and types.
+
+use codec::{Decode, Encode};
+use sp_runtime::RuntimeDebug;
+use sp_std::prelude::*;
+
+#[cfg(feature = "try-runtime")]
+use crate::weights::Weight;
+#[cfg(feature = "try-runtime")]
+use sp_runtime::traits::UniqueSaturatedInto;
+
+/// Which pallets should have their try-state checks executed.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub enum TryStateSelect {
+	/// Don't run any checks.
+	None,
+	/// Run the checks of all pallets.
+	All,
+	/// Run the checks of this many pallets, moving on to the next ones with every block.
+	///
+	/// Pallets are taken in the order of the tuple the checks are run for, wrapping around at the
+	/// end, so that all of them are eventually checked over consecutive blocks.
+	RoundRobin(u32),
+	/// Run only the checks of the pallets with these names, as configured in the runtime.
+	Only(Vec<Vec<u8>>),
+}
+
+impl Default for TryStateSelect {
+	fn default() -> Self {
+		Self::None
+	}
+}
+
+#[cfg(feature = "std")]
+impl sp_std::str::FromStr for TryStateSelect {
+	type Err = &'static str;
+
+	/// Parses `none`, `all`, `rr-<n>` (round robin over `n` pallets) or a comma separated list
+	/// of pallet names.
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"none" => Ok(Self::None),
+			"all" => Ok(Self::All),
+			_ if s.starts_with("rr-") => s[3..]
+				.parse::<u32>()
+				.map(Self::RoundRobin)
+				.map_err(|_| "invalid number of pallets for round-robin selection"),
+			"" => Err("empty pallet selection"),
+			_ => Ok(Self::Only(s.split(',').map(|name| name.as_bytes().to_vec()).collect())),
+		}
+	}
+}
+
+/// The name of a pallet, along with the function running its try-state checks, as used by
+/// [`TryState`].
+#[cfg(feature = "try-runtime")]
+pub type TryStateCheck<BlockNumber> =
+	(&'static str, fn(BlockNumber) -> Result<Weight, &'static str>);
+
+/// Execute some checks to ensure the internal state of one or more pallets is consistent.
+///
+/// This is implemented for every pallet and for tuples of pallets, and is never meant to be
+/// executed on-chain but is meant to be used by testing tools.
+#[cfg(feature = "try-runtime")]
+pub trait TryState<BlockNumber> {
+	/// The try-state checks of all pallets covered by this type, in tuple order.
+	fn try_state_checks() -> Vec<TryStateCheck<BlockNumber>> {
+		Vec::new()
+	}
+
+	/// Execute the try-state checks of the pallets picked by `targets`, at block `n`.
+	///
+	/// Returns the weight consumed by the executed checks, or the error of the first failing one.
+	fn try_state(n: BlockNumber, targets: TryStateSelect) -> Result<Weight, &'static str>
+	where
+		BlockNumber: Copy + UniqueSaturatedInto<u64>,
+	{
+		let checks = Self::try_state_checks();
+		let mut weight: Weight = 0;
+
+		match targets {
+			TryStateSelect::None => {},
+			TryStateSelect::All =>
+				for (_, check) in checks.iter() {
+					weight = weight.saturating_add(check(n)?);
+				},
+			TryStateSelect::RoundRobin(per_block) => {
+				if checks.is_empty() {
+					return Ok(0)
+				}
+
+				let total = checks.len() as u64;
+				let per_block = (per_block as u64).min(total);
+				let block: u64 = n.unique_saturated_into();
+				let start = ((block % total) * per_block) % total;
+
+				for (_, check) in
+					checks.iter().cycle().skip(start as usize).take(per_block as usize)
+				{
+					weight = weight.saturating_add(check(n)?);
+				}
+			},
+			TryStateSelect::Only(names) =>
+				for name in names {
+					match checks.iter().find(|(pallet, _)| pallet.as_bytes() == &name[..]) {
+						Some((_, check)) => weight = weight.saturating_add(check(n)?),
+						None => crate::log::warn!(
+							target: crate::LOG_TARGET,
+							"pallet {:?} not found, skipping its try-state checks",
+							sp_std::str::from_utf8(&name),
+						),
+					}
+				},
+		}
+
+		Ok(weight)
+	}
+}
+
+#[cfg(feature = "try-runtime")]
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+impl<BlockNumber> TryState<BlockNumber> for Tuple {
+	fn try_state_checks() -> Vec<TryStateCheck<BlockNumber>> {
+		let mut checks = Vec::new();
+		for_tuples!( #( checks.extend(Tuple::try_state_checks()); )* );
+		checks
+	}
+}
+
+#[cfg(all(test, feature = "try-runtime"))]
+mod tests {
+	use super::*;
+
+	struct Checked<const I: u64>;
+
+	impl<const I: u64> TryState<u64> for Checked<I> {
+		fn try_state_checks() -> Vec<TryStateCheck<u64>> {
+			fn check<const W: u64>(_: u64) -> Result<Weight, &'static str> {
+				if W == 0 {
+					Err("check failed")
+				} else {
+					Ok(W)
+				}
+			}
+
+			let name = match I {
+				0 => "Zero",
+				1 => "One",
+				2 => "Two",
+				_ => "Other",
+			};
+			vec![(name, check::<I>)]
+		}
+	}
+
+	type Pallets = (Checked<1>, (Checked<2>, Checked<4>));
+
+	#[test]
+	fn nested_tuples_are_flattened() {
+		let names = Pallets::try_state_checks().into_iter().map(|(n, _)| n).collect::<Vec<_>>();
+		assert_eq!(names, vec!["One", "Two", "Other"]);
+	}
+
+	#[test]
+	fn selection_works() {
+		assert_eq!(Pallets::try_state(0, TryStateSelect::None), Ok(0));
+		assert_eq!(Pallets::try_state(0, TryStateSelect::All), Ok(7));
+		assert_eq!(
+			Pallets::try_state(0, TryStateSelect::Only(vec![b"Two".to_vec(), b"Nope".to_vec()])),
+			Ok(2),
+		);
+		assert_eq!(
+			<(Checked<1>, Checked<0>)>::try_state(0, TryStateSelect::All),
+			Err("check failed")
+		);
+	}
+
+	#[test]
+	fn round_robin_covers_all_pallets() {
+		assert_eq!(Pallets::try_state(0, TryStateSelect::RoundRobin(2)), Ok(1 + 2));
+		assert_eq!(Pallets::try_state(1, TryStateSelect::RoundRobin(2)), Ok(4 + 1));
+		assert_eq!(Pallets::try_state(2, TryStateSelect::RoundRobin(2)), Ok(2 + 4));
+		assert_eq!(Pallets::try_state(3, TryStateSelect::RoundRobin(2)), Ok(1 + 2));
+		assert_eq!(Pallets::try_state(5, TryStateSelect::RoundRobin(10)), Ok(7));
+		assert_eq!(<()>::try_state(5, TryStateSelect::RoundRobin(1)), Ok(0));
+	}
+
+	#[test]
+	fn parse_select_works() {
+		assert_eq!("none".parse(), Ok(TryStateSelect::None));
+		assert_eq!("all".parse(), Ok(TryStateSelect::All));
+		assert_eq!("rr-3".parse(), Ok(TryStateSelect::RoundRobin(3)));
+		assert_eq!(
+			"System,Balances".parse(),
+			Ok(TryStateSelect::Only(vec![b"System".to_vec(), b"Balances".to_vec()])),
+		);
+		assert!("rr-x".parse::<TryStateSelect>().is_err());
+	}
+}
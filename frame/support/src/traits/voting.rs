@@ -18,7 +18,14 @@
 //! Traits and associated data structures concerned with voting, and moving between tokens and
 //! votes.
 
-use sp_arithmetic::traits::{SaturatedConversion, UniqueSaturatedFrom, UniqueSaturatedInto};
+use crate::dispatch::{DispatchError, Parameter};
+use codec::HasCompact;
+use sp_arithmetic::{
+	traits::{SaturatedConversion, UniqueSaturatedFrom, UniqueSaturatedInto},
+	Perbill,
+};
+use sp_runtime::traits::Member;
+use sp_std::prelude::*;
 
 /// A trait similar to `Convert` to convert values from `B` an abstract balance type
 /// into u64 and back from u128. (This conversion is used in election and other places where complex
@@ -87,3 +94,88 @@ impl<B: UniqueSaturatedInto<u64> + UniqueSaturatedFrom<u128>> CurrencyToVote<B>
 		B::unique_saturated_from(value)
 	}
 }
+
+/// The tally of a poll of some `Class`, from which the passing state of the poll can be
+/// determined.
+pub trait VoteTally<Votes, Class> {
+	/// A new, empty tally for a poll of the given `class`.
+	fn new(class: Class) -> Self;
+	/// The number of aye votes, used to order polls which are waiting to be decided.
+	fn ayes(&self, class: Class) -> Votes;
+	/// The proportion of the total possible votes which have been cast in support of the poll.
+	fn support(&self, class: Class) -> Perbill;
+	/// The proportion of the votes cast which are approving of the poll.
+	fn approval(&self, class: Class) -> Perbill;
+	/// A tally which would pass any poll of the given `class`.
+	#[cfg(feature = "runtime-benchmarks")]
+	fn unanimity(class: Class) -> Self;
+	/// A tally which would fail to pass any poll of the given `class`.
+	#[cfg(feature = "runtime-benchmarks")]
+	fn rejection(class: Class) -> Self;
+	/// A tally with the given `support` and `approval` for a poll of the given `class`.
+	#[cfg(feature = "runtime-benchmarks")]
+	fn from_requirements(support: Perbill, approval: Perbill, class: Class) -> Self;
+}
+
+/// The status of a poll, as seen by the voting system.
+pub enum PollStatus<Tally, Moment, Class> {
+	/// There is no poll with the given index.
+	None,
+	/// The poll is ongoing, with the given tally and class.
+	Ongoing(Tally, Class),
+	/// The poll ended at the given moment, either approved (`true`) or not.
+	Completed(Moment, bool),
+}
+
+impl<Tally, Moment, Class> PollStatus<Tally, Moment, Class> {
+	/// Return the tally and class of the poll if it is ongoing.
+	pub fn ensure_ongoing(self) -> Option<(Tally, Class)> {
+		match self {
+			Self::Ongoing(t, c) => Some((t, c)),
+			_ => None,
+		}
+	}
+}
+
+/// A source of polls, the tallies of which may be accessed and altered by a voting system.
+pub trait Polling<Tally> {
+	/// The index by which a poll is identified.
+	type Index: Parameter + Member + Ord + PartialOrd + Copy + HasCompact;
+	/// The type in which votes are counted.
+	type Votes: Parameter + Member + Ord + PartialOrd + Copy + HasCompact;
+	/// The class of a poll. Polls of different classes are voted on independently.
+	type Class: Parameter + Member + Ord + PartialOrd;
+	/// The type used to express the moment at which a poll ended.
+	type Moment;
+
+	/// All classes of poll which may exist.
+	fn classes() -> Vec<Self::Class>;
+
+	/// Return the tally and class of the poll `index` if it is ongoing.
+	fn as_ongoing(index: Self::Index) -> Option<(Tally, Self::Class)>;
+
+	/// Access the status of the poll `index`, possibly altering its tally.
+	fn access_poll<R>(
+		index: Self::Index,
+		f: impl FnOnce(PollStatus<&mut Tally, Self::Moment, Self::Class>) -> R,
+	) -> R;
+
+	/// Access the status of the poll `index`, possibly altering its tally. Any changes are only
+	/// kept if `f` returns `Ok`.
+	fn try_access_poll<R>(
+		index: Self::Index,
+		f: impl FnOnce(PollStatus<&mut Tally, Self::Moment, Self::Class>) -> Result<R, DispatchError>,
+	) -> Result<R, DispatchError>;
+
+	/// Create an ongoing poll of the given `class`, returning its index.
+	#[cfg(feature = "runtime-benchmarks")]
+	fn create_ongoing(class: Self::Class) -> Result<Self::Index, ()>;
+
+	/// End the ongoing poll `index`, with the given outcome.
+	#[cfg(feature = "runtime-benchmarks")]
+	fn end_ongoing(index: Self::Index, approved: bool) -> Result<(), ()>;
+
+	/// The class with the most polls which may be ongoing at once, together with that number.
+	#[cfg(feature = "runtime-benchmarks")]
+	fn max_ongoing() -> (Self::Class, u32);
+}
@@ -127,7 +127,10 @@
 //! - Ubuntu 19.10 (GNU/Linux 5.3.0-18-generic x86_64)
 //! - rustc 1.42.0 (b8cedc004 2020-03-09)
 
-use crate::dispatch::{DispatchError, DispatchErrorWithPostInfo, DispatchResultWithPostInfo};
+use crate::{
+	dispatch::{DispatchError, DispatchErrorWithPostInfo, DispatchResultWithPostInfo},
+	traits::Get,
+};
 use codec::{Decode, Encode};
 use scale_info::TypeInfo;
 #[cfg(feature = "std")]
@@ -650,6 +653,15 @@ impl RuntimeDbWeight {
 	}
 }
 
+/// A trait that describes the weight to fee calculation.
+pub trait WeightToFee {
+	/// The type that is returned as result from calculation.
+	type Balance: BaseArithmetic + From<u32> + Copy + Unsigned;
+
+	/// Calculates the fee from the passed `weight`.
+	fn calc(weight: &Weight) -> Self::Balance;
+}
+
 /// One coefficient and its position in the `WeightToFeePolynomial`.
 ///
 /// One term of polynomial is calculated as:
@@ -677,7 +689,8 @@ pub type WeightToFeeCoefficients<T> = SmallVec<[WeightToFeeCoefficient<T>; 4]>;
 
 /// A trait that describes the weight to fee calculation as polynomial.
 ///
-/// An implementor should only implement the `polynomial` function.
+/// An implementor should only implement the `polynomial` function. Every implementor also
+/// implements [`WeightToFee`].
 pub trait WeightToFeePolynomial {
 	/// The type that is returned as result from polynomial evaluation.
 	type Balance: BaseArithmetic + From<u32> + Copy + Unsigned;
@@ -689,11 +702,18 @@ pub trait WeightToFeePolynomial {
 	/// that the order of coefficients is important as putting the negative coefficients
 	/// first will most likely saturate the result to zero mid evaluation.
 	fn polynomial() -> WeightToFeeCoefficients<Self::Balance>;
+}
+
+impl<T> WeightToFee for T
+where
+	T: WeightToFeePolynomial,
+{
+	type Balance = <Self as WeightToFeePolynomial>::Balance;
 
 	/// Calculates the fee from the passed `weight` according to the `polynomial`.
 	///
-	/// This should not be overriden in most circumstances. Calculation is done in the
-	/// `Balance` type and never overflows. All evaluation is saturating.
+	/// Calculation is done in the `Balance` type and never overflows. All evaluation is
+	/// saturating.
 	fn calc(weight: &Weight) -> Self::Balance {
 		Self::polynomial()
 			.iter()
@@ -737,6 +757,21 @@ where
 	}
 }
 
+/// Implementor of `WeightToFee` that maps one unit of weight to `M` units of fee.
+pub struct ConstantMultiplier<T, M>(sp_std::marker::PhantomData<(T, M)>);
+
+impl<T, M> WeightToFee for ConstantMultiplier<T, M>
+where
+	T: BaseArithmetic + From<u32> + Copy + Unsigned,
+	M: Get<T>,
+{
+	type Balance = T;
+
+	fn calc(weight: &Weight) -> Self::Balance {
+		Self::Balance::saturated_from(*weight).saturating_mul(M::get())
+	}
+}
+
 /// A struct holding value for each `DispatchClass`.
 #[derive(Clone, Eq, PartialEq, Default, RuntimeDebug, Encode, Decode, TypeInfo)]
 pub struct PerDispatchClass<T> {
@@ -1011,4 +1046,18 @@ mod tests {
 		assert_eq!(IdentityFee::<Balance>::calc(&50), 50);
 		assert_eq!(IdentityFee::<Balance>::calc(&Weight::max_value()), Balance::max_value());
 	}
+
+	#[test]
+	fn constant_fee_works() {
+		crate::parameter_types! {
+			pub const Zero: Balance = 0;
+			pub const Ten: Balance = 10;
+			pub const Max: Balance = Balance::max_value();
+		}
+
+		assert_eq!(ConstantMultiplier::<Balance, Ten>::calc(&0), 0);
+		assert_eq!(ConstantMultiplier::<Balance, Ten>::calc(&50), 500);
+		assert_eq!(ConstantMultiplier::<Balance, Zero>::calc(&50), 0);
+		assert_eq!(ConstantMultiplier::<Balance, Max>::calc(&2), Balance::max_value());
+	}
 }
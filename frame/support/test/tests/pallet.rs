@@ -180,6 +180,12 @@ pub mod pallet {
 			T::AccountId::from(SomeType1); // Test for where clause
 			T::AccountId::from(SomeType2); // Test for where clause
 		}
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_: BlockNumberFor<T>) -> Result<Weight, &'static str> {
+			T::AccountId::from(SomeType1); // Test for where clause
+			T::AccountId::from(SomeType2); // Test for where clause
+			Ok(40)
+		}
 	}
 
 	#[pallet::call]
@@ -945,6 +951,28 @@ fn pallet_hooks_expand() {
 	})
 }
 
+#[cfg(feature = "try-runtime")]
+#[test]
+fn pallet_try_state_expand() {
+	use frame_support::traits::{TryState, TryStateSelect};
+
+	TestExternalities::default().execute_with(|| {
+		assert_eq!(AllPallets::try_state(1, TryStateSelect::None), Ok(0));
+		assert_eq!(AllPallets::try_state(1, TryStateSelect::All), Ok(40));
+		assert_eq!(
+			AllPallets::try_state(1, TryStateSelect::Only(vec![b"Example".to_vec()])),
+			Ok(40),
+		);
+		assert_eq!(
+			AllPallets::try_state(1, TryStateSelect::Only(vec![b"Example2".to_vec()])),
+			Ok(0),
+		);
+		// `AllPallets` is `(Example2, (Example, ()))`.
+		assert_eq!(AllPallets::try_state(1, TryStateSelect::RoundRobin(1)), Ok(40));
+		assert_eq!(AllPallets::try_state(2, TryStateSelect::RoundRobin(1)), Ok(0));
+	})
+}
+
 #[test]
 fn pallet_on_genesis() {
 	TestExternalities::default().execute_with(|| {
@@ -0,0 +1,170 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::Config;
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::SignedExtension, transaction_validity::TransactionValidityError, RuntimeDebug,
+};
+
+/// The metadata hash given through the `RUNTIME_METADATA_HASH` environment variable at compile
+/// time, as `0x` prefixed, hex encoded 32 byte hash.
+const RUNTIME_METADATA_HASH: Option<[u8; 32]> = match option_env!("RUNTIME_METADATA_HASH") {
+	Some(hash) => Some(decode_hex_hash(hash)),
+	None => None,
+};
+
+/// Decode a `0x` prefixed, hex encoded 32 byte hash.
+const fn decode_hex_hash(hash: &str) -> [u8; 32] {
+	let hash = hash.as_bytes();
+	assert!(
+		hash.len() == 66 && hash[0] == b'0' && hash[1] == b'x',
+		"`RUNTIME_METADATA_HASH` must be a `0x` prefixed, hex encoded 32 byte hash",
+	);
+
+	let mut result = [0u8; 32];
+	let mut i = 0;
+	while i < 32 {
+		result[i] = decode_hex_digit(hash[2 + i * 2]) << 4 | decode_hex_digit(hash[3 + i * 2]);
+		i += 1;
+	}
+	result
+}
+
+/// Decode a single hex digit.
+const fn decode_hex_digit(digit: u8) -> u8 {
+	match digit {
+		b'0'..=b'9' => digit - b'0',
+		b'a'..=b'f' => digit - b'a' + 10,
+		b'A'..=b'F' => digit - b'A' + 10,
+		_ => panic!("`RUNTIME_METADATA_HASH` contains an invalid hex digit"),
+	}
+}
+
+/// The mode of [`CheckMetadataHash`], as chosen by the signer.
+#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum Mode {
+	/// The metadata hash is not part of the signed payload.
+	Disabled,
+	/// The metadata hash is part of the signed payload.
+	Enabled,
+}
+
+/// Ensure the transaction was signed against the metadata of the current runtime.
+///
+/// When enabled by the signer, the hash of the metadata becomes part of the signed payload. This
+/// enables offline signers, like hardware wallets, to verify that the metadata they used to
+/// decode the transaction is the metadata of the runtime. The hash is given at compile time with
+/// the `RUNTIME_METADATA_HASH` environment variable. If it was not given, any transaction
+/// that enables the check is rejected, because its signature won't match.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct CheckMetadataHash<T: Config + Send + Sync> {
+	mode: Mode,
+	/// Overwrites the metadata hash given at compile time.
+	#[codec(skip)]
+	custom_metadata_hash: Option<[u8; 32]>,
+	_phantom: sp_std::marker::PhantomData<T>,
+}
+
+impl<T: Config + Send + Sync> sp_std::fmt::Debug for CheckMetadataHash<T> {
+	#[cfg(feature = "std")]
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		write!(f, "CheckMetadataHash({:?})", self.mode)
+	}
+
+	#[cfg(not(feature = "std"))]
+	fn fmt(&self, _: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		Ok(())
+	}
+}
+
+impl<T: Config + Send + Sync> CheckMetadataHash<T> {
+	/// Create new `SignedExtension` to check the metadata hash.
+	pub fn new(enable: bool) -> Self {
+		Self {
+			mode: if enable { Mode::Enabled } else { Mode::Disabled },
+			custom_metadata_hash: None,
+			_phantom: Default::default(),
+		}
+	}
+
+	/// Create new `SignedExtension` to check against the given `metadata_hash`.
+	///
+	/// This is useful for testing and for signing transactions on the client side.
+	pub fn new_with_custom_hash(metadata_hash: [u8; 32]) -> Self {
+		Self {
+			mode: Mode::Enabled,
+			custom_metadata_hash: Some(metadata_hash),
+			_phantom: Default::default(),
+		}
+	}
+}
+
+impl<T: Config + Send + Sync> SignedExtension for CheckMetadataHash<T> {
+	type AccountId = T::AccountId;
+	type Call = <T as Config>::Call;
+	type AdditionalSigned = Option<[u8; 32]>;
+	type Pre = ();
+	const IDENTIFIER: &'static str = "CheckMetadataHash";
+
+	fn additional_signed(&self) -> Result<Self::AdditionalSigned, TransactionValidityError> {
+		match self.mode {
+			Mode::Disabled => Ok(None),
+			Mode::Enabled => Ok(self.custom_metadata_hash.or(RUNTIME_METADATA_HASH)),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::mock::Test;
+
+	#[test]
+	fn additional_signed_follows_the_mode() {
+		assert_eq!(CheckMetadataHash::<Test>::new(false).additional_signed().unwrap(), None);
+		assert_eq!(
+			CheckMetadataHash::<Test>::new(true).additional_signed().unwrap(),
+			RUNTIME_METADATA_HASH,
+		);
+		assert_eq!(
+			CheckMetadataHash::<Test>::new_with_custom_hash([1; 32])
+				.additional_signed()
+				.unwrap(),
+			Some([1; 32]),
+		);
+	}
+
+	#[test]
+	fn only_the_mode_is_encoded() {
+		let ext = CheckMetadataHash::<Test>::new_with_custom_hash([1; 32]);
+		assert_eq!(ext.encode(), Mode::Enabled.encode());
+
+		let decoded = CheckMetadataHash::<Test>::decode(&mut &ext.encode()[..]).unwrap();
+		assert_eq!(decoded, CheckMetadataHash::<Test>::new(true));
+	}
+
+	#[test]
+	fn decode_hex_hash_works() {
+		let hash =
+			decode_hex_hash("0x000102030405060708090a0b0c0d0e0f101112131415161718191A1B1C1D1E1F");
+		let expected: Vec<u8> = (0..32).collect();
+		assert_eq!(&hash[..], &expected[..]);
+	}
+}
@@ -132,6 +132,8 @@ parameter_types! {
 	pub const TreasuryPalletId: PalletId = PalletId(*b"py/trsry");
 	pub const MaximumReasonLength: u32 = 16384;
 	pub const MaxApprovals: u32 = 100;
+	pub const PayoutPeriod: u64 = 5;
+	pub TreasuryAccount: u128 = Treasury::account_id();
 }
 impl pallet_treasury::Config for Test {
 	type PalletId = TreasuryPalletId;
@@ -148,6 +150,9 @@ impl pallet_treasury::Config for Test {
 	type WeightInfo = ();
 	type SpendFunds = ();
 	type MaxApprovals = MaxApprovals;
+	type SpendOrigin = frame_system::EnsureNever<u64>;
+	type Paymaster = frame_support::traits::tokens::PayFromAccount<Balances, TreasuryAccount, u128>;
+	type PayoutPeriod = PayoutPeriod;
 }
 parameter_types! {
 	pub const TipCountdown: u64 = 1;
@@ -22,7 +22,10 @@
 use super::{Pallet as Treasury, *};
 
 use frame_benchmarking::{account, benchmarks_instance_pallet, impl_benchmark_test_suite};
-use frame_support::{ensure, traits::OnInitialize};
+use frame_support::{
+	ensure,
+	traits::{EnsureOrigin, OnInitialize, UnfilteredDispatchable},
+};
 use frame_system::RawOrigin;
 
 const SEED: u32 = 0;
@@ -51,6 +54,21 @@ fn create_approved_proposals<T: Config<I>, I: 'static>(n: u32) -> Result<(), &'s
 	Ok(())
 }
 
+// Create a spend that is approved and ready to be paid out.
+fn create_spend<T: Config<I>, I: 'static>(
+) -> Result<(T::AccountId, BalanceOf<T, I>, T::AccountId), &'static str> {
+	let (caller, value, beneficiary_lookup) = setup_proposal::<T, I>(SEED);
+	let origin = T::SpendOrigin::successful_origin();
+	let max_amount = T::SpendOrigin::ensure_origin(origin.clone())
+		.map_err(|_| "spend origin is not successful")?;
+	let amount = value.min(max_amount);
+	Call::<T, I>::spend { amount, beneficiary: beneficiary_lookup.clone() }
+		.dispatch_bypass_filter(origin)
+		.map_err(|_| "spend failed")?;
+	let beneficiary = T::Lookup::lookup(beneficiary_lookup).map_err(|_| "lookup failed")?;
+	Ok((caller, amount, beneficiary))
+}
+
 fn setup_pot_account<T: Config<I>, I: 'static>() {
 	let pot_account = Treasury::<T, I>::account_id();
 	let value = T::Currency::minimum_balance().saturating_mul(1_000_000_000u32.into());
@@ -94,6 +112,37 @@ benchmarks_instance_pallet! {
 	}: {
 		Treasury::<T, _>::on_initialize(T::BlockNumber::zero());
 	}
+
+	spend {
+		let origin = T::SpendOrigin::successful_origin();
+		let (_, value, beneficiary_lookup) = setup_proposal::<T, _>(SEED);
+		let max_amount = T::SpendOrigin::ensure_origin(origin.clone())
+			.map_err(|_| "spend origin is not successful")?;
+		let amount = value.min(max_amount);
+		let call = Call::<T, I>::spend { amount, beneficiary: beneficiary_lookup };
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_eq!(Treasury::<T, I>::spend_count(), 1);
+	}
+
+	payout {
+		let (caller, amount, beneficiary) = create_spend::<T, I>()?;
+		T::Paymaster::ensure_successful(&beneficiary, (), amount);
+	}: _(RawOrigin::Signed(caller), 0u32)
+	verify {
+		let status = Spends::<T, I>::get(0).expect("spend was not removed; qed").status;
+		assert!(matches!(status, PaymentState::Attempted { .. }));
+	}
+
+	check_status {
+		let (caller, amount, beneficiary) = create_spend::<T, I>()?;
+		T::Paymaster::ensure_successful(&beneficiary, (), amount);
+		Treasury::<T, I>::payout(RawOrigin::Signed(caller.clone()).into(), 0u32)?;
+		match Spends::<T, I>::get(0).expect("spend was just created; qed").status {
+			PaymentState::Attempted { id } => T::Paymaster::ensure_concluded(id),
+			_ => return Err("payout was not attempted".into()),
+		};
+	}: _(RawOrigin::Signed(caller), 0u32)
 }
 
 impl_benchmark_test_suite!(Treasury, crate::tests::new_test_ext(), crate::tests::Test);
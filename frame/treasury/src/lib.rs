@@ -41,6 +41,8 @@
 //! - **Deposit:** Funds that a proposer must lock when making a proposal. The deposit will be
 //!   returned or slashed if the proposal is approved or rejected respectively.
 //! - **Pot:** Unspent funds accumulated by the treasury pallet.
+//! - **Spend:** An approved transfer of funds from the pot to a beneficiary, authorised directly by
+//!   the `SpendOrigin` rather than through a proposal.
 //!
 //! ## Interface
 //!
@@ -51,6 +53,11 @@
 //! - `reject_proposal` - Reject a proposal, slashing the deposit.
 //! - `approve_proposal` - Accept the proposal, returning the deposit.
 //!
+//! Direct spending protocol:
+//! - `spend` - Approve a spend of up to the origin's allowance in favour of a beneficiary.
+//! - `payout` - Attempt the payment of an approved spend through the `Paymaster`.
+//! - `check_status` - Check the status of an attempted payment and clean up concluded spends.
+//!
 //! ## GenesisConfig
 //!
 //! The Treasury pallet depends on the [`GenesisConfig`].
@@ -74,8 +81,10 @@ use sp_std::prelude::*;
 use frame_support::{
 	print,
 	traits::{
-		Currency, ExistenceRequirement::KeepAlive, Get, Imbalance, OnUnbalanced,
-		ReservableCurrency, WithdrawReasons,
+		tokens::{Pay, PaymentStatus},
+		Currency,
+		ExistenceRequirement::KeepAlive,
+		Get, Imbalance, OnUnbalanced, ReservableCurrency, WithdrawReasons,
 	},
 	weights::Weight,
 	PalletId,
@@ -131,6 +140,44 @@ pub struct Proposal<AccountId, Balance> {
 	bond: Balance,
 }
 
+/// An index of a spend. Just a `u32`.
+pub type SpendIndex = u32;
+
+/// The state of the payment of an approved spend.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub enum PaymentState<Id> {
+	/// Pending claim.
+	Pending,
+	/// Payment attempted with a payment identifier.
+	Attempted { id: Id },
+	/// Payment failed.
+	Failed,
+}
+
+/// Info regarding an approved spend.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub struct SpendStatus<Balance, Beneficiary, BlockNumber, PaymentId> {
+	/// The amount to be paid.
+	amount: Balance,
+	/// The beneficiary of the spend.
+	beneficiary: Beneficiary,
+	/// The block number after which the spend can no longer be paid out.
+	expire_at: BlockNumber,
+	/// The status of the payout.
+	status: PaymentState<PaymentId>,
+}
+
+/// The payment identifier used by the `Paymaster` of a treasury instance.
+pub type PaymentIdOf<T, I = ()> = <<T as Config<I>>::Paymaster as Pay>::Id;
+
+/// An approved spend of a treasury instance.
+pub type SpendStatusOf<T, I = ()> = SpendStatus<
+	BalanceOf<T, I>,
+	<T as frame_system::Config>::AccountId,
+	<T as frame_system::Config>::BlockNumber,
+	PaymentIdOf<T, I>,
+>;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -192,6 +239,22 @@ pub mod pallet {
 		/// The maximum number of approvals that can wait in the spending queue.
 		#[pallet::constant]
 		type MaxApprovals: Get<u32>;
+
+		/// The origin required for approving spends from the treasury outside of the proposal
+		/// process. The `Success` value is the maximum amount that this origin is allowed to
+		/// spend at a time.
+		type SpendOrigin: EnsureOrigin<Self::Origin, Success = BalanceOf<Self, I>>;
+
+		/// Type for processing spends in favour of `Self::AccountId`.
+		type Paymaster: Pay<
+			Beneficiary = Self::AccountId,
+			Balance = BalanceOf<Self, I>,
+			AssetKind = (),
+		>;
+
+		/// The period during which an approved spend has to be claimed.
+		#[pallet::constant]
+		type PayoutPeriod: Get<Self::BlockNumber>;
 	}
 
 	/// Number of proposals that have been made.
@@ -216,6 +279,17 @@ pub mod pallet {
 	pub type Approvals<T: Config<I>, I: 'static = ()> =
 		StorageValue<_, BoundedVec<ProposalIndex, T::MaxApprovals>, ValueQuery>;
 
+	/// The count of spends that have been made.
+	#[pallet::storage]
+	#[pallet::getter(fn spend_count)]
+	pub(crate) type SpendCount<T, I = ()> = StorageValue<_, SpendIndex, ValueQuery>;
+
+	/// Spends that have been approved and are being processed.
+	#[pallet::storage]
+	#[pallet::getter(fn spends)]
+	pub type Spends<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, SpendIndex, SpendStatusOf<T, I>, OptionQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig;
 
@@ -270,6 +344,15 @@ pub mod pallet {
 		Rollover(BalanceOf<T, I>),
 		/// Some funds have been deposited. \[deposit\]
 		Deposit(BalanceOf<T, I>),
+		/// A new spend has been approved. \[spend_index, amount, beneficiary\]
+		SpendApproved(SpendIndex, BalanceOf<T, I>, T::AccountId),
+		/// A payment of an approved spend has been attempted. \[spend_index, payment_id\]
+		Paid(SpendIndex, PaymentIdOf<T, I>),
+		/// A payment of an approved spend has failed. \[spend_index, payment_id\]
+		PaymentFailed(SpendIndex, PaymentIdOf<T, I>),
+		/// A spend was processed and removed from storage. It might have been successfully paid
+		/// or it may have expired. \[spend_index\]
+		SpendProcessed(SpendIndex),
 	}
 
 	/// Old name generated by `decl_event`.
@@ -285,6 +368,19 @@ pub mod pallet {
 		InvalidIndex,
 		/// Too many approvals in the queue.
 		TooManyApprovals,
+		/// The spend origin is valid but the amount it is allowed to spend is lower than the
+		/// amount to be spent.
+		InsufficientPermission,
+		/// The spend has expired and cannot be claimed.
+		SpendExpired,
+		/// The payment has already been attempted.
+		AlreadyAttempted,
+		/// There was some issue with the mechanism of payment.
+		PayoutError,
+		/// The payout was not yet attempted.
+		NotAttempted,
+		/// The payment has neither failed nor succeeded yet.
+		Inconclusive,
 	}
 
 	#[pallet::hooks]
@@ -386,6 +482,123 @@ pub mod pallet {
 				.map_err(|_| Error::<T, I>::TooManyApprovals)?;
 			Ok(())
 		}
+
+		/// Approve a spend of `amount` from the treasury in favour of `beneficiary`, bypassing
+		/// the proposal process.
+		///
+		/// May only be called from `T::SpendOrigin`, and `amount` must not exceed the maximum
+		/// amount that the origin is allowed to spend. The spend has to be paid out with
+		/// `payout` before `T::PayoutPeriod` elapses.
+		///
+		/// # <weight>
+		/// - Complexity: O(1)
+		/// - DbReads: `SpendCount`
+		/// - DbWrites: `SpendCount`, `Spends`
+		/// # </weight>
+		#[pallet::weight(T::WeightInfo::spend())]
+		pub fn spend(
+			origin: OriginFor<T>,
+			#[pallet::compact] amount: BalanceOf<T, I>,
+			beneficiary: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			let max_amount = T::SpendOrigin::ensure_origin(origin)?;
+			let beneficiary = T::Lookup::lookup(beneficiary)?;
+
+			ensure!(amount <= max_amount, Error::<T, I>::InsufficientPermission);
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let index = Self::spend_count();
+			<SpendCount<T, I>>::put(index + 1);
+			<Spends<T, I>>::insert(
+				index,
+				SpendStatus {
+					amount,
+					beneficiary: beneficiary.clone(),
+					expire_at: now.saturating_add(T::PayoutPeriod::get()),
+					status: PaymentState::Pending,
+				},
+			);
+
+			Self::deposit_event(Event::SpendApproved(index, amount, beneficiary));
+			Ok(())
+		}
+
+		/// Attempt the payment of an approved spend through `T::Paymaster`.
+		///
+		/// May be called by any signed origin. The spend must not have expired and its payment
+		/// must not already be in progress; a failed payment can be retried.
+		///
+		/// # <weight>
+		/// - Complexity: O(1)
+		/// - DbReads: `Spends`
+		/// - DbWrites: `Spends`
+		/// # </weight>
+		#[pallet::weight(T::WeightInfo::payout())]
+		pub fn payout(origin: OriginFor<T>, index: SpendIndex) -> DispatchResult {
+			ensure_signed(origin)?;
+			let mut spend = Spends::<T, I>::get(index).ok_or(Error::<T, I>::InvalidIndex)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now <= spend.expire_at, Error::<T, I>::SpendExpired);
+			ensure!(
+				!matches!(spend.status, PaymentState::Attempted { .. }),
+				Error::<T, I>::AlreadyAttempted
+			);
+
+			let id = T::Paymaster::pay(&spend.beneficiary, (), spend.amount)
+				.map_err(|_| Error::<T, I>::PayoutError)?;
+
+			spend.status = PaymentState::Attempted { id };
+			Spends::<T, I>::insert(index, spend);
+
+			Self::deposit_event(Event::<T, I>::Paid(index, id));
+			Ok(())
+		}
+
+		/// Check the status of the payment of a spend and remove it from storage once it has
+		/// concluded.
+		///
+		/// May be called by any signed origin. A successful payment or an expired spend is
+		/// removed from storage and the call is free. A failed payment is marked as such so it
+		/// can be retried with `payout`.
+		///
+		/// # <weight>
+		/// - Complexity: O(1)
+		/// - DbReads: `Spends`
+		/// - DbWrites: `Spends`
+		/// # </weight>
+		#[pallet::weight(T::WeightInfo::check_status())]
+		pub fn check_status(origin: OriginFor<T>, index: SpendIndex) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let mut spend = Spends::<T, I>::get(index).ok_or(Error::<T, I>::InvalidIndex)?;
+			let now = frame_system::Pallet::<T>::block_number();
+
+			if now > spend.expire_at && !matches!(spend.status, PaymentState::Attempted { .. }) {
+				// spend has expired and no further status update is expected.
+				Spends::<T, I>::remove(index);
+				Self::deposit_event(Event::<T, I>::SpendProcessed(index));
+				return Ok(Pays::No.into())
+			}
+
+			let payment_id = match spend.status {
+				PaymentState::Attempted { id } => id,
+				_ => return Err(Error::<T, I>::NotAttempted.into()),
+			};
+
+			match T::Paymaster::check_payment(payment_id) {
+				PaymentStatus::Failure => {
+					spend.status = PaymentState::Failed;
+					Spends::<T, I>::insert(index, spend);
+					Self::deposit_event(Event::<T, I>::PaymentFailed(index, payment_id));
+				},
+				PaymentStatus::Success | PaymentStatus::Unknown => {
+					Spends::<T, I>::remove(index);
+					Self::deposit_event(Event::<T, I>::SpendProcessed(index));
+					return Ok(Pays::No.into())
+				},
+				PaymentStatus::InProgress => return Err(Error::<T, I>::Inconclusive.into()),
+			}
+			Ok(Pays::Yes.into())
+		}
 	}
 }
 
@@ -19,16 +19,19 @@
 
 #![cfg(test)]
 
-use std::cell::RefCell;
+use std::{cell::RefCell, collections::BTreeMap};
 
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
-	traits::{BlakeTwo256, IdentityLookup},
+	traits::{BadOrigin, BlakeTwo256, IdentityLookup},
 };
 
 use frame_support::{
-	assert_noop, assert_ok, pallet_prelude::GenesisBuild, parameter_types, traits::OnInitialize,
+	assert_noop, assert_ok,
+	pallet_prelude::GenesisBuild,
+	parameter_types,
+	traits::{tokens::PaymentStatus, OnInitialize},
 	PalletId,
 };
 
@@ -107,6 +110,69 @@ parameter_types! {
 	pub const BountyCuratorDeposit: Permill = Permill::from_percent(50);
 	pub const BountyValueMinimum: u64 = 1;
 	pub const MaxApprovals: u32 = 100;
+	pub const PayoutPeriod: u64 = 5;
+}
+pub struct TestSpendOrigin;
+impl frame_support::traits::EnsureOrigin<Origin> for TestSpendOrigin {
+	type Success = u64;
+	fn try_origin(o: Origin) -> Result<Self::Success, Origin> {
+		Result::<frame_system::RawOrigin<_>, Origin>::from(o).and_then(|o| match o {
+			frame_system::RawOrigin::Root => Ok(u64::max_value()),
+			frame_system::RawOrigin::Signed(10) => Ok(5),
+			frame_system::RawOrigin::Signed(11) => Ok(10),
+			frame_system::RawOrigin::Signed(12) => Ok(20),
+			frame_system::RawOrigin::Signed(13) => Ok(50),
+			r => Err(Origin::from(r)),
+		})
+	}
+	#[cfg(feature = "runtime-benchmarks")]
+	fn successful_origin() -> Origin {
+		Origin::root()
+	}
+}
+thread_local! {
+	static PAID: RefCell<BTreeMap<u128, u64>> = RefCell::new(BTreeMap::new());
+	static STATUS: RefCell<BTreeMap<u64, PaymentStatus>> = RefCell::new(BTreeMap::new());
+	static LAST_ID: RefCell<u64> = RefCell::new(0u64);
+}
+/// Paymaster which records the payments made and whose status can be set by tests.
+pub struct TestPay;
+impl Pay for TestPay {
+	type Balance = u64;
+	type Beneficiary = u128;
+	type AssetKind = ();
+	type Id = u64;
+	type Error = ();
+	fn pay(
+		who: &Self::Beneficiary,
+		_: Self::AssetKind,
+		amount: Self::Balance,
+	) -> Result<Self::Id, Self::Error> {
+		PAID.with(|paid| *paid.borrow_mut().entry(*who).or_default() += amount);
+		Ok(LAST_ID.with(|lid| {
+			let x = *lid.borrow();
+			lid.replace(x + 1);
+			x
+		}))
+	}
+	fn check_payment(id: Self::Id) -> PaymentStatus {
+		STATUS.with(|s| s.borrow().get(&id).cloned().unwrap_or(PaymentStatus::Unknown))
+	}
+	#[cfg(feature = "runtime-benchmarks")]
+	fn ensure_successful(_: &Self::Beneficiary, _: Self::AssetKind, _: Self::Balance) {}
+	#[cfg(feature = "runtime-benchmarks")]
+	fn ensure_concluded(id: Self::Id) {
+		set_status(id, PaymentStatus::Failure)
+	}
+}
+fn paid(who: u128) -> u64 {
+	PAID.with(|p| p.borrow().get(&who).cloned().unwrap_or(0))
+}
+fn set_status(id: u64, s: PaymentStatus) {
+	STATUS.with(|m| m.borrow_mut().insert(id, s));
+}
+fn last_event() -> Event {
+	System::events().pop().expect("Event expected").event
 }
 impl Config for Test {
 	type PalletId = TreasuryPalletId;
@@ -123,6 +189,9 @@ impl Config for Test {
 	type WeightInfo = ();
 	type SpendFunds = ();
 	type MaxApprovals = MaxApprovals;
+	type SpendOrigin = TestSpendOrigin;
+	type Paymaster = TestPay;
+	type PayoutPeriod = PayoutPeriod;
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -389,3 +458,121 @@ fn max_approvals_limited() {
 		);
 	});
 }
+
+#[test]
+fn spend_origin_permissioning_works() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(Treasury::spend(Origin::signed(1), 1, 1), BadOrigin);
+		assert_noop!(
+			Treasury::spend(Origin::signed(10), 6, 1),
+			Error::<Test>::InsufficientPermission
+		);
+		assert_noop!(
+			Treasury::spend(Origin::signed(11), 11, 1),
+			Error::<Test>::InsufficientPermission
+		);
+		assert_noop!(
+			Treasury::spend(Origin::signed(12), 21, 1),
+			Error::<Test>::InsufficientPermission
+		);
+		assert_noop!(
+			Treasury::spend(Origin::signed(13), 51, 1),
+			Error::<Test>::InsufficientPermission
+		);
+	});
+}
+
+#[test]
+fn spend_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Treasury::spend(Origin::signed(10), 5, 6));
+
+		assert_eq!(Treasury::spend_count(), 1);
+		assert_eq!(
+			Spends::<Test>::get(0),
+			Some(SpendStatus {
+				amount: 5,
+				beneficiary: 6,
+				expire_at: 1 + PayoutPeriod::get(),
+				status: PaymentState::Pending,
+			})
+		);
+		assert_eq!(last_event(), Event::Treasury(crate::Event::SpendApproved(0, 5, 6)));
+		// Nothing has been paid yet.
+		assert_eq!(paid(6), 0);
+	});
+}
+
+#[test]
+fn payout_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Treasury::spend(Origin::signed(10), 2, 6));
+		assert_ok!(Treasury::payout(Origin::signed(1), 0));
+		assert_eq!(paid(6), 2);
+		assert_eq!(last_event(), Event::Treasury(crate::Event::Paid(0, 0)));
+		assert_noop!(Treasury::payout(Origin::signed(1), 0), Error::<Test>::AlreadyAttempted);
+
+		set_status(0, PaymentStatus::Success);
+		assert_ok!(Treasury::check_status(Origin::signed(1), 0));
+		assert_eq!(last_event(), Event::Treasury(crate::Event::SpendProcessed(0)));
+		assert_eq!(Spends::<Test>::get(0), None);
+		assert_noop!(Treasury::payout(Origin::signed(1), 0), Error::<Test>::InvalidIndex);
+	});
+}
+
+#[test]
+fn payout_retry_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Treasury::spend(Origin::signed(10), 2, 6));
+		assert_ok!(Treasury::payout(Origin::signed(1), 0));
+
+		set_status(0, PaymentStatus::Failure);
+		assert_ok!(Treasury::check_status(Origin::signed(1), 0));
+		assert_eq!(last_event(), Event::Treasury(crate::Event::PaymentFailed(0, 0)));
+		assert_eq!(Spends::<Test>::get(0).unwrap().status, PaymentState::Failed);
+
+		assert_ok!(Treasury::payout(Origin::signed(1), 0));
+		assert_eq!(paid(6), 4);
+		assert_eq!(Spends::<Test>::get(0).unwrap().status, PaymentState::Attempted { id: 1 });
+	});
+}
+
+#[test]
+fn check_status_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(Treasury::check_status(Origin::signed(1), 0), Error::<Test>::InvalidIndex);
+
+		assert_ok!(Treasury::spend(Origin::signed(10), 2, 6));
+		assert_noop!(Treasury::check_status(Origin::signed(1), 0), Error::<Test>::NotAttempted);
+
+		assert_ok!(Treasury::payout(Origin::signed(1), 0));
+		set_status(0, PaymentStatus::InProgress);
+		assert_noop!(Treasury::check_status(Origin::signed(1), 0), Error::<Test>::Inconclusive);
+
+		// An unknown status is considered concluded.
+		set_status(0, PaymentStatus::Unknown);
+		assert_ok!(Treasury::check_status(Origin::signed(1), 0));
+		assert_eq!(Spends::<Test>::get(0), None);
+	});
+}
+
+#[test]
+fn expired_spend_cannot_be_paid_out() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Treasury::spend(Origin::signed(10), 2, 6));
+
+		System::set_block_number(2 + PayoutPeriod::get());
+		assert_noop!(Treasury::payout(Origin::signed(1), 0), Error::<Test>::SpendExpired);
+
+		// Once expired, the spend can be removed from storage.
+		assert_ok!(Treasury::check_status(Origin::signed(1), 0));
+		assert_eq!(last_event(), Event::Treasury(crate::Event::SpendProcessed(0)));
+		assert_eq!(Spends::<Test>::get(0), None);
+		assert_eq!(paid(6), 0);
+	});
+}
@@ -49,6 +49,9 @@ pub trait WeightInfo {
 	fn reject_proposal() -> Weight;
 	fn approve_proposal(p: u32, ) -> Weight;
 	fn on_initialize_proposals(p: u32, ) -> Weight;
+	fn spend() -> Weight;
+	fn payout() -> Weight;
+	fn check_status() -> Weight;
 }
 
 /// Weights for pallet_treasury using the Substrate node and recommended hardware.
@@ -90,6 +93,26 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(p as Weight)))
 	}
+	// Storage: Treasury SpendCount (r:1 w:1)
+	// Storage: Treasury Spends (r:0 w:1)
+	fn spend() -> Weight {
+		(17_910_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Treasury Spends (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn payout() -> Weight {
+		(52_318_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Treasury Spends (r:1 w:1)
+	fn check_status() -> Weight {
+		(19_772_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
 }
 
 // For backwards compatibility and tests
@@ -130,4 +153,24 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
 			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(p as Weight)))
 	}
+	// Storage: Treasury SpendCount (r:1 w:1)
+	// Storage: Treasury Spends (r:0 w:1)
+	fn spend() -> Weight {
+		(17_910_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Treasury Spends (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn payout() -> Weight {
+		(52_318_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Treasury Spends (r:1 w:1)
+	fn check_status() -> Weight {
+		(19_772_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
 }
@@ -19,11 +19,13 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+pub use frame_support::traits::TryStateSelect;
 use frame_support::weights::Weight;
 use sp_std::prelude::*;
 
 sp_api::decl_runtime_apis! {
 	/// Runtime api for testing the execution of a runtime upgrade.
+	#[api_version(2)]
 	pub trait TryRuntime {
 		/// dry-run runtime upgrades, returning the total weight consumed.
 		///
@@ -38,6 +40,18 @@ sp_api::decl_runtime_apis! {
 		///
 		/// This is only sensible where the incoming block is from a different network, yet it has
 		/// the same block format as the runtime implementing this API.
+		///
+		/// Once the block is executed, the try-state checks of the pallets picked by `select` are
+		/// run against the resulting state. Returns the weight of the block, along with the weight
+		/// consumed by the try-state checks.
+		fn execute_block_no_check(block: Block, select: TryStateSelect) -> (Weight, Weight);
+		#[changed_in(2)]
 		fn execute_block_no_check(block: Block) -> Weight;
+
+		/// Run the try-state checks of the pallets picked by `select` against the current state.
+		///
+		/// Returns the weight consumed by the checks, which lets huge runtimes spread them over
+		/// multiple invocations, e.g. with [`TryStateSelect::RoundRobin`].
+		fn try_state(select: TryStateSelect) -> Weight;
 	}
 }
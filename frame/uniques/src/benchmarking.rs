@@ -379,6 +379,30 @@ benchmarks_instance_pallet! {
 	verify {
 		assert_last_event::<T, I>(Event::ApprovalCancelled(class, instance, caller, delegate).into());
 	}
+	set_price {
+		let (class, caller, _) = create_class::<T, I>();
+		let (instance, ..) = mint_instance::<T, I>(0);
+		let delegate: T::AccountId = account("delegate", 0, SEED);
+		let delegate_lookup = T::Lookup::unlookup(delegate.clone());
+		let price = InstancePrice::<T, I>::from(100u32);
+	}: _(SystemOrigin::Signed(caller.clone()), class, instance, Some(price), Some(delegate_lookup))
+	verify {
+		assert_last_event::<T, I>(Event::InstancePriceSet(class, instance, price, Some(delegate)).into());
+	}
+
+	buy_item {
+		let (class, seller, _) = create_class::<T, I>();
+		let (instance, ..) = mint_instance::<T, I>(0);
+		let buyer: T::AccountId = account("buyer", 0, SEED);
+		let buyer_lookup = T::Lookup::unlookup(buyer.clone());
+		let price = InstancePrice::<T, I>::from(0u32);
+		let origin = SystemOrigin::Signed(seller.clone()).into();
+		Uniques::<T, I>::set_price(origin, class, instance, Some(price.clone()), Some(buyer_lookup))?;
+		T::Currency::make_free_balance_be(&buyer, DepositBalanceOf::<T, I>::max_value());
+	}: _(SystemOrigin::Signed(buyer.clone()), class, instance, price.clone())
+	verify {
+		assert_last_event::<T, I>(Event::InstanceBought(class, instance, price, seller, buyer).into());
+	}
 }
 
 impl_benchmark_test_suite!(Uniques, crate::mock::new_test_ext(), crate::mock::Test);
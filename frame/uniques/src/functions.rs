@@ -18,7 +18,11 @@
 //! Various pieces of common functionality.
 
 use super::*;
-use frame_support::{ensure, traits::Get};
+use frame_support::{
+	ensure,
+	traits::{ExistenceRequirement, Get},
+	BoundedVec,
+};
 use sp_runtime::{DispatchError, DispatchResult};
 
 impl<T: Config<I>, I: 'static> Pallet<T, I> {
@@ -43,6 +47,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		let origin = details.owner;
 		details.owner = dest;
 		Asset::<T, I>::insert(&class, &instance, &details);
+		InstancePriceOf::<T, I>::remove(&class, &instance);
 
 		Self::deposit_event(Event::Transferred(class, instance, origin, details.owner));
 		Ok(())
@@ -138,8 +143,102 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 		Asset::<T, I>::remove(&class, &instance);
 		Account::<T, I>::remove((&owner, &class, &instance));
+		InstancePriceOf::<T, I>::remove(&class, &instance);
 
 		Self::deposit_event(Event::Burned(class, instance, owner));
 		Ok(())
 	}
+
+	pub(super) fn do_set_attribute(
+		class: T::ClassId,
+		maybe_instance: Option<T::InstanceId>,
+		key: BoundedVec<u8, T::KeyLimit>,
+		value: BoundedVec<u8, T::ValueLimit>,
+		maybe_check_owner: Option<T::AccountId>,
+	) -> DispatchResult {
+		let mut class_details = Class::<T, I>::get(&class).ok_or(Error::<T, I>::Unknown)?;
+		if let Some(check_owner) = &maybe_check_owner {
+			ensure!(check_owner == &class_details.owner, Error::<T, I>::NoPermission);
+		}
+		let maybe_is_frozen = match maybe_instance {
+			None => ClassMetadataOf::<T, I>::get(class).map(|v| v.is_frozen),
+			Some(instance) => InstanceMetadataOf::<T, I>::get(class, instance).map(|v| v.is_frozen),
+		};
+		ensure!(!maybe_is_frozen.unwrap_or(false), Error::<T, I>::Frozen);
+
+		let attribute = Attribute::<T, I>::get((class, maybe_instance, &key));
+		if attribute.is_none() {
+			class_details.attributes.saturating_inc();
+		}
+		let old_deposit = attribute.map_or(Zero::zero(), |m| m.1);
+		class_details.total_deposit.saturating_reduce(old_deposit);
+		let mut deposit = Zero::zero();
+		if !class_details.free_holding && maybe_check_owner.is_some() {
+			deposit = T::DepositPerByte::get()
+				.saturating_mul(((key.len() + value.len()) as u32).into())
+				.saturating_add(T::AttributeDepositBase::get());
+		}
+		class_details.total_deposit.saturating_accrue(deposit);
+		if deposit > old_deposit {
+			T::Currency::reserve(&class_details.owner, deposit - old_deposit)?;
+		} else if deposit < old_deposit {
+			T::Currency::unreserve(&class_details.owner, old_deposit - deposit);
+		}
+
+		Attribute::<T, I>::insert((&class, maybe_instance, &key), (&value, deposit));
+		Class::<T, I>::insert(class, &class_details);
+		Self::deposit_event(Event::AttributeSet(class, maybe_instance, key, value));
+		Ok(())
+	}
+
+	pub(super) fn do_set_price(
+		class: T::ClassId,
+		instance: T::InstanceId,
+		sender: T::AccountId,
+		price: Option<InstancePrice<T, I>>,
+		whitelisted_buyer: Option<T::AccountId>,
+	) -> DispatchResult {
+		let details = Asset::<T, I>::get(&class, &instance).ok_or(Error::<T, I>::Unknown)?;
+		ensure!(details.owner == sender, Error::<T, I>::NoPermission);
+
+		if let Some(ref price) = price {
+			InstancePriceOf::<T, I>::insert(&class, &instance, (price, whitelisted_buyer.clone()));
+			Self::deposit_event(Event::InstancePriceSet(
+				class,
+				instance,
+				*price,
+				whitelisted_buyer,
+			));
+		} else {
+			InstancePriceOf::<T, I>::remove(&class, &instance);
+			Self::deposit_event(Event::InstancePriceRemoved(class, instance));
+		}
+
+		Ok(())
+	}
+
+	pub(super) fn do_buy_item(
+		class: T::ClassId,
+		instance: T::InstanceId,
+		buyer: T::AccountId,
+		bid_price: InstancePrice<T, I>,
+	) -> DispatchResult {
+		let details = Asset::<T, I>::get(&class, &instance).ok_or(Error::<T, I>::Unknown)?;
+		ensure!(details.owner != buyer, Error::<T, I>::NoPermission);
+
+		let (price, maybe_buyer) =
+			InstancePriceOf::<T, I>::get(&class, &instance).ok_or(Error::<T, I>::NotForSale)?;
+		ensure!(bid_price >= price, Error::<T, I>::BidTooLow);
+		if let Some(only_buyer) = maybe_buyer {
+			ensure!(only_buyer == buyer, Error::<T, I>::NoPermission);
+		}
+
+		T::Currency::transfer(&buyer, &details.owner, price, ExistenceRequirement::KeepAlive)?;
+
+		let seller = details.owner;
+		Self::do_transfer(class, instance, buyer.clone(), |_, _| Ok(()))?;
+
+		Self::deposit_event(Event::InstanceBought(class, instance, price, seller, buyer));
+		Ok(())
+	}
 }
@@ -23,7 +23,7 @@ use frame_support::{
 		tokens::nonfungibles::{Create, Inspect, InspectEnumerable, Mutate, Transfer},
 		Get,
 	},
-	BoundedSlice,
+	BoundedSlice, BoundedVec,
 };
 use sp_runtime::DispatchResult;
 use sp_std::convert::TryFrom;
@@ -118,6 +118,31 @@ impl<T: Config<I>, I: 'static> Mutate<<T as SystemConfig>::AccountId> for Pallet
 	fn burn_from(class: &Self::ClassId, instance: &Self::InstanceId) -> DispatchResult {
 		Self::do_burn(class.clone(), instance.clone(), |_, _| Ok(()))
 	}
+
+	/// Set the attribute `key` of `instance` of `class` to `value`.
+	///
+	/// No deposit is taken for attributes set through this interface.
+	fn set_attribute(
+		class: &Self::ClassId,
+		instance: &Self::InstanceId,
+		key: &[u8],
+		value: &[u8],
+	) -> DispatchResult {
+		let key = BoundedVec::try_from(key.to_vec()).map_err(|_| Error::<T, I>::BadAttribute)?;
+		let value =
+			BoundedVec::try_from(value.to_vec()).map_err(|_| Error::<T, I>::BadAttribute)?;
+		Self::do_set_attribute(class.clone(), Some(instance.clone()), key, value, None)
+	}
+
+	/// Set the attribute `key` of `class` to `value`.
+	///
+	/// No deposit is taken for attributes set through this interface.
+	fn set_class_attribute(class: &Self::ClassId, key: &[u8], value: &[u8]) -> DispatchResult {
+		let key = BoundedVec::try_from(key.to_vec()).map_err(|_| Error::<T, I>::BadAttribute)?;
+		let value =
+			BoundedVec::try_from(value.to_vec()).map_err(|_| Error::<T, I>::BadAttribute)?;
+		Self::do_set_attribute(class.clone(), None, key, value, None)
+	}
 }
 
 impl<T: Config<I>, I: 'static> Transfer<T::AccountId> for Pallet<T, I> {
@@ -135,14 +160,14 @@ impl<T: Config<I>, I: 'static> InspectEnumerable<T::AccountId> for Pallet<T, I>
 	///
 	/// NOTE: iterating this list invokes a storage read per item.
 	fn classes() -> Box<dyn Iterator<Item = Self::ClassId>> {
-		Box::new(ClassMetadataOf::<T, I>::iter_keys())
+		Box::new(Class::<T, I>::iter_keys())
 	}
 
 	/// Returns an iterator of the instances of an asset `class` in existence.
 	///
 	/// NOTE: iterating this list invokes a storage read per item.
 	fn instances(class: &Self::ClassId) -> Box<dyn Iterator<Item = Self::InstanceId>> {
-		Box::new(InstanceMetadataOf::<T, I>::iter_key_prefix(class))
+		Box::new(Asset::<T, I>::iter_key_prefix(class))
 	}
 
 	/// Returns an iterator of the asset instances of all classes owned by `who`.
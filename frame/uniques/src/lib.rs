@@ -41,7 +41,10 @@ mod types;
 pub use types::*;
 
 use codec::{Decode, Encode, HasCompact};
-use frame_support::traits::{BalanceStatus::Reserved, Currency, ReservableCurrency};
+use frame_support::{
+	traits::{BalanceStatus::Reserved, Currency, ReservableCurrency},
+	transactional,
+};
 use frame_system::Config as SystemConfig;
 use sp_runtime::{
 	traits::{Saturating, StaticLookup, Zero},
@@ -188,6 +191,18 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	#[pallet::storage]
+	/// Price of an asset instance.
+	pub(super) type InstancePriceOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::ClassId,
+		Blake2_128Concat,
+		T::InstanceId,
+		(InstancePrice<T, I>, Option<T::AccountId>),
+		OptionQuery,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config<I>, I: 'static = ()> {
@@ -248,6 +263,12 @@ pub mod pallet {
 		/// Attribute metadata has been cleared for an asset class or instance.
 		/// \[ class, maybe_instance, key, maybe_value \]
 		AttributeCleared(T::ClassId, Option<T::InstanceId>, BoundedVec<u8, T::KeyLimit>),
+		/// The price was set for the instance. \[ class, instance, price, whitelisted_buyer \]
+		InstancePriceSet(T::ClassId, T::InstanceId, InstancePrice<T, I>, Option<T::AccountId>),
+		/// The price for the instance was removed. \[ class, instance \]
+		InstancePriceRemoved(T::ClassId, T::InstanceId),
+		/// An instance was bought. \[ class, instance, price, seller, buyer \]
+		InstanceBought(T::ClassId, T::InstanceId, InstancePrice<T, I>, T::AccountId, T::AccountId),
 	}
 
 	#[pallet::error]
@@ -272,6 +293,12 @@ pub mod pallet {
 		NoDelegate,
 		/// No approval exists that would allow the transfer.
 		Unapproved,
+		/// The attribute key or value is longer than permitted.
+		BadAttribute,
+		/// The asset instance is not for sale.
+		NotForSale,
+		/// The provided bid is too low.
+		BidTooLow,
 	}
 
 	#[pallet::hooks]
@@ -852,6 +879,58 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Set (or reset) the price for an asset instance.
+		///
+		/// Origin must be Signed and must be the owner of the asset `instance`.
+		///
+		/// - `class`: The class of the asset.
+		/// - `instance`: The instance of the asset to set the price for.
+		/// - `price`: The price for the instance. Pass `None`, to reset the price.
+		/// - `whitelisted_buyer`: Restricts the buy operation to a specific account.
+		///
+		/// Emits `InstancePriceSet` on success if the price is not `None`.
+		/// Emits `InstancePriceRemoved` on success if the price is `None`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_price())]
+		pub fn set_price(
+			origin: OriginFor<T>,
+			#[pallet::compact] class: T::ClassId,
+			#[pallet::compact] instance: T::InstanceId,
+			price: Option<InstancePrice<T, I>>,
+			whitelisted_buyer: Option<<T::Lookup as StaticLookup>::Source>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let whitelisted_buyer = whitelisted_buyer.map(T::Lookup::lookup).transpose()?;
+			Self::do_set_price(class, instance, origin, price, whitelisted_buyer)
+		}
+
+		/// Allows to buy an asset instance if it's up for sale.
+		///
+		/// Origin must be Signed and must not be the owner of the asset `instance`.
+		///
+		/// The price is transferred from the buyer to the owner and the ownership of the
+		/// instance changes hands within the same transaction.
+		///
+		/// - `class`: The class of the asset.
+		/// - `instance`: The instance the sender wants to buy.
+		/// - `bid_price`: The price the sender is willing to pay.
+		///
+		/// Emits `InstanceBought` on success.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::buy_item())]
+		#[transactional]
+		pub fn buy_item(
+			origin: OriginFor<T>,
+			#[pallet::compact] class: T::ClassId,
+			#[pallet::compact] instance: T::InstanceId,
+			bid_price: InstancePrice<T, I>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_buy_item(class, instance, origin, bid_price)
+		}
+
 		/// Alter the attributes of a given asset.
 		///
 		/// Origin must be `ForceOrigin`.
@@ -903,7 +982,7 @@ pub mod pallet {
 		/// asset `class`.
 		///
 		/// If the origin is Signed, then funds of signer are reserved according to the formula:
-		/// `MetadataDepositBase + DepositPerByte * (key.len + value.len)` taking into
+		/// `AttributeDepositBase + DepositPerByte * (key.len + value.len)` taking into
 		/// account any already reserved funds.
 		///
 		/// - `class`: The identifier of the asset class whose instance's metadata to set.
@@ -926,57 +1005,21 @@ pub mod pallet {
 				.map(|_| None)
 				.or_else(|origin| ensure_signed(origin).map(Some))?;
 
-			let mut class_details = Class::<T, I>::get(&class).ok_or(Error::<T, I>::Unknown)?;
-			if let Some(check_owner) = &maybe_check_owner {
-				ensure!(check_owner == &class_details.owner, Error::<T, I>::NoPermission);
-			}
-			let maybe_is_frozen = match maybe_instance {
-				None => ClassMetadataOf::<T, I>::get(class).map(|v| v.is_frozen),
-				Some(instance) =>
-					InstanceMetadataOf::<T, I>::get(class, instance).map(|v| v.is_frozen),
-			};
-			ensure!(!maybe_is_frozen.unwrap_or(false), Error::<T, I>::Frozen);
-
-			let attribute = Attribute::<T, I>::get((class, maybe_instance, &key));
-			if attribute.is_none() {
-				class_details.attributes.saturating_inc();
-			}
-			let old_deposit = attribute.map_or(Zero::zero(), |m| m.1);
-			class_details.total_deposit.saturating_reduce(old_deposit);
-			let mut deposit = Zero::zero();
-			if !class_details.free_holding && maybe_check_owner.is_some() {
-				deposit = T::DepositPerByte::get()
-					.saturating_mul(((key.len() + value.len()) as u32).into())
-					.saturating_add(T::AttributeDepositBase::get());
-			}
-			class_details.total_deposit.saturating_accrue(deposit);
-			if deposit > old_deposit {
-				T::Currency::reserve(&class_details.owner, deposit - old_deposit)?;
-			} else if deposit < old_deposit {
-				T::Currency::unreserve(&class_details.owner, old_deposit - deposit);
-			}
-
-			Attribute::<T, I>::insert((&class, maybe_instance, &key), (&value, deposit));
-			Class::<T, I>::insert(class, &class_details);
-			Self::deposit_event(Event::AttributeSet(class, maybe_instance, key, value));
-			Ok(())
+			Self::do_set_attribute(class, maybe_instance, key, value, maybe_check_owner)
 		}
 
-		/// Set an attribute for an asset class or instance.
+		/// Clear an attribute for an asset class or instance.
 		///
 		/// Origin must be either `ForceOrigin` or Signed and the sender should be the Owner of the
 		/// asset `class`.
 		///
-		/// If the origin is Signed, then funds of signer are reserved according to the formula:
-		/// `MetadataDepositBase + DepositPerByte * (key.len + value.len)` taking into
-		/// account any already reserved funds.
+		/// Any deposit is freed for the asset class owner.
 		///
-		/// - `class`: The identifier of the asset class whose instance's metadata to set.
-		/// - `instance`: The identifier of the asset instance whose metadata to set.
+		/// - `class`: The identifier of the asset class whose instance's metadata to clear.
+		/// - `maybe_instance`: The identifier of the asset instance whose metadata to clear.
 		/// - `key`: The key of the attribute.
-		/// - `value`: The value to which to set the attribute.
 		///
-		/// Emits `AttributeSet`.
+		/// Emits `AttributeCleared`.
 		///
 		/// Weight: `O(1)`
 		#[pallet::weight(T::WeightInfo::clear_attribute())]
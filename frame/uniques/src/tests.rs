@@ -48,6 +48,18 @@ fn assets() -> Vec<(u64, u32, u32)> {
 	r
 }
 
+fn events() -> Vec<crate::Event<Test>> {
+	let result = System::events()
+		.into_iter()
+		.map(|r| r.event)
+		.filter_map(|e| if let mock::Event::Uniques(inner) = e { Some(inner) } else { None })
+		.collect::<Vec<_>>();
+
+	System::reset_events();
+
+	result
+}
+
 macro_rules! bvec {
 	($( $x:tt )*) => {
 		vec![$( $x )*].try_into().unwrap()
@@ -428,6 +440,45 @@ fn set_attribute_should_respect_freeze() {
 	});
 }
 
+#[test]
+fn nonfungibles_set_attribute_should_work() {
+	use frame_support::traits::tokens::nonfungibles::{Inspect, Mutate};
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+
+		assert_ok!(Uniques::force_create(Origin::root(), 0, 1, false));
+		assert_ok!(<Uniques as Mutate<_>>::set_class_attribute(&0, &[0], &[1]));
+		assert_ok!(<Uniques as Mutate<_>>::set_attribute(&0, &42, &[0], &[2]));
+		assert_eq!(attributes(0), vec![(None, bvec![0], bvec![1]), (Some(42), bvec![0], bvec![2])]);
+		assert_eq!(<Uniques as Inspect<_>>::class_attribute(&0, &[0]), Some(vec![1]));
+		assert_eq!(<Uniques as Inspect<_>>::attribute(&0, &42, &[0]), Some(vec![2]));
+		assert_eq!(Class::<Test>::get(0).unwrap().attributes, 2);
+		assert_eq!(Balances::reserved_balance(1), 0);
+
+		assert_noop!(
+			<Uniques as Mutate<_>>::set_attribute(&0, &42, &[0; 51], &[0]),
+			Error::<Test>::BadAttribute
+		);
+		assert_noop!(
+			<Uniques as Mutate<_>>::set_class_attribute(&1, &[0], &[0]),
+			Error::<Test>::Unknown
+		);
+	});
+}
+
+#[test]
+fn nonfungibles_enumeration_should_not_require_metadata() {
+	use frame_support::traits::tokens::nonfungibles::InspectEnumerable;
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+
+		assert_ok!(Uniques::force_create(Origin::root(), 0, 1, true));
+		assert_ok!(Uniques::mint(Origin::signed(1), 0, 42, 2));
+		assert_eq!(<Uniques as InspectEnumerable<_>>::classes().collect::<Vec<_>>(), vec![0]);
+		assert_eq!(<Uniques as InspectEnumerable<_>>::instances(&0).collect::<Vec<_>>(), vec![42]);
+	});
+}
+
 #[test]
 fn force_asset_status_should_work() {
 	new_test_ext().execute_with(|| {
@@ -581,3 +632,159 @@ fn cancel_approval_works_with_force() {
 		);
 	});
 }
+
+#[test]
+fn set_price_should_work() {
+	new_test_ext().execute_with(|| {
+		let user_1 = 1;
+		let user_2 = 2;
+		let class_id = 0;
+		let instance_1 = 1;
+		let instance_2 = 2;
+
+		assert_ok!(Uniques::force_create(Origin::root(), class_id, user_1, true));
+		assert_ok!(Uniques::mint(Origin::signed(user_1), class_id, instance_1, user_1));
+		assert_ok!(Uniques::mint(Origin::signed(user_1), class_id, instance_2, user_1));
+
+		assert_ok!(Uniques::set_price(Origin::signed(user_1), class_id, instance_1, Some(1), None));
+		assert_ok!(Uniques::set_price(
+			Origin::signed(user_1),
+			class_id,
+			instance_2,
+			Some(2),
+			Some(user_2)
+		));
+
+		assert_eq!(InstancePriceOf::<Test>::get(class_id, instance_1), Some((1, None)));
+		assert_eq!(InstancePriceOf::<Test>::get(class_id, instance_2), Some((2, Some(user_2))));
+		assert!(events().contains(&crate::Event::<Test>::InstancePriceSet(
+			class_id,
+			instance_2,
+			2,
+			Some(user_2)
+		)));
+
+		// validate we can unset the price
+		assert_ok!(Uniques::set_price(Origin::signed(user_1), class_id, instance_2, None, None));
+		assert!(events().contains(&crate::Event::<Test>::InstancePriceRemoved(class_id, instance_2)));
+		assert!(!InstancePriceOf::<Test>::contains_key(class_id, instance_2));
+
+		// only the owner may set a price
+		assert_noop!(
+			Uniques::set_price(Origin::signed(user_2), class_id, instance_1, Some(1), None),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn buy_item_should_work() {
+	new_test_ext().execute_with(|| {
+		let user_1 = 1;
+		let user_2 = 2;
+		let user_3 = 3;
+		let class_id = 0;
+		let instance_1 = 1;
+		let instance_2 = 2;
+		let instance_3 = 3;
+		let price_1 = 20;
+		let price_2 = 30;
+		let initial_balance = 100;
+
+		Balances::make_free_balance_be(&user_1, initial_balance);
+		Balances::make_free_balance_be(&user_2, initial_balance);
+		Balances::make_free_balance_be(&user_3, initial_balance);
+
+		assert_ok!(Uniques::force_create(Origin::root(), class_id, user_1, true));
+		assert_ok!(Uniques::mint(Origin::signed(user_1), class_id, instance_1, user_1));
+		assert_ok!(Uniques::mint(Origin::signed(user_1), class_id, instance_2, user_1));
+		assert_ok!(Uniques::mint(Origin::signed(user_1), class_id, instance_3, user_1));
+
+		assert_ok!(Uniques::set_price(
+			Origin::signed(user_1),
+			class_id,
+			instance_1,
+			Some(price_1),
+			None,
+		));
+		assert_ok!(Uniques::set_price(
+			Origin::signed(user_1),
+			class_id,
+			instance_2,
+			Some(price_2),
+			Some(user_3),
+		));
+
+		// can't buy for less
+		assert_noop!(
+			Uniques::buy_item(Origin::signed(user_2), class_id, instance_1, 1),
+			Error::<Test>::BidTooLow
+		);
+
+		// pass the higher price to validate it will still deduct correctly
+		assert_ok!(Uniques::buy_item(Origin::signed(user_2), class_id, instance_1, price_1 + 1));
+
+		// validate the new owner & balances
+		let item = Asset::<Test>::get(class_id, instance_1).unwrap();
+		assert_eq!(item.owner, user_2);
+		assert_eq!(Balances::total_balance(&user_1), initial_balance + price_1);
+		assert_eq!(Balances::total_balance(&user_2), initial_balance - price_1);
+
+		// can't buy from yourself
+		assert_noop!(
+			Uniques::buy_item(Origin::signed(user_1), class_id, instance_2, price_2),
+			Error::<Test>::NoPermission
+		);
+
+		// can't buy when the item is listed for a specific buyer
+		assert_noop!(
+			Uniques::buy_item(Origin::signed(user_2), class_id, instance_2, price_2),
+			Error::<Test>::NoPermission
+		);
+
+		// can buy when I'm a whitelisted buyer
+		assert_ok!(Uniques::buy_item(Origin::signed(user_3), class_id, instance_2, price_2));
+
+		assert!(events().contains(&crate::Event::<Test>::InstanceBought(
+			class_id, instance_2, price_2, user_1, user_3
+		)));
+
+		// ensure we reset the buyer field
+		assert!(!InstancePriceOf::<Test>::contains_key(class_id, instance_2));
+
+		// can't buy when item is not for sale
+		assert_noop!(
+			Uniques::buy_item(Origin::signed(user_2), class_id, instance_3, price_2),
+			Error::<Test>::NotForSale
+		);
+
+		// ensure we can't buy an item when the class or an instance is frozen
+		{
+			assert_ok!(Uniques::set_price(
+				Origin::signed(user_1),
+				class_id,
+				instance_3,
+				Some(price_1),
+				None,
+			));
+
+			// freeze class
+			assert_ok!(Uniques::freeze_class(Origin::signed(user_1), class_id));
+
+			assert_noop!(
+				Uniques::buy_item(Origin::signed(user_2), class_id, instance_3, price_1),
+				Error::<Test>::Frozen
+			);
+
+			assert_ok!(Uniques::thaw_class(Origin::signed(user_1), class_id));
+
+			// freeze instance
+			assert_ok!(Uniques::freeze(Origin::signed(user_1), class_id, instance_3));
+
+			assert_noop!(
+				Uniques::buy_item(Origin::signed(user_2), class_id, instance_3, price_1),
+				Error::<Test>::Frozen
+			);
+		}
+	});
+}
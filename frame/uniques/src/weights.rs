@@ -67,6 +67,8 @@ pub trait WeightInfo {
 	fn clear_class_metadata() -> Weight;
 	fn approve_transfer() -> Weight;
 	fn cancel_approval() -> Weight;
+	fn set_price() -> Weight;
+	fn buy_item() -> Weight;
 }
 
 /// Weights for pallet_uniques using the Substrate node and recommended hardware.
@@ -243,6 +245,23 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	// Storage: Uniques Asset (r:1 w:0)
+	// Storage: Uniques InstancePriceOf (r:0 w:1)
+	fn set_price() -> Weight {
+		(28_457_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: Uniques InstancePriceOf (r:1 w:1)
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Uniques Account (r:0 w:2)
+	fn buy_item() -> Weight {
+		(66_025_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
 }
 
 // For backwards compatibility and tests
@@ -418,4 +437,21 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
+	// Storage: Uniques Asset (r:1 w:0)
+	// Storage: Uniques InstancePriceOf (r:0 w:1)
+	fn set_price() -> Weight {
+		(28_457_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: Uniques InstancePriceOf (r:1 w:1)
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Uniques Account (r:0 w:2)
+	fn buy_item() -> Weight {
+		(66_025_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
 }
@@ -0,0 +1,136 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Mock for Whitelist Pallet
+
+#![cfg(test)]
+
+use crate::{self as pallet_whitelist, *};
+
+use frame_support::{
+	parameter_types,
+	traits::{ConstU32, Nothing},
+	BoundedVec,
+};
+use frame_system::EnsureRoot;
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, IdentityLookup},
+};
+use sp_std::collections::{btree_map::BTreeMap, btree_set::BTreeSet};
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Whitelist: pallet_whitelist::{Pallet, Call, Storage, Event<T>},
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub BlockWeights: frame_system::limits::BlockWeights =
+		frame_system::limits::BlockWeights::simple_max(1024);
+	pub static Preimages: BTreeMap<H256, Vec<u8>> = BTreeMap::new();
+	pub static PreimageRequests: BTreeSet<H256> = BTreeSet::new();
+}
+impl frame_system::Config for Test {
+	type BaseCallFilter = Nothing;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type Origin = Origin;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Call = Call;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+}
+
+/// A simple in-memory preimage store.
+pub struct TestPreimage;
+impl PreimageProvider<H256> for TestPreimage {
+	fn have_preimage(hash: &H256) -> bool {
+		Preimages::get().contains_key(hash)
+	}
+	fn get_preimage(hash: &H256) -> Option<Vec<u8>> {
+		Preimages::get().get(hash).cloned()
+	}
+	fn preimage_requested(hash: &H256) -> bool {
+		PreimageRequests::get().contains(hash)
+	}
+	fn request_preimage(hash: &H256) {
+		let mut requests = PreimageRequests::get();
+		requests.insert(*hash);
+		PreimageRequests::set(requests);
+	}
+	fn unrequest_preimage(hash: &H256) {
+		let mut requests = PreimageRequests::get();
+		requests.remove(hash);
+		PreimageRequests::set(requests);
+	}
+}
+impl PreimageRecipient<H256> for TestPreimage {
+	type MaxSize = ConstU32<{ 4 * 1024 * 1024 }>;
+
+	fn note_preimage(bytes: BoundedVec<u8, Self::MaxSize>) {
+		let mut preimages = Preimages::get();
+		preimages.insert(BlakeTwo256::hash(&bytes[..]), bytes.into_inner());
+		Preimages::set(preimages);
+	}
+	fn unnote_preimage(hash: &H256) {
+		let mut preimages = Preimages::get();
+		preimages.remove(hash);
+		Preimages::set(preimages);
+	}
+}
+
+impl pallet_whitelist::Config for Test {
+	type Event = Event;
+	type Call = Call;
+	type WhitelistOrigin = EnsureRoot<Self::AccountId>;
+	type DispatchWhitelistedOrigin = EnsureRoot<Self::AccountId>;
+	type PreimageProvider = TestPreimage;
+	type WeightInfo = ();
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
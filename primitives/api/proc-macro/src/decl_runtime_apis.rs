@@ -19,7 +19,7 @@ use crate::utils::{
 	extract_parameter_names_types_and_borrows, fold_fn_decl_for_client_side,
 	generate_call_api_at_fn_name, generate_crate_access, generate_hidden_includes,
 	generate_method_runtime_api_impl_name, generate_native_call_generator_fn_name,
-	generate_runtime_mod_name_for_trait, prefix_function_with_trait,
+	generate_runtime_mod_name_for_trait, parse_runtime_api_version, prefix_function_with_trait,
 	replace_wild_card_parameter_names, return_type_extract_type, AllowSelfRefInParameters,
 };
 
@@ -51,7 +51,8 @@ const HIDDEN_INCLUDES_ID: &str = "DECL_RUNTIME_APIS";
 const CORE_TRAIT_ATTRIBUTE: &str = "core_trait";
 /// The `api_version` attribute.
 ///
-/// Is used to set the current version of the trait.
+/// Is used to set the current version of the trait. When put on a method, it declares the version
+/// of the trait the method was added in.
 const API_VERSION_ATTRIBUTE: &str = "api_version";
 /// The `changed_in` attribute.
 ///
@@ -463,13 +464,24 @@ fn generate_runtime_decls(decls: &[ItemTrait]) -> Result<TokenStream> {
 			.iter_mut()
 			.filter_map(|i| match i {
 				TraitItem::Method(ref mut method) => {
-					if remove_supported_attributes(&mut method.attrs)
-						.contains_key(CHANGED_IN_ATTRIBUTE)
-					{
+					let method_attrs = remove_supported_attributes(&mut method.attrs);
+					if method_attrs.contains_key(CHANGED_IN_ATTRIBUTE) {
 						None
 					} else {
 						// Make sure we replace all the wild card parameter names.
 						replace_wild_card_parameter_names(&mut method.sig);
+
+						// Methods that were added in a later version are only required to be
+						// implemented by runtimes that implement this version of the trait.
+						if method_attrs.contains_key(API_VERSION_ATTRIBUTE) {
+							let panic = format!(
+								"`{}` is not implemented by this version of the runtime api",
+								method.sig.ident,
+							);
+							method.default = Some(parse_quote!({ unimplemented!(#panic) }));
+							method.attrs.push(parse_quote!( #[allow(unused_variables)] ));
+						}
+
 						Some(TraitItem::Method(method.clone()))
 					}
 				},
@@ -562,7 +574,8 @@ impl<'a> ToClientSideDecl<'a> {
 		&mut self,
 		mut method: TraitItemMethod,
 	) -> Option<TraitItemMethod> {
-		if remove_supported_attributes(&mut method.attrs).contains_key(CHANGED_IN_ATTRIBUTE) {
+		let found_attributes = remove_supported_attributes(&mut method.attrs);
+		if found_attributes.contains_key(CHANGED_IN_ATTRIBUTE) {
 			return None
 		}
 
@@ -590,6 +603,25 @@ impl<'a> ToClientSideDecl<'a> {
 		let block_id = self.block_id;
 		let crate_ = self.crate_;
 
+		// A method that was added in a later version of the trait does not need to be
+		// implemented by every runtime, so we return an error if it is missing.
+		if found_attributes.contains_key(API_VERSION_ATTRIBUTE) {
+			let function = prefix_function_with_trait(&self.trait_, &method.sig.ident);
+
+			return Some(parse_quote! {
+				#[doc(hidden)]
+				fn #name(
+					&self,
+					_: &#block_id,
+					_: #crate_::ExecutionContext,
+					_: Option<( #( #param_types ),* )>,
+					_: Vec<u8>,
+				) -> std::result::Result<#crate_::NativeOrEncoded<#ret_type>, #crate_::ApiError> {
+					Err(#crate_::ApiError::FunctionNotImplemented { function: #function })
+				}
+			})
+		}
+
 		Some(parse_quote! {
 			#[doc(hidden)]
 			fn #name(
@@ -724,31 +756,6 @@ impl<'a> Fold for ToClientSideDecl<'a> {
 	}
 }
 
-/// Parse the given attribute as `API_VERSION_ATTRIBUTE`.
-fn parse_runtime_api_version(version: &Attribute) -> Result<u64> {
-	let meta = version.parse_meta()?;
-
-	let err = Err(Error::new(
-		meta.span(),
-		&format!(
-			"Unexpected `{api_version}` attribute. The supported format is `{api_version}(1)`",
-			api_version = API_VERSION_ATTRIBUTE
-		),
-	));
-
-	match meta {
-		Meta::List(list) =>
-			if list.nested.len() != 1 {
-				err
-			} else if let Some(NestedMeta::Lit(Lit::Int(i))) = list.nested.first() {
-				i.base10_parse()
-			} else {
-				err
-			},
-		_ => err,
-	}
-}
-
 /// Generates the identifier as const variable for the given `trait_name`
 /// by hashing the `trait_name`.
 fn generate_runtime_api_id(trait_name: &str) -> TokenStream {
@@ -798,7 +805,7 @@ fn generate_runtime_info_impl(trait_: &ItemTrait, version: u64) -> TokenStream {
 fn get_changed_in(found_attributes: &HashMap<&'static str, Attribute>) -> Result<Option<u64>> {
 	found_attributes
 		.get(&CHANGED_IN_ATTRIBUTE)
-		.map(|v| parse_runtime_api_version(v).map(Some))
+		.map(|v| v.parse_meta().and_then(|m| parse_runtime_api_version(&m)).map(Some))
 		.unwrap_or(Ok(None))
 }
 
@@ -806,10 +813,20 @@ fn get_changed_in(found_attributes: &HashMap<&'static str, Attribute>) -> Result
 fn get_api_version(found_attributes: &HashMap<&'static str, Attribute>) -> Result<u64> {
 	found_attributes
 		.get(&API_VERSION_ATTRIBUTE)
-		.map(parse_runtime_api_version)
+		.map(|v| v.parse_meta().and_then(|m| parse_runtime_api_version(&m)))
 		.unwrap_or(Ok(1))
 }
 
+/// Get the version a method was added in or `Ok(None)`, if no `api_version` attribute was given.
+fn get_method_api_version(
+	found_attributes: &HashMap<&'static str, Attribute>,
+) -> Result<Option<u64>> {
+	found_attributes
+		.get(&API_VERSION_ATTRIBUTE)
+		.map(|v| v.parse_meta().and_then(|m| parse_runtime_api_version(&m)).map(Some))
+		.unwrap_or(Ok(None))
+}
+
 /// Generate the declaration of the trait for the client side.
 fn generate_client_side_decls(decls: &[ItemTrait]) -> Result<TokenStream> {
 	let mut result = Vec::new();
@@ -854,10 +871,22 @@ impl CheckTraitDecl {
 	///
 	/// All errors will be collected in `self.errors`.
 	fn check(&mut self, trait_: &ItemTrait) {
-		self.check_method_declarations(trait_.items.iter().filter_map(|i| match i {
-			TraitItem::Method(method) => Some(method),
-			_ => None,
-		}));
+		let trait_version =
+			match get_api_version(&remove_supported_attributes(&mut trait_.attrs.clone())) {
+				Ok(v) => v,
+				Err(e) => {
+					self.errors.push(e);
+					return
+				},
+			};
+
+		self.check_method_declarations(
+			trait_version,
+			trait_.items.iter().filter_map(|i| match i {
+				TraitItem::Method(method) => Some(method),
+				_ => None,
+			}),
+		);
 
 		visit::visit_item_trait(self, trait_);
 	}
@@ -867,6 +896,7 @@ impl CheckTraitDecl {
 	/// Any error is stored in `self.errors`.
 	fn check_method_declarations<'a>(
 		&mut self,
+		trait_version: u64,
 		methods: impl Iterator<Item = &'a TraitItemMethod>,
 	) {
 		let mut method_to_signature_changed = HashMap::<Ident, Vec<Option<u64>>>::new();
@@ -882,6 +912,24 @@ impl CheckTraitDecl {
 				},
 			};
 
+			match get_method_api_version(&attributes) {
+				Ok(Some(_)) if changed_in.is_some() => self.errors.push(Error::new(
+					method.span(),
+					format!(
+						"`{}` and `{}` are not supported at once.",
+						API_VERSION_ATTRIBUTE, CHANGED_IN_ATTRIBUTE
+					),
+				)),
+				Ok(Some(version)) if version <= trait_version => self.errors.push(Error::new(
+					method.span(),
+					"`api_version` of a method needs to be greater than the `api_version` of \
+						the trait. Methods that are part of the current trait version don't \
+						require the attribute.",
+				)),
+				Ok(_) => {},
+				Err(e) => self.errors.push(e),
+			}
+
 			method_to_signature_changed
 				.entry(method.sig.ident.clone())
 				.or_default()
@@ -20,8 +20,8 @@ use crate::utils::{
 	extract_parameter_names_types_and_borrows, generate_call_api_at_fn_name, generate_crate_access,
 	generate_hidden_includes, generate_method_runtime_api_impl_name,
 	generate_native_call_generator_fn_name, generate_runtime_mod_name_for_trait,
-	prefix_function_with_trait, return_type_extract_type, AllowSelfRefInParameters,
-	RequireQualifiedTraitPath,
+	parse_runtime_api_version, prefix_function_with_trait, return_type_extract_type,
+	AllowSelfRefInParameters, RequireQualifiedTraitPath,
 };
 
 use proc_macro2::{Span, TokenStream};
@@ -33,8 +33,8 @@ use syn::{
 	parse::{Error, Parse, ParseStream, Result},
 	parse_macro_input, parse_quote,
 	spanned::Spanned,
-	Attribute, GenericArgument, Ident, ImplItem, ItemImpl, Path, PathArguments, Signature, Type,
-	TypePath,
+	Attribute, GenericArgument, Ident, ImplItem, ItemImpl, Meta, NestedMeta, Path, PathArguments,
+	Signature, Type, TypePath,
 };
 
 use std::collections::HashSet;
@@ -42,6 +42,11 @@ use std::collections::HashSet;
 /// Unique identifier used to make the hidden includes unique for this macro.
 const HIDDEN_INCLUDES_ID: &str = "IMPL_RUNTIME_APIS";
 
+/// The `api_version` attribute.
+///
+/// Is used to set the version of the trait that is implemented by the runtime.
+const API_VERSION_ATTRIBUTE: &str = "api_version";
+
 /// The structure used for parsing the runtime api implementations.
 struct RuntimeApiImpls {
 	impls: Vec<ItemImpl>,
@@ -118,11 +123,14 @@ fn generate_impl_calls(
 				let impl_call =
 					generate_impl_call(&method.sig, &impl_.self_ty, input, &impl_trait)?;
 
+				let mut attrs = filter_cfg_attrs(&impl_.attrs);
+				attrs.extend(filter_cfg_attrs(&method.attrs));
+
 				impl_calls.push((
 					impl_trait_ident.clone(),
 					method.sig.ident.clone(),
 					impl_call,
-					filter_cfg_attrs(&impl_.attrs),
+					attrs,
 				));
 			}
 		}
@@ -652,21 +660,40 @@ fn generate_runtime_api_versions(impls: &[ItemImpl]) -> Result<TokenStream> {
 		let id: Path = parse_quote!( #path ID );
 		let version: Path = parse_quote!( #path VERSION );
 		let attrs = filter_cfg_attrs(&impl_.attrs);
+		let api_version = extract_api_version(&impl_.attrs)?;
+
+		// Every version is paired with the `cfg` predicate that needs to hold for it to be used.
+		let versions = match (api_version.custom, api_version.feature_gated) {
+			(custom, Some((predicate, gated))) => {
+				let default = custom.map(|v| quote!(#v)).unwrap_or_else(|| quote!(#version));
+				vec![
+					(Some(quote!(#[cfg(#predicate)])), quote!(#gated)),
+					(Some(quote!(#[cfg(not(#predicate))])), default),
+				]
+			},
+			(Some(custom), None) => vec![(None, quote!(#custom))],
+			(None, None) => vec![(None, quote!(#version))],
+		};
 
-		result.push(quote!(
-			#( #attrs )*
-			(#id, #version)
-		));
-
-		sections.push(quote!(
-			#( #attrs )*
-			const _: () = {
-				// All sections with the same name are going to be merged by concatenation.
-				#[cfg(not(feature = "std"))]
-				#[link_section = "runtime_apis"]
-				static SECTION_CONTENTS: [u8; 12] = #c::serialize_runtime_api_info(#id, #version);
-			};
-		));
+		for (cfg, version) in versions {
+			result.push(quote!(
+				#( #attrs )*
+				#cfg
+				(#id, #version)
+			));
+
+			sections.push(quote!(
+				#( #attrs )*
+				#cfg
+				const _: () = {
+					// All sections with the same name are going to be merged by concatenation.
+					#[cfg(not(feature = "std"))]
+					#[link_section = "runtime_apis"]
+					static SECTION_CONTENTS: [u8; 12] =
+						#c::serialize_runtime_api_info(#id, #version);
+				};
+			));
+		}
 	}
 
 	Ok(quote!(
@@ -721,6 +748,59 @@ fn filter_cfg_attrs(attrs: &[Attribute]) -> Vec<Attribute> {
 	attrs.into_iter().filter(|a| a.path.is_ident("cfg")).cloned().collect()
 }
 
+/// The version of a runtime api trait implementation as given by the user.
+#[derive(Default)]
+struct ApiVersion {
+	/// The version set with `#[api_version(N)]`.
+	custom: Option<u32>,
+	/// The version set with `#[cfg_attr(PREDICATE, api_version(N))]` together with
+	/// `PREDICATE`.
+	feature_gated: Option<(TokenStream, u32)>,
+}
+
+/// Extract the `api_version` attributes of a runtime api trait implementation.
+///
+/// Besides `#[api_version(N)]`, the version can also be set behind a feature gate with
+/// `#[cfg_attr(feature = "staging", api_version(N))]`.
+fn extract_api_version(attrs: &[Attribute]) -> Result<ApiVersion> {
+	let mut result = ApiVersion::default();
+
+	for attr in attrs {
+		if attr.path.is_ident(API_VERSION_ATTRIBUTE) {
+			if result.custom.is_some() {
+				return Err(Error::new(attr.span(), "Found multiple `api_version` attributes!"))
+			}
+
+			result.custom = Some(parse_runtime_api_version(&attr.parse_meta()?)? as u32);
+		} else if attr.path.is_ident("cfg_attr") {
+			let list = match attr.parse_meta()? {
+				Meta::List(list) => list,
+				_ => continue,
+			};
+
+			let mut nested = list.nested.iter();
+			let (predicate, version) = match (nested.next(), nested.next(), nested.next()) {
+				(Some(NestedMeta::Meta(predicate)), Some(NestedMeta::Meta(version)), None)
+					if version.path().is_ident(API_VERSION_ATTRIBUTE) =>
+					(predicate, version),
+				_ => continue,
+			};
+
+			if result.feature_gated.is_some() {
+				return Err(Error::new(
+					attr.span(),
+					"Found multiple feature gated `api_version` attributes!",
+				))
+			}
+
+			result.feature_gated =
+				Some((quote!(#predicate), parse_runtime_api_version(version)? as u32));
+		}
+	}
+
+	Ok(result)
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -19,7 +19,8 @@ use proc_macro2::{Span, TokenStream};
 
 use syn::{
 	parse_quote, spanned::Spanned, token::And, Error, FnArg, GenericArgument, Ident, ImplItem,
-	ItemImpl, Pat, Path, PathArguments, Result, ReturnType, Signature, Type, TypePath,
+	ItemImpl, Lit, Meta, NestedMeta, Pat, Path, PathArguments, Result, ReturnType, Signature, Type,
+	TypePath,
 };
 
 use quote::quote;
@@ -271,3 +272,23 @@ pub fn extract_impl_trait<'a>(
 			}
 		})
 }
+
+/// Parse the given `Meta` as version attribute, e.g. `api_version(1)`.
+pub fn parse_runtime_api_version(version: &Meta) -> Result<u64> {
+	let err = Err(Error::new(
+		version.span(),
+		"Unexpected `api_version` attribute. The supported format is `api_version(1)`",
+	));
+
+	match version {
+		Meta::List(list) =>
+			if list.nested.len() != 1 {
+				err
+			} else if let Some(NestedMeta::Lit(Lit::Int(i))) = list.nested.first() {
+				i.base10_parse()
+			} else {
+				err
+			},
+		_ => err,
+	}
+}
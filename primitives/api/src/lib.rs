@@ -185,6 +185,34 @@ pub const MAX_EXTRINSIC_DEPTH: u32 = 256;
 /// To check if a given runtime implements a runtime api trait, the `RuntimeVersion` has the
 /// function `has_api<A>()`. Also the `ApiExt` provides a function `has_api<A>(at: &BlockId)`
 /// to check if the runtime at the given block id implements the requested runtime api trait.
+///
+/// # Versioned methods
+///
+/// A method can also be added to a trait without bumping the version of the whole trait, by
+/// putting the `#[api_version(3)]` attribute above the method. The version needs to be
+/// greater than the version of the trait. Runtimes that implement the trait at a lower
+/// version don't need to implement this method; runtimes that implement it need to declare
+/// the version of the trait they implement with `#[api_version(3)]` on the implementation in
+/// [`impl_runtime_apis!`](macro.impl_runtime_apis.html). Before calling such a method, the
+/// caller should check the version of the trait at the given block with
+/// `ApiExt::api_version<A>(at: &BlockId)`. Calling a method that is not implemented by the
+/// runtime api implementation returns [`ApiError::FunctionNotImplemented`].
+///
+/// ```rust
+/// sp_api::decl_runtime_apis! {
+///     /// Declare the api trait.
+///     #[api_version(2)]
+///     pub trait Balance {
+///         /// Get the balance.
+///         fn get_balance() -> u64;
+///         /// Only available with version 3 of the trait.
+///         #[api_version(3)]
+///         fn get_reserved_balance() -> u64;
+///     }
+/// }
+///
+/// # fn main() {}
+/// ```
 pub use sp_api_proc_macro::decl_runtime_apis;
 
 /// Tags given trait implementations as runtime apis.
@@ -205,6 +233,13 @@ pub use sp_api_proc_macro::decl_runtime_apis;
 /// `RUNTIME_API_VERSIONS` is generated. This constant should be used to instantiate the `apis`
 /// field of `RuntimeVersion`.
 ///
+/// By default, a trait implementation is exposed with the version of the trait declaration. An
+/// implementation that also implements the methods of a later version (see
+/// [`decl_runtime_apis!`](macro.decl_runtime_apis.html)) needs to declare this version with
+/// `#[api_version(N)]`. To only implement these methods behind a feature, e.g. for staging
+/// them on a test network, use `#[cfg_attr(feature = "staging", api_version(N))]` and put
+/// `#[cfg(feature = "staging")]` on the methods that belong to this version.
+///
 /// # Example
 ///
 /// ```rust
@@ -453,6 +488,8 @@ pub enum ApiError {
 		#[source]
 		error: codec::Error,
 	},
+	#[error("The function {function} is not implemented by the runtime api implementation")]
+	FunctionNotImplemented { function: &'static str },
 	#[error(transparent)]
 	Application(#[from] Box<dyn std::error::Error + Send + Sync>),
 }
@@ -47,6 +47,18 @@ decl_runtime_apis! {
 		#[changed_in(2)]
 		fn same_name() -> String;
 	}
+
+	pub trait ApiWithStagingMethod {
+		fn stable_one(data: u64);
+		#[api_version(2)]
+		fn staging_one();
+	}
+
+	pub trait ApiWithUnimplementedStagingMethod {
+		fn stable_one(data: u64);
+		#[api_version(2)]
+		fn staging_one();
+	}
 }
 
 impl_runtime_apis! {
@@ -72,6 +84,17 @@ impl_runtime_apis! {
 		fn same_name() {}
 	}
 
+	#[api_version(2)]
+	impl self::ApiWithStagingMethod<Block> for Runtime {
+		fn stable_one(_: u64) {}
+
+		fn staging_one() {}
+	}
+
+	impl self::ApiWithUnimplementedStagingMethod<Block> for Runtime {
+		fn stable_one(_: u64) {}
+	}
+
 	impl sp_api::Core<Block> for Runtime {
 		fn version() -> sp_version::RuntimeVersion {
 			unimplemented!()
@@ -132,6 +155,10 @@ mock_impl_runtime_apis! {
 	impl ApiWithCustomVersion<Block> for MockApi {
 		fn same_name() {}
 	}
+
+	impl ApiWithUnimplementedStagingMethod<Block> for MockApi {
+		fn stable_one(_: u64) {}
+	}
 }
 
 type TestClient = substrate_test_runtime_client::client::Client<
@@ -187,6 +214,13 @@ fn check_runtime_api_versions() {
 	check_runtime_api_versions_contains::<dyn Api<Block>>();
 	check_runtime_api_versions_contains::<dyn ApiWithCustomVersion<Block>>();
 	check_runtime_api_versions_contains::<dyn sp_api::Core<Block>>();
+	check_runtime_api_versions_contains::<dyn ApiWithUnimplementedStagingMethod<Block>>();
+
+	// The implementation of the staging method bumps the exposed version.
+	assert_eq!(<dyn ApiWithStagingMethod::<Block>>::VERSION, 1);
+	assert!(RUNTIME_API_VERSIONS
+		.iter()
+		.any(|v| v == &(<dyn ApiWithStagingMethod::<Block>>::ID, 2)));
 }
 
 #[test]
@@ -197,6 +231,19 @@ fn mock_runtime_api_has_api() {
 	assert!(mock.has_api::<dyn Api<Block>>(&BlockId::Number(0)).unwrap());
 }
 
+#[test]
+fn mock_runtime_api_errors_on_calling_unimplemented_staging_method() {
+	let mock = MockApi { block: None };
+
+	mock.stable_one(&BlockId::Number(0), 1).unwrap();
+	assert!(matches!(
+		mock.staging_one(&BlockId::Number(0)),
+		Err(ApiError::FunctionNotImplemented {
+			function: "ApiWithUnimplementedStagingMethod_staging_one"
+		}),
+	));
+}
+
 #[test]
 #[should_panic(expected = "Mocked runtime apis don't support calling deprecated api versions")]
 fn mock_runtime_api_panics_on_calling_old_version() {
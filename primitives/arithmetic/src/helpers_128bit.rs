@@ -118,3 +118,95 @@ pub fn multiply_by_rational(mut a: u128, mut b: u128, mut c: u128) -> Result<u12
 		q.try_into().map_err(|_| "result cannot fit in u128")
 	}
 }
+
+/// The rounding method to use for [`multiply_by_rational_with_rounding`].
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Rounding {
+	/// Round towards zero.
+	Down,
+	/// Round towards infinity.
+	Up,
+	/// Round to the nearest value, rounding an exact half down.
+	NearestPrefDown,
+	/// Round to the nearest value, rounding an exact half up.
+	NearestPrefUp,
+}
+
+/// Compute `a * b / c`, rounded as specified by `r`.
+///
+/// The intermediate product is held in 256 bits, so unlike [`multiply_by_rational`] no precision
+/// is lost and the result is always rounded in the requested direction.
+///
+/// Returns `None` if `c` is zero or if the result does not fit in a `u128`.
+pub fn multiply_by_rational_with_rounding(a: u128, b: u128, c: u128, r: Rounding) -> Option<u128> {
+	if c.is_zero() {
+		return None
+	}
+
+	let (high, low) = double128::mul(a, b);
+	// The quotient is at least `2^128` in this case.
+	if high >= c {
+		return None
+	}
+	let (result, remainder) = double128::div(high, low, c);
+
+	let round_up = match r {
+		Rounding::Down => false,
+		Rounding::Up => !remainder.is_zero(),
+		// `remainder < c`, so this compares `2 * remainder` with `c` without overflowing.
+		Rounding::NearestPrefDown => remainder > c - remainder,
+		Rounding::NearestPrefUp => remainder >= c - remainder,
+	};
+
+	if round_up {
+		result.checked_add(1)
+	} else {
+		Some(result)
+	}
+}
+
+/// 256-bit unsigned arithmetic, with numbers represented by their `(high, low)` 128-bit halves.
+mod double128 {
+	const LOW_MASK: u128 = u64::MAX as u128;
+
+	/// Returns the full 256-bit product of `a` and `b`.
+	pub fn mul(a: u128, b: u128) -> (u128, u128) {
+		let (a_high, a_low) = (a >> 64, a & LOW_MASK);
+		let (b_high, b_low) = (b >> 64, b & LOW_MASK);
+
+		// Each partial product fits in 128 bits since its factors fit in 64 bits.
+		let low_low = a_low * b_low;
+		let high_low = a_high * b_low;
+		let low_high = a_low * b_high;
+		let high_high = a_high * b_high;
+
+		// Sum of three values below `2^64`, thus it can't overflow.
+		let middle = (low_low >> 64) + (high_low & LOW_MASK) + (low_high & LOW_MASK);
+
+		let low = (middle << 64) | (low_low & LOW_MASK);
+		let high = high_high + (high_low >> 64) + (low_high >> 64) + (middle >> 64);
+		(high, low)
+	}
+
+	/// Divides the 256-bit number `(high, low)` by `c`, returning the quotient and the remainder.
+	///
+	/// The quotient must fit in 128 bits, that is `high` must be less than `c`.
+	pub fn div(high: u128, low: u128, c: u128) -> (u128, u128) {
+		debug_assert!(high < c);
+
+		// Plain long division, one bit of `low` at a time. The remainder is kept below `c`.
+		let mut remainder = high;
+		let mut quotient = 0u128;
+		for i in (0..128).rev() {
+			// The bit shifted out of `remainder`, if set the shifted value is at least `c`.
+			let overflow = remainder >> 127 == 1;
+			remainder = (remainder << 1) | ((low >> i) & 1);
+			quotient <<= 1;
+			if overflow || remainder >= c {
+				remainder = remainder.wrapping_sub(c);
+				quotient |= 1;
+			}
+		}
+		(quotient, remainder)
+	}
+}
@@ -462,6 +462,64 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn multiply_by_rational_with_rounding_works() {
+		use primitive_types::U256;
+		use Rounding::*;
+
+		let check = |a: u128, b: u128, c: u128| {
+			let ce: U256 = c.into();
+			let product = U256::from(a) * U256::from(b);
+			let (quotient, remainder) = (product / ce, product % ce);
+			let half_or_more = remainder * 2 >= ce;
+			let expected = |up: bool| {
+				let q = if up { quotient + 1 } else { quotient };
+				if q > u128::MAX.into() {
+					None
+				} else {
+					Some(q.as_u128())
+				}
+			};
+
+			assert_eq!(multiply_by_rational_with_rounding(a, b, c, Down), expected(false));
+			assert_eq!(
+				multiply_by_rational_with_rounding(a, b, c, Up),
+				expected(!remainder.is_zero())
+			);
+			assert_eq!(
+				multiply_by_rational_with_rounding(a, b, c, NearestPrefDown),
+				expected(remainder * 2 > ce)
+			);
+			assert_eq!(
+				multiply_by_rational_with_rounding(a, b, c, NearestPrefUp),
+				expected(half_or_more && !remainder.is_zero())
+			);
+		};
+
+		check(7, 2, 3);
+		check(5, 1, 2);
+		check(0, MAX128, 1);
+		check(MAX128, MAX128, MAX128);
+		check(MAX128, MAX128 - 1, MAX128);
+		check(MAX128, 5, 7);
+		check(MAX128, 11, 13);
+		check(MAX128, 555, 1000);
+		check(MAX128, 1, 1);
+		check(MAX128, 2, 1);
+		check(2 * MAX64 - 1, MAX64 - 1, MAX64);
+		check(MAX64 + 100, MAX64_2 / 100, MAX64_2 / 200 + 1);
+		check(2u128.pow(127) + 1, 2u128.pow(127) - 1, 2u128.pow(127));
+		check(29459999999999999988000, 1000000000000000000, 10000000000000000000);
+
+		// Exact halves.
+		assert_eq!(multiply_by_rational_with_rounding(5, 1, 2, NearestPrefDown), Some(2));
+		assert_eq!(multiply_by_rational_with_rounding(5, 1, 2, NearestPrefUp), Some(3));
+		// Rounding up can overflow on its own.
+		assert_eq!(multiply_by_rational_with_rounding(MAX128, MAX128, MAX128 - 1, Down), None);
+		assert_eq!(multiply_by_rational_with_rounding(MAX128, 2, 2, Up), Some(MAX128));
+		assert_eq!(multiply_by_rational_with_rounding(1, 1, 0, Down), None);
+	}
+
 	#[test]
 	fn multiply_by_rational_a_b_are_interchangeable() {
 		assert_eq!(multiply_by_rational(10, MAX128, MAX128 / 2), Ok(20));
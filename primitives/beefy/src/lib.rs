@@ -117,7 +117,7 @@ pub enum ConsensusLog<AuthorityId: Codec> {
 ///
 /// A vote message is a direct vote created by a BEEFY node on every voting round
 /// and is gossiped to its peers.
-#[derive(Debug, Decode, Encode, TypeInfo)]
+#[derive(Clone, Debug, Decode, Encode, PartialEq, TypeInfo)]
 pub struct VoteMessage<Hash, Number, Id, Signature> {
 	/// Commit to information extracted from a finalized block
 	pub commitment: Commitment<Number, Hash>,
@@ -127,6 +127,90 @@ pub struct VoteMessage<Hash, Number, Id, Signature> {
 	pub signature: Signature,
 }
 
+/// Proof of voter misbehavior on a given set id.
+///
+/// Misbehavior/equivocation in BEEFY happens when a voter votes on the same round/block for
+/// different payloads. Proving is achieved by collecting the signed commitments of conflicting
+/// votes.
+#[derive(Clone, Debug, Decode, Encode, PartialEq, TypeInfo)]
+pub struct EquivocationProof<Hash, Number, Id, Signature> {
+	/// The first vote in the equivocation.
+	pub first: VoteMessage<Hash, Number, Id, Signature>,
+	/// The second vote in the equivocation.
+	pub second: VoteMessage<Hash, Number, Id, Signature>,
+}
+
+impl<Hash, Number: Clone, Id, Signature> EquivocationProof<Hash, Number, Id, Signature> {
+	/// Returns the authority id of the equivocator.
+	pub fn offender_id(&self) -> &Id {
+		&self.first.id
+	}
+	/// Returns the round number at which the equivocation occurred.
+	pub fn round_number(&self) -> &Number {
+		&self.first.commitment.block_number
+	}
+	/// Returns the set id at which the equivocation occurred.
+	pub fn set_id(&self) -> ValidatorSetId {
+		self.first.commitment.validator_set_id
+	}
+}
+
+/// Check a commitment signature by encoding the commitment and verifying the provided signature
+/// using the expected authority id.
+///
+/// BEEFY authorities sign the keccak-256 hash of the SCALE-encoded commitment.
+pub fn check_commitment_signature<Number, Payload>(
+	commitment: &Commitment<Number, Payload>,
+	authority_id: &crypto::AuthorityId,
+	signature: &crypto::Signature,
+) -> bool
+where
+	Number: Encode,
+	Payload: Encode,
+{
+	let msg = sp_io::hashing::keccak_256(&commitment.encode());
+	let signature: &sp_core::ecdsa::Signature = signature.as_ref();
+	match sp_io::crypto::secp256k1_ecdsa_recover_compressed(signature.as_ref(), &msg) {
+		Ok(public) => AsRef::<[u8]>::as_ref(authority_id) == &public[..],
+		Err(_) => false,
+	}
+}
+
+/// Verifies the equivocation proof by making sure that both votes target different payloads on
+/// the same round and set id, are made by the same authority, and that both signatures are
+/// valid.
+pub fn check_equivocation_proof<Hash, Number>(
+	report: &EquivocationProof<Hash, Number, crypto::AuthorityId, crypto::Signature>,
+) -> bool
+where
+	Hash: Encode + PartialEq,
+	Number: Encode + PartialEq,
+{
+	let first = &report.first;
+	let second = &report.second;
+
+	// if votes
+	//   come from different authorities,
+	//   are for different rounds,
+	//   have different validator set ids,
+	//   or both votes have the same payload,
+	// there is no equivocation.
+	if first.id != second.id ||
+		first.commitment.block_number != second.commitment.block_number ||
+		first.commitment.validator_set_id != second.commitment.validator_set_id ||
+		first.commitment.payload == second.commitment.payload
+	{
+		return false
+	}
+
+	// check signatures on both votes are valid
+	let valid_first = check_commitment_signature(&first.commitment, &first.id, &first.signature);
+	let valid_second =
+		check_commitment_signature(&second.commitment, &second.id, &second.signature);
+
+	valid_first && valid_second
+}
+
 sp_api::decl_runtime_apis! {
 	/// API necessary for BEEFY voters.
 	pub trait BeefyApi
@@ -135,3 +219,83 @@ sp_api::decl_runtime_apis! {
 		fn validator_set() -> ValidatorSet<crypto::AuthorityId>;
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_application_crypto::ecdsa;
+	use sp_core::{keccak_256, Pair};
+
+	fn signed_vote(
+		pair: &ecdsa::Pair,
+		block_number: u64,
+		payload: MmrRootHash,
+		validator_set_id: ValidatorSetId,
+	) -> VoteMessage<MmrRootHash, u64, crypto::AuthorityId, crypto::Signature> {
+		let commitment = Commitment { payload, block_number, validator_set_id };
+		let signature = pair.sign_prehashed(&keccak_256(&commitment.encode()));
+		VoteMessage { commitment, id: pair.public().into(), signature: signature.into() }
+	}
+
+	#[test]
+	fn commitment_signature_works() {
+		let alice = ecdsa::Pair::from_string("//Alice", None).unwrap();
+		let bob = ecdsa::Pair::from_string("//Bob", None).unwrap();
+		let vote = signed_vote(&alice, 1, MmrRootHash::repeat_byte(1), 0);
+
+		assert!(check_commitment_signature(&vote.commitment, &vote.id, &vote.signature));
+
+		let bob_id: crypto::AuthorityId = bob.public().into();
+		assert!(!check_commitment_signature(&vote.commitment, &bob_id, &vote.signature));
+
+		let mut commitment = vote.commitment.clone();
+		commitment.block_number = 2;
+		assert!(!check_commitment_signature(&commitment, &vote.id, &vote.signature));
+	}
+
+	#[test]
+	fn equivocation_proof_checks_work() {
+		let alice = ecdsa::Pair::from_string("//Alice", None).unwrap();
+		let bob = ecdsa::Pair::from_string("//Bob", None).unwrap();
+		let first = signed_vote(&alice, 1, MmrRootHash::repeat_byte(1), 0);
+
+		// votes for different payloads on the same round are equivocations
+		let proof = EquivocationProof {
+			first: first.clone(),
+			second: signed_vote(&alice, 1, MmrRootHash::repeat_byte(2), 0),
+		};
+		assert!(check_equivocation_proof(&proof));
+		assert_eq!(proof.offender_id(), &first.id);
+		assert_eq!(proof.round_number(), &1);
+		assert_eq!(proof.set_id(), 0);
+
+		// the same vote twice is not an equivocation
+		let proof = EquivocationProof { first: first.clone(), second: first.clone() };
+		assert!(!check_equivocation_proof(&proof));
+
+		// votes on different rounds or set ids are not equivocations
+		let proof = EquivocationProof {
+			first: first.clone(),
+			second: signed_vote(&alice, 2, MmrRootHash::repeat_byte(2), 0),
+		};
+		assert!(!check_equivocation_proof(&proof));
+		let proof = EquivocationProof {
+			first: first.clone(),
+			second: signed_vote(&alice, 1, MmrRootHash::repeat_byte(2), 1),
+		};
+		assert!(!check_equivocation_proof(&proof));
+
+		// votes from different authorities are not equivocations
+		let proof = EquivocationProof {
+			first: first.clone(),
+			second: signed_vote(&bob, 1, MmrRootHash::repeat_byte(2), 0),
+		};
+		assert!(!check_equivocation_proof(&proof));
+
+		// both signatures must be valid
+		let mut second = signed_vote(&alice, 1, MmrRootHash::repeat_byte(2), 0);
+		second.signature = signed_vote(&alice, 1, MmrRootHash::repeat_byte(3), 0).signature;
+		let proof = EquivocationProof { first, second };
+		assert!(!check_equivocation_proof(&proof));
+	}
+}
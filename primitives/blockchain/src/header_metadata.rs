@@ -169,6 +169,17 @@ pub struct TreeRoute<Block: BlockT> {
 }
 
 impl<Block: BlockT> TreeRoute<Block> {
+	/// Creates a new `TreeRoute`.
+	///
+	/// `route` goes from the first retracted block to the last enacted one, and `pivot` is the
+	/// index of the common block in `route`.
+	///
+	/// Panics if `pivot` is out of bounds.
+	pub fn new(route: Vec<HashAndNumber<Block>>, pivot: usize) -> Self {
+		assert!(pivot < route.len(), "`pivot` must be the index of a block in `route`");
+		TreeRoute { route, pivot }
+	}
+
 	/// Get a slice of all retracted blocks in reverse order (towards common ancestor).
 	pub fn retracted(&self) -> &[HashAndNumber<Block>] {
 		&self.route[..self.pivot]
@@ -22,7 +22,7 @@
 //! unless you know what you're doing. Using `sp_io` will be more performant, since instead of
 //! computing the hash in WASM it delegates that computation to the host client.
 
-use sha2::{Digest, Sha256};
+use sha2::{Digest, Sha256, Sha512};
 use tiny_keccak::{Hasher, Keccak};
 
 /// Do a Blake2 512-bit hash and place result in `dest`.
@@ -166,3 +166,12 @@ pub fn sha2_256(data: &[u8]) -> [u8; 32] {
 	output.copy_from_slice(&hasher.finalize());
 	output
 }
+
+/// Do a sha2 512-bit hash and return result.
+pub fn sha2_512(data: &[u8]) -> [u8; 64] {
+	let mut hasher = Sha512::new();
+	hasher.update(data);
+	let mut output = [0u8; 64];
+	output.copy_from_slice(&hasher.finalize());
+	output
+}
@@ -0,0 +1,77 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Substrate genesis config builder.
+//!
+//! This crate contains the [`GenesisBuilder`] runtime API. It lets a node or a tool build the
+//! genesis state of a runtime from JSON, using only the runtime's Wasm blob.
+//!
+//! A runtime may provide a number of named presets, e.g. [`DEV_RUNTIME_PRESET`]. A preset is
+//! returned as a JSON patch: an object that only contains the fields that differ from the
+//! runtime's default genesis config. Tooling merges the patch over the default config and passes
+//! the result to [`GenesisBuilder::build_state`].
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![warn(missing_docs)]
+
+use sp_runtime::RuntimeString;
+use sp_std::vec::Vec;
+
+/// The result type alias, used in build methods. `Err` contains formatted error message.
+pub type Result = sp_std::result::Result<(), RuntimeString>;
+
+/// The type representing the preset ID.
+pub type PresetId = RuntimeString;
+
+/// The default `development` preset used to communicate with the runtime via
+/// [`GenesisBuilder`] interface.
+///
+/// (Recommended for testing with a single node, e.g., for benchmarking)
+pub const DEV_RUNTIME_PRESET: &str = "development";
+
+/// The default `local_testnet` preset used to communicate with the runtime via
+/// [`GenesisBuilder`] interface.
+///
+/// (Recommended for local testing with multiple nodes)
+pub const LOCAL_TESTNET_RUNTIME_PRESET: &str = "local_testnet";
+
+sp_api::decl_runtime_apis! {
+	/// API to interact with the genesis config of the runtime.
+	pub trait GenesisBuilder {
+		/// Build the genesis state from the given JSON representation of the genesis config.
+		///
+		/// The JSON must be a complete genesis config: every field has to be present. Patches
+		/// returned by [`GenesisBuilder::get_preset`] have to be merged over the default config
+		/// first, which is returned for `None`.
+		///
+		/// Errors if the JSON cannot be deserialized into the genesis config.
+		fn build_state(json: Vec<u8>) -> Result;
+
+		/// Returns a JSON blob representation of the built-in genesis config named by `id`.
+		///
+		/// If `id` is `None` the function returns the JSON of the default genesis config. For
+		/// any other `id` it returns the JSON patch of the named preset, or `None` if the
+		/// preset is unknown.
+		fn get_preset(id: &Option<PresetId>) -> Option<Vec<u8>>;
+
+		/// Returns a list of identifiers of the available presets.
+		///
+		/// The list may include [`DEV_RUNTIME_PRESET`], [`LOCAL_TESTNET_RUNTIME_PRESET`] and any
+		/// custom presets of the runtime.
+		fn preset_names() -> Vec<PresetId>;
+	}
+}
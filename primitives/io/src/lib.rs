@@ -67,6 +67,9 @@ use codec::{Decode, Encode};
 #[cfg(feature = "std")]
 use sp_externalities::{Externalities, ExternalitiesExt};
 
+#[cfg(feature = "std")]
+use sp_state_machine::ProofSizeExt;
+
 #[cfg(feature = "std")]
 mod batch_verifier;
 
@@ -861,6 +864,16 @@ pub trait Hashing {
 		sp_core::hashing::sha2_256(data)
 	}
 
+	/// Conduct a 512-bit Sha2 hash.
+	fn sha2_512(data: &[u8]) -> [u8; 64] {
+		sp_core::hashing::sha2_512(data)
+	}
+
+	/// Conduct a 64-bit Blake2 hash.
+	fn blake2_64(data: &[u8]) -> [u8; 8] {
+		sp_core::hashing::blake2_64(data)
+	}
+
 	/// Conduct a 128-bit Blake2 hash.
 	fn blake2_128(data: &[u8]) -> [u8; 16] {
 		sp_core::hashing::blake2_128(data)
@@ -915,6 +928,18 @@ pub trait OffchainIndex {
 	}
 }
 
+/// Interface that provides access to the storage proof that is being recorded.
+#[runtime_interface]
+pub trait ProofSize {
+	/// Returns the estimated encoded size of the storage proof recorded so far.
+	///
+	/// Returns `u64::MAX` if no proof is being recorded.
+	fn storage_proof_size(&mut self) -> u64 {
+		self.extension::<ProofSizeExt>()
+			.map_or(u64::MAX, |ext| ext.storage_proof_size())
+	}
+}
+
 #[cfg(feature = "std")]
 sp_externalities::decl_extension! {
 	/// Batch verification extension to register/retrieve from the externalities.
@@ -1516,6 +1541,7 @@ pub type SubstrateHostFunctions = (
 	sandbox::HostFunctions,
 	crate::trie::HostFunctions,
 	offchain_index::HostFunctions,
+	proof_size::HostFunctions,
 	runtime_tasks::HostFunctions,
 	transaction_index::HostFunctions,
 );
@@ -1592,6 +1618,24 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn storage_proof_size_works() {
+		let mut ext = BasicExternalities::default();
+		ext.execute_with(|| assert_eq!(proof_size::storage_proof_size(), u64::MAX));
+
+		let recorder = sp_state_machine::ProofRecorder::<sp_core::H256>::default();
+		ext.register_extension(ProofSizeExt::new(recorder.clone()));
+		ext.execute_with(|| {
+			assert_eq!(proof_size::storage_proof_size(), recorder.estimate_encoded_size() as u64);
+		});
+
+		recorder.record(Default::default(), Some(vec![1; 32]));
+		ext.execute_with(|| {
+			assert_eq!(proof_size::storage_proof_size(), recorder.estimate_encoded_size() as u64);
+			assert!(proof_size::storage_proof_size() >= 32);
+		});
+	}
+
 	#[test]
 	fn batch_verify_start_finish_works() {
 		let mut ext = BasicExternalities::default();
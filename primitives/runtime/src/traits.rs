@@ -32,9 +32,9 @@ use impl_trait_for_tuples::impl_for_tuples;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use sp_application_crypto::AppKey;
 pub use sp_arithmetic::traits::{
-	AtLeast32Bit, AtLeast32BitUnsigned, Bounded, CheckedAdd, CheckedDiv, CheckedMul, CheckedShl,
-	CheckedShr, CheckedSub, IntegerSquareRoot, One, SaturatedConversion, Saturating,
-	UniqueSaturatedFrom, UniqueSaturatedInto, Zero,
+	AtLeast16Bit, AtLeast16BitUnsigned, AtLeast32Bit, AtLeast32BitUnsigned, Bounded, CheckedAdd,
+	CheckedDiv, CheckedMul, CheckedShl, CheckedShr, CheckedSub, IntegerSquareRoot, One,
+	SaturatedConversion, Saturating, UniqueSaturatedFrom, UniqueSaturatedInto, Zero,
 };
 use sp_core::{self, Hasher, RuntimeDebug, TypeId};
 use sp_std::{
@@ -176,6 +176,35 @@ impl<Hash: std::hash::Hash + Eq> ProofRecorder<Hash> {
 	}
 }
 
+/// Something that knows the size of the storage proof that is currently being recorded.
+pub trait ProofSizeProvider {
+	/// Returns the estimated encoded size of the proof recorded so far.
+	fn estimate_encoded_size(&self) -> usize;
+}
+
+impl<Hash: std::hash::Hash + Eq> ProofSizeProvider for ProofRecorder<Hash> {
+	fn estimate_encoded_size(&self) -> usize {
+		ProofRecorder::estimate_encoded_size(self)
+	}
+}
+
+sp_externalities::decl_extension! {
+	/// Extension that gives the runtime access to the size of the storage proof recorded so far.
+	pub struct ProofSizeExt(Box<dyn ProofSizeProvider + Send + Sync>);
+}
+
+impl ProofSizeExt {
+	/// Create a new instance of the extension for the given proof size provider.
+	pub fn new<T: ProofSizeProvider + Send + Sync + 'static>(provider: T) -> Self {
+		Self(Box::new(provider))
+	}
+
+	/// Returns the estimated encoded size of the proof recorded so far.
+	pub fn storage_proof_size(&self) -> u64 {
+		self.0.estimate_encoded_size() as u64
+	}
+}
+
 /// Patricia trie-based backend which also tracks all touched storage trie values.
 /// These can be sent to remote node and used as a proof of execution.
 pub struct ProvingBackend<'a, S: 'a + TrieBackendStorage<H>, H: 'a + Hasher>(
@@ -30,20 +30,22 @@ use crate::{
 	},
 	ext::Ext,
 	InMemoryBackend, OverlayedChanges, StorageKey, StorageTransactionCache, StorageValue,
+	TrieBackend,
 };
 
 use codec::Decode;
-use hash_db::Hasher;
+use hash_db::{HashDB, Hasher, EMPTY_PREFIX};
 use sp_core::{
 	offchain::testing::TestPersistentOffchainDB,
 	storage::{
 		well_known_keys::{is_child_storage_key, CHANGES_TRIE_CONFIG, CODE},
-		Storage,
+		ChildInfo, Storage,
 	},
 	testing::TaskExecutor,
 	traits::TaskExecutorExt,
 };
 use sp_externalities::{Extension, ExtensionStore, Extensions};
+use sp_trie::{MemoryDB, StorageProof};
 
 /// Simple HashMap-based Externalities impl.
 pub struct TestExternalities<H: Hasher, N: ChangesTrieBlockNumber = u64>
@@ -145,6 +147,11 @@ where
 		self.backend.insert(vec![(None, vec![(k, Some(v))])]);
 	}
 
+	/// Insert key/value into the child trie `c` of the backend.
+	pub fn insert_child(&mut self, c: ChildInfo, k: StorageKey, v: StorageValue) {
+		self.backend.insert(vec![(Some(c), vec![(k, Some(v))])]);
+	}
+
 	/// Registers the given extension for this instance.
 	pub fn register_extension<E: Any + Extension>(&mut self, ext: E) {
 		self.extensions.register(ext);
@@ -176,10 +183,12 @@ where
 
 	/// Commit all pending changes to the underlying backend.
 	///
+	/// Returns the storage root of the backend after the commit.
+	///
 	/// # Panic
 	///
 	/// This will panic if there are still open transactions.
-	pub fn commit_all(&mut self) -> Result<(), String> {
+	pub fn commit_all(&mut self) -> Result<H::Out, String> {
 		let changes = self.overlay.drain_storage_changes::<_, _, N>(
 			&self.backend,
 			None,
@@ -189,7 +198,34 @@ where
 
 		self.backend
 			.apply_transaction(changes.transaction_storage_root, changes.transaction);
-		Ok(())
+		Ok(changes.transaction_storage_root)
+	}
+
+	/// Drain the trie database of the backend into its raw nodes, keyed by their hash and along
+	/// with their reference count, and return them with the storage root.
+	///
+	/// Pending changes are not part of the snapshot, so [`commit_all`](Self::commit_all) should
+	/// be called before.
+	pub fn into_raw_snapshot(self) -> (Vec<(H::Out, (Vec<u8>, i32))>, H::Out) {
+		let root = *self.backend.root();
+		let raw = self.backend.into_storage().drain().into_iter().collect();
+		(raw, root)
+	}
+
+	/// Create a new instance of `TestExternalities` from the raw nodes of a trie database and its
+	/// storage root, as returned by [`into_raw_snapshot`](Self::into_raw_snapshot).
+	pub fn from_raw_snapshot(raw_storage: Vec<(H::Out, (Vec<u8>, i32))>, root: H::Out) -> Self {
+		let mut db = MemoryDB::default();
+		for (key, (value, ref_count)) in raw_storage {
+			// every `emplace` increments the reference count of the node.
+			for _ in 0..ref_count {
+				db.emplace(key, EMPTY_PREFIX, value.clone());
+			}
+		}
+
+		let mut ext = Self::new_empty();
+		ext.backend = TrieBackend::new(db, root);
+		ext
 	}
 
 	/// Execute the given closure while `self` is set as externalities.
@@ -200,6 +236,33 @@ where
 		sp_externalities::set_and_run_with_externalities(&mut ext, execute)
 	}
 
+	/// Execute the given closure while `self` is set as externalities, recording all trie nodes
+	/// of the backend that are accessed.
+	///
+	/// Returns the result of the given closure and the proof of the accessed nodes. Values that
+	/// are read from pending changes are not part of the proof, so
+	/// [`commit_all`](Self::commit_all) should be called before.
+	pub fn execute_and_prove<R>(&mut self, execute: impl FnOnce() -> R) -> (R, StorageProof) {
+		let proving_backend = crate::ProvingBackend::new(&self.backend);
+		let mut proving_ext = Ext::new(
+			&mut self.overlay,
+			&mut self.storage_transaction_cache,
+			&proving_backend,
+			match self.changes_trie_config.clone() {
+				Some(config) => Some(ChangesTrieState {
+					config,
+					zero: 0.into(),
+					storage: &self.changes_trie_storage,
+				}),
+				None => None,
+			},
+			Some(&mut self.extensions),
+		);
+
+		let outcome = sp_externalities::set_and_run_with_externalities(&mut proving_ext, execute);
+		(outcome, proving_backend.extract_proof())
+	}
+
 	/// Execute the given closure while `self` is set as externalities.
 	///
 	/// Returns the result of the given closure, if no panics occured.
@@ -307,7 +370,7 @@ where
 mod tests {
 	use super::*;
 	use hex_literal::hex;
-	use sp_core::{storage::ChildInfo, traits::Externalities, H256};
+	use sp_core::{traits::Externalities, H256};
 	use sp_runtime::traits::BlakeTwo256;
 
 	#[test]
@@ -333,6 +396,17 @@ mod tests {
 		assert_eq!(&ext.storage(CODE).unwrap(), &code);
 	}
 
+	#[test]
+	fn insert_child_works() {
+		let mut ext = TestExternalities::<BlakeTwo256, u64>::default();
+		let child_info = ChildInfo::new_default(&b"test_child"[..]);
+		ext.insert_child(child_info.clone(), b"doe".to_vec(), b"reindeer".to_vec());
+
+		let mut ext = ext.ext();
+		assert_eq!(ext.child_storage(&child_info, b"doe"), Some(b"reindeer".to_vec()));
+		assert!(ext.storage(child_info.prefixed_storage_key().as_slice()).is_some());
+	}
+
 	#[test]
 	fn check_send() {
 		fn assert_send<T: Send>() {}
@@ -364,6 +438,52 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn commit_all_returns_storage_root() {
+		let mut ext = TestExternalities::<BlakeTwo256, u64>::default();
+		let root = {
+			let mut ext = ext.ext();
+			ext.set_storage(b"doe".to_vec(), b"reindeer".to_vec());
+			H256::from_slice(ext.storage_root().as_slice())
+		};
+
+		assert_eq!(ext.commit_all().unwrap(), root);
+		assert_eq!(*ext.backend.root(), root);
+	}
+
+	#[test]
+	fn execute_and_prove_works() {
+		let mut ext = TestExternalities::<BlakeTwo256, u64>::default();
+		ext.insert(b"doe".to_vec(), b"reindeer".to_vec());
+		ext.insert(b"dog".to_vec(), b"puppy".to_vec());
+		let root = ext.commit_all().unwrap();
+
+		let (value, proof) = ext.execute_and_prove(|| {
+			sp_externalities::with_externalities(|ext| ext.storage(b"doe")).unwrap()
+		});
+		assert_eq!(value, Some(b"reindeer".to_vec()));
+
+		let checked =
+			crate::read_proof_check::<BlakeTwo256, _>(root, proof, &[&b"doe"[..]]).unwrap();
+		assert_eq!(checked.get(&b"doe"[..]), Some(&Some(b"reindeer".to_vec())));
+	}
+
+	#[test]
+	fn raw_snapshot_roundtrip_works() {
+		let mut ext = TestExternalities::<BlakeTwo256, u64>::default();
+		ext.insert(b"doe".to_vec(), b"reindeer".to_vec());
+		ext.insert(b"dog".to_vec(), b"puppy".to_vec());
+		let root = ext.commit_all().unwrap();
+
+		let (raw, snapshot_root) = ext.into_raw_snapshot();
+		assert_eq!(snapshot_root, root);
+
+		let mut ext = TestExternalities::<BlakeTwo256, u64>::from_raw_snapshot(raw, root);
+		let mut ext = ext.ext();
+		assert_eq!(ext.storage(b"dog"), Some(b"puppy".to_vec()));
+		assert_eq!(H256::from_slice(ext.storage_root().as_slice()), root);
+	}
+
 	#[test]
 	fn as_backend_generates_same_backend_as_commit_all() {
 		let mut ext = TestExternalities::<BlakeTwo256, u64>::default();
@@ -0,0 +1,385 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Primitives for the statement store.
+//!
+//! A [`Statement`] is a piece of data gossiped between nodes outside of the chain. It is
+//! authenticated by a [`Proof`], which is either a signature of one of the supported schemes or a
+//! reference to an on-chain event. The runtime decides whether a statement is accepted through
+//! the [`runtime_api::ValidateStatement`] API, and may submit or query statements itself through
+//! the [`runtime_api::statement_store`] host functions.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![warn(missing_docs)]
+
+use codec::{Decode, Encode, Input};
+use scale_info::TypeInfo;
+use sp_core::{ecdsa, ed25519, sr25519, RuntimeDebug};
+use sp_runtime::traits::{BlakeTwo256, Hash as _, Verify};
+use sp_runtime_interface::pass_by::PassByCodec;
+use sp_std::vec::Vec;
+
+pub mod runtime_api;
+#[cfg(feature = "std")]
+mod store_api;
+
+#[cfg(feature = "std")]
+pub use store_api::{Error, Result, StatementStore};
+
+/// Statement topic.
+pub type Topic = [u8; 32];
+/// Decryption key identifier.
+pub type DecryptionKey = [u8; 32];
+/// Statement hash.
+pub type Hash = [u8; 32];
+/// Block hash.
+pub type BlockHash = [u8; 32];
+/// Account id.
+pub type AccountId = [u8; 32];
+/// Statement channel.
+pub type Channel = [u8; 32];
+
+/// Total number of topic fields allowed in a statement.
+pub const MAX_TOPICS: usize = 4;
+
+/// Statement proof.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum Proof {
+	/// Sr25519 signature.
+	Sr25519 {
+		/// Signature.
+		signature: sr25519::Signature,
+		/// Public key.
+		signer: sr25519::Public,
+	},
+	/// Ed25519 signature.
+	Ed25519 {
+		/// Signature.
+		signature: ed25519::Signature,
+		/// Public key.
+		signer: ed25519::Public,
+	},
+	/// Secp256k1 signature.
+	Secp256k1Ecdsa {
+		/// Signature.
+		signature: ecdsa::Signature,
+		/// Public key.
+		signer: ecdsa::Public,
+	},
+	/// On-chain event proof.
+	///
+	/// The statement is authenticated by an event emitted in the given block. The runtime is
+	/// responsible for checking that the event exists.
+	OnChain {
+		/// Account identifier associated with the event.
+		who: AccountId,
+		/// Hash of the block that contains the event.
+		block_hash: BlockHash,
+		/// Index of the event in the event list.
+		event_index: u64,
+	},
+}
+
+/// Result returned by [`Statement::verify_signature`].
+#[derive(Clone, PartialEq, Eq, RuntimeDebug)]
+pub enum SignatureVerificationResult {
+	/// Signature is valid and matches this account id.
+	Valid(AccountId),
+	/// Signature has failed verification.
+	Invalid,
+	/// No signature in the proof or no proof.
+	NoSignature,
+}
+
+/// Statement structure.
+///
+/// All fields are optional. The proof covers every other field, see
+/// [`Statement::signature_material`].
+#[derive(Clone, Default, PartialEq, Eq, Encode, RuntimeDebug, TypeInfo, PassByCodec)]
+pub struct Statement {
+	proof: Option<Proof>,
+	decryption_key: Option<DecryptionKey>,
+	channel: Option<Channel>,
+	priority: Option<u32>,
+	topics: Vec<Topic>,
+	data: Option<Vec<u8>>,
+}
+
+impl Decode for Statement {
+	fn decode<I: Input>(input: &mut I) -> sp_std::result::Result<Self, codec::Error> {
+		let proof = Decode::decode(input)?;
+		let decryption_key = Decode::decode(input)?;
+		let channel = Decode::decode(input)?;
+		let priority = Decode::decode(input)?;
+		let topics: Vec<Topic> = Decode::decode(input)?;
+		if topics.len() > MAX_TOPICS {
+			return Err("Statement has too many topics".into())
+		}
+		let data = Decode::decode(input)?;
+		Ok(Statement { proof, decryption_key, channel, priority, topics, data })
+	}
+}
+
+impl Statement {
+	/// Create a new empty statement with no proof.
+	pub fn new() -> Statement {
+		Default::default()
+	}
+
+	/// Create a new statement with a proof.
+	pub fn new_with_proof(proof: Proof) -> Statement {
+		Statement { proof: Some(proof), ..Default::default() }
+	}
+
+	/// Sign the statement with an sr25519 key and attach the resulting proof.
+	#[cfg(feature = "std")]
+	pub fn sign_sr25519_private(&mut self, key: &sr25519::Pair) {
+		use sp_core::Pair;
+		let signature = key.sign(&self.signature_material());
+		self.proof = Some(Proof::Sr25519 { signature, signer: key.public() });
+	}
+
+	/// Sign the statement with an ed25519 key and attach the resulting proof.
+	#[cfg(feature = "std")]
+	pub fn sign_ed25519_private(&mut self, key: &ed25519::Pair) {
+		use sp_core::Pair;
+		let signature = key.sign(&self.signature_material());
+		self.proof = Some(Proof::Ed25519 { signature, signer: key.public() });
+	}
+
+	/// Sign the statement with a secp256k1 key and attach the resulting proof.
+	///
+	/// The message is hashed with blake2-256 before signing.
+	#[cfg(feature = "std")]
+	pub fn sign_ecdsa_private(&mut self, key: &ecdsa::Pair) {
+		use sp_core::Pair;
+		let signature = key.sign(&self.signature_material());
+		self.proof = Some(Proof::Secp256k1Ecdsa { signature, signer: key.public() });
+	}
+
+	/// Check the proof signature, if any.
+	///
+	/// A [`Proof::OnChain`] proof carries no signature and is reported as
+	/// [`SignatureVerificationResult::NoSignature`]; it has to be checked by the runtime.
+	pub fn verify_signature(&self) -> SignatureVerificationResult {
+		let message = self.signature_material();
+		match &self.proof {
+			Some(Proof::Sr25519 { signature, signer }) =>
+				if signature.verify(&message[..], signer) {
+					SignatureVerificationResult::Valid(signer.0)
+				} else {
+					SignatureVerificationResult::Invalid
+				},
+			Some(Proof::Ed25519 { signature, signer }) =>
+				if signature.verify(&message[..], signer) {
+					SignatureVerificationResult::Valid(signer.0)
+				} else {
+					SignatureVerificationResult::Invalid
+				},
+			Some(Proof::Secp256k1Ecdsa { signature, signer }) =>
+				if signature.verify(&message[..], signer) {
+					SignatureVerificationResult::Valid(BlakeTwo256::hash(signer.as_ref()).into())
+				} else {
+					SignatureVerificationResult::Invalid
+				},
+			Some(Proof::OnChain { .. }) | None => SignatureVerificationResult::NoSignature,
+		}
+	}
+
+	/// Calculate the statement hash.
+	///
+	/// The hash covers the whole statement, including the proof.
+	pub fn hash(&self) -> Hash {
+		self.using_encoded(|encoded| BlakeTwo256::hash(encoded).into())
+	}
+
+	/// The encoded data covered by the proof: every field except the proof itself.
+	pub fn signature_material(&self) -> Vec<u8> {
+		(&self.decryption_key, &self.channel, &self.priority, &self.topics, &self.data).encode()
+	}
+
+	/// Returns a topic by topic index.
+	pub fn topic(&self, index: usize) -> Option<Topic> {
+		self.topics.get(index).cloned()
+	}
+
+	/// Returns all topics.
+	pub fn topics(&self) -> &[Topic] {
+		&self.topics
+	}
+
+	/// Returns the decryption key if any.
+	pub fn decryption_key(&self) -> Option<DecryptionKey> {
+		self.decryption_key
+	}
+
+	/// Convert to the internal data vector.
+	pub fn into_data(self) -> Vec<u8> {
+		self.data.unwrap_or_default()
+	}
+
+	/// Get a reference to the statement proof, if any.
+	pub fn proof(&self) -> Option<&Proof> {
+		self.proof.as_ref()
+	}
+
+	/// Get the proof account id, if any.
+	///
+	/// For signature proofs this does not check that the signature is valid.
+	pub fn account_id(&self) -> Option<AccountId> {
+		self.proof.as_ref().map(|proof| match proof {
+			Proof::Sr25519 { signer, .. } => signer.0,
+			Proof::Ed25519 { signer, .. } => signer.0,
+			Proof::Secp256k1Ecdsa { signer, .. } => BlakeTwo256::hash(signer.as_ref()).into(),
+			Proof::OnChain { who, .. } => *who,
+		})
+	}
+
+	/// Get a reference to the data, if any.
+	pub fn data(&self) -> Option<&Vec<u8>> {
+		self.data.as_ref()
+	}
+
+	/// Get the length of the data, or zero if there is none.
+	pub fn data_len(&self) -> usize {
+		self.data.as_ref().map_or(0, Vec::len)
+	}
+
+	/// Get the channel, if any.
+	pub fn channel(&self) -> Option<Channel> {
+		self.channel
+	}
+
+	/// Get the priority, if any.
+	pub fn priority(&self) -> Option<u32> {
+		self.priority
+	}
+
+	/// Set the statement proof.
+	pub fn set_proof(&mut self, proof: Proof) {
+		self.proof = Some(proof)
+	}
+
+	/// Set the statement priority.
+	pub fn set_priority(&mut self, priority: u32) {
+		self.priority = Some(priority)
+	}
+
+	/// Set the statement channel.
+	pub fn set_channel(&mut self, channel: Channel) {
+		self.channel = Some(channel)
+	}
+
+	/// Set a topic by index.
+	///
+	/// Missing topics below `index` are filled with zeroes. Indexes of [`MAX_TOPICS`] or more are
+	/// ignored.
+	pub fn set_topic(&mut self, index: usize, topic: Topic) {
+		if index < MAX_TOPICS {
+			if self.topics.len() <= index {
+				self.topics.resize(index + 1, Default::default());
+			}
+			self.topics[index] = topic;
+		}
+	}
+
+	/// Set the decryption key.
+	pub fn set_decryption_key(&mut self, key: DecryptionKey) {
+		self.decryption_key = Some(key);
+	}
+
+	/// Set the data.
+	pub fn set_plain_data(&mut self, data: Vec<u8>) {
+		self.data = Some(data)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_core::Pair;
+
+	#[test]
+	fn statement_encoding_roundtrips() {
+		let mut statement = Statement::new();
+		assert_eq!(Statement::decode(&mut &statement.encode()[..]).unwrap(), statement);
+
+		statement.set_proof(Proof::OnChain {
+			who: [42u8; 32],
+			block_hash: [24u8; 32],
+			event_index: 66,
+		});
+		statement.set_decryption_key([0xde; 32]);
+		statement.set_priority(999);
+		statement.set_channel([0xcc; 32]);
+		statement.set_topic(0, [0x01; 32]);
+		statement.set_topic(1, [0x02; 32]);
+		statement.set_plain_data(vec![55, 99]);
+
+		let encoded = statement.encode();
+		assert_eq!(Statement::decode(&mut &encoded[..]).unwrap(), statement);
+		assert_eq!(statement.topics(), &[[0x01; 32], [0x02; 32]]);
+		assert_eq!(statement.account_id(), Some([42u8; 32]));
+	}
+
+	#[test]
+	fn decoding_rejects_too_many_topics() {
+		let mut statement = Statement::new();
+		for i in 0..MAX_TOPICS + 1 {
+			statement.set_topic(i, [i as u8; 32]);
+		}
+		assert_eq!(statement.topics().len(), MAX_TOPICS);
+
+		statement.topics.push([0xff; 32]);
+		assert!(Statement::decode(&mut &statement.encode()[..]).is_err());
+	}
+
+	#[test]
+	fn sign_and_verify() {
+		let mut statement = Statement::new();
+		statement.set_plain_data(vec![42]);
+		assert_eq!(statement.verify_signature(), SignatureVerificationResult::NoSignature);
+
+		let sr25519_kp = sr25519::Pair::from_string("//Alice", None).unwrap();
+		let ed25519_kp = ed25519::Pair::from_string("//Alice", None).unwrap();
+		let secp256k1_kp = ecdsa::Pair::from_string("//Alice", None).unwrap();
+
+		statement.sign_sr25519_private(&sr25519_kp);
+		assert_eq!(
+			statement.verify_signature(),
+			SignatureVerificationResult::Valid(sr25519_kp.public().0)
+		);
+
+		statement.sign_ed25519_private(&ed25519_kp);
+		assert_eq!(
+			statement.verify_signature(),
+			SignatureVerificationResult::Valid(ed25519_kp.public().0)
+		);
+
+		statement.sign_ecdsa_private(&secp256k1_kp);
+		assert_eq!(
+			statement.verify_signature(),
+			SignatureVerificationResult::Valid(
+				BlakeTwo256::hash(secp256k1_kp.public().as_ref()).into()
+			)
+		);
+
+		// Changing any field invalidates the signature.
+		statement.set_priority(1);
+		assert_eq!(statement.verify_signature(), SignatureVerificationResult::Invalid);
+	}
+}
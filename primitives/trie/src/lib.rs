@@ -231,6 +231,32 @@ pub fn read_trie_value_with<
 		.map(|x| x.map(|val| val.to_vec()))
 }
 
+/// Read the Merkle value of the closest descendant of `key` in the trie, that is of the first
+/// node whose key starts with `key`.
+///
+/// The Merkle value of a node is its hash, or its encoding if it is inlined in its parent.
+pub fn read_closest_descendant_merkle_value<L, DB>(
+	db: &DB,
+	root: &TrieHash<L>,
+	key: &[u8],
+) -> Result<Option<Vec<u8>>, Box<TrieError<L>>>
+where
+	L: TrieConfiguration,
+	DB: hash_db::HashDBRef<L::Hash, trie_db::DBValue>,
+{
+	let trie = TrieDB::<L>::new(&*db, root)?;
+	let mut iter = trie_db::TrieDBNodeIterator::new(&trie)?;
+	iter.prefix(key)?;
+	match iter.next() {
+		Some(Ok((_, hash, node))) => Ok(match node.node_plan() {
+			trie_db::node::NodePlan::Empty => None,
+			_ => Some(hash.map_or_else(|| node.data().to_vec(), |hash| hash.as_ref().to_vec())),
+		}),
+		Some(Err(e)) => Err(e),
+		None => Ok(None),
+	}
+}
+
 /// Determine the empty trie root.
 pub fn empty_trie_root<L: TrieConfiguration>() -> <L::Hash as Hasher>::Out {
 	L::trie_root::<_, Vec<u8>, Vec<u8>>(core::iter::empty())
@@ -848,6 +874,50 @@ mod tests {
 		.is_err());
 	}
 
+	#[test]
+	fn closest_descendant_merkle_value_works() {
+		use sp_core::H256;
+
+		let value = vec![1u8; 64];
+		let mut db = MemoryDB::default();
+		let mut root = H256::default();
+		{
+			let mut t = TrieDBMut::<Layout>::new(&mut db, &mut root);
+			t.insert(b"ab1", &value).unwrap();
+			t.insert(b"ab2", &value).unwrap();
+			t.insert(b"cd", &value).unwrap();
+		}
+		fn merkle_value(db: &MemoryDB<Blake2Hasher>, root: &H256, key: &[u8]) -> Option<Vec<u8>> {
+			read_closest_descendant_merkle_value::<Layout, _>(db, root, key).unwrap()
+		}
+
+		// The closest descendant of the empty key is the root.
+		assert_eq!(merkle_value(&db, &root, b""), Some(root.as_ref().to_vec()));
+		assert_eq!(merkle_value(&db, &root, b"e"), None);
+		let ab = merkle_value(&db, &root, b"ab").unwrap();
+		assert_eq!(merkle_value(&db, &root, b"a"), Some(ab.clone()));
+		assert_ne!(merkle_value(&db, &root, b"ab1"), Some(ab.clone()));
+
+		// A proof of the lookup of `key` is enough to read its closest descendant.
+		let mut recorder = Recorder::new();
+		read_trie_value_with::<Layout, _, _>(&db, &root, b"ab", &mut recorder).unwrap();
+		let proof = StorageProof::new(recorder.drain().into_iter().map(|r| r.data).collect());
+		let proof_db = proof.into_memory_db::<Blake2Hasher>();
+		assert_eq!(merkle_value(&proof_db, &root, b"ab"), Some(ab.clone()));
+
+		// Only changes below `key` change the Merkle value of its closest descendant.
+		{
+			let mut t = TrieDBMut::<Layout>::from_existing(&mut db, &mut root).unwrap();
+			t.insert(b"cd", &[2u8; 64]).unwrap();
+		}
+		assert_eq!(merkle_value(&db, &root, b"ab"), Some(ab.clone()));
+		{
+			let mut t = TrieDBMut::<Layout>::from_existing(&mut db, &mut root).unwrap();
+			t.insert(b"ab2", &[2u8; 64]).unwrap();
+		}
+		assert_ne!(merkle_value(&db, &root, b"ab"), Some(ab));
+	}
+
 	#[test]
 	fn generate_storage_root_with_proof_works_independently_from_the_delta_order() {
 		let proof = StorageProof::decode(&mut &include_bytes!("../test-res/proof")[..]).unwrap();
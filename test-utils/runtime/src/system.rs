@@ -374,7 +374,7 @@ mod tests {
 	}
 
 	fn executor() -> NativeElseWasmExecutor<NativeDispatch> {
-		NativeElseWasmExecutor::new(WasmExecutionMethod::Interpreted, None, 8)
+		NativeElseWasmExecutor::new(WasmExecutionMethod::Interpreted, None, 8, 2)
 	}
 
 	fn new_test_ext() -> TestExternalities {
@@ -345,6 +345,14 @@ impl sc_transaction_pool::test_helpers::ChainApi for TestApi {
 				self.chain.read().block_by_hash.get(hash).map(|b| b.header().clone()),
 		})
 	}
+
+	fn tree_route(
+		&self,
+		from: Hash,
+		to: Hash,
+	) -> Result<sp_blockchain::TreeRoute<Self::Block>, Self::Error> {
+		TestApi::tree_route(self, from, to)
+	}
 }
 
 impl sp_blockchain::HeaderMetadata<Block> for TestApi {
@@ -111,6 +111,7 @@ where
 		config.wasm_method,
 		config.default_heap_pages,
 		config.max_runtime_instances,
+		config.runtime_cache_size,
 	);
 
 	let (client, backend, keystore, mut task_manager) =
@@ -162,6 +163,7 @@ where
 			on_demand: None,
 			block_announce_validator_builder: None,
 			warp_sync: None,
+			block_relay: None,
 		};
 		build_network(params)?
 	};
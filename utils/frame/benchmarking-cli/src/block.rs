@@ -0,0 +1,200 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The `benchmark-block` command, re-executing historic blocks and comparing their execution time
+//! to the weight they consumed.
+
+use codec::Decode;
+use frame_support::weights::{constants::WEIGHT_PER_NANOS, PerDispatchClass, Weight};
+use sc_cli::{CliConfiguration, ImportParams, Result, SharedParams};
+use sc_client_api::{Backend as ClientBackend, BlockBackend, StorageProvider};
+use sp_api::{Core, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_core::{storage::StorageKey, twox_128};
+use sp_runtime::{
+	generic::BlockId,
+	traits::{Block as BlockT, Header as HeaderT, UniqueSaturatedInto},
+};
+use std::{fmt::Debug, sync::Arc, time::Instant};
+
+/// The `benchmark-block` command used to check generated weights against historic blocks.
+///
+/// Every block in the range is re-executed on top of the state of its parent, and its execution
+/// time is compared to the weight it recorded in `System::BlockWeight`. A positive deviation
+/// means the block took longer than its weight predicted, i.e. the weights are underestimated.
+#[derive(Debug, structopt::StructOpt)]
+pub struct BlockCmd {
+	/// Number of the first block to re-execute. Must be at least 1.
+	#[structopt(long, default_value = "1")]
+	pub from: u32,
+
+	/// Number of the last block to re-execute. Defaults to the best block.
+	#[structopt(long)]
+	pub to: Option<u32>,
+
+	/// How many times every block is executed. The fastest of the runs is used.
+	#[structopt(long, default_value = "1")]
+	pub repeat: u32,
+
+	/// Print the deviation of every single block, not only the summary.
+	#[structopt(long)]
+	pub verbose: bool,
+
+	#[allow(missing_docs)]
+	#[structopt(flatten)]
+	pub shared_params: SharedParams,
+
+	#[allow(missing_docs)]
+	#[structopt(flatten)]
+	pub import_params: ImportParams,
+}
+
+impl BlockCmd {
+	/// Run the benchmark-block command.
+	pub async fn run<Block, BA, C>(&self, client: Arc<C>) -> Result<()>
+	where
+		Block: BlockT,
+		BA: ClientBackend<Block>,
+		C: BlockBackend<Block>
+			+ HeaderBackend<Block>
+			+ StorageProvider<Block, BA>
+			+ ProvideRuntimeApi<Block>,
+		C::Api: Core<Block>,
+	{
+		if self.from < 1 {
+			return Err(
+				"the genesis block can not be re-executed, `--from` must be at least 1".into()
+			)
+		}
+		if self.repeat < 1 {
+			return Err("`--repeat` must be at least 1".into())
+		}
+		let best: u64 = client.info().best_number.unique_saturated_into();
+		let to = self.to.map(u64::from).unwrap_or(best);
+		if to < self.from as u64 || to > best {
+			return Err(format!(
+				"invalid block range {}..={}, best block is {}",
+				self.from, to, best
+			)
+			.into())
+		}
+
+		let weight_key = StorageKey([twox_128(b"System"), twox_128(b"BlockWeight")].concat());
+		let mut deviations = Vec::new();
+
+		for number in self.from..=(to as u32) {
+			let id = BlockId::Number(number.into());
+			let block =
+				client.block(&id)?.ok_or_else(|| format!("block {} not found", number))?.block;
+			let hash = block.header().hash();
+			let parent = BlockId::Hash(*block.header().parent_hash());
+
+			let weight = match client.storage(&BlockId::Hash(hash), &weight_key)? {
+				Some(raw) => PerDispatchClass::<Weight>::decode(&mut &raw.0[..])
+					.map_err(|e| format!("failed to decode weight of block {}: {}", number, e))?
+					.total(),
+				None => return Err(format!("block {} did not record its weight", number).into()),
+			};
+
+			let mut elapsed = u128::MAX;
+			for _ in 0..self.repeat {
+				let runtime_api = client.runtime_api();
+				let start = Instant::now();
+				runtime_api
+					.execute_block(&parent, block.clone())
+					.map_err(|e| format!("failed to execute block {}: {:?}", number, e))?;
+				elapsed = elapsed.min(start.elapsed().as_nanos());
+			}
+
+			let deviation = deviation(elapsed, weight);
+			if self.verbose {
+				println!(
+					"block {}: {} extrinsics, {} ns, weight {} ({} ns), deviation {:+.2}%",
+					number,
+					block.extrinsics().len(),
+					elapsed,
+					weight,
+					weight / WEIGHT_PER_NANOS,
+					deviation,
+				);
+			}
+			deviations.push(deviation);
+		}
+
+		deviations.sort_by(|a, b| a.partial_cmp(b).expect("deviations are never NaN; qed"));
+		println!(
+			"Re-executed {} blocks, deviation of execution time from weight:",
+			deviations.len()
+		);
+		let percentiles =
+			[("min", 0.0), ("p50", 0.5), ("p75", 0.75), ("p90", 0.9), ("p99", 0.99), ("max", 1.0)];
+		for (name, p) in percentiles.iter() {
+			println!("  {}: {:+.2}%", name, percentile(&deviations, *p));
+		}
+
+		Ok(())
+	}
+}
+
+/// The deviation of `elapsed` nanoseconds from the time predicted by `weight`, in percent.
+///
+/// A weight of zero never takes any time, so every execution overshoots it infinitely; it is
+/// clamped to 1 to keep the deviation finite.
+fn deviation(elapsed: u128, weight: Weight) -> f64 {
+	let expected = (weight as f64 / WEIGHT_PER_NANOS as f64).max(1.0);
+	(elapsed as f64 / expected - 1.0) * 100.0
+}
+
+/// The `p` percentile of the non-empty, sorted `values`, using the nearest rank.
+fn percentile(values: &[f64], p: f64) -> f64 {
+	let rank = (p * values.len() as f64).ceil() as usize;
+	values[rank.saturating_sub(1).min(values.len() - 1)]
+}
+
+impl CliConfiguration for BlockCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+
+	fn import_params(&self) -> Option<&ImportParams> {
+		Some(&self.import_params)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn deviation_works() {
+		assert_eq!(deviation(1_000, 1_000 * WEIGHT_PER_NANOS), 0.0);
+		assert_eq!(deviation(1_500, 1_000 * WEIGHT_PER_NANOS), 50.0);
+		assert_eq!(deviation(500, 1_000 * WEIGHT_PER_NANOS), -50.0);
+		assert_eq!(deviation(2, 0), 100.0);
+	}
+
+	#[test]
+	fn percentile_works() {
+		let values = (1..=10).map(|v| v as f64).collect::<Vec<_>>();
+		assert_eq!(percentile(&values, 0.0), 1.0);
+		assert_eq!(percentile(&values, 0.5), 5.0);
+		assert_eq!(percentile(&values, 0.9), 9.0);
+		assert_eq!(percentile(&values, 0.99), 10.0);
+		assert_eq!(percentile(&values, 1.0), 10.0);
+		assert_eq!(percentile(&[3.0], 0.5), 3.0);
+	}
+}
@@ -23,13 +23,18 @@ use frame_benchmarking::{
 };
 use frame_support::traits::StorageInfo;
 use linked_hash_map::LinkedHashMap;
+use sc_chain_spec::GenesisConfigBuilderRuntimeCaller;
 use sc_cli::{CliConfiguration, ExecutionStrategy, Result, SharedParams};
 use sc_client_db::BenchmarkingState;
-use sc_executor::NativeElseWasmExecutor;
+use sc_executor::{sp_wasm_interface::HostFunctions, NativeElseWasmExecutor, WasmExecutor};
 use sc_service::{Configuration, NativeExecutionDispatch};
-use sp_core::offchain::{
-	testing::{TestOffchainExt, TestTransactionPoolExt},
-	OffchainDbExt, OffchainWorkerExt, TransactionPoolExt,
+use sp_core::{
+	offchain::{
+		testing::{TestOffchainExt, TestTransactionPoolExt},
+		OffchainDbExt, OffchainWorkerExt, TransactionPoolExt,
+	},
+	storage::{well_known_keys, Storage},
+	traits::CodeExecutor,
 };
 use sp_externalities::Extensions;
 use sp_keystore::{testing::KeyStore, KeystoreExt, SyncCryptoStorePtr};
@@ -87,8 +92,23 @@ fn combine_batches(
 		.collect::<Vec<_>>()
 }
 
+// Whether `name` matches `pattern`, where every `*` in `pattern` matches any (possibly empty)
+// sequence of bytes.
+fn matches_glob(pattern: &[u8], name: &[u8]) -> bool {
+	match pattern.iter().position(|b| *b == b'*') {
+		None => pattern == name,
+		Some(star) => {
+			let (prefix, rest) = (&pattern[..star], &pattern[star + 1..]);
+			name.starts_with(prefix) &&
+				(prefix.len()..=name.len()).any(|i| matches_glob(rest, &name[i..]))
+		},
+	}
+}
+
 impl BenchmarkCmd {
 	/// Runs the command and benchmarks the chain.
+	///
+	/// If `--runtime` is given, this is the same as [`run_with_runtime`](Self::run_with_runtime).
 	pub fn run<BB, ExecDispatch>(&self, config: Configuration) -> Result<()>
 	where
 		BB: BlockT + Debug,
@@ -96,6 +116,58 @@ impl BenchmarkCmd {
 		<BB as BlockT>::Hash: std::str::FromStr,
 		ExecDispatch: NativeExecutionDispatch + 'static,
 	{
+		if self.runtime.is_some() {
+			return self.run_with_runtime::<BB>()
+		}
+		self.check_paths()?;
+
+		let genesis_storage = config.chain_spec.build_storage()?;
+		let executor = NativeElseWasmExecutor::<ExecDispatch>::new(
+			self.wasm_method.into(),
+			self.heap_pages,
+			2, // The runtime instances cache size.
+			2, // The runtime cache size.
+		);
+		let strategy = self.execution.unwrap_or(ExecutionStrategy::Native);
+
+		self.run_benchmarks::<BB, _>(genesis_storage, &executor, strategy)
+	}
+
+	/// Runs the command and benchmarks the runtime Wasm blob given by `--runtime`.
+	///
+	/// No chain spec and native runtime are needed: the genesis state is built from the preset
+	/// `--genesis-builder-preset` through the `GenesisBuilder` API of the runtime, and the
+	/// benchmarks are executed in Wasm.
+	pub fn run_with_runtime<BB>(&self) -> Result<()>
+	where
+		BB: BlockT + Debug,
+	{
+		let runtime = self.runtime.as_ref().ok_or("No runtime given, use `--runtime`.")?;
+		self.check_paths()?;
+
+		let code = std::fs::read(runtime)
+			.map_err(|e| format!("Failed to read runtime {:?}: {}", runtime, e))?;
+		let mut genesis_storage = GenesisConfigBuilderRuntimeCaller::new(&code)
+			.get_storage_for_named_preset(&self.genesis_builder_preset)?;
+		genesis_storage.top.insert(well_known_keys::CODE.to_vec(), code);
+
+		let executor = WasmExecutor::new(
+			self.wasm_method.into(),
+			self.heap_pages,
+			sp_io::SubstrateHostFunctions::host_functions()
+				.into_iter()
+				.chain(frame_benchmarking::benchmarking::HostFunctions::host_functions())
+				.collect(),
+			2, // The runtime instances cache size.
+			None,
+			2, // The runtime cache size.
+		);
+
+		self.run_benchmarks::<BB, _>(genesis_storage, &executor, ExecutionStrategy::Wasm)
+	}
+
+	/// Checks the paths of the output, header and template files.
+	fn check_paths(&self) -> Result<()> {
 		if let Some(output_path) = &self.output {
 			if !output_path.is_dir() && output_path.file_name().is_none() {
 				return Err("Output file or path is invalid!".into())
@@ -114,15 +186,25 @@ impl BenchmarkCmd {
 			};
 		}
 
-		let spec = config.chain_spec;
-		let wasm_method = self.wasm_method.into();
-		let strategy = self.execution.unwrap_or(ExecutionStrategy::Native);
+		Ok(())
+	}
+
+	/// Benchmarks the runtime of `genesis_storage`, using `executor` with `strategy`.
+	fn run_benchmarks<BB, E>(
+		&self,
+		genesis_storage: Storage,
+		executor: &E,
+		strategy: ExecutionStrategy,
+	) -> Result<()>
+	where
+		BB: BlockT + Debug,
+		E: CodeExecutor + Clone + 'static,
+	{
 		let pallet = self.pallet.clone().unwrap_or_else(|| String::new());
 		let pallet = pallet.as_bytes();
 		let extrinsic = self.extrinsic.clone().unwrap_or_else(|| String::new());
 		let extrinsic = extrinsic.as_bytes();
 
-		let genesis_storage = spec.build_storage()?;
 		let mut changes = Default::default();
 		let cache_size = Some(self.database_cache_size as usize);
 		let state_with_tracking = BenchmarkingState::<BB>::new(
@@ -133,11 +215,6 @@ impl BenchmarkCmd {
 		)?;
 		let state_without_tracking =
 			BenchmarkingState::<BB>::new(genesis_storage, cache_size, self.record_proof, false)?;
-		let executor = NativeElseWasmExecutor::<ExecDispatch>::new(
-			wasm_method,
-			self.heap_pages,
-			2, // The runtime instances cache size.
-		);
 
 		let extensions = || -> Extensions {
 			let mut extensions = Extensions::default();
@@ -156,7 +233,7 @@ impl BenchmarkCmd {
 			state,
 			None,
 			&mut changes,
-			&executor,
+			executor,
 			"Benchmark_benchmark_metadata",
 			&(self.extra).encode(),
 			extensions(),
@@ -173,13 +250,13 @@ impl BenchmarkCmd {
 		// Use the benchmark list and the user input to determine the set of benchmarks to run.
 		let mut benchmarks_to_run = Vec::new();
 		list.iter()
-			.filter(|item| pallet.is_empty() || pallet == &b"*"[..] || pallet == &item.pallet[..])
+			.filter(|item| pallet.is_empty() || matches_glob(pallet, &item.pallet))
+			.filter(|item| {
+				!self.exclude_pallets.iter().any(|p| matches_glob(p.as_bytes(), &item.pallet))
+			})
 			.for_each(|item| {
 				for benchmark in &item.benchmarks {
-					if extrinsic.is_empty() ||
-						&extrinsic[..] == &b"*"[..] ||
-						extrinsic == benchmark.name
-					{
+					if extrinsic.is_empty() || matches_glob(extrinsic, &benchmark.name) {
 						benchmarks_to_run.push((
 							item.pallet.clone(),
 							benchmark.name.clone(),
@@ -247,7 +324,7 @@ impl BenchmarkCmd {
 						state,
 						None,
 						&mut changes,
-						&executor,
+						executor,
 						"Benchmark_dispatch_benchmark",
 						&(
 							&pallet.clone(),
@@ -274,7 +351,7 @@ impl BenchmarkCmd {
 						state, // todo remove tracking
 						None,
 						&mut changes,
-						&executor,
+						executor,
 						"Benchmark_dispatch_benchmark",
 						&(
 							&pallet.clone(),
@@ -307,7 +384,7 @@ impl BenchmarkCmd {
 						state, // todo remove tracking
 						None,
 						&mut changes,
-						&executor,
+						executor,
 						"Benchmark_dispatch_benchmark",
 						&(
 							&pallet.clone(),
@@ -486,3 +563,21 @@ fn list_benchmark(benchmarks_to_run: Vec<(Vec<u8>, Vec<u8>, Vec<(BenchmarkParame
 		println!("{}, {}", String::from_utf8_lossy(&pallet), String::from_utf8_lossy(&extrinsic));
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::matches_glob;
+
+	#[test]
+	fn matches_glob_works() {
+		assert!(matches_glob(b"*", b"pallet_balances"));
+		assert!(matches_glob(b"*", b""));
+		assert!(matches_glob(b"pallet_balances", b"pallet_balances"));
+		assert!(!matches_glob(b"pallet_balances", b"pallet_balances_2"));
+		assert!(matches_glob(b"pallet_*", b"pallet_balances"));
+		assert!(!matches_glob(b"pallet_*", b"frame_system"));
+		assert!(matches_glob(b"*_keep_alive", b"transfer_keep_alive"));
+		assert!(matches_glob(b"tr*er*", b"transfer_all"));
+		assert!(!matches_glob(b"tr*er*x", b"transfer_all"));
+	}
+}
@@ -15,9 +15,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod block;
 mod command;
 mod writer;
 
+pub use block::BlockCmd;
+
 use sc_cli::{ExecutionStrategy, WasmExecutionMethod};
 use std::fmt::Debug;
 
@@ -31,13 +34,21 @@ fn parse_pallet_name(pallet: &str) -> String {
 #[derive(Debug, structopt::StructOpt)]
 pub struct BenchmarkCmd {
 	/// Select a FRAME Pallet to benchmark, or `*` for all (in which case `extrinsic` must be `*`).
+	///
+	/// A `*` inside the name matches any sequence of characters, e.g. `pallet_*`.
 	#[structopt(short, long, parse(from_str = parse_pallet_name), required_unless = "list")]
 	pub pallet: Option<String>,
 
 	/// Select an extrinsic inside the pallet to benchmark, or `*` for all.
+	///
+	/// A `*` inside the name matches any sequence of characters, e.g. `transfer*`.
 	#[structopt(short, long, required_unless = "list")]
 	pub extrinsic: Option<String>,
 
+	/// Comma separated list of pallets that should be skipped, e.g. when `pallet` is `*`.
+	#[structopt(long, parse(from_str = parse_pallet_name), use_delimiter = true)]
+	pub exclude_pallets: Vec<String>,
+
 	/// Select how many samples we should take across the variable components.
 	#[structopt(short, long, default_value = "1")]
 	pub steps: u32,
@@ -132,6 +143,18 @@ pub struct BenchmarkCmd {
 	)]
 	pub wasm_method: WasmExecutionMethod,
 
+	/// Path to a runtime Wasm blob to benchmark, instead of the runtime of the chain spec.
+	///
+	/// The genesis state is built through the `GenesisBuilder` runtime API and the benchmarks are
+	/// executed in Wasm only, so neither a chain spec nor a native runtime is needed. `--chain`
+	/// and `--execution` are ignored.
+	#[structopt(long)]
+	pub runtime: Option<std::path::PathBuf>,
+
+	/// The `GenesisBuilder` preset to build the genesis state of `--runtime` from.
+	#[structopt(long, default_value = "development")]
+	pub genesis_builder_preset: String,
+
 	/// Limit the memory the database cache can use.
 	#[structopt(long = "db-cache", value_name = "MiB", default_value = "128")]
 	pub database_cache_size: u32,
@@ -0,0 +1,38 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Benchmark the pallets of any runtime from only its Wasm blob.
+//!
+//! In contrast to the `benchmark` command of a node, this needs neither a chain spec nor a node
+//! binary built with `runtime-benchmarks`. The runtime has to be built with `runtime-benchmarks`
+//! and implement the `GenesisBuilder` API, which is used to build its genesis state:
+//!
+//! ```sh
+//! frame-omni-bencher --runtime runtime.compact.compressed.wasm --pallet "*" --extrinsic "*"
+//! ```
+
+use frame_benchmarking_cli::BenchmarkCmd;
+use sp_runtime::{generic, traits::BlakeTwo256, OpaqueExtrinsic};
+use structopt::StructOpt;
+
+/// The block type used to benchmark the runtime, without knowing its actual block type.
+type Block = generic::Block<generic::Header<u32, BlakeTwo256>, OpaqueExtrinsic>;
+
+fn main() -> sc_cli::Result<()> {
+	sp_tracing::try_init_simple();
+	BenchmarkCmd::from_args().run_with_runtime::<Block>()
+}
@@ -26,22 +26,38 @@ use log::*;
 use sp_core::{
 	hashing::twox_128,
 	hexdisplay::HexDisplay,
-	storage::{StorageData, StorageKey},
+	storage::{
+		well_known_keys::DEFAULT_CHILD_STORAGE_KEY_PREFIX, ChildInfo, ChildType,
+		PrefixedStorageKey, StorageData, StorageKey,
+	},
 };
 pub use sp_io::TestExternalities;
 use sp_runtime::traits::Block as BlockT;
 use std::{
 	fs,
 	path::{Path, PathBuf},
+	time::Duration,
 };
 
 pub mod rpc_api;
 
 type KeyPair = (StorageKey, StorageData);
+/// The key-values of the default child tries, each identified by its prefixed storage key.
+type ChildKeyPairs = Vec<(StorageKey, Vec<KeyPair>)>;
 
 const LOG_TARGET: &str = "remote-ext";
 const DEFAULT_TARGET: &str = "wss://rpc.polkadot.io";
 const BATCH_SIZE: usize = 1000;
+/// Number of batch requests that are kept in flight at the same time.
+const PARALLEL_REQUESTS: usize = 4;
+/// Number of times a failed batch request is retried before giving up.
+const MAX_RETRIES: u32 = 3;
+/// Base delay between retries. It is multiplied by the number of the current attempt.
+const RETRY_BACKOFF: Duration = Duration::from_millis(500);
+/// Prefix of the state snapshot files, followed by `SNAPSHOT_VERSION`.
+const SNAPSHOT_PREFIX: &[u8] = b"remote-ext";
+/// Version of the state snapshot format. Version 1 added the default child tries.
+const SNAPSHOT_VERSION: u8 = 1;
 
 jsonrpsee_proc_macros::rpc_client_api! {
 	RpcApi<B: BlockT> {
@@ -54,6 +70,14 @@ jsonrpsee_proc_macros::rpc_client_api! {
 			start_key: Option<StorageKey>,
 			hash: Option<B::Hash>,
 		) -> Vec<StorageKey>;
+		#[rpc(method = "childstate_getKeysPaged", positional_params)]
+		fn child_get_keys_paged(
+			child_storage_key: PrefixedStorageKey,
+			prefix: Option<StorageKey>,
+			count: u32,
+			start_key: Option<StorageKey>,
+			hash: Option<B::Hash>,
+		) -> Vec<StorageKey>;
 		#[rpc(method = "chain_getFinalizedHead", positional_params)]
 		fn finalized_head() -> B::Hash;
 	}
@@ -66,6 +90,9 @@ pub enum Mode<B: BlockT> {
 	Online(OnlineConfig<B>),
 	/// Offline. Uses a state snapshot file and needs not any client config.
 	Offline(OfflineConfig),
+	/// Prefer using a cached state snapshot if it exists, else use a remote node and write the
+	/// downloaded state to the snapshot file of the offline config.
+	OfflineOrElseOnline(OfflineConfig, OnlineConfig<B>),
 }
 
 impl<B: BlockT> Default for Mode<B> {
@@ -113,6 +140,10 @@ pub struct OnlineConfig<B: BlockT> {
 	/// An optional state snapshot file to WRITE to, not for reading. Not written if set to `None`.
 	pub state_snapshot: Option<SnapshotConfig>,
 	/// The pallets to scrape. If empty, entire chain state will be scraped.
+	///
+	/// Default child tries are scraped only if their roots are among the scraped top keys. When
+	/// filtering by pallet, use [`Builder::inject_hashed_prefix`] with
+	/// `DEFAULT_CHILD_STORAGE_KEY_PREFIX` to include them.
 	pub pallets: Vec<String>,
 	/// Transport config.
 	pub transport: Transport,
@@ -188,7 +219,8 @@ impl<B: BlockT> Default for Builder<B> {
 impl<B: BlockT> Builder<B> {
 	fn as_online(&self) -> &OnlineConfig<B> {
 		match &self.mode {
-			Mode::Online(config) => &config,
+			Mode::Online(config) => config,
+			Mode::OfflineOrElseOnline(_, config) => config,
 			_ => panic!("Unexpected mode: Online"),
 		}
 	}
@@ -196,6 +228,7 @@ impl<B: BlockT> Builder<B> {
 	fn as_online_mut(&mut self) -> &mut OnlineConfig<B> {
 		match &mut self.mode {
 			Mode::Online(config) => config,
+			Mode::OfflineOrElseOnline(_, config) => config,
 			_ => panic!("Unexpected mode: Online"),
 		}
 	}
@@ -226,23 +259,40 @@ impl<B: BlockT> Builder<B> {
 	}
 
 	/// Get all the keys at `prefix` at `hash` using the paged, safe RPC methods.
+	///
+	/// The keys are read from the top trie, or from the default child trie `child` if given.
 	async fn get_keys_paged(
 		&self,
 		prefix: StorageKey,
+		child: Option<&PrefixedStorageKey>,
 		at: B::Hash,
 	) -> Result<Vec<StorageKey>, &'static str> {
 		const PAGE: u32 = 512;
+		let client = self.as_online().rpc_client();
 		let mut last_key: Option<StorageKey> = None;
 		let mut all_keys: Vec<StorageKey> = vec![];
 		let keys = loop {
-			let page = RpcApi::<B>::get_keys_paged(
-				self.as_online().rpc_client(),
-				Some(prefix.clone()),
-				PAGE,
-				last_key.clone(),
-				Some(at),
-			)
-			.await
+			let page = match child {
+				None =>
+					RpcApi::<B>::get_keys_paged(
+						client,
+						Some(prefix.clone()),
+						PAGE,
+						last_key.clone(),
+						Some(at),
+					)
+					.await,
+				Some(child) =>
+					RpcApi::<B>::child_get_keys_paged(
+						client,
+						child.clone(),
+						Some(prefix.clone()),
+						PAGE,
+						last_key.clone(),
+						Some(at),
+					)
+					.await,
+			}
 			.map_err(|e| {
 				error!(target: LOG_TARGET, "Error = {:?}", e);
 				"rpc get_keys failed"
@@ -269,65 +319,128 @@ impl<B: BlockT> Builder<B> {
 		Ok(keys)
 	}
 
-	/// Synonym of `rpc_get_pairs_unsafe` that uses paged queries to first get the keys, and then
-	/// map them to values one by one.
+	/// Fetch the values of `chunk_keys` at `at` in a single batch request.
 	///
-	/// This can work with public nodes. But, expect it to be darn slow.
-	pub(crate) async fn rpc_get_pairs_paged(
+	/// The values are read from the top trie, or from the default child trie `child` if given.
+	/// A failed request is retried up to [`MAX_RETRIES`] times, with a linearly increasing
+	/// delay between the attempts.
+	async fn rpc_get_batch(
 		&self,
-		prefix: StorageKey,
+		chunk_keys: &[StorageKey],
+		child: Option<&PrefixedStorageKey>,
 		at: B::Hash,
-	) -> Result<Vec<KeyPair>, &'static str> {
+	) -> Result<Vec<Option<StorageData>>, &'static str> {
 		use jsonrpsee_ws_client::types::traits::Client;
 		use serde_json::to_value;
-		let keys = self.get_keys_paged(prefix, at).await?;
-		let keys_count = keys.len();
-		debug!(target: LOG_TARGET, "Querying a total of {} keys", keys.len());
-
-		let mut key_values: Vec<KeyPair> = vec![];
 		let client = self.as_online().rpc_client();
-		for chunk_keys in keys.chunks(BATCH_SIZE) {
+		let mut attempt = 0;
+		loop {
 			let batch = chunk_keys
 				.iter()
 				.cloned()
-				.map(|key| {
-					(
+				.map(|key| match child {
+					None => (
 						"state_getStorage",
 						JsonRpcParams::Array(vec![
 							to_value(key).expect("json serialization will work; qed."),
 							to_value(at).expect("json serialization will work; qed."),
 						]),
-					)
+					),
+					Some(child) => (
+						"childstate_getStorage",
+						JsonRpcParams::Array(vec![
+							to_value(child).expect("json serialization will work; qed."),
+							to_value(key).expect("json serialization will work; qed."),
+							to_value(at).expect("json serialization will work; qed."),
+						]),
+					),
 				})
 				.collect::<Vec<_>>();
-			let values = client.batch_request::<Option<StorageData>>(batch).await.map_err(|e| {
-				log::error!(
-					target: LOG_TARGET,
-					"failed to execute batch: {:?}. Error: {:?}",
-					chunk_keys,
-					e
-				);
-				"batch failed."
-			})?;
-			assert_eq!(chunk_keys.len(), values.len());
-			for (idx, key) in chunk_keys.into_iter().enumerate() {
-				let maybe_value = values[idx].clone();
-				let value = maybe_value.unwrap_or_else(|| {
-					log::warn!(target: LOG_TARGET, "key {:?} had none corresponding value.", &key);
-					StorageData(vec![])
-				});
-				key_values.push((key.clone(), value));
-				if key_values.len() % (10 * BATCH_SIZE) == 0 {
-					let ratio: f64 = key_values.len() as f64 / keys_count as f64;
-					debug!(
+			match client.batch_request::<Option<StorageData>>(batch).await {
+				Ok(values) => return Ok(values),
+				Err(e) if attempt < MAX_RETRIES => {
+					attempt += 1;
+					log::warn!(
+						target: LOG_TARGET,
+						"failed to execute batch ({:?}), retrying ({}/{})",
+						e,
+						attempt,
+						MAX_RETRIES,
+					);
+					tokio::time::sleep(RETRY_BACKOFF * attempt).await;
+				},
+				Err(e) => {
+					log::error!(
 						target: LOG_TARGET,
-						"progress = {:.2} [{} / {}]",
-						ratio,
-						key_values.len(),
-						keys_count,
+						"failed to execute batch: {:?}. Error: {:?}",
+						chunk_keys,
+						e
 					);
+					return Err("batch failed.")
+				},
+			}
+		}
+	}
+
+	/// Synonym of `rpc_get_pairs_unsafe` that uses paged queries to first get the keys, and then
+	/// map them to values in batches.
+	///
+	/// Up to [`PARALLEL_REQUESTS`] batches are requested concurrently. This can work with public
+	/// nodes, but expect it to be slow for large prefixes.
+	pub(crate) async fn rpc_get_pairs_paged(
+		&self,
+		prefix: StorageKey,
+		at: B::Hash,
+	) -> Result<Vec<KeyPair>, &'static str> {
+		self.get_pairs_paged(prefix, None, at).await
+	}
+
+	/// Get all the key-values of the default child trie `child` at `at`.
+	pub(crate) async fn rpc_get_child_pairs_paged(
+		&self,
+		child: &PrefixedStorageKey,
+		at: B::Hash,
+	) -> Result<Vec<KeyPair>, &'static str> {
+		self.get_pairs_paged(StorageKey(vec![]), Some(child), at).await
+	}
+
+	async fn get_pairs_paged(
+		&self,
+		prefix: StorageKey,
+		child: Option<&PrefixedStorageKey>,
+		at: B::Hash,
+	) -> Result<Vec<KeyPair>, &'static str> {
+		let keys = self.get_keys_paged(prefix, child, at).await?;
+		let keys_count = keys.len();
+		debug!(target: LOG_TARGET, "Querying a total of {} keys", keys.len());
+
+		let mut key_values: Vec<KeyPair> = Vec::with_capacity(keys_count);
+		for group_keys in keys.chunks(BATCH_SIZE * PARALLEL_REQUESTS) {
+			let requests = group_keys
+				.chunks(BATCH_SIZE)
+				.map(|chunk_keys| self.rpc_get_batch(chunk_keys, child, at));
+			let responses = futures::future::join_all(requests).await;
+
+			for (chunk_keys, values) in group_keys.chunks(BATCH_SIZE).zip(responses) {
+				let values = values?;
+				assert_eq!(chunk_keys.len(), values.len());
+				for (key, maybe_value) in chunk_keys.iter().zip(values) {
+					let value = maybe_value.unwrap_or_else(|| {
+						log::warn!(target: LOG_TARGET, "key {:?} had none corresponding value.", &key);
+						StorageData(vec![])
+					});
+					key_values.push((key.clone(), value));
 				}
 			}
+
+			let ratio: f64 = key_values.len() as f64 / keys_count as f64;
+			info!(
+				target: LOG_TARGET,
+				"progress = {:.2} [{} / {}]",
+				ratio,
+				key_values.len(),
+				keys_count,
+			);
 		}
 
 		Ok(key_values)
@@ -336,22 +449,41 @@ impl<B: BlockT> Builder<B> {
 
 // Internal methods
 impl<B: BlockT> Builder<B> {
-	/// Save the given data as state snapshot.
-	fn save_state_snapshot(&self, data: &[KeyPair], path: &Path) -> Result<(), &'static str> {
+	/// Save the given top and child trie data as state snapshot.
+	fn save_state_snapshot(
+		&self,
+		data: &(Vec<KeyPair>, ChildKeyPairs),
+		path: &Path,
+	) -> Result<(), &'static str> {
 		debug!(target: LOG_TARGET, "writing to state snapshot file {:?}", path);
-		fs::write(path, data.encode()).map_err(|_| "fs::write failed.")?;
+		let mut bytes = SNAPSHOT_PREFIX.to_vec();
+		bytes.push(SNAPSHOT_VERSION);
+		data.encode_to(&mut bytes);
+		fs::write(path, bytes).map_err(|_| "fs::write failed.")?;
 		Ok(())
 	}
 
 	/// initialize `Self` from state snapshot. Panics if the file does not exist.
-	fn load_state_snapshot(&self, path: &Path) -> Result<Vec<KeyPair>, &'static str> {
+	fn load_state_snapshot(
+		&self,
+		path: &Path,
+	) -> Result<(Vec<KeyPair>, ChildKeyPairs), &'static str> {
 		info!(target: LOG_TARGET, "scraping key-pairs from state snapshot {:?}", path);
 		let bytes = fs::read(path).map_err(|_| "fs::read failed.")?;
-		Decode::decode(&mut &*bytes).map_err(|_| "decode failed")
+		let bytes = bytes
+			.strip_prefix(SNAPSHOT_PREFIX)
+			.ok_or("state snapshot of an older, unsupported format. Create it again.")?;
+		match bytes.split_first() {
+			Some((&SNAPSHOT_VERSION, data)) =>
+				Decode::decode(&mut &*data).map_err(|_| "decode failed"),
+			_ => Err("state snapshot of an unsupported version. Create it again."),
+		}
 	}
 
 	/// Build `Self` from a network node denoted by `uri`.
-	async fn load_remote(&self) -> Result<Vec<KeyPair>, &'static str> {
+	///
+	/// The default child tries whose roots are among the scraped top keys are scraped as well.
+	async fn load_remote(&self) -> Result<(Vec<KeyPair>, ChildKeyPairs), &'static str> {
 		let config = self.as_online();
 		let at = self
 			.as_online()
@@ -398,7 +530,35 @@ impl<B: BlockT> Builder<B> {
 			keys_and_values.push((key, value));
 		}
 
-		Ok(keys_and_values)
+		let child_keys_and_values = self.load_child_remote(&keys_and_values, at).await?;
+		Ok((keys_and_values, child_keys_and_values))
+	}
+
+	/// Scrape the default child tries whose prefixed storage keys are among `top_kv`.
+	async fn load_child_remote(
+		&self,
+		top_kv: &[KeyPair],
+		at: B::Hash,
+	) -> Result<ChildKeyPairs, &'static str> {
+		let mut child_kv = vec![];
+		for (prefixed_key, _) in
+			top_kv.iter().filter(|(k, _)| k.0.starts_with(DEFAULT_CHILD_STORAGE_KEY_PREFIX))
+		{
+			let child = PrefixedStorageKey::new_ref(&prefixed_key.0);
+			let kv = self.rpc_get_child_pairs_paged(child, at).await?;
+			debug!(
+				target: LOG_TARGET,
+				"downloaded data for child trie {:?} (count: {}).",
+				HexDisplay::from(prefixed_key),
+				kv.len(),
+			);
+			child_kv.push((prefixed_key.clone(), kv));
+		}
+
+		if !child_kv.is_empty() {
+			info!(target: LOG_TARGET, "downloaded data for {} child tries.", child_kv.len());
+		}
+		Ok(child_kv)
 	}
 
 	pub(crate) async fn init_remote_client(&mut self) -> Result<(), &'static str> {
@@ -422,8 +582,8 @@ impl<B: BlockT> Builder<B> {
 		Ok(())
 	}
 
-	pub(crate) async fn pre_build(mut self) -> Result<Vec<KeyPair>, &'static str> {
-		let mut base_kv = match self.mode.clone() {
+	pub(crate) async fn pre_build(mut self) -> Result<(Vec<KeyPair>, ChildKeyPairs), &'static str> {
+		let (mut base_kv, child_kv) = match self.mode.clone() {
 			Mode::Offline(config) => self.load_state_snapshot(&config.state_snapshot.path)?,
 			Mode::Online(config) => {
 				self.init_remote_client().await?;
@@ -433,6 +593,21 @@ impl<B: BlockT> Builder<B> {
 				}
 				kp
 			},
+			Mode::OfflineOrElseOnline(offline_config, online_config) => {
+				let path = &offline_config.state_snapshot.path;
+				if path.exists() {
+					self.load_state_snapshot(path)?
+				} else {
+					info!(target: LOG_TARGET, "state snapshot {:?} not found, using remote", path);
+					self.init_remote_client().await?;
+					let kp = self.load_remote().await?;
+					self.save_state_snapshot(&kp, path)?;
+					if let Some(c) = online_config.state_snapshot {
+						self.save_state_snapshot(&kp, &c.path)?;
+					}
+					kp
+				}
+			},
 		};
 
 		debug!(
@@ -441,7 +616,7 @@ impl<B: BlockT> Builder<B> {
 			self.inject.len()
 		);
 		base_kv.extend(self.inject.clone());
-		Ok(base_kv)
+		Ok((base_kv, child_kv))
 	}
 }
 
@@ -482,29 +657,47 @@ impl<B: BlockT> Builder<B> {
 		self
 	}
 
-	/// overwrite the `at` value, if `mode` is set to [`Mode::Online`].
+	/// overwrite the `at` value, if `mode` is set to [`Mode::Online`] or
+	/// [`Mode::OfflineOrElseOnline`].
 	///
 	/// noop if `mode` is [`Mode::Offline`]
 	pub fn overwrite_online_at(mut self, at: B::Hash) -> Self {
-		if let Mode::Online(mut online) = self.mode.clone() {
-			online.at = Some(at);
-			self.mode = Mode::Online(online);
+		match &mut self.mode {
+			Mode::Online(online) | Mode::OfflineOrElseOnline(_, online) => online.at = Some(at),
+			Mode::Offline(_) => {},
 		}
 		self
 	}
 
 	/// Build the test externalities.
 	pub async fn build(self) -> Result<TestExternalities, &'static str> {
-		let kv = self.pre_build().await?;
+		let (top_kv, child_kv) = self.pre_build().await?;
 		let mut ext = TestExternalities::new_empty();
 
-		info!(target: LOG_TARGET, "injecting a total of {} keys", kv.len());
-		for (k, v) in kv {
+		info!(target: LOG_TARGET, "injecting a total of {} top keys", top_kv.len());
+		for (k, v) in top_kv {
+			// The roots of the child tries are recomputed when the child tries are inserted.
+			if k.0.starts_with(DEFAULT_CHILD_STORAGE_KEY_PREFIX) {
+				continue
+			}
 			let (k, v) = (k.0, v.0);
 			// Insert the key,value pair into the test trie backend
 			ext.insert(k, v);
 		}
 
+		info!(target: LOG_TARGET, "injecting a total of {} child tries", child_kv.len());
+		for (prefixed_key, kv) in child_kv {
+			let child_info =
+				match ChildType::from_prefixed_key(PrefixedStorageKey::new_ref(&prefixed_key.0)) {
+					Some((ChildType::ParentKeyId, storage_key)) =>
+						ChildInfo::new_default(storage_key),
+					None => return Err("invalid child storage key."),
+				};
+			for (k, v) in kv {
+				ext.insert_child(child_info.clone(), k.0, v.0);
+			}
+		}
+
 		Ok(ext)
 	}
 }
@@ -541,6 +734,65 @@ mod tests {
 			.expect("Can't read state snapshot file")
 			.execute_with(|| {});
 	}
+
+	#[tokio::test]
+	async fn can_load_child_tries_from_state_snapshot() {
+		init_logger();
+		let child_info = ChildInfo::new_default(b"child");
+		let prefixed_key = StorageKey(child_info.prefixed_storage_key().into_inner());
+		let top = vec![(prefixed_key.clone(), StorageData(vec![0u8; 32]))];
+		let child = vec![(prefixed_key, vec![(StorageKey(b"foo".to_vec()), StorageData(vec![1]))])];
+
+		let path = "test_child_snapshot_to_remove.bin";
+		let builder = Builder::<Block>::new();
+		builder.save_state_snapshot(&(top, child), Path::new(path)).unwrap();
+
+		builder
+			.mode(Mode::Offline(OfflineConfig { state_snapshot: SnapshotConfig::new(path) }))
+			.build()
+			.await
+			.expect("Can't read state snapshot file")
+			.execute_with(|| {
+				assert_eq!(sp_io::default_child_storage::get(b"child", b"foo"), Some(vec![1]));
+			});
+		std::fs::remove_file(path).unwrap();
+	}
+
+	#[tokio::test]
+	async fn rejects_state_snapshot_of_older_format() {
+		init_logger();
+		let path = "test_old_snapshot_to_remove.bin";
+		let top: Vec<KeyPair> = vec![(StorageKey(b"foo".to_vec()), StorageData(vec![1]))];
+		std::fs::write(path, top.encode()).unwrap();
+
+		let result = Builder::<Block>::new()
+			.mode(Mode::Offline(OfflineConfig { state_snapshot: SnapshotConfig::new(path) }))
+			.build()
+			.await;
+		std::fs::remove_file(path).unwrap();
+		assert_eq!(
+			result.err(),
+			Some("state snapshot of an older, unsupported format. Create it again.")
+		);
+	}
+
+	#[tokio::test]
+	async fn can_load_cached_state_snapshot() {
+		init_logger();
+		// the snapshot exists, so the (unreachable) remote node is never contacted.
+		Builder::<Block>::new()
+			.mode(Mode::OfflineOrElseOnline(
+				OfflineConfig { state_snapshot: SnapshotConfig::new("test_data/proxy_test") },
+				OnlineConfig {
+					transport: "ws://127.0.0.1:1".to_owned().into(),
+					..Default::default()
+				},
+			))
+			.build()
+			.await
+			.expect("Can't read state snapshot file")
+			.execute_with(|| {});
+	}
 }
 
 #[cfg(all(test, feature = "remote-test"))]
@@ -0,0 +1,69 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::{fmt::Debug, path::PathBuf, str::FromStr};
+
+use sp_runtime::traits::Block as BlockT;
+
+use crate::{parse, State, LOG_TARGET};
+
+/// Configurations of the [`Command::CreateSnapshot`].
+#[derive(Debug, Clone, structopt::StructOpt)]
+pub struct CreateSnapshotCmd {
+	/// The url to connect to.
+	#[structopt(
+		short,
+		long,
+		parse(try_from_str = parse::url),
+	)]
+	pub uri: String,
+
+	/// The block hash at which to fetch the state.
+	///
+	/// If non provided, then the latest finalized head is used.
+	#[structopt(
+		short,
+		long,
+		multiple = false,
+		parse(try_from_str = parse::hash),
+	)]
+	pub at: Option<String>,
+
+	/// The pallets to scrape. If empty, entire chain state will be scraped.
+	#[structopt(short, long, require_delimiter = true)]
+	pub pallets: Option<Vec<String>>,
+
+	/// The path to write the snapshot to.
+	pub snapshot_path: PathBuf,
+}
+
+pub(crate) async fn create_snapshot<Block>(command: CreateSnapshotCmd) -> sc_cli::Result<()>
+where
+	Block: BlockT + serde::de::DeserializeOwned,
+	Block::Hash: FromStr,
+	<Block::Hash as FromStr>::Err: Debug,
+{
+	let CreateSnapshotCmd { uri, at, pallets, snapshot_path } = command;
+	let path = snapshot_path.clone();
+	let state = State::Live { uri, at, pallets, snapshot_path: Some(snapshot_path) };
+
+	// building the externalities scrapes the remote state and writes it to the snapshot.
+	let _ = state.builder::<Block>()?.build().await?;
+	log::info!(target: LOG_TARGET, "snapshot written to {:?}", path);
+
+	Ok(())
+}
@@ -19,6 +19,7 @@ use crate::{
 	build_executor, ensure_matching_spec, extract_code, full_extensions, hash_of, local_spec,
 	state_machine_call, SharedParams, State, LOG_TARGET,
 };
+use parity_scale_codec::Encode;
 use remote_externalities::rpc_api;
 use sc_service::{Configuration, NativeExecutionDispatch};
 use sp_core::storage::well_known_keys;
@@ -38,6 +39,13 @@ pub struct ExecuteBlockCmd {
 	#[structopt(long)]
 	no_check: bool,
 
+	/// Which try-state checks to run after the block. Only used if `no_check` is set too.
+	///
+	/// Can be `none`, `all`, `rr-<n>` to check `n` pallets in a round-robin fashion, or a comma
+	/// separated list of pallet names.
+	#[structopt(long, default_value = "none")]
+	try_state: frame_try_runtime::TryStateSelect,
+
 	/// The block hash at which to fetch the block.
 	///
 	/// If the `live` state type is being used, then this can be omitted, and is equal to whatever
@@ -167,16 +175,22 @@ where
 	)
 	.await;
 
+	let (method, payload) = if command.no_check {
+		("TryRuntime_execute_block_no_check", (block, command.try_state).encode())
+	} else {
+		("Core_execute_block", block.encode())
+	};
+
 	let _ = state_machine_call::<Block, ExecDispatch>(
 		&ext,
 		&executor,
 		execution,
-		if command.no_check { "TryRuntime_execute_block_no_check" } else { "Core_execute_block" },
-		block.encode().as_ref(),
+		method,
+		payload.as_ref(),
 		full_extensions(),
 	)?;
 
-	log::info!(target: LOG_TARGET, "Core_execute_block executed without errors.");
+	log::info!(target: LOG_TARGET, "{} executed without errors.", method);
 
 	Ok(())
 }
@@ -23,7 +23,7 @@ use jsonrpsee_ws_client::{
 	types::{traits::SubscriptionClient, v2::params::JsonRpcParams, Subscription},
 	WsClientBuilder,
 };
-use parity_scale_codec::Decode;
+use parity_scale_codec::{Decode, Encode};
 use remote_externalities::{rpc_api, Builder, Mode, OnlineConfig};
 use sc_executor::NativeExecutionDispatch;
 use sc_service::Configuration;
@@ -44,6 +44,13 @@ pub struct FollowChainCmd {
 			parse(try_from_str = parse::url),
 		)]
 	uri: String,
+
+	/// Which try-state checks to run after each block.
+	///
+	/// Can be `none`, `all`, `rr-<n>` to check `n` pallets in a round-robin fashion, or a comma
+	/// separated list of pallet names.
+	#[structopt(long, default_value = "none")]
+	try_state: frame_try_runtime::TryStateSelect,
 }
 
 pub(crate) async fn follow_chain<Block, ExecDispatch>(
@@ -142,12 +149,13 @@ where
 			&executor,
 			execution,
 			"TryRuntime_execute_block_no_check",
-			block.encode().as_ref(),
+			(block, command.try_state.clone()).encode().as_ref(),
 			full_extensions(),
 		)?;
 
-		let consumed_weight = <u64 as Decode>::decode(&mut &*encoded_result)
-			.map_err(|e| format!("failed to decode output: {:?}", e))?;
+		let (consumed_weight, try_state_weight) =
+			<(u64, u64) as Decode>::decode(&mut &*encoded_result)
+				.map_err(|e| format!("failed to decode output: {:?}", e))?;
 
 		let storage_changes = changes
 			.drain_storage_changes::<_, _, NumberFor<Block>>(
@@ -164,11 +172,24 @@ where
 
 		log::info!(
 			target: LOG_TARGET,
-			"executed block {}, consumed weight {}, new storage root {:?}",
+			"executed block {}, consumed weight {}, try-state weight {}, new storage root {:?}",
 			number,
 			consumed_weight,
+			try_state_weight,
 			state_ext.as_backend().root(),
 		);
+
+		// the state root check is disabled while executing the block, so that the local runtime
+		// can keep following the chain. Still, flag any divergence from the remote chain.
+		if state_ext.as_backend().root() != header.state_root() {
+			log::warn!(
+				target: LOG_TARGET,
+				"storage root of block {} diverged from the remote chain: local {:?}, remote {:?}",
+				number,
+				state_ext.as_backend().root(),
+				header.state_root(),
+			);
+		}
 	}
 
 	log::error!(target: LOG_TARGET, "ws subscription must have terminated.");
@@ -0,0 +1,183 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::{
+	collections::{BTreeMap, HashMap},
+	fmt::Debug,
+	path::PathBuf,
+	str::FromStr,
+};
+
+use sc_service::Configuration;
+use sp_core::{
+	bytes,
+	storage::{well_known_keys, ChildInfo, Storage, StorageChild},
+	twox_128,
+};
+use sp_runtime::traits::Block as BlockT;
+use sp_state_machine::Backend;
+
+use crate::{State, LOG_TARGET};
+
+/// Configurations of the [`Command::ForkOff`].
+#[derive(Debug, Clone, structopt::StructOpt)]
+pub struct ForkOffCmd {
+	/// Keep the runtime code of the forked state, instead of replacing it with the code of the
+	/// local chain spec (specified by `--chain`).
+	#[structopt(long)]
+	pub keep_remote_code: bool,
+
+	/// The pallets whose storage is taken from the genesis of the local chain spec, instead of
+	/// the forked state.
+	///
+	/// This is typically used to replace the validator set of the live chain with the one of the
+	/// local chain spec, e.g. `--genesis-pallets Session,Babe,Grandpa`.
+	#[structopt(long, require_delimiter = true)]
+	pub genesis_pallets: Vec<String>,
+
+	/// A JSON file of storage overrides, applied after everything else.
+	///
+	/// The file contains a single object, mapping storage keys to their new SCALE encoded value,
+	/// or to `null` to remove the key. Keys are either hex encoded, or the `Pallet::Item` name of
+	/// a storage value, e.g. `"Sudo::Key"`. Values are hex encoded.
+	#[structopt(long)]
+	pub patch: Option<PathBuf>,
+
+	/// The path to write the raw chain spec of the fork to.
+	#[structopt(short, long)]
+	pub output: PathBuf,
+
+	/// The state to fork off.
+	#[structopt(subcommand)]
+	pub state: State,
+}
+
+/// Parse a storage key of a patch file, either hex encoded or as `Pallet::Item`.
+fn patch_key(key: &str) -> sc_cli::Result<Vec<u8>> {
+	if key.starts_with("0x") {
+		return bytes::from_hex(key).map_err(|e| format!("invalid key {}: {:?}", key, e).into())
+	}
+
+	match key.split("::").collect::<Vec<_>>()[..] {
+		[pallet, item] if !pallet.is_empty() && !item.is_empty() =>
+			Ok([twox_128(pallet.as_bytes()), twox_128(item.as_bytes())].concat()),
+		_ => Err(format!("invalid key {}: expected hex or `Pallet::Item`", key).into()),
+	}
+}
+
+/// Apply the overrides of the patch file at `path` to `top`.
+fn apply_patch(top: &mut BTreeMap<Vec<u8>, Vec<u8>>, path: &PathBuf) -> sc_cli::Result<()> {
+	let file = std::fs::File::open(path)?;
+	let patch: BTreeMap<String, Option<String>> = serde_json::from_reader(file)
+		.map_err(|e| format!("failed to parse patch file {:?}: {}", path, e))?;
+
+	for (key, value) in patch {
+		let storage_key = patch_key(&key)?;
+		match value {
+			Some(value) => {
+				let value = bytes::from_hex(&value)
+					.map_err(|e| format!("invalid value of {}: {:?}", key, e))?;
+				log::info!(target: LOG_TARGET, "patching {}", key);
+				top.insert(storage_key, value);
+			},
+			None => {
+				log::info!(target: LOG_TARGET, "removing {}", key);
+				top.remove(&storage_key);
+			},
+		}
+	}
+
+	Ok(())
+}
+
+pub(crate) async fn fork_off<Block>(
+	command: ForkOffCmd,
+	mut config: Configuration,
+) -> sc_cli::Result<()>
+where
+	Block: BlockT + serde::de::DeserializeOwned,
+	Block::Hash: FromStr,
+	<Block::Hash as FromStr>::Err: Debug,
+{
+	let ext = command.state.builder::<Block>()?.build().await?;
+	let backend = ext.as_backend();
+
+	let mut top = backend.pairs().into_iter().collect::<BTreeMap<_, _>>();
+	let mut children_default = HashMap::new();
+	for key in top.keys().filter(|k| well_known_keys::is_child_storage_key(k)) {
+		let child_info = match key.strip_prefix(well_known_keys::DEFAULT_CHILD_STORAGE_KEY_PREFIX) {
+			Some(storage_key) => ChildInfo::new_default(storage_key),
+			None => {
+				log::warn!(target: LOG_TARGET, "skipping child trie of unknown type {:?}", key);
+				continue
+			},
+		};
+
+		let mut data = BTreeMap::new();
+		for child_key in backend.child_keys(&child_info, &[]) {
+			if let Some(value) = backend
+				.child_storage(&child_info, &child_key)
+				.map_err(|e| format!("failed to read child storage: {:?}", e))?
+			{
+				data.insert(child_key, value);
+			}
+		}
+		children_default
+			.insert(child_info.storage_key().to_vec(), StorageChild { data, child_info });
+	}
+	// the roots of the child tries are recomputed from their data at genesis.
+	top.retain(|k, _| !well_known_keys::is_child_storage_key(k));
+	log::info!(
+		target: LOG_TARGET,
+		"forking off {} top keys and {} child tries",
+		top.len(),
+		children_default.len(),
+	);
+
+	let genesis = config.chain_spec.build_storage()?;
+	if !command.keep_remote_code {
+		let code = genesis
+			.top
+			.get(well_known_keys::CODE)
+			.ok_or("local chain spec does not contain any code")?;
+		top.insert(well_known_keys::CODE.to_vec(), code.clone());
+	}
+
+	for pallet in &command.genesis_pallets {
+		let prefix = twox_128(pallet.as_bytes());
+		top.retain(|k, _| !k.starts_with(&prefix));
+		top.extend(
+			genesis
+				.top
+				.iter()
+				.filter(|(k, _)| k.starts_with(&prefix))
+				.map(|(k, v)| (k.clone(), v.clone())),
+		);
+		log::info!(target: LOG_TARGET, "using the genesis storage of {}", pallet);
+	}
+
+	if let Some(ref patch) = command.patch {
+		apply_patch(&mut top, patch)?;
+	}
+
+	config.chain_spec.set_storage(Storage { top, children_default });
+	let json = config.chain_spec.as_json(true)?;
+	std::fs::write(&command.output, json)?;
+	log::info!(target: LOG_TARGET, "chain spec of the fork written to {:?}", command.output);
+
+	Ok(())
+}
@@ -17,7 +17,7 @@
 
 use std::{fmt::Debug, str::FromStr};
 
-use parity_scale_codec::Decode;
+use parity_scale_codec::{Decode, Encode};
 use sc_executor::NativeExecutionDispatch;
 use sc_service::Configuration;
 use sp_runtime::traits::{Block as BlockT, NumberFor};
@@ -33,6 +33,13 @@ pub struct OnRuntimeUpgradeCmd {
 	/// The state type to use.
 	#[structopt(subcommand)]
 	pub state: State,
+
+	/// Which try-state checks to run after the runtime upgrade.
+	///
+	/// Can be `none`, `all`, `rr-<n>` to check `n` pallets in a round-robin fashion, or a comma
+	/// separated list of pallet names.
+	#[structopt(long, default_value = "none")]
+	pub try_state: frame_try_runtime::TryStateSelect,
 }
 
 pub(crate) async fn on_runtime_upgrade<Block, ExecDispatch>(
@@ -51,7 +58,7 @@ where
 	let executor = build_executor(&shared, &config);
 	let execution = shared.execution;
 
-	let ext = {
+	let mut ext = {
 		let builder = command.state.builder::<Block>()?;
 		let (code_key, code) = extract_code(&config.chain_spec)?;
 		builder.inject_key_value(&[(code_key, code)]).build().await?
@@ -69,7 +76,7 @@ where
 		.await;
 	}
 
-	let (_, encoded_result) = state_machine_call::<Block, ExecDispatch>(
+	let (mut changes, encoded_result) = state_machine_call::<Block, ExecDispatch>(
 		&ext,
 		&executor,
 		execution,
@@ -88,5 +95,38 @@ where
 		weight as f64 / total_weight.max(1) as f64
 	);
 
+	if command.try_state != frame_try_runtime::TryStateSelect::None {
+		// run the checks against the state left by the upgrade.
+		let storage_changes = changes
+			.drain_storage_changes::<_, _, NumberFor<Block>>(
+				&ext.backend,
+				None,
+				Default::default(),
+				&mut Default::default(),
+			)
+			.map_err(|e| format!("failed to drain the storage changes: {:?}", e))?;
+		ext.backend.apply_transaction(
+			storage_changes.transaction_storage_root,
+			storage_changes.transaction,
+		);
+
+		let (_, encoded_result) = state_machine_call::<Block, ExecDispatch>(
+			&ext,
+			&executor,
+			execution,
+			"TryRuntime_try_state",
+			command.try_state.encode().as_ref(),
+			Default::default(),
+		)?;
+
+		let try_state_weight = <u64 as Decode>::decode(&mut &*encoded_result)
+			.map_err(|e| format!("failed to decode output: {:?}", e))?;
+		log::info!(
+			target: LOG_TARGET,
+			"TryRuntime_try_state executed without errors. Consumed weight = {}",
+			try_state_weight,
+		);
+	}
+
 	Ok(())
 }
@@ -79,7 +79,7 @@
 //! 2. `--chain` flag (if present in your cli), which determines *which local runtime*, is selected.
 //!    This will specify:
 //!     1. which native runtime is used, if you select `--execution Native`
-//!	    2. which wasm runtime is used to replace the `:CODE:`, if try-runtime is instructed to do
+//! 	    2. which wasm runtime is used to replace the `:CODE:`, if try-runtime is instructed to do
 //!        so.
 //!
 //! All in all, if the term "local runtime" is used in the rest of this crate's documentation, it
@@ -200,6 +200,38 @@
 //!     --at <block-hash>
 //! ```
 //!
+//! To avoid scraping the same state from a remote node over and over again, the state can be
+//! saved to a snapshot file once, and then used by any command through `snap`.
+//!
+//! ```ignore
+//! cargo run try-runtime \
+//!     --chain polkadot-dev \
+//!     create-snapshot \
+//!     --uri wss://rpc.polkadot.io \
+//!     --at <block-hash> \
+//!     my-snapshot.bin
+//!
+//! cargo run try-runtime \
+//!     --execution Native \
+//!     --chain polkadot-dev \
+//!     on-runtime-upgrade \
+//!     snap \
+//!     --snapshot-path my-snapshot.bin
+//! ```
+//!
+//! The try-state checks of the pallets can be run on the state left by the migrations as well,
+//! e.g. for all pallets with `--try-state all`.
+//!
+//! ```ignore
+//! cargo run try-runtime \
+//!     --execution Native \
+//!     --chain polkadot-dev \
+//!     on-runtime-upgrade \
+//!     --try-state all \
+//!     snap \
+//!     --snapshot-path my-snapshot.bin
+//! ```
+//!
 //! Moving to `execute-block` and `offchain-workers`. For these commands, you always needs to
 //! specify a block hash. For the rest of these examples, we assume we're in the polkadot repo.
 //!
@@ -367,6 +399,21 @@ pub enum Command {
 	/// initializes the state from the remote node, and starts applying that block, plus all the
 	/// blocks that follow, to the same growing state.
 	FollowChain(commands::follow_chain::FollowChainCmd),
+
+	/// Create a new snapshot file of the state of a live chain.
+	///
+	/// The snapshot can then be used by any other command through [`State::Snap`], so that
+	/// repeated runs do not need to scrape the remote node again. Similar to [`State::Live`], the
+	/// scraped state can be limited to some pallets.
+	CreateSnapshot(commands::create_snapshot::CreateSnapshotCmd),
+
+	/// Fork off the state of a live chain (or a snapshot of it) into the raw chain spec of a new
+	/// development chain.
+	///
+	/// The runtime code is replaced by the one of the local chain spec (specified by `--chain`),
+	/// the storage of some pallets can be reset to their local genesis, e.g. to replace the
+	/// validator set, and any other key, e.g. the sudo key, can be overridden with a JSON patch.
+	ForkOff(commands::fork_off::ForkOffCmd),
 }
 
 /// Shared parameters of the `try-runtime` commands
@@ -542,6 +589,10 @@ impl TryRuntimeCmd {
 					config,
 				)
 				.await,
+			Command::CreateSnapshot(cmd) =>
+				commands::create_snapshot::create_snapshot::<Block>(cmd.clone()).await,
+			Command::ForkOff(cmd) =>
+				commands::fork_off::fork_off::<Block>(cmd.clone(), config).await,
 		}
 	}
 }
@@ -662,8 +713,14 @@ pub(crate) fn build_executor<D: NativeExecutionDispatch + 'static>(
 	let wasm_method = shared.wasm_method;
 	let heap_pages = shared.heap_pages.or(config.default_heap_pages);
 	let max_runtime_instances = config.max_runtime_instances;
+	let runtime_cache_size = config.runtime_cache_size;
 
-	NativeElseWasmExecutor::<D>::new(wasm_method.into(), heap_pages, max_runtime_instances)
+	NativeElseWasmExecutor::<D>::new(
+		wasm_method.into(),
+		heap_pages,
+		max_runtime_instances,
+		runtime_cache_size,
+	)
 }
 
 /// Execute the given `method` and `data` on top of `ext`, returning the results (encoded) and the
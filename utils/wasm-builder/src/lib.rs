@@ -63,6 +63,9 @@
 //! feature. This `runtime-wasm` feature will be enabled by the wasm builder when it compiles the
 //! Wasm binary. If this feature is not present, it will not be enabled.
 //!
+//! This means features like `runtime-benchmarks` or `try-runtime` are forwarded to the Wasm build
+//! automatically when they are enabled for the native build.
+//!
 //! ## Environment variables
 //!
 //! By using environment variables, you can configure which Wasm binaries are built and how:
@@ -83,6 +86,12 @@
 //!   needs to be absolute.
 //! - `WASM_BUILD_TOOLCHAIN` - The toolchain that should be used to build the Wasm binaries. The
 //!   format needs to be the same as used by cargo, e.g. `nightly-2020-02-20`.
+//! - `RUNTIME_METADATA_HASH` - The `0x` prefixed, hex encoded hash of the runtime metadata that is
+//!   used by `frame_system::CheckMetadataHash`. The Wasm binary is rebuilt when it changes.
+//! - `WASM_BUILD_OFFLINE` - Run the cargo invocation that builds the Wasm binary with `--offline`,
+//!   so that it never accesses the network.
+//! - `WASM_BUILD_LOCKED` - Run the cargo invocation that builds the Wasm binary with `--locked`, so
+//!   that it fails instead of updating the `Cargo.lock` copied from the main project.
 //!
 //! Each project can be skipped individually by using the environment variable
 //! `SKIP_PROJECT_NAME_WASM_BUILD`. Where `PROJECT_NAME` needs to be replaced by the name of the
@@ -138,6 +147,17 @@ const WASM_BUILD_TOOLCHAIN: &str = "WASM_BUILD_TOOLCHAIN";
 /// Environment variable that makes sure the WASM build is triggered.
 const FORCE_WASM_BUILD_ENV: &str = "FORCE_WASM_BUILD";
 
+/// Environment variable to build the wasm binary with `--offline`.
+const WASM_BUILD_OFFLINE_ENV: &str = "WASM_BUILD_OFFLINE";
+
+/// Environment variable to build the wasm binary with `--locked`.
+const WASM_BUILD_LOCKED_ENV: &str = "WASM_BUILD_LOCKED";
+
+/// Environment variable that holds the hash of the runtime metadata.
+///
+/// It is read by the runtime at compile time, so the Wasm binary needs to be rebuilt on change.
+const RUNTIME_METADATA_HASH_ENV: &str = "RUNTIME_METADATA_HASH";
+
 /// Write to the given `file` if the `content` is different.
 fn write_file_if_changed(file: impl AsRef<Path>, content: impl AsRef<str>) {
 	if fs::read_to_string(file.as_ref()).ok().as_deref() != Some(content.as_ref()) {
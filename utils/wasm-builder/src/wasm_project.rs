@@ -451,6 +451,14 @@ fn build_project(project: &Path, default_rustflags: &str, cargo_cmd: CargoComman
 		build_cmd.arg("--release");
 	};
 
+	if env::var(crate::WASM_BUILD_OFFLINE_ENV).is_ok() {
+		build_cmd.arg("--offline");
+	}
+
+	if env::var(crate::WASM_BUILD_LOCKED_ENV).is_ok() {
+		build_cmd.arg("--locked");
+	}
+
 	println!("{}", colorize_info_message("Information that should be included in a bug report."));
 	println!("{} {:?}", colorize_info_message("Executing build command:"), build_cmd);
 	println!("{} {}", colorize_info_message("Using rustc version:"), cargo_cmd.rustc_version());
@@ -633,6 +641,9 @@ fn generate_rerun_if_changed_instructions(
 	println!("cargo:rerun-if-env-changed={}", crate::WASM_BUILD_RUSTFLAGS_ENV);
 	println!("cargo:rerun-if-env-changed={}", crate::WASM_TARGET_DIRECTORY);
 	println!("cargo:rerun-if-env-changed={}", crate::WASM_BUILD_TOOLCHAIN);
+	println!("cargo:rerun-if-env-changed={}", crate::RUNTIME_METADATA_HASH_ENV);
+	println!("cargo:rerun-if-env-changed={}", crate::WASM_BUILD_OFFLINE_ENV);
+	println!("cargo:rerun-if-env-changed={}", crate::WASM_BUILD_LOCKED_ENV);
 }
 
 /// Track files and paths related to the given package to rerun `build.rs` on any relevant change.